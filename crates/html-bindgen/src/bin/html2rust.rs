@@ -0,0 +1,59 @@
+//! Convert HTML into Rust code which uses the element builders of the `html`
+//! crate.
+//!
+//! ```sh
+//! cargo run -p html-bindgen --features cli --bin html2rust -- template.html
+//! ```
+//!
+//! The code is printed to stdout, and anything which couldn't be converted is
+//! reported on stderr. The process exits with a non-zero status if there were
+//! any warnings.
+//!
+//! The element definitions are read from the `resources` directory of the
+//! source tree the binary was built from. Pass `--resources` when that tree
+//! isn't around anymore, e.g. after `cargo install`.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{fs, process};
+
+use html_bindgen::convert::Converter;
+use html_bindgen::Result;
+use structopt::StructOpt;
+
+/// Convert HTML into Rust code which uses the builders of the `html` crate
+#[derive(StructOpt)]
+struct Opt {
+    /// The HTML file to convert, read from stdin if left out
+    file: Option<PathBuf>,
+    /// The `resources` directory holding the element definitions
+    #[structopt(long)]
+    resources: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let opt = Opt::from_args();
+    let resources = opt
+        .resources
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../../resources"));
+    let converter = Converter::from_resources(&resources)?;
+
+    let source = match opt.file {
+        Some(path) => fs::read_to_string(path)?,
+        None => {
+            let mut source = String::new();
+            std::io::stdin().read_to_string(&mut source)?;
+            source
+        }
+    };
+
+    let conversion = converter.convert(&source);
+    print!("{}", conversion.code);
+    for warning in &conversion.warnings {
+        eprintln!("warning: {warning}");
+    }
+    if !conversion.warnings.is_empty() {
+        process::exit(1);
+    }
+    Ok(())
+}
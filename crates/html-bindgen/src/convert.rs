@@ -0,0 +1,579 @@
+//! Convert HTML into Rust code which builds the same tree using the element
+//! builders of the `html` crate.
+//!
+//! The HTML is parsed the way a browser would parse it, and the resulting
+//! tree is checked against the same content models the builders are generated
+//! from. Elements, attributes and text which the builders can't express are
+//! left out of the code, and reported as warnings. A comment is left in their
+//! place so they can be ported by hand.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use convert_case::{Case, Casing};
+use ego_tree::NodeRef;
+use html5ever::{namespace_url, ns};
+use scraper::{Html, Node};
+
+use crate::generate::html::{attr_method_name, element_method_name};
+use crate::generate::sys::keyword_variant_name;
+use crate::generate::{public_module_name, ModuleMapping};
+use crate::merge::MergedElement;
+use crate::parse::{Attribute, AttributeType, Namespace};
+use crate::Result;
+
+/// Chains which are at most this long are written on a single line.
+const CHAIN_WIDTH: usize = 60;
+/// The maximum width of a line, including its indentation.
+const MAX_WIDTH: usize = 100;
+
+/// Converts HTML into Rust code.
+#[derive(Debug)]
+pub struct Converter {
+    elements: Vec<Element>,
+    global_attributes: Vec<Attribute>,
+}
+
+#[derive(Debug)]
+struct Element {
+    merged: MergedElement,
+    /// The public module of the `html` crate the element is exported from.
+    module_name: String,
+    /// The names of the builder methods which append a child element, which
+    /// attribute methods may conflict with.
+    child_method_names: Vec<String>,
+}
+
+/// The result of converting HTML into Rust code.
+#[derive(Debug)]
+pub struct Conversion {
+    /// The `use` statements, followed by an expression for every element at
+    /// the top level of the input.
+    pub code: String,
+    /// The constructs which couldn't be converted.
+    pub warnings: Vec<String>,
+}
+
+impl Converter {
+    /// Create a new converter from the merged element definitions.
+    pub fn new(
+        elements: impl Iterator<Item = Result<MergedElement>>,
+        global_attributes: Vec<Attribute>,
+        module_map: &[ModuleMapping],
+    ) -> Result<Self> {
+        let elements = elements
+            .map(|merged| {
+                let merged = merged?;
+                let module_name = public_module_name(&merged, module_map)?;
+                let child_method_names = merged
+                    .permitted_child_elements
+                    .iter()
+                    .map(|element_ty| element_ty.to_case(Case::Snake))
+                    .collect();
+                Ok(Element {
+                    merged,
+                    module_name,
+                    child_method_names,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            elements,
+            global_attributes,
+        })
+    }
+
+    /// Create a new converter from the element definitions in the
+    /// `resources` directory of the repository.
+    pub fn from_resources(dir: &Path) -> Result<Self> {
+        let elements = fs::read_dir(dir.join("merged/elements"))?
+            .map(|entry| read_json::<MergedElement>(&entry?.path()));
+        let global_attributes = read_json(&dir.join("merged/global_attributes.json"))?;
+        let module_map = read_json::<Vec<ModuleMapping>>(&dir.join("manual/mdn_modules.json"))?;
+        Self::new(elements, global_attributes, &module_map)
+    }
+
+    /// Convert an HTML document or fragment into Rust code.
+    ///
+    /// Input starting with a doctype or an `<html>` tag is parsed as a
+    /// document, and anything else as the contents of a `<body>` element.
+    pub fn convert(&self, source: &str) -> Conversion {
+        let start = source.trim_start().to_ascii_lowercase();
+        let html = match start.starts_with("<!doctype") || start.starts_with("<html") {
+            true => Html::parse_document(source),
+            false => Html::parse_fragment(source),
+        };
+
+        let mut state = State {
+            converter: self,
+            imports: BTreeSet::new(),
+            warnings: vec![],
+        };
+        let root = html.root_element();
+        let items = match html.tree.root().value() {
+            Node::Document => vec![state.root(*root)],
+            _ => root
+                .children()
+                .filter_map(|node| match node.value() {
+                    Node::Element(_) => Some(state.root(node)),
+                    Node::Comment(comment) => Some(comment_lines(comment)),
+                    Node::Text(text) if !text.trim().is_empty() => {
+                        Some(state.warn("text outside of an element can't be converted".to_owned()))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        };
+
+        let mut code = state
+            .imports
+            .iter()
+            .map(|path| format!("use {path};\n"))
+            .collect::<String>();
+        if !code.is_empty() {
+            code.push('\n');
+        }
+        code.push_str(&items.join("\n\n"));
+        code.push('\n');
+        Conversion {
+            code,
+            warnings: state.warnings,
+        }
+    }
+
+    fn find_element(&self, namespace: Namespace, tag_name: &str) -> Option<&Element> {
+        find_name(
+            self.elements
+                .iter()
+                .filter(|el| el.merged.namespace == namespace),
+            tag_name,
+            |el| &el.merged.tag_name,
+        )
+    }
+}
+
+/// The state while converting a single input.
+struct State<'a> {
+    converter: &'a Converter,
+    imports: BTreeSet<String>,
+    warnings: Vec<String>,
+}
+
+impl<'a> State<'a> {
+    /// Record a warning, and return the comment which takes the place of the
+    /// construct in the code.
+    fn warn(&mut self, message: String) -> String {
+        let comment = format!("// html2rust: {message}");
+        self.warnings.push(message);
+        comment
+    }
+
+    /// Convert an element at the top level into an expression.
+    fn root(&mut self, node: NodeRef<'_, Node>) -> String {
+        let el = node.value().as_element().expect("the node is an element");
+        let namespace = namespace(el);
+        let Some(definition) = self.converter.find_element(namespace, el.name()) else {
+            return self.warn(format!("unknown element `<{}>`", el.name()));
+        };
+
+        let struct_name = &definition.merged.struct_name;
+        let module_name = &definition.module_name;
+        self.imports
+            .insert(format!("html::{module_name}::{struct_name}"));
+        let mut chain = self.element(node, definition, format!("{struct_name}::builder()"), false);
+        chain.calls.push(Call::Method("build".to_owned(), vec![]));
+        chain.render(0)
+    }
+
+    /// Convert an element into a chain of builder calls on `receiver`.
+    fn element(
+        &mut self,
+        node: NodeRef<'_, Node>,
+        definition: &'a Element,
+        receiver: String,
+        preserve_whitespace: bool,
+    ) -> Chain {
+        let el = node.value().as_element().expect("the node is an element");
+        let tag_name = &definition.merged.tag_name;
+        let preserve_whitespace = preserve_whitespace
+            || matches!(
+                tag_name.as_str(),
+                "pre" | "textarea" | "listing" | "script" | "style"
+            );
+
+        let mut calls = self.attributes(el, definition);
+        for child in node.children() {
+            let call = match child.value() {
+                Node::Element(child_el) => {
+                    self.child(child, child_el, definition, preserve_whitespace)
+                }
+                Node::Text(text) => {
+                    let text = match preserve_whitespace {
+                        true => Some(text.to_string()),
+                        false => collapse_whitespace(text),
+                    };
+                    match text {
+                        Some(text) => self.text(text, definition),
+                        None => continue,
+                    }
+                }
+                // Comments are kept in the tree if the element can hold
+                // them, and otherwise carried over into the code.
+                Node::Comment(comment) if definition.merged.permitted_child_elements.is_empty() => {
+                    Call::Comment(comment_lines(comment))
+                }
+                Node::Comment(comment) => {
+                    let comment = format!("{:?}", &**comment);
+                    Call::Method("comment".to_owned(), vec![Arg::Value(comment)])
+                }
+                // Templates keep their contents in a document fragment,
+                // which the builders can't express.
+                Node::Fragment => Call::Comment(
+                    self.warn(format!("the contents of `<{tag_name}>` can't be converted")),
+                ),
+                node => Call::Comment(self.warn(format!(
+                    "`<{tag_name}>` contains {node:?}, which can't be converted"
+                ))),
+            };
+            calls.push(call);
+        }
+        Chain { receiver, calls }
+    }
+
+    fn child(
+        &mut self,
+        node: NodeRef<'_, Node>,
+        el: &scraper::node::Element,
+        parent: &'a Element,
+        preserve_whitespace: bool,
+    ) -> Call {
+        let parent_tag_name = &parent.merged.tag_name;
+        let candidates = self.converter.elements.iter().filter(|candidate| {
+            candidate.merged.namespace == namespace(el)
+                && parent
+                    .merged
+                    .permitted_child_elements
+                    .contains(&candidate.merged.struct_name)
+        });
+        let Some(definition) = find_name(candidates, el.name(), |el| &el.merged.tag_name) else {
+            let message = match self.converter.find_element(namespace(el), el.name()) {
+                Some(_) if parent.merged.permitted_child_elements.is_empty() => {
+                    format!("`<{parent_tag_name}>` can't have any children")
+                }
+                Some(_) => format!(
+                    "`<{}>` is not a permitted child of `<{parent_tag_name}>`",
+                    el.name()
+                ),
+                None => format!("unknown element `<{}>`", el.name()),
+            };
+            return Call::Comment(self.warn(message));
+        };
+
+        let method_name = element_method_name(&definition.merged.struct_name);
+        let param = param_name(&definition.merged.tag_name, &method_name);
+        let chain = self.element(node, definition, param.clone(), preserve_whitespace);
+        Call::Method(method_name, vec![Arg::Closure(param, chain)])
+    }
+
+    fn text(&mut self, text: String, parent: &Element) -> Call {
+        let permitted = &parent.merged.permitted_child_elements;
+        let tag_name = &parent.merged.tag_name;
+        if permitted.iter().any(|child| child == "Text") {
+            Call::Method("text".to_owned(), vec![Arg::Value(format!("{text:?}"))])
+        } else if text.trim().is_empty() {
+            Call::Comment(String::new())
+        } else if permitted.is_empty() {
+            Call::Comment(self.warn(format!("`<{tag_name}>` can't have any children")))
+        } else {
+            Call::Comment(self.warn(format!("`<{tag_name}>` can't contain text")))
+        }
+    }
+
+    /// Convert the attributes of an element into calls to their setters.
+    ///
+    /// Attributes are rendered in the order they're defined in, rather than
+    /// the order they're set in, so they're converted in that order too.
+    fn attributes(&mut self, el: &scraper::node::Element, definition: &Element) -> Vec<Call> {
+        let merged = &definition.merged;
+        let globals = match merged.has_global_attributes {
+            true => self.converter.global_attributes.as_slice(),
+            false => &[],
+        };
+        let attributes = merged.attributes.iter().chain(globals).collect::<Vec<_>>();
+
+        let mut known = vec![];
+        let mut data = vec![];
+        let mut unknown = vec![];
+        for (name, value) in el.attrs() {
+            if let Some(key) = name.strip_prefix("data-") {
+                data.push((key, value));
+                continue;
+            }
+            let position = find_name(attributes.iter().enumerate(), name, |(_, attr)| &attr.name);
+            match position {
+                Some((position, attr)) => known.push((position, *attr, value)),
+                None => unknown.push(name),
+            }
+        }
+        known.sort_by_key(|(position, ..)| *position);
+        data.sort();
+        unknown.sort();
+
+        let tag_name = &merged.tag_name;
+        let mut calls = vec![];
+        for (_, attr, value) in known {
+            let method_name = attr_method_name(&definition.child_method_names, attr);
+            let value = match attr.ty {
+                // ARIA states are written as `"true"` or `"false"`.
+                AttributeType::Bool if attr.name.starts_with("aria-") => {
+                    Ok((value != "false").to_string())
+                }
+                _ => self.attribute_value(&attr.ty, value),
+            };
+            let call = match value {
+                Ok(value) => Call::Method(method_name, vec![Arg::Value(value)]),
+                Err(message) => Call::Comment(self.warn(format!(
+                    "the `{}` attribute of `<{tag_name}>` {message}",
+                    attr.name
+                ))),
+            };
+            calls.push(call);
+        }
+        for (key, value) in data {
+            let args = vec![
+                Arg::Value(format!("{key:?}")),
+                Arg::Value(format!("{value:?}")),
+            ];
+            calls.push(Call::Method("data".to_owned(), args));
+        }
+        for name in unknown {
+            let message = format!("`<{tag_name}>` has no attribute `{name}`");
+            calls.push(Call::Comment(self.warn(message)));
+        }
+        calls
+    }
+
+    /// Convert the value of an attribute into the type its setter takes.
+    fn attribute_value(
+        &mut self,
+        ty: &AttributeType,
+        value: &str,
+    ) -> std::result::Result<String, String> {
+        match ty {
+            // The presence of a boolean attribute means it's set, whatever
+            // its value is.
+            AttributeType::Bool => Ok("true".to_owned()),
+            AttributeType::String => Ok(format!("{value:?}")),
+            AttributeType::OptionalBool => match value.trim().parse::<bool>() {
+                Ok(value) => Ok(value.to_string()),
+                Err(_) => Err(format!("expects `true` or `false`, found {value:?}")),
+            },
+            AttributeType::Integer => match value.trim().parse::<i64>() {
+                Ok(value) => Ok(value.to_string()),
+                Err(_) => Err(format!("expects an integer, found {value:?}")),
+            },
+            AttributeType::Float => match value.trim().parse::<f64>() {
+                Ok(value) => Ok(format!("{value:?}")),
+                Err(_) => Err(format!("expects a number, found {value:?}")),
+            },
+            AttributeType::Enumerable(enumeration) => {
+                let name = &enumeration.name;
+                let keyword = find_name(enumeration.keywords.iter(), value, |keyword| {
+                    keyword.as_str()
+                });
+                let value = match keyword {
+                    Some(keyword) => format!("{name}::{}", keyword_variant_name(keyword)),
+                    None if enumeration.closed => {
+                        return Err(format!("doesn't permit {value:?}"));
+                    }
+                    None => format!("{name}::Custom({value:?}.into())"),
+                };
+                self.imports.insert(format!("html::attributes::{name}"));
+                Ok(value)
+            }
+            AttributeType::List(item_ty) => {
+                let items = value
+                    .split_ascii_whitespace()
+                    .map(|item| self.attribute_value(item_ty, item))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                match items.is_empty() {
+                    true => Err("expects at least one value".to_owned()),
+                    false => Ok(format!("[{}]", items.join(", "))),
+                }
+            }
+            AttributeType::Identifier(name) => Err(format!("takes a `{name}`")),
+        }
+    }
+}
+
+/// A chain of method calls on a receiver.
+struct Chain {
+    receiver: String,
+    calls: Vec<Call>,
+}
+
+enum Call {
+    Method(String, Vec<Arg>),
+    /// One or more lines of comments, or nothing at all.
+    Comment(String),
+}
+
+enum Arg {
+    Value(String),
+    /// A closure taking a builder, and the calls on that builder.
+    Closure(String, Chain),
+}
+
+impl Chain {
+    /// Write the chain on a single line, if it doesn't contain any comments.
+    fn single_line(&self) -> Option<String> {
+        let mut line = self.receiver.clone();
+        for call in &self.calls {
+            match call {
+                Call::Method(name, args) => {
+                    let args = args
+                        .iter()
+                        .map(|arg| match arg {
+                            Arg::Value(value) => Some(value.clone()),
+                            Arg::Closure(param, chain) => {
+                                Some(format!("|{param}| {}", chain.single_line()?))
+                            }
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    line.push_str(&format!(".{name}({})", args.join(", ")));
+                }
+                Call::Comment(comment) if comment.is_empty() => {}
+                Call::Comment(_) => return None,
+            }
+        }
+        Some(line)
+    }
+
+    /// Write the chain on a single line, if it fits at the given
+    /// indentation.
+    fn fitting_line(&self, indent: usize) -> Option<String> {
+        self.single_line()
+            .filter(|line| line.len() <= CHAIN_WIDTH && indent + line.len() <= MAX_WIDTH)
+    }
+
+    /// Write the chain, starting at the given indentation.
+    fn render(&self, indent: usize) -> String {
+        if let Some(line) = self.fitting_line(indent) {
+            return line;
+        }
+
+        let pad = " ".repeat(indent + 4);
+        let mut output = self.receiver.clone();
+        // Short receivers such as closure parameters are followed by their
+        // first call, like `rustfmt` does.
+        let mut separator = match self.receiver.len() <= 4 {
+            true => String::new(),
+            false => format!("\n{pad}"),
+        };
+        for call in &self.calls {
+            match call {
+                Call::Method(name, args) => {
+                    let args = args
+                        .iter()
+                        .map(|arg| match arg {
+                            Arg::Value(value) => value.clone(),
+                            // Nested chains which don't fit on a line go in a
+                            // block, which is only rendered once so deeply
+                            // nested trees don't take exponential time.
+                            Arg::Closure(param, chain) => match chain.fitting_line(indent + 4) {
+                                Some(line) => format!("|{param}| {line}"),
+                                None => format!(
+                                    "|{param}| {{\n{pad}    {}\n{pad}}}",
+                                    chain.render(indent + 8)
+                                ),
+                            },
+                        })
+                        .collect::<Vec<_>>();
+                    output.push_str(&format!("{separator}.{name}({})", args.join(", ")));
+                }
+                Call::Comment(comment) if comment.is_empty() => continue,
+                Call::Comment(comment) => {
+                    for line in comment.lines() {
+                        output.push_str(&format!("\n{pad}{line}"));
+                    }
+                }
+            }
+            separator = format!("\n{pad}");
+        }
+        output
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let s = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&s)?)
+}
+
+/// Find an item by name, preferring an exact match over an ASCII
+/// case-insensitive one.
+fn find_name<T>(
+    items: impl Iterator<Item = T> + Clone,
+    name: &str,
+    item_name: impl Fn(&T) -> &str,
+) -> Option<T> {
+    items
+        .clone()
+        .find(|item| item_name(item) == name)
+        .or_else(|| {
+            items
+                .into_iter()
+                .find(|item| item_name(item).eq_ignore_ascii_case(name))
+        })
+}
+
+fn namespace(el: &scraper::node::Element) -> Namespace {
+    if el.name.ns == ns!(svg) {
+        Namespace::Svg
+    } else if el.name.ns == ns!(mathml) {
+        Namespace::MathMl
+    } else {
+        Namespace::Html
+    }
+}
+
+/// The name of the closure parameter which holds the builder of an element.
+fn param_name(tag_name: &str, method_name: &str) -> String {
+    let name = tag_name.to_case(Case::Snake);
+    match syn::parse_str::<syn::Ident>(&name) {
+        Ok(_) => name,
+        // Tag names such as `use` are keywords.
+        Err(_) => method_name.to_owned(),
+    }
+}
+
+/// Collapse runs of whitespace in a text node into a single space. Text which
+/// only contains whitespace is dropped if it spans several lines, as it's
+/// most likely there to indent the HTML.
+fn collapse_whitespace(text: &str) -> Option<String> {
+    if text.trim().is_empty() {
+        return match text.contains('\n') {
+            true => None,
+            false => Some(" ".to_owned()),
+        };
+    }
+    let mut collapsed = String::new();
+    if text.starts_with(char::is_whitespace) {
+        collapsed.push(' ');
+    }
+    collapsed.push_str(&text.split_whitespace().collect::<Vec<_>>().join(" "));
+    if text.ends_with(char::is_whitespace) {
+        collapsed.push(' ');
+    }
+    Some(collapsed)
+}
+
+/// Convert an HTML comment into Rust line comments.
+fn comment_lines(comment: &str) -> String {
+    comment
+        .trim()
+        .lines()
+        .map(|line| format!("// {}", line.trim()).trim_end().to_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,139 @@
+use super::CodeFile;
+use crate::{utils, Result};
+
+/// Generate the `AnyElement` enum, which holds any element by value.
+pub(crate) fn gen_any_element(struct_names: &[String]) -> Result<CodeFile> {
+    let variants = struct_names
+        .iter()
+        .map(|el| {
+            format!(
+                "/// The `{el}` element
+                {el}(crate::generated::all::{el}),"
+            )
+        })
+        .collect::<String>();
+    let as_visit = struct_names
+        .iter()
+        .map(|el| format!("Self::{el}(el) => el,"))
+        .collect::<String>();
+    let debug = struct_names
+        .iter()
+        .map(|el| format!("Self::{el}(el) => std::fmt::Debug::fmt(el, f),"))
+        .collect::<String>();
+    let into_any = struct_names
+        .iter()
+        .map(|el| format!("Self::{el}(el) => Box::new(el),"))
+        .collect::<String>();
+    let from_impls = struct_names
+        .iter()
+        .map(|el| {
+            format!(
+                "
+                impl std::convert::From<crate::generated::all::{el}> for AnyElement {{
+                    fn from(value: crate::generated::all::{el}) -> Self {{
+                        Self::{el}(value)
+                    }}
+                }}"
+            )
+        })
+        .collect::<String>();
+
+    let code = format!(
+        r#"
+        /// Any element, held by value.
+        ///
+        /// Elements can be converted into the child enums of the elements
+        /// they're permitted in with `TryFrom`.
+        #[derive(PartialEq, Clone)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyElement {{
+            {variants}
+        }}
+
+        impl AnyElement {{
+            /// Access the element.
+            pub fn as_visit(&self) -> &dyn crate::visit::Visit {{
+                match self {{
+                    {as_visit}
+                }}
+            }}
+
+            /// Mutably access the element.
+            pub fn as_visit_mut(&mut self) -> &mut dyn crate::visit::Visit {{
+                match self {{
+                    {as_visit}
+                }}
+            }}
+
+            pub(crate) fn into_any(self) -> Box<dyn std::any::Any> {{
+                match self {{
+                    {into_any}
+                }}
+            }}
+        }}
+
+        {from_impls}
+
+        impl std::fmt::Debug for AnyElement {{
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                match self {{
+                    {debug}
+                }}
+            }}
+        }}
+
+        impl std::fmt::Display for AnyElement {{
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                std::fmt::Display::fmt(self.as_visit(), f)
+            }}
+        }}
+        "#
+    );
+
+    Ok(CodeFile {
+        filename: "any_element.rs".to_owned(),
+        code: utils::fmt(&code)?,
+        dir: String::new(),
+    })
+}
+
+/// Generate the conversion from `AnyElement` into the child enum of an
+/// element.
+pub(crate) fn gen_try_from_any(
+    tag_name: &str,
+    struct_name: &str,
+    permitted_child_elements: &[String],
+) -> String {
+    if permitted_child_elements.is_empty() {
+        return String::new();
+    }
+
+    let patterns = permitted_child_elements
+        .iter()
+        .filter(|el| *el != "Text")
+        .map(|el| format!("crate::AnyElement::{el}(el) => Ok(Self::{el}(el)),"))
+        .collect::<String>();
+    let body = match patterns.is_empty() {
+        true => format!(r#"Err(crate::NotPermitted::new(element, "{tag_name}"))"#),
+        false => format!(
+            r#"
+            match element {{
+                {patterns}
+                #[allow(unreachable_patterns)]
+                element => Err(crate::NotPermitted::new(element, "{tag_name}")),
+            }}"#
+        ),
+    };
+
+    format!(
+        r#"
+        impl std::convert::TryFrom<crate::AnyElement> for {struct_name}Child {{
+            type Error = crate::NotPermitted;
+
+            fn try_from(element: crate::AnyElement) -> Result<Self, Self::Error> {{
+                {body}
+            }}
+        }}
+        "#
+    )
+}
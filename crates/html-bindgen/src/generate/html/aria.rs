@@ -0,0 +1,68 @@
+use super::CodeFile;
+use crate::merge::MergedAria;
+use crate::{utils, Result};
+
+/// Generate the tables which map elements to their implicit ARIA roles, and
+/// roles to the properties they support and where their names come from.
+pub(crate) fn gen_aria(
+    aria: &MergedAria,
+    implicit_roles: &[(String, Vec<String>)],
+) -> Result<CodeFile> {
+    let list = |items: &[String]| {
+        items
+            .iter()
+            .map(|item| format!("{item:?},"))
+            .collect::<String>()
+    };
+    let global_properties = list(&aria.global_properties);
+    let roles = aria
+        .roles
+        .iter()
+        .map(|role| format!("({:?}, &[{}]),", role.name, list(&role.properties)))
+        .collect::<String>();
+    let name_from_contents = aria
+        .roles
+        .iter()
+        .filter(|role| role.name_from_contents)
+        .map(|role| format!("{:?},", role.name))
+        .collect::<String>();
+    let implicit_roles = implicit_roles
+        .iter()
+        .filter(|(_, roles)| !roles.is_empty())
+        .map(|(tag_name, roles)| format!("{tag_name:?} => &[{}],", list(roles)))
+        .collect::<String>();
+
+    let code = format!(
+        r#"
+        //! The ARIA roles of elements, and the properties they support.
+
+        /// The properties which are supported regardless of the role of an
+        /// element.
+        pub(crate) const GLOBAL_PROPERTIES: &[&str] = &[{global_properties}];
+
+        /// The concrete roles and the properties they support, sorted by
+        /// name.
+        pub(crate) const ROLES: &[(&str, &[&str])] = &[{roles}];
+
+        /// The roles which can get their name from their contents, sorted by
+        /// name.
+        pub(crate) const NAME_FROM_CONTENTS: &[&str] = &[{name_from_contents}];
+
+        /// The roles an HTML element can have when it has no `role` attribute.
+        /// Which of them applies depends on the element's attributes and
+        /// ancestors.
+        pub(crate) fn implicit_roles(tag_name: &str) -> &'static [&'static str] {{
+            match tag_name {{
+                {implicit_roles}
+                _ => &[],
+            }}
+        }}
+        "#
+    );
+
+    Ok(CodeFile {
+        filename: "aria.rs".to_owned(),
+        code: utils::fmt(&code)?,
+        dir: String::new(),
+    })
+}
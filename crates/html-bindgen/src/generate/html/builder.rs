@@ -1,9 +1,11 @@
+use super::list_item_type;
 use crate::parse::{Attribute, AttributeType};
 use convert_case::{Case, Casing};
 
 pub(crate) fn gen_builder(
     struct_name: &str,
     permitted_child_elements: &[String],
+    is_raw_text: bool,
     method_attributes: &[Attribute],
 ) -> String {
     let builder_ty = format!("{struct_name}Builder");
@@ -16,7 +18,7 @@ pub(crate) fn gen_builder(
     let has_children = !permitted_child_elements.is_empty();
 
     let element_methods = gen_element_methods(permitted_child_elements);
-    let push_append_methods = gen_push_append_methods(&struct_name, has_children);
+    let push_append_methods = gen_push_append_methods(struct_name, has_children, is_raw_text);
     let attr_methods = gen_attr_methods(&method_names, method_attributes);
 
     format!(
@@ -50,13 +52,35 @@ pub(crate) fn gen_builder(
     )
 }
 
-fn gen_push_append_methods(struct_name: &str, has_children: bool) -> String {
+fn gen_push_append_methods(struct_name: &str, has_children: bool, is_raw_text: bool) -> String {
     if !has_children {
         return String::new();
     }
     let child_ty = format!("crate::generated::all::children::{struct_name}Child");
+    let comment_method = match is_raw_text {
+        true => "",
+        false => {
+            "
+        /// Append a comment.
+        pub fn comment(&mut self, s: impl Into<std::borrow::Cow<'static, str>>) -> &mut Self {
+            self.element.children_mut().push(crate::Comment::new(s).into());
+            self
+        }"
+        }
+    };
     format!(
         "
+        {comment_method}
+
+        /// Append trusted HTML, which is written without being escaped.
+        ///
+        /// It's up to the caller to make sure the HTML is well-formed and
+        /// doesn't contain any untrusted input.
+        pub fn raw_html(&mut self, s: impl Into<std::borrow::Cow<'static, str>>) -> &mut Self {{
+            self.element.children_mut().push(crate::RawHtml::new(s).into());
+            self
+        }}
+
         /// Push a new child element to the list of children.
         pub fn push<T>(&mut self, child_el: T) -> &mut Self
         where
@@ -86,22 +110,16 @@ fn gen_element_methods(permitted_child_elements: &[String]) -> String {
     permitted_child_elements
         .iter()
         .map(|element_ty| {
-            let method_name = match element_ty.to_case(Case::Snake).as_str() {
-                "data" => "data_el".to_owned(),
-                other => other.to_owned(),
-            };
+            let method_name = element_method_name(element_ty);
 
             match element_ty.as_str() {
-                "Text" => {
-                    // String::new()
-                    format!("/// Append a new text element.
-                            pub fn text(&mut self, s: impl Into<std::borrow::Cow<'static, str>>) -> &mut Self {{
-                                let cow = s.into();
-                                self.element.children_mut().push(cow.into());
-                                self
-                            }}"
-                        )
-                }
+                "Text" => "/// Append a new text element.
+                    pub fn text(&mut self, s: impl Into<std::borrow::Cow<'static, str>>) -> &mut Self {
+                        let cow = s.into();
+                        self.element.children_mut().push(cow.into());
+                        self
+                    }"
+                .to_owned(),
                 element_ty => {
                     let ty = format!("crate::generated::all::{element_ty}");
                     let ty_builder =
@@ -128,34 +146,30 @@ fn gen_element_methods(permitted_child_elements: &[String]) -> String {
 
 fn gen_attr_methods(permitted_child_elements: &[String], attributes: &[Attribute]) -> String {
     attributes
-        .into_iter()
+        .iter()
         .map(|attr| {
             let name = &attr.name;
             let field_name = &attr.field_name;
-
-            // A field name may conflict with element name. In which case we
-            // suffix the method name to include `_attr`.
-            let method_name = if permitted_child_elements.contains(field_name) {
-                format!("{field_name}_attr")
-            } else {
-                field_name.clone()
-            };
-
-            let method_name = match method_name.as_str() {
-                "data" => "data_attr".to_owned(),
-                other => other.to_owned(),
-            };
+            let method_name = attr_method_name(permitted_child_elements, attr);
 
             let param_ty = match &attr.ty {
                 AttributeType::Bool => "bool".to_owned(),
                 AttributeType::String => "impl Into<std::borrow::Cow<'static, str>>".to_owned(),
+                AttributeType::Enumerable(_) => format!("html_sys::attributes::{}", attr.ty),
+                AttributeType::List(item_ty) => {
+                    format!(
+                        "impl IntoIterator<Item = impl Into<{}>>",
+                        list_item_type(item_ty)
+                    )
+                }
                 ty => format!("{ty}"),
             };
 
             let field_setter = match &attr.ty {
-                AttributeType::String => format!("Some(value.into())"),
-                AttributeType::Bool => format!("value"),
-                _ => format!("Some(value)"),
+                AttributeType::String => "Some(value.into())".to_string(),
+                AttributeType::Bool => "value".to_string(),
+                AttributeType::List(_) => "value.into_iter().map(Into::into).collect()".to_string(),
+                _ => "Some(value)".to_string(),
             };
             format!(
                 "
@@ -168,3 +182,30 @@ fn gen_attr_methods(permitted_child_elements: &[String], attributes: &[Attribute
         })
         .collect()
 }
+
+/// The name of the builder method which appends a child element.
+pub(crate) fn element_method_name(element_ty: &str) -> String {
+    match element_ty.to_case(Case::Snake).as_str() {
+        "data" => "data_el".to_owned(),
+        "use" => "use_".to_owned(),
+        other => other.to_owned(),
+    }
+}
+
+/// The name of the builder method which sets an attribute.
+pub(crate) fn attr_method_name(permitted_child_elements: &[String], attr: &Attribute) -> String {
+    let field_name = &attr.field_name;
+
+    // A field name may conflict with element name. In which case we
+    // suffix the method name to include `_attr`.
+    let method_name = if permitted_child_elements.contains(field_name) {
+        format!("{field_name}_attr")
+    } else {
+        field_name.clone()
+    };
+
+    match method_name.as_str() {
+        "data" => "data_attr".to_owned(),
+        other => other.to_owned(),
+    }
+}
@@ -1,49 +1,100 @@
 use super::{CodeFile, ModuleMapping};
-use crate::merge::{MergedCategory, MergedElement};
-use crate::parse::{Attribute, AttributeType};
+use crate::merge::{MergedAria, MergedCategory, MergedElement};
+use crate::parse::{Attribute, AttributeType, Namespace};
 use crate::{utils, Result};
+use any::{gen_any_element, gen_try_from_any};
+use aria::gen_aria;
 use builder::gen_builder;
 use indoc::formatdoc;
-
+use parse::{gen_from_content, gen_from_html};
+use serialize::{gen_serde_child, gen_serde_element};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tree::{gen_tree_child, gen_tree_element};
+use visit::{gen_visit_child, gen_visit_element};
+
+mod any;
+mod aria;
 mod builder;
+mod parse;
+mod serialize;
+mod tree;
+mod visit;
+
+pub(crate) use builder::attr_method_name;
+#[cfg(feature = "cli")]
+pub(crate) use builder::element_method_name;
 
 pub fn generate(
     parsed: impl Iterator<Item = Result<MergedElement>>,
     global_attributes: &[Attribute],
+    aria: &MergedAria,
     module_map: &[super::ModuleMapping],
 ) -> Result<Vec<CodeFile>> {
     let mut output = vec![];
-    let mut tag_names = vec![];
+    let mut module_names = vec![];
+    let mut foreign_modules: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut has_children = HashSet::new();
+    let mut struct_names = vec![];
+    let mut implicit_roles = vec![];
+
+    // Child enums refer to elements by struct name, but parsing needs to
+    // match on tag names.
+    let elements = parsed.collect::<Result<Vec<_>>>()?;
+    let struct_tag_names = elements
+        .iter()
+        .map(|el| (el.struct_name.clone(), el.tag_name.clone()))
+        .collect::<HashMap<_, _>>();
 
     // generate individual `{element}.rs` files
-    for el in parsed {
-        let el = el?;
-        tag_names.push(el.tag_name.clone());
-        output.push(generate_element(el, global_attributes)?);
+    for el in elements {
+        let module_name = el.module_name();
+        module_names.push(module_name.clone());
+        struct_names.push(el.struct_name.clone());
+        if el.namespace != Namespace::Html {
+            let entry = foreign_modules.entry(el.submodule_name.clone());
+            entry.or_default().push(module_name.clone());
+        }
+        if !el.permitted_child_elements.is_empty() {
+            has_children.insert(module_name);
+        }
+        if el.namespace == Namespace::Html {
+            implicit_roles.push((el.tag_name.clone(), el.implicit_roles.clone()));
+        }
+        output.push(generate_element(el, global_attributes, &struct_tag_names)?);
     }
 
-    let mods = tag_names
+    output.push(gen_any_element(&struct_names)?);
+    output.push(gen_aria(aria, &implicit_roles)?);
+
+    let mods = module_names
         .iter()
-        .map(|tag_name| format!("pub(crate) mod {tag_name};"))
+        .map(|module_name| format!("pub(crate) mod {module_name};"))
         .collect::<String>();
 
-    let all_files = tag_names
+    let all_files = module_names
         .iter()
-        .map(|tag_name| format!("pub(crate) use crate::generated::{tag_name}::element::*;"))
+        .map(|module_name| format!("pub(crate) use crate::generated::{module_name}::element::*;"))
         .collect::<String>();
 
-    let all_builders = tag_names
+    let all_builders = module_names
         .iter()
-        .map(|tag_name| format!("pub(crate) use crate::generated::{tag_name}::builder::*;"))
+        .map(|module_name| format!("pub(crate) use crate::generated::{module_name}::builder::*;"))
         .collect::<String>();
 
-    let all_children = tag_names
+    let all_children = module_names
         .iter()
-        .map(|tag_name| format!("pub(crate) use crate::generated::{tag_name}::child::*;"))
+        .map(|module_name| format!("pub(crate) use crate::generated::{module_name}::child::*;"))
         .collect::<String>();
 
+    // MDN documents the SVG and MathML elements separately from the HTML
+    // elements, so they're each grouped into a single module.
+    let foreign_mappings = foreign_modules
+        .into_iter()
+        .map(|(name, children)| ModuleMapping { name, children })
+        .collect::<Vec<_>>();
     let by_mapping = module_map
         .iter()
+        .chain(&foreign_mappings)
         .map(|ModuleMapping { name, children }| {
             let elements = children
                 .iter()
@@ -55,6 +106,7 @@ pub fn generate(
                 .collect::<String>();
             let children = children
                 .iter()
+                .filter(|tag_name| has_children.contains(*tag_name))
                 .map(|tag_name| format!("pub use crate::generated::{tag_name}::child::*;"))
                 .collect::<String>();
 
@@ -80,6 +132,8 @@ pub fn generate(
 
     let code = format!(
         "//! HTML elements support
+        pub(crate) mod any_element;
+        pub(crate) mod aria;
         {mods}
 
         /// All auto-generated items in this crate
@@ -114,37 +168,67 @@ pub fn generate(
 }
 
 /// Generate a single element.
-fn generate_element(el: MergedElement, global_attributes: &[Attribute]) -> Result<CodeFile> {
+fn generate_element(
+    el: MergedElement,
+    global_attributes: &[Attribute],
+    struct_tag_names: &HashMap<String, String>,
+) -> Result<CodeFile> {
+    let filename = format!("{}.rs", el.module_name());
     let MergedElement {
         tag_name,
         struct_name,
+        namespace,
         attributes,
         mdn_link,
         has_global_attributes,
         submodule_name,
         content_categories,
         permitted_child_elements,
-        has_closing_tag,
+        tag_omission,
         ..
     } = el;
 
-    let filename = format!("{}.rs", tag_name);
     let enum_name = format!("super::child::{struct_name}Child");
     let sys_name = format!("html_sys::{submodule_name}::{struct_name}");
 
-    let should_indent = match tag_name.as_str() {
-        "pre" => false,
-        _ => true,
+    // The contents of raw text elements are written without any character
+    // references, so they need their own escaping rules. This doesn't apply to
+    // `<script>` and `<style>` inside of SVG, which are regular elements.
+    let is_html = namespace == Namespace::Html;
+    let is_raw_text = is_html && matches!(tag_name.as_str(), "script" | "style");
+
+    // Whitespace is significant in these elements, so pretty-printing must
+    // leave their contents alone.
+    let is_preformatted = is_html && matches!(tag_name.as_str(), "pre" | "textarea") || is_raw_text;
+
+    // Phrasing content is laid out inline, except for metadata content such
+    // as `<script>` and `<link>`, which isn't rendered at all. Inside of SVG
+    // the elements which hold runs of text are laid out inline, and MathML
+    // formulas are always laid out inline.
+    let is_phrasing = content_categories.contains(&MergedCategory::Phrasing)
+        && !content_categories.contains(&MergedCategory::Metadata);
+    let is_inline = match namespace {
+        Namespace::Html => is_phrasing,
+        Namespace::Svg => is_phrasing || matches!(tag_name.as_str(), "a" | "textPath" | "tspan"),
+        Namespace::MathMl => true,
     };
 
-    let has_children = permitted_child_elements.len() != 0;
+    // Foreign elements without any content are written as a single
+    // self-closing tag.
+    let is_self_closing = !is_html;
+
+    let has_children = !permitted_child_elements.is_empty();
     let categories_impl = gen_categories_impl(&content_categories, &struct_name);
-    let html_element_impl =
-        gen_html_element_impl(&tag_name, &struct_name, has_global_attributes, has_children);
-    let children_enum = gen_enum(&struct_name, &permitted_child_elements, should_indent);
+    let html_element_impl = gen_html_element_impl(&tag_name, &struct_name, has_children);
+    let children_enum = gen_enum(
+        &tag_name,
+        &struct_name,
+        &permitted_child_elements,
+        is_raw_text,
+    );
     let child_methods = gen_child_methods(&struct_name, &enum_name, &permitted_child_elements);
     let data_map_methods = gen_data_map_methods(&struct_name);
-    let display_impl = gen_fmt_impl(&struct_name, has_children, has_closing_tag, should_indent);
+    let display_impl = gen_fmt_impl(&struct_name, has_children, is_self_closing);
 
     let method_attributes = match has_global_attributes {
         true => {
@@ -154,8 +238,50 @@ fn generate_element(el: MergedElement, global_attributes: &[Attribute]) -> Resul
         }
         false => attributes.clone(),
     };
-    let builder = gen_builder(&struct_name, &permitted_child_elements, &method_attributes);
+    let builder = gen_builder(
+        &struct_name,
+        &permitted_child_elements,
+        is_raw_text,
+        &method_attributes,
+    );
+    let (doctype_methods, doctype_builder_methods) = gen_doctype_methods(&tag_name, namespace);
     let getter_setter_methods = gen_methods(&struct_name, &method_attributes);
+    let from_html_impl = gen_from_html(&tag_name, &struct_name, &method_attributes, has_children);
+    let from_content_impl = gen_from_content(
+        &tag_name,
+        &struct_name,
+        &permitted_child_elements,
+        is_raw_text,
+        struct_tag_names,
+    );
+    let serde_element_impl =
+        gen_serde_element(&tag_name, &struct_name, &sys_name, &enum_name, has_children);
+    let tree_element_impl = gen_tree_element(
+        &tag_name,
+        &struct_name,
+        has_children,
+        is_inline,
+        is_preformatted,
+        is_self_closing,
+        &tag_omission,
+    );
+    let tree_child_impl = gen_tree_child(
+        &tag_name,
+        &struct_name,
+        &permitted_child_elements,
+        is_raw_text,
+    );
+    let try_from_any_impl = gen_try_from_any(&tag_name, &struct_name, &permitted_child_elements);
+    let visit_element_impl =
+        gen_visit_element(&struct_name, &enum_name, &method_attributes, has_children);
+    let visit_child_impl = gen_visit_child(&struct_name, &permitted_child_elements, is_raw_text);
+    let serde_child_impl = gen_serde_child(
+        &tag_name,
+        &struct_name,
+        &permitted_child_elements,
+        is_raw_text,
+        struct_tag_names,
+    );
 
     let children = match has_children {
         true => format!("children: Vec<{enum_name}>"),
@@ -167,8 +293,9 @@ fn generate_element(el: MergedElement, global_attributes: &[Attribute]) -> Resul
         false => String::new(),
     };
 
+    let language = namespace.language();
     let element = formatdoc!(
-        r#"/// The HTML `<{tag_name}>` element
+        r#"/// The {language} `<{tag_name}>` element
         ///
         /// [MDN Documentation]({mdn_link})
         #[doc(alias = "{tag_name}")]
@@ -193,16 +320,21 @@ fn generate_element(el: MergedElement, global_attributes: &[Attribute]) -> Resul
         }}
 
         {data_map_methods}
+        {doctype_methods}
         {getter_setter_methods}
         {child_methods}
 
         {display_impl}
         {html_element_impl}
         {categories_impl}
-
-        impl std::convert::Into<{sys_name}> for {struct_name} {{
-            fn into(self) -> {sys_name} {{
-                self.sys
+        {from_html_impl}
+        {tree_element_impl}
+        {visit_element_impl}
+        {serde_element_impl}
+
+        impl From<{struct_name}> for {sys_name} {{
+            fn from(element: {struct_name}) -> Self {{
+                element.sys
             }}
         }}
 
@@ -225,10 +357,16 @@ fn generate_element(el: MergedElement, global_attributes: &[Attribute]) -> Resul
 
         pub mod child {{
             {children_enum}
+            {from_content_impl}
+            {tree_child_impl}
+            {visit_child_impl}
+            {try_from_any_impl}
+            {serde_child_impl}
         }}
 
         pub mod builder {{
             {builder}
+            {doctype_builder_methods}
         }}
     "
     );
@@ -240,82 +378,43 @@ fn generate_element(el: MergedElement, global_attributes: &[Attribute]) -> Resul
     })
 }
 
-fn gen_fmt_impl(
-    struct_name: &str,
-    has_children: bool,
-    has_closing_tag: bool,
-    should_indent: bool,
-) -> String {
-    let write_debug_children = if has_children && should_indent {
-        format!(
-            r#"
-            if !self.children.is_empty() {{
-                write!(f, "\n")?;
-            }}
-            for el in &self.children {{
-                crate::Render::render(&el, f, depth)?;
-                write!(f, "\n")?;
-            }}"#
-        )
-    } else if has_children && !should_indent {
-        format!(
-            r#"
-            for el in &self.children {{
-                crate::Render::render(&el, f, 0)?;
-            }}"#
-        )
-    } else {
-        String::new()
-    };
-
+fn gen_fmt_impl(struct_name: &str, has_children: bool, is_self_closing: bool) -> String {
     let write_display_children = if has_children {
-        format!(
-            r#"
-            for el in &self.children {{
-                write!(f, "{{el}}")?;
-            }}"#
-        )
-    } else {
-        String::new()
-    };
-
-    let write_closing_tag = if has_closing_tag && should_indent {
-        r#"
-            write!(f, "{:level$}", "", level = depth * 4)?;
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            "#
-    } else if has_closing_tag && !should_indent {
         r#"
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            "#
+            for el in &self.children {
+                write!(f, "{el}")?;
+            }"#
     } else {
         ""
     };
-    format!(
-        r#"
-        impl crate::Render for {struct_name} {{
-            fn render(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {{
-                write!(f, "{{:level$}}", "", level = depth * 4)?;
-                html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-                {write_debug_children}
-                {write_closing_tag}
-                Ok(())
+    let write_element = format!(
+        "html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
+        {write_display_children}
+        html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
+        Ok(())"
+    );
+    let write_display = match (is_self_closing, has_children) {
+        (true, true) => format!(
+            "if self.children.is_empty() {{
+                return html_sys::RenderElement::write_empty_tag(&self.sys, f);
             }}
-        }}
+            {write_element}"
+        ),
+        (true, false) => "html_sys::RenderElement::write_empty_tag(&self.sys, f)".to_owned(),
+        (false, _) => write_element,
+    };
 
+    format!(
+        r#"
         impl std::fmt::Debug for {struct_name} {{
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
-                crate::Render::render(self, f, 0)?;
-                Ok(())
+                write!(f, "{{}}", crate::render::Pretty::from(self))
             }}
         }}
 
         impl std::fmt::Display for {struct_name} {{
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
-                html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-                {write_display_children}
-                html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-                Ok(())
+                {write_display}
             }}
         }}
     "#
@@ -327,7 +426,7 @@ fn gen_child_methods(
     enum_name: &str,
     permitted_child_elements: &[String],
 ) -> String {
-    if permitted_child_elements.len() == 0 {
+    if permitted_child_elements.is_empty() {
         return String::new();
     }
 
@@ -362,8 +461,40 @@ fn gen_data_map_methods(struct_name: &str) -> String {
     )
 }
 
-fn gen_enum(struct_name: &str, permitted_child_elements: &[String], should_indent: bool) -> String {
-    if permitted_child_elements.len() == 0 {
+/// The doctype is written as part of the `<html>` element, so it's set through
+/// the element and its builder.
+fn gen_doctype_methods(tag_name: &str, namespace: Namespace) -> (String, String) {
+    if namespace != Namespace::Html || tag_name != "html" {
+        return (String::new(), String::new());
+    }
+    let methods = "impl Html {
+            /// Get the doctype which is written before the element
+            pub fn doctype(&self) -> &html_sys::Doctype {
+                &self.sys.doctype
+            }
+
+            /// Set the doctype which is written before the element
+            pub fn set_doctype(&mut self, value: html_sys::Doctype) {
+                self.sys.doctype = value;
+            }
+        }";
+    let builder_methods = "impl HtmlBuilder {
+            /// Set the doctype which is written before the element
+            pub fn doctype(&mut self, value: html_sys::Doctype) -> &mut Self {
+                self.element.set_doctype(value);
+                self
+            }
+        }";
+    (methods.to_owned(), builder_methods.to_owned())
+}
+
+fn gen_enum(
+    tag_name: &str,
+    struct_name: &str,
+    permitted_child_elements: &[String],
+    is_raw_text: bool,
+) -> String {
+    if permitted_child_elements.is_empty() {
         return String::new();
     }
 
@@ -378,7 +509,7 @@ fn gen_enum(struct_name: &str, permitted_child_elements: &[String], should_inden
         }
     }
 
-    let members = permitted_child_elements
+    let mut members = permitted_child_elements
         .iter()
         .map(|el| {
             let ty = gen_ty_path(el);
@@ -388,8 +519,20 @@ fn gen_enum(struct_name: &str, permitted_child_elements: &[String], should_inden
             )
         })
         .collect::<String>();
+    // The contents of raw text elements are never parsed as markup, so they
+    // can't hold comments.
+    if !is_raw_text {
+        members.push_str(
+            "/// A comment
+            Comment(crate::Comment),",
+        );
+    }
+    members.push_str(
+        "/// Trusted HTML, which is written without being escaped
+        RawHtml(crate::RawHtml),",
+    );
 
-    let from = permitted_child_elements
+    let mut from = permitted_child_elements
         .iter()
         .map(|el| {
             let ty = gen_ty_path(el);
@@ -425,47 +568,65 @@ fn gen_enum(struct_name: &str, permitted_child_elements: &[String], should_inden
             }
         })
         .collect::<String>();
+    if !is_raw_text {
+        from.push_str(&format!(
+            "
+            impl std::convert::From<crate::Comment> for {struct_name}Child {{
+                fn from(value: crate::Comment) -> Self {{
+                    Self::Comment(value)
+                }}
+            }}
+        "
+        ));
+    }
+    from.push_str(&format!(
+        "
+        impl std::convert::From<crate::RawHtml> for {struct_name}Child {{
+            fn from(value: crate::RawHtml) -> Self {{
+                Self::RawHtml(value)
+            }}
+        }}
+    "
+    ));
 
-    let increase_depth = match should_indent {
-        true => "+ 1",
-        false => "",
-    };
-    let debug_patterns = permitted_child_elements
+    let mut display_patterns = permitted_child_elements
         .iter()
-        .map(|el| {
-            format!(r#"Self::{el}(el) => crate::Render::render(el, f, depth {increase_depth}),"#)
+        .map(|el| match el.as_str() {
+            "Text" if is_raw_text => format!(
+                r#"Self::Text(el) => write!(f, "{{}}", html_sys::escape::RawText::new("{tag_name}", el)),"#
+            ),
+            "Text" => r#"Self::Text(el) => write!(f, "{}", html_sys::escape::Text(el)),"#.to_owned(),
+            _ => format!(r#"Self::{el}(el) => write!(f, "{{el}}"),"#),
         })
         .collect::<String>();
-    let display_patterns = permitted_child_elements
-        .iter()
-        .map(|el| format!(r#"Self::{el}(el) => write!(f, "{{el}}"),"#))
-        .collect::<String>();
-    let node_from_patterns = permitted_child_elements
+    let mut node_from_patterns = permitted_child_elements
         .iter()
         .map(|el| format!(r#"{struct_name}Child::{el}(el) => crate::Node::from(el),"#))
         .collect::<String>();
+    if !is_raw_text {
+        display_patterns.push_str(r#"Self::Comment(el) => write!(f, "{el}"),"#);
+        node_from_patterns.push_str(&format!(
+            r#"{struct_name}Child::Comment(el) => crate::Node::from(el),"#
+        ));
+    }
+    display_patterns.push_str(r#"Self::RawHtml(el) => write!(f, "{el}"),"#);
+    node_from_patterns.push_str(&format!(
+        r#"{struct_name}Child::RawHtml(el) => crate::Node::from(el),"#
+    ));
 
     format!(
         r#"
         /// The permitted child items for the `{struct_name}` element
         #[derive(PartialEq, Clone)]
+        #[allow(clippy::large_enum_variant)]
         pub enum {struct_name}Child {{
             {members}
         }}
         {from}
 
-        impl crate::Render for {struct_name}Child {{
-            fn render(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {{
-                match self {{
-                    {debug_patterns}
-                }}
-            }}
-        }}
-
         impl std::fmt::Debug for {struct_name}Child {{
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
-                crate::Render::render(self, f, 0)?;
-                Ok(())
+                write!(f, "{{}}", crate::render::Pretty::from(self))
             }}
         }}
 
@@ -488,50 +649,41 @@ fn gen_enum(struct_name: &str, permitted_child_elements: &[String], should_inden
     )
 }
 
-fn gen_html_element_impl(
-    tag_name: &str,
-    struct_name: &str,
-    has_global_attributes: bool,
-    has_children: bool,
-) -> String {
-    if has_global_attributes {
-        let children = if has_children {
-            "
-            self.children
-                .iter()
-                .map(From::from)
-                .collect()
-            "
-        } else {
-            "vec![]"
-        };
+fn gen_html_element_impl(tag_name: &str, struct_name: &str, has_children: bool) -> String {
+    let children = if has_children {
+        "
+        self.children
+            .iter()
+            .map(From::from)
+            .collect()
+        "
+    } else {
+        "vec![]"
+    };
 
-        format!(
-            r##"
-            impl crate::HtmlElement for {struct_name} {{
-                fn tag_name(&self) -> &'static str {{
-                    "{tag_name}"
-                }}
+    format!(
+        r##"
+        impl crate::HtmlElement for {struct_name} {{
+            fn tag_name(&self) -> &'static str {{
+                "{tag_name}"
+            }}
 
-                fn attributes(&self) -> std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>> {{
-                    use html_sys::ElementDescription;
-                    self.sys.attributes()
-                }}
+            fn attributes(&self) -> html_sys::AttributeMap {{
+                use html_sys::ElementDescription;
+                self.sys.attributes()
+            }}
 
-                fn data(&self) -> std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>> {{
-                    use html_sys::ElementDescription;
-                    self.sys.data()
-                }}
+            fn data(&self) -> html_sys::AttributeMap {{
+                use html_sys::ElementDescription;
+                self.sys.data()
+            }}
 
-                fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {{
-                    {children}
-                }}
+            fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {{
+                {children}
             }}
-        "##
-        )
-    } else {
-        String::new()
-    }
+        }}
+    "##
+    )
 }
 
 fn gen_categories_impl(categories: &[MergedCategory], struct_name: &str) -> String {
@@ -592,6 +744,15 @@ fn generate_category(cat: &MergedCategory, output: &mut String, struct_name: &st
     }
 }
 
+/// The type of the items of a list attribute.
+pub(crate) fn list_item_type(ty: &AttributeType) -> String {
+    match ty {
+        AttributeType::String => "std::borrow::Cow<'static, str>".to_owned(),
+        AttributeType::Enumerable(_) => format!("html_sys::attributes::{ty}"),
+        ty => format!("{ty}"),
+    }
+}
+
 fn gen_methods(struct_name: &str, attributes: &[Attribute]) -> String {
     fn gen_method(attr: &Attribute) -> String {
         let name = &attr.name;
@@ -599,6 +760,10 @@ fn gen_methods(struct_name: &str, attributes: &[Attribute]) -> String {
         let return_ty = match &attr.ty {
             AttributeType::Bool => "bool".to_owned(),
             AttributeType::String => "std::option::Option<&str>".to_owned(),
+            AttributeType::Enumerable(_) => {
+                format!("std::option::Option<&html_sys::attributes::{}>", attr.ty)
+            }
+            AttributeType::List(item_ty) => format!("&[{}]", list_item_type(item_ty)),
             ty => format!("std::option::Option<{ty}>"),
         };
 
@@ -607,21 +772,32 @@ fn gen_methods(struct_name: &str, attributes: &[Attribute]) -> String {
             AttributeType::String => {
                 "std::option::Option<impl Into<std::borrow::Cow<'static, str>>>".to_owned()
             }
+            AttributeType::Enumerable(_) => {
+                format!("std::option::Option<html_sys::attributes::{}>", attr.ty)
+            }
+            AttributeType::List(item_ty) => format!("std::vec::Vec<{}>", list_item_type(item_ty)),
             ty => format!("std::option::Option<{ty}>"),
         };
 
         let field_access = match &attr.ty {
-            AttributeType::Integer | AttributeType::Float | AttributeType::Bool => {
+            AttributeType::Integer
+            | AttributeType::Float
+            | AttributeType::Bool
+            | AttributeType::OptionalBool => {
                 format!("self.sys.{field_name}")
             }
+            AttributeType::List(_) => format!("&self.sys.{field_name}"),
             AttributeType::String => {
                 format!("self.sys.{field_name}.as_deref()")
             }
+            AttributeType::Enumerable(_) => {
+                format!("self.sys.{field_name}.as_ref()")
+            }
             _ => todo!("unhandled type"),
         };
         let field_setter = match &attr.ty {
-            AttributeType::String => format!("value.map(|v| v.into())"),
-            _ => format!("value"),
+            AttributeType::String => "value.map(|v| v.into())".to_string(),
+            _ => "value".to_string(),
         };
         format!(
             "
@@ -635,7 +811,7 @@ fn gen_methods(struct_name: &str, attributes: &[Attribute]) -> String {
             }}",
         )
     }
-    let methods: String = attributes.into_iter().map(gen_method).collect();
+    let methods: String = attributes.iter().map(gen_method).collect();
 
     match methods.len() {
         0 => String::new(),
@@ -0,0 +1,121 @@
+use crate::parse::{Attribute, AttributeType};
+use std::collections::{HashMap, HashSet};
+
+/// Generate the conversion from a parsed element into the typed element.
+pub(crate) fn gen_from_html(
+    tag_name: &str,
+    struct_name: &str,
+    method_attributes: &[Attribute],
+    has_children: bool,
+) -> String {
+    let mut seen = HashSet::new();
+    let attribute_patterns = method_attributes
+        .iter()
+        .filter(|attr| seen.insert(attr.name.as_str()))
+        .map(|attr| {
+            let name = &attr.name;
+            let field_name = &attr.field_name;
+            let value = match &attr.ty {
+                // ARIA states are written as `"true"` or `"false"`, whereas
+                // HTML boolean attributes are enabled by being present.
+                AttributeType::Bool if name.starts_with("aria-") => {
+                    r#"value != "false""#.to_owned()
+                }
+                AttributeType::Bool => "true".to_owned(),
+                AttributeType::String => "Some(value.to_owned())".to_owned(),
+                AttributeType::List(item_ty) if **item_ty == AttributeType::String => {
+                    "value.split_ascii_whitespace().map(|value| value.to_owned().into()).collect()"
+                        .to_owned()
+                }
+                AttributeType::List(_) => "value
+                    .split_ascii_whitespace()
+                    .filter_map(|value| cx.parse_attribute(name, value))
+                    .collect()"
+                    .to_owned(),
+                _ => "cx.parse_attribute(name, value)".to_owned(),
+            };
+            format!(r#""{name}" => this.set_{field_name}({value}),"#)
+        })
+        .collect::<String>();
+
+    let children = match has_children {
+        true => "this.children = cx.children(element);",
+        false => "cx.no_children(element);",
+    };
+
+    format!(
+        r#"
+        #[cfg(feature = "parse")]
+        impl crate::parse::FromHtml for {struct_name} {{
+            const TAG_NAME: &'static str = "{tag_name}";
+
+            fn from_html(element: crate::parse::Element<'_>, cx: &mut crate::parse::Context) -> Self {{
+                let mut this: Self = Default::default();
+                for (name, value) in element.attributes() {{
+                    match name {{
+                        {attribute_patterns}
+                        _ => cx.other_attribute(this.data_map_mut(), name, value),
+                    }}
+                }}
+                {children}
+                this
+            }}
+        }}
+        "#
+    )
+}
+
+/// Generate the conversion from parsed content into the child enum.
+pub(crate) fn gen_from_content(
+    tag_name: &str,
+    struct_name: &str,
+    permitted_child_elements: &[String],
+    is_raw_text: bool,
+    tag_names: &HashMap<String, String>,
+) -> String {
+    if permitted_child_elements.is_empty() {
+        return String::new();
+    }
+
+    let text_pattern = match permitted_child_elements.iter().any(|el| el == "Text") {
+        true => "crate::parse::Content::Text(text) => Some(Self::Text(text.to_owned().into())),",
+        false => "crate::parse::Content::Text(_) => cx.not_permitted(content),",
+    };
+    let comment_pattern = match is_raw_text {
+        true => "crate::parse::Content::Comment(_) => cx.not_permitted(content),",
+        false => "crate::parse::Content::Comment(comment) => Some(Self::Comment(crate::Comment::new(comment.to_owned()))),",
+    };
+
+    let element_patterns = permitted_child_elements
+        .iter()
+        .filter_map(|el| tag_names.get(el).map(|tag_name| (el, tag_name)))
+        .map(|(el, tag_name)| format!(r#""{tag_name}" => Some(Self::{el}(cx.element(el))),"#))
+        .collect::<String>();
+
+    let element_pattern = match element_patterns.is_empty() {
+        true => "crate::parse::Content::Element(_) => cx.not_permitted(content),".to_owned(),
+        false => format!(
+            "crate::parse::Content::Element(el) => match el.tag_name() {{
+                {element_patterns}
+                _ => cx.not_permitted(content),
+            }},"
+        ),
+    };
+
+    format!(
+        r#"
+        #[cfg(feature = "parse")]
+        impl crate::parse::FromContent for {struct_name}Child {{
+            const PARENT_TAG_NAME: &'static str = "{tag_name}";
+
+            fn from_content(content: crate::parse::Content<'_>, cx: &mut crate::parse::Context) -> Option<Self> {{
+                match content {{
+                    {text_pattern}
+                    {comment_pattern}
+                    {element_pattern}
+                }}
+            }}
+        }}
+        "#
+    )
+}
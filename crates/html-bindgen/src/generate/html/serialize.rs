@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+/// Generate the serde implementations for an element.
+pub(crate) fn gen_serde_element(
+    tag_name: &str,
+    struct_name: &str,
+    sys_name: &str,
+    enum_name: &str,
+    has_children: bool,
+) -> String {
+    let (child_ty, children, from_parts) = match has_children {
+        true => (enum_name, "&self.children", "Self { sys, children }"),
+        false => ("crate::serialize::Never", "&[]", "Self { sys }"),
+    };
+    let children_param = match has_children {
+        true => "children",
+        false => "_children",
+    };
+
+    format!(
+        r#"
+        #[cfg(feature = "serde")]
+        impl crate::serialize::SerdeElement for {struct_name} {{
+            const TAG_NAME: &'static str = "{tag_name}";
+            type Attributes = {sys_name};
+            type Child = {child_ty};
+
+            fn attributes(&self) -> &Self::Attributes {{
+                &self.sys
+            }}
+
+            fn children(&self) -> &[Self::Child] {{
+                {children}
+            }}
+
+            fn from_parts(sys: Self::Attributes, {children_param}: Vec<Self::Child>) -> Self {{
+                {from_parts}
+            }}
+        }}
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for {struct_name} {{
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                crate::serialize::serialize_element(self, serializer)
+            }}
+        }}
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for {struct_name} {{
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                crate::serialize::deserialize_element(deserializer)
+            }}
+        }}
+        "#
+    )
+}
+
+/// Generate the serde implementations for the child enum of an element.
+pub(crate) fn gen_serde_child(
+    tag_name: &str,
+    struct_name: &str,
+    permitted_child_elements: &[String],
+    is_raw_text: bool,
+    tag_names: &HashMap<String, String>,
+) -> String {
+    if permitted_child_elements.is_empty() {
+        return String::new();
+    }
+
+    let has_text = permitted_child_elements.iter().any(|el| el == "Text");
+
+    let mut serialize_patterns = permitted_child_elements
+        .iter()
+        .map(|el| format!("Self::{el}(el) => serde::Serialize::serialize(el, serializer),"))
+        .collect::<String>();
+    if !is_raw_text {
+        serialize_patterns
+            .push_str("Self::Comment(el) => serde::Serialize::serialize(el, serializer),");
+    }
+    serialize_patterns
+        .push_str("Self::RawHtml(el) => serde::Serialize::serialize(el, serializer),");
+
+    let (text_param, from_text) = match has_text {
+        true => ("text", "Some(Self::Text(text.into()))"),
+        false => ("_text", "None"),
+    };
+    let (comment_param, from_comment) = match is_raw_text {
+        true => ("_comment", "None"),
+        false => ("comment", "Some(Self::Comment(comment))"),
+    };
+
+    let element_patterns = permitted_child_elements
+        .iter()
+        .filter_map(|el| tag_names.get(el).map(|tag_name| (el, tag_name)))
+        .map(|(el, tag_name)| {
+            format!(r#""{tag_name}" => crate::serialize::deserialize_element_body(map).map(Self::{el}),"#)
+        })
+        .collect::<String>();
+    let not_permitted = "Err(crate::serialize::child_not_permitted(Self::PARENT_TAG_NAME, tag))";
+    let (map_param, deserialize_element) = match element_patterns.is_empty() {
+        true => ("_map", not_permitted.to_owned()),
+        false => (
+            "map",
+            format!(
+                "match tag {{
+                    {element_patterns}
+                    tag => {not_permitted},
+                }}"
+            ),
+        ),
+    };
+
+    format!(
+        r#"
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for {struct_name}Child {{
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                match self {{
+                    {serialize_patterns}
+                }}
+            }}
+        }}
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for {struct_name}Child {{
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                crate::serialize::deserialize_child(deserializer)
+            }}
+        }}
+
+        #[cfg(feature = "serde")]
+        impl crate::serialize::SerdeChild for {struct_name}Child {{
+            const PARENT_TAG_NAME: &'static str = "{tag_name}";
+
+            fn from_text({text_param}: String) -> Option<Self> {{
+                {from_text}
+            }}
+
+            fn from_comment({comment_param}: crate::Comment) -> Option<Self> {{
+                {from_comment}
+            }}
+
+            fn from_raw_html(html: crate::RawHtml) -> Self {{
+                Self::RawHtml(html)
+            }}
+
+            fn deserialize_element<'de, M: serde::de::MapAccess<'de>>(tag: &str, {map_param}: M) -> Result<Self, M::Error> {{
+                {deserialize_element}
+            }}
+        }}
+        "#
+    )
+}
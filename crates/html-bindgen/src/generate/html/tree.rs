@@ -0,0 +1,228 @@
+use crate::parse::{EndTagOmission, FirstChild, TagOmission};
+
+/// Generate the type-erased tree implementations for an element, which are
+/// used for streaming, pretty-printing, and minifying.
+pub(crate) fn gen_tree_element(
+    tag_name: &str,
+    struct_name: &str,
+    has_children: bool,
+    is_inline: bool,
+    is_preformatted: bool,
+    is_self_closing: bool,
+    tag_omission: &TagOmission,
+) -> String {
+    let child = match has_children {
+        true => {
+            "fn child(&self, index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+                self.children.get(index).map(From::from)
+            }"
+        }
+        false => {
+            "fn child(&self, _index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+                None
+            }"
+        }
+    };
+
+    let tag_omission = gen_tag_omission(tag_omission);
+
+    // Self-closing elements are written as a single tag while they're empty,
+    // in which case there's no closing tag.
+    let tags = match is_self_closing {
+        true => {
+            let is_empty = match has_children {
+                true => "self.children.is_empty()",
+                false => "true",
+            };
+            format!(
+                "fn is_self_closing(&self) -> bool {{
+                    {is_empty}
+                }}
+
+                fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {{
+                    match self.is_self_closing() {{
+                        true => html_sys::RenderElement::write_empty_tag(&self.sys, writer),
+                        false => html_sys::RenderElement::write_opening_tag(&self.sys, writer),
+                    }}
+                }}
+
+                fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {{
+                    match self.is_self_closing() {{
+                        true => Ok(()),
+                        false => html_sys::RenderElement::write_closing_tag(&self.sys, writer),
+                    }}
+                }}"
+            )
+        }
+        false => "fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {
+                html_sys::RenderElement::write_opening_tag(&self.sys, writer)
+            }
+
+            fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {
+                html_sys::RenderElement::write_closing_tag(&self.sys, writer)
+            }"
+        .to_owned(),
+    };
+
+    format!(
+        r#"
+        impl crate::tree::Element for {struct_name} {{
+            fn tag_name(&self) -> &'static str {{
+                "{tag_name}"
+            }}
+
+            fn is_inline(&self) -> bool {{
+                {is_inline}
+            }}
+
+            fn is_preformatted(&self) -> bool {{
+                {is_preformatted}
+            }}
+
+            {tags}
+
+            {child}
+            {tag_omission}
+        }}
+
+        impl<'a> From<&'a {struct_name}> for crate::tree::Part<'a> {{
+            fn from(element: &'a {struct_name}) -> Self {{
+                crate::tree::Part::Element(element)
+            }}
+        }}
+
+        impl<'a> From<&'a {struct_name}> for crate::stream::Chunks<'a> {{
+            fn from(element: &'a {struct_name}) -> Self {{
+                crate::stream::Chunks::new(element)
+            }}
+        }}
+
+        impl<'a> From<&'a {struct_name}> for crate::render::Pretty<'a> {{
+            fn from(element: &'a {struct_name}) -> Self {{
+                crate::render::Pretty::new(element.into())
+            }}
+        }}
+
+        impl<'a> From<&'a {struct_name}> for crate::minify::Minified<'a> {{
+            fn from(element: &'a {struct_name}) -> Self {{
+                crate::minify::Minified::new(element.into())
+            }}
+        }}
+        "#
+    )
+}
+
+/// Generate the type-erased tree implementations for the child enum of an
+/// element.
+pub(crate) fn gen_tree_child(
+    tag_name: &str,
+    struct_name: &str,
+    permitted_child_elements: &[String],
+    is_raw_text: bool,
+) -> String {
+    if permitted_child_elements.is_empty() {
+        return String::new();
+    }
+
+    let mut patterns = permitted_child_elements
+        .iter()
+        .map(|el| match el.as_str() {
+            "Text" if is_raw_text => format!(
+                r#"{struct_name}Child::Text(el) => crate::tree::Part::RawText(html_sys::escape::RawText::new("{tag_name}", el)),"#
+            ),
+            "Text" => format!(
+                "{struct_name}Child::Text(el) => crate::tree::Part::Text(html_sys::escape::Text(el)),"
+            ),
+            _ => format!("{struct_name}Child::{el}(el) => crate::tree::Part::Element(el),"),
+        })
+        .collect::<String>();
+    if !is_raw_text {
+        patterns.push_str(&format!(
+            "{struct_name}Child::Comment(el) => crate::tree::Part::Comment(html_sys::escape::Comment(el.as_str())),"
+        ));
+    }
+    patterns.push_str(&format!(
+        "{struct_name}Child::RawHtml(el) => crate::tree::Part::RawHtml(el.as_str()),"
+    ));
+
+    format!(
+        r#"
+        impl<'a> From<&'a {struct_name}Child> for crate::tree::Part<'a> {{
+            fn from(child: &'a {struct_name}Child) -> Self {{
+                match child {{
+                    {patterns}
+                }}
+            }}
+        }}
+
+        impl<'a> From<&'a {struct_name}Child> for crate::render::Pretty<'a> {{
+            fn from(child: &'a {struct_name}Child) -> Self {{
+                crate::render::Pretty::new(child.into())
+            }}
+        }}
+
+        impl<'a> From<&'a {struct_name}Child> for crate::minify::Minified<'a> {{
+            fn from(child: &'a {struct_name}Child) -> Self {{
+                crate::minify::Minified::new(child.into())
+            }}
+        }}
+        "#
+    )
+}
+
+/// Generate the methods which describe when the tags of an element can be
+/// omitted. Most elements can't omit either tag, which is what the default
+/// implementations return.
+fn gen_tag_omission(tag_omission: &TagOmission) -> String {
+    let mut output = String::new();
+    if let Some(start) = &tag_omission.start {
+        let first_child = match &start.first_child {
+            None => "None".to_owned(),
+            Some(FirstChild::Element) => "Some(crate::minify::FirstChild::Element)".to_owned(),
+            Some(FirstChild::OneOf(elements)) => format!(
+                "Some(crate::minify::FirstChild::OneOf(&{elements:?}))"
+            ),
+            Some(FirstChild::NoneOf {
+                whitespace,
+                elements,
+            }) => format!(
+                "Some(crate::minify::FirstChild::NoneOf {{ whitespace: {whitespace}, elements: &{elements:?} }})"
+            ),
+        };
+        let if_empty = start.if_empty;
+        let not_after_omitted = &start.not_after_omitted;
+        output.push_str(&format!(
+            "fn start_tag_omission(&self) -> std::option::Option<crate::minify::StartTagOmission> {{
+                Some(crate::minify::StartTagOmission {{
+                    if_empty: {if_empty},
+                    first_child: {first_child},
+                    not_after_omitted: &{not_after_omitted:?},
+                }})
+            }}"
+        ));
+    }
+    if let Some(end) = &tag_omission.end {
+        let end = match end {
+            EndTagOmission::UnlessFollowedBy { whitespace } => format!(
+                "crate::minify::EndTagOmission::UnlessFollowedBy {{ whitespace: {whitespace} }}"
+            ),
+            EndTagOmission::Conditional {
+                followed_by,
+                last_child,
+                parent_not,
+            } => format!(
+                "crate::minify::EndTagOmission::Conditional {{
+                    followed_by: &{followed_by:?},
+                    last_child: {last_child},
+                    parent_not: &{parent_not:?},
+                }}"
+            ),
+        };
+        output.push_str(&format!(
+            "fn end_tag_omission(&self) -> std::option::Option<crate::minify::EndTagOmission> {{
+                Some({end})
+            }}"
+        ));
+    }
+    output
+}
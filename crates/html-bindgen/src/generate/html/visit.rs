@@ -0,0 +1,228 @@
+use crate::parse::{Attribute, AttributeType};
+use std::collections::HashSet;
+
+/// Generate the `Visit` implementation for an element, which gives
+/// type-erased access to its children and attributes.
+pub(crate) fn gen_visit_element(
+    struct_name: &str,
+    enum_name: &str,
+    method_attributes: &[Attribute],
+    has_children: bool,
+) -> String {
+    let children = match has_children {
+        true => format!(
+            "fn child(&self, index: usize) -> std::option::Option<crate::visit::NodeRef<'_>> {{
+                self.children.get(index).map(From::from)
+            }}
+
+            fn child_mut(&mut self, index: usize) -> std::option::Option<crate::visit::NodeMut<'_>> {{
+                self.children.get_mut(index).map(From::from)
+            }}
+
+            fn retain_children(&mut self, f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool) {{
+                self.children.retain(|child| f(child.into()));
+            }}
+
+            fn insert_child(&mut self, index: usize, node: crate::visit::NodeRef<'_>) -> bool {{
+                match {enum_name}::from_node(node) {{
+                    Some(child) if index <= self.children.len() => {{
+                        self.children.insert(index, child);
+                        true
+                    }}
+                    _ => false,
+                }}
+            }}
+
+            fn remove_child(&mut self, index: usize) -> bool {{
+                match index < self.children.len() {{
+                    true => {{
+                        self.children.remove(index);
+                        true
+                    }}
+                    false => false,
+                }}
+            }}"
+        ),
+        false => "fn child(&self, _index: usize) -> std::option::Option<crate::visit::NodeRef<'_>> {
+                None
+            }
+
+            fn child_mut(&mut self, _index: usize) -> std::option::Option<crate::visit::NodeMut<'_>> {
+                None
+            }
+
+            fn retain_children(&mut self, _f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool) {}
+
+            fn insert_child(&mut self, _index: usize, _node: crate::visit::NodeRef<'_>) -> bool {
+                false
+            }
+
+            fn remove_child(&mut self, _index: usize) -> bool {
+                false
+            }"
+        .to_owned(),
+    };
+
+    let mut seen = HashSet::new();
+    let attribute_patterns = method_attributes
+        .iter()
+        .filter(|attr| seen.insert(attr.name.as_str()))
+        .map(|attr| {
+            let name = &attr.name;
+            let field_name = &attr.field_name;
+            let value = match &attr.ty {
+                AttributeType::Bool if name.starts_with("aria-") => {
+                    r#"value.is_some_and(|value| value != "false")"#
+                }
+                AttributeType::Bool => "value.is_some()",
+                AttributeType::String => "value.map(str::to_owned)",
+                AttributeType::List(item_ty) if **item_ty == AttributeType::String => {
+                    "value
+                        .map(|value| value.split_ascii_whitespace().map(|value| value.to_owned().into()).collect())
+                        .unwrap_or_default()"
+                }
+                AttributeType::List(_) => {
+                    "value
+                        .and_then(|value| value.split_ascii_whitespace().map(str::parse).collect::<Result<_, _>>().ok())
+                        .unwrap_or_default()"
+                }
+                _ => "value.and_then(|value| value.parse().ok())",
+            };
+            format!(r#""{name}" => self.set_{field_name}({value}),"#)
+        })
+        .collect::<String>();
+
+    format!(
+        r#"
+        impl crate::visit::Visit for {struct_name} {{
+            fn as_any(&self) -> &dyn std::any::Any {{
+                self
+            }}
+
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any {{
+                self
+            }}
+
+            fn eq_element(&self, other: &dyn crate::visit::Visit) -> bool {{
+                other.downcast_ref::<Self>() == Some(self)
+            }}
+
+            {children}
+
+            fn set_attribute(&mut self, name: &str, value: std::option::Option<&str>) -> bool {{
+                match name {{
+                    {attribute_patterns}
+                    _ => match (name.strip_prefix("data-"), value) {{
+                        (Some(key), Some(value)) => {{
+                            self.data_map_mut().insert(key.to_owned().into(), value.to_owned().into());
+                        }}
+                        (Some(key), None) => {{
+                            self.data_map_mut().remove(key);
+                        }}
+                        (None, _) => return false,
+                    }},
+                }}
+                true
+            }}
+        }}
+        "#
+    )
+}
+
+/// Generate the conversions from the child enum of an element into the nodes
+/// which are handed to visitors.
+pub(crate) fn gen_visit_child(
+    struct_name: &str,
+    permitted_child_elements: &[String],
+    is_raw_text: bool,
+) -> String {
+    if permitted_child_elements.is_empty() {
+        return String::new();
+    }
+
+    let mut variants = permitted_child_elements
+        .iter()
+        .map(|el| match el.as_str() {
+            "Text" => ("Text", "Text"),
+            el => (el, "Element"),
+        })
+        .collect::<Vec<_>>();
+    if !is_raw_text {
+        variants.push(("Comment", "Comment"));
+    }
+    variants.push(("RawHtml", "RawHtml"));
+
+    let ref_patterns = variants
+        .iter()
+        .map(|(el, node)| {
+            format!("{struct_name}Child::{el}(el) => crate::visit::NodeRef::{node}(el),")
+        })
+        .collect::<String>();
+    let mut_patterns = variants
+        .iter()
+        .map(|(el, node)| {
+            format!("{struct_name}Child::{el}(el) => crate::visit::NodeMut::{node}(el),")
+        })
+        .collect::<String>();
+
+    let element_patterns = permitted_child_elements
+        .iter()
+        .filter(|el| *el != "Text")
+        .map(|el| {
+            format!(
+                "if let Some(el) = el.downcast_ref::<crate::generated::all::{el}>() {{
+                    return Some(Self::{el}(el.clone()));
+                }}"
+            )
+        })
+        .collect::<String>();
+    let element_pattern = match element_patterns.is_empty() {
+        true => "crate::visit::NodeRef::Element(_) => None,".to_owned(),
+        false => format!(
+            "crate::visit::NodeRef::Element(el) => {{
+                {element_patterns}
+                None
+            }}"
+        ),
+    };
+    let text_pattern = match permitted_child_elements.iter().any(|el| el == "Text") {
+        true => "crate::visit::NodeRef::Text(text) => Some(Self::Text(text.to_owned().into())),",
+        false => "crate::visit::NodeRef::Text(_) => None,",
+    };
+    let comment_pattern = match is_raw_text {
+        true => "crate::visit::NodeRef::Comment(_) => None,",
+        false => "crate::visit::NodeRef::Comment(comment) => Some(Self::Comment(comment.clone())),",
+    };
+
+    format!(
+        r#"
+        impl {struct_name}Child {{
+            /// Clone a node into a child, if it's permitted.
+            pub(crate) fn from_node(node: crate::visit::NodeRef<'_>) -> std::option::Option<Self> {{
+                match node {{
+                    {element_pattern}
+                    {text_pattern}
+                    {comment_pattern}
+                    crate::visit::NodeRef::RawHtml(html) => Some(Self::RawHtml(html.clone())),
+                }}
+            }}
+        }}
+
+        impl<'a> From<&'a {struct_name}Child> for crate::visit::NodeRef<'a> {{
+            fn from(child: &'a {struct_name}Child) -> Self {{
+                match child {{
+                    {ref_patterns}
+                }}
+            }}
+        }}
+
+        impl<'a> From<&'a mut {struct_name}Child> for crate::visit::NodeMut<'a> {{
+            fn from(child: &'a mut {struct_name}Child) -> Self {{
+                match child {{
+                    {mut_patterns}
+                }}
+            }}
+        }}
+        "#
+    )
+}
@@ -0,0 +1,120 @@
+use super::html::attr_method_name;
+use super::sys::keyword_variant_name;
+use super::{CodeFile, ModuleMapping};
+use crate::merge::MergedElement;
+use crate::parse::{Attribute, AttributeType};
+use crate::{utils, Result};
+use convert_case::{Case, Casing};
+
+/// Generate the element definitions the `html!` macro checks its input
+/// against.
+pub fn generate(
+    parsed: impl Iterator<Item = Result<MergedElement>>,
+    global_attributes: &[Attribute],
+    module_map: &[ModuleMapping],
+) -> Result<Vec<CodeFile>> {
+    let mut elements = String::new();
+    for el in parsed {
+        let el = el?;
+        let public_module = super::public_module_name(&el, module_map)?;
+        elements.push_str(&gen_element(&el, &public_module, global_attributes));
+    }
+
+    let global_attributes = global_attributes
+        .iter()
+        .map(|attr| gen_attribute(attr, &attr_method_name(&[], attr)))
+        .collect::<String>();
+
+    let code = format!(
+        "//! The element definitions, generated from the same data as the
+        //! elements of the `html` crate.
+
+        use crate::elements::{{Attribute, AttributeKind, Element}};
+
+        /// The attributes shared by every HTML element.
+        pub(crate) static GLOBAL_ATTRIBUTES: &[Attribute] = &[{global_attributes}];
+
+        /// All elements, with the HTML elements first.
+        pub(crate) static ELEMENTS: &[Element] = &[{elements}];
+        "
+    );
+    Ok(vec![CodeFile {
+        filename: "generated.rs".to_owned(),
+        code: utils::fmt(&code)?,
+        dir: String::new(),
+    }])
+}
+
+fn gen_element(el: &MergedElement, public_module: &str, global_attributes: &[Attribute]) -> String {
+    let MergedElement {
+        tag_name,
+        struct_name,
+        has_closing_tag,
+        has_global_attributes,
+        permitted_child_elements,
+        attributes,
+        ..
+    } = el;
+
+    let method_names = permitted_child_elements
+        .iter()
+        .map(|element_ty| element_ty.to_case(Case::Snake))
+        .collect::<Vec<_>>();
+
+    // Global attributes are only listed per element if their builder method
+    // had to be renamed to not conflict with a child element.
+    let renamed_globals = global_attributes
+        .iter()
+        .filter(|_| *has_global_attributes)
+        .filter(|attr| attr_method_name(&method_names, attr) != attr.field_name);
+    let attributes = attributes
+        .iter()
+        .chain(renamed_globals)
+        .map(|attr| gen_attribute(attr, &attr_method_name(&method_names, attr)))
+        .collect::<String>();
+
+    format!(
+        "Element {{
+            tag_name: {tag_name:?},
+            struct_name: {struct_name:?},
+            module_name: {public_module:?},
+            has_closing_tag: {has_closing_tag},
+            has_global_attributes: {has_global_attributes},
+            permitted_children: &{permitted_child_elements:?},
+            attributes: &[{attributes}],
+        }},"
+    )
+}
+
+fn gen_attribute(attr: &Attribute, method_name: &str) -> String {
+    let name = &attr.name;
+    let kind = attribute_kind(&attr.ty);
+    format!("Attribute::new({name:?}, {method_name:?}, {kind}),")
+}
+
+fn attribute_kind(ty: &AttributeType) -> String {
+    match ty {
+        AttributeType::Bool => "AttributeKind::Bool".to_owned(),
+        AttributeType::String | AttributeType::Identifier(_) => "AttributeKind::String".to_owned(),
+        AttributeType::Integer => "AttributeKind::Integer".to_owned(),
+        AttributeType::Float => "AttributeKind::Float".to_owned(),
+        AttributeType::OptionalBool => "AttributeKind::OptionalBool".to_owned(),
+        AttributeType::Enumerable(enumeration) if enumeration.closed => {
+            let keywords = enumeration
+                .keywords
+                .iter()
+                .map(|keyword| format!("({keyword:?}, {:?}),", keyword_variant_name(keyword)))
+                .collect::<String>();
+            format!(
+                "AttributeKind::Keywords({:?}, &[{keywords}])",
+                enumeration.name
+            )
+        }
+        AttributeType::Enumerable(enumeration) => {
+            format!("AttributeKind::Enumerable({:?})", enumeration.name)
+        }
+        AttributeType::List(item_ty) => {
+            format!("AttributeKind::List(&{})", attribute_kind(item_ty))
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod html;
+pub mod macros;
 pub mod sys;
 
+use crate::merge::MergedElement;
+use crate::parse::Namespace;
+use crate::Result;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -23,3 +27,19 @@ pub struct CodeFile {
     pub dir: String,
     pub code: String,
 }
+
+/// The public module of the `html` crate an element is exported from.
+pub(crate) fn public_module_name(
+    el: &MergedElement,
+    module_map: &[ModuleMapping],
+) -> Result<String> {
+    let module_name = el.module_name();
+    match el.namespace {
+        Namespace::Html => module_map
+            .iter()
+            .find(|mapping| mapping.children.contains(&module_name))
+            .map(|mapping| mapping.name.clone())
+            .ok_or_else(|| format!("element `{module_name}` is not in any MDN module").into()),
+        Namespace::Svg | Namespace::MathMl => Ok(el.submodule_name.clone()),
+    }
+}
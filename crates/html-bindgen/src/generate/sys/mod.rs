@@ -1,10 +1,12 @@
 use super::{CodeFile, Module};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::{collections::HashMap, iter};
 
 use crate::merge::MergedElement;
-use crate::parse::{Attribute, AttributeType};
+use crate::parse::{Attribute, AttributeType, Enumeration, Namespace};
 use crate::{utils, Result};
+use convert_case::{Case, Casing};
 use indoc::{formatdoc, writedoc};
 
 const INCLUDES: &str = r##"
@@ -15,25 +17,238 @@ pub trait RenderElement {
 
     /// Write the closing tag to a writer, if one is available.
     fn write_closing_tag<W: std::fmt::Write >(&self, writer: &mut W) -> std::fmt::Result;
+
+    /// Write the element without any content to a writer.
+    ///
+    /// Foreign elements such as the SVG elements are written as a single
+    /// self-closing tag, e.g. `<path d="M 0 0"/>`. HTML elements can't be
+    /// self-closing, so they're written as an opening and a closing tag.
+    fn write_empty_tag<W: std::fmt::Write >(&self, writer: &mut W) -> std::fmt::Result {
+        self.write_opening_tag(writer)?;
+        self.write_closing_tag(writer)
+    }
 }
 
 /// Get information about an element.
 pub trait ElementDescription {
-    /// Access the attributes that have a value for an element.
-    fn attributes(&self) -> std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>>;
+    /// Access the attributes that have a value for an element, in the order
+    /// in which they're rendered.
+    fn attributes(&self) -> AttributeMap;
+
+    /// Access the data attributes that have a value for an element, in the
+    /// order in which they were inserted.
+    fn data(&self) -> AttributeMap;
+}
+
+/// A map of attribute names to their values, which iterates in insertion
+/// order.
+///
+/// Elements only have a handful of attributes, so entries are stored in a
+/// `Vec` rather than hashed. This keeps the order in which attributes are
+/// rendered the same between runs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AttributeMap {
+    entries: Vec<(std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>)>,
+}
+
+impl AttributeMap {
+    /// Create a new, empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of attributes in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the map holds a value for `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Get the value for `key`.
+    pub fn get(&self, key: &str) -> Option<&std::borrow::Cow<'static, str>> {
+        self.position(key).map(|index| &self.entries[index].1)
+    }
+
+    /// Get a mutable reference to the value for `key`.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut std::borrow::Cow<'static, str>> {
+        self.position(key).map(|index| &mut self.entries[index].1)
+    }
+
+    /// Insert a value for `key`, returning the previous value if there was
+    /// one. Replacing a value keeps the attribute in its original position.
+    pub fn insert(
+        &mut self,
+        key: std::borrow::Cow<'static, str>,
+        value: std::borrow::Cow<'static, str>,
+    ) -> Option<std::borrow::Cow<'static, str>> {
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Remove the value for `key`, keeping the order of the other
+    /// attributes.
+    pub fn remove(&mut self, key: &str) -> Option<std::borrow::Cow<'static, str>> {
+        self.position(key).map(|index| self.entries.remove(index).1)
+    }
+
+    /// Remove all attributes.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Iterate over the attributes in insertion order.
+    pub fn iter(&self) -> AttributeIter<'_> {
+        AttributeIter {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Iterate over the attribute names in insertion order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.iter().map(|(key, _)| key)
+    }
 
-    /// Access the data attributes that have a value for an element.
-    fn data(&self) -> std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>>;
+    /// Iterate over the attribute values in insertion order.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.iter().map(|(_, value)| value)
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| k == key)
+    }
+}
+
+impl<'a> IntoIterator for &'a AttributeMap {
+    type Item = (&'a str, &'a str);
+    type IntoIter = AttributeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for AttributeMap {
+    type Item = (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<K, V> Extend<(K, V)> for AttributeMap
+where
+    K: Into<std::borrow::Cow<'static, str>>,
+    V: Into<std::borrow::Cow<'static, str>>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key.into(), value.into());
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for AttributeMap
+where
+    K: Into<std::borrow::Cow<'static, str>>,
+    V: Into<std::borrow::Cow<'static, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// An iterator over the attributes of an [`AttributeMap`], in insertion order.
+#[derive(Debug, Clone)]
+pub struct AttributeIter<'a> {
+    entries: std::slice::Iter<'a, (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>)>,
+}
+
+impl<'a> Iterator for AttributeIter<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(key, value)| (&**key, &**value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for AttributeIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.entries.next_back().map(|(key, value)| (&**key, &**value))
+    }
+}
+
+impl ExactSizeIterator for AttributeIter<'_> {}
+
+/// Attribute maps are serialized as maps, in insertion order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeMap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AttributeMap {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = AttributeMap;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a map of attribute names to values")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = AttributeMap::new();
+                while let Some((key, value)) = access.next_entry::<String, String>()? {
+                    map.insert(key.into(), value.into());
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
 }
 
 /// Container for `data-*` attributes.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct DataMap {
-    map: std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>>,
+    map: AttributeMap,
+}
+
+impl DataMap {
+    /// Returns `true` if no `data-*` attributes have been set.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
 }
 
 impl std::ops::Deref for DataMap {
-    type Target = std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>>;
+    type Target = AttributeMap;
 
     fn deref(&self) -> &Self::Target {
         &self.map
@@ -49,11 +264,207 @@ impl std::ops::DerefMut for DataMap {
 impl std::fmt::Display for DataMap {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (key, value) in self.map.iter() {
+            // Names can't be escaped, so keys which would end the name early
+            // are left out rather than letting them inject markup.
+            if !escape::is_attribute_name(key) {
+                continue;
+            }
+            let value = escape::AttributeValue(value);
             write!(writer, r#" data-{key}="{value}""#)?;
         }
         Ok(())
     }
 }
+
+/// The doctype which is written before the `<html>` element.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Doctype {
+    /// The HTML doctype, `<!DOCTYPE html>`.
+    #[default]
+    Html,
+    /// A doctype with custom contents, such as
+    /// `html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN"`. Any `>` is left
+    /// out, since it would end the doctype early.
+    Custom(std::borrow::Cow<'static, str>),
+    /// No doctype is written.
+    Omitted,
+}
+
+impl Doctype {
+    /// Returns `true` if this is the HTML doctype.
+    pub fn is_html(&self) -> bool {
+        *self == Doctype::Html
+    }
+}
+
+impl std::fmt::Display for Doctype {
+    fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Doctype::Html => writer.write_str("<!DOCTYPE html>"),
+            Doctype::Custom(doctype) => {
+                writer.write_str("<!DOCTYPE ")?;
+                for part in doctype.split('>') {
+                    writer.write_str(part)?;
+                }
+                writer.write_str(">")
+            }
+            Doctype::Omitted => Ok(()),
+        }
+    }
+}
+
+/// Join the values of a list attribute, which are separated by spaces.
+fn join_list<T: std::fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Escape strings so they can be safely written into an HTML document.
+///
+/// Which characters need to be escaped depends on where in the document a
+/// string ends up. Attribute values, regular text, comments, and the contents
+/// of raw text elements such as `<script>` and `<style>` each have their own
+/// rules.
+pub mod escape {
+    use std::fmt::{self, Display, Write};
+
+    /// An attribute value which is escaped for use between double quotes.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AttributeValue<'a>(pub &'a str);
+
+    impl Display for AttributeValue<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_escaped(f, self.0, |c| match c {
+                '&' => Some("&amp;"),
+                '"' => Some("&quot;"),
+                '<' => Some("&lt;"),
+                '>' => Some("&gt;"),
+                _ => None,
+            })
+        }
+    }
+
+    /// A text node which is escaped so it can't be parsed as markup.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Text<'a>(pub &'a str);
+
+    impl Display for Text<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_escaped(f, self.0, |c| match c {
+                '&' => Some("&amp;"),
+                '<' => Some("&lt;"),
+                '>' => Some("&gt;"),
+                _ => None,
+            })
+        }
+    }
+
+    /// The text contents of a raw text element such as `<script>` or `<style>`.
+    ///
+    /// Character references are not decoded inside raw text elements, so the
+    /// text can't be escaped the way regular text is. Instead we make sure the
+    /// text can't end the element early: any `</{tag_name}` sequence (compared
+    /// case-insensitively) is written as `<\/{tag_name}`, and inside `<script>`
+    /// the `<!--` sequence is written as `<\!--`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct RawText<'a> {
+        tag_name: &'a str,
+        text: &'a str,
+    }
+
+    impl<'a> RawText<'a> {
+        /// Create a new instance of `RawText` for the element `tag_name`.
+        pub fn new(tag_name: &'a str, text: &'a str) -> Self {
+            Self { tag_name, text }
+        }
+    }
+
+    impl Display for RawText<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let is_script = self.tag_name.eq_ignore_ascii_case("script");
+            let mut text = self.text;
+            while let Some(index) = text.find('<') {
+                f.write_str(&text[..index])?;
+                text = &text[index + 1..];
+                let ends_element = text
+                    .strip_prefix('/')
+                    .and_then(|rest| rest.get(..self.tag_name.len()))
+                    .is_some_and(|name| name.eq_ignore_ascii_case(self.tag_name));
+                let opens_comment = is_script && text.starts_with("!--");
+                match ends_element || opens_comment {
+                    true => f.write_str("<\\")?,
+                    false => f.write_char('<')?,
+                }
+            }
+            f.write_str(text)
+        }
+    }
+
+    /// The text of a comment, which is escaped so it can't end the comment
+    /// early.
+    ///
+    /// Comments can't hold character references, so instead a space is
+    /// inserted between any two consecutive hyphens. A space is also put in
+    /// front of text starting with `>` or `->`, and after text ending with
+    /// `<!-`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Comment<'a>(pub &'a str);
+
+    impl Display for Comment<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.0.starts_with('>') || self.0.starts_with("->") {
+                f.write_char(' ')?;
+            }
+            let mut text = self.0;
+            while let Some(index) = text.find("--") {
+                f.write_str(&text[..index + 1])?;
+                f.write_char(' ')?;
+                text = &text[index + 1..];
+            }
+            f.write_str(text)?;
+            if self.0.ends_with("<!-") {
+                f.write_char(' ')?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if `name` only holds characters which are permitted in
+    /// attribute names: anything but controls, whitespace, `"`, `'`, `>`,
+    /// `/`, `=` and noncharacters.
+    pub fn is_attribute_name(name: &str) -> bool {
+        name.chars().all(is_attribute_name_char)
+    }
+
+    fn is_attribute_name_char(c: char) -> bool {
+        if c.is_control() || c.is_whitespace() {
+            return false;
+        }
+        let noncharacter = matches!(c, '\u{FDD0}'..='\u{FDEF}');
+        let noncharacter = noncharacter || (c as u32 & 0xFFFE) == 0xFFFE;
+        !noncharacter && !matches!(c, '"' | '\'' | '>' | '/' | '=')
+    }
+
+    fn write_escaped(
+        f: &mut fmt::Formatter<'_>,
+        s: &str,
+        escape: impl Fn(char) -> Option<&'static str>,
+    ) -> fmt::Result {
+        let mut last = 0;
+        for (index, c) in s.char_indices() {
+            if let Some(escaped) = escape(c) {
+                f.write_str(&s[last..index])?;
+                f.write_str(escaped)?;
+                last = index + c.len_utf8();
+            }
+        }
+        f.write_str(&s[last..])
+    }
+}
 "##;
 
 pub fn generate(
@@ -63,16 +474,26 @@ pub fn generate(
 ) -> Result<Vec<CodeFile>> {
     let mut output = vec![];
     let mut generated: HashMap<String, Vec<String>> = HashMap::new();
+    let mut enumerations = Enumerations::default();
+    enumerations.extend(global_attributes, None)?;
 
     // generate individual `{element}.rs` files
     for el in merged {
         let el = el?;
         let entry = generated.entry(el.submodule_name.clone());
-        entry.or_default().push(el.tag_name.clone());
+        entry.or_default().push(el.module_name());
+        enumerations.extend(&el.attributes, Some(&el.tag_name))?;
         let cf = generate_element(el)?;
         output.push(cf);
     }
 
+    // generate the `attributes.rs` file
+    output.push(CodeFile {
+        filename: "attributes.rs".to_owned(),
+        code: utils::fmt(&enumerations.generate())?,
+        dir: String::new(),
+    });
+
     // generate `mod.rs` files
     let mut dirs = vec![];
     for (dir, filenames) in generated {
@@ -82,7 +503,7 @@ pub fn generate(
             .map(|name| format!("mod {name};\npub use {name}::*;"))
             .collect::<String>();
 
-        let module = modules.iter().find(|el| &el.name == &dir).unwrap();
+        let module = modules.iter().find(|el| el.name == dir).unwrap();
         let description = &module.description;
         let code = format!(
             "//! {description}
@@ -101,6 +522,7 @@ pub fn generate(
     let code = dirs
         .into_iter()
         .map(|d| format!("pub mod {d};\n"))
+        .chain(iter::once("pub mod attributes;\n".to_owned()))
         .chain(iter::once(INCLUDES.to_owned()))
         .chain(iter::once({
             let fields = generate_fields(global_attributes);
@@ -108,14 +530,16 @@ pub fn generate(
             let mut display_attrs = String::new();
             let mut add_attrs = String::new();
             for attr in global_attributes {
-                display_attrs.push_str(&generate_attribute_display(&attr));
-                add_attrs.push_str(&generate_attribute_adder(&attr));
+                display_attrs.push_str(&generate_attribute_display(attr));
+                add_attrs.push_str(&generate_attribute_adder(attr));
             }
             formatdoc!(
                 r#"
 
                     /// The "global attributes" struct
                     #[derive(Debug, Clone, PartialEq, Default)]
+                    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+                    #[cfg_attr(feature = "serde", serde(default))]
                     pub struct GlobalAttributes {{
                         {fields}
                     }}
@@ -128,7 +552,7 @@ pub fn generate(
                     }}
 
                     impl GlobalAttributes {{
-                        fn add(&self, attrs: &mut std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>>) {{
+                        fn add(&self, attrs: &mut crate::AttributeMap) {{
                             {add_attrs}
                         }}
                     }}
@@ -148,9 +572,11 @@ pub fn generate(
 /// Generate a single element.
 fn generate_element(el: MergedElement) -> Result<CodeFile> {
     let dir = el.submodule_name.clone();
+    let filename = format!("{}.rs", el.module_name());
     let MergedElement {
         tag_name,
         struct_name,
+        namespace,
         has_closing_tag,
         attributes,
         mdn_link,
@@ -158,30 +584,67 @@ fn generate_element(el: MergedElement) -> Result<CodeFile> {
         ..
     } = el;
 
-    let filename = format!("{}.rs", tag_name);
     let fields = generate_fields(&attributes);
-    let opening_tag_content = generate_opening_tag(&attributes, &tag_name, has_global_attributes);
+    let opening_tag_content =
+        generate_opening_tag(&attributes, &tag_name, has_global_attributes, ">");
     let closing_tag_content = generate_closing_tag(&tag_name, has_closing_tag);
+    let language = namespace.language();
 
     let mut add_attrs = String::new();
-    for attr in attributes {
-        add_attrs.push_str(&generate_attribute_adder(&attr));
+    for attr in &attributes {
+        add_attrs.push_str(&generate_attribute_adder(attr));
     }
 
+    // The doctype is written as part of the `<html>` element.
+    let doctype_field = match (namespace, tag_name.as_str()) {
+        (Namespace::Html, "html") => {
+            r#"/// The doctype which is written before the element
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "crate::Doctype::is_html"))]
+            pub doctype: crate::Doctype,"#
+        }
+        _ => "",
+    };
     let global_field = match has_global_attributes {
-        true => format!("global_attrs: crate::GlobalAttributes,"),
+        true => r#"#[cfg_attr(feature = "serde", serde(flatten))]
+            global_attrs: crate::GlobalAttributes,"#
+            .to_string(),
         false => String::new(),
     };
+    let add_global_attrs = match has_global_attributes {
+        true => "self.global_attrs.add(&mut attrs);",
+        false => "",
+    };
+
+    // Foreign elements without any content are written as a single
+    // self-closing tag.
+    let empty_tag_impl = match namespace {
+        Namespace::Html => String::new(),
+        Namespace::Svg | Namespace::MathMl => {
+            let empty_tag_content =
+                generate_opening_tag(&attributes, &tag_name, has_global_attributes, "/>");
+            formatdoc!(
+                r#"
+                fn write_empty_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {{
+                    {empty_tag_content}
+                    Ok(())
+                }}"#
+            )
+        }
+    };
 
     let mut code = formatdoc!(
-        r#"/// The HTML `<{tag_name}>` element
+        r#"/// The {language} `<{tag_name}>` element
         ///
         /// [MDN Documentation]({mdn_link})
         #[doc(alias = "{tag_name}")]
         #[non_exhaustive]
         #[derive(Debug, Clone, PartialEq, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(default))]
         pub struct {struct_name} {{
+            #[cfg_attr(feature = "serde", serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty"))]
             pub data_map: crate::DataMap,
+            {doctype_field}
             {global_field}
             {fields}
         }}
@@ -197,27 +660,26 @@ fn generate_element(el: MergedElement) -> Result<CodeFile> {
                 {closing_tag_content}
                 Ok(())
             }}
+            {empty_tag_impl}
         }}
 
         impl crate::ElementDescription for {struct_name} {{
-            fn attributes(&self) -> std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>> {{
-                let mut attrs = std::collections::HashMap::new();
-                self.global_attrs.add(&mut attrs);
+            fn attributes(&self) -> crate::AttributeMap {{
+                let mut attrs = crate::AttributeMap::new();
                 {add_attrs}
+                {add_global_attrs}
                 attrs
             }}
 
-            fn data(&self) -> std::collections::HashMap<std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>> {{
-                (&*self.data_map).clone()
+            fn data(&self) -> crate::AttributeMap {{
+                self.data_map.map.clone()
             }}
         }}
 
         impl std::fmt::Display for {struct_name} {{
             fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
                 use crate::RenderElement;
-                self.write_opening_tag(writer)?;
-                self.write_closing_tag(writer)?;
-                Ok(())
+                self.write_empty_tag(writer)
             }}
         }}
     "#
@@ -255,19 +717,38 @@ fn generate_fields(attributes: &[Attribute]) -> String {
         let description = &attr.description;
         let field_name = &attr.field_name;
         let ty = &attr.ty;
+        let serde_attr = generate_serde_field_attr(attr);
         output.push_str(&match ty {
             AttributeType::Bool => format!(
                 "/// {description}
+                {serde_attr}
                 pub {field_name}: bool,
                 "
             ),
             AttributeType::String => format!(
                 "/// {description}
+             {serde_attr}
              pub {field_name}: std::option::Option<std::borrow::Cow<'static, str>>,
             "
             ),
+            AttributeType::Enumerable(Enumeration { name, .. }) => format!(
+                "/// {description}
+             {serde_attr}
+             pub {field_name}: std::option::Option<crate::attributes::{name}>,
+            "
+            ),
+            AttributeType::List(item_ty) => {
+                let item_ty = sys_list_item_type(item_ty);
+                format!(
+                    "/// {description}
+                 {serde_attr}
+                 pub {field_name}: std::vec::Vec<{item_ty}>,
+                "
+                )
+            }
             _ => format!(
                 "/// {description}
+             {serde_attr}
              pub {field_name}: std::option::Option<{ty}>,
             "
             ),
@@ -276,29 +757,64 @@ fn generate_fields(attributes: &[Attribute]) -> String {
     output
 }
 
+/// The type of the items of a list attribute in `html-sys`.
+pub(crate) fn sys_list_item_type(ty: &AttributeType) -> String {
+    match ty {
+        AttributeType::String => "std::borrow::Cow<'static, str>".to_owned(),
+        AttributeType::Enumerable(Enumeration { name, .. }) => {
+            format!("crate::attributes::{name}")
+        }
+        ty => format!("{ty}"),
+    }
+}
+
+/// Attributes are serialized using their HTML name, and only if they're set.
+fn generate_serde_field_attr(attr: &Attribute) -> String {
+    let Attribute {
+        name,
+        field_name,
+        ty,
+        ..
+    } = attr;
+    let skip = match ty {
+        AttributeType::Bool => "std::ops::Not::not",
+        AttributeType::List(_) => "std::vec::Vec::is_empty",
+        _ => "std::option::Option::is_none",
+    };
+    match name == field_name {
+        true => format!(r#"#[cfg_attr(feature = "serde", serde(skip_serializing_if = "{skip}"))]"#),
+        false => format!(
+            r#"#[cfg_attr(feature = "serde", serde(rename = "{name}", skip_serializing_if = "{skip}"))]"#
+        ),
+    }
+}
+
 fn generate_opening_tag(
     attributes: &[Attribute],
     tag_name: &str,
     has_global_attrs: bool,
+    end: &str,
 ) -> String {
-    let preamble = match tag_name {
-        "html" => "<!DOCTYPE html>",
-        _ => "",
+    let mut output = match tag_name {
+        "html" => r#"write!(writer, "{}", self.doctype)?;"#.to_owned(),
+        _ => String::new(),
     };
-    let mut output = formatdoc!(
+    writedoc!(
+        &mut output,
         r#"
-        write!(writer, "{preamble}<{tag_name}")?;
+        write!(writer, "<{tag_name}")?;
     "#
-    );
+    )
+    .unwrap();
     for attr in attributes {
-        output.push_str(&generate_attribute_display(&attr));
+        output.push_str(&generate_attribute_display(attr));
     }
     if has_global_attrs {
-        output.push_str(&format!(r#"write!(writer, "{{}}", self.global_attrs)?;"#));
+        output.push_str(r#"write!(writer, "{}", self.global_attrs)?;"#);
     }
 
-    output.push_str(&format!(r#"write!(writer, "{{}}", self.data_map)?;"#));
-    writedoc!(&mut output, r#"write!(writer, ">")?;"#).unwrap();
+    output.push_str(r#"write!(writer, "{}", self.data_map)?;"#);
+    writedoc!(&mut output, r#"write!(writer, "{end}")?;"#).unwrap();
     output
 }
 
@@ -321,18 +837,43 @@ fn generate_attribute_display(attr: &Attribute) -> String {
         ..
     } = &attr;
     match ty {
+        // ARIA states are written as `"true"` or `"false"`, and a bare ARIA
+        // attribute means the default, which is false.
+        AttributeType::Bool if name.starts_with("aria-") => format!(
+            r##"if self.{field_name} {{
+                    write!(writer, r#" {name}="true""#)?;
+            }}"##
+        ),
         AttributeType::Bool => format!(
             r##"if self.{field_name} {{
                     write!(writer, r#" {name}"#)?;
             }}"##
         ),
-        AttributeType::String | AttributeType::Integer | AttributeType::Float => format!(
+        AttributeType::String => format!(
+            r##"if let Some(field) = self.{field_name}.as_ref() {{
+                let field = crate::escape::AttributeValue(field);
+                write!(writer, r#" {name}="{{field}}""#)?;
+            }}"##
+        ),
+        AttributeType::Integer | AttributeType::Float | AttributeType::OptionalBool => format!(
+            r##"if let Some(field) = self.{field_name}.as_ref() {{
+                write!(writer, r#" {name}="{{field}}""#)?;
+            }}"##
+        ),
+        AttributeType::Enumerable(_) => format!(
             r##"if let Some(field) = self.{field_name}.as_ref() {{
+                let field = crate::escape::AttributeValue(field.as_str());
+                write!(writer, r#" {name}="{{field}}""#)?;
+            }}"##
+        ),
+        AttributeType::List(_) => format!(
+            r##"if !self.{field_name}.is_empty() {{
+                let field = crate::join_list(&self.{field_name});
+                let field = crate::escape::AttributeValue(&field);
                 write!(writer, r#" {name}="{{field}}""#)?;
             }}"##
         ),
         AttributeType::Identifier(_) => todo!(),
-        AttributeType::Enumerable(_) => todo!(),
     }
 }
 
@@ -349,18 +890,252 @@ fn generate_attribute_adder(attr: &Attribute) -> String {
                     attrs.insert(std::borrow::Cow::Borrowed("{name}"), std::borrow::Cow::Borrowed("true"));
             }}"##
         ),
-        // TODO: make enum attribute for value kinds.
         AttributeType::String => format!(
             r##"if let Some(field) = &self.{field_name} {{
-                attrs.insert(std::borrow::Cow::Borrowed("{name}"), field.to_owned());
+                attrs.insert(std::borrow::Cow::Borrowed("{name}"), field.clone());
+            }}"##
+        ),
+        AttributeType::Integer | AttributeType::Float | AttributeType::OptionalBool => format!(
+            r##"if let Some(field) = &self.{field_name} {{
+                attrs.insert(std::borrow::Cow::Borrowed("{name}"), std::borrow::Cow::Owned(field.to_string()));
+            }}"##
+        ),
+        AttributeType::List(_) => format!(
+            r##"if !self.{field_name}.is_empty() {{
+                attrs.insert(std::borrow::Cow::Borrowed("{name}"), std::borrow::Cow::Owned(crate::join_list(&self.{field_name})));
             }}"##
         ),
-        AttributeType::Integer | AttributeType::Float => format!(
+        AttributeType::Enumerable(_) => format!(
             r##"if let Some(field) = &self.{field_name} {{
-                attrs.insert(std::borrow::Cow::Borrowed("{name}"), std::borrow::Cow::Owned(format!(r#" {{field}}"#)));
+                attrs.insert(std::borrow::Cow::Borrowed("{name}"), std::borrow::Cow::Owned(field.to_string()));
             }}"##
         ),
         AttributeType::Identifier(_) => todo!(),
-        AttributeType::Enumerable(_) => todo!(),
+    }
+}
+
+/// All enumerated attribute types, keyed by the name of their enum.
+#[derive(Default)]
+struct Enumerations {
+    map: BTreeMap<String, EnumerationUses>,
+}
+
+/// An enumerated attribute type, and the attributes and elements using it.
+struct EnumerationUses {
+    enumeration: Enumeration,
+    attribute_names: BTreeSet<String>,
+    /// The elements using the type, which is empty for global attributes.
+    tag_names: BTreeSet<String>,
+}
+
+impl Enumerations {
+    /// Track the enumerated types of a list of attributes.
+    fn extend(&mut self, attributes: &[Attribute], tag_name: Option<&str>) -> Result<()> {
+        for attr in attributes {
+            let ty = match &attr.ty {
+                AttributeType::List(item_ty) => item_ty,
+                ty => ty,
+            };
+            if let AttributeType::Enumerable(enumeration) = ty {
+                let uses =
+                    self.map
+                        .entry(enumeration.name.clone())
+                        .or_insert_with(|| EnumerationUses {
+                            enumeration: enumeration.clone(),
+                            attribute_names: BTreeSet::new(),
+                            tag_names: BTreeSet::new(),
+                        });
+                if &uses.enumeration != enumeration {
+                    let name = &enumeration.name;
+                    return Err(format!("enum `{name}` is defined with different keywords").into());
+                }
+                uses.attribute_names.insert(attr.name.clone());
+                uses.tag_names.extend(tag_name.map(str::to_owned));
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate the `attributes` module.
+    fn generate(&self) -> String {
+        let enums = self.map.values().map(gen_enumeration).collect::<String>();
+        format!(
+            "//! Typed values for enumerated attributes.
+
+            /// An error returned when parsing a value which isn't one of the
+            /// keywords of an enumerated attribute that doesn't take custom
+            /// values.
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub struct ParseKeywordError {{
+                value: String,
+            }}
+
+            impl ParseKeywordError {{
+                /// The value which isn't one of the keywords.
+                pub fn value(&self) -> &str {{
+                    &self.value
+                }}
+            }}
+
+            impl std::fmt::Display for ParseKeywordError {{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                    write!(f, \"`{{}}` is not a permitted keyword\", self.value)
+                }}
+            }}
+
+            impl std::error::Error for ParseKeywordError {{}}
+            {enums}"
+        )
+    }
+}
+
+fn gen_enumeration(uses: &EnumerationUses) -> String {
+    let EnumerationUses {
+        enumeration:
+            Enumeration {
+                name,
+                keywords,
+                closed,
+            },
+        attribute_names,
+        tag_names,
+    } = uses;
+    let attribute_names = attribute_names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>();
+    let mut description = match attribute_names.as_slice() {
+        [name] => format!("The keywords of the {name} attribute"),
+        [names @ .., last] => format!(
+            "The keywords of the {} and {last} attributes",
+            names.join(", ")
+        ),
+        [] => unreachable!(),
+    };
+    if *closed && tag_names.len() == 1 {
+        let tag_name = tag_names.first().unwrap();
+        write!(description, " of `<{tag_name}>`").unwrap();
+    }
+
+    let variants = keywords
+        .iter()
+        .map(|keyword| (keyword, keyword_variant_name(keyword)))
+        .collect::<Vec<_>>();
+
+    let members = variants
+        .iter()
+        .map(|(keyword, variant)| format!("/// The `{keyword}` keyword\n{variant},"))
+        .collect::<String>();
+    let as_str_patterns = variants
+        .iter()
+        .map(|(keyword, variant)| format!(r#"Self::{variant} => "{keyword}","#))
+        .collect::<String>();
+
+    // Keywords are matched ASCII case-insensitively, unless that would make
+    // them ambiguous. This is the case for e.g. `<ol type="a">` and `<ol type="A">`.
+    let lowercase = keywords
+        .iter()
+        .map(|keyword| keyword.to_ascii_lowercase())
+        .collect::<BTreeSet<_>>();
+    let from_str_patterns = variants
+        .iter()
+        .map(
+            |(keyword, variant)| match lowercase.len() == keywords.len() {
+                true => format!(r#"s if s.eq_ignore_ascii_case("{keyword}") => Self::{variant},"#),
+                false => format!(r#""{keyword}" => Self::{variant},"#),
+            },
+        )
+        .collect::<String>();
+
+    // Closed enumerations reject other values, while the others keep them as
+    // custom values.
+    let (derives, custom_member, custom_as_str, err_ty, from_str_fallback, deserialize) =
+        match *closed {
+            true => (
+                "Debug, Clone, Copy, PartialEq, Eq, Hash",
+                "",
+                "",
+                "ParseKeywordError",
+                "s => return Err(ParseKeywordError { value: s.to_owned() }),",
+                "s.parse().map_err(serde::de::Error::custom)",
+            ),
+            false => (
+                "Debug, Clone, PartialEq, Eq, Hash",
+                "/// A value which isn't one of the known keywords
+                Custom(std::borrow::Cow<'static, str>),",
+                "Self::Custom(value) => value,",
+                "std::convert::Infallible",
+                "s => Self::Custom(s.to_owned().into()),",
+                "Ok(s.parse().unwrap_or_else(|never| match never {}))",
+            ),
+        };
+
+    format!(
+        r#"
+        /// {description}
+        #[derive({derives})]
+        pub enum {name} {{
+            {members}
+            {custom_member}
+        }}
+
+        impl {name} {{
+            /// Get the value as a string slice.
+            pub fn as_str(&self) -> &str {{
+                match self {{
+                    {as_str_patterns}
+                    {custom_as_str}
+                }}
+            }}
+        }}
+
+        impl std::fmt::Display for {name} {{
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                f.write_str(self.as_str())
+            }}
+        }}
+
+        impl std::str::FromStr for {name} {{
+            type Err = {err_ty};
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                Ok(match s {{
+                    {from_str_patterns}
+                    {from_str_fallback}
+                }})
+            }}
+        }}
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for {name} {{
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                serializer.serialize_str(self.as_str())
+            }}
+        }}
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for {name} {{
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+                {deserialize}
+            }}
+        }}
+        "#
+    )
+}
+
+/// Convert a keyword into the name of an enum variant.
+pub(crate) fn keyword_variant_name(keyword: &str) -> String {
+    match keyword {
+        // The list marker types of `<ol>` are single characters.
+        "1" => "Decimal".to_owned(),
+        "a" => "LowerAlpha".to_owned(),
+        "A" => "UpperAlpha".to_owned(),
+        "i" => "LowerRoman".to_owned(),
+        "I" => "UpperRoman".to_owned(),
+        // Keywords such as `multipart/form-data` contain other punctuation.
+        other => other
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "-")
+            .to_case(Case::UpperCamel),
     }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "cli")]
+pub mod convert;
 pub mod generate;
 pub mod merge;
 pub mod parse;
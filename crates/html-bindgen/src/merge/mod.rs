@@ -3,10 +3,12 @@
 use std::collections::{BTreeSet, HashMap};
 
 use crate::parse::{
-    Attribute, AttributeType, ParsedAriaElement, ParsedAriaProperty, ParsedAriaRole,
-    ParsedCategory, ParsedElement, ParsedInterface, ParsedRelationship,
+    Attribute, AttributeType, Enumeration, Namespace, ParsedAriaElement, ParsedAriaProperty,
+    ParsedAriaRole, ParsedAttribute, ParsedCategory, ParsedElement, ParsedInterface,
+    ParsedRelationship, TagOmission,
 };
 use crate::Result;
+use convert_case::{Case, Casing};
 use serde::{Deserialize, Serialize};
 
 /// The final source of truth we used to generate code from.
@@ -16,14 +18,37 @@ use serde::{Deserialize, Serialize};
 pub struct MergedElement {
     pub tag_name: String,
     pub struct_name: String,
+    pub namespace: Namespace,
     pub submodule_name: String,
     pub mdn_link: String,
     pub has_global_attributes: bool,
     pub has_closing_tag: bool,
+    pub tag_omission: TagOmission,
     pub attributes: Vec<Attribute>,
     pub dom_interface: String,
     pub content_categories: Vec<MergedCategory>,
     pub permitted_child_elements: Vec<String>,
+    /// The roles ARIA in HTML maps the element to when it has no `role`
+    /// attribute. Which of them applies can depend on the attributes and
+    /// ancestors of the element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub implicit_roles: Vec<String>,
+}
+
+impl MergedElement {
+    /// The name of the Rust module the element is generated in. SVG elements
+    /// can share their tag name with HTML elements, and are written in camel
+    /// case, so they're prefixed and converted to snake case.
+    pub fn module_name(&self) -> String {
+        match self.namespace {
+            Namespace::Html => self.tag_name.clone(),
+            Namespace::Svg | Namespace::MathMl => format!(
+                "{}_{}",
+                self.submodule_name,
+                self.tag_name.to_case(Case::Snake)
+            ),
+        }
+    }
 }
 
 /// Each element in HTML falls into zero or more categories that group elements
@@ -44,6 +69,25 @@ pub enum MergedCategory {
     Transparent,
 }
 
+/// The ARIA properties which are supported regardless of the role of an
+/// element, and the roles with the properties they support.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergedAria {
+    pub global_properties: Vec<String>,
+    pub roles: Vec<MergedAriaRole>,
+}
+
+/// A concrete ARIA role.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergedAriaRole {
+    pub name: String,
+    /// All properties supported by the role, including the global ones
+    /// which aren't prohibited on it.
+    pub properties: Vec<String>,
+    /// Whether the role can get its name from its contents.
+    pub name_from_contents: bool,
+}
+
 impl From<ParsedCategory> for MergedCategory {
     fn from(value: ParsedCategory) -> Self {
         match value {
@@ -67,6 +111,7 @@ pub fn merge(
     parsed_aria_elements: impl Iterator<Item = Result<ParsedAriaElement>>,
     parsed_aria_roles: impl Iterator<Item = Result<ParsedAriaRole>>,
     parsed_aria_properties: impl Iterator<Item = Result<ParsedAriaProperty>>,
+    parsed_attributes: impl Iterator<Item = Result<ParsedAttribute>>,
 ) -> Result<Vec<MergedElement>> {
     let mut elements = HashMap::new();
     for el in parsed_elements {
@@ -91,6 +136,7 @@ pub fn merge(
     let aria_properties = parsed_aria_properties
         .map(|x| x.map(|y| (y.name.clone(), y)))
         .collect::<Result<HashMap<_, _>>>()?;
+    let attributes = parsed_attributes.collect::<Result<Vec<_>>>()?;
 
     let by_content_type = categorize_elements(&elements);
     let mut children_map = children_per_element(&elements, &by_content_type);
@@ -101,6 +147,7 @@ pub fn merge(
         &aria_elements,
         &aria_roles,
         &aria_properties,
+        &attributes,
     );
 
     let mut output = vec![];
@@ -108,29 +155,105 @@ pub fn merge(
         let mut permitted_child_elements = children_map.get(&el.struct_name).unwrap().clone();
         permitted_child_elements.dedup();
         let attributes = attributes_map.get(&el.struct_name).unwrap().clone();
+        let implicit_roles = match el.namespace {
+            Namespace::Html => aria_elements
+                .get(&el.tag_name)
+                .map(|aria_el| aria_el.implicit_roles.iter().cloned().collect())
+                .unwrap_or_default(),
+            _ => vec![],
+        };
         output.push(MergedElement {
             tag_name: el.tag_name,
             struct_name: el.struct_name,
+            namespace: el.namespace,
             submodule_name: el.submodule_name,
             mdn_link: el.mdn_link,
             has_global_attributes: el.has_global_attributes,
             has_closing_tag: el.has_closing_tag,
+            tag_omission: el.tag_omission,
             dom_interface: el.dom_interface,
             content_categories: convert_parsed_categories(&el.content_categories),
             attributes,
             permitted_child_elements,
+            implicit_roles,
         })
     }
     Ok(output)
 }
 
+/// Merge the ARIA roles with the properties they support.
+pub fn merge_aria(
+    parsed_aria_roles: impl Iterator<Item = Result<ParsedAriaRole>>,
+    parsed_aria_properties: impl Iterator<Item = Result<ParsedAriaProperty>>,
+) -> Result<MergedAria> {
+    let mut global_properties = vec![];
+    for property in parsed_aria_properties {
+        let property = property?;
+        if property.is_global {
+            global_properties.push(property.name);
+        }
+    }
+    global_properties.sort();
+
+    let mut roles = parsed_aria_roles
+        .map(|role| {
+            let role = role?;
+            let mut properties = role.allowed_properties;
+            properties.sort();
+            properties.dedup();
+            Ok(MergedAriaRole {
+                name: role.name,
+                properties,
+                name_from_contents: role.name_from_contents,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    roles.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // `presentation` is a synonym of `none`, and doesn't list any properties
+    // of its own.
+    let none = roles.iter().find(|role| role.name == "none").cloned();
+    if let (Some(none), Some(presentation)) = (
+        none,
+        roles.iter_mut().find(|role| role.name == "presentation"),
+    ) {
+        presentation.properties = none.properties;
+    }
+
+    Ok(MergedAria {
+        global_properties,
+        roles,
+    })
+}
+
+/// Combine the manually curated global attributes with the event handler
+/// attributes of the `GlobalEventHandlers` mixin, which every element has.
+pub fn merge_global_attributes(
+    manual: Vec<Attribute>,
+    parsed_interfaces: impl Iterator<Item = Result<ParsedInterface>>,
+) -> Result<Vec<Attribute>> {
+    let mut output = manual;
+    for interface in parsed_interfaces {
+        let interface = interface?;
+        if interface.name != "GlobalEventHandlers" {
+            continue;
+        }
+        for attr in interface.attributes {
+            if !output.iter().any(|other| other.name == attr.name) {
+                output.push(attr);
+            }
+        }
+    }
+    Ok(output)
+}
+
 /// In order to correctly handle `PhrasingContent` we add one more item to the
 /// mix: `Text`, which in later stages we'll replace with a Rust string type.
 fn insert_text_content(
     elements: &HashMap<String, ParsedElement>,
     children_map: &mut HashMap<String, Vec<String>>,
 ) {
-    for (_, parent_el) in elements {
+    for parent_el in elements.values() {
         let has_phrasing = parent_el
             .permitted_content
             .contains(&ParsedRelationship::Category(ParsedCategory::Phrasing));
@@ -189,12 +312,12 @@ fn children_per_element(
     // Because not all elements will have children,
     // we create empty lists for all elements first.
     let mut output = elements
-        .iter()
-        .map(|(name, _)| (name.clone(), vec![]))
+        .keys()
+        .map(|name| (name.clone(), vec![]))
         .collect::<HashMap<_, _>>();
 
     // First we start by iterating over all elements.
-    for (_, parent_el) in elements {
+    for parent_el in elements.values() {
         // Then we take a look at which elements they can take as children.
         for child_relationship in &parent_el.permitted_content {
             match child_relationship {
@@ -220,6 +343,9 @@ fn children_per_element(
                     // with it like this for now.
                     if let ParsedCategory::Transparent = child_category {
                         for (_, child_el) in elements.iter() {
+                            if !shares_namespace(child_el, parent_el) {
+                                continue;
+                            }
                             output
                                 .get_mut(&parent_el.struct_name)
                                 .unwrap()
@@ -230,7 +356,7 @@ fn children_per_element(
 
                     // Otherwise look at the content type, find all children for that
                     // type and then intsert those.
-                    for child_el_name in by_content_type.get(&child_category).unwrap() {
+                    for child_el_name in by_content_type.get(child_category).unwrap() {
                         let child_el = elements.get(child_el_name).unwrap();
                         if child_can_have_parent(child_el, parent_el) {
                             output
@@ -244,6 +370,14 @@ fn children_per_element(
         }
     }
 
+    // Transparent content only holds elements of the parent's namespace, with
+    // the exception of `<svg>` which can be used in HTML content.
+    fn shares_namespace(child: &ParsedElement, parent: &ParsedElement) -> bool {
+        child.namespace == parent.namespace
+            || (child.namespace == Namespace::Svg && child.tag_name == "svg")
+            || (child.namespace == Namespace::MathMl && child.tag_name == "math")
+    }
+
     // Check whether
     fn child_can_have_parent(child: &ParsedElement, proposed_parent: &ParsedElement) -> bool {
         // If the parent allows "transparent" content, then all children are valid.
@@ -295,10 +429,11 @@ fn merge_attributes(
     aria_elements: &HashMap<String, ParsedAriaElement>,
     aria_roles: &HashMap<String, ParsedAriaRole>,
     aria_properties: &HashMap<String, ParsedAriaProperty>,
+    attributes: &[ParsedAttribute],
 ) -> HashMap<String, Vec<Attribute>> {
     let mut output = elements
-        .iter()
-        .map(|(name, _)| (name.clone(), vec![]))
+        .keys()
+        .map(|name| (name.clone(), vec![]))
         .collect::<HashMap<_, _>>();
 
     let interface_map = interfaces
@@ -314,14 +449,18 @@ fn merge_attributes(
         .collect::<HashMap<String, _>>();
 
     // From https://www.w3.org/TR/role-attribute/
-    let role_attr = Attribute {
+    let role_attr = |name: String, roles: &BTreeSet<String>| Attribute {
         name: "role".to_owned(),
-        description:
-            "Describes the role(s) the current element plays in the context of the document."
-                .to_owned(),
+        description: "Describes the role the current element plays in the context of the document."
+            .to_owned(),
         field_name: "role".to_owned(),
-        ty: AttributeType::String,
+        ty: AttributeType::Enumerable(Enumeration {
+            name,
+            keywords: roles.iter().cloned().collect(),
+            closed: true,
+        }),
     };
+    let all_roles = aria_roles.keys().cloned().collect::<BTreeSet<_>>();
 
     for el in elements.values() {
         let vec = output.entry(el.struct_name.clone()).or_default();
@@ -335,7 +474,20 @@ fn merge_attributes(
                             field_name: other.field_name.clone(),
                             ty: other.ty.clone(),
                         },
-                        None => attr.clone(),
+                        // Attributes of parent interfaces only tell us
+                        // about the type, e.g. `controls` on `<video>`.
+                        None => match find_inherited_attribute(
+                            interfaces,
+                            &interface_map,
+                            el,
+                            &attr.name,
+                        ) {
+                            Some(other) => Attribute {
+                                ty: other.ty.clone(),
+                                ..attr.clone()
+                            },
+                            None => attr.clone(),
+                        },
                     };
                     vec.push(attr);
                 }
@@ -345,9 +497,34 @@ fn merge_attributes(
             }
         };
 
+        // The attribute index and ARIA in HTML only cover HTML elements.
+        if el.namespace != Namespace::Html {
+            continue;
+        }
+
+        // Attributes which take a keyword are typed using the attribute index.
+        for attr in vec.iter_mut() {
+            if attr.ty != AttributeType::String {
+                continue;
+            }
+            let keyword_attr = attributes
+                .iter()
+                .find(|other| other.name == attr.name && other.elements.contains(&el.tag_name));
+            if let Some(keyword_attr) = keyword_attr {
+                attr.ty = keyword_attr.ty.clone();
+            }
+        }
+
         if let Some(aria_el) = aria_elements.get(&el.tag_name) {
-            if !aria_el.no_role || !aria_el.allowed_roles.is_empty() {
-                vec.push(role_attr.clone());
+            // Elements which take any role share the `Role` enum, the others
+            // only take the roles ARIA in HTML permits on them. The allowed
+            // roles of elements which take no role are their implicit roles,
+            // which authors shouldn't set.
+            if aria_el.any_role {
+                vec.push(role_attr("Role".to_owned(), &all_roles));
+            } else if !aria_el.no_role && !aria_el.allowed_roles.is_empty() {
+                let name = format!("{}Role", el.struct_name);
+                vec.push(role_attr(name, &aria_el.allowed_roles));
             }
 
             let mut properties = if aria_el.no_aria_attributes {
@@ -400,8 +577,26 @@ fn merge_attributes(
     output
 }
 
+/// Find an attribute defined by one of the interfaces the element's interface
+/// inherits from, such as `HTMLMediaElement` for `<video>`.
+fn find_inherited_attribute<'a>(
+    interfaces: &HashMap<String, ParsedInterface>,
+    interface_map: &'a HashMap<String, HashMap<String, Attribute>>,
+    el: &ParsedElement,
+    name: &str,
+) -> Option<&'a Attribute> {
+    let mut parent = interfaces.get(&el.dom_interface)?.inherits_from.as_deref();
+    while let Some(current) = parent {
+        if let Some(attr) = interface_map.get(current)?.get(name) {
+            return Some(attr);
+        }
+        parent = interfaces.get(current)?.inherits_from.as_deref();
+    }
+    None
+}
+
 /// Take a list of parsed categories and output a list of merged categories + a
 /// list of child elements.
 fn convert_parsed_categories(categories: &[ParsedCategory]) -> Vec<MergedCategory> {
-    categories.into_iter().cloned().map(Into::into).collect()
+    categories.iter().cloned().map(Into::into).collect()
 }
@@ -8,13 +8,15 @@ use crate::{
     Result,
 };
 
-use super::{Attribute, AttributeType};
+use super::{Attribute, AttributeType, Enumeration};
 
 /// The parsed WebIDL definitions converted from the raw spec.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParsedAriaRole {
     pub name: String,
     pub allowed_properties: Vec<String>,
+    /// Whether the role can get its name from its contents.
+    pub name_from_contents: bool,
 }
 
 /// The parsed WebIDL definitions converted from the raw spec.
@@ -31,11 +33,52 @@ pub struct ParsedAriaProperty {
 impl From<ParsedAriaProperty> for Attribute {
     fn from(value: ParsedAriaProperty) -> Self {
         let field_name = value.idl_name.to_case(Case::Snake);
+        // Tokens are typed by an enum named after the property, e.g.
+        // `AriaLive` for `aria-live`.
+        let enumeration = |tokens: Vec<String>| Enumeration {
+            name: value.idl_name.to_case(Case::UpperCamel),
+            keywords: tokens,
+            closed: true,
+        };
+        let ty = match value.value_kind {
+            AriaPropertyValueKind::Bool => AttributeType::Bool,
+            AriaPropertyValueKind::Tristate => AttributeType::Enumerable(Enumeration {
+                name: "Tristate".to_owned(),
+                keywords: vec!["true".to_owned(), "false".to_owned(), "mixed".to_owned()],
+                closed: true,
+            }),
+            AriaPropertyValueKind::OptionalBool => AttributeType::OptionalBool,
+            // IDs are plain strings.
+            AriaPropertyValueKind::IdRef => AttributeType::String,
+            AriaPropertyValueKind::IdRefList => {
+                AttributeType::List(Box::new(AttributeType::String))
+            }
+            AriaPropertyValueKind::Integer => AttributeType::Integer,
+            AriaPropertyValueKind::Number => AttributeType::Float,
+            AriaPropertyValueKind::String => AttributeType::String,
+            // Leaving the attribute out already means `undefined`.
+            AriaPropertyValueKind::Token(tokens) => AttributeType::Enumerable(enumeration(
+                tokens
+                    .into_iter()
+                    .filter(|token| token != "undefined")
+                    .collect(),
+            )),
+            // Combinations of tokens such as `additions text` are listed
+            // alongside the tokens themselves.
+            AriaPropertyValueKind::TokenList(tokens) => {
+                AttributeType::List(Box::new(AttributeType::Enumerable(enumeration(
+                    tokens
+                        .into_iter()
+                        .filter(|token| !token.contains(' '))
+                        .collect(),
+                ))))
+            }
+        };
         Attribute {
             name: value.name,
             description: value.description,
             field_name,
-            ty: value.value_kind.into(),
+            ty,
         }
     }
 }
@@ -54,29 +97,13 @@ pub enum AriaPropertyValueKind {
     TokenList(Vec<String>),
 }
 
-impl From<AriaPropertyValueKind> for AttributeType {
-    fn from(value: AriaPropertyValueKind) -> Self {
-        match value {
-            AriaPropertyValueKind::Bool => AttributeType::Bool,
-            AriaPropertyValueKind::Tristate => AttributeType::String, // TODO: AttributeType::Enumerable
-            AriaPropertyValueKind::OptionalBool => AttributeType::Bool,
-            AriaPropertyValueKind::IdRef => AttributeType::String, // TODO: AttributeType::Identifier
-            AriaPropertyValueKind::IdRefList => AttributeType::String, // TODO: AttributeType::Vec?
-            AriaPropertyValueKind::Integer => AttributeType::Integer,
-            AriaPropertyValueKind::Number => AttributeType::Float,
-            AriaPropertyValueKind::String => AttributeType::String,
-            AriaPropertyValueKind::Token(_) => AttributeType::String, // TODO: AttributeType::Enumerable
-            AriaPropertyValueKind::TokenList(_) => AttributeType::String, // TODO: AttributeType::Vec?
-        }
-    }
-}
-
 /// The parsed WebIDL definitions converted from the raw spec.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParsedAriaElement {
     pub tag_name: String,
     pub any_role: bool,
     pub no_role: bool,
+    pub implicit_roles: BTreeSet<String>,
     pub allowed_roles: BTreeSet<String>,
     pub global_aria_attributes: bool,
     pub no_aria_attributes: bool,
@@ -98,13 +125,18 @@ pub fn parse_aria_roles(
         let allowed_properties = role
             .required
             .into_iter()
-            .chain(role.inherited.into_iter())
-            .chain(role.properties.into_iter())
+            .chain(role.inherited)
+            .chain(role.properties)
             .collect();
 
+        let name_from_contents = role
+            .name_from
+            .is_some_and(|name_from| name_from.split_whitespace().any(|from| from == "contents"));
+
         output.push(ParsedAriaRole {
             name: role.name,
             allowed_properties,
+            name_from_contents,
         });
     }
 
@@ -199,6 +231,7 @@ pub fn parse_aria_elements(
                     tag_name: tag_name.to_owned(),
                     any_role: false,
                     no_role: true,
+                    implicit_roles: BTreeSet::new(),
                     allowed_roles: BTreeSet::new(),
                     global_aria_attributes: false,
                     no_aria_attributes: true,
@@ -217,12 +250,21 @@ pub fn parse_aria_elements(
                 .iter()
                 .any(|x| x == "No `aria-*` attributes" || x == "No `role` or `aria-*` attributes");
 
-            let mut allowed_roles =
+            let implicit_roles =
                 BTreeSet::from_iter(el.implicit_roles.iter().map(|x| x.replace('`', "")));
+            let mut allowed_roles = implicit_roles.clone();
             allowed_roles.extend(el.allowed_roles.iter().map(|x| x.replace('`', "")));
 
+            // Some properties are only allowed with a value, such as
+            // `aria-hidden="true"` on `<img>`.
             let allowed_aria_attributes =
-                BTreeSet::from_iter(el.allowed_properties.iter().map(|x| x.replace('`', "")));
+                BTreeSet::from_iter(el.allowed_properties.iter().map(|x| {
+                    let x = x.replace('`', "");
+                    match x.split_once('=') {
+                        Some((name, _)) => name.to_owned(),
+                        None => x,
+                    }
+                }));
             let mut prohibited_aria_attributes =
                 if el.links.iter().any(|x| x == "Naming Prohibited") {
                     BTreeSet::from([
@@ -256,10 +298,11 @@ pub fn parse_aria_elements(
             parsed.any_role |= any_role;
             parsed.global_aria_attributes |= global_aria_attributes;
             parsed.no_aria_attributes &= no_aria_attributes || !allowed_aria_attributes.is_empty();
-            parsed.allowed_roles.extend(allowed_roles.into_iter());
+            parsed.implicit_roles.extend(implicit_roles);
+            parsed.allowed_roles.extend(allowed_roles);
             parsed
                 .allowed_aria_attributes
-                .extend(allowed_aria_attributes.into_iter());
+                .extend(allowed_aria_attributes);
 
             if !new {
                 prohibited_aria_attributes =
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use convert_case::{Case, Casing};
+use serde::{Deserialize, Serialize};
+
+use super::{parse_struct_name, AttributeType, Enumeration};
+use crate::Result;
+
+/// A row of the HTML spec's attribute index, as described in
+/// `resources/manual/attribute_index.json`.
+///
+/// The rows are transcribed from the spec by hand, and only cover the
+/// attributes which can take keywords. The values keep the spec's wording,
+/// e.g. `"lazy"; "eager"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualAttribute {
+    pub name: String,
+    pub elements: Vec<String>,
+    pub description: String,
+    pub value: String,
+}
+
+/// An attribute from the spec's attribute index whose value is one of a fixed
+/// set of keywords.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedAttribute {
+    pub name: String,
+    /// The tag names of the elements this definition applies to. This is empty
+    /// for global attributes.
+    pub elements: Vec<String>,
+    pub ty: AttributeType,
+}
+
+pub fn parse_attributes(manual: Vec<ManualAttribute>) -> Result<Vec<ParsedAttribute>> {
+    // Attributes such as `type` have a different meaning depending on the
+    // element they're used on. We need to know this to name their enums.
+    let mut definitions: HashMap<&str, usize> = HashMap::new();
+    for attr in &manual {
+        *definitions.entry(&attr.name).or_default() += 1;
+    }
+
+    let mut output = vec![];
+    for attr in &manual {
+        let keywords = match parse_keywords(&attr.value) {
+            Some(keywords) => keywords,
+            None => continue,
+        };
+        let elements = match attr.elements.as_slice() {
+            [global] if global == "HTML elements" => vec![],
+            elements => elements.to_vec(),
+        };
+        let is_ambiguous = definitions[attr.name.as_str()] > 1;
+        let name = parse_enum_name(&attr.name, &elements, is_ambiguous);
+        output.push(ParsedAttribute {
+            name: attr.name.clone(),
+            elements,
+            ty: AttributeType::Enumerable(Enumeration {
+                name,
+                keywords,
+                closed: false,
+            }),
+        });
+    }
+    Ok(output)
+}
+
+/// Parse a value such as `"lazy"; "eager"` into a list of keywords.
+///
+/// Values which aren't made up of keywords only, such as `"module"; a valid
+/// MIME type string`, return `None`.
+fn parse_keywords(value: &str) -> Option<Vec<String>> {
+    // Some keyword sets are defined elsewhere in the spec, and are only
+    // referred to by name in the index.
+    match value.to_lowercase().as_str() {
+        "input type keyword" => return Some(to_owned(INPUT_TYPES)),
+        "referrer policy" => return Some(to_owned(REFERRER_POLICIES)),
+        _ => {}
+    }
+
+    let mut keywords = vec![];
+    for keyword in value.split(';') {
+        let keyword = keyword.trim().strip_prefix('"')?.strip_suffix('"')?;
+        // The empty string is a valid value for some attributes, but we
+        // can't name a variant after it. It's still covered by `Custom`.
+        if !keyword.is_empty() {
+            keywords.push(keyword.to_owned());
+        }
+    }
+    match keywords.is_empty() {
+        true => None,
+        false => Some(keywords),
+    }
+}
+
+fn to_owned(keywords: &[&str]) -> Vec<String> {
+    keywords.iter().map(|s| s.to_string()).collect()
+}
+
+/// The keywords of the `type` attribute on `<input>`.
+const INPUT_TYPES: &[&str] = &[
+    "hidden",
+    "text",
+    "search",
+    "tel",
+    "url",
+    "email",
+    "password",
+    "date",
+    "month",
+    "week",
+    "time",
+    "datetime-local",
+    "number",
+    "range",
+    "color",
+    "checkbox",
+    "radio",
+    "file",
+    "submit",
+    "image",
+    "reset",
+    "button",
+];
+
+/// The referrer policies, as defined by the Referrer Policy spec.
+const REFERRER_POLICIES: &[&str] = &[
+    "no-referrer",
+    "no-referrer-when-downgrade",
+    "same-origin",
+    "origin",
+    "strict-origin",
+    "origin-when-cross-origin",
+    "strict-origin-when-cross-origin",
+    "unsafe-url",
+];
+
+/// Name the Rust enum which holds the keywords of an attribute.
+fn parse_enum_name(name: &str, elements: &[String], is_ambiguous: bool) -> String {
+    let base = match name {
+        "autocapitalize" => "AutoCapitalize".to_owned(),
+        "contenteditable" => "ContentEditable".to_owned(),
+        "crossorigin" => "CrossOrigin".to_owned(),
+        "dir" => "Direction".to_owned(),
+        "enctype" | "formenctype" => "FormEncoding".to_owned(),
+        "enterkeyhint" => "EnterKeyHint".to_owned(),
+        "fetchpriority" => "FetchPriority".to_owned(),
+        "formmethod" | "method" => "FormMethod".to_owned(),
+        "inputmode" => "InputMode".to_owned(),
+        "kind" => "TrackKind".to_owned(),
+        "popovertargetaction" => "PopoverTargetAction".to_owned(),
+        "referrerpolicy" => "ReferrerPolicy".to_owned(),
+        "shadowrootmode" => "ShadowRootMode".to_owned(),
+        other => other.to_case(Case::UpperCamel),
+    };
+
+    // Prefix the enums of attributes which are defined differently for
+    // different elements, e.g. `ButtonType` and `OrderedListType`.
+    match (is_ambiguous, elements.first()) {
+        (true, Some(tag_name)) => format!("{}{base}", parse_struct_name(tag_name)),
+        _ => base,
+    }
+}
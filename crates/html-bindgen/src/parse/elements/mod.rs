@@ -3,20 +3,26 @@ use crate::Result;
 use convert_case::{Case, Casing};
 use serde::{Deserialize, Serialize};
 
-use super::{Attribute, AttributeType, ParsedCategory, ParsedRelationship};
+use super::webidls::{event_handler_attribute, parse_interface_attributes};
+use super::{Attribute, AttributeType, Namespace, ParsedCategory, ParsedRelationship};
 use categories::parse_categories;
+use tags::parse_tag_omission;
+pub use tags::{EndTagOmission, FirstChild, StartTagOmission, TagOmission};
 
 mod categories;
+mod tags;
 
 /// The parsed values converted from the raw spec
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParsedElement {
     pub tag_name: String,
     pub struct_name: String,
+    pub namespace: Namespace,
     pub submodule_name: String,
     pub mdn_link: String,
     pub has_global_attributes: bool,
     pub has_closing_tag: bool,
+    pub tag_omission: TagOmission,
     pub attributes: Vec<Attribute>,
     pub dom_interface: String,
     pub content_categories: Vec<ParsedCategory>,
@@ -42,14 +48,18 @@ pub fn parse_elements(
     for scraped in scraped {
         let tag_name = scraped.tag_name;
         let struct_name = parse_struct_name(&tag_name);
-        let (has_global_attributes, attributes) = parse_attrs(scraped.content_attributes);
+        let interface_attributes = parse_dom_interface_attributes(&scraped.dom_interface)?;
+        let (has_global_attributes, attributes) =
+            parse_attrs(scraped.content_attributes, &interface_attributes);
         let dom_interface = parse_dom_interface(&scraped.dom_interface);
         let mut permitted_parents = parse_relationships(&scraped.contexts, &tag_names);
         append_super_categories(&mut permitted_parents);
         output.push(ParsedElement {
             struct_name,
+            namespace: Namespace::Html,
             dom_interface,
-            has_closing_tag: parse_tags(scraped.tag_omission),
+            has_closing_tag: parse_tags(&scraped.tag_omission),
+            tag_omission: parse_tag_omission(&scraped.tag_omission),
             attributes,
             has_global_attributes,
             submodule_name: parse_kinds(scraped.submodule_name),
@@ -141,25 +151,32 @@ pub fn parse_struct_name(tag_name: &str) -> String {
     }
 }
 
-fn parse_tags(input: Vec<String>) -> bool {
+fn parse_tags(input: &[String]) -> bool {
     let s = input.join("");
     match &*s {
         "Neither tag is omissible." | "" => true,
         "No end tag." => false,
-        // NOTE: There are a bunch of conditional cases which allow omitting end tags
-        // but for the sake of convenience we just don't bother with any of those.
-        // That's mostly important for parsers, which we're not defining here.
+        // The conditional cases which allow omitting tags are parsed by
+        // `parse_tag_omission`.
         _ => true,
     }
 }
 
-fn parse_attrs(content_attributes: Vec<String>) -> (bool, Vec<Attribute>) {
+fn parse_attrs(
+    content_attributes: Vec<String>,
+    interface_attributes: &[Attribute],
+) -> (bool, Vec<Attribute>) {
     let mut has_global_attributes = false;
     let mut output = vec![];
     for s in content_attributes {
         if s == "Global attributes" {
             has_global_attributes = true;
             continue;
+        } else if s.starts_with("on") && !s.contains(' ') {
+            // Event handlers such as `onafterprint` on `<body>` are listed
+            // without a description.
+            output.push(event_handler_attribute(&s));
+            continue;
         } else if !s.contains("—") {
             continue;
         }
@@ -175,8 +192,19 @@ fn parse_attrs(content_attributes: Vec<String>) -> (bool, Vec<Attribute>) {
         // Rename attributes which are labeled after keywords
         let field_name = super::normalize_field_name(&name);
 
+        // The spec only describes content attributes in prose. But boolean
+        // attributes are reflected by `boolean` IDL attributes of the same
+        // name, so we can find out about them through the DOM interface.
+        let is_boolean = interface_attributes
+            .iter()
+            .any(|other| other.ty == AttributeType::Bool && other.name.eq_ignore_ascii_case(&name));
+        let ty = match is_boolean {
+            true => AttributeType::Bool,
+            false => AttributeType::String,
+        };
+
         output.push(Attribute {
-            ty: AttributeType::String,
+            ty,
             name,
             description,
             field_name,
@@ -291,9 +319,25 @@ fn append_super_categories(cat_output: &mut Vec<ParsedRelationship>) {
     cat_output.dedup();
 }
 
+/// Find the attributes of the WebIDL interface defined by this element, if it
+/// defines one.
+fn parse_dom_interface_attributes(lines: &[String]) -> Result<Vec<Attribute>> {
+    let line = lines.first().unwrap();
+    if line.starts_with("Use") {
+        return Ok(vec![]);
+    }
+    // Some definitions are followed by a note about which other elements use
+    // the interface, so we stop after the last definition.
+    let idl = match line.rfind(';') {
+        Some(index) => &line[..=index],
+        None => line,
+    };
+    parse_interface_attributes(idl)
+}
+
 /// Find out which WebIDL interface this element relies on.
 fn parse_dom_interface(lines: &[String]) -> String {
-    let line = lines.get(0).as_deref().unwrap().clone();
+    let line = lines.first().unwrap().clone();
 
     if line.starts_with("Uses") {
         let line = line.strip_prefix("Uses").unwrap();
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+/// When the start and end tags of an element can be omitted.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TagOmission {
+    pub start: Option<StartTagOmission>,
+    pub end: Option<EndTagOmission>,
+}
+
+/// When the start tag of an element can be omitted. Start tags can only ever
+/// be omitted if the element has no attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StartTagOmission {
+    /// The start tag can be omitted if the element is empty.
+    pub if_empty: bool,
+    /// The start tag can be omitted if the first child matches.
+    pub first_child: Option<FirstChild>,
+    /// The start tag can't be omitted if the element is preceded by one of
+    /// these elements whose end tag has been omitted.
+    pub not_after_omitted: Vec<String>,
+}
+
+/// What the first child of an element has to be for its start tag to be
+/// omitted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FirstChild {
+    /// Any element.
+    Element,
+    /// One of these elements.
+    OneOf(Vec<String>),
+    /// Anything but a comment, text starting with whitespace if `whitespace`
+    /// is set, or one of these elements.
+    NoneOf {
+        whitespace: bool,
+        elements: Vec<String>,
+    },
+}
+
+/// When the end tag of an element can be omitted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EndTagOmission {
+    /// The end tag can be omitted unless the element is followed by a
+    /// comment, or by text starting with whitespace if `whitespace` is set.
+    UnlessFollowedBy { whitespace: bool },
+    /// The end tag can be omitted if the element is followed by one of the
+    /// `followed_by` elements, or if `last_child` is set and the element is
+    /// the last child of a parent which isn't one of the `parent_not`
+    /// elements.
+    Conditional {
+        followed_by: Vec<String>,
+        last_child: bool,
+        parent_not: Vec<String>,
+    },
+}
+
+/// Parse the "tag omission in text/html" prose of an element.
+///
+/// The prose follows a small number of sentence patterns, which we match on.
+/// Omitting a tag when it isn't allowed changes the meaning of a document, so
+/// if any part of a sentence isn't recognized we don't allow omitting the
+/// tag at all.
+pub(crate) fn parse_tag_omission(input: &[String]) -> TagOmission {
+    let text = input.join(" ");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut omission = TagOmission::default();
+    for sentence in text.split(". ") {
+        let sentence = sentence.trim_end_matches('.');
+        if let Some((_, condition)) = sentence.split_once("start tag can be omitted if ") {
+            omission.start = parse_start_tag_omission(condition);
+        } else if let Some((_, condition)) = sentence.split_once("end tag can be omitted if ") {
+            omission.end = parse_end_tag_omission(condition);
+        }
+    }
+    omission
+}
+
+fn parse_start_tag_omission(condition: &str) -> Option<StartTagOmission> {
+    let (condition, exception) = match condition.split_once(", except if ") {
+        Some((condition, exception)) => (condition, Some(exception)),
+        None => (condition, None),
+    };
+
+    let mut omission = StartTagOmission::default();
+    for clause in split_clauses(condition) {
+        if clause == "the element is empty" {
+            omission.if_empty = true;
+        } else if let Some((_, rest)) = clause.split_once("is not immediately preceded by ") {
+            let rest = rest.strip_suffix(" whose end tag has been omitted")?;
+            omission.not_after_omitted = parse_element_names(rest);
+        } else if let Some((_, rest)) = clause.split_once(" element is not ") {
+            let elements = match exception {
+                Some(exception) => parse_element_names(exception.split_once(" element is ")?.1),
+                None => vec![],
+            };
+            omission.first_child = Some(FirstChild::NoneOf {
+                whitespace: rest.contains("ASCII whitespace"),
+                elements,
+            });
+        } else if clause.ends_with(" element is an element") {
+            omission.first_child = Some(FirstChild::Element);
+        } else if let Some((_, rest)) = clause.split_once(" element is ") {
+            omission.first_child = Some(FirstChild::OneOf(parse_element_names(rest)));
+        } else {
+            return None;
+        }
+    }
+    Some(omission)
+}
+
+fn parse_end_tag_omission(condition: &str) -> Option<EndTagOmission> {
+    if condition.contains("is not immediately followed by ") {
+        return Some(EndTagOmission::UnlessFollowedBy {
+            whitespace: condition.contains("ASCII whitespace"),
+        });
+    }
+
+    let mut followed_by = vec![];
+    let mut last_child = false;
+    let mut parent_not = vec![];
+    for clause in split_clauses(condition) {
+        if let Some((_, rest)) = clause.split_once("immediately followed by ") {
+            followed_by.extend(parse_element_names(rest));
+        } else if clause.starts_with("there is no more content in the parent element") {
+            last_child = true;
+            if let Some((_, rest)) = clause.split_once(" that is not ") {
+                parent_not = parse_element_names(rest);
+            }
+        } else {
+            return None;
+        }
+    }
+    Some(EndTagOmission::Conditional {
+        followed_by,
+        last_child,
+        parent_not,
+    })
+}
+
+/// Split a condition into the clauses it's made of.
+fn split_clauses(condition: &str) -> impl Iterator<Item = &str> {
+    condition
+        .split(" or if ")
+        .map(|clause| clause.trim_end_matches(','))
+        .flat_map(|clause| clause.split(", and if "))
+        .flat_map(|clause| clause.split(", if "))
+}
+
+/// Parse a list of element names such as "a tbody, thead, or tfoot element".
+/// Items which aren't a single element name, such as "an autonomous custom
+/// element", are skipped.
+fn parse_element_names(list: &str) -> Vec<String> {
+    list.split(',')
+        .flat_map(|item| item.split(" or "))
+        .filter_map(|item| {
+            let words: Vec<_> = item.split_whitespace().collect();
+            let words = match words.as_slice() {
+                ["another" | "an" | "a", rest @ ..] if !rest.is_empty() => rest,
+                words => words,
+            };
+            let words = match words {
+                [rest @ .., "element"] => rest,
+                words => words,
+            };
+            match words {
+                [name] => Some(name.to_string()),
+                _ => None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use super::{
+    normalize_field_name, Attribute, AttributeType, Namespace, ParsedCategory, ParsedElement,
+    ParsedInterface, ParsedRelationship,
+};
+use crate::Result;
+use convert_case::{Case, Casing};
+use serde::{Deserialize, Serialize};
+
+/// The attributes which MathML Core defines on all MathML elements, in
+/// addition to the event handler attributes.
+const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "autofocus",
+    "class",
+    "dir",
+    "displaystyle",
+    "id",
+    "mathbackground",
+    "mathcolor",
+    "mathsize",
+    "nonce",
+    "scriptlevel",
+    "style",
+    "tabindex",
+];
+
+/// A MathML element, as described in `resources/manual/mathml_elements.json`.
+///
+/// MathML Core describes its elements in prose rather than in summary tables,
+/// so like the SVG elements these are maintained by hand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualMathMlElement {
+    pub tag_name: String,
+    /// The elements the element can contain. Besides element names this can
+    /// hold `MathML content`, `text`, and `flow content`.
+    pub content_model: Vec<String>,
+    /// The attributes of the element, without the global attributes.
+    pub attributes: Vec<String>,
+}
+
+/// Parse the MathML Core elements into the same form as the HTML elements.
+///
+/// Most MathML elements accept any "MathML content", which is every element
+/// except for `<math>` itself and the elements which can only be used inside
+/// of a specific parent, such as `<mtr>` and `<annotation>`. Permitted parents
+/// are derived from the content models of the other elements.
+pub fn parse_mathml_elements(
+    manual: Vec<ManualMathMlElement>,
+    parsed_interfaces: impl Iterator<Item = Result<ParsedInterface>>,
+) -> Result<Vec<ParsedElement>> {
+    let mut event_handlers = vec![];
+    for interface in parsed_interfaces {
+        let interface = interface?;
+        if interface.name == "GlobalEventHandlers" {
+            event_handlers = interface.attributes;
+        }
+    }
+
+    let restricted = manual
+        .iter()
+        .flat_map(|el| el.content_model.iter())
+        .filter(|item| !matches!(item.as_str(), "MathML content" | "text" | "flow content"))
+        .collect::<Vec<_>>();
+    let mathml_content = manual
+        .iter()
+        .map(|el| el.tag_name.as_str())
+        .filter(|tag_name| *tag_name != "math" && !restricted.iter().any(|item| item == tag_name))
+        .collect::<Vec<_>>();
+
+    let mut parents: BTreeMap<String, Vec<ParsedRelationship>> = BTreeMap::new();
+    let mut output = vec![];
+    for el in &manual {
+        let mut permitted_content = vec![];
+        for item in &el.content_model {
+            let children = match item.as_str() {
+                "text" => {
+                    permitted_content.push(ParsedRelationship::Element("Text".to_owned()));
+                    continue;
+                }
+                // `<annotation-xml>` is an HTML integration point, so it holds
+                // HTML content.
+                "flow content" => {
+                    permitted_content.push(ParsedRelationship::Category(ParsedCategory::Flow));
+                    continue;
+                }
+                "MathML content" => mathml_content.clone(),
+                item => vec![item],
+            };
+            for child in children {
+                let relationship =
+                    ParsedRelationship::Element(parse_mathml_struct_name(&el.tag_name));
+                parents
+                    .entry(child.to_owned())
+                    .or_default()
+                    .push(relationship);
+                permitted_content
+                    .push(ParsedRelationship::Element(parse_mathml_struct_name(child)));
+            }
+        }
+
+        let mut attributes = GLOBAL_ATTRIBUTES
+            .iter()
+            .copied()
+            .chain(el.attributes.iter().map(String::as_str))
+            .map(parse_mathml_attribute)
+            .collect::<Vec<_>>();
+        attributes.extend(event_handlers.iter().cloned());
+
+        // The `<math>` element is the only MathML element which can be used
+        // in HTML content, where it's flow, phrasing, embedded, and palpable
+        // content.
+        let (content_categories, permitted_parents) = match el.tag_name.as_str() {
+            "math" => (
+                vec![
+                    ParsedCategory::Flow,
+                    ParsedCategory::Phrasing,
+                    ParsedCategory::Embedded,
+                    ParsedCategory::Palpable,
+                ],
+                vec![
+                    ParsedRelationship::Category(ParsedCategory::Flow),
+                    ParsedRelationship::Category(ParsedCategory::Phrasing),
+                ],
+            ),
+            _ => (vec![], vec![]),
+        };
+
+        output.push(ParsedElement {
+            tag_name: el.tag_name.clone(),
+            struct_name: parse_mathml_struct_name(&el.tag_name),
+            namespace: Namespace::MathMl,
+            submodule_name: "mathml".to_owned(),
+            mdn_link: format!(
+                "https://developer.mozilla.org/en-US/docs/Web/MathML/Element/{}",
+                el.tag_name
+            ),
+            has_global_attributes: false,
+            has_closing_tag: true,
+            tag_omission: Default::default(),
+            attributes,
+            dom_interface: "MathMLElement".to_owned(),
+            content_categories,
+            permitted_parents,
+            permitted_content,
+        });
+    }
+
+    for el in output.iter_mut() {
+        if let Some(parents) = parents.remove(&el.tag_name) {
+            el.permitted_parents.extend(parents);
+        }
+    }
+    Ok(output)
+}
+
+/// MathML elements are named after their tag, e.g. `Mfrac` for `<mfrac>`.
+pub fn parse_mathml_struct_name(tag_name: &str) -> String {
+    tag_name.to_case(Case::UpperCamel)
+}
+
+fn parse_mathml_attribute(name: &str) -> Attribute {
+    let ty = match name {
+        "autofocus" => AttributeType::Bool,
+        "tabindex" => AttributeType::Integer,
+        _ => AttributeType::String,
+    };
+    Attribute {
+        name: name.to_owned(),
+        description: format!("The `{name}` attribute"),
+        field_name: normalize_field_name(name),
+        ty,
+    }
+}
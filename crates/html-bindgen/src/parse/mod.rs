@@ -1,13 +1,22 @@
 mod aria;
+mod attributes;
 mod elements;
+mod mathml;
+mod svg;
 mod webidls;
 
 pub use aria::{
     parse_aria_elements, parse_aria_properties, parse_aria_roles, ParsedAriaElement,
     ParsedAriaProperty, ParsedAriaRole,
 };
+pub use attributes::{parse_attributes, ManualAttribute, ParsedAttribute};
 use convert_case::{Case, Casing};
-pub use elements::{parse_elements, parse_struct_name, ParsedElement};
+pub use elements::{
+    parse_elements, parse_struct_name, EndTagOmission, FirstChild, ParsedElement, StartTagOmission,
+    TagOmission,
+};
+pub use mathml::{parse_mathml_elements, parse_mathml_struct_name, ManualMathMlElement};
+pub use svg::{parse_svg_elements, parse_svg_struct_name, ManualSvgElement};
 pub use webidls::{parse_webidls, ParsedInterface};
 
 use serde::{Deserialize, Serialize};
@@ -29,7 +38,24 @@ pub enum AttributeType {
     Integer,
     Float,
     Identifier(String),
-    Enumerable(Vec<String>),
+    Enumerable(Enumeration),
+    /// `true` or `false`, which is undefined when the attribute isn't set.
+    OptionalBool,
+    /// A space-separated list of values.
+    List(Box<AttributeType>),
+}
+
+/// A set of keywords which the value of an attribute can be one of.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Enumeration {
+    /// The name of the Rust enum.
+    pub name: String,
+    /// The keywords, in the order they are listed in the spec.
+    pub keywords: Vec<String>,
+    /// Whether values besides the keywords are rejected, rather than kept as
+    /// custom values.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub closed: bool,
 }
 
 impl Display for AttributeType {
@@ -39,8 +65,30 @@ impl Display for AttributeType {
             AttributeType::String => write!(f, "String"),
             AttributeType::Integer => write!(f, "i64"),
             AttributeType::Float => write!(f, "f64"),
-            AttributeType::Identifier(_) => todo!("identifier attr not yet implemented"),
-            AttributeType::Enumerable(_) => todo!("enum attr not yet implemented"),
+            AttributeType::Identifier(name) => write!(f, "{name}"),
+            AttributeType::Enumerable(enumeration) => write!(f, "{}", enumeration.name),
+            AttributeType::OptionalBool => write!(f, "bool"),
+            AttributeType::List(ty) => write!(f, "Vec<{ty}>"),
+        }
+    }
+}
+
+/// The namespace an element is defined in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Namespace {
+    #[default]
+    Html,
+    Svg,
+    MathMl,
+}
+
+impl Namespace {
+    /// The name of the markup language, as used in documentation.
+    pub fn language(&self) -> &'static str {
+        match self {
+            Namespace::Html => "HTML",
+            Namespace::Svg => "SVG",
+            Namespace::MathMl => "MathML",
         }
     }
 }
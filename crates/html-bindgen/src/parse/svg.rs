@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::webidls::event_handler_attribute;
+use super::{
+    Attribute, AttributeType, Namespace, ParsedCategory, ParsedElement, ParsedRelationship,
+};
+use crate::Result;
+use convert_case::{Boundary, Case, Casing};
+use serde::{Deserialize, Serialize};
+
+/// An SVG element, as described in `resources/manual/svg_elements.json`.
+///
+/// The elements are transcribed by hand from the element summaries of the
+/// SVG 2 spec, using the spec's own wording for categories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualSvgElement {
+    pub tag_name: String,
+    pub categories: Vec<String>,
+    /// The categories and elements the element can contain. The prose of the
+    /// content model is reduced to the markers `any elements`,
+    /// `character data`, and `transparent`.
+    pub content_model: Vec<String>,
+    /// The attributes of the element, with attribute groups such as the
+    /// "core attributes" expanded into their members.
+    pub attributes: Vec<String>,
+    pub dom_interface: String,
+}
+
+/// Parse the elements of the SVG spec into the same form as the HTML elements.
+///
+/// SVG describes which elements may contain each other through its own set
+/// of element categories, such as "shape elements". We resolve those into
+/// lists of elements here, and derive each element's permitted parents from
+/// the content models of the other elements. Elements and categories which
+/// we don't generate bindings for, such as the animation and filter
+/// elements, are skipped.
+pub fn parse_svg_elements(manual: Vec<ManualSvgElement>) -> Result<Vec<ParsedElement>> {
+    let mut by_category: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for el in &manual {
+        for category in &el.categories {
+            // The content model refers to categories in the plural, e.g.
+            // "shape elements".
+            let category = format!("{category}s");
+            by_category
+                .entry(category)
+                .or_default()
+                .push(el.tag_name.clone());
+        }
+    }
+    let tag_names = manual
+        .iter()
+        .map(|el| el.tag_name.clone())
+        .collect::<BTreeSet<_>>();
+
+    let mut parents: BTreeMap<String, Vec<ParsedRelationship>> = BTreeMap::new();
+    let mut output = vec![];
+    for el in &manual {
+        let mut permitted_content = vec![];
+        for item in &el.content_model {
+            match item.as_str() {
+                "transparent" => permitted_content
+                    .push(ParsedRelationship::Category(ParsedCategory::Transparent)),
+                // Inside of HTML documents, elements in an integration point
+                // such as `<foreignObject>` are parsed as HTML.
+                "any elements" => {
+                    permitted_content.push(ParsedRelationship::Category(ParsedCategory::Flow))
+                }
+                "character data" => {
+                    permitted_content.push(ParsedRelationship::Element("Text".to_owned()))
+                }
+                item => {
+                    let children = match by_category.get(item) {
+                        Some(children) => children.clone(),
+                        None if tag_names.contains(item) => vec![item.to_owned()],
+                        None => continue,
+                    };
+                    for child in children {
+                        let relationship =
+                            ParsedRelationship::Element(parse_svg_struct_name(&el.tag_name));
+                        let child_parents = parents.entry(child.clone()).or_default();
+                        if !child_parents.contains(&relationship) {
+                            child_parents.push(relationship);
+                        }
+                        let relationship =
+                            ParsedRelationship::Element(parse_svg_struct_name(&child));
+                        if !permitted_content.contains(&relationship) {
+                            permitted_content.push(relationship);
+                        }
+                    }
+                }
+            }
+        }
+
+        output.push(ParsedElement {
+            tag_name: el.tag_name.clone(),
+            struct_name: parse_svg_struct_name(&el.tag_name),
+            namespace: Namespace::Svg,
+            submodule_name: "svg".to_owned(),
+            mdn_link: format!(
+                "https://developer.mozilla.org/en-US/docs/Web/SVG/Element/{}",
+                el.tag_name
+            ),
+            has_global_attributes: false,
+            has_closing_tag: true,
+            tag_omission: Default::default(),
+            attributes: parse_svg_attributes(&el.attributes),
+            dom_interface: el.dom_interface.clone(),
+            content_categories: parse_svg_content_categories(&el.tag_name),
+            permitted_parents: parse_svg_content_categories(&el.tag_name)
+                .into_iter()
+                .filter(|category| {
+                    matches!(category, ParsedCategory::Flow | ParsedCategory::Phrasing)
+                })
+                .map(ParsedRelationship::Category)
+                .collect(),
+            permitted_content,
+        });
+    }
+
+    for el in output.iter_mut() {
+        if let Some(parents) = parents.remove(&el.tag_name) {
+            el.permitted_parents.extend(parents);
+        }
+    }
+    Ok(output)
+}
+
+/// SVG elements share their tag name with HTML elements in a few cases, and
+/// `<text>` would conflict with text nodes. Those are prefixed with `Svg`.
+pub fn parse_svg_struct_name(tag_name: &str) -> String {
+    match tag_name {
+        "a" => "SvgAnchor".to_owned(),
+        "image" => "SvgImage".to_owned(),
+        "script" => "SvgScript".to_owned(),
+        "style" => "SvgStyle".to_owned(),
+        "text" => "SvgText".to_owned(),
+        "title" => "SvgTitle".to_owned(),
+        other => other.to_case(Case::UpperCamel),
+    }
+}
+
+/// The `<svg>` element is the only SVG element which can be used in HTML
+/// content. The HTML spec lists it as flow, phrasing, embedded, and palpable
+/// content.
+fn parse_svg_content_categories(tag_name: &str) -> Vec<ParsedCategory> {
+    match tag_name {
+        "svg" => vec![
+            ParsedCategory::Flow,
+            ParsedCategory::Phrasing,
+            ParsedCategory::Embedded,
+            ParsedCategory::Palpable,
+        ],
+        _ => vec![],
+    }
+}
+
+/// Parse the attributes of an SVG element.
+///
+/// The deprecated `xlink:` and `xml:` attributes are skipped, as are the ARIA
+/// attributes since we don't know which roles SVG elements can take yet.
+fn parse_svg_attributes(attributes: &[String]) -> Vec<Attribute> {
+    attributes
+        .iter()
+        .filter(|name| !name.contains(':') && !name.starts_with("aria-") && *name != "role")
+        .map(|name| {
+            if name.starts_with("on") {
+                return event_handler_attribute(name);
+            }
+            let ty = match name.as_str() {
+                "autofocus" => AttributeType::Bool,
+                "tabindex" => AttributeType::Integer,
+                _ => AttributeType::String,
+            };
+            Attribute {
+                name: name.clone(),
+                description: format!("The `{name}` attribute"),
+                field_name: parse_svg_field_name(name),
+                ty,
+            }
+        })
+        .collect()
+}
+
+/// SVG attribute names are either camel case, like `viewBox`, or hyphenated,
+/// like `stroke-width`. Unlike HTML attributes they can end in digits, as in
+/// `x1`, which we keep as part of the word.
+fn parse_svg_field_name(name: &str) -> String {
+    let field_name = name
+        .with_boundaries(&[Boundary::Hyphen, Boundary::LowerUpper])
+        .to_case(Case::Snake);
+    match field_name.as_str() {
+        "type" => "type_".to_owned(),
+        _ => field_name,
+    }
+}
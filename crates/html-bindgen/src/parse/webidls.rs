@@ -21,15 +21,15 @@ pub fn parse_webidls(
     for res in iter {
         let (string, path) = res?;
         let filename = path.file_name().unwrap().to_str().unwrap();
-        if !filename.starts_with("HTML") {
+        if !filename.starts_with("HTML") && !filename.ends_with("EventHandlers.webidl") {
             continue;
         }
         let string = string.trim();
-        let definitions = weedle::parse(&string).map_err(|err| err.to_string())?;
+        let definitions = weedle::parse(string).map_err(|err| err.to_string())?;
         let definitions = definitions.into_iter();
         for def in definitions {
-            if let weedle::Definition::Interface(interface) = def {
-                outputs.push(ParsedInterface {
+            match def {
+                weedle::Definition::Interface(interface) => outputs.push(ParsedInterface {
                     name: parse_interface_name(&interface),
                     inherits_from: parse_inheritance(&interface),
                     attributes: interface
@@ -38,13 +38,46 @@ pub fn parse_webidls(
                         .iter()
                         .filter_map(parse_attributes)
                         .collect::<Vec<_>>(),
-                });
+                }),
+                // Mixins such as `GlobalEventHandlers` hold the event handler
+                // attributes, which are shared between elements.
+                weedle::Definition::InterfaceMixin(mixin)
+                    if mixin.identifier.0.ends_with("EventHandlers") =>
+                {
+                    outputs.push(ParsedInterface {
+                        name: mixin.identifier.0.to_owned(),
+                        inherits_from: None,
+                        attributes: mixin
+                            .members
+                            .body
+                            .iter()
+                            .filter_map(parse_mixin_attributes)
+                            .collect::<Vec<_>>(),
+                    })
+                }
+                _ => {}
             }
         }
     }
     Ok(outputs)
 }
 
+/// Parse the attributes of the interfaces defined in a snippet of WebIDL, such
+/// as the "DOM interface" section of an element definition.
+pub(crate) fn parse_interface_attributes(string: &str) -> Result<Vec<Attribute>> {
+    let definitions = weedle::parse(string.trim()).map_err(|err| err.to_string())?;
+    let attributes = definitions
+        .iter()
+        .filter_map(|def| match def {
+            weedle::Definition::Interface(interface) => Some(&interface.members.body),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(parse_attributes)
+        .collect();
+    Ok(attributes)
+}
+
 fn parse_interface_name(interface: &weedle::InterfaceDefinition) -> String {
     interface.identifier.0.to_owned()
 }
@@ -55,6 +88,39 @@ fn parse_inheritance(interface: &weedle::InterfaceDefinition) -> Option<String>
         .map(|parent| parent.identifier.0.to_string())
 }
 
+fn parse_mixin_attributes(member: &weedle::mixin::MixinMember) -> Option<Attribute> {
+    match member {
+        weedle::mixin::MixinMember::Attribute(attr) => {
+            let name = attr.identifier.0;
+            let ty = match &attr.type_.type_ {
+                weedle::types::Type::Single(weedle::types::SingleType::NonAny(
+                    weedle::types::NonAnyType::Identifier(id),
+                )) => id.type_.0,
+                _ => return None,
+            };
+            match ty {
+                "EventHandler" | "OnErrorEventHandler" | "OnBeforeUnloadEventHandler" => {
+                    Some(event_handler_attribute(name))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Event handler content attributes hold the body of a JavaScript function,
+/// so they're typed as strings.
+pub(crate) fn event_handler_attribute(name: &str) -> Attribute {
+    let event = name.strip_prefix("on").unwrap_or(name);
+    Attribute {
+        name: name.to_owned(),
+        description: format!("Handler for the `{event}` event"),
+        field_name: super::normalize_field_name(name),
+        ty: AttributeType::String,
+    }
+}
+
 fn parse_attributes(member: &InterfaceMember) -> Option<Attribute> {
     if let InterfaceMember::Attribute(attr) = member {
         // NOTE: we're skipping over all DOM-only methods for now.
@@ -148,7 +148,7 @@ fn scrape_aria_roles(document: &scraper::Html) -> Result<Vec<ScrapedAriaRole>> {
         let parent = extract_vec(".role-parent code", element);
         let properties = extract_vec(".role-properties code", element);
         let related = extract_str(".role-related", element);
-        let required = extract_vec(".required-properties code", element);
+        let required = extract_vec(".role-required-properties code", element);
         let scope = extract_vec(".role-scope code", element);
 
         specs.push(ScrapedAriaRole {
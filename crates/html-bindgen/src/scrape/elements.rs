@@ -36,7 +36,7 @@ pub fn scrape_elements(spec: String) -> Result<Vec<ScrapedElement>> {
 
     let mut specs = vec![];
 
-    for element in document.select(&selector).into_iter() {
+    for element in document.select(&selector) {
         let tag_names = match extract_tag_names(element) {
             Some(tag_names) => tag_names,
             None => continue,
@@ -49,7 +49,7 @@ pub fn scrape_elements(spec: String) -> Result<Vec<ScrapedElement>> {
         let mut outputs: HashMap<String, Vec<String>> = HashMap::new();
         for child in element.children() {
             let el = child.value().as_element();
-            let tag_name = el.as_deref().unwrap().name();
+            let tag_name = el.unwrap().name();
             match tag_name {
                 "dt" => {
                     if current.is_some() {
@@ -72,7 +72,7 @@ pub fn scrape_elements(spec: String) -> Result<Vec<ScrapedElement>> {
 
         // Construct a raw spec item from the parsed data.
         for tag_name in tag_names {
-            let tag_omission = match outputs.get("Tag omission in text/html:").as_deref() {
+            let tag_omission = match outputs.get("Tag omission in text/html:") {
                 Some(vec) => vec.clone(),
                 None => vec![],
             };
@@ -80,20 +80,15 @@ pub fn scrape_elements(spec: String) -> Result<Vec<ScrapedElement>> {
             specs.push(ScrapedElement {
                 tag_name,
                 submodule_name: element_kind.clone(),
-                categories: outputs.get("Categories:").as_deref().unwrap().clone(),
+                categories: outputs.get("Categories:").unwrap().clone(),
                 contexts: outputs
                     .get("Contexts in which this element can be used:")
-                    .as_deref()
-                    .unwrap()
-                    .clone(),
-                content_model: outputs.get("Content model:").as_deref().unwrap().clone(),
-                content_attributes: outputs
-                    .get("Content attributes:")
-                    .as_deref()
                     .unwrap()
                     .clone(),
+                content_model: outputs.get("Content model:").unwrap().clone(),
+                content_attributes: outputs.get("Content attributes:").unwrap().clone(),
                 tag_omission,
-                dom_interface: outputs.get("DOM interface:").as_deref().unwrap().clone(),
+                dom_interface: outputs.get("DOM interface:").unwrap().clone(),
             });
         }
     }
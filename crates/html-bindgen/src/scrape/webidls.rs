@@ -23,7 +23,7 @@ pub fn scrape_webidls(spec: String) -> Result<Vec<ScrapedInterface>> {
     let selector = scraper::Selector::parse(".idl").unwrap();
 
     let mut specs = vec![];
-    for element in document.select(&selector).into_iter() {
+    for element in document.select(&selector) {
         let idl = element.text().map(|t| t.to_owned()).collect::<String>();
         let name = match extract_webidl_name(&idl) {
             Some(name) => name,
@@ -2,7 +2,7 @@ use crate::Result;
 
 /// Format generated Rust code prior to writing it.
 pub fn fmt(input: &str) -> Result<String> {
-    let syntax_tree = syn::parse_file(&input)?;
+    let syntax_tree = syn::parse_file(input)?;
     Ok(prettyplease::unparse(&syntax_tree))
     // Ok(input.into())
 }
@@ -15,7 +15,7 @@ pub fn fmt(input: &str) -> Result<String> {
 // NOTE: if this stops working or becomes erroneous, replace it
 // with a proper `weedle`-based extractor
 pub(crate) fn extract_webidl_name(idl: &str) -> Option<String> {
-    let name = (&idl).lines().find(|line| line.contains("interface"))?;
+    let name = idl.lines().find(|line| line.contains("interface"))?;
     let mut iter = name.split("interface");
     iter.next()?;
     let mut name = iter.next()?;
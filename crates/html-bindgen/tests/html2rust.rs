@@ -0,0 +1,178 @@
+#![cfg(feature = "cli")]
+
+use std::path::Path;
+
+use html_bindgen::convert::{Conversion, Converter};
+
+fn convert(source: &str) -> Conversion {
+    let resources = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../resources");
+    let converter = Converter::from_resources(&resources).unwrap();
+    converter.convert(source)
+}
+
+#[test]
+fn elements() {
+    let conversion = convert(
+        r#"
+        <!-- The cats -->
+        <ul class="cats">
+            <li>nori</li>
+            <!-- more to come -->
+            <li data-age="3"><a href="/chashu">chashu   the
+                cat</a></li>
+        </ul>
+        "#,
+    );
+    assert_eq!(
+        conversion.code,
+        r#"use html::text_content::UnorderedList;
+
+// The cats
+
+UnorderedList::builder()
+    .class("cats")
+    .list_item(|li| li.text("nori"))
+    .comment(" more to come ")
+    .list_item(|li| {
+        li.data("age", "3")
+            .anchor(|a| a.href("/chashu").text("chashu the cat"))
+    })
+    .build()
+"#
+    );
+    assert!(conversion.warnings.is_empty());
+}
+
+#[test]
+fn attributes() {
+    let conversion = convert(
+        r#"<form method="post"><input type="checkbox" checked="checked" tabindex="2" disabled></form>"#,
+    );
+    assert_eq!(
+        conversion.code,
+        r#"use html::attributes::FormMethod;
+use html::attributes::InputType;
+use html::forms::Form;
+
+Form::builder()
+    .method(FormMethod::Post)
+    .input(|input| {
+        input
+            .checked(true)
+            .disabled(true)
+            .type_(InputType::Checkbox)
+            .tab_index(2)
+    })
+    .build()
+"#
+    );
+    assert!(conversion.warnings.is_empty());
+}
+
+#[test]
+fn foreign_elements() {
+    let conversion =
+        convert(r##"<svg viewBox="0 0 8 8"><clipPath><use href="#a"/></clipPath></svg>"##);
+    assert_eq!(
+        conversion.code,
+        r##"use html::svg::Svg;
+
+Svg::builder()
+    .view_box("0 0 8 8")
+    .clip_path(|clip_path| clip_path.use_(|use_| use_.href("#a")))
+    .build()
+"##
+    );
+}
+
+#[test]
+fn rejected() {
+    let conversion = convert(r#"<ul><p>not a list item</p><li colour="red">nori</li></ul>"#);
+    assert_eq!(
+        conversion.code,
+        r#"use html::text_content::UnorderedList;
+
+UnorderedList::builder()
+    // html2rust: `<p>` is not a permitted child of `<ul>`
+    .list_item(|li| {
+        li
+            // html2rust: `<li>` has no attribute `colour`
+            .text("nori")
+    })
+    .build()
+"#
+    );
+    assert_eq!(
+        conversion.warnings,
+        [
+            "`<p>` is not a permitted child of `<ul>`",
+            "`<li>` has no attribute `colour`",
+        ]
+    );
+}
+
+#[test]
+fn roles() {
+    let conversion = convert(r#"<div role="navigation"><img role="banner" src="nori.png"></div>"#);
+    assert_eq!(
+        conversion.code,
+        r#"use html::attributes::Role;
+use html::text_content::Division;
+
+Division::builder()
+    .role(Role::Navigation)
+    .image(|img| {
+        img.src("nori.png")
+            // html2rust: the `role` attribute of `<img>` doesn't permit "banner"
+    })
+    .build()
+"#
+    );
+    assert_eq!(
+        conversion.warnings,
+        [r#"the `role` attribute of `<img>` doesn't permit "banner""#]
+    );
+}
+
+#[test]
+fn aria_properties() {
+    let conversion = convert(
+        r#"<nav aria-labelledby="title subtitle"><button aria-expanded="false" aria-pressed="maybe" aria-busy="false">menu</button></nav>"#,
+    );
+    assert_eq!(
+        conversion.code,
+        r#"use html::content::Navigation;
+
+Navigation::builder()
+    .aria_labelled_by_elements(["title", "subtitle"])
+    .button(|button| {
+        button
+            .aria_busy(false)
+            .aria_expanded(false)
+            // html2rust: the `aria-pressed` attribute of `<button>` doesn't permit "maybe"
+            .text("menu")
+    })
+    .build()
+"#
+    );
+}
+
+#[test]
+fn templates() {
+    let conversion = convert(r#"<div><template><p>nori</p></template></div>"#);
+    assert_eq!(
+        conversion.warnings,
+        ["the contents of `<template>` can't be converted"]
+    );
+}
+
+#[test]
+fn deeply_nested() {
+    // Each level of nesting used to double the time taken to render the code.
+    let depth = 40;
+    let source = format!("{}nori{}", "<div>".repeat(depth), "</div>".repeat(depth));
+    let conversion = convert(&source);
+    assert!(conversion.warnings.is_empty());
+    assert_eq!(conversion.code.matches(".division(").count(), depth - 1);
+    assert!(conversion.code.contains(r#".text("nori")"#));
+}
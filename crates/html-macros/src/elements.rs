@@ -0,0 +1,121 @@
+//! The element definitions the macro checks its input against.
+//!
+//! These are generated from the same data as the elements in the `html` crate,
+//! so the permitted children here match the variants of the `*Child` enums.
+
+use crate::generated::{ELEMENTS, GLOBAL_ATTRIBUTES};
+
+/// An element, and the module of the `html` crate it's exported from.
+pub(crate) struct Element {
+    pub(crate) tag_name: &'static str,
+    pub(crate) struct_name: &'static str,
+    pub(crate) module_name: &'static str,
+    pub(crate) has_closing_tag: bool,
+    pub(crate) has_global_attributes: bool,
+    /// The struct names of the permitted children, which includes `Text` if
+    /// the element can hold text.
+    pub(crate) permitted_children: &'static [&'static str],
+    /// The element's own attributes. This includes any global attributes
+    /// whose builder method has been renamed for this element.
+    pub(crate) attributes: &'static [Attribute],
+}
+
+/// An attribute, and the name of the builder method which sets it.
+pub(crate) struct Attribute {
+    pub(crate) name: &'static str,
+    pub(crate) method_name: &'static str,
+    pub(crate) kind: AttributeKind,
+}
+
+/// The type of value the builder method of an attribute takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttributeKind {
+    Bool,
+    String,
+    Integer,
+    Float,
+    /// An enumerated attribute, holding the name of the enum in
+    /// `html::attributes`.
+    Enumerable(&'static str),
+    /// An enumerated attribute which only takes its keywords, holding the
+    /// name of the enum and the keywords with the names of their variants.
+    Keywords(&'static str, &'static [(&'static str, &'static str)]),
+    /// `true` or `false`, for attributes which are undefined when unset.
+    OptionalBool,
+    /// A list of values separated by whitespace.
+    List(&'static AttributeKind),
+}
+
+impl Attribute {
+    pub(crate) const fn new(
+        name: &'static str,
+        method_name: &'static str,
+        kind: AttributeKind,
+    ) -> Self {
+        Self {
+            name,
+            method_name,
+            kind,
+        }
+    }
+}
+
+impl Element {
+    /// Find the element a tag refers to outside of any parent. HTML elements
+    /// take precedence over SVG and MathML elements with the same tag name.
+    pub(crate) fn root(tag_name: &str) -> Option<&'static Element> {
+        find_name(ELEMENTS.iter(), tag_name, |el| el.tag_name)
+    }
+
+    /// Find the permitted child element a tag refers to.
+    pub(crate) fn child(&self, tag_name: &str) -> Option<&'static Element> {
+        let children = self
+            .permitted_children
+            .iter()
+            .filter_map(|struct_name| ELEMENTS.iter().find(|el| el.struct_name == *struct_name));
+        find_name(children, tag_name, |el| el.tag_name)
+    }
+
+    /// Whether any element has the tag name, regardless of where it's used.
+    pub(crate) fn exists(tag_name: &str) -> bool {
+        Self::root(tag_name).is_some()
+    }
+
+    /// Whether the element can't hold any content, in which case it has no
+    /// closing tag.
+    pub(crate) fn is_void(tag_name: &str) -> bool {
+        let void_elements = ELEMENTS.iter().filter(|el| !el.has_closing_tag);
+        find_name(void_elements, tag_name, |el| el.tag_name).is_some()
+    }
+
+    pub(crate) fn has_children(&self) -> bool {
+        !self.permitted_children.is_empty()
+    }
+
+    pub(crate) fn permits_text(&self) -> bool {
+        self.permitted_children.contains(&"Text")
+    }
+
+    pub(crate) fn attribute(&self, name: &str) -> Option<&'static Attribute> {
+        let globals = match self.has_global_attributes {
+            true => GLOBAL_ATTRIBUTES,
+            false => &[],
+        };
+        find_name(self.attributes.iter().chain(globals), name, |attr| {
+            attr.name
+        })
+    }
+}
+
+/// Find an item by name. Names in HTML are case-insensitive, but SVG names
+/// such as `viewBox` are not, so exact matches take precedence.
+fn find_name<T: Copy>(
+    mut items: impl Iterator<Item = T> + Clone,
+    name: &str,
+    item_name: impl Fn(T) -> &'static str,
+) -> Option<T> {
+    items
+        .clone()
+        .find(|item| item_name(*item) == name)
+        .or_else(|| items.find(|item| item_name(*item).eq_ignore_ascii_case(name)))
+}
@@ -0,0 +1,292 @@
+//! Check the tree against the element definitions, and expand it into calls to
+//! the element builders of the `html` crate.
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Lit, LitStr, Result};
+
+use crate::elements::{AttributeKind, Element as ElementDefinition};
+use crate::node::{Attribute, AttributeValue, Element, Else, For, If, Name, Node, Root};
+
+pub(crate) fn expand(root: Root) -> Result<TokenStream> {
+    let Root(el) = root;
+    let definition = ElementDefinition::root(&el.name.value).ok_or_else(|| {
+        el.name
+            .error(format_args!("unknown element `<{}>`", el.name.value))
+    })?;
+    expand_element(&el, definition)
+}
+
+/// Expand an element into a block which evaluates to the built element.
+fn expand_element(el: &Element, definition: &'static ElementDefinition) -> Result<TokenStream> {
+    // The builder can't be named by the expressions which are interpolated
+    // into the tree.
+    let builder = Ident::new("builder", Span::mixed_site());
+    let module_name = Ident::new(definition.module_name, Span::call_site());
+    let struct_name = Ident::new(definition.struct_name, el.name.span);
+
+    let attributes = el
+        .attributes
+        .iter()
+        .map(|attr| expand_attribute(attr, &builder, definition))
+        .collect::<Result<Vec<_>>>()?;
+    let children = expand_children(&el.children, &builder, definition)?;
+
+    Ok(quote! {
+        {
+            let mut #builder = ::html::#module_name::#struct_name::builder();
+            #(#attributes)*
+            #children
+            #builder.build()
+        }
+    })
+}
+
+fn expand_children(
+    nodes: &[Node],
+    builder: &Ident,
+    parent: &'static ElementDefinition,
+) -> Result<TokenStream> {
+    nodes
+        .iter()
+        .map(|node| expand_child(node, builder, parent))
+        .collect()
+}
+
+fn expand_child(
+    node: &Node,
+    builder: &Ident,
+    parent: &'static ElementDefinition,
+) -> Result<TokenStream> {
+    // Text which only contains whitespace can't be avoided when formatting
+    // HTML by hand, so it's only kept where text is permitted.
+    if let Node::Text(text) = node {
+        if !parent.permits_text() && text.value.value().trim().is_empty() {
+            return Ok(TokenStream::new());
+        }
+    }
+
+    if !parent.has_children() {
+        return Err(node.error(format_args!(
+            "`<{}>` can't have any children",
+            parent.tag_name
+        )));
+    }
+
+    match node {
+        Node::Element(el) => {
+            let definition = parent.child(&el.name.value).ok_or_else(|| {
+                let message = match ElementDefinition::exists(&el.name.value) {
+                    true => format!(
+                        "`<{}>` is not a permitted child of `<{}>`",
+                        el.name.value, parent.tag_name
+                    ),
+                    false => format!("unknown element `<{}>`", el.name.value),
+                };
+                el.name.error(message)
+            })?;
+            let el = expand_element(el, definition)?;
+            Ok(quote! { #builder.push(#el); })
+        }
+        Node::Text(text) => match parent.permits_text() {
+            true => {
+                let value = &text.value;
+                Ok(quote! { #builder.text(#value); })
+            }
+            false => Err(text.error(format_args!("`<{}>` can't contain text", parent.tag_name))),
+        },
+        Node::Expr(expr) => Ok(quote_spanned! { expr.span() => #builder.push(#expr); }),
+        Node::Spread(expr) => Ok(quote_spanned! { expr.span() => #builder.extend(#expr); }),
+        Node::If(node) => expand_if(node, builder, parent),
+        Node::For(For {
+            for_token,
+            pat,
+            expr,
+            body,
+        }) => {
+            let body = expand_children(body, builder, parent)?;
+            Ok(quote! { #for_token #pat in #expr { #body } })
+        }
+    }
+}
+
+fn expand_if(
+    node: &If,
+    builder: &Ident,
+    parent: &'static ElementDefinition,
+) -> Result<TokenStream> {
+    let If {
+        if_token,
+        cond,
+        then_branch,
+        else_branch,
+    } = node;
+    let then_branch = expand_children(then_branch, builder, parent)?;
+    let else_branch = match else_branch {
+        Some(Else::If(node)) => {
+            let node = expand_if(node, builder, parent)?;
+            quote! { else #node }
+        }
+        Some(Else::Block(nodes)) => {
+            let nodes = expand_children(nodes, builder, parent)?;
+            quote! { else { #nodes } }
+        }
+        None => TokenStream::new(),
+    };
+    Ok(quote! { #if_token #cond { #then_branch } #else_branch })
+}
+
+/// Expand an attribute into a call to its builder method.
+fn expand_attribute(
+    attr: &Attribute,
+    builder: &Ident,
+    definition: &'static ElementDefinition,
+) -> Result<TokenStream> {
+    let Attribute { name, value } = attr;
+
+    if let Some(key) = name.value.strip_prefix("data-") {
+        let value = match value {
+            Some(AttributeValue::Lit(Lit::Str(lit))) => quote! { #lit },
+            Some(AttributeValue::Expr(expr)) => quote! { #expr },
+            _ => return Err(name.error("expected a string value")),
+        };
+        return Ok(quote! { #builder.data(#key, #value); });
+    }
+
+    let definition = definition.attribute(&name.value).ok_or_else(|| {
+        name.error(format_args!(
+            "`<{}>` has no attribute `{}`",
+            definition.tag_name, name.value
+        ))
+    })?;
+    let method_name = Ident::new(definition.method_name, name.span);
+
+    let value = match (definition.kind, value) {
+        (_, Some(AttributeValue::Expr(expr))) => quote! { #expr },
+        (AttributeKind::Bool, None) => quote! { true },
+        (kind, Some(AttributeValue::Lit(lit))) => expand_literal(name, kind, lit)?,
+        (_, None) => {
+            return Err(name.error(format_args!(
+                "the `{}` attribute requires a value",
+                name.value
+            )))
+        }
+    };
+
+    Ok(quote! { #builder.#method_name(#value); })
+}
+
+/// Expand a literal into a value of the type an attribute's builder method
+/// takes.
+fn expand_literal(name: &Name, kind: AttributeKind, lit: &Lit) -> Result<TokenStream> {
+    Ok(match (kind, lit) {
+        (AttributeKind::Bool, Lit::Bool(lit)) => quote! { #lit },
+        // ARIA states are written as `"true"` or `"false"`, whereas in HTML
+        // the presence of a boolean attribute means it's set, whatever its
+        // value is.
+        (AttributeKind::Bool, Lit::Str(lit)) if name.value.starts_with("aria-") => {
+            let value = lit.value() != "false";
+            quote! { #value }
+        }
+        (AttributeKind::Bool, Lit::Str(_)) => quote! { true },
+        (AttributeKind::Bool, lit) => return Err(name.error_at(lit.span(), "expected a boolean")),
+        (AttributeKind::OptionalBool, Lit::Bool(lit)) => quote! { #lit },
+        (AttributeKind::OptionalBool, lit) => {
+            let value = match lit {
+                Lit::Str(lit) => lit.value().trim().parse::<bool>().ok(),
+                _ => None,
+            };
+            match value {
+                Some(value) => quote! { #value },
+                None => return Err(name.error_at(lit.span(), "expected `true` or `false`")),
+            }
+        }
+        (AttributeKind::String, lit) => {
+            let lit = match lit {
+                Lit::Str(lit) => lit.clone(),
+                Lit::Int(lit) => LitStr::new(lit.base10_digits(), lit.span()),
+                Lit::Float(lit) => LitStr::new(lit.base10_digits(), lit.span()),
+                lit => return Err(name.error_at(lit.span(), "expected a string")),
+            };
+            quote! { #lit }
+        }
+        (AttributeKind::Integer, lit) => {
+            let value = match lit {
+                Lit::Int(lit) => lit.base10_parse::<i64>()?,
+                Lit::Str(lit) => lit
+                    .value()
+                    .trim()
+                    .parse::<i64>()
+                    .map_err(|_| name.error_at(lit.span(), "expected an integer"))?,
+                lit => return Err(name.error_at(lit.span(), "expected an integer")),
+            };
+            let lit = Literal::i64_unsuffixed(value);
+            quote! { #lit }
+        }
+        (AttributeKind::Float, lit) => {
+            let value = match lit {
+                Lit::Int(lit) => lit.base10_parse::<f64>()?,
+                Lit::Float(lit) => lit.base10_parse::<f64>()?,
+                Lit::Str(lit) => lit
+                    .value()
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| name.error_at(lit.span(), "expected a number"))?,
+                lit => return Err(name.error_at(lit.span(), "expected a number")),
+            };
+            let lit = Literal::f64_suffixed(value);
+            quote! { #lit }
+        }
+        // Enumerated attributes accept any value besides their keywords, so
+        // parsing them can't fail.
+        (AttributeKind::Enumerable(enum_name), Lit::Str(lit)) => {
+            let enum_name = Ident::new(enum_name, lit.span());
+            quote! {
+                match <::html::attributes::#enum_name as ::core::str::FromStr>::from_str(#lit) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(err) => match err {},
+                }
+            }
+        }
+        // Other enumerated attributes only take their keywords, which are
+        // checked up front.
+        (AttributeKind::Keywords(enum_name, keywords), Lit::Str(lit)) => {
+            let value = lit.value();
+            let (_, variant) = keywords
+                .iter()
+                .find(|(keyword, _)| keyword.eq_ignore_ascii_case(&value))
+                .ok_or_else(|| {
+                    name.error_at(
+                        lit.span(),
+                        format_args!(
+                            "`{value}` is not a permitted value of the `{}` attribute",
+                            name.value
+                        ),
+                    )
+                })?;
+            let enum_name = Ident::new(enum_name, lit.span());
+            let variant = Ident::new(variant, lit.span());
+            quote! { ::html::attributes::#enum_name::#variant }
+        }
+        // The values of lists are separated by whitespace.
+        (AttributeKind::List(item_kind), Lit::Str(lit)) => {
+            let items = lit
+                .value()
+                .split_ascii_whitespace()
+                .map(|item| {
+                    let item = Lit::Str(LitStr::new(item, lit.span()));
+                    expand_literal(name, *item_kind, &item)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if items.is_empty() {
+                return Err(name.error_at(lit.span(), "expected at least one value"));
+            }
+            quote! { [#(#items),*] }
+        }
+        (
+            AttributeKind::Enumerable(_) | AttributeKind::Keywords(..) | AttributeKind::List(_),
+            lit,
+        ) => return Err(name.error_at(lit.span(), "expected a string")),
+    })
+}
@@ -0,0 +1,225 @@
+//! Read an HTML file into a tree of nodes, for the `include_html!` macro.
+//!
+//! The file is split into tokens by `html5ever`, but the tree is built without
+//! the error recovery of the HTML tree builder: every element has to be closed
+//! explicitly, unless it's a void element or written as a self-closing tag.
+//! This way what's in the file is what ends up in the tree, and any mistakes
+//! are reported rather than silently corrected.
+
+use std::fmt::Display;
+use std::path::Path;
+
+use html5ever::tendril::StrTendril;
+use html5ever::tokenizer::states::RawKind;
+use html5ever::tokenizer::{
+    BufferQueue, TagKind, Token, TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts,
+};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Lit, LitStr, Result};
+
+use crate::elements::Element as ElementDefinition;
+use crate::node::{Attribute, AttributeValue, Element, Name, Node, Root, Text};
+
+pub(crate) fn include_html(path: LitStr) -> Result<TokenStream> {
+    // Proc macros can't find out which file they're invoked from, so unlike
+    // `include_str!` paths are relative to the crate root.
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| Error::new(path.span(), "`CARGO_MANIFEST_DIR` is not set"))?;
+    let full_path = Path::new(&manifest_dir).join(path.value());
+    let source = std::fs::read_to_string(&full_path).map_err(|err| {
+        let message = format!("couldn't read `{}`: {err}", full_path.display());
+        Error::new(path.span(), message)
+    })?;
+
+    let root = parse(&source, &path.value(), path.span())?;
+    let element = crate::expand::expand(root)?;
+
+    // Including the file makes sure the crate is rebuilt when it changes.
+    let full_path = full_path.to_string_lossy();
+    Ok(quote! {
+        {
+            const _: &str = ::core::include_str!(#full_path);
+            #element
+        }
+    })
+}
+
+/// Parse the contents of a file into its root element.
+fn parse(source: &str, file: &str, span: Span) -> Result<Root> {
+    let sink = Sink {
+        file: file.to_owned(),
+        span,
+        stack: vec![],
+        roots: vec![],
+        text: String::new(),
+        text_line: 0,
+        error: None,
+    };
+    let mut input = BufferQueue::new();
+    input.push_back(StrTendril::from_slice(source));
+    let mut tokenizer = Tokenizer::new(sink, TokenizerOpts::default());
+    let _ = tokenizer.feed(&mut input);
+    tokenizer.end();
+
+    let Sink { error, roots, .. } = tokenizer.sink;
+    if let Some(error) = error {
+        return Err(error);
+    }
+    let mut roots = roots.into_iter();
+    match (roots.next(), roots.next()) {
+        (Some(root), None) => Ok(Root(root)),
+        (Some(_), Some(el)) => Err(el.name.error("expected a single root element")),
+        (None, _) => Err(Error::new(
+            span,
+            format!("`{file}` doesn't contain an element"),
+        )),
+    }
+}
+
+struct Sink {
+    file: String,
+    /// The span of the path in the macro input, which all errors point at.
+    span: Span,
+    /// The elements which have been opened, but not yet closed.
+    stack: Vec<Element>,
+    /// The elements which have been closed at the top level.
+    roots: Vec<Element>,
+    /// The text since the last tag, and the line it started on.
+    text: String,
+    text_line: u64,
+    /// The first error in the file.
+    error: Option<Error>,
+}
+
+impl Sink {
+    fn location(&self, line: u64) -> Option<String> {
+        Some(format!("{}:{line}", self.file))
+    }
+
+    fn name(&self, value: &str, line: u64) -> Name {
+        Name {
+            value: value.to_owned(),
+            span: self.span,
+            location: self.location(line),
+        }
+    }
+
+    fn report(&mut self, error: Error) {
+        self.error.get_or_insert(error);
+    }
+
+    fn report_at(&mut self, line: u64, message: impl Display) {
+        let error = Error::new(self.span, format!("{}:{line}: {message}", self.file));
+        self.report(error);
+    }
+
+    /// Append a closed element to its parent.
+    fn append(&mut self, el: Element) {
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(Node::Element(el)),
+            None => self.roots.push(el),
+        }
+    }
+
+    /// Append the text since the last tag to its parent.
+    fn flush_text(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+        let text = std::mem::take(&mut self.text);
+        let location = self.location(self.text_line);
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(Node::Text(Text {
+                value: LitStr::new(&text, self.span),
+                location,
+            })),
+            None if text.trim().is_empty() => {}
+            None => self.report_at(self.text_line, "text outside of the root element"),
+        }
+    }
+
+    /// The contents of some HTML elements are read as raw text, but not inside
+    /// of SVG and MathML, where they're regular elements.
+    fn raw_kind(&self, tag_name: &str) -> Option<RawKind> {
+        let is_foreign = self
+            .stack
+            .iter()
+            .any(|el| matches!(el.name.value.as_str(), "svg" | "math"));
+        match tag_name {
+            _ if is_foreign => None,
+            "script" => Some(RawKind::ScriptData),
+            "style" | "xmp" | "iframe" | "noembed" | "noframes" => Some(RawKind::Rawtext),
+            "title" | "textarea" => Some(RawKind::Rcdata),
+            _ => None,
+        }
+    }
+}
+
+impl TokenSink for Sink {
+    type Handle = ();
+
+    fn process_token(&mut self, token: Token, line: u64) -> TokenSinkResult<()> {
+        match token {
+            Token::CharacterTokens(text) => {
+                if self.text.is_empty() {
+                    self.text_line = line;
+                }
+                self.text.push_str(&text);
+            }
+            Token::TagToken(tag) if tag.kind == TagKind::StartTag => {
+                self.flush_text();
+                let attributes = tag
+                    .attrs
+                    .iter()
+                    .map(|attr| Attribute {
+                        name: self.name(&attr.name.local, line),
+                        value: Some(AttributeValue::Lit(Lit::Str(LitStr::new(
+                            &attr.value,
+                            self.span,
+                        )))),
+                    })
+                    .collect();
+                let el = Element {
+                    name: self.name(&tag.name, line),
+                    attributes,
+                    children: vec![],
+                };
+                if tag.self_closing || ElementDefinition::is_void(&tag.name) {
+                    self.append(el);
+                } else {
+                    let raw_kind = self.raw_kind(&tag.name);
+                    self.stack.push(el);
+                    if let Some(raw_kind) = raw_kind {
+                        return TokenSinkResult::RawData(raw_kind);
+                    }
+                }
+            }
+            Token::TagToken(tag) => {
+                self.flush_text();
+                match self.stack.pop() {
+                    Some(el) if el.name.value == *tag.name => self.append(el),
+                    Some(el) => self.report_at(
+                        line,
+                        format_args!("expected `</{}>`, found `</{}>`", el.name.value, tag.name),
+                    ),
+                    None => self.report_at(
+                        line,
+                        format_args!("unexpected closing tag `</{}>`", tag.name),
+                    ),
+                }
+            }
+            Token::ParseError(message) => self.report_at(line, message),
+            Token::EOFToken => {
+                self.flush_text();
+                if let Some(el) = self.stack.pop() {
+                    let message = format!("missing closing tag `</{}>`", el.name.value);
+                    self.report(el.name.error(message));
+                }
+            }
+            // Comments and doctypes aren't part of the element tree.
+            Token::CommentToken(_) | Token::DoctypeToken(_) | Token::NullCharacterToken => {}
+        }
+        TokenSinkResult::Continue
+    }
+}
@@ -0,0 +1,130 @@
+//! Procedural macros for the `html` crate.
+//!
+//! The [`html!`] macro writes element trees using HTML syntax, and expands to
+//! the element builders of the `html` crate. The tree is checked against the
+//! same content models as the `*Child` enums, so elements which aren't
+//! permitted in their parent are reported at compile time. The
+//! [`include_html!`] macro does the same for the contents of an HTML file.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs, future_incompatible, rust_2018_idioms)]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, LitStr};
+
+mod elements;
+mod expand;
+mod generated;
+mod include;
+mod node;
+
+/// Create an element using HTML syntax.
+///
+/// The macro takes a single root element, and evaluates to the typed element
+/// from the `html` crate. It expands to calls to the element's builder, so it
+/// doesn't require raising the `recursion_limit`.
+///
+/// - Text is written as string literals: `<p>"hello"</p>`.
+/// - Attributes take a literal or an expression: `class="x"` or `class={name}`.
+///   Boolean attributes can be written without a value: `<input disabled/>`.
+/// - `data-*` attributes are inserted into the element's dataset.
+/// - `{expr}` pushes a child, which can be anything that converts into the
+///   parent's child enum, such as an element or a string.
+/// - `{..iter}` pushes every child of an iterator.
+/// - `if` and `for` blocks hold children, and work like their Rust
+///   counterparts.
+///
+/// Void elements such as `<br>` don't need a closing tag, and any element can
+/// be written as a self-closing tag: `<path d="M 0 0"/>`.
+///
+/// # Examples
+///
+/// ```
+/// use html_macros::html;
+///
+/// let cats = ["nori", "chashu"];
+/// let show_count = true;
+/// let tree = html! {
+///     <div class="cats">
+///         if show_count {
+///             <p>{format!("{} cats", cats.len())}</p>
+///         }
+///         <ul>
+///             for cat in cats {
+///                 <li data-name={cat}>{cat}</li>
+///             }
+///         </ul>
+///     </div>
+/// };
+/// assert_eq!(
+///     tree.to_string(),
+///     r#"<div class="cats"><p>2 cats</p><ul><li data-name="nori">nori</li><li data-name="chashu">chashu</li></ul></div>"#,
+/// );
+/// ```
+///
+/// Children can be built separately, and spread into their parent:
+///
+/// ```
+/// use html_macros::html;
+///
+/// let items = ["a", "b"].map(|s| html! { <li>{s}</li> });
+/// let list = html! { <ol start=2>{..items}</ol> };
+/// assert_eq!(list.to_string(), r#"<ol start="2"><li>a</li><li>b</li></ol>"#);
+/// ```
+///
+/// Elements which aren't permitted in their parent are rejected:
+///
+/// ```compile_fail
+/// use html_macros::html;
+///
+/// let list = html! { <ul><p>"not a list item"</p></ul> };
+/// ```
+///
+/// And so are unknown attributes:
+///
+/// ```compile_fail
+/// use html_macros::html;
+///
+/// let div = html! { <div colour="red"></div> };
+/// ```
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    let root = parse_macro_input!(input as node::Root);
+    expand::expand(root)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Create an element from an HTML file.
+///
+/// The file is read at compile time, and expands to the same builder calls as
+/// the [`html!`] macro would. The path is relative to the root of the crate,
+/// which is the directory holding its `Cargo.toml`.
+///
+/// The file must hold a single root element, and every element has to be
+/// closed, unless it's a void element such as `<br>` or written as a
+/// self-closing tag. Whitespace is kept where text is permitted. Comments and
+/// doctypes are skipped.
+///
+/// Macros can't point into other files, so errors point at the path, and
+/// include the line of the file they occur on:
+///
+/// ```text
+/// error: partials/footer.html:4: `<li>` is not a permitted child of `<footer>`
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use html::content::Footer;
+/// use html_macros::include_html;
+///
+/// let footer: Footer = include_html!("partials/footer.html");
+/// ```
+#[proc_macro]
+pub fn include_html(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    include::include_html(path)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
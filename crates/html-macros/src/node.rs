@@ -0,0 +1,323 @@
+//! Parse the input of the `html!` macro into a tree of nodes.
+
+use proc_macro2::Span;
+use std::fmt::Display;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{braced, Error, Expr, Ident, Lit, LitStr, Pat, Result, Token};
+
+use crate::elements::Element as ElementDefinition;
+
+/// The single element at the root of the macro input.
+pub(crate) struct Root(pub(crate) Element);
+
+/// A node in the tree.
+pub(crate) enum Node {
+    /// A nested element: `<p>...</p>`.
+    Element(Element),
+    /// A string literal: `"hello"`.
+    Text(Text),
+    /// An interpolated expression: `{name}`.
+    Expr(Expr),
+    /// An iterator of children to spread: `{..items}`.
+    Spread(Expr),
+    /// A conditional block: `if cond { ... } else { ... }`.
+    If(If),
+    /// A loop: `for item in items { ... }`.
+    For(For),
+}
+
+pub(crate) struct Element {
+    pub(crate) name: Name,
+    pub(crate) attributes: Vec<Attribute>,
+    pub(crate) children: Vec<Node>,
+}
+
+/// A tag or attribute name, which can contain hyphens.
+pub(crate) struct Name {
+    pub(crate) value: String,
+    pub(crate) span: Span,
+    /// The file and line the name was read from, if it wasn't written in the
+    /// macro input.
+    pub(crate) location: Option<String>,
+}
+
+pub(crate) struct Text {
+    pub(crate) value: LitStr,
+    pub(crate) location: Option<String>,
+}
+
+pub(crate) struct Attribute {
+    pub(crate) name: Name,
+    pub(crate) value: Option<AttributeValue>,
+}
+
+pub(crate) enum AttributeValue {
+    /// A literal value: `class="x"`.
+    Lit(Lit),
+    /// An interpolated expression: `class={name}`.
+    Expr(Expr),
+}
+
+pub(crate) struct If {
+    pub(crate) if_token: Token![if],
+    pub(crate) cond: Expr,
+    pub(crate) then_branch: Vec<Node>,
+    pub(crate) else_branch: Option<Else>,
+}
+
+pub(crate) enum Else {
+    If(Box<If>),
+    Block(Vec<Node>),
+}
+
+pub(crate) struct For {
+    pub(crate) for_token: Token![for],
+    pub(crate) pat: Pat,
+    pub(crate) expr: Expr,
+    pub(crate) body: Vec<Node>,
+}
+
+impl Node {
+    /// Create an error about the node as a whole.
+    pub(crate) fn error(&self, message: impl Display) -> Error {
+        match self {
+            Node::Element(el) => el.name.error(message),
+            Node::Text(text) => text.error(message),
+            Node::Expr(expr) | Node::Spread(expr) => Error::new_spanned(expr, message),
+            Node::If(node) => Error::new(node.if_token.span, message),
+            Node::For(node) => Error::new(node.for_token.span, message),
+        }
+    }
+}
+
+impl Name {
+    /// Create an error about the name.
+    pub(crate) fn error(&self, message: impl Display) -> Error {
+        self.error_at(self.span, message)
+    }
+
+    /// Create an error which points at `span`, such as the value of an
+    /// attribute. Proc macros can't point into other files, so the location
+    /// of names read from a file is included in the message instead.
+    pub(crate) fn error_at(&self, span: Span, message: impl Display) -> Error {
+        match &self.location {
+            Some(location) => Error::new(span, format!("{location}: {message}")),
+            None => Error::new(span, message),
+        }
+    }
+}
+
+impl Text {
+    /// Create an error about the text.
+    pub(crate) fn error(&self, message: impl Display) -> Error {
+        match &self.location {
+            Some(location) => Error::new(self.value.span(), format!("{location}: {message}")),
+            None => Error::new(self.value.span(), message),
+        }
+    }
+}
+
+impl Parse for Root {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("expected a root element"));
+        }
+        let el = match input.parse()? {
+            Node::Element(el) => el,
+            node => return Err(node.error("expected a root element")),
+        };
+        if !input.is_empty() {
+            return Err(input.error("expected a single root element"));
+        }
+        Ok(Root(el))
+    }
+}
+
+impl Parse for Node {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Token![<]) {
+            if input.peek2(Token![/]) {
+                return Err(input.error("unexpected closing tag"));
+            }
+            input.parse().map(Node::Element)
+        } else if input.peek(LitStr) {
+            Ok(Node::Text(Text {
+                value: input.parse()?,
+                location: None,
+            }))
+        } else if input.peek(Lit) {
+            Err(input.error("text must be written as a string literal"))
+        } else if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            let node = match content.peek(Token![..]) {
+                true => {
+                    content.parse::<Token![..]>()?;
+                    Node::Spread(content.parse()?)
+                }
+                false => Node::Expr(content.parse()?),
+            };
+            match content.is_empty() {
+                true => Ok(node),
+                false => Err(content.error("expected a single expression")),
+            }
+        } else if input.peek(Token![if]) {
+            input.parse().map(Node::If)
+        } else if input.peek(Token![for]) {
+            input.parse().map(Node::For)
+        } else {
+            Err(input.error("expected an element, a string literal, `{...}`, `if`, or `for`"))
+        }
+    }
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        input.parse::<Token![<]>()?;
+        let name: Name = input.parse()?;
+
+        let mut attributes = vec![];
+        while !(input.peek(Token![>]) || input.peek(Token![/]) && input.peek2(Token![>])) {
+            if input.is_empty() {
+                return Err(name.error(format_args!("unclosed tag `<{}>`", name.value)));
+            }
+            attributes.push(input.parse()?);
+        }
+
+        // Self-closing tags and void elements don't have a closing tag.
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+            return Ok(Self {
+                name,
+                attributes,
+                children: vec![],
+            });
+        }
+        input.parse::<Token![>]>()?;
+        if ElementDefinition::is_void(&name.value) {
+            return Ok(Self {
+                name,
+                attributes,
+                children: vec![],
+            });
+        }
+
+        let mut children = vec![];
+        while !(input.peek(Token![<]) && input.peek2(Token![/])) {
+            if input.is_empty() {
+                return Err(name.error(format_args!("missing closing tag `</{}>`", name.value)));
+            }
+            children.push(input.parse()?);
+        }
+
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let closing: Name = input.parse()?;
+        if closing.value != name.value {
+            return Err(closing.error(format_args!("expected closing tag `</{}>`", name.value)));
+        }
+        input.parse::<Token![>]>()?;
+
+        Ok(Self {
+            name,
+            attributes,
+            children,
+        })
+    }
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        // Names such as `type` and `for` are Rust keywords, and names such as
+        // `aria-label` are made up of several tokens.
+        let first = Ident::parse_any(input)?;
+        let mut value = first.to_string();
+        let mut span = first.span();
+        while input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+            let ident = Ident::parse_any(input)?;
+            value.push('-');
+            value.push_str(&ident.to_string());
+            span = span.join(ident.span()).unwrap_or(span);
+        }
+        Ok(Self {
+            value,
+            span,
+            location: None,
+        })
+    }
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let name = input.parse()?;
+        if !input.peek(Token![=]) {
+            return Ok(Self { name, value: None });
+        }
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            AttributeValue::Expr(content.parse()?)
+        } else {
+            AttributeValue::Lit(input.parse()?)
+        };
+        Ok(Self {
+            name,
+            value: Some(value),
+        })
+    }
+}
+
+impl Parse for If {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let if_token = input.parse()?;
+        let cond = Expr::parse_without_eager_brace(input)?;
+        let then_branch = parse_block(input)?;
+        let else_branch = match input.peek(Token![else]) {
+            true => {
+                input.parse::<Token![else]>()?;
+                match input.peek(Token![if]) {
+                    true => Some(Else::If(Box::new(input.parse()?))),
+                    false => Some(Else::Block(parse_block(input)?)),
+                }
+            }
+            false => None,
+        };
+        Ok(Self {
+            if_token,
+            cond,
+            then_branch,
+            else_branch,
+        })
+    }
+}
+
+impl Parse for For {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let for_token = input.parse()?;
+        let pat = Pat::parse_multi_with_leading_vert(input)?;
+        input.parse::<Token![in]>()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+        let body = parse_block(input)?;
+        Ok(Self {
+            for_token,
+            pat,
+            expr,
+            body,
+        })
+    }
+}
+
+/// Parse the nodes inside of a `{ ... }` block.
+fn parse_block(input: ParseStream<'_>) -> Result<Vec<Node>> {
+    let content;
+    braced!(content in input);
+    let mut nodes = vec![];
+    while !content.is_empty() {
+        nodes.push(content.parse()?);
+    }
+    Ok(nodes)
+}
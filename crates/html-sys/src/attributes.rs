@@ -0,0 +1,5166 @@
+//! Typed values for enumerated attributes.
+/// An error returned when parsing a value which isn't one of the
+/// keywords of an enumerated attribute that doesn't take custom
+/// values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeywordError {
+    value: String,
+}
+impl ParseKeywordError {
+    /// The value which isn't one of the keywords.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+impl std::fmt::Display for ParseKeywordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a permitted keyword", self.value)
+    }
+}
+impl std::error::Error for ParseKeywordError {}
+/// The keywords of the `aria-autocomplete` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaAutoComplete {
+    /// The `inline` keyword
+    Inline,
+    /// The `list` keyword
+    List,
+    /// The `both` keyword
+    Both,
+    /// The `none` keyword
+    None,
+}
+impl AriaAutoComplete {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Inline => "inline",
+            Self::List => "list",
+            Self::Both => "both",
+            Self::None => "none",
+        }
+    }
+}
+impl std::fmt::Display for AriaAutoComplete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaAutoComplete {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("inline") => Self::Inline,
+                s if s.eq_ignore_ascii_case("list") => Self::List,
+                s if s.eq_ignore_ascii_case("both") => Self::Both,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaAutoComplete {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaAutoComplete {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-current` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaCurrent {
+    /// The `page` keyword
+    Page,
+    /// The `step` keyword
+    Step,
+    /// The `location` keyword
+    Location,
+    /// The `date` keyword
+    Date,
+    /// The `time` keyword
+    Time,
+    /// The `true` keyword
+    True,
+    /// The `false` keyword
+    False,
+}
+impl AriaCurrent {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Page => "page",
+            Self::Step => "step",
+            Self::Location => "location",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::True => "true",
+            Self::False => "false",
+        }
+    }
+}
+impl std::fmt::Display for AriaCurrent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaCurrent {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("page") => Self::Page,
+                s if s.eq_ignore_ascii_case("step") => Self::Step,
+                s if s.eq_ignore_ascii_case("location") => Self::Location,
+                s if s.eq_ignore_ascii_case("date") => Self::Date,
+                s if s.eq_ignore_ascii_case("time") => Self::Time,
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaCurrent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaCurrent {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-dropeffect` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaDropEffect {
+    /// The `copy` keyword
+    Copy,
+    /// The `execute` keyword
+    Execute,
+    /// The `link` keyword
+    Link,
+    /// The `move` keyword
+    Move,
+    /// The `none` keyword
+    None,
+    /// The `popup` keyword
+    Popup,
+}
+impl AriaDropEffect {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Copy => "copy",
+            Self::Execute => "execute",
+            Self::Link => "link",
+            Self::Move => "move",
+            Self::None => "none",
+            Self::Popup => "popup",
+        }
+    }
+}
+impl std::fmt::Display for AriaDropEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaDropEffect {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("copy") => Self::Copy,
+                s if s.eq_ignore_ascii_case("execute") => Self::Execute,
+                s if s.eq_ignore_ascii_case("link") => Self::Link,
+                s if s.eq_ignore_ascii_case("move") => Self::Move,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("popup") => Self::Popup,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaDropEffect {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaDropEffect {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-haspopup` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaHasPopup {
+    /// The `false` keyword
+    False,
+    /// The `true` keyword
+    True,
+    /// The `menu` keyword
+    Menu,
+    /// The `listbox` keyword
+    Listbox,
+    /// The `tree` keyword
+    Tree,
+    /// The `grid` keyword
+    Grid,
+    /// The `dialog` keyword
+    Dialog,
+}
+impl AriaHasPopup {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::False => "false",
+            Self::True => "true",
+            Self::Menu => "menu",
+            Self::Listbox => "listbox",
+            Self::Tree => "tree",
+            Self::Grid => "grid",
+            Self::Dialog => "dialog",
+        }
+    }
+}
+impl std::fmt::Display for AriaHasPopup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaHasPopup {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s if s.eq_ignore_ascii_case("listbox") => Self::Listbox,
+                s if s.eq_ignore_ascii_case("tree") => Self::Tree,
+                s if s.eq_ignore_ascii_case("grid") => Self::Grid,
+                s if s.eq_ignore_ascii_case("dialog") => Self::Dialog,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaHasPopup {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaHasPopup {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-invalid` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaInvalid {
+    /// The `grammar` keyword
+    Grammar,
+    /// The `false` keyword
+    False,
+    /// The `spelling` keyword
+    Spelling,
+    /// The `true` keyword
+    True,
+}
+impl AriaInvalid {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Grammar => "grammar",
+            Self::False => "false",
+            Self::Spelling => "spelling",
+            Self::True => "true",
+        }
+    }
+}
+impl std::fmt::Display for AriaInvalid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaInvalid {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("grammar") => Self::Grammar,
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s if s.eq_ignore_ascii_case("spelling") => Self::Spelling,
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaInvalid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaInvalid {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-live` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaLive {
+    /// The `assertive` keyword
+    Assertive,
+    /// The `off` keyword
+    Off,
+    /// The `polite` keyword
+    Polite,
+}
+impl AriaLive {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Assertive => "assertive",
+            Self::Off => "off",
+            Self::Polite => "polite",
+        }
+    }
+}
+impl std::fmt::Display for AriaLive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaLive {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("assertive") => Self::Assertive,
+                s if s.eq_ignore_ascii_case("off") => Self::Off,
+                s if s.eq_ignore_ascii_case("polite") => Self::Polite,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaLive {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaLive {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-orientation` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaOrientation {
+    /// The `horizontal` keyword
+    Horizontal,
+    /// The `vertical` keyword
+    Vertical,
+}
+impl AriaOrientation {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+impl std::fmt::Display for AriaOrientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaOrientation {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("horizontal") => Self::Horizontal,
+                s if s.eq_ignore_ascii_case("vertical") => Self::Vertical,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaOrientation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaOrientation {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-relevant` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaRelevant {
+    /// The `additions` keyword
+    Additions,
+    /// The `all` keyword
+    All,
+    /// The `removals` keyword
+    Removals,
+    /// The `text` keyword
+    Text,
+}
+impl AriaRelevant {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Additions => "additions",
+            Self::All => "all",
+            Self::Removals => "removals",
+            Self::Text => "text",
+        }
+    }
+}
+impl std::fmt::Display for AriaRelevant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaRelevant {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("additions") => Self::Additions,
+                s if s.eq_ignore_ascii_case("all") => Self::All,
+                s if s.eq_ignore_ascii_case("removals") => Self::Removals,
+                s if s.eq_ignore_ascii_case("text") => Self::Text,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaRelevant {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaRelevant {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `aria-sort` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaSort {
+    /// The `ascending` keyword
+    Ascending,
+    /// The `descending` keyword
+    Descending,
+    /// The `none` keyword
+    None,
+    /// The `other` keyword
+    Other,
+}
+impl AriaSort {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+            Self::None => "none",
+            Self::Other => "other",
+        }
+    }
+}
+impl std::fmt::Display for AriaSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AriaSort {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("ascending") => Self::Ascending,
+                s if s.eq_ignore_ascii_case("descending") => Self::Descending,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("other") => Self::Other,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AriaSort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AriaSort {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<article>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArticleRole {
+    /// The `application` keyword
+    Application,
+    /// The `article` keyword
+    Article,
+    /// The `document` keyword
+    Document,
+    /// The `feed` keyword
+    Feed,
+    /// The `main` keyword
+    Main,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `region` keyword
+    Region,
+}
+impl ArticleRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+            Self::Article => "article",
+            Self::Document => "document",
+            Self::Feed => "feed",
+            Self::Main => "main",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Region => "region",
+        }
+    }
+}
+impl std::fmt::Display for ArticleRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ArticleRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s if s.eq_ignore_ascii_case("article") => Self::Article,
+                s if s.eq_ignore_ascii_case("document") => Self::Document,
+                s if s.eq_ignore_ascii_case("feed") => Self::Feed,
+                s if s.eq_ignore_ascii_case("main") => Self::Main,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("region") => Self::Region,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ArticleRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ArticleRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<aside>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsideRole {
+    /// The `complementary` keyword
+    Complementary,
+    /// The `feed` keyword
+    Feed,
+    /// The `none` keyword
+    None,
+    /// The `note` keyword
+    Note,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `region` keyword
+    Region,
+    /// The `search` keyword
+    Search,
+}
+impl AsideRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Complementary => "complementary",
+            Self::Feed => "feed",
+            Self::None => "none",
+            Self::Note => "note",
+            Self::Presentation => "presentation",
+            Self::Region => "region",
+            Self::Search => "search",
+        }
+    }
+}
+impl std::fmt::Display for AsideRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AsideRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("complementary") => Self::Complementary,
+                s if s.eq_ignore_ascii_case("feed") => Self::Feed,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("note") => Self::Note,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("region") => Self::Region,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AsideRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AsideRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<audio>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioRole {
+    /// The `application` keyword
+    Application,
+}
+impl AudioRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+        }
+    }
+}
+impl std::fmt::Display for AudioRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AudioRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AudioRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AudioRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `autocapitalize` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AutoCapitalize {
+    /// The `on` keyword
+    On,
+    /// The `off` keyword
+    Off,
+    /// The `none` keyword
+    None,
+    /// The `sentences` keyword
+    Sentences,
+    /// The `words` keyword
+    Words,
+    /// The `characters` keyword
+    Characters,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl AutoCapitalize {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::On => "on",
+            Self::Off => "off",
+            Self::None => "none",
+            Self::Sentences => "sentences",
+            Self::Words => "words",
+            Self::Characters => "characters",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for AutoCapitalize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for AutoCapitalize {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("on") => Self::On,
+                s if s.eq_ignore_ascii_case("off") => Self::Off,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("sentences") => Self::Sentences,
+                s if s.eq_ignore_ascii_case("words") => Self::Words,
+                s if s.eq_ignore_ascii_case("characters") => Self::Characters,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AutoCapitalize {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AutoCapitalize {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<button>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonRole {
+    /// The `button` keyword
+    Button,
+    /// The `checkbox` keyword
+    Checkbox,
+    /// The `combobox` keyword
+    Combobox,
+    /// The `gridcell` keyword
+    Gridcell,
+    /// The `link` keyword
+    Link,
+    /// The `menuitem` keyword
+    Menuitem,
+    /// The `menuitemcheckbox` keyword
+    Menuitemcheckbox,
+    /// The `menuitemradio` keyword
+    Menuitemradio,
+    /// The `option` keyword
+    Option,
+    /// The `radio` keyword
+    Radio,
+    /// The `separator` keyword
+    Separator,
+    /// The `slider` keyword
+    Slider,
+    /// The `switch` keyword
+    Switch,
+    /// The `tab` keyword
+    Tab,
+    /// The `treeitem` keyword
+    Treeitem,
+}
+impl ButtonRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Button => "button",
+            Self::Checkbox => "checkbox",
+            Self::Combobox => "combobox",
+            Self::Gridcell => "gridcell",
+            Self::Link => "link",
+            Self::Menuitem => "menuitem",
+            Self::Menuitemcheckbox => "menuitemcheckbox",
+            Self::Menuitemradio => "menuitemradio",
+            Self::Option => "option",
+            Self::Radio => "radio",
+            Self::Separator => "separator",
+            Self::Slider => "slider",
+            Self::Switch => "switch",
+            Self::Tab => "tab",
+            Self::Treeitem => "treeitem",
+        }
+    }
+}
+impl std::fmt::Display for ButtonRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ButtonRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s if s.eq_ignore_ascii_case("checkbox") => Self::Checkbox,
+                s if s.eq_ignore_ascii_case("combobox") => Self::Combobox,
+                s if s.eq_ignore_ascii_case("gridcell") => Self::Gridcell,
+                s if s.eq_ignore_ascii_case("link") => Self::Link,
+                s if s.eq_ignore_ascii_case("menuitem") => Self::Menuitem,
+                s if s.eq_ignore_ascii_case("menuitemcheckbox") => Self::Menuitemcheckbox,
+                s if s.eq_ignore_ascii_case("menuitemradio") => Self::Menuitemradio,
+                s if s.eq_ignore_ascii_case("option") => Self::Option,
+                s if s.eq_ignore_ascii_case("radio") => Self::Radio,
+                s if s.eq_ignore_ascii_case("separator") => Self::Separator,
+                s if s.eq_ignore_ascii_case("slider") => Self::Slider,
+                s if s.eq_ignore_ascii_case("switch") => Self::Switch,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s if s.eq_ignore_ascii_case("treeitem") => Self::Treeitem,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ButtonRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ButtonRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `type` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ButtonType {
+    /// The `submit` keyword
+    Submit,
+    /// The `reset` keyword
+    Reset,
+    /// The `button` keyword
+    Button,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl ButtonType {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Submit => "submit",
+            Self::Reset => "reset",
+            Self::Button => "button",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for ButtonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ButtonType {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("submit") => Self::Submit,
+                s if s.eq_ignore_ascii_case("reset") => Self::Reset,
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ButtonType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ButtonType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `contenteditable` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContentEditable {
+    /// The `true` keyword
+    True,
+    /// The `false` keyword
+    False,
+    /// The `plaintext-only` keyword
+    PlaintextOnly,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl ContentEditable {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+            Self::PlaintextOnly => "plaintext-only",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for ContentEditable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ContentEditable {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s if s.eq_ignore_ascii_case("plaintext-only") => Self::PlaintextOnly,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ContentEditable {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ContentEditable {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `crossorigin` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CrossOrigin {
+    /// The `anonymous` keyword
+    Anonymous,
+    /// The `use-credentials` keyword
+    UseCredentials,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl CrossOrigin {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Anonymous => "anonymous",
+            Self::UseCredentials => "use-credentials",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for CrossOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for CrossOrigin {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("anonymous") => Self::Anonymous,
+                s if s.eq_ignore_ascii_case("use-credentials") => Self::UseCredentials,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CrossOrigin {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CrossOrigin {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `decoding` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Decoding {
+    /// The `sync` keyword
+    Sync,
+    /// The `async` keyword
+    Async,
+    /// The `auto` keyword
+    Auto,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Decoding {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sync => "sync",
+            Self::Async => "async",
+            Self::Auto => "auto",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Decoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Decoding {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("sync") => Self::Sync,
+                s if s.eq_ignore_ascii_case("async") => Self::Async,
+                s if s.eq_ignore_ascii_case("auto") => Self::Auto,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Decoding {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Decoding {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<dl>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DescriptionListRole {
+    /// The `group` keyword
+    Group,
+    /// The `list` keyword
+    List,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl DescriptionListRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Group => "group",
+            Self::List => "list",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for DescriptionListRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for DescriptionListRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("list") => Self::List,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DescriptionListRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DescriptionListRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<dt>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DescriptionTermRole {
+    /// The `listitem` keyword
+    Listitem,
+}
+impl DescriptionTermRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Listitem => "listitem",
+        }
+    }
+}
+impl std::fmt::Display for DescriptionTermRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for DescriptionTermRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("listitem") => Self::Listitem,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DescriptionTermRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DescriptionTermRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<dialog>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DialogRole {
+    /// The `alertdialog` keyword
+    Alertdialog,
+    /// The `dialog` keyword
+    Dialog,
+}
+impl DialogRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Alertdialog => "alertdialog",
+            Self::Dialog => "dialog",
+        }
+    }
+}
+impl std::fmt::Display for DialogRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for DialogRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("alertdialog") => Self::Alertdialog,
+                s if s.eq_ignore_ascii_case("dialog") => Self::Dialog,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DialogRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DialogRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `dir` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The `ltr` keyword
+    Ltr,
+    /// The `rtl` keyword
+    Rtl,
+    /// The `auto` keyword
+    Auto,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Direction {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+            Self::Auto => "auto",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Direction {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("ltr") => Self::Ltr,
+                s if s.eq_ignore_ascii_case("rtl") => Self::Rtl,
+                s if s.eq_ignore_ascii_case("auto") => Self::Auto,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Direction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Direction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `draggable` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Draggable {
+    /// The `true` keyword
+    True,
+    /// The `false` keyword
+    False,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Draggable {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Draggable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Draggable {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Draggable {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Draggable {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<embed>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmbedRole {
+    /// The `application` keyword
+    Application,
+    /// The `document` keyword
+    Document,
+    /// The `img` keyword
+    Img,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl EmbedRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+            Self::Document => "document",
+            Self::Img => "img",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for EmbedRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for EmbedRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s if s.eq_ignore_ascii_case("document") => Self::Document,
+                s if s.eq_ignore_ascii_case("img") => Self::Img,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmbedRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmbedRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `enterkeyhint` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EnterKeyHint {
+    /// The `enter` keyword
+    Enter,
+    /// The `done` keyword
+    Done,
+    /// The `go` keyword
+    Go,
+    /// The `next` keyword
+    Next,
+    /// The `previous` keyword
+    Previous,
+    /// The `search` keyword
+    Search,
+    /// The `send` keyword
+    Send,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl EnterKeyHint {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Enter => "enter",
+            Self::Done => "done",
+            Self::Go => "go",
+            Self::Next => "next",
+            Self::Previous => "previous",
+            Self::Search => "search",
+            Self::Send => "send",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for EnterKeyHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for EnterKeyHint {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("enter") => Self::Enter,
+                s if s.eq_ignore_ascii_case("done") => Self::Done,
+                s if s.eq_ignore_ascii_case("go") => Self::Go,
+                s if s.eq_ignore_ascii_case("next") => Self::Next,
+                s if s.eq_ignore_ascii_case("previous") => Self::Previous,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s if s.eq_ignore_ascii_case("send") => Self::Send,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EnterKeyHint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EnterKeyHint {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `fetchpriority` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FetchPriority {
+    /// The `auto` keyword
+    Auto,
+    /// The `high` keyword
+    High,
+    /// The `low` keyword
+    Low,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl FetchPriority {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Auto => "auto",
+            Self::High => "high",
+            Self::Low => "low",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for FetchPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FetchPriority {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("auto") => Self::Auto,
+                s if s.eq_ignore_ascii_case("high") => Self::High,
+                s if s.eq_ignore_ascii_case("low") => Self::Low,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FetchPriority {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FetchPriority {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<fieldset>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldsetRole {
+    /// The `group` keyword
+    Group,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `radiogroup` keyword
+    Radiogroup,
+}
+impl FieldsetRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Group => "group",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Radiogroup => "radiogroup",
+        }
+    }
+}
+impl std::fmt::Display for FieldsetRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FieldsetRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("radiogroup") => Self::Radiogroup,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldsetRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FieldsetRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<figcaption>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FigureCaptionRole {
+    /// The `group` keyword
+    Group,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl FigureCaptionRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Group => "group",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for FigureCaptionRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FigureCaptionRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FigureCaptionRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FigureCaptionRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<footer>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FooterRole {
+    /// The `contentinfo` keyword
+    Contentinfo,
+    /// The `generic` keyword
+    Generic,
+    /// The `group` keyword
+    Group,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl FooterRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Contentinfo => "contentinfo",
+            Self::Generic => "generic",
+            Self::Group => "group",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for FooterRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FooterRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("contentinfo") => Self::Contentinfo,
+                s if s.eq_ignore_ascii_case("generic") => Self::Generic,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FooterRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FooterRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `autocomplete` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormAutocomplete {
+    /// The `on` keyword
+    On,
+    /// The `off` keyword
+    Off,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl FormAutocomplete {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::On => "on",
+            Self::Off => "off",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for FormAutocomplete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FormAutocomplete {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("on") => Self::On,
+                s if s.eq_ignore_ascii_case("off") => Self::Off,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FormAutocomplete {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FormAutocomplete {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `enctype` and `formenctype` attributes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormEncoding {
+    /// The `application/x-www-form-urlencoded` keyword
+    ApplicationXWwwFormUrlencoded,
+    /// The `multipart/form-data` keyword
+    MultipartFormData,
+    /// The `text/plain` keyword
+    TextPlain,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl FormEncoding {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ApplicationXWwwFormUrlencoded => "application/x-www-form-urlencoded",
+            Self::MultipartFormData => "multipart/form-data",
+            Self::TextPlain => "text/plain",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for FormEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FormEncoding {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application/x-www-form-urlencoded") => {
+                    Self::ApplicationXWwwFormUrlencoded
+                }
+                s if s.eq_ignore_ascii_case("multipart/form-data") => {
+                    Self::MultipartFormData
+                }
+                s if s.eq_ignore_ascii_case("text/plain") => Self::TextPlain,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FormEncoding {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FormEncoding {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `formmethod` and `method` attributes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormMethod {
+    /// The `GET` keyword
+    Get,
+    /// The `POST` keyword
+    Post,
+    /// The `dialog` keyword
+    Dialog,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl FormMethod {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Dialog => "dialog",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for FormMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FormMethod {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("GET") => Self::Get,
+                s if s.eq_ignore_ascii_case("POST") => Self::Post,
+                s if s.eq_ignore_ascii_case("dialog") => Self::Dialog,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FormMethod {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FormMethod {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<form>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormRole {
+    /// The `form` keyword
+    Form,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `search` keyword
+    Search,
+}
+impl FormRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Form => "form",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Search => "search",
+        }
+    }
+}
+impl std::fmt::Display for FormRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for FormRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("form") => Self::Form,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for FormRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FormRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<header>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HeaderRole {
+    /// The `banner` keyword
+    Banner,
+    /// The `contentinfo` keyword
+    Contentinfo,
+    /// The `generic` keyword
+    Generic,
+    /// The `group` keyword
+    Group,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl HeaderRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Banner => "banner",
+            Self::Contentinfo => "contentinfo",
+            Self::Generic => "generic",
+            Self::Group => "group",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for HeaderRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for HeaderRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("banner") => Self::Banner,
+                s if s.eq_ignore_ascii_case("contentinfo") => Self::Contentinfo,
+                s if s.eq_ignore_ascii_case("generic") => Self::Generic,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for HeaderRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HeaderRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<h1>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading1Role {
+    /// The `heading` keyword
+    Heading,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tab` keyword
+    Tab,
+}
+impl Heading1Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Heading => "heading",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tab => "tab",
+        }
+    }
+}
+impl std::fmt::Display for Heading1Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Heading1Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Heading1Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Heading1Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<h2>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading2Role {
+    /// The `heading` keyword
+    Heading,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tab` keyword
+    Tab,
+}
+impl Heading2Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Heading => "heading",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tab => "tab",
+        }
+    }
+}
+impl std::fmt::Display for Heading2Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Heading2Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Heading2Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Heading2Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<h3>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading3Role {
+    /// The `heading` keyword
+    Heading,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tab` keyword
+    Tab,
+}
+impl Heading3Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Heading => "heading",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tab => "tab",
+        }
+    }
+}
+impl std::fmt::Display for Heading3Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Heading3Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Heading3Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Heading3Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<h4>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading4Role {
+    /// The `heading` keyword
+    Heading,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tab` keyword
+    Tab,
+}
+impl Heading4Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Heading => "heading",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tab => "tab",
+        }
+    }
+}
+impl std::fmt::Display for Heading4Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Heading4Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Heading4Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Heading4Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<h5>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading5Role {
+    /// The `heading` keyword
+    Heading,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tab` keyword
+    Tab,
+}
+impl Heading5Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Heading => "heading",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tab => "tab",
+        }
+    }
+}
+impl std::fmt::Display for Heading5Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Heading5Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Heading5Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Heading5Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<h6>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading6Role {
+    /// The `heading` keyword
+    Heading,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tab` keyword
+    Tab,
+}
+impl Heading6Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Heading => "heading",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tab => "tab",
+        }
+    }
+}
+impl std::fmt::Display for Heading6Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Heading6Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Heading6Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Heading6Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `hidden` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Hidden {
+    /// The `until-found` keyword
+    UntilFound,
+    /// The `hidden` keyword
+    Hidden,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Hidden {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::UntilFound => "until-found",
+            Self::Hidden => "hidden",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Hidden {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Hidden {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("until-found") => Self::UntilFound,
+                s if s.eq_ignore_ascii_case("hidden") => Self::Hidden,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hidden {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hidden {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `http-equiv` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HttpEquiv {
+    /// The `content-type` keyword
+    ContentType,
+    /// The `default-style` keyword
+    DefaultStyle,
+    /// The `refresh` keyword
+    Refresh,
+    /// The `x-ua-compatible` keyword
+    XUaCompatible,
+    /// The `content-security-policy` keyword
+    ContentSecurityPolicy,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl HttpEquiv {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ContentType => "content-type",
+            Self::DefaultStyle => "default-style",
+            Self::Refresh => "refresh",
+            Self::XUaCompatible => "x-ua-compatible",
+            Self::ContentSecurityPolicy => "content-security-policy",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for HttpEquiv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for HttpEquiv {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("content-type") => Self::ContentType,
+                s if s.eq_ignore_ascii_case("default-style") => Self::DefaultStyle,
+                s if s.eq_ignore_ascii_case("refresh") => Self::Refresh,
+                s if s.eq_ignore_ascii_case("x-ua-compatible") => Self::XUaCompatible,
+                s if s.eq_ignore_ascii_case("content-security-policy") => {
+                    Self::ContentSecurityPolicy
+                }
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for HttpEquiv {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HttpEquiv {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<iframe>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IframeRole {
+    /// The `application` keyword
+    Application,
+    /// The `document` keyword
+    Document,
+    /// The `img` keyword
+    Img,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl IframeRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+            Self::Document => "document",
+            Self::Img => "img",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for IframeRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for IframeRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s if s.eq_ignore_ascii_case("document") => Self::Document,
+                s if s.eq_ignore_ascii_case("img") => Self::Img,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for IframeRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IframeRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<area>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageMapAreaRole {
+    /// The `button` keyword
+    Button,
+    /// The `generic` keyword
+    Generic,
+    /// The `link` keyword
+    Link,
+}
+impl ImageMapAreaRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Button => "button",
+            Self::Generic => "generic",
+            Self::Link => "link",
+        }
+    }
+}
+impl std::fmt::Display for ImageMapAreaRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ImageMapAreaRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s if s.eq_ignore_ascii_case("generic") => Self::Generic,
+                s if s.eq_ignore_ascii_case("link") => Self::Link,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImageMapAreaRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ImageMapAreaRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<img>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageRole {
+    /// The `button` keyword
+    Button,
+    /// The `checkbox` keyword
+    Checkbox,
+    /// The `img` keyword
+    Img,
+    /// The `link` keyword
+    Link,
+    /// The `menuitem` keyword
+    Menuitem,
+    /// The `menuitemcheckbox` keyword
+    Menuitemcheckbox,
+    /// The `menuitemradio` keyword
+    Menuitemradio,
+    /// The `meter` keyword
+    Meter,
+    /// The `none` keyword
+    None,
+    /// The `option` keyword
+    Option,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `progressbar` keyword
+    Progressbar,
+    /// The `radio` keyword
+    Radio,
+    /// The `scrollbar` keyword
+    Scrollbar,
+    /// The `separator` keyword
+    Separator,
+    /// The `slider` keyword
+    Slider,
+    /// The `switch` keyword
+    Switch,
+    /// The `tab` keyword
+    Tab,
+    /// The `treeitem` keyword
+    Treeitem,
+}
+impl ImageRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Button => "button",
+            Self::Checkbox => "checkbox",
+            Self::Img => "img",
+            Self::Link => "link",
+            Self::Menuitem => "menuitem",
+            Self::Menuitemcheckbox => "menuitemcheckbox",
+            Self::Menuitemradio => "menuitemradio",
+            Self::Meter => "meter",
+            Self::None => "none",
+            Self::Option => "option",
+            Self::Presentation => "presentation",
+            Self::Progressbar => "progressbar",
+            Self::Radio => "radio",
+            Self::Scrollbar => "scrollbar",
+            Self::Separator => "separator",
+            Self::Slider => "slider",
+            Self::Switch => "switch",
+            Self::Tab => "tab",
+            Self::Treeitem => "treeitem",
+        }
+    }
+}
+impl std::fmt::Display for ImageRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ImageRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s if s.eq_ignore_ascii_case("checkbox") => Self::Checkbox,
+                s if s.eq_ignore_ascii_case("img") => Self::Img,
+                s if s.eq_ignore_ascii_case("link") => Self::Link,
+                s if s.eq_ignore_ascii_case("menuitem") => Self::Menuitem,
+                s if s.eq_ignore_ascii_case("menuitemcheckbox") => Self::Menuitemcheckbox,
+                s if s.eq_ignore_ascii_case("menuitemradio") => Self::Menuitemradio,
+                s if s.eq_ignore_ascii_case("meter") => Self::Meter,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("option") => Self::Option,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("progressbar") => Self::Progressbar,
+                s if s.eq_ignore_ascii_case("radio") => Self::Radio,
+                s if s.eq_ignore_ascii_case("scrollbar") => Self::Scrollbar,
+                s if s.eq_ignore_ascii_case("separator") => Self::Separator,
+                s if s.eq_ignore_ascii_case("slider") => Self::Slider,
+                s if s.eq_ignore_ascii_case("switch") => Self::Switch,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s if s.eq_ignore_ascii_case("treeitem") => Self::Treeitem,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImageRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ImageRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `inputmode` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputMode {
+    /// The `none` keyword
+    None,
+    /// The `text` keyword
+    Text,
+    /// The `tel` keyword
+    Tel,
+    /// The `email` keyword
+    Email,
+    /// The `url` keyword
+    Url,
+    /// The `numeric` keyword
+    Numeric,
+    /// The `decimal` keyword
+    Decimal,
+    /// The `search` keyword
+    Search,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl InputMode {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Text => "text",
+            Self::Tel => "tel",
+            Self::Email => "email",
+            Self::Url => "url",
+            Self::Numeric => "numeric",
+            Self::Decimal => "decimal",
+            Self::Search => "search",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for InputMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for InputMode {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("text") => Self::Text,
+                s if s.eq_ignore_ascii_case("tel") => Self::Tel,
+                s if s.eq_ignore_ascii_case("email") => Self::Email,
+                s if s.eq_ignore_ascii_case("url") => Self::Url,
+                s if s.eq_ignore_ascii_case("numeric") => Self::Numeric,
+                s if s.eq_ignore_ascii_case("decimal") => Self::Decimal,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for InputMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for InputMode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<input>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputRole {
+    /// The `button` keyword
+    Button,
+    /// The `checkbox` keyword
+    Checkbox,
+    /// The `combobox` keyword
+    Combobox,
+    /// The `gridcell` keyword
+    Gridcell,
+    /// The `link` keyword
+    Link,
+    /// The `menuitem` keyword
+    Menuitem,
+    /// The `menuitemcheckbox` keyword
+    Menuitemcheckbox,
+    /// The `menuitemradio` keyword
+    Menuitemradio,
+    /// The `option` keyword
+    Option,
+    /// The `radio` keyword
+    Radio,
+    /// The `searchbox` keyword
+    Searchbox,
+    /// The `separator` keyword
+    Separator,
+    /// The `slider` keyword
+    Slider,
+    /// The `spinbutton` keyword
+    Spinbutton,
+    /// The `switch` keyword
+    Switch,
+    /// The `tab` keyword
+    Tab,
+    /// The `textbox` keyword
+    Textbox,
+    /// The `treeitem` keyword
+    Treeitem,
+}
+impl InputRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Button => "button",
+            Self::Checkbox => "checkbox",
+            Self::Combobox => "combobox",
+            Self::Gridcell => "gridcell",
+            Self::Link => "link",
+            Self::Menuitem => "menuitem",
+            Self::Menuitemcheckbox => "menuitemcheckbox",
+            Self::Menuitemradio => "menuitemradio",
+            Self::Option => "option",
+            Self::Radio => "radio",
+            Self::Searchbox => "searchbox",
+            Self::Separator => "separator",
+            Self::Slider => "slider",
+            Self::Spinbutton => "spinbutton",
+            Self::Switch => "switch",
+            Self::Tab => "tab",
+            Self::Textbox => "textbox",
+            Self::Treeitem => "treeitem",
+        }
+    }
+}
+impl std::fmt::Display for InputRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for InputRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s if s.eq_ignore_ascii_case("checkbox") => Self::Checkbox,
+                s if s.eq_ignore_ascii_case("combobox") => Self::Combobox,
+                s if s.eq_ignore_ascii_case("gridcell") => Self::Gridcell,
+                s if s.eq_ignore_ascii_case("link") => Self::Link,
+                s if s.eq_ignore_ascii_case("menuitem") => Self::Menuitem,
+                s if s.eq_ignore_ascii_case("menuitemcheckbox") => Self::Menuitemcheckbox,
+                s if s.eq_ignore_ascii_case("menuitemradio") => Self::Menuitemradio,
+                s if s.eq_ignore_ascii_case("option") => Self::Option,
+                s if s.eq_ignore_ascii_case("radio") => Self::Radio,
+                s if s.eq_ignore_ascii_case("searchbox") => Self::Searchbox,
+                s if s.eq_ignore_ascii_case("separator") => Self::Separator,
+                s if s.eq_ignore_ascii_case("slider") => Self::Slider,
+                s if s.eq_ignore_ascii_case("spinbutton") => Self::Spinbutton,
+                s if s.eq_ignore_ascii_case("switch") => Self::Switch,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s if s.eq_ignore_ascii_case("textbox") => Self::Textbox,
+                s if s.eq_ignore_ascii_case("treeitem") => Self::Treeitem,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for InputRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for InputRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `type` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputType {
+    /// The `hidden` keyword
+    Hidden,
+    /// The `text` keyword
+    Text,
+    /// The `search` keyword
+    Search,
+    /// The `tel` keyword
+    Tel,
+    /// The `url` keyword
+    Url,
+    /// The `email` keyword
+    Email,
+    /// The `password` keyword
+    Password,
+    /// The `date` keyword
+    Date,
+    /// The `month` keyword
+    Month,
+    /// The `week` keyword
+    Week,
+    /// The `time` keyword
+    Time,
+    /// The `datetime-local` keyword
+    DatetimeLocal,
+    /// The `number` keyword
+    Number,
+    /// The `range` keyword
+    Range,
+    /// The `color` keyword
+    Color,
+    /// The `checkbox` keyword
+    Checkbox,
+    /// The `radio` keyword
+    Radio,
+    /// The `file` keyword
+    File,
+    /// The `submit` keyword
+    Submit,
+    /// The `image` keyword
+    Image,
+    /// The `reset` keyword
+    Reset,
+    /// The `button` keyword
+    Button,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl InputType {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Hidden => "hidden",
+            Self::Text => "text",
+            Self::Search => "search",
+            Self::Tel => "tel",
+            Self::Url => "url",
+            Self::Email => "email",
+            Self::Password => "password",
+            Self::Date => "date",
+            Self::Month => "month",
+            Self::Week => "week",
+            Self::Time => "time",
+            Self::DatetimeLocal => "datetime-local",
+            Self::Number => "number",
+            Self::Range => "range",
+            Self::Color => "color",
+            Self::Checkbox => "checkbox",
+            Self::Radio => "radio",
+            Self::File => "file",
+            Self::Submit => "submit",
+            Self::Image => "image",
+            Self::Reset => "reset",
+            Self::Button => "button",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for InputType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for InputType {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("hidden") => Self::Hidden,
+                s if s.eq_ignore_ascii_case("text") => Self::Text,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s if s.eq_ignore_ascii_case("tel") => Self::Tel,
+                s if s.eq_ignore_ascii_case("url") => Self::Url,
+                s if s.eq_ignore_ascii_case("email") => Self::Email,
+                s if s.eq_ignore_ascii_case("password") => Self::Password,
+                s if s.eq_ignore_ascii_case("date") => Self::Date,
+                s if s.eq_ignore_ascii_case("month") => Self::Month,
+                s if s.eq_ignore_ascii_case("week") => Self::Week,
+                s if s.eq_ignore_ascii_case("time") => Self::Time,
+                s if s.eq_ignore_ascii_case("datetime-local") => Self::DatetimeLocal,
+                s if s.eq_ignore_ascii_case("number") => Self::Number,
+                s if s.eq_ignore_ascii_case("range") => Self::Range,
+                s if s.eq_ignore_ascii_case("color") => Self::Color,
+                s if s.eq_ignore_ascii_case("checkbox") => Self::Checkbox,
+                s if s.eq_ignore_ascii_case("radio") => Self::Radio,
+                s if s.eq_ignore_ascii_case("file") => Self::File,
+                s if s.eq_ignore_ascii_case("submit") => Self::Submit,
+                s if s.eq_ignore_ascii_case("image") => Self::Image,
+                s if s.eq_ignore_ascii_case("reset") => Self::Reset,
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for InputType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for InputType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<wbr>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineBreakOpportunityRole {
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl LineBreakOpportunityRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for LineBreakOpportunityRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for LineBreakOpportunityRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for LineBreakOpportunityRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LineBreakOpportunityRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<br>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineBreakRole {
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+}
+impl LineBreakRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Presentation => "presentation",
+        }
+    }
+}
+impl std::fmt::Display for LineBreakRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for LineBreakRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for LineBreakRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LineBreakRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `loading` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Loading {
+    /// The `lazy` keyword
+    Lazy,
+    /// The `eager` keyword
+    Eager,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Loading {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Lazy => "lazy",
+            Self::Eager => "eager",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Loading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Loading {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("lazy") => Self::Lazy,
+                s if s.eq_ignore_ascii_case("eager") => Self::Eager,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Loading {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Loading {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<menu>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuRole {
+    /// The `directory` keyword
+    Directory,
+    /// The `group` keyword
+    Group,
+    /// The `list` keyword
+    List,
+    /// The `listbox` keyword
+    Listbox,
+    /// The `menu` keyword
+    Menu,
+    /// The `menubar` keyword
+    Menubar,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `radiogroup` keyword
+    Radiogroup,
+    /// The `tablist` keyword
+    Tablist,
+    /// The `toolbar` keyword
+    Toolbar,
+    /// The `tree` keyword
+    Tree,
+}
+impl MenuRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Directory => "directory",
+            Self::Group => "group",
+            Self::List => "list",
+            Self::Listbox => "listbox",
+            Self::Menu => "menu",
+            Self::Menubar => "menubar",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Radiogroup => "radiogroup",
+            Self::Tablist => "tablist",
+            Self::Toolbar => "toolbar",
+            Self::Tree => "tree",
+        }
+    }
+}
+impl std::fmt::Display for MenuRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for MenuRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("directory") => Self::Directory,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("list") => Self::List,
+                s if s.eq_ignore_ascii_case("listbox") => Self::Listbox,
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s if s.eq_ignore_ascii_case("menubar") => Self::Menubar,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("radiogroup") => Self::Radiogroup,
+                s if s.eq_ignore_ascii_case("tablist") => Self::Tablist,
+                s if s.eq_ignore_ascii_case("toolbar") => Self::Toolbar,
+                s if s.eq_ignore_ascii_case("tree") => Self::Tree,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for MenuRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MenuRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<nav>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavigationRole {
+    /// The `menu` keyword
+    Menu,
+    /// The `menubar` keyword
+    Menubar,
+    /// The `navigation` keyword
+    Navigation,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `tablist` keyword
+    Tablist,
+}
+impl NavigationRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Menu => "menu",
+            Self::Menubar => "menubar",
+            Self::Navigation => "navigation",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Tablist => "tablist",
+        }
+    }
+}
+impl std::fmt::Display for NavigationRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for NavigationRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s if s.eq_ignore_ascii_case("menubar") => Self::Menubar,
+                s if s.eq_ignore_ascii_case("navigation") => Self::Navigation,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("tablist") => Self::Tablist,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for NavigationRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NavigationRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<object>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectRole {
+    /// The `application` keyword
+    Application,
+    /// The `document` keyword
+    Document,
+    /// The `img` keyword
+    Img,
+}
+impl ObjectRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+            Self::Document => "document",
+            Self::Img => "img",
+        }
+    }
+}
+impl std::fmt::Display for ObjectRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ObjectRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s if s.eq_ignore_ascii_case("document") => Self::Document,
+                s if s.eq_ignore_ascii_case("img") => Self::Img,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ObjectRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ObjectRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<ol>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderedListRole {
+    /// The `directory` keyword
+    Directory,
+    /// The `group` keyword
+    Group,
+    /// The `list` keyword
+    List,
+    /// The `listbox` keyword
+    Listbox,
+    /// The `menu` keyword
+    Menu,
+    /// The `menubar` keyword
+    Menubar,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `radiogroup` keyword
+    Radiogroup,
+    /// The `tablist` keyword
+    Tablist,
+    /// The `toolbar` keyword
+    Toolbar,
+    /// The `tree` keyword
+    Tree,
+}
+impl OrderedListRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Directory => "directory",
+            Self::Group => "group",
+            Self::List => "list",
+            Self::Listbox => "listbox",
+            Self::Menu => "menu",
+            Self::Menubar => "menubar",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Radiogroup => "radiogroup",
+            Self::Tablist => "tablist",
+            Self::Toolbar => "toolbar",
+            Self::Tree => "tree",
+        }
+    }
+}
+impl std::fmt::Display for OrderedListRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for OrderedListRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("directory") => Self::Directory,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("list") => Self::List,
+                s if s.eq_ignore_ascii_case("listbox") => Self::Listbox,
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s if s.eq_ignore_ascii_case("menubar") => Self::Menubar,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("radiogroup") => Self::Radiogroup,
+                s if s.eq_ignore_ascii_case("tablist") => Self::Tablist,
+                s if s.eq_ignore_ascii_case("toolbar") => Self::Toolbar,
+                s if s.eq_ignore_ascii_case("tree") => Self::Tree,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for OrderedListRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OrderedListRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `type` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OrderedListType {
+    /// The `1` keyword
+    Decimal,
+    /// The `a` keyword
+    LowerAlpha,
+    /// The `A` keyword
+    UpperAlpha,
+    /// The `i` keyword
+    LowerRoman,
+    /// The `I` keyword
+    UpperRoman,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl OrderedListType {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Decimal => "1",
+            Self::LowerAlpha => "a",
+            Self::UpperAlpha => "A",
+            Self::LowerRoman => "i",
+            Self::UpperRoman => "I",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for OrderedListType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for OrderedListType {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                "1" => Self::Decimal,
+                "a" => Self::LowerAlpha,
+                "A" => Self::UpperAlpha,
+                "i" => Self::LowerRoman,
+                "I" => Self::UpperRoman,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for OrderedListType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OrderedListType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `popovertargetaction` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PopoverTargetAction {
+    /// The `toggle` keyword
+    Toggle,
+    /// The `show` keyword
+    Show,
+    /// The `hide` keyword
+    Hide,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl PopoverTargetAction {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Toggle => "toggle",
+            Self::Show => "show",
+            Self::Hide => "hide",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for PopoverTargetAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for PopoverTargetAction {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("toggle") => Self::Toggle,
+                s if s.eq_ignore_ascii_case("show") => Self::Show,
+                s if s.eq_ignore_ascii_case("hide") => Self::Hide,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for PopoverTargetAction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PopoverTargetAction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `preload` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Preload {
+    /// The `none` keyword
+    None,
+    /// The `metadata` keyword
+    Metadata,
+    /// The `auto` keyword
+    Auto,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Preload {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Metadata => "metadata",
+            Self::Auto => "auto",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Preload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Preload {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("metadata") => Self::Metadata,
+                s if s.eq_ignore_ascii_case("auto") => Self::Auto,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Preload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Preload {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `referrerpolicy` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferrerPolicy {
+    /// The `no-referrer` keyword
+    NoReferrer,
+    /// The `no-referrer-when-downgrade` keyword
+    NoReferrerWhenDowngrade,
+    /// The `same-origin` keyword
+    SameOrigin,
+    /// The `origin` keyword
+    Origin,
+    /// The `strict-origin` keyword
+    StrictOrigin,
+    /// The `origin-when-cross-origin` keyword
+    OriginWhenCrossOrigin,
+    /// The `strict-origin-when-cross-origin` keyword
+    StrictOriginWhenCrossOrigin,
+    /// The `unsafe-url` keyword
+    UnsafeUrl,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl ReferrerPolicy {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NoReferrer => "no-referrer",
+            Self::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
+            Self::SameOrigin => "same-origin",
+            Self::Origin => "origin",
+            Self::StrictOrigin => "strict-origin",
+            Self::OriginWhenCrossOrigin => "origin-when-cross-origin",
+            Self::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
+            Self::UnsafeUrl => "unsafe-url",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for ReferrerPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ReferrerPolicy {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("no-referrer") => Self::NoReferrer,
+                s if s.eq_ignore_ascii_case("no-referrer-when-downgrade") => {
+                    Self::NoReferrerWhenDowngrade
+                }
+                s if s.eq_ignore_ascii_case("same-origin") => Self::SameOrigin,
+                s if s.eq_ignore_ascii_case("origin") => Self::Origin,
+                s if s.eq_ignore_ascii_case("strict-origin") => Self::StrictOrigin,
+                s if s.eq_ignore_ascii_case("origin-when-cross-origin") => {
+                    Self::OriginWhenCrossOrigin
+                }
+                s if s.eq_ignore_ascii_case("strict-origin-when-cross-origin") => {
+                    Self::StrictOriginWhenCrossOrigin
+                }
+                s if s.eq_ignore_ascii_case("unsafe-url") => Self::UnsafeUrl,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ReferrerPolicy {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ReferrerPolicy {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// The `alert` keyword
+    Alert,
+    /// The `alertdialog` keyword
+    Alertdialog,
+    /// The `application` keyword
+    Application,
+    /// The `article` keyword
+    Article,
+    /// The `associationlist` keyword
+    Associationlist,
+    /// The `associationlistitemkey` keyword
+    Associationlistitemkey,
+    /// The `associationlistitemvalue` keyword
+    Associationlistitemvalue,
+    /// The `banner` keyword
+    Banner,
+    /// The `blockquote` keyword
+    Blockquote,
+    /// The `button` keyword
+    Button,
+    /// The `caption` keyword
+    Caption,
+    /// The `cell` keyword
+    Cell,
+    /// The `checkbox` keyword
+    Checkbox,
+    /// The `code` keyword
+    Code,
+    /// The `columnheader` keyword
+    Columnheader,
+    /// The `combobox` keyword
+    Combobox,
+    /// The `comment` keyword
+    Comment,
+    /// The `complementary` keyword
+    Complementary,
+    /// The `contentinfo` keyword
+    Contentinfo,
+    /// The `definition` keyword
+    Definition,
+    /// The `deletion` keyword
+    Deletion,
+    /// The `dialog` keyword
+    Dialog,
+    /// The `directory` keyword
+    Directory,
+    /// The `document` keyword
+    Document,
+    /// The `emphasis` keyword
+    Emphasis,
+    /// The `feed` keyword
+    Feed,
+    /// The `figure` keyword
+    Figure,
+    /// The `form` keyword
+    Form,
+    /// The `generic` keyword
+    Generic,
+    /// The `grid` keyword
+    Grid,
+    /// The `gridcell` keyword
+    Gridcell,
+    /// The `group` keyword
+    Group,
+    /// The `heading` keyword
+    Heading,
+    /// The `image` keyword
+    Image,
+    /// The `img` keyword
+    Img,
+    /// The `insertion` keyword
+    Insertion,
+    /// The `link` keyword
+    Link,
+    /// The `list` keyword
+    List,
+    /// The `listbox` keyword
+    Listbox,
+    /// The `listitem` keyword
+    Listitem,
+    /// The `log` keyword
+    Log,
+    /// The `main` keyword
+    Main,
+    /// The `mark` keyword
+    Mark,
+    /// The `marquee` keyword
+    Marquee,
+    /// The `math` keyword
+    Math,
+    /// The `menu` keyword
+    Menu,
+    /// The `menubar` keyword
+    Menubar,
+    /// The `menuitem` keyword
+    Menuitem,
+    /// The `menuitemcheckbox` keyword
+    Menuitemcheckbox,
+    /// The `menuitemradio` keyword
+    Menuitemradio,
+    /// The `meter` keyword
+    Meter,
+    /// The `navigation` keyword
+    Navigation,
+    /// The `none` keyword
+    None,
+    /// The `note` keyword
+    Note,
+    /// The `option` keyword
+    Option,
+    /// The `paragraph` keyword
+    Paragraph,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `progressbar` keyword
+    Progressbar,
+    /// The `radio` keyword
+    Radio,
+    /// The `radiogroup` keyword
+    Radiogroup,
+    /// The `region` keyword
+    Region,
+    /// The `row` keyword
+    Row,
+    /// The `rowgroup` keyword
+    Rowgroup,
+    /// The `rowheader` keyword
+    Rowheader,
+    /// The `scrollbar` keyword
+    Scrollbar,
+    /// The `search` keyword
+    Search,
+    /// The `searchbox` keyword
+    Searchbox,
+    /// The `separator` keyword
+    Separator,
+    /// The `slider` keyword
+    Slider,
+    /// The `spinbutton` keyword
+    Spinbutton,
+    /// The `status` keyword
+    Status,
+    /// The `strong` keyword
+    Strong,
+    /// The `subscript` keyword
+    Subscript,
+    /// The `suggestion` keyword
+    Suggestion,
+    /// The `superscript` keyword
+    Superscript,
+    /// The `switch` keyword
+    Switch,
+    /// The `tab` keyword
+    Tab,
+    /// The `table` keyword
+    Table,
+    /// The `tablist` keyword
+    Tablist,
+    /// The `tabpanel` keyword
+    Tabpanel,
+    /// The `term` keyword
+    Term,
+    /// The `textbox` keyword
+    Textbox,
+    /// The `time` keyword
+    Time,
+    /// The `timer` keyword
+    Timer,
+    /// The `toolbar` keyword
+    Toolbar,
+    /// The `tooltip` keyword
+    Tooltip,
+    /// The `tree` keyword
+    Tree,
+    /// The `treegrid` keyword
+    Treegrid,
+    /// The `treeitem` keyword
+    Treeitem,
+}
+impl Role {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Alert => "alert",
+            Self::Alertdialog => "alertdialog",
+            Self::Application => "application",
+            Self::Article => "article",
+            Self::Associationlist => "associationlist",
+            Self::Associationlistitemkey => "associationlistitemkey",
+            Self::Associationlistitemvalue => "associationlistitemvalue",
+            Self::Banner => "banner",
+            Self::Blockquote => "blockquote",
+            Self::Button => "button",
+            Self::Caption => "caption",
+            Self::Cell => "cell",
+            Self::Checkbox => "checkbox",
+            Self::Code => "code",
+            Self::Columnheader => "columnheader",
+            Self::Combobox => "combobox",
+            Self::Comment => "comment",
+            Self::Complementary => "complementary",
+            Self::Contentinfo => "contentinfo",
+            Self::Definition => "definition",
+            Self::Deletion => "deletion",
+            Self::Dialog => "dialog",
+            Self::Directory => "directory",
+            Self::Document => "document",
+            Self::Emphasis => "emphasis",
+            Self::Feed => "feed",
+            Self::Figure => "figure",
+            Self::Form => "form",
+            Self::Generic => "generic",
+            Self::Grid => "grid",
+            Self::Gridcell => "gridcell",
+            Self::Group => "group",
+            Self::Heading => "heading",
+            Self::Image => "image",
+            Self::Img => "img",
+            Self::Insertion => "insertion",
+            Self::Link => "link",
+            Self::List => "list",
+            Self::Listbox => "listbox",
+            Self::Listitem => "listitem",
+            Self::Log => "log",
+            Self::Main => "main",
+            Self::Mark => "mark",
+            Self::Marquee => "marquee",
+            Self::Math => "math",
+            Self::Menu => "menu",
+            Self::Menubar => "menubar",
+            Self::Menuitem => "menuitem",
+            Self::Menuitemcheckbox => "menuitemcheckbox",
+            Self::Menuitemradio => "menuitemradio",
+            Self::Meter => "meter",
+            Self::Navigation => "navigation",
+            Self::None => "none",
+            Self::Note => "note",
+            Self::Option => "option",
+            Self::Paragraph => "paragraph",
+            Self::Presentation => "presentation",
+            Self::Progressbar => "progressbar",
+            Self::Radio => "radio",
+            Self::Radiogroup => "radiogroup",
+            Self::Region => "region",
+            Self::Row => "row",
+            Self::Rowgroup => "rowgroup",
+            Self::Rowheader => "rowheader",
+            Self::Scrollbar => "scrollbar",
+            Self::Search => "search",
+            Self::Searchbox => "searchbox",
+            Self::Separator => "separator",
+            Self::Slider => "slider",
+            Self::Spinbutton => "spinbutton",
+            Self::Status => "status",
+            Self::Strong => "strong",
+            Self::Subscript => "subscript",
+            Self::Suggestion => "suggestion",
+            Self::Superscript => "superscript",
+            Self::Switch => "switch",
+            Self::Tab => "tab",
+            Self::Table => "table",
+            Self::Tablist => "tablist",
+            Self::Tabpanel => "tabpanel",
+            Self::Term => "term",
+            Self::Textbox => "textbox",
+            Self::Time => "time",
+            Self::Timer => "timer",
+            Self::Toolbar => "toolbar",
+            Self::Tooltip => "tooltip",
+            Self::Tree => "tree",
+            Self::Treegrid => "treegrid",
+            Self::Treeitem => "treeitem",
+        }
+    }
+}
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Role {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("alert") => Self::Alert,
+                s if s.eq_ignore_ascii_case("alertdialog") => Self::Alertdialog,
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s if s.eq_ignore_ascii_case("article") => Self::Article,
+                s if s.eq_ignore_ascii_case("associationlist") => Self::Associationlist,
+                s if s.eq_ignore_ascii_case("associationlistitemkey") => {
+                    Self::Associationlistitemkey
+                }
+                s if s.eq_ignore_ascii_case("associationlistitemvalue") => {
+                    Self::Associationlistitemvalue
+                }
+                s if s.eq_ignore_ascii_case("banner") => Self::Banner,
+                s if s.eq_ignore_ascii_case("blockquote") => Self::Blockquote,
+                s if s.eq_ignore_ascii_case("button") => Self::Button,
+                s if s.eq_ignore_ascii_case("caption") => Self::Caption,
+                s if s.eq_ignore_ascii_case("cell") => Self::Cell,
+                s if s.eq_ignore_ascii_case("checkbox") => Self::Checkbox,
+                s if s.eq_ignore_ascii_case("code") => Self::Code,
+                s if s.eq_ignore_ascii_case("columnheader") => Self::Columnheader,
+                s if s.eq_ignore_ascii_case("combobox") => Self::Combobox,
+                s if s.eq_ignore_ascii_case("comment") => Self::Comment,
+                s if s.eq_ignore_ascii_case("complementary") => Self::Complementary,
+                s if s.eq_ignore_ascii_case("contentinfo") => Self::Contentinfo,
+                s if s.eq_ignore_ascii_case("definition") => Self::Definition,
+                s if s.eq_ignore_ascii_case("deletion") => Self::Deletion,
+                s if s.eq_ignore_ascii_case("dialog") => Self::Dialog,
+                s if s.eq_ignore_ascii_case("directory") => Self::Directory,
+                s if s.eq_ignore_ascii_case("document") => Self::Document,
+                s if s.eq_ignore_ascii_case("emphasis") => Self::Emphasis,
+                s if s.eq_ignore_ascii_case("feed") => Self::Feed,
+                s if s.eq_ignore_ascii_case("figure") => Self::Figure,
+                s if s.eq_ignore_ascii_case("form") => Self::Form,
+                s if s.eq_ignore_ascii_case("generic") => Self::Generic,
+                s if s.eq_ignore_ascii_case("grid") => Self::Grid,
+                s if s.eq_ignore_ascii_case("gridcell") => Self::Gridcell,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("heading") => Self::Heading,
+                s if s.eq_ignore_ascii_case("image") => Self::Image,
+                s if s.eq_ignore_ascii_case("img") => Self::Img,
+                s if s.eq_ignore_ascii_case("insertion") => Self::Insertion,
+                s if s.eq_ignore_ascii_case("link") => Self::Link,
+                s if s.eq_ignore_ascii_case("list") => Self::List,
+                s if s.eq_ignore_ascii_case("listbox") => Self::Listbox,
+                s if s.eq_ignore_ascii_case("listitem") => Self::Listitem,
+                s if s.eq_ignore_ascii_case("log") => Self::Log,
+                s if s.eq_ignore_ascii_case("main") => Self::Main,
+                s if s.eq_ignore_ascii_case("mark") => Self::Mark,
+                s if s.eq_ignore_ascii_case("marquee") => Self::Marquee,
+                s if s.eq_ignore_ascii_case("math") => Self::Math,
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s if s.eq_ignore_ascii_case("menubar") => Self::Menubar,
+                s if s.eq_ignore_ascii_case("menuitem") => Self::Menuitem,
+                s if s.eq_ignore_ascii_case("menuitemcheckbox") => Self::Menuitemcheckbox,
+                s if s.eq_ignore_ascii_case("menuitemradio") => Self::Menuitemradio,
+                s if s.eq_ignore_ascii_case("meter") => Self::Meter,
+                s if s.eq_ignore_ascii_case("navigation") => Self::Navigation,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("note") => Self::Note,
+                s if s.eq_ignore_ascii_case("option") => Self::Option,
+                s if s.eq_ignore_ascii_case("paragraph") => Self::Paragraph,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("progressbar") => Self::Progressbar,
+                s if s.eq_ignore_ascii_case("radio") => Self::Radio,
+                s if s.eq_ignore_ascii_case("radiogroup") => Self::Radiogroup,
+                s if s.eq_ignore_ascii_case("region") => Self::Region,
+                s if s.eq_ignore_ascii_case("row") => Self::Row,
+                s if s.eq_ignore_ascii_case("rowgroup") => Self::Rowgroup,
+                s if s.eq_ignore_ascii_case("rowheader") => Self::Rowheader,
+                s if s.eq_ignore_ascii_case("scrollbar") => Self::Scrollbar,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s if s.eq_ignore_ascii_case("searchbox") => Self::Searchbox,
+                s if s.eq_ignore_ascii_case("separator") => Self::Separator,
+                s if s.eq_ignore_ascii_case("slider") => Self::Slider,
+                s if s.eq_ignore_ascii_case("spinbutton") => Self::Spinbutton,
+                s if s.eq_ignore_ascii_case("status") => Self::Status,
+                s if s.eq_ignore_ascii_case("strong") => Self::Strong,
+                s if s.eq_ignore_ascii_case("subscript") => Self::Subscript,
+                s if s.eq_ignore_ascii_case("suggestion") => Self::Suggestion,
+                s if s.eq_ignore_ascii_case("superscript") => Self::Superscript,
+                s if s.eq_ignore_ascii_case("switch") => Self::Switch,
+                s if s.eq_ignore_ascii_case("tab") => Self::Tab,
+                s if s.eq_ignore_ascii_case("table") => Self::Table,
+                s if s.eq_ignore_ascii_case("tablist") => Self::Tablist,
+                s if s.eq_ignore_ascii_case("tabpanel") => Self::Tabpanel,
+                s if s.eq_ignore_ascii_case("term") => Self::Term,
+                s if s.eq_ignore_ascii_case("textbox") => Self::Textbox,
+                s if s.eq_ignore_ascii_case("time") => Self::Time,
+                s if s.eq_ignore_ascii_case("timer") => Self::Timer,
+                s if s.eq_ignore_ascii_case("toolbar") => Self::Toolbar,
+                s if s.eq_ignore_ascii_case("tooltip") => Self::Tooltip,
+                s if s.eq_ignore_ascii_case("tree") => Self::Tree,
+                s if s.eq_ignore_ascii_case("treegrid") => Self::Treegrid,
+                s if s.eq_ignore_ascii_case("treeitem") => Self::Treeitem,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Role {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `scope` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// The `row` keyword
+    Row,
+    /// The `col` keyword
+    Col,
+    /// The `rowgroup` keyword
+    Rowgroup,
+    /// The `colgroup` keyword
+    Colgroup,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Scope {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Row => "row",
+            Self::Col => "col",
+            Self::Rowgroup => "rowgroup",
+            Self::Colgroup => "colgroup",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Scope {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("row") => Self::Row,
+                s if s.eq_ignore_ascii_case("col") => Self::Col,
+                s if s.eq_ignore_ascii_case("rowgroup") => Self::Rowgroup,
+                s if s.eq_ignore_ascii_case("colgroup") => Self::Colgroup,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Scope {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Scope {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<search>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchRole {
+    /// The `form` keyword
+    Form,
+    /// The `group` keyword
+    Group,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `region` keyword
+    Region,
+    /// The `search` keyword
+    Search,
+}
+impl SearchRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Form => "form",
+            Self::Group => "group",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Region => "region",
+            Self::Search => "search",
+        }
+    }
+}
+impl std::fmt::Display for SearchRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SearchRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("form") => Self::Form,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("region") => Self::Region,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SearchRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SearchRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<section>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionRole {
+    /// The `alert` keyword
+    Alert,
+    /// The `alertdialog` keyword
+    Alertdialog,
+    /// The `application` keyword
+    Application,
+    /// The `banner` keyword
+    Banner,
+    /// The `complementary` keyword
+    Complementary,
+    /// The `contentinfo` keyword
+    Contentinfo,
+    /// The `dialog` keyword
+    Dialog,
+    /// The `document` keyword
+    Document,
+    /// The `feed` keyword
+    Feed,
+    /// The `generic` keyword
+    Generic,
+    /// The `group` keyword
+    Group,
+    /// The `log` keyword
+    Log,
+    /// The `main` keyword
+    Main,
+    /// The `marquee` keyword
+    Marquee,
+    /// The `navigation` keyword
+    Navigation,
+    /// The `none` keyword
+    None,
+    /// The `note` keyword
+    Note,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `region` keyword
+    Region,
+    /// The `search` keyword
+    Search,
+    /// The `status` keyword
+    Status,
+    /// The `tabpanel` keyword
+    Tabpanel,
+}
+impl SectionRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Alert => "alert",
+            Self::Alertdialog => "alertdialog",
+            Self::Application => "application",
+            Self::Banner => "banner",
+            Self::Complementary => "complementary",
+            Self::Contentinfo => "contentinfo",
+            Self::Dialog => "dialog",
+            Self::Document => "document",
+            Self::Feed => "feed",
+            Self::Generic => "generic",
+            Self::Group => "group",
+            Self::Log => "log",
+            Self::Main => "main",
+            Self::Marquee => "marquee",
+            Self::Navigation => "navigation",
+            Self::None => "none",
+            Self::Note => "note",
+            Self::Presentation => "presentation",
+            Self::Region => "region",
+            Self::Search => "search",
+            Self::Status => "status",
+            Self::Tabpanel => "tabpanel",
+        }
+    }
+}
+impl std::fmt::Display for SectionRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SectionRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("alert") => Self::Alert,
+                s if s.eq_ignore_ascii_case("alertdialog") => Self::Alertdialog,
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s if s.eq_ignore_ascii_case("banner") => Self::Banner,
+                s if s.eq_ignore_ascii_case("complementary") => Self::Complementary,
+                s if s.eq_ignore_ascii_case("contentinfo") => Self::Contentinfo,
+                s if s.eq_ignore_ascii_case("dialog") => Self::Dialog,
+                s if s.eq_ignore_ascii_case("document") => Self::Document,
+                s if s.eq_ignore_ascii_case("feed") => Self::Feed,
+                s if s.eq_ignore_ascii_case("generic") => Self::Generic,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("log") => Self::Log,
+                s if s.eq_ignore_ascii_case("main") => Self::Main,
+                s if s.eq_ignore_ascii_case("marquee") => Self::Marquee,
+                s if s.eq_ignore_ascii_case("navigation") => Self::Navigation,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("note") => Self::Note,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("region") => Self::Region,
+                s if s.eq_ignore_ascii_case("search") => Self::Search,
+                s if s.eq_ignore_ascii_case("status") => Self::Status,
+                s if s.eq_ignore_ascii_case("tabpanel") => Self::Tabpanel,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SectionRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SectionRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<select>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectRole {
+    /// The `combobox` keyword
+    Combobox,
+    /// The `listbox` keyword
+    Listbox,
+    /// The `menu` keyword
+    Menu,
+}
+impl SelectRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Combobox => "combobox",
+            Self::Listbox => "listbox",
+            Self::Menu => "menu",
+        }
+    }
+}
+impl std::fmt::Display for SelectRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SelectRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("combobox") => Self::Combobox,
+                s if s.eq_ignore_ascii_case("listbox") => Self::Listbox,
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SelectRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SelectRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `shape` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// The `circle` keyword
+    Circle,
+    /// The `default` keyword
+    Default,
+    /// The `poly` keyword
+    Poly,
+    /// The `rect` keyword
+    Rect,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Shape {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Circle => "circle",
+            Self::Default => "default",
+            Self::Poly => "poly",
+            Self::Rect => "rect",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Shape {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("circle") => Self::Circle,
+                s if s.eq_ignore_ascii_case("default") => Self::Default,
+                s if s.eq_ignore_ascii_case("poly") => Self::Poly,
+                s if s.eq_ignore_ascii_case("rect") => Self::Rect,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Shape {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Shape {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `spellcheck` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Spellcheck {
+    /// The `true` keyword
+    True,
+    /// The `false` keyword
+    False,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Spellcheck {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Spellcheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Spellcheck {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Spellcheck {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Spellcheck {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `role` attribute of `<hr>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThematicBreakRole {
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `separator` keyword
+    Separator,
+}
+impl ThematicBreakRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Separator => "separator",
+        }
+    }
+}
+impl std::fmt::Display for ThematicBreakRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for ThematicBreakRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("separator") => Self::Separator,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ThematicBreakRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ThematicBreakRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `kind` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TrackKind {
+    /// The `subtitles` keyword
+    Subtitles,
+    /// The `captions` keyword
+    Captions,
+    /// The `descriptions` keyword
+    Descriptions,
+    /// The `chapters` keyword
+    Chapters,
+    /// The `metadata` keyword
+    Metadata,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl TrackKind {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Subtitles => "subtitles",
+            Self::Captions => "captions",
+            Self::Descriptions => "descriptions",
+            Self::Chapters => "chapters",
+            Self::Metadata => "metadata",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for TrackKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for TrackKind {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("subtitles") => Self::Subtitles,
+                s if s.eq_ignore_ascii_case("captions") => Self::Captions,
+                s if s.eq_ignore_ascii_case("descriptions") => Self::Descriptions,
+                s if s.eq_ignore_ascii_case("chapters") => Self::Chapters,
+                s if s.eq_ignore_ascii_case("metadata") => Self::Metadata,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for TrackKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TrackKind {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `translate` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Translate {
+    /// The `yes` keyword
+    Yes,
+    /// The `no` keyword
+    No,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Translate {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Yes => "yes",
+            Self::No => "no",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Translate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Translate {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("yes") => Self::Yes,
+                s if s.eq_ignore_ascii_case("no") => Self::No,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Translate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Translate {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+/// The keywords of the `aria-checked` and `aria-pressed` attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tristate {
+    /// The `true` keyword
+    True,
+    /// The `false` keyword
+    False,
+    /// The `mixed` keyword
+    Mixed,
+}
+impl Tristate {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::True => "true",
+            Self::False => "false",
+            Self::Mixed => "mixed",
+        }
+    }
+}
+impl std::fmt::Display for Tristate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Tristate {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("true") => Self::True,
+                s if s.eq_ignore_ascii_case("false") => Self::False,
+                s if s.eq_ignore_ascii_case("mixed") => Self::Mixed,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Tristate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Tristate {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<ul>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnorderedListRole {
+    /// The `directory` keyword
+    Directory,
+    /// The `group` keyword
+    Group,
+    /// The `list` keyword
+    List,
+    /// The `listbox` keyword
+    Listbox,
+    /// The `menu` keyword
+    Menu,
+    /// The `menubar` keyword
+    Menubar,
+    /// The `none` keyword
+    None,
+    /// The `presentation` keyword
+    Presentation,
+    /// The `radiogroup` keyword
+    Radiogroup,
+    /// The `tablist` keyword
+    Tablist,
+    /// The `toolbar` keyword
+    Toolbar,
+    /// The `tree` keyword
+    Tree,
+}
+impl UnorderedListRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Directory => "directory",
+            Self::Group => "group",
+            Self::List => "list",
+            Self::Listbox => "listbox",
+            Self::Menu => "menu",
+            Self::Menubar => "menubar",
+            Self::None => "none",
+            Self::Presentation => "presentation",
+            Self::Radiogroup => "radiogroup",
+            Self::Tablist => "tablist",
+            Self::Toolbar => "toolbar",
+            Self::Tree => "tree",
+        }
+    }
+}
+impl std::fmt::Display for UnorderedListRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for UnorderedListRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("directory") => Self::Directory,
+                s if s.eq_ignore_ascii_case("group") => Self::Group,
+                s if s.eq_ignore_ascii_case("list") => Self::List,
+                s if s.eq_ignore_ascii_case("listbox") => Self::Listbox,
+                s if s.eq_ignore_ascii_case("menu") => Self::Menu,
+                s if s.eq_ignore_ascii_case("menubar") => Self::Menubar,
+                s if s.eq_ignore_ascii_case("none") => Self::None,
+                s if s.eq_ignore_ascii_case("presentation") => Self::Presentation,
+                s if s.eq_ignore_ascii_case("radiogroup") => Self::Radiogroup,
+                s if s.eq_ignore_ascii_case("tablist") => Self::Tablist,
+                s if s.eq_ignore_ascii_case("toolbar") => Self::Toolbar,
+                s if s.eq_ignore_ascii_case("tree") => Self::Tree,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnorderedListRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UnorderedListRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `role` attribute of `<video>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VideoRole {
+    /// The `application` keyword
+    Application,
+}
+impl VideoRole {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+        }
+    }
+}
+impl std::fmt::Display for VideoRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for VideoRole {
+    type Err = ParseKeywordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("application") => Self::Application,
+                s => {
+                    return Err(ParseKeywordError {
+                        value: s.to_owned(),
+                    });
+                }
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VideoRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VideoRole {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+/// The keywords of the `wrap` attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Wrap {
+    /// The `soft` keyword
+    Soft,
+    /// The `hard` keyword
+    Hard,
+    /// A value which isn't one of the known keywords
+    Custom(std::borrow::Cow<'static, str>),
+}
+impl Wrap {
+    /// Get the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Soft => "soft",
+            Self::Hard => "hard",
+            Self::Custom(value) => value,
+        }
+    }
+}
+impl std::fmt::Display for Wrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for Wrap {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match s {
+                s if s.eq_ignore_ascii_case("soft") => Self::Soft,
+                s if s.eq_ignore_ascii_case("hard") => Self::Hard,
+                s => Self::Custom(s.to_owned().into()),
+            },
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Wrap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Wrap {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
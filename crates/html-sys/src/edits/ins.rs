@@ -4,148 +4,482 @@
 #[doc(alias = "ins")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct InsertedText {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// Link to the source of the quotation or more information about the edit
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub cite: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Date and (optionally) time of the change
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "datetime", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub date_time: std::option::Option<std::borrow::Cow<'static, str>>,
-    /// Describes the role(s) the current element plays in the context of the document.
-    pub role: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Describes the role the current element plays in the context of the document.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub role: std::option::Option<crate::attributes::Role>,
     /// Identifies the currently active element when DOM focus is on a composite widget, combobox, textbox, group, or application.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-activedescendant",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_active_descendant_element: std::option::Option<
         std::borrow::Cow<'static, str>,
     >,
     /// Indicates whether assistive technologies will present all, or only parts of, the changed region based on the change notifications defined by the aria-relevant attribute.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-atomic", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_atomic: bool,
     /// Indicates whether inputting text could trigger display of one or more predictions of the user's intended value for a combobox, searchbox, or textbox and specifies how predictions would be presented if they were made.
-    pub aria_auto_complete: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-autocomplete",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_auto_complete: std::option::Option<crate::attributes::AriaAutoComplete>,
     /// Defines a human-readable, author-localized abbreviated description for the role of an element, which is intended to be converted into Braille. See related aria-roledescription.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-brailleroledescription",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_braille_role_description: std::option::Option<
         std::borrow::Cow<'static, str>,
     >,
     /// Indicates an element is being modified and that assistive technologies could wait until the modifications are complete before exposing them to the user.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-busy", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_busy: bool,
     /// Indicates the current "checked" state of checkboxes, radio buttons, and other widgets. See related aria-pressed and aria-selected.
-    pub aria_checked: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-checked",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_checked: std::option::Option<crate::attributes::Tristate>,
     /// Defines the total number of columns in a table, grid, or treegrid. See related aria-colindex.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-colcount",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_col_count: std::option::Option<i64>,
     /// Defines an element's column index or position with respect to the total number of columns within a table, grid, or treegrid. See related aria-colindextext, aria-colcount, and aria-colspan.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-colindex",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_col_index: std::option::Option<i64>,
     /// Defines a human readable text alternative of aria-colindex. See related aria-rowindextext.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-colindextext",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_col_index_text: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Defines the number of columns spanned by a cell or gridcell within a table, grid, or treegrid. See related aria-colindex and aria-rowspan.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-colspan",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_col_span: std::option::Option<i64>,
     /// Identifies the element (or elements) whose contents or presence are controlled by the current element. See related aria-owns.
-    pub aria_controls_elements: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-controls", skip_serializing_if = "std::vec::Vec::is_empty")
+    )]
+    pub aria_controls_elements: std::vec::Vec<std::borrow::Cow<'static, str>>,
     /// Indicates the element that represents the current item within a container or set of related elements.
-    pub aria_current: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-current",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_current: std::option::Option<crate::attributes::AriaCurrent>,
     /// Identifies the element (or elements) that describes the object. See related aria-labelledby and aria-description.
-    pub aria_described_by_elements: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-describedby",
+            skip_serializing_if = "std::vec::Vec::is_empty"
+        )
+    )]
+    pub aria_described_by_elements: std::vec::Vec<std::borrow::Cow<'static, str>>,
     /// Defines a string value that describes or annotates the current element. See related aria-describedby.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-description",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_description: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Identifies the element (or elements) that provide additional information related to the object. See related aria-describedby.
-    pub aria_details_elements: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-details", skip_serializing_if = "std::vec::Vec::is_empty")
+    )]
+    pub aria_details_elements: std::vec::Vec<std::borrow::Cow<'static, str>>,
     /// Indicates that the element is perceivable but disabled, so it is not editable or otherwise operable. See related aria-hidden and aria-readonly.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-disabled", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_disabled: bool,
     /// [Deprecated in ARIA 1.1] Indicates what functions can be performed when a dragged object is released on the drop target.
-    pub aria_drop_effect: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-dropeffect",
+            skip_serializing_if = "std::vec::Vec::is_empty"
+        )
+    )]
+    pub aria_drop_effect: std::vec::Vec<crate::attributes::AriaDropEffect>,
     /// Identifies the element (or elements) that provides an error message for an object. See related aria-invalid and aria-describedby.
-    pub aria_error_message_elements: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-errormessage",
+            skip_serializing_if = "std::vec::Vec::is_empty"
+        )
+    )]
+    pub aria_error_message_elements: std::vec::Vec<std::borrow::Cow<'static, str>>,
     /// Indicates whether a grouping element owned or controlled by this element is expanded or collapsed.
-    pub aria_expanded: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-expanded",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_expanded: std::option::Option<bool>,
     /// Identifies the next element (or elements) in an alternate reading order of content which, at the user's discretion, allows assistive technology to override the general default of reading in document source order.
-    pub aria_flow_to_elements: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-flowto", skip_serializing_if = "std::vec::Vec::is_empty")
+    )]
+    pub aria_flow_to_elements: std::vec::Vec<std::borrow::Cow<'static, str>>,
     /// [Deprecated in ARIA 1.1] Indicates an element's "grabbed" state in a drag-and-drop operation.
-    pub aria_grabbed: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-grabbed",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_grabbed: std::option::Option<bool>,
     /// Indicates the availability and type of interactive popup element, such as menu or dialog, that can be triggered by an element.
-    pub aria_has_popup: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-haspopup",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_has_popup: std::option::Option<crate::attributes::AriaHasPopup>,
     /// Indicates whether the element is exposed to an accessibility API. See related aria-disabled.
-    pub aria_hidden: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-hidden",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_hidden: std::option::Option<bool>,
     /// Indicates the entered value does not conform to the format expected by the application. See related aria-errormessage.
-    pub aria_invalid: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-invalid",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_invalid: std::option::Option<crate::attributes::AriaInvalid>,
     /// Defines keyboard shortcuts that an author has implemented to activate or give focus to an element.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-keyshortcuts",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_key_shortcuts: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Defines the hierarchical level of an element within a structure.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-level",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_level: std::option::Option<i64>,
     /// Indicates that an element will be updated, and describes the types of updates the user agents, assistive technologies, and user can expect from the live region.
-    pub aria_live: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-live", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub aria_live: std::option::Option<crate::attributes::AriaLive>,
     /// Indicates whether an element is modal when displayed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-modal", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_modal: bool,
     /// Indicates whether a text box accepts multiple lines of input or only a single line.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-multiline", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_multi_line: bool,
     /// Indicates that the user can select more than one item from the current selectable descendants.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-multiselectable",
+            skip_serializing_if = "std::ops::Not::not"
+        )
+    )]
     pub aria_multi_selectable: bool,
     /// Indicates whether the element's orientation is horizontal, vertical, or unknown/ambiguous.
-    pub aria_orientation: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-orientation",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_orientation: std::option::Option<crate::attributes::AriaOrientation>,
     /// Identifies an element (or elements) in order to define a visual, functional, or contextual parent/child relationship between DOM elements where the DOM hierarchy cannot be used to represent the relationship. See related aria-controls.
-    pub aria_owns_elements: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-owns", skip_serializing_if = "std::vec::Vec::is_empty")
+    )]
+    pub aria_owns_elements: std::vec::Vec<std::borrow::Cow<'static, str>>,
     /// Defines a short hint (a word or short phrase) intended to aid the user with data entry when the control has no value. A hint could be a sample value or a brief description of the expected format.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-placeholder",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_placeholder: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Defines an element's number or position in the current set of listitems or treeitems. Not required if all elements in the set are present in the DOM. See related aria-setsize.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-posinset",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_pos_in_set: std::option::Option<i64>,
     /// Indicates the current "pressed" state of toggle buttons. See related aria-checked and aria-selected.
-    pub aria_pressed: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-pressed",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_pressed: std::option::Option<crate::attributes::Tristate>,
     ///  Indicates that the element is not editable, but is otherwise operable. See related aria-disabled.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-readonly", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_read_only: bool,
     /// Indicates what notifications the user agent will trigger when the accessibility tree within a live region is modified. See related aria-atomic.
-    pub aria_relevant: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-relevant", skip_serializing_if = "std::vec::Vec::is_empty")
+    )]
+    pub aria_relevant: std::vec::Vec<crate::attributes::AriaRelevant>,
     /// Indicates that user input is required on the element before a form can be submitted.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-required", skip_serializing_if = "std::ops::Not::not")
+    )]
     pub aria_required: bool,
     /// Defines a human-readable, author-localized description for the role of an element.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-roledescription",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_role_description: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Defines the total number of rows in a table, grid, or treegrid. See related aria-rowindex.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-rowcount",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_row_count: std::option::Option<i64>,
     /// Defines an element's row index or position with respect to the total number of rows within a table, grid, or treegrid. See related aria-rowindextext, aria-rowcount, and aria-rowspan.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-rowindex",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_row_index: std::option::Option<i64>,
     /// Defines a human readable text alternative of aria-rowindex. See related aria-colindextext.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-rowindextext",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_row_index_text: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Defines the number of rows spanned by a cell or gridcell within a table, grid, or treegrid. See related aria-rowindex and aria-colspan.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-rowspan",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_row_span: std::option::Option<i64>,
     /// Indicates the current "selected" state of various widgets. See related aria-checked and aria-pressed.
-    pub aria_selected: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-selected",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub aria_selected: std::option::Option<bool>,
     /// Defines the number of items in the current set of listitems or treeitems. Not required if all elements in the set are present in the DOM. See related aria-posinset.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-setsize",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_set_size: std::option::Option<i64>,
     /// Indicates if items in a table or grid are sorted in ascending or descending order.
-    pub aria_sort: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "aria-sort", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub aria_sort: std::option::Option<crate::attributes::AriaSort>,
     /// Defines the maximum allowed value for a range widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-valuemax",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_value_max: std::option::Option<f64>,
     /// Defines the minimum allowed value for a range widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-valuemin",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_value_min: std::option::Option<f64>,
     /// Defines the current value for a range widget. See related aria-valuetext.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-valuenow",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_value_now: std::option::Option<f64>,
     /// Defines the human readable text alternative of aria-valuenow for a range widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "aria-valuetext",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub aria_value_text: std::option::Option<std::borrow::Cow<'static, str>>,
 }
 impl crate::RenderElement for InsertedText {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<ins")?;
         if let Some(field) = self.cite.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" cite="{field}""#)?;
         }
         if let Some(field) = self.date_time.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" datetime="{field}""#)?;
         }
         if let Some(field) = self.role.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" role="{field}""#)?;
         }
         if let Some(field) = self.aria_active_descendant_element.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-activedescendant="{field}""#)?;
         }
         if self.aria_atomic {
-            write!(writer, r#" aria-atomic"#)?;
+            write!(writer, r#" aria-atomic="true""#)?;
         }
         if let Some(field) = self.aria_auto_complete.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-autocomplete="{field}""#)?;
         }
         if let Some(field) = self.aria_braille_role_description.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-brailleroledescription="{field}""#)?;
         }
         if self.aria_busy {
-            write!(writer, r#" aria-busy"#)?;
+            write!(writer, r#" aria-busy="true""#)?;
         }
         if let Some(field) = self.aria_checked.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-checked="{field}""#)?;
         }
         if let Some(field) = self.aria_col_count.as_ref() {
@@ -155,96 +489,123 @@ impl crate::RenderElement for InsertedText {
             write!(writer, r#" aria-colindex="{field}""#)?;
         }
         if let Some(field) = self.aria_col_index_text.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-colindextext="{field}""#)?;
         }
         if let Some(field) = self.aria_col_span.as_ref() {
             write!(writer, r#" aria-colspan="{field}""#)?;
         }
-        if let Some(field) = self.aria_controls_elements.as_ref() {
+        if !self.aria_controls_elements.is_empty() {
+            let field = crate::join_list(&self.aria_controls_elements);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-controls="{field}""#)?;
         }
         if let Some(field) = self.aria_current.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-current="{field}""#)?;
         }
-        if let Some(field) = self.aria_described_by_elements.as_ref() {
+        if !self.aria_described_by_elements.is_empty() {
+            let field = crate::join_list(&self.aria_described_by_elements);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-describedby="{field}""#)?;
         }
         if let Some(field) = self.aria_description.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-description="{field}""#)?;
         }
-        if let Some(field) = self.aria_details_elements.as_ref() {
+        if !self.aria_details_elements.is_empty() {
+            let field = crate::join_list(&self.aria_details_elements);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-details="{field}""#)?;
         }
         if self.aria_disabled {
-            write!(writer, r#" aria-disabled"#)?;
+            write!(writer, r#" aria-disabled="true""#)?;
         }
-        if let Some(field) = self.aria_drop_effect.as_ref() {
+        if !self.aria_drop_effect.is_empty() {
+            let field = crate::join_list(&self.aria_drop_effect);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-dropeffect="{field}""#)?;
         }
-        if let Some(field) = self.aria_error_message_elements.as_ref() {
+        if !self.aria_error_message_elements.is_empty() {
+            let field = crate::join_list(&self.aria_error_message_elements);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-errormessage="{field}""#)?;
         }
-        if self.aria_expanded {
-            write!(writer, r#" aria-expanded"#)?;
+        if let Some(field) = self.aria_expanded.as_ref() {
+            write!(writer, r#" aria-expanded="{field}""#)?;
         }
-        if let Some(field) = self.aria_flow_to_elements.as_ref() {
+        if !self.aria_flow_to_elements.is_empty() {
+            let field = crate::join_list(&self.aria_flow_to_elements);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-flowto="{field}""#)?;
         }
-        if self.aria_grabbed {
-            write!(writer, r#" aria-grabbed"#)?;
+        if let Some(field) = self.aria_grabbed.as_ref() {
+            write!(writer, r#" aria-grabbed="{field}""#)?;
         }
         if let Some(field) = self.aria_has_popup.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-haspopup="{field}""#)?;
         }
-        if self.aria_hidden {
-            write!(writer, r#" aria-hidden"#)?;
+        if let Some(field) = self.aria_hidden.as_ref() {
+            write!(writer, r#" aria-hidden="{field}""#)?;
         }
         if let Some(field) = self.aria_invalid.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-invalid="{field}""#)?;
         }
         if let Some(field) = self.aria_key_shortcuts.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-keyshortcuts="{field}""#)?;
         }
         if let Some(field) = self.aria_level.as_ref() {
             write!(writer, r#" aria-level="{field}""#)?;
         }
         if let Some(field) = self.aria_live.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-live="{field}""#)?;
         }
         if self.aria_modal {
-            write!(writer, r#" aria-modal"#)?;
+            write!(writer, r#" aria-modal="true""#)?;
         }
         if self.aria_multi_line {
-            write!(writer, r#" aria-multiline"#)?;
+            write!(writer, r#" aria-multiline="true""#)?;
         }
         if self.aria_multi_selectable {
-            write!(writer, r#" aria-multiselectable"#)?;
+            write!(writer, r#" aria-multiselectable="true""#)?;
         }
         if let Some(field) = self.aria_orientation.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-orientation="{field}""#)?;
         }
-        if let Some(field) = self.aria_owns_elements.as_ref() {
+        if !self.aria_owns_elements.is_empty() {
+            let field = crate::join_list(&self.aria_owns_elements);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-owns="{field}""#)?;
         }
         if let Some(field) = self.aria_placeholder.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-placeholder="{field}""#)?;
         }
         if let Some(field) = self.aria_pos_in_set.as_ref() {
             write!(writer, r#" aria-posinset="{field}""#)?;
         }
         if let Some(field) = self.aria_pressed.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-pressed="{field}""#)?;
         }
         if self.aria_read_only {
-            write!(writer, r#" aria-readonly"#)?;
+            write!(writer, r#" aria-readonly="true""#)?;
         }
-        if let Some(field) = self.aria_relevant.as_ref() {
+        if !self.aria_relevant.is_empty() {
+            let field = crate::join_list(&self.aria_relevant);
+            let field = crate::escape::AttributeValue(&field);
             write!(writer, r#" aria-relevant="{field}""#)?;
         }
         if self.aria_required {
-            write!(writer, r#" aria-required"#)?;
+            write!(writer, r#" aria-required="true""#)?;
         }
         if let Some(field) = self.aria_role_description.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-roledescription="{field}""#)?;
         }
         if let Some(field) = self.aria_row_count.as_ref() {
@@ -254,18 +615,20 @@ impl crate::RenderElement for InsertedText {
             write!(writer, r#" aria-rowindex="{field}""#)?;
         }
         if let Some(field) = self.aria_row_index_text.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-rowindextext="{field}""#)?;
         }
         if let Some(field) = self.aria_row_span.as_ref() {
             write!(writer, r#" aria-rowspan="{field}""#)?;
         }
-        if self.aria_selected {
-            write!(writer, r#" aria-selected"#)?;
+        if let Some(field) = self.aria_selected.as_ref() {
+            write!(writer, r#" aria-selected="{field}""#)?;
         }
         if let Some(field) = self.aria_set_size.as_ref() {
             write!(writer, r#" aria-setsize="{field}""#)?;
         }
         if let Some(field) = self.aria_sort.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" aria-sort="{field}""#)?;
         }
         if let Some(field) = self.aria_value_max.as_ref() {
@@ -278,6 +641,7 @@ impl crate::RenderElement for InsertedText {
             write!(writer, r#" aria-valuenow="{field}""#)?;
         }
         if let Some(field) = self.aria_value_text.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" aria-valuetext="{field}""#)?;
         }
         write!(writer, "{}", self.global_attrs)?;
@@ -292,28 +656,26 @@ impl crate::RenderElement for InsertedText {
     }
 }
 impl crate::ElementDescription for InsertedText {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.cite {
-            attrs.insert(std::borrow::Cow::Borrowed("cite"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("cite"), field.clone());
         }
         if let Some(field) = &self.date_time {
-            attrs.insert(std::borrow::Cow::Borrowed("datetime"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("datetime"), field.clone());
         }
         if let Some(field) = &self.role {
-            attrs.insert(std::borrow::Cow::Borrowed("role"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("role"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.aria_active_descendant_element {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-activedescendant"),
-                    field.to_owned(),
+                    field.clone(),
                 );
         }
         if self.aria_atomic {
@@ -327,14 +689,14 @@ impl crate::ElementDescription for InsertedText {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-autocomplete"),
-                    field.to_owned(),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_braille_role_description {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-brailleroledescription"),
-                    field.to_owned(),
+                    field.clone(),
                 );
         }
         if self.aria_busy {
@@ -345,59 +707,73 @@ impl crate::ElementDescription for InsertedText {
                 );
         }
         if let Some(field) = &self.aria_checked {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-checked"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-checked"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.aria_col_count {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-colcount"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_col_index {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-colindex"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_col_index_text {
-            attrs
-                .insert(
-                    std::borrow::Cow::Borrowed("aria-colindextext"),
-                    field.to_owned(),
-                );
+            attrs.insert(std::borrow::Cow::Borrowed("aria-colindextext"), field.clone());
         }
         if let Some(field) = &self.aria_col_span {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-colspan"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
-        if let Some(field) = &self.aria_controls_elements {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-controls"), field.to_owned());
+        if !self.aria_controls_elements.is_empty() {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-controls"),
+                    std::borrow::Cow::Owned(
+                        crate::join_list(&self.aria_controls_elements),
+                    ),
+                );
         }
         if let Some(field) = &self.aria_current {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-current"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-current"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
-        if let Some(field) = &self.aria_described_by_elements {
+        if !self.aria_described_by_elements.is_empty() {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-describedby"),
-                    field.to_owned(),
+                    std::borrow::Cow::Owned(
+                        crate::join_list(&self.aria_described_by_elements),
+                    ),
                 );
         }
         if let Some(field) = &self.aria_description {
+            attrs.insert(std::borrow::Cow::Borrowed("aria-description"), field.clone());
+        }
+        if !self.aria_details_elements.is_empty() {
             attrs
                 .insert(
-                    std::borrow::Cow::Borrowed("aria-description"),
-                    field.to_owned(),
+                    std::borrow::Cow::Borrowed("aria-details"),
+                    std::borrow::Cow::Owned(
+                        crate::join_list(&self.aria_details_elements),
+                    ),
                 );
         }
-        if let Some(field) = &self.aria_details_elements {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-details"), field.to_owned());
-        }
         if self.aria_disabled {
             attrs
                 .insert(
@@ -405,63 +781,82 @@ impl crate::ElementDescription for InsertedText {
                     std::borrow::Cow::Borrowed("true"),
                 );
         }
-        if let Some(field) = &self.aria_drop_effect {
+        if !self.aria_drop_effect.is_empty() {
             attrs
-                .insert(std::borrow::Cow::Borrowed("aria-dropeffect"), field.to_owned());
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-dropeffect"),
+                    std::borrow::Cow::Owned(crate::join_list(&self.aria_drop_effect)),
+                );
         }
-        if let Some(field) = &self.aria_error_message_elements {
+        if !self.aria_error_message_elements.is_empty() {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-errormessage"),
-                    field.to_owned(),
+                    std::borrow::Cow::Owned(
+                        crate::join_list(&self.aria_error_message_elements),
+                    ),
                 );
         }
-        if self.aria_expanded {
+        if let Some(field) = &self.aria_expanded {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-expanded"),
-                    std::borrow::Cow::Borrowed("true"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
-        if let Some(field) = &self.aria_flow_to_elements {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-flowto"), field.to_owned());
+        if !self.aria_flow_to_elements.is_empty() {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-flowto"),
+                    std::borrow::Cow::Owned(
+                        crate::join_list(&self.aria_flow_to_elements),
+                    ),
+                );
         }
-        if self.aria_grabbed {
+        if let Some(field) = &self.aria_grabbed {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-grabbed"),
-                    std::borrow::Cow::Borrowed("true"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_has_popup {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-haspopup"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-haspopup"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
-        if self.aria_hidden {
+        if let Some(field) = &self.aria_hidden {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-hidden"),
-                    std::borrow::Cow::Borrowed("true"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_invalid {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-invalid"), field.to_owned());
-        }
-        if let Some(field) = &self.aria_key_shortcuts {
             attrs
                 .insert(
-                    std::borrow::Cow::Borrowed("aria-keyshortcuts"),
-                    field.to_owned(),
+                    std::borrow::Cow::Borrowed("aria-invalid"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
+        if let Some(field) = &self.aria_key_shortcuts {
+            attrs.insert(std::borrow::Cow::Borrowed("aria-keyshortcuts"), field.clone());
+        }
         if let Some(field) = &self.aria_level {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-level"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_live {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-live"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-live"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if self.aria_modal {
             attrs
@@ -488,28 +883,32 @@ impl crate::ElementDescription for InsertedText {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-orientation"),
-                    field.to_owned(),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
-        if let Some(field) = &self.aria_owns_elements {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-owns"), field.to_owned());
-        }
-        if let Some(field) = &self.aria_placeholder {
+        if !self.aria_owns_elements.is_empty() {
             attrs
                 .insert(
-                    std::borrow::Cow::Borrowed("aria-placeholder"),
-                    field.to_owned(),
+                    std::borrow::Cow::Borrowed("aria-owns"),
+                    std::borrow::Cow::Owned(crate::join_list(&self.aria_owns_elements)),
                 );
         }
+        if let Some(field) = &self.aria_placeholder {
+            attrs.insert(std::borrow::Cow::Borrowed("aria-placeholder"), field.clone());
+        }
         if let Some(field) = &self.aria_pos_in_set {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-posinset"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_pressed {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-pressed"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-pressed"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if self.aria_read_only {
             attrs
@@ -518,8 +917,12 @@ impl crate::ElementDescription for InsertedText {
                     std::borrow::Cow::Borrowed("true"),
                 );
         }
-        if let Some(field) = &self.aria_relevant {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-relevant"), field.to_owned());
+        if !self.aria_relevant.is_empty() {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-relevant"),
+                    std::borrow::Cow::Owned(crate::join_list(&self.aria_relevant)),
+                );
         }
         if self.aria_required {
             attrs
@@ -532,95 +935,89 @@ impl crate::ElementDescription for InsertedText {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-roledescription"),
-                    field.to_owned(),
+                    field.clone(),
                 );
         }
         if let Some(field) = &self.aria_row_count {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-rowcount"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_row_index {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-rowindex"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_row_index_text {
-            attrs
-                .insert(
-                    std::borrow::Cow::Borrowed("aria-rowindextext"),
-                    field.to_owned(),
-                );
+            attrs.insert(std::borrow::Cow::Borrowed("aria-rowindextext"), field.clone());
         }
         if let Some(field) = &self.aria_row_span {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-rowspan"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
-        if self.aria_selected {
+        if let Some(field) = &self.aria_selected {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-selected"),
-                    std::borrow::Cow::Borrowed("true"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_set_size {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-setsize"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_sort {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-sort"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("aria-sort"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.aria_value_max {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-valuemax"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_value_min {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-valuemin"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_value_now {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("aria-valuenow"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.aria_value_text {
-            attrs.insert(std::borrow::Cow::Borrowed("aria-valuetext"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("aria-valuetext"), field.clone());
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for InsertedText {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for InsertedText {
@@ -4,21 +4,38 @@
 #[doc(alias = "source")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct MediaSource {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// Type of embedded resource
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub type_: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Applicable media
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub media: std::option::Option<std::borrow::Cow<'static, str>>,
 }
 impl crate::RenderElement for MediaSource {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<source")?;
         if let Some(field) = self.type_.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" type="{field}""#)?;
         }
         if let Some(field) = self.media.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" media="{field}""#)?;
         }
         write!(writer, "{}", self.global_attrs)?;
@@ -32,37 +49,25 @@ impl crate::RenderElement for MediaSource {
     }
 }
 impl crate::ElementDescription for MediaSource {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.type_ {
-            attrs.insert(std::borrow::Cow::Borrowed("type"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("type"), field.clone());
         }
         if let Some(field) = &self.media {
-            attrs.insert(std::borrow::Cow::Borrowed("media"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("media"), field.clone());
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for MediaSource {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for MediaSource {
@@ -4,33 +4,61 @@
 #[doc(alias = "track")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TextTrack {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// The type of text track
-    pub kind: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub kind: std::option::Option<crate::attributes::TrackKind>,
     /// Address of the resource
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub src: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Language of the text track
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub srclang: std::option::Option<std::borrow::Cow<'static, str>>,
     /// User-visible label
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub label: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Enable the track if no other text track is more suitable
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
     pub default: bool,
 }
 impl crate::RenderElement for TextTrack {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<track")?;
         if let Some(field) = self.kind.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" kind="{field}""#)?;
         }
         if let Some(field) = self.src.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" src="{field}""#)?;
         }
         if let Some(field) = self.srclang.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" srclang="{field}""#)?;
         }
         if let Some(field) = self.label.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" label="{field}""#)?;
         }
         if self.default {
@@ -47,25 +75,23 @@ impl crate::RenderElement for TextTrack {
     }
 }
 impl crate::ElementDescription for TextTrack {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.kind {
-            attrs.insert(std::borrow::Cow::Borrowed("kind"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("kind"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.src {
-            attrs.insert(std::borrow::Cow::Borrowed("src"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("src"), field.clone());
         }
         if let Some(field) = &self.srclang {
-            attrs.insert(std::borrow::Cow::Borrowed("srclang"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("srclang"), field.clone());
         }
         if let Some(field) = &self.label {
-            attrs.insert(std::borrow::Cow::Borrowed("label"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("label"), field.clone());
         }
         if self.default {
             attrs
@@ -74,23 +100,17 @@ impl crate::ElementDescription for TextTrack {
                     std::borrow::Cow::Borrowed("true"),
                 );
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for TextTrack {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for TextTrack {
@@ -2,49 +2,223 @@ pub mod edits;
 pub mod embedded;
 pub mod forms;
 pub mod interactive;
+pub mod mathml;
 pub mod metadata;
 pub mod root;
 pub mod scripting;
 pub mod sections;
+pub mod svg;
 pub mod tables;
 pub mod text;
+pub mod attributes;
 /// Render an element to a writer.
 pub trait RenderElement {
     /// Write the opening tag to a writer.
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result;
     /// Write the closing tag to a writer, if one is available.
     fn write_closing_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result;
+    /// Write the element without any content to a writer.
+    ///
+    /// Foreign elements such as the SVG elements are written as a single
+    /// self-closing tag, e.g. `<path d="M 0 0"/>`. HTML elements can't be
+    /// self-closing, so they're written as an opening and a closing tag.
+    fn write_empty_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        self.write_opening_tag(writer)?;
+        self.write_closing_tag(writer)
+    }
 }
 /// Get information about an element.
 pub trait ElementDescription {
-    /// Access the attributes that have a value for an element.
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    >;
-    /// Access the data attributes that have a value for an element.
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    >;
+    /// Access the attributes that have a value for an element, in the order
+    /// in which they're rendered.
+    fn attributes(&self) -> AttributeMap;
+    /// Access the data attributes that have a value for an element, in the
+    /// order in which they were inserted.
+    fn data(&self) -> AttributeMap;
+}
+/// A map of attribute names to their values, which iterates in insertion
+/// order.
+///
+/// Elements only have a handful of attributes, so entries are stored in a
+/// `Vec` rather than hashed. This keeps the order in which attributes are
+/// rendered the same between runs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AttributeMap {
+    entries: Vec<(std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>)>,
+}
+impl AttributeMap {
+    /// Create a new, empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns the number of attributes in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Returns `true` if the map holds no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// Returns `true` if the map holds a value for `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+    /// Get the value for `key`.
+    pub fn get(&self, key: &str) -> Option<&std::borrow::Cow<'static, str>> {
+        self.position(key).map(|index| &self.entries[index].1)
+    }
+    /// Get a mutable reference to the value for `key`.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut std::borrow::Cow<'static, str>> {
+        self.position(key).map(|index| &mut self.entries[index].1)
+    }
+    /// Insert a value for `key`, returning the previous value if there was
+    /// one. Replacing a value keeps the attribute in its original position.
+    pub fn insert(
+        &mut self,
+        key: std::borrow::Cow<'static, str>,
+        value: std::borrow::Cow<'static, str>,
+    ) -> Option<std::borrow::Cow<'static, str>> {
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+    /// Remove the value for `key`, keeping the order of the other
+    /// attributes.
+    pub fn remove(&mut self, key: &str) -> Option<std::borrow::Cow<'static, str>> {
+        self.position(key).map(|index| self.entries.remove(index).1)
+    }
+    /// Remove all attributes.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+    /// Iterate over the attributes in insertion order.
+    pub fn iter(&self) -> AttributeIter<'_> {
+        AttributeIter {
+            entries: self.entries.iter(),
+        }
+    }
+    /// Iterate over the attribute names in insertion order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.iter().map(|(key, _)| key)
+    }
+    /// Iterate over the attribute values in insertion order.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.iter().map(|(_, value)| value)
+    }
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| k == key)
+    }
+}
+impl<'a> IntoIterator for &'a AttributeMap {
+    type Item = (&'a str, &'a str);
+    type IntoIter = AttributeIter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for AttributeMap {
+    type Item = (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+impl<K, V> Extend<(K, V)> for AttributeMap
+where
+    K: Into<std::borrow::Cow<'static, str>>,
+    V: Into<std::borrow::Cow<'static, str>>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key.into(), value.into());
+        }
+    }
+}
+impl<K, V> FromIterator<(K, V)> for AttributeMap
+where
+    K: Into<std::borrow::Cow<'static, str>>,
+    V: Into<std::borrow::Cow<'static, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+/// An iterator over the attributes of an [`AttributeMap`], in insertion order.
+#[derive(Debug, Clone)]
+pub struct AttributeIter<'a> {
+    entries: std::slice::Iter<
+        'a,
+        (std::borrow::Cow<'static, str>, std::borrow::Cow<'static, str>),
+    >,
+}
+impl<'a> Iterator for AttributeIter<'a> {
+    type Item = (&'a str, &'a str);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(key, value)| (&**key, &**value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+impl DoubleEndedIterator for AttributeIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.entries.next_back().map(|(key, value)| (&**key, &**value))
+    }
+}
+impl ExactSizeIterator for AttributeIter<'_> {}
+/// Attribute maps are serialized as maps, in insertion order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeMap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AttributeMap {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = AttributeMap;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a map of attribute names to values")
+            }
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut map = AttributeMap::new();
+                while let Some((key, value)) = access.next_entry::<String, String>()? {
+                    map.insert(key.into(), value.into());
+                }
+                Ok(map)
+            }
+        }
+        deserializer.deserialize_map(Visitor)
+    }
 }
 /// Container for `data-*` attributes.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct DataMap {
-    map: std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    >,
+    map: AttributeMap,
+}
+impl DataMap {
+    /// Returns `true` if no `data-*` attributes have been set.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
 }
 impl std::ops::Deref for DataMap {
-    type Target = std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    >;
+    type Target = AttributeMap;
     fn deref(&self) -> &Self::Target {
         &self.map
     }
@@ -57,173 +231,1246 @@ impl std::ops::DerefMut for DataMap {
 impl std::fmt::Display for DataMap {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (key, value) in self.map.iter() {
+            if !escape::is_attribute_name(key) {
+                continue;
+            }
+            let value = escape::AttributeValue(value);
             write!(writer, r#" data-{key}="{value}""#)?;
         }
         Ok(())
     }
 }
+/// The doctype which is written before the `<html>` element.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Doctype {
+    /// The HTML doctype, `<!DOCTYPE html>`.
+    #[default]
+    Html,
+    /// A doctype with custom contents, such as
+    /// `html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN"`. Any `>` is left
+    /// out, since it would end the doctype early.
+    Custom(std::borrow::Cow<'static, str>),
+    /// No doctype is written.
+    Omitted,
+}
+impl Doctype {
+    /// Returns `true` if this is the HTML doctype.
+    pub fn is_html(&self) -> bool {
+        *self == Doctype::Html
+    }
+}
+impl std::fmt::Display for Doctype {
+    fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Doctype::Html => writer.write_str("<!DOCTYPE html>"),
+            Doctype::Custom(doctype) => {
+                writer.write_str("<!DOCTYPE ")?;
+                for part in doctype.split('>') {
+                    writer.write_str(part)?;
+                }
+                writer.write_str(">")
+            }
+            Doctype::Omitted => Ok(()),
+        }
+    }
+}
+/// Join the values of a list attribute, which are separated by spaces.
+fn join_list<T: std::fmt::Display>(values: &[T]) -> String {
+    values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(" ")
+}
+/// Escape strings so they can be safely written into an HTML document.
+///
+/// Which characters need to be escaped depends on where in the document a
+/// string ends up. Attribute values, regular text, comments, and the contents
+/// of raw text elements such as `<script>` and `<style>` each have their own
+/// rules.
+pub mod escape {
+    use std::fmt::{self, Display, Write};
+    /// An attribute value which is escaped for use between double quotes.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AttributeValue<'a>(pub &'a str);
+    impl Display for AttributeValue<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_escaped(
+                f,
+                self.0,
+                |c| match c {
+                    '&' => Some("&amp;"),
+                    '"' => Some("&quot;"),
+                    '<' => Some("&lt;"),
+                    '>' => Some("&gt;"),
+                    _ => None,
+                },
+            )
+        }
+    }
+    /// A text node which is escaped so it can't be parsed as markup.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Text<'a>(pub &'a str);
+    impl Display for Text<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_escaped(
+                f,
+                self.0,
+                |c| match c {
+                    '&' => Some("&amp;"),
+                    '<' => Some("&lt;"),
+                    '>' => Some("&gt;"),
+                    _ => None,
+                },
+            )
+        }
+    }
+    /// The text contents of a raw text element such as `<script>` or `<style>`.
+    ///
+    /// Character references are not decoded inside raw text elements, so the
+    /// text can't be escaped the way regular text is. Instead we make sure the
+    /// text can't end the element early: any `</{tag_name}` sequence (compared
+    /// case-insensitively) is written as `<\/{tag_name}`, and inside `<script>`
+    /// the `<!--` sequence is written as `<\!--`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct RawText<'a> {
+        tag_name: &'a str,
+        text: &'a str,
+    }
+    impl<'a> RawText<'a> {
+        /// Create a new instance of `RawText` for the element `tag_name`.
+        pub fn new(tag_name: &'a str, text: &'a str) -> Self {
+            Self { tag_name, text }
+        }
+    }
+    impl Display for RawText<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let is_script = self.tag_name.eq_ignore_ascii_case("script");
+            let mut text = self.text;
+            while let Some(index) = text.find('<') {
+                f.write_str(&text[..index])?;
+                text = &text[index + 1..];
+                let ends_element = text
+                    .strip_prefix('/')
+                    .and_then(|rest| rest.get(..self.tag_name.len()))
+                    .is_some_and(|name| name.eq_ignore_ascii_case(self.tag_name));
+                let opens_comment = is_script && text.starts_with("!--");
+                match ends_element || opens_comment {
+                    true => f.write_str("<\\")?,
+                    false => f.write_char('<')?,
+                }
+            }
+            f.write_str(text)
+        }
+    }
+    /// The text of a comment, which is escaped so it can't end the comment
+    /// early.
+    ///
+    /// Comments can't hold character references, so instead a space is
+    /// inserted between any two consecutive hyphens. A space is also put in
+    /// front of text starting with `>` or `->`, and after text ending with
+    /// `<!-`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Comment<'a>(pub &'a str);
+    impl Display for Comment<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.0.starts_with('>') || self.0.starts_with("->") {
+                f.write_char(' ')?;
+            }
+            let mut text = self.0;
+            while let Some(index) = text.find("--") {
+                f.write_str(&text[..index + 1])?;
+                f.write_char(' ')?;
+                text = &text[index + 1..];
+            }
+            f.write_str(text)?;
+            if self.0.ends_with("<!-") {
+                f.write_char(' ')?;
+            }
+            Ok(())
+        }
+    }
+    /// Returns `true` if `name` only holds characters which are permitted in
+    /// attribute names: anything but controls, whitespace, `"`, `'`, `>`,
+    /// `/`, `=` and noncharacters.
+    pub fn is_attribute_name(name: &str) -> bool {
+        name.chars().all(is_attribute_name_char)
+    }
+    fn is_attribute_name_char(c: char) -> bool {
+        if c.is_control() || c.is_whitespace() {
+            return false;
+        }
+        let noncharacter = matches!(c, '\u{FDD0}'..='\u{FDEF}');
+        let noncharacter = noncharacter || (c as u32 & 0xFFFE) == 0xFFFE;
+        !noncharacter && !matches!(c, '"' | '\'' | '>' | '/' | '=')
+    }
+    fn write_escaped(
+        f: &mut fmt::Formatter<'_>,
+        s: &str,
+        escape: impl Fn(char) -> Option<&'static str>,
+    ) -> fmt::Result {
+        let mut last = 0;
+        for (index, c) in s.char_indices() {
+            if let Some(escaped) = escape(c) {
+                f.write_str(&s[last..index])?;
+                f.write_str(escaped)?;
+                last = index + c.len_utf8();
+            }
+        }
+        f.write_str(&s[last..])
+    }
+}
 /// The "global attributes" struct
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GlobalAttributes {
     /// Provides a hint for generating a keyboard shortcut for the current element
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "accesskey", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub access_key: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Controls whether and how text input is automatically capitalized as it is entered/edited by the user
-    pub auto_capitalize: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "autocapitalize",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub auto_capitalize: std::option::Option<crate::attributes::AutoCapitalize>,
     /// Indicates that an element should be focused on page load, or when the <dialog> that it is part of is displayed
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
     pub autofocus: bool,
     /// A space-separated list of the case-sensitive classes of the element
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub class: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Indicates if the element should be editable by the user
-    pub content_editable: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "contenteditable",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub content_editable: std::option::Option<crate::attributes::ContentEditable>,
     /// Indicates the directionality of the element's text
-    pub direction: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dir", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub direction: std::option::Option<crate::attributes::Direction>,
     /// Indicates whether the element can be dragged, either with native browser behavior or the HTML Drag and Drop API.
-    pub draggable: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub draggable: std::option::Option<crate::attributes::Draggable>,
     /// Defines what action label (or icon) to present for the enter key on virtual keyboards
-    pub enter_key_hint: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "enterkeyhint",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub enter_key_hint: std::option::Option<crate::attributes::EnterKeyHint>,
     /// The exportparts global attribute allows you to select and style elements existing in nested shadow trees, by exporting their part names
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "exportparts",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
     pub export_parts: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Indicates that the browser should not render the contents of the element
-    pub hidden: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub hidden: std::option::Option<crate::attributes::Hidden>,
     /// Defines an identifier (ID) which must be unique in the whole document
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub id: std::option::Option<std::borrow::Cow<'static, str>>,
     /// indicating that the browser will ignore the element
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
     pub inert: bool,
     /// hints at the type of data that might be entered by the user while editing the element or its contents
-    pub input_mode: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "inputmode", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub input_mode: std::option::Option<crate::attributes::InputMode>,
     /// allows you to specify that a standard HTML element should behave like a defined custom built-in element
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "is", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub is_: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The itemid global attribute provides microdata in the form of a unique, global identifier of an item
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "itemid", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub item_id: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The itemprop global attribute is used to add properties to an item
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "itemprop", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub item_prop: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Properties that are not descendants of an element with the itemscope attribute can be associated with an item using the global attribute itemref
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "itemref", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub item_ref: std::option::Option<std::borrow::Cow<'static, str>>,
     /// itemscope is a boolean global attribute that defines the scope of associated metadata
-    pub item_scope: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "itemscope", skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub item_scope: bool,
     /// The global attribute itemtype specifies the URL of the vocabulary that will be used to define itemprop's (item properties) in the data structure
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "itemtype", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub item_type: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The lang global attribute helps define the language of an element: the language that non-editable elements are written in, or the language that the editable elements should be written in by the user
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub lang: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The nonce global attribute is a content attribute defining a cryptographic nonce ("number used once") which can be used by Content Security Policy to determine whether or not a given fetch will be allowed to proceed for a given element
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub nonce: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The part global attribute contains a space-separated list of the part names of the element
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub part: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The slot global attribute assigns a slot in a shadow DOM shadow tree to an element: An element with a slot attribute is assigned to the slot created by the <slot> element whose name attribute's value matches that slot attribute's value
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub slot: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The spellcheck global attribute is an enumerated attribute that defines whether the element may be checked for spelling errors
-    pub spellcheck: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub spellcheck: std::option::Option<crate::attributes::Spellcheck>,
     /// The style global attribute contains CSS styling declarations to be applied to the element
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub style: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The tabindex global attribute allows developers to make HTML elements focusable, allow or prevent them from being sequentially focusable (usually with the Tab key, hence the name) and determine their relative ordering for sequential focus navigation
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "tabindex", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub tab_index: std::option::Option<i64>,
     /// The title global attribute contains text representing advisory information related to the element it belongs to
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub title: std::option::Option<std::borrow::Cow<'static, str>>,
     /// The translate global attribute is an enumerated attribute that is used to specify whether an element's translatable attribute values and its Text node children should be translated when the page is localized, or whether to leave them unchanged
-    pub translate: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub translate: std::option::Option<crate::attributes::Translate>,
+    /// Handler for the `abort` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onabort: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `auxclick` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onauxclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `beforeinput` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onbeforeinput: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `beforematch` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onbeforematch: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `beforetoggle` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onbeforetoggle: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `blur` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onblur: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cancel` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncancel: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `canplay` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncanplay: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `canplaythrough` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncanplaythrough: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `change` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `click` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `close` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onclose: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `contextlost` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncontextlost: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `contextmenu` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncontextmenu: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `contextrestored` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncontextrestored: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `copy` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncopy: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cuechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncuechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cut` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncut: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dblclick` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondblclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `drag` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondrag: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragenter` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragenter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragleave` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragleave: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragover` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragover: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `drop` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondrop: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `durationchange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondurationchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `emptied` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onemptied: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `ended` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onended: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `error` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onerror: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `focus` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onfocus: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `formdata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onformdata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `input` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oninput: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `invalid` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oninvalid: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keydown` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeydown: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keypress` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeypress: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keyup` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeyup: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `load` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onload: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadeddata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadeddata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadedmetadata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadedmetadata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mousedown` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmousedown: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseenter` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseenter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseleave` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseleave: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mousemove` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmousemove: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseout` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseout: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseover` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseover: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseup` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseup: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `paste` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onpaste: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `pause` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onpause: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `play` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onplay: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `playing` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onplaying: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `progress` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onprogress: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `ratechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onratechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `reset` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onreset: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `resize` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onresize: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `scroll` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onscroll: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `scrollend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onscrollend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `securitypolicyviolation` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsecuritypolicyviolation: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `seeked` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onseeked: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `seeking` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onseeking: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `select` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onselect: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `slotchange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onslotchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `stalled` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onstalled: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `submit` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsubmit: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `suspend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsuspend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `timeupdate` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ontimeupdate: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `toggle` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ontoggle: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `volumechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onvolumechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `waiting` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwaiting: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkitanimationend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkitanimationend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkitanimationiteration` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkitanimationiteration: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkitanimationstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkitanimationstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkittransitionend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkittransitionend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `wheel` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwheel: std::option::Option<std::borrow::Cow<'static, str>>,
 }
 impl std::fmt::Display for GlobalAttributes {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(field) = self.access_key.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" accesskey="{field}""#)?;
         }
         if let Some(field) = self.auto_capitalize.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" autocapitalize="{field}""#)?;
         }
         if self.autofocus {
             write!(writer, r#" autofocus"#)?;
         }
         if let Some(field) = self.class.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" class="{field}""#)?;
         }
         if let Some(field) = self.content_editable.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" contenteditable="{field}""#)?;
         }
         if let Some(field) = self.direction.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" dir="{field}""#)?;
         }
-        if self.draggable {
-            write!(writer, r#" draggable"#)?;
+        if let Some(field) = self.draggable.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
+            write!(writer, r#" draggable="{field}""#)?;
         }
         if let Some(field) = self.enter_key_hint.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" enterkeyhint="{field}""#)?;
         }
         if let Some(field) = self.export_parts.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" exportparts="{field}""#)?;
         }
         if let Some(field) = self.hidden.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" hidden="{field}""#)?;
         }
         if let Some(field) = self.id.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" id="{field}""#)?;
         }
         if self.inert {
             write!(writer, r#" inert"#)?;
         }
         if let Some(field) = self.input_mode.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" inputmode="{field}""#)?;
         }
         if let Some(field) = self.is_.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" is="{field}""#)?;
         }
         if let Some(field) = self.item_id.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" itemid="{field}""#)?;
         }
         if let Some(field) = self.item_prop.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" itemprop="{field}""#)?;
         }
         if let Some(field) = self.item_ref.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" itemref="{field}""#)?;
         }
-        if let Some(field) = self.item_scope.as_ref() {
-            write!(writer, r#" itemscope="{field}""#)?;
+        if self.item_scope {
+            write!(writer, r#" itemscope"#)?;
         }
         if let Some(field) = self.item_type.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" itemtype="{field}""#)?;
         }
         if let Some(field) = self.lang.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" lang="{field}""#)?;
         }
         if let Some(field) = self.nonce.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" nonce="{field}""#)?;
         }
         if let Some(field) = self.part.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" part="{field}""#)?;
         }
         if let Some(field) = self.slot.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" slot="{field}""#)?;
         }
         if let Some(field) = self.spellcheck.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" spellcheck="{field}""#)?;
         }
         if let Some(field) = self.style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" style="{field}""#)?;
         }
         if let Some(field) = self.tab_index.as_ref() {
             write!(writer, r#" tabindex="{field}""#)?;
         }
         if let Some(field) = self.title.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" title="{field}""#)?;
         }
-        if self.translate {
-            write!(writer, r#" translate"#)?;
+        if let Some(field) = self.translate.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
+            write!(writer, r#" translate="{field}""#)?;
+        }
+        if let Some(field) = self.onabort.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onabort="{field}""#)?;
+        }
+        if let Some(field) = self.onauxclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onauxclick="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforeinput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforeinput="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforematch.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforematch="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforetoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforetoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onblur.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onblur="{field}""#)?;
+        }
+        if let Some(field) = self.oncancel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncancel="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplay="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplaythrough.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplaythrough="{field}""#)?;
+        }
+        if let Some(field) = self.onchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onchange="{field}""#)?;
+        }
+        if let Some(field) = self.onclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclick="{field}""#)?;
+        }
+        if let Some(field) = self.onclose.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclose="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextlost.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextlost="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextmenu.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextmenu="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextrestored.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextrestored="{field}""#)?;
+        }
+        if let Some(field) = self.oncopy.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncopy="{field}""#)?;
+        }
+        if let Some(field) = self.oncuechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncuechange="{field}""#)?;
+        }
+        if let Some(field) = self.oncut.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncut="{field}""#)?;
+        }
+        if let Some(field) = self.ondblclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondblclick="{field}""#)?;
+        }
+        if let Some(field) = self.ondrag.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrag="{field}""#)?;
+        }
+        if let Some(field) = self.ondragend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragend="{field}""#)?;
+        }
+        if let Some(field) = self.ondragenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragenter="{field}""#)?;
+        }
+        if let Some(field) = self.ondragleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragleave="{field}""#)?;
+        }
+        if let Some(field) = self.ondragover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragover="{field}""#)?;
+        }
+        if let Some(field) = self.ondragstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragstart="{field}""#)?;
+        }
+        if let Some(field) = self.ondrop.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrop="{field}""#)?;
+        }
+        if let Some(field) = self.ondurationchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondurationchange="{field}""#)?;
+        }
+        if let Some(field) = self.onemptied.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onemptied="{field}""#)?;
+        }
+        if let Some(field) = self.onended.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onended="{field}""#)?;
+        }
+        if let Some(field) = self.onerror.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onerror="{field}""#)?;
+        }
+        if let Some(field) = self.onfocus.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onfocus="{field}""#)?;
+        }
+        if let Some(field) = self.onformdata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onformdata="{field}""#)?;
+        }
+        if let Some(field) = self.oninput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninput="{field}""#)?;
+        }
+        if let Some(field) = self.oninvalid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninvalid="{field}""#)?;
+        }
+        if let Some(field) = self.onkeydown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeydown="{field}""#)?;
+        }
+        if let Some(field) = self.onkeypress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeypress="{field}""#)?;
+        }
+        if let Some(field) = self.onkeyup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeyup="{field}""#)?;
+        }
+        if let Some(field) = self.onload.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onload="{field}""#)?;
+        }
+        if let Some(field) = self.onloadeddata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadeddata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadedmetadata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadedmetadata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadstart="{field}""#)?;
+        }
+        if let Some(field) = self.onmousedown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousedown="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseenter="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseleave="{field}""#)?;
+        }
+        if let Some(field) = self.onmousemove.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousemove="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseout.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseout="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseover="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseup="{field}""#)?;
+        }
+        if let Some(field) = self.onpaste.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpaste="{field}""#)?;
+        }
+        if let Some(field) = self.onpause.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpause="{field}""#)?;
+        }
+        if let Some(field) = self.onplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplay="{field}""#)?;
+        }
+        if let Some(field) = self.onplaying.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplaying="{field}""#)?;
+        }
+        if let Some(field) = self.onprogress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onprogress="{field}""#)?;
+        }
+        if let Some(field) = self.onratechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onratechange="{field}""#)?;
+        }
+        if let Some(field) = self.onreset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onreset="{field}""#)?;
+        }
+        if let Some(field) = self.onresize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onresize="{field}""#)?;
+        }
+        if let Some(field) = self.onscroll.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscroll="{field}""#)?;
+        }
+        if let Some(field) = self.onscrollend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscrollend="{field}""#)?;
+        }
+        if let Some(field) = self.onsecuritypolicyviolation.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsecuritypolicyviolation="{field}""#)?;
+        }
+        if let Some(field) = self.onseeked.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeked="{field}""#)?;
+        }
+        if let Some(field) = self.onseeking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeking="{field}""#)?;
+        }
+        if let Some(field) = self.onselect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onselect="{field}""#)?;
+        }
+        if let Some(field) = self.onslotchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onslotchange="{field}""#)?;
+        }
+        if let Some(field) = self.onstalled.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onstalled="{field}""#)?;
+        }
+        if let Some(field) = self.onsubmit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsubmit="{field}""#)?;
+        }
+        if let Some(field) = self.onsuspend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsuspend="{field}""#)?;
+        }
+        if let Some(field) = self.ontimeupdate.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontimeupdate="{field}""#)?;
+        }
+        if let Some(field) = self.ontoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onvolumechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onvolumechange="{field}""#)?;
+        }
+        if let Some(field) = self.onwaiting.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwaiting="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationend="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationiteration.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationiteration="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationstart="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkittransitionend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkittransitionend="{field}""#)?;
+        }
+        if let Some(field) = self.onwheel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwheel="{field}""#)?;
         }
         Ok(())
     }
 }
 impl GlobalAttributes {
-    fn add(
-        &self,
-        attrs: &mut std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        >,
-    ) {
+    fn add(&self, attrs: &mut crate::AttributeMap) {
         if let Some(field) = &self.access_key {
-            attrs.insert(std::borrow::Cow::Borrowed("accesskey"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("accesskey"), field.clone());
         }
         if let Some(field) = &self.auto_capitalize {
-            attrs.insert(std::borrow::Cow::Borrowed("autocapitalize"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("autocapitalize"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if self.autofocus {
             attrs
@@ -233,33 +1480,48 @@ impl GlobalAttributes {
                 );
         }
         if let Some(field) = &self.class {
-            attrs.insert(std::borrow::Cow::Borrowed("class"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("class"), field.clone());
         }
         if let Some(field) = &self.content_editable {
             attrs
-                .insert(std::borrow::Cow::Borrowed("contenteditable"), field.to_owned());
+                .insert(
+                    std::borrow::Cow::Borrowed("contenteditable"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.direction {
-            attrs.insert(std::borrow::Cow::Borrowed("dir"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("dir"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
-        if self.draggable {
+        if let Some(field) = &self.draggable {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("draggable"),
-                    std::borrow::Cow::Borrowed("true"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.enter_key_hint {
-            attrs.insert(std::borrow::Cow::Borrowed("enterkeyhint"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("enterkeyhint"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.export_parts {
-            attrs.insert(std::borrow::Cow::Borrowed("exportparts"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("exportparts"), field.clone());
         }
         if let Some(field) = &self.hidden {
-            attrs.insert(std::borrow::Cow::Borrowed("hidden"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("hidden"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.id {
-            attrs.insert(std::borrow::Cow::Borrowed("id"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("id"), field.clone());
         }
         if self.inert {
             attrs
@@ -269,60 +1531,317 @@ impl GlobalAttributes {
                 );
         }
         if let Some(field) = &self.input_mode {
-            attrs.insert(std::borrow::Cow::Borrowed("inputmode"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("inputmode"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.is_ {
-            attrs.insert(std::borrow::Cow::Borrowed("is"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("is"), field.clone());
         }
         if let Some(field) = &self.item_id {
-            attrs.insert(std::borrow::Cow::Borrowed("itemid"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("itemid"), field.clone());
         }
         if let Some(field) = &self.item_prop {
-            attrs.insert(std::borrow::Cow::Borrowed("itemprop"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("itemprop"), field.clone());
         }
         if let Some(field) = &self.item_ref {
-            attrs.insert(std::borrow::Cow::Borrowed("itemref"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("itemref"), field.clone());
         }
-        if let Some(field) = &self.item_scope {
-            attrs.insert(std::borrow::Cow::Borrowed("itemscope"), field.to_owned());
+        if self.item_scope {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("itemscope"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
         }
         if let Some(field) = &self.item_type {
-            attrs.insert(std::borrow::Cow::Borrowed("itemtype"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("itemtype"), field.clone());
         }
         if let Some(field) = &self.lang {
-            attrs.insert(std::borrow::Cow::Borrowed("lang"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("lang"), field.clone());
         }
         if let Some(field) = &self.nonce {
-            attrs.insert(std::borrow::Cow::Borrowed("nonce"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("nonce"), field.clone());
         }
         if let Some(field) = &self.part {
-            attrs.insert(std::borrow::Cow::Borrowed("part"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("part"), field.clone());
         }
         if let Some(field) = &self.slot {
-            attrs.insert(std::borrow::Cow::Borrowed("slot"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("slot"), field.clone());
         }
         if let Some(field) = &self.spellcheck {
-            attrs.insert(std::borrow::Cow::Borrowed("spellcheck"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("spellcheck"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.style {
-            attrs.insert(std::borrow::Cow::Borrowed("style"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("style"), field.clone());
         }
         if let Some(field) = &self.tab_index {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("tabindex"),
-                    std::borrow::Cow::Owned(format!(r#" {field}"#)),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
         if let Some(field) = &self.title {
-            attrs.insert(std::borrow::Cow::Borrowed("title"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("title"), field.clone());
         }
-        if self.translate {
+        if let Some(field) = &self.translate {
             attrs
                 .insert(
                     std::borrow::Cow::Borrowed("translate"),
-                    std::borrow::Cow::Borrowed("true"),
+                    std::borrow::Cow::Owned(field.to_string()),
                 );
         }
+        if let Some(field) = &self.onabort {
+            attrs.insert(std::borrow::Cow::Borrowed("onabort"), field.clone());
+        }
+        if let Some(field) = &self.onauxclick {
+            attrs.insert(std::borrow::Cow::Borrowed("onauxclick"), field.clone());
+        }
+        if let Some(field) = &self.onbeforeinput {
+            attrs.insert(std::borrow::Cow::Borrowed("onbeforeinput"), field.clone());
+        }
+        if let Some(field) = &self.onbeforematch {
+            attrs.insert(std::borrow::Cow::Borrowed("onbeforematch"), field.clone());
+        }
+        if let Some(field) = &self.onbeforetoggle {
+            attrs.insert(std::borrow::Cow::Borrowed("onbeforetoggle"), field.clone());
+        }
+        if let Some(field) = &self.onblur {
+            attrs.insert(std::borrow::Cow::Borrowed("onblur"), field.clone());
+        }
+        if let Some(field) = &self.oncancel {
+            attrs.insert(std::borrow::Cow::Borrowed("oncancel"), field.clone());
+        }
+        if let Some(field) = &self.oncanplay {
+            attrs.insert(std::borrow::Cow::Borrowed("oncanplay"), field.clone());
+        }
+        if let Some(field) = &self.oncanplaythrough {
+            attrs.insert(std::borrow::Cow::Borrowed("oncanplaythrough"), field.clone());
+        }
+        if let Some(field) = &self.onchange {
+            attrs.insert(std::borrow::Cow::Borrowed("onchange"), field.clone());
+        }
+        if let Some(field) = &self.onclick {
+            attrs.insert(std::borrow::Cow::Borrowed("onclick"), field.clone());
+        }
+        if let Some(field) = &self.onclose {
+            attrs.insert(std::borrow::Cow::Borrowed("onclose"), field.clone());
+        }
+        if let Some(field) = &self.oncontextlost {
+            attrs.insert(std::borrow::Cow::Borrowed("oncontextlost"), field.clone());
+        }
+        if let Some(field) = &self.oncontextmenu {
+            attrs.insert(std::borrow::Cow::Borrowed("oncontextmenu"), field.clone());
+        }
+        if let Some(field) = &self.oncontextrestored {
+            attrs.insert(std::borrow::Cow::Borrowed("oncontextrestored"), field.clone());
+        }
+        if let Some(field) = &self.oncopy {
+            attrs.insert(std::borrow::Cow::Borrowed("oncopy"), field.clone());
+        }
+        if let Some(field) = &self.oncuechange {
+            attrs.insert(std::borrow::Cow::Borrowed("oncuechange"), field.clone());
+        }
+        if let Some(field) = &self.oncut {
+            attrs.insert(std::borrow::Cow::Borrowed("oncut"), field.clone());
+        }
+        if let Some(field) = &self.ondblclick {
+            attrs.insert(std::borrow::Cow::Borrowed("ondblclick"), field.clone());
+        }
+        if let Some(field) = &self.ondrag {
+            attrs.insert(std::borrow::Cow::Borrowed("ondrag"), field.clone());
+        }
+        if let Some(field) = &self.ondragend {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragend"), field.clone());
+        }
+        if let Some(field) = &self.ondragenter {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragenter"), field.clone());
+        }
+        if let Some(field) = &self.ondragleave {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragleave"), field.clone());
+        }
+        if let Some(field) = &self.ondragover {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragover"), field.clone());
+        }
+        if let Some(field) = &self.ondragstart {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragstart"), field.clone());
+        }
+        if let Some(field) = &self.ondrop {
+            attrs.insert(std::borrow::Cow::Borrowed("ondrop"), field.clone());
+        }
+        if let Some(field) = &self.ondurationchange {
+            attrs.insert(std::borrow::Cow::Borrowed("ondurationchange"), field.clone());
+        }
+        if let Some(field) = &self.onemptied {
+            attrs.insert(std::borrow::Cow::Borrowed("onemptied"), field.clone());
+        }
+        if let Some(field) = &self.onended {
+            attrs.insert(std::borrow::Cow::Borrowed("onended"), field.clone());
+        }
+        if let Some(field) = &self.onerror {
+            attrs.insert(std::borrow::Cow::Borrowed("onerror"), field.clone());
+        }
+        if let Some(field) = &self.onfocus {
+            attrs.insert(std::borrow::Cow::Borrowed("onfocus"), field.clone());
+        }
+        if let Some(field) = &self.onformdata {
+            attrs.insert(std::borrow::Cow::Borrowed("onformdata"), field.clone());
+        }
+        if let Some(field) = &self.oninput {
+            attrs.insert(std::borrow::Cow::Borrowed("oninput"), field.clone());
+        }
+        if let Some(field) = &self.oninvalid {
+            attrs.insert(std::borrow::Cow::Borrowed("oninvalid"), field.clone());
+        }
+        if let Some(field) = &self.onkeydown {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeydown"), field.clone());
+        }
+        if let Some(field) = &self.onkeypress {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeypress"), field.clone());
+        }
+        if let Some(field) = &self.onkeyup {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeyup"), field.clone());
+        }
+        if let Some(field) = &self.onload {
+            attrs.insert(std::borrow::Cow::Borrowed("onload"), field.clone());
+        }
+        if let Some(field) = &self.onloadeddata {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadeddata"), field.clone());
+        }
+        if let Some(field) = &self.onloadedmetadata {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadedmetadata"), field.clone());
+        }
+        if let Some(field) = &self.onloadstart {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadstart"), field.clone());
+        }
+        if let Some(field) = &self.onmousedown {
+            attrs.insert(std::borrow::Cow::Borrowed("onmousedown"), field.clone());
+        }
+        if let Some(field) = &self.onmouseenter {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseenter"), field.clone());
+        }
+        if let Some(field) = &self.onmouseleave {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseleave"), field.clone());
+        }
+        if let Some(field) = &self.onmousemove {
+            attrs.insert(std::borrow::Cow::Borrowed("onmousemove"), field.clone());
+        }
+        if let Some(field) = &self.onmouseout {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseout"), field.clone());
+        }
+        if let Some(field) = &self.onmouseover {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseover"), field.clone());
+        }
+        if let Some(field) = &self.onmouseup {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseup"), field.clone());
+        }
+        if let Some(field) = &self.onpaste {
+            attrs.insert(std::borrow::Cow::Borrowed("onpaste"), field.clone());
+        }
+        if let Some(field) = &self.onpause {
+            attrs.insert(std::borrow::Cow::Borrowed("onpause"), field.clone());
+        }
+        if let Some(field) = &self.onplay {
+            attrs.insert(std::borrow::Cow::Borrowed("onplay"), field.clone());
+        }
+        if let Some(field) = &self.onplaying {
+            attrs.insert(std::borrow::Cow::Borrowed("onplaying"), field.clone());
+        }
+        if let Some(field) = &self.onprogress {
+            attrs.insert(std::borrow::Cow::Borrowed("onprogress"), field.clone());
+        }
+        if let Some(field) = &self.onratechange {
+            attrs.insert(std::borrow::Cow::Borrowed("onratechange"), field.clone());
+        }
+        if let Some(field) = &self.onreset {
+            attrs.insert(std::borrow::Cow::Borrowed("onreset"), field.clone());
+        }
+        if let Some(field) = &self.onresize {
+            attrs.insert(std::borrow::Cow::Borrowed("onresize"), field.clone());
+        }
+        if let Some(field) = &self.onscroll {
+            attrs.insert(std::borrow::Cow::Borrowed("onscroll"), field.clone());
+        }
+        if let Some(field) = &self.onscrollend {
+            attrs.insert(std::borrow::Cow::Borrowed("onscrollend"), field.clone());
+        }
+        if let Some(field) = &self.onsecuritypolicyviolation {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onsecuritypolicyviolation"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onseeked {
+            attrs.insert(std::borrow::Cow::Borrowed("onseeked"), field.clone());
+        }
+        if let Some(field) = &self.onseeking {
+            attrs.insert(std::borrow::Cow::Borrowed("onseeking"), field.clone());
+        }
+        if let Some(field) = &self.onselect {
+            attrs.insert(std::borrow::Cow::Borrowed("onselect"), field.clone());
+        }
+        if let Some(field) = &self.onslotchange {
+            attrs.insert(std::borrow::Cow::Borrowed("onslotchange"), field.clone());
+        }
+        if let Some(field) = &self.onstalled {
+            attrs.insert(std::borrow::Cow::Borrowed("onstalled"), field.clone());
+        }
+        if let Some(field) = &self.onsubmit {
+            attrs.insert(std::borrow::Cow::Borrowed("onsubmit"), field.clone());
+        }
+        if let Some(field) = &self.onsuspend {
+            attrs.insert(std::borrow::Cow::Borrowed("onsuspend"), field.clone());
+        }
+        if let Some(field) = &self.ontimeupdate {
+            attrs.insert(std::borrow::Cow::Borrowed("ontimeupdate"), field.clone());
+        }
+        if let Some(field) = &self.ontoggle {
+            attrs.insert(std::borrow::Cow::Borrowed("ontoggle"), field.clone());
+        }
+        if let Some(field) = &self.onvolumechange {
+            attrs.insert(std::borrow::Cow::Borrowed("onvolumechange"), field.clone());
+        }
+        if let Some(field) = &self.onwaiting {
+            attrs.insert(std::borrow::Cow::Borrowed("onwaiting"), field.clone());
+        }
+        if let Some(field) = &self.onwebkitanimationend {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkitanimationend"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwebkitanimationiteration {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkitanimationiteration"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwebkitanimationstart {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkitanimationstart"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwebkittransitionend {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkittransitionend"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwheel {
+            attrs.insert(std::borrow::Cow::Borrowed("onwheel"), field.clone());
+        }
     }
 }
@@ -0,0 +1,1566 @@
+/// The MathML `<annotation>` element
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/annotation)
+#[doc(alias = "annotation")]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Annotation {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
+    pub data_map: crate::DataMap,
+    /// The `autofocus` attribute
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
+    pub autofocus: bool,
+    /// The `class` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub class: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `dir` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub dir: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `displaystyle` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub displaystyle: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `id` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub id: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `mathbackground` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub mathbackground: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `mathcolor` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub mathcolor: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `mathsize` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub mathsize: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `nonce` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub nonce: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `scriptlevel` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub scriptlevel: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `style` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub style: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `tabindex` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub tabindex: std::option::Option<i64>,
+    /// The `encoding` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub encoding: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `abort` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onabort: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `auxclick` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onauxclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `beforeinput` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onbeforeinput: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `beforematch` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onbeforematch: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `beforetoggle` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onbeforetoggle: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `blur` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onblur: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cancel` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncancel: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `canplay` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncanplay: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `canplaythrough` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncanplaythrough: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `change` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `click` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `close` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onclose: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `contextlost` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncontextlost: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `contextmenu` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncontextmenu: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `contextrestored` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncontextrestored: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `copy` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncopy: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cuechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncuechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cut` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncut: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dblclick` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondblclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `drag` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondrag: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragenter` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragenter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragleave` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragleave: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragover` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragover: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `drop` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondrop: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `durationchange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondurationchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `emptied` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onemptied: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `ended` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onended: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `error` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onerror: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `focus` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onfocus: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `formdata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onformdata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `input` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oninput: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `invalid` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oninvalid: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keydown` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeydown: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keypress` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeypress: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keyup` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeyup: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `load` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onload: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadeddata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadeddata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadedmetadata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadedmetadata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mousedown` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmousedown: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseenter` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseenter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseleave` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseleave: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mousemove` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmousemove: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseout` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseout: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseover` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseover: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseup` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseup: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `paste` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onpaste: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `pause` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onpause: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `play` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onplay: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `playing` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onplaying: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `progress` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onprogress: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `ratechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onratechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `reset` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onreset: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `resize` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onresize: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `scroll` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onscroll: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `scrollend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onscrollend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `securitypolicyviolation` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsecuritypolicyviolation: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `seeked` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onseeked: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `seeking` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onseeking: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `select` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onselect: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `slotchange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onslotchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `stalled` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onstalled: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `submit` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsubmit: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `suspend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsuspend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `timeupdate` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ontimeupdate: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `toggle` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ontoggle: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `volumechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onvolumechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `waiting` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwaiting: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkitanimationend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkitanimationend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkitanimationiteration` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkitanimationiteration: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkitanimationstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkitanimationstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `webkittransitionend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwebkittransitionend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `wheel` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwheel: std::option::Option<std::borrow::Cow<'static, str>>,
+}
+impl crate::RenderElement for Annotation {
+    fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        write!(writer, "<annotation")?;
+        if self.autofocus {
+            write!(writer, r#" autofocus"#)?;
+        }
+        if let Some(field) = self.class.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" class="{field}""#)?;
+        }
+        if let Some(field) = self.dir.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" dir="{field}""#)?;
+        }
+        if let Some(field) = self.displaystyle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" displaystyle="{field}""#)?;
+        }
+        if let Some(field) = self.id.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" id="{field}""#)?;
+        }
+        if let Some(field) = self.mathbackground.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mathbackground="{field}""#)?;
+        }
+        if let Some(field) = self.mathcolor.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mathcolor="{field}""#)?;
+        }
+        if let Some(field) = self.mathsize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mathsize="{field}""#)?;
+        }
+        if let Some(field) = self.nonce.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" nonce="{field}""#)?;
+        }
+        if let Some(field) = self.scriptlevel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" scriptlevel="{field}""#)?;
+        }
+        if let Some(field) = self.style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" style="{field}""#)?;
+        }
+        if let Some(field) = self.tabindex.as_ref() {
+            write!(writer, r#" tabindex="{field}""#)?;
+        }
+        if let Some(field) = self.encoding.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" encoding="{field}""#)?;
+        }
+        if let Some(field) = self.onabort.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onabort="{field}""#)?;
+        }
+        if let Some(field) = self.onauxclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onauxclick="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforeinput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforeinput="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforematch.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforematch="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforetoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforetoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onblur.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onblur="{field}""#)?;
+        }
+        if let Some(field) = self.oncancel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncancel="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplay="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplaythrough.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplaythrough="{field}""#)?;
+        }
+        if let Some(field) = self.onchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onchange="{field}""#)?;
+        }
+        if let Some(field) = self.onclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclick="{field}""#)?;
+        }
+        if let Some(field) = self.onclose.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclose="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextlost.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextlost="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextmenu.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextmenu="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextrestored.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextrestored="{field}""#)?;
+        }
+        if let Some(field) = self.oncopy.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncopy="{field}""#)?;
+        }
+        if let Some(field) = self.oncuechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncuechange="{field}""#)?;
+        }
+        if let Some(field) = self.oncut.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncut="{field}""#)?;
+        }
+        if let Some(field) = self.ondblclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondblclick="{field}""#)?;
+        }
+        if let Some(field) = self.ondrag.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrag="{field}""#)?;
+        }
+        if let Some(field) = self.ondragend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragend="{field}""#)?;
+        }
+        if let Some(field) = self.ondragenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragenter="{field}""#)?;
+        }
+        if let Some(field) = self.ondragleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragleave="{field}""#)?;
+        }
+        if let Some(field) = self.ondragover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragover="{field}""#)?;
+        }
+        if let Some(field) = self.ondragstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragstart="{field}""#)?;
+        }
+        if let Some(field) = self.ondrop.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrop="{field}""#)?;
+        }
+        if let Some(field) = self.ondurationchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondurationchange="{field}""#)?;
+        }
+        if let Some(field) = self.onemptied.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onemptied="{field}""#)?;
+        }
+        if let Some(field) = self.onended.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onended="{field}""#)?;
+        }
+        if let Some(field) = self.onerror.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onerror="{field}""#)?;
+        }
+        if let Some(field) = self.onfocus.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onfocus="{field}""#)?;
+        }
+        if let Some(field) = self.onformdata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onformdata="{field}""#)?;
+        }
+        if let Some(field) = self.oninput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninput="{field}""#)?;
+        }
+        if let Some(field) = self.oninvalid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninvalid="{field}""#)?;
+        }
+        if let Some(field) = self.onkeydown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeydown="{field}""#)?;
+        }
+        if let Some(field) = self.onkeypress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeypress="{field}""#)?;
+        }
+        if let Some(field) = self.onkeyup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeyup="{field}""#)?;
+        }
+        if let Some(field) = self.onload.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onload="{field}""#)?;
+        }
+        if let Some(field) = self.onloadeddata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadeddata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadedmetadata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadedmetadata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadstart="{field}""#)?;
+        }
+        if let Some(field) = self.onmousedown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousedown="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseenter="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseleave="{field}""#)?;
+        }
+        if let Some(field) = self.onmousemove.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousemove="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseout.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseout="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseover="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseup="{field}""#)?;
+        }
+        if let Some(field) = self.onpaste.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpaste="{field}""#)?;
+        }
+        if let Some(field) = self.onpause.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpause="{field}""#)?;
+        }
+        if let Some(field) = self.onplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplay="{field}""#)?;
+        }
+        if let Some(field) = self.onplaying.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplaying="{field}""#)?;
+        }
+        if let Some(field) = self.onprogress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onprogress="{field}""#)?;
+        }
+        if let Some(field) = self.onratechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onratechange="{field}""#)?;
+        }
+        if let Some(field) = self.onreset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onreset="{field}""#)?;
+        }
+        if let Some(field) = self.onresize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onresize="{field}""#)?;
+        }
+        if let Some(field) = self.onscroll.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscroll="{field}""#)?;
+        }
+        if let Some(field) = self.onscrollend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscrollend="{field}""#)?;
+        }
+        if let Some(field) = self.onsecuritypolicyviolation.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsecuritypolicyviolation="{field}""#)?;
+        }
+        if let Some(field) = self.onseeked.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeked="{field}""#)?;
+        }
+        if let Some(field) = self.onseeking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeking="{field}""#)?;
+        }
+        if let Some(field) = self.onselect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onselect="{field}""#)?;
+        }
+        if let Some(field) = self.onslotchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onslotchange="{field}""#)?;
+        }
+        if let Some(field) = self.onstalled.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onstalled="{field}""#)?;
+        }
+        if let Some(field) = self.onsubmit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsubmit="{field}""#)?;
+        }
+        if let Some(field) = self.onsuspend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsuspend="{field}""#)?;
+        }
+        if let Some(field) = self.ontimeupdate.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontimeupdate="{field}""#)?;
+        }
+        if let Some(field) = self.ontoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onvolumechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onvolumechange="{field}""#)?;
+        }
+        if let Some(field) = self.onwaiting.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwaiting="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationend="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationiteration.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationiteration="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationstart="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkittransitionend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkittransitionend="{field}""#)?;
+        }
+        if let Some(field) = self.onwheel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwheel="{field}""#)?;
+        }
+        write!(writer, "{}", self.data_map)?;
+        write!(writer, ">")?;
+        Ok(())
+    }
+    #[allow(unused_variables)]
+    fn write_closing_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        write!(writer, "</annotation>")?;
+        Ok(())
+    }
+    fn write_empty_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        write!(writer, "<annotation")?;
+        if self.autofocus {
+            write!(writer, r#" autofocus"#)?;
+        }
+        if let Some(field) = self.class.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" class="{field}""#)?;
+        }
+        if let Some(field) = self.dir.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" dir="{field}""#)?;
+        }
+        if let Some(field) = self.displaystyle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" displaystyle="{field}""#)?;
+        }
+        if let Some(field) = self.id.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" id="{field}""#)?;
+        }
+        if let Some(field) = self.mathbackground.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mathbackground="{field}""#)?;
+        }
+        if let Some(field) = self.mathcolor.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mathcolor="{field}""#)?;
+        }
+        if let Some(field) = self.mathsize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mathsize="{field}""#)?;
+        }
+        if let Some(field) = self.nonce.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" nonce="{field}""#)?;
+        }
+        if let Some(field) = self.scriptlevel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" scriptlevel="{field}""#)?;
+        }
+        if let Some(field) = self.style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" style="{field}""#)?;
+        }
+        if let Some(field) = self.tabindex.as_ref() {
+            write!(writer, r#" tabindex="{field}""#)?;
+        }
+        if let Some(field) = self.encoding.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" encoding="{field}""#)?;
+        }
+        if let Some(field) = self.onabort.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onabort="{field}""#)?;
+        }
+        if let Some(field) = self.onauxclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onauxclick="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforeinput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforeinput="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforematch.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforematch="{field}""#)?;
+        }
+        if let Some(field) = self.onbeforetoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onbeforetoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onblur.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onblur="{field}""#)?;
+        }
+        if let Some(field) = self.oncancel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncancel="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplay="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplaythrough.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplaythrough="{field}""#)?;
+        }
+        if let Some(field) = self.onchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onchange="{field}""#)?;
+        }
+        if let Some(field) = self.onclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclick="{field}""#)?;
+        }
+        if let Some(field) = self.onclose.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclose="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextlost.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextlost="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextmenu.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextmenu="{field}""#)?;
+        }
+        if let Some(field) = self.oncontextrestored.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncontextrestored="{field}""#)?;
+        }
+        if let Some(field) = self.oncopy.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncopy="{field}""#)?;
+        }
+        if let Some(field) = self.oncuechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncuechange="{field}""#)?;
+        }
+        if let Some(field) = self.oncut.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncut="{field}""#)?;
+        }
+        if let Some(field) = self.ondblclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondblclick="{field}""#)?;
+        }
+        if let Some(field) = self.ondrag.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrag="{field}""#)?;
+        }
+        if let Some(field) = self.ondragend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragend="{field}""#)?;
+        }
+        if let Some(field) = self.ondragenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragenter="{field}""#)?;
+        }
+        if let Some(field) = self.ondragleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragleave="{field}""#)?;
+        }
+        if let Some(field) = self.ondragover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragover="{field}""#)?;
+        }
+        if let Some(field) = self.ondragstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragstart="{field}""#)?;
+        }
+        if let Some(field) = self.ondrop.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrop="{field}""#)?;
+        }
+        if let Some(field) = self.ondurationchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondurationchange="{field}""#)?;
+        }
+        if let Some(field) = self.onemptied.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onemptied="{field}""#)?;
+        }
+        if let Some(field) = self.onended.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onended="{field}""#)?;
+        }
+        if let Some(field) = self.onerror.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onerror="{field}""#)?;
+        }
+        if let Some(field) = self.onfocus.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onfocus="{field}""#)?;
+        }
+        if let Some(field) = self.onformdata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onformdata="{field}""#)?;
+        }
+        if let Some(field) = self.oninput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninput="{field}""#)?;
+        }
+        if let Some(field) = self.oninvalid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninvalid="{field}""#)?;
+        }
+        if let Some(field) = self.onkeydown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeydown="{field}""#)?;
+        }
+        if let Some(field) = self.onkeypress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeypress="{field}""#)?;
+        }
+        if let Some(field) = self.onkeyup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeyup="{field}""#)?;
+        }
+        if let Some(field) = self.onload.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onload="{field}""#)?;
+        }
+        if let Some(field) = self.onloadeddata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadeddata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadedmetadata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadedmetadata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadstart="{field}""#)?;
+        }
+        if let Some(field) = self.onmousedown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousedown="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseenter="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseleave="{field}""#)?;
+        }
+        if let Some(field) = self.onmousemove.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousemove="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseout.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseout="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseover="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseup="{field}""#)?;
+        }
+        if let Some(field) = self.onpaste.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpaste="{field}""#)?;
+        }
+        if let Some(field) = self.onpause.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpause="{field}""#)?;
+        }
+        if let Some(field) = self.onplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplay="{field}""#)?;
+        }
+        if let Some(field) = self.onplaying.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplaying="{field}""#)?;
+        }
+        if let Some(field) = self.onprogress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onprogress="{field}""#)?;
+        }
+        if let Some(field) = self.onratechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onratechange="{field}""#)?;
+        }
+        if let Some(field) = self.onreset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onreset="{field}""#)?;
+        }
+        if let Some(field) = self.onresize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onresize="{field}""#)?;
+        }
+        if let Some(field) = self.onscroll.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscroll="{field}""#)?;
+        }
+        if let Some(field) = self.onscrollend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscrollend="{field}""#)?;
+        }
+        if let Some(field) = self.onsecuritypolicyviolation.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsecuritypolicyviolation="{field}""#)?;
+        }
+        if let Some(field) = self.onseeked.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeked="{field}""#)?;
+        }
+        if let Some(field) = self.onseeking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeking="{field}""#)?;
+        }
+        if let Some(field) = self.onselect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onselect="{field}""#)?;
+        }
+        if let Some(field) = self.onslotchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onslotchange="{field}""#)?;
+        }
+        if let Some(field) = self.onstalled.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onstalled="{field}""#)?;
+        }
+        if let Some(field) = self.onsubmit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsubmit="{field}""#)?;
+        }
+        if let Some(field) = self.onsuspend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsuspend="{field}""#)?;
+        }
+        if let Some(field) = self.ontimeupdate.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontimeupdate="{field}""#)?;
+        }
+        if let Some(field) = self.ontoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onvolumechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onvolumechange="{field}""#)?;
+        }
+        if let Some(field) = self.onwaiting.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwaiting="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationend="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationiteration.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationiteration="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkitanimationstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkitanimationstart="{field}""#)?;
+        }
+        if let Some(field) = self.onwebkittransitionend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwebkittransitionend="{field}""#)?;
+        }
+        if let Some(field) = self.onwheel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwheel="{field}""#)?;
+        }
+        write!(writer, "{}", self.data_map)?;
+        write!(writer, "/>")?;
+        Ok(())
+    }
+}
+impl crate::ElementDescription for Annotation {
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
+        if self.autofocus {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("autofocus"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
+        }
+        if let Some(field) = &self.class {
+            attrs.insert(std::borrow::Cow::Borrowed("class"), field.clone());
+        }
+        if let Some(field) = &self.dir {
+            attrs.insert(std::borrow::Cow::Borrowed("dir"), field.clone());
+        }
+        if let Some(field) = &self.displaystyle {
+            attrs.insert(std::borrow::Cow::Borrowed("displaystyle"), field.clone());
+        }
+        if let Some(field) = &self.id {
+            attrs.insert(std::borrow::Cow::Borrowed("id"), field.clone());
+        }
+        if let Some(field) = &self.mathbackground {
+            attrs.insert(std::borrow::Cow::Borrowed("mathbackground"), field.clone());
+        }
+        if let Some(field) = &self.mathcolor {
+            attrs.insert(std::borrow::Cow::Borrowed("mathcolor"), field.clone());
+        }
+        if let Some(field) = &self.mathsize {
+            attrs.insert(std::borrow::Cow::Borrowed("mathsize"), field.clone());
+        }
+        if let Some(field) = &self.nonce {
+            attrs.insert(std::borrow::Cow::Borrowed("nonce"), field.clone());
+        }
+        if let Some(field) = &self.scriptlevel {
+            attrs.insert(std::borrow::Cow::Borrowed("scriptlevel"), field.clone());
+        }
+        if let Some(field) = &self.style {
+            attrs.insert(std::borrow::Cow::Borrowed("style"), field.clone());
+        }
+        if let Some(field) = &self.tabindex {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("tabindex"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
+        }
+        if let Some(field) = &self.encoding {
+            attrs.insert(std::borrow::Cow::Borrowed("encoding"), field.clone());
+        }
+        if let Some(field) = &self.onabort {
+            attrs.insert(std::borrow::Cow::Borrowed("onabort"), field.clone());
+        }
+        if let Some(field) = &self.onauxclick {
+            attrs.insert(std::borrow::Cow::Borrowed("onauxclick"), field.clone());
+        }
+        if let Some(field) = &self.onbeforeinput {
+            attrs.insert(std::borrow::Cow::Borrowed("onbeforeinput"), field.clone());
+        }
+        if let Some(field) = &self.onbeforematch {
+            attrs.insert(std::borrow::Cow::Borrowed("onbeforematch"), field.clone());
+        }
+        if let Some(field) = &self.onbeforetoggle {
+            attrs.insert(std::borrow::Cow::Borrowed("onbeforetoggle"), field.clone());
+        }
+        if let Some(field) = &self.onblur {
+            attrs.insert(std::borrow::Cow::Borrowed("onblur"), field.clone());
+        }
+        if let Some(field) = &self.oncancel {
+            attrs.insert(std::borrow::Cow::Borrowed("oncancel"), field.clone());
+        }
+        if let Some(field) = &self.oncanplay {
+            attrs.insert(std::borrow::Cow::Borrowed("oncanplay"), field.clone());
+        }
+        if let Some(field) = &self.oncanplaythrough {
+            attrs.insert(std::borrow::Cow::Borrowed("oncanplaythrough"), field.clone());
+        }
+        if let Some(field) = &self.onchange {
+            attrs.insert(std::borrow::Cow::Borrowed("onchange"), field.clone());
+        }
+        if let Some(field) = &self.onclick {
+            attrs.insert(std::borrow::Cow::Borrowed("onclick"), field.clone());
+        }
+        if let Some(field) = &self.onclose {
+            attrs.insert(std::borrow::Cow::Borrowed("onclose"), field.clone());
+        }
+        if let Some(field) = &self.oncontextlost {
+            attrs.insert(std::borrow::Cow::Borrowed("oncontextlost"), field.clone());
+        }
+        if let Some(field) = &self.oncontextmenu {
+            attrs.insert(std::borrow::Cow::Borrowed("oncontextmenu"), field.clone());
+        }
+        if let Some(field) = &self.oncontextrestored {
+            attrs.insert(std::borrow::Cow::Borrowed("oncontextrestored"), field.clone());
+        }
+        if let Some(field) = &self.oncopy {
+            attrs.insert(std::borrow::Cow::Borrowed("oncopy"), field.clone());
+        }
+        if let Some(field) = &self.oncuechange {
+            attrs.insert(std::borrow::Cow::Borrowed("oncuechange"), field.clone());
+        }
+        if let Some(field) = &self.oncut {
+            attrs.insert(std::borrow::Cow::Borrowed("oncut"), field.clone());
+        }
+        if let Some(field) = &self.ondblclick {
+            attrs.insert(std::borrow::Cow::Borrowed("ondblclick"), field.clone());
+        }
+        if let Some(field) = &self.ondrag {
+            attrs.insert(std::borrow::Cow::Borrowed("ondrag"), field.clone());
+        }
+        if let Some(field) = &self.ondragend {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragend"), field.clone());
+        }
+        if let Some(field) = &self.ondragenter {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragenter"), field.clone());
+        }
+        if let Some(field) = &self.ondragleave {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragleave"), field.clone());
+        }
+        if let Some(field) = &self.ondragover {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragover"), field.clone());
+        }
+        if let Some(field) = &self.ondragstart {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragstart"), field.clone());
+        }
+        if let Some(field) = &self.ondrop {
+            attrs.insert(std::borrow::Cow::Borrowed("ondrop"), field.clone());
+        }
+        if let Some(field) = &self.ondurationchange {
+            attrs.insert(std::borrow::Cow::Borrowed("ondurationchange"), field.clone());
+        }
+        if let Some(field) = &self.onemptied {
+            attrs.insert(std::borrow::Cow::Borrowed("onemptied"), field.clone());
+        }
+        if let Some(field) = &self.onended {
+            attrs.insert(std::borrow::Cow::Borrowed("onended"), field.clone());
+        }
+        if let Some(field) = &self.onerror {
+            attrs.insert(std::borrow::Cow::Borrowed("onerror"), field.clone());
+        }
+        if let Some(field) = &self.onfocus {
+            attrs.insert(std::borrow::Cow::Borrowed("onfocus"), field.clone());
+        }
+        if let Some(field) = &self.onformdata {
+            attrs.insert(std::borrow::Cow::Borrowed("onformdata"), field.clone());
+        }
+        if let Some(field) = &self.oninput {
+            attrs.insert(std::borrow::Cow::Borrowed("oninput"), field.clone());
+        }
+        if let Some(field) = &self.oninvalid {
+            attrs.insert(std::borrow::Cow::Borrowed("oninvalid"), field.clone());
+        }
+        if let Some(field) = &self.onkeydown {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeydown"), field.clone());
+        }
+        if let Some(field) = &self.onkeypress {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeypress"), field.clone());
+        }
+        if let Some(field) = &self.onkeyup {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeyup"), field.clone());
+        }
+        if let Some(field) = &self.onload {
+            attrs.insert(std::borrow::Cow::Borrowed("onload"), field.clone());
+        }
+        if let Some(field) = &self.onloadeddata {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadeddata"), field.clone());
+        }
+        if let Some(field) = &self.onloadedmetadata {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadedmetadata"), field.clone());
+        }
+        if let Some(field) = &self.onloadstart {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadstart"), field.clone());
+        }
+        if let Some(field) = &self.onmousedown {
+            attrs.insert(std::borrow::Cow::Borrowed("onmousedown"), field.clone());
+        }
+        if let Some(field) = &self.onmouseenter {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseenter"), field.clone());
+        }
+        if let Some(field) = &self.onmouseleave {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseleave"), field.clone());
+        }
+        if let Some(field) = &self.onmousemove {
+            attrs.insert(std::borrow::Cow::Borrowed("onmousemove"), field.clone());
+        }
+        if let Some(field) = &self.onmouseout {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseout"), field.clone());
+        }
+        if let Some(field) = &self.onmouseover {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseover"), field.clone());
+        }
+        if let Some(field) = &self.onmouseup {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseup"), field.clone());
+        }
+        if let Some(field) = &self.onpaste {
+            attrs.insert(std::borrow::Cow::Borrowed("onpaste"), field.clone());
+        }
+        if let Some(field) = &self.onpause {
+            attrs.insert(std::borrow::Cow::Borrowed("onpause"), field.clone());
+        }
+        if let Some(field) = &self.onplay {
+            attrs.insert(std::borrow::Cow::Borrowed("onplay"), field.clone());
+        }
+        if let Some(field) = &self.onplaying {
+            attrs.insert(std::borrow::Cow::Borrowed("onplaying"), field.clone());
+        }
+        if let Some(field) = &self.onprogress {
+            attrs.insert(std::borrow::Cow::Borrowed("onprogress"), field.clone());
+        }
+        if let Some(field) = &self.onratechange {
+            attrs.insert(std::borrow::Cow::Borrowed("onratechange"), field.clone());
+        }
+        if let Some(field) = &self.onreset {
+            attrs.insert(std::borrow::Cow::Borrowed("onreset"), field.clone());
+        }
+        if let Some(field) = &self.onresize {
+            attrs.insert(std::borrow::Cow::Borrowed("onresize"), field.clone());
+        }
+        if let Some(field) = &self.onscroll {
+            attrs.insert(std::borrow::Cow::Borrowed("onscroll"), field.clone());
+        }
+        if let Some(field) = &self.onscrollend {
+            attrs.insert(std::borrow::Cow::Borrowed("onscrollend"), field.clone());
+        }
+        if let Some(field) = &self.onsecuritypolicyviolation {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onsecuritypolicyviolation"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onseeked {
+            attrs.insert(std::borrow::Cow::Borrowed("onseeked"), field.clone());
+        }
+        if let Some(field) = &self.onseeking {
+            attrs.insert(std::borrow::Cow::Borrowed("onseeking"), field.clone());
+        }
+        if let Some(field) = &self.onselect {
+            attrs.insert(std::borrow::Cow::Borrowed("onselect"), field.clone());
+        }
+        if let Some(field) = &self.onslotchange {
+            attrs.insert(std::borrow::Cow::Borrowed("onslotchange"), field.clone());
+        }
+        if let Some(field) = &self.onstalled {
+            attrs.insert(std::borrow::Cow::Borrowed("onstalled"), field.clone());
+        }
+        if let Some(field) = &self.onsubmit {
+            attrs.insert(std::borrow::Cow::Borrowed("onsubmit"), field.clone());
+        }
+        if let Some(field) = &self.onsuspend {
+            attrs.insert(std::borrow::Cow::Borrowed("onsuspend"), field.clone());
+        }
+        if let Some(field) = &self.ontimeupdate {
+            attrs.insert(std::borrow::Cow::Borrowed("ontimeupdate"), field.clone());
+        }
+        if let Some(field) = &self.ontoggle {
+            attrs.insert(std::borrow::Cow::Borrowed("ontoggle"), field.clone());
+        }
+        if let Some(field) = &self.onvolumechange {
+            attrs.insert(std::borrow::Cow::Borrowed("onvolumechange"), field.clone());
+        }
+        if let Some(field) = &self.onwaiting {
+            attrs.insert(std::borrow::Cow::Borrowed("onwaiting"), field.clone());
+        }
+        if let Some(field) = &self.onwebkitanimationend {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkitanimationend"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwebkitanimationiteration {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkitanimationiteration"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwebkitanimationstart {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkitanimationstart"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwebkittransitionend {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("onwebkittransitionend"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.onwheel {
+            attrs.insert(std::borrow::Cow::Borrowed("onwheel"), field.clone());
+        }
+        attrs
+    }
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
+    }
+}
+impl std::fmt::Display for Annotation {
+    fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use crate::RenderElement;
+        self.write_empty_tag(writer)
+    }
+}
@@ -0,0 +1,61 @@
+//! MathML elements, which describe mathematical notation that can be embedded in HTML documents
+mod mathml_annotation;
+pub use mathml_annotation::*;
+mod mathml_annotation_xml;
+pub use mathml_annotation_xml::*;
+mod mathml_maction;
+pub use mathml_maction::*;
+mod mathml_math;
+pub use mathml_math::*;
+mod mathml_merror;
+pub use mathml_merror::*;
+mod mathml_mfrac;
+pub use mathml_mfrac::*;
+mod mathml_mi;
+pub use mathml_mi::*;
+mod mathml_mmultiscripts;
+pub use mathml_mmultiscripts::*;
+mod mathml_mn;
+pub use mathml_mn::*;
+mod mathml_mo;
+pub use mathml_mo::*;
+mod mathml_mover;
+pub use mathml_mover::*;
+mod mathml_mpadded;
+pub use mathml_mpadded::*;
+mod mathml_mphantom;
+pub use mathml_mphantom::*;
+mod mathml_mprescripts;
+pub use mathml_mprescripts::*;
+mod mathml_mroot;
+pub use mathml_mroot::*;
+mod mathml_mrow;
+pub use mathml_mrow::*;
+mod mathml_ms;
+pub use mathml_ms::*;
+mod mathml_mspace;
+pub use mathml_mspace::*;
+mod mathml_msqrt;
+pub use mathml_msqrt::*;
+mod mathml_mstyle;
+pub use mathml_mstyle::*;
+mod mathml_msub;
+pub use mathml_msub::*;
+mod mathml_msubsup;
+pub use mathml_msubsup::*;
+mod mathml_msup;
+pub use mathml_msup::*;
+mod mathml_mtable;
+pub use mathml_mtable::*;
+mod mathml_mtd;
+pub use mathml_mtd::*;
+mod mathml_mtext;
+pub use mathml_mtext::*;
+mod mathml_mtr;
+pub use mathml_mtr::*;
+mod mathml_munder;
+pub use mathml_munder::*;
+mod mathml_munderover;
+pub use mathml_munderover::*;
+mod mathml_semantics;
+pub use mathml_semantics::*;
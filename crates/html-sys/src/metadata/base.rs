@@ -4,21 +4,38 @@
 #[doc(alias = "base")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Base {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// Document base URL
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub href: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Default navigable for hyperlink navigation and form submission
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub target: std::option::Option<std::borrow::Cow<'static, str>>,
 }
 impl crate::RenderElement for Base {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<base")?;
         if let Some(field) = self.href.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" href="{field}""#)?;
         }
         if let Some(field) = self.target.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" target="{field}""#)?;
         }
         write!(writer, "{}", self.global_attrs)?;
@@ -32,37 +49,25 @@ impl crate::RenderElement for Base {
     }
 }
 impl crate::ElementDescription for Base {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.href {
-            attrs.insert(std::borrow::Cow::Borrowed("href"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("href"), field.clone());
         }
         if let Some(field) = &self.target {
-            attrs.insert(std::borrow::Cow::Borrowed("target"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("target"), field.clone());
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for Base {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for Base {
@@ -4,91 +4,174 @@
 #[doc(alias = "link")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Link {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// Address of the hyperlink
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub href: std::option::Option<std::borrow::Cow<'static, str>>,
     /// How the element handles crossorigin requests
-    pub crossorigin: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub crossorigin: std::option::Option<crate::attributes::CrossOrigin>,
     /// Relationship between the document containing the hyperlink and the destination resource
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub rel: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Applicable media
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub media: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Integrity metadata used in Subresource Integrity checks [SRI]
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub integrity: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Language of the linked resource
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub hreflang: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Hint for the type of the referenced resource
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub type_: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Referrer policy for fetches initiated by the element
-    pub referrerpolicy: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub referrerpolicy: std::option::Option<crate::attributes::ReferrerPolicy>,
     /// Sizes of the icons (for rel="icon")
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub sizes: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Images to use in different situations, e.g., high-resolution displays, small monitors, etc. (for rel="preload")
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub imagesrcset: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Image sizes for different page layouts (for rel="preload")
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub imagesizes: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Potential destination for a preload request (for rel="preload" and rel="modulepreload")
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "as", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub as_: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Whether the element is potentially render-blocking
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub blocking: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Color to use when customizing a site's icon (for rel="mask-icon")
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub color: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Whether the link is disabled
-    pub disabled: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
+    pub disabled: bool,
     /// Sets the priority for fetches initiated by the element
-    pub fetchpriority: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub fetchpriority: std::option::Option<crate::attributes::FetchPriority>,
 }
 impl crate::RenderElement for Link {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<link")?;
         if let Some(field) = self.href.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" href="{field}""#)?;
         }
         if let Some(field) = self.crossorigin.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" crossorigin="{field}""#)?;
         }
         if let Some(field) = self.rel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" rel="{field}""#)?;
         }
         if let Some(field) = self.media.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" media="{field}""#)?;
         }
         if let Some(field) = self.integrity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" integrity="{field}""#)?;
         }
         if let Some(field) = self.hreflang.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" hreflang="{field}""#)?;
         }
         if let Some(field) = self.type_.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" type="{field}""#)?;
         }
         if let Some(field) = self.referrerpolicy.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" referrerpolicy="{field}""#)?;
         }
         if let Some(field) = self.sizes.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" sizes="{field}""#)?;
         }
         if let Some(field) = self.imagesrcset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" imagesrcset="{field}""#)?;
         }
         if let Some(field) = self.imagesizes.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" imagesizes="{field}""#)?;
         }
         if let Some(field) = self.as_.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" as="{field}""#)?;
         }
         if let Some(field) = self.blocking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" blocking="{field}""#)?;
         }
         if let Some(field) = self.color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" color="{field}""#)?;
         }
-        if let Some(field) = self.disabled.as_ref() {
-            write!(writer, r#" disabled="{field}""#)?;
+        if self.disabled {
+            write!(writer, r#" disabled"#)?;
         }
         if let Some(field) = self.fetchpriority.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" fetchpriority="{field}""#)?;
         }
         write!(writer, "{}", self.global_attrs)?;
@@ -102,79 +185,83 @@ impl crate::RenderElement for Link {
     }
 }
 impl crate::ElementDescription for Link {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.href {
-            attrs.insert(std::borrow::Cow::Borrowed("href"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("href"), field.clone());
         }
         if let Some(field) = &self.crossorigin {
-            attrs.insert(std::borrow::Cow::Borrowed("crossorigin"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("crossorigin"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.rel {
-            attrs.insert(std::borrow::Cow::Borrowed("rel"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("rel"), field.clone());
         }
         if let Some(field) = &self.media {
-            attrs.insert(std::borrow::Cow::Borrowed("media"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("media"), field.clone());
         }
         if let Some(field) = &self.integrity {
-            attrs.insert(std::borrow::Cow::Borrowed("integrity"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("integrity"), field.clone());
         }
         if let Some(field) = &self.hreflang {
-            attrs.insert(std::borrow::Cow::Borrowed("hreflang"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("hreflang"), field.clone());
         }
         if let Some(field) = &self.type_ {
-            attrs.insert(std::borrow::Cow::Borrowed("type"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("type"), field.clone());
         }
         if let Some(field) = &self.referrerpolicy {
-            attrs.insert(std::borrow::Cow::Borrowed("referrerpolicy"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("referrerpolicy"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.sizes {
-            attrs.insert(std::borrow::Cow::Borrowed("sizes"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("sizes"), field.clone());
         }
         if let Some(field) = &self.imagesrcset {
-            attrs.insert(std::borrow::Cow::Borrowed("imagesrcset"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("imagesrcset"), field.clone());
         }
         if let Some(field) = &self.imagesizes {
-            attrs.insert(std::borrow::Cow::Borrowed("imagesizes"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("imagesizes"), field.clone());
         }
         if let Some(field) = &self.as_ {
-            attrs.insert(std::borrow::Cow::Borrowed("as"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("as"), field.clone());
         }
         if let Some(field) = &self.blocking {
-            attrs.insert(std::borrow::Cow::Borrowed("blocking"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("blocking"), field.clone());
         }
         if let Some(field) = &self.color {
-            attrs.insert(std::borrow::Cow::Borrowed("color"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("color"), field.clone());
         }
-        if let Some(field) = &self.disabled {
-            attrs.insert(std::borrow::Cow::Borrowed("disabled"), field.to_owned());
+        if self.disabled {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("disabled"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
         }
         if let Some(field) = &self.fetchpriority {
-            attrs.insert(std::borrow::Cow::Borrowed("fetchpriority"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("fetchpriority"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for Link {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for Link {
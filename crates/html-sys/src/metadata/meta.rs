@@ -4,36 +4,71 @@
 #[doc(alias = "meta")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Meta {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// Metadata name
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub name: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Pragma directive
-    pub http_equiv: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "http-equiv",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub http_equiv: std::option::Option<crate::attributes::HttpEquiv>,
     /// Value of the element
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub content: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Character encoding declaration
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub charset: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Applicable media
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub media: std::option::Option<std::borrow::Cow<'static, str>>,
 }
 impl crate::RenderElement for Meta {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<meta")?;
         if let Some(field) = self.name.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" name="{field}""#)?;
         }
         if let Some(field) = self.http_equiv.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" http-equiv="{field}""#)?;
         }
         if let Some(field) = self.content.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" content="{field}""#)?;
         }
         if let Some(field) = self.charset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" charset="{field}""#)?;
         }
         if let Some(field) = self.media.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" media="{field}""#)?;
         }
         write!(writer, "{}", self.global_attrs)?;
@@ -47,46 +82,38 @@ impl crate::RenderElement for Meta {
     }
 }
 impl crate::ElementDescription for Meta {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.name {
-            attrs.insert(std::borrow::Cow::Borrowed("name"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("name"), field.clone());
         }
         if let Some(field) = &self.http_equiv {
-            attrs.insert(std::borrow::Cow::Borrowed("http-equiv"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("http-equiv"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.content {
-            attrs.insert(std::borrow::Cow::Borrowed("content"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("content"), field.clone());
         }
         if let Some(field) = &self.charset {
-            attrs.insert(std::borrow::Cow::Borrowed("charset"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("charset"), field.clone());
         }
         if let Some(field) = &self.media {
-            attrs.insert(std::borrow::Cow::Borrowed("media"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("media"), field.clone());
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for Meta {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for Meta {
@@ -4,61 +4,109 @@
 #[doc(alias = "script")]
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Script {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
     pub data_map: crate::DataMap,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     global_attrs: crate::GlobalAttributes,
     /// Address of the resource
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub src: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Type of script
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub type_: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Prevents execution in user agents that support module scripts
-    pub nomodule: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
+    pub nomodule: bool,
     /// Execute script when available, without blocking while fetching
-    pub async_: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "async", skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub async_: bool,
     /// Defer script execution
-    pub defer: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
+    pub defer: bool,
     /// How the element handles crossorigin requests
-    pub crossorigin: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub crossorigin: std::option::Option<crate::attributes::CrossOrigin>,
     /// Integrity metadata used in Subresource Integrity checks [SRI]
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub integrity: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Referrer policy for fetches initiated by the element
-    pub referrerpolicy: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub referrerpolicy: std::option::Option<crate::attributes::ReferrerPolicy>,
     /// Whether the element is potentially render-blocking
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
     pub blocking: std::option::Option<std::borrow::Cow<'static, str>>,
     /// Sets the priority for fetches initiated by the element
-    pub fetchpriority: std::option::Option<std::borrow::Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub fetchpriority: std::option::Option<crate::attributes::FetchPriority>,
 }
 impl crate::RenderElement for Script {
     fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
         write!(writer, "<script")?;
         if let Some(field) = self.src.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" src="{field}""#)?;
         }
         if let Some(field) = self.type_.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" type="{field}""#)?;
         }
-        if let Some(field) = self.nomodule.as_ref() {
-            write!(writer, r#" nomodule="{field}""#)?;
+        if self.nomodule {
+            write!(writer, r#" nomodule"#)?;
         }
-        if let Some(field) = self.async_.as_ref() {
-            write!(writer, r#" async="{field}""#)?;
+        if self.async_ {
+            write!(writer, r#" async"#)?;
         }
-        if let Some(field) = self.defer.as_ref() {
-            write!(writer, r#" defer="{field}""#)?;
+        if self.defer {
+            write!(writer, r#" defer"#)?;
         }
         if let Some(field) = self.crossorigin.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" crossorigin="{field}""#)?;
         }
         if let Some(field) = self.integrity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" integrity="{field}""#)?;
         }
         if let Some(field) = self.referrerpolicy.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" referrerpolicy="{field}""#)?;
         }
         if let Some(field) = self.blocking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
             write!(writer, r#" blocking="{field}""#)?;
         }
         if let Some(field) = self.fetchpriority.as_ref() {
+            let field = crate::escape::AttributeValue(field.as_str());
             write!(writer, r#" fetchpriority="{field}""#)?;
         }
         write!(writer, "{}", self.global_attrs)?;
@@ -73,61 +121,73 @@ impl crate::RenderElement for Script {
     }
 }
 impl crate::ElementDescription for Script {
-    fn attributes(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        let mut attrs = std::collections::HashMap::new();
-        self.global_attrs.add(&mut attrs);
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
         if let Some(field) = &self.src {
-            attrs.insert(std::borrow::Cow::Borrowed("src"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("src"), field.clone());
         }
         if let Some(field) = &self.type_ {
-            attrs.insert(std::borrow::Cow::Borrowed("type"), field.to_owned());
-        }
-        if let Some(field) = &self.nomodule {
-            attrs.insert(std::borrow::Cow::Borrowed("nomodule"), field.to_owned());
-        }
-        if let Some(field) = &self.async_ {
-            attrs.insert(std::borrow::Cow::Borrowed("async"), field.to_owned());
-        }
-        if let Some(field) = &self.defer {
-            attrs.insert(std::borrow::Cow::Borrowed("defer"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("type"), field.clone());
+        }
+        if self.nomodule {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("nomodule"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
+        }
+        if self.async_ {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("async"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
+        }
+        if self.defer {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("defer"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
         }
         if let Some(field) = &self.crossorigin {
-            attrs.insert(std::borrow::Cow::Borrowed("crossorigin"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("crossorigin"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.integrity {
-            attrs.insert(std::borrow::Cow::Borrowed("integrity"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("integrity"), field.clone());
         }
         if let Some(field) = &self.referrerpolicy {
-            attrs.insert(std::borrow::Cow::Borrowed("referrerpolicy"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("referrerpolicy"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
         if let Some(field) = &self.blocking {
-            attrs.insert(std::borrow::Cow::Borrowed("blocking"), field.to_owned());
+            attrs.insert(std::borrow::Cow::Borrowed("blocking"), field.clone());
         }
         if let Some(field) = &self.fetchpriority {
-            attrs.insert(std::borrow::Cow::Borrowed("fetchpriority"), field.to_owned());
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("fetchpriority"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
         }
+        self.global_attrs.add(&mut attrs);
         attrs
     }
-    fn data(
-        &self,
-    ) -> std::collections::HashMap<
-        std::borrow::Cow<'static, str>,
-        std::borrow::Cow<'static, str>,
-    > {
-        (&*self.data_map).clone()
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
     }
 }
 impl std::fmt::Display for Script {
     fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::RenderElement;
-        self.write_opening_tag(writer)?;
-        self.write_closing_tag(writer)?;
-        Ok(())
+        self.write_empty_tag(writer)
     }
 }
 impl std::ops::Deref for Script {
@@ -0,0 +1,65 @@
+//! Scalable Vector Graphics elements, which describe two-dimensional vector graphics that can be embedded in HTML documents
+mod svg_a;
+pub use svg_a::*;
+mod svg_circle;
+pub use svg_circle::*;
+mod svg_clip_path;
+pub use svg_clip_path::*;
+mod svg_defs;
+pub use svg_defs::*;
+mod svg_desc;
+pub use svg_desc::*;
+mod svg_ellipse;
+pub use svg_ellipse::*;
+mod svg_foreign_object;
+pub use svg_foreign_object::*;
+mod svg_g;
+pub use svg_g::*;
+mod svg_image;
+pub use svg_image::*;
+mod svg_line;
+pub use svg_line::*;
+mod svg_linear_gradient;
+pub use svg_linear_gradient::*;
+mod svg_marker;
+pub use svg_marker::*;
+mod svg_mask;
+pub use svg_mask::*;
+mod svg_metadata;
+pub use svg_metadata::*;
+mod svg_path;
+pub use svg_path::*;
+mod svg_pattern;
+pub use svg_pattern::*;
+mod svg_polygon;
+pub use svg_polygon::*;
+mod svg_polyline;
+pub use svg_polyline::*;
+mod svg_radial_gradient;
+pub use svg_radial_gradient::*;
+mod svg_rect;
+pub use svg_rect::*;
+mod svg_script;
+pub use svg_script::*;
+mod svg_stop;
+pub use svg_stop::*;
+mod svg_style;
+pub use svg_style::*;
+mod svg_svg;
+pub use svg_svg::*;
+mod svg_switch;
+pub use svg_switch::*;
+mod svg_symbol;
+pub use svg_symbol::*;
+mod svg_text;
+pub use svg_text::*;
+mod svg_text_path;
+pub use svg_text_path::*;
+mod svg_title;
+pub use svg_title::*;
+mod svg_tspan;
+pub use svg_tspan::*;
+mod svg_use;
+pub use svg_use::*;
+mod svg_view;
+pub use svg_view::*;
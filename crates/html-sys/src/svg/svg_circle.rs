@@ -0,0 +1,2458 @@
+/// The SVG `<circle>` element
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/circle)
+#[doc(alias = "circle")]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Circle {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dataset", skip_serializing_if = "crate::DataMap::is_empty")
+    )]
+    pub data_map: crate::DataMap,
+    /// The `id` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub id: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `tabindex` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub tabindex: std::option::Option<i64>,
+    /// The `autofocus` attribute
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
+    pub autofocus: bool,
+    /// The `lang` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub lang: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `class` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub class: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `style` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub style: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `requiredExtensions` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "requiredExtensions",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub required_extensions: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `systemLanguage` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "systemLanguage",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub system_language: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `alignment-baseline` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "alignment-baseline",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub alignment_baseline: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `baseline-shift` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "baseline-shift",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub baseline_shift: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `clip` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub clip: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `clip-path` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "clip-path", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub clip_path: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `clip-rule` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "clip-rule", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub clip_rule: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `color` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub color: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `color-interpolation` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "color-interpolation",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub color_interpolation: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `color-interpolation-filters` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "color-interpolation-filters",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub color_interpolation_filters: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `color-rendering` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "color-rendering",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub color_rendering: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `cursor` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub cursor: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `direction` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub direction: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `display` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub display: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `dominant-baseline` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "dominant-baseline",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub dominant_baseline: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `fill` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub fill: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `fill-opacity` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "fill-opacity",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub fill_opacity: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `fill-rule` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fill-rule", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub fill_rule: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `filter` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub filter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `flood-color` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "flood-color",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub flood_color: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `flood-opacity` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "flood-opacity",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub flood_opacity: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-family` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "font-family",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub font_family: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-size` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "font-size", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub font_size: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-size-adjust` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "font-size-adjust",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub font_size_adjust: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-stretch` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "font-stretch",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub font_stretch: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-style` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "font-style",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub font_style: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-variant` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "font-variant",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub font_variant: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `font-weight` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "font-weight",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub font_weight: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `glyph-orientation-horizontal` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "glyph-orientation-horizontal",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub glyph_orientation_horizontal: std::option::Option<
+        std::borrow::Cow<'static, str>,
+    >,
+    /// The `glyph-orientation-vertical` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "glyph-orientation-vertical",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub glyph_orientation_vertical: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `image-rendering` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "image-rendering",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub image_rendering: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `letter-spacing` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "letter-spacing",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub letter_spacing: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `lighting-color` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "lighting-color",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub lighting_color: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `marker-end` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "marker-end",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub marker_end: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `marker-mid` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "marker-mid",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub marker_mid: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `marker-start` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "marker-start",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub marker_start: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `mask` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub mask: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `mask-type` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "mask-type", skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub mask_type: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `opacity` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub opacity: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `overflow` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub overflow: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `paint-order` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "paint-order",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub paint_order: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `pointer-events` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "pointer-events",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub pointer_events: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `shape-rendering` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "shape-rendering",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub shape_rendering: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stop-color` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stop-color",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stop_color: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stop-opacity` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stop-opacity",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stop_opacity: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub stroke: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-dasharray` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-dasharray",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_dasharray: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-dashoffset` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-dashoffset",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_dashoffset: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-linecap` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-linecap",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_linecap: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-linejoin` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-linejoin",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_linejoin: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-miterlimit` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-miterlimit",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_miterlimit: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-opacity` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-opacity",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_opacity: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-width` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "stroke-width",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub stroke_width: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `text-anchor` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "text-anchor",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub text_anchor: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `text-decoration` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "text-decoration",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub text_decoration: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `text-overflow` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "text-overflow",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub text_overflow: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `text-rendering` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "text-rendering",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub text_rendering: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `transform` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub transform: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `transform-origin` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "transform-origin",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub transform_origin: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `unicode-bidi` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "unicode-bidi",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub unicode_bidi: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `vector-effect` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "vector-effect",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub vector_effect: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `visibility` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub visibility: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `white-space` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "white-space",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub white_space: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `word-spacing` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "word-spacing",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub word_spacing: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `writing-mode` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "writing-mode",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub writing_mode: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cancel` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncancel: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `canplay` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncanplay: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `canplaythrough` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncanplaythrough: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `change` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `click` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `close` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onclose: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `cuechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oncuechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dblclick` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondblclick: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `drag` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondrag: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragenter` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragenter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragexit` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragexit: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragleave` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragleave: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragover` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragover: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `dragstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondragstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `drop` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondrop: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `durationchange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ondurationchange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `emptied` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onemptied: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `ended` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onended: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `error` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onerror: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `focus` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onfocus: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `input` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oninput: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `invalid` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub oninvalid: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keydown` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeydown: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keypress` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeypress: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `keyup` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onkeyup: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `load` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onload: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadeddata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadeddata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadedmetadata` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadedmetadata: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `loadstart` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onloadstart: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mousedown` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmousedown: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseenter` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseenter: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseleave` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseleave: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mousemove` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmousemove: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseout` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseout: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseover` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseover: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `mouseup` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onmouseup: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `pause` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onpause: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `play` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onplay: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `playing` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onplaying: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `progress` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onprogress: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `ratechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onratechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `reset` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onreset: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `resize` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onresize: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `scroll` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onscroll: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `seeked` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onseeked: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `seeking` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onseeking: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `select` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onselect: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `show` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onshow: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `stalled` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onstalled: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `submit` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsubmit: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `suspend` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onsuspend: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `timeupdate` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ontimeupdate: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `toggle` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub ontoggle: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `volumechange` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onvolumechange: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `waiting` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwaiting: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// Handler for the `wheel` event
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub onwheel: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `cx` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub cx: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `cy` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub cy: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `r` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "std::option::Option::is_none")
+    )]
+    pub r: std::option::Option<std::borrow::Cow<'static, str>>,
+    /// The `pathLength` attribute
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "pathLength",
+            skip_serializing_if = "std::option::Option::is_none"
+        )
+    )]
+    pub path_length: std::option::Option<std::borrow::Cow<'static, str>>,
+}
+impl crate::RenderElement for Circle {
+    fn write_opening_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        write!(writer, "<circle")?;
+        if let Some(field) = self.id.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" id="{field}""#)?;
+        }
+        if let Some(field) = self.tabindex.as_ref() {
+            write!(writer, r#" tabindex="{field}""#)?;
+        }
+        if self.autofocus {
+            write!(writer, r#" autofocus"#)?;
+        }
+        if let Some(field) = self.lang.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" lang="{field}""#)?;
+        }
+        if let Some(field) = self.class.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" class="{field}""#)?;
+        }
+        if let Some(field) = self.style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" style="{field}""#)?;
+        }
+        if let Some(field) = self.required_extensions.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" requiredExtensions="{field}""#)?;
+        }
+        if let Some(field) = self.system_language.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" systemLanguage="{field}""#)?;
+        }
+        if let Some(field) = self.alignment_baseline.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" alignment-baseline="{field}""#)?;
+        }
+        if let Some(field) = self.baseline_shift.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" baseline-shift="{field}""#)?;
+        }
+        if let Some(field) = self.clip.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" clip="{field}""#)?;
+        }
+        if let Some(field) = self.clip_path.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" clip-path="{field}""#)?;
+        }
+        if let Some(field) = self.clip_rule.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" clip-rule="{field}""#)?;
+        }
+        if let Some(field) = self.color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color="{field}""#)?;
+        }
+        if let Some(field) = self.color_interpolation.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color-interpolation="{field}""#)?;
+        }
+        if let Some(field) = self.color_interpolation_filters.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color-interpolation-filters="{field}""#)?;
+        }
+        if let Some(field) = self.color_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.cursor.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" cursor="{field}""#)?;
+        }
+        if let Some(field) = self.direction.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" direction="{field}""#)?;
+        }
+        if let Some(field) = self.display.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" display="{field}""#)?;
+        }
+        if let Some(field) = self.dominant_baseline.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" dominant-baseline="{field}""#)?;
+        }
+        if let Some(field) = self.fill.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" fill="{field}""#)?;
+        }
+        if let Some(field) = self.fill_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" fill-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.fill_rule.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" fill-rule="{field}""#)?;
+        }
+        if let Some(field) = self.filter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" filter="{field}""#)?;
+        }
+        if let Some(field) = self.flood_color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" flood-color="{field}""#)?;
+        }
+        if let Some(field) = self.flood_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" flood-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.font_family.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-family="{field}""#)?;
+        }
+        if let Some(field) = self.font_size.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-size="{field}""#)?;
+        }
+        if let Some(field) = self.font_size_adjust.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-size-adjust="{field}""#)?;
+        }
+        if let Some(field) = self.font_stretch.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-stretch="{field}""#)?;
+        }
+        if let Some(field) = self.font_style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-style="{field}""#)?;
+        }
+        if let Some(field) = self.font_variant.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-variant="{field}""#)?;
+        }
+        if let Some(field) = self.font_weight.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-weight="{field}""#)?;
+        }
+        if let Some(field) = self.glyph_orientation_horizontal.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" glyph-orientation-horizontal="{field}""#)?;
+        }
+        if let Some(field) = self.glyph_orientation_vertical.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" glyph-orientation-vertical="{field}""#)?;
+        }
+        if let Some(field) = self.image_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" image-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.letter_spacing.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" letter-spacing="{field}""#)?;
+        }
+        if let Some(field) = self.lighting_color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" lighting-color="{field}""#)?;
+        }
+        if let Some(field) = self.marker_end.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" marker-end="{field}""#)?;
+        }
+        if let Some(field) = self.marker_mid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" marker-mid="{field}""#)?;
+        }
+        if let Some(field) = self.marker_start.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" marker-start="{field}""#)?;
+        }
+        if let Some(field) = self.mask.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mask="{field}""#)?;
+        }
+        if let Some(field) = self.mask_type.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mask-type="{field}""#)?;
+        }
+        if let Some(field) = self.opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" opacity="{field}""#)?;
+        }
+        if let Some(field) = self.overflow.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" overflow="{field}""#)?;
+        }
+        if let Some(field) = self.paint_order.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" paint-order="{field}""#)?;
+        }
+        if let Some(field) = self.pointer_events.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" pointer-events="{field}""#)?;
+        }
+        if let Some(field) = self.shape_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" shape-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.stop_color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stop-color="{field}""#)?;
+        }
+        if let Some(field) = self.stop_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stop-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.stroke.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_dasharray.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-dasharray="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_dashoffset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-dashoffset="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_linecap.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-linecap="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_linejoin.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-linejoin="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_miterlimit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-miterlimit="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_width.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-width="{field}""#)?;
+        }
+        if let Some(field) = self.text_anchor.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-anchor="{field}""#)?;
+        }
+        if let Some(field) = self.text_decoration.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-decoration="{field}""#)?;
+        }
+        if let Some(field) = self.text_overflow.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-overflow="{field}""#)?;
+        }
+        if let Some(field) = self.text_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.transform.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" transform="{field}""#)?;
+        }
+        if let Some(field) = self.transform_origin.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" transform-origin="{field}""#)?;
+        }
+        if let Some(field) = self.unicode_bidi.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" unicode-bidi="{field}""#)?;
+        }
+        if let Some(field) = self.vector_effect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" vector-effect="{field}""#)?;
+        }
+        if let Some(field) = self.visibility.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" visibility="{field}""#)?;
+        }
+        if let Some(field) = self.white_space.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" white-space="{field}""#)?;
+        }
+        if let Some(field) = self.word_spacing.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" word-spacing="{field}""#)?;
+        }
+        if let Some(field) = self.writing_mode.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" writing-mode="{field}""#)?;
+        }
+        if let Some(field) = self.oncancel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncancel="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplay="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplaythrough.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplaythrough="{field}""#)?;
+        }
+        if let Some(field) = self.onchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onchange="{field}""#)?;
+        }
+        if let Some(field) = self.onclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclick="{field}""#)?;
+        }
+        if let Some(field) = self.onclose.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclose="{field}""#)?;
+        }
+        if let Some(field) = self.oncuechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncuechange="{field}""#)?;
+        }
+        if let Some(field) = self.ondblclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondblclick="{field}""#)?;
+        }
+        if let Some(field) = self.ondrag.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrag="{field}""#)?;
+        }
+        if let Some(field) = self.ondragend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragend="{field}""#)?;
+        }
+        if let Some(field) = self.ondragenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragenter="{field}""#)?;
+        }
+        if let Some(field) = self.ondragexit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragexit="{field}""#)?;
+        }
+        if let Some(field) = self.ondragleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragleave="{field}""#)?;
+        }
+        if let Some(field) = self.ondragover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragover="{field}""#)?;
+        }
+        if let Some(field) = self.ondragstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragstart="{field}""#)?;
+        }
+        if let Some(field) = self.ondrop.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrop="{field}""#)?;
+        }
+        if let Some(field) = self.ondurationchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondurationchange="{field}""#)?;
+        }
+        if let Some(field) = self.onemptied.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onemptied="{field}""#)?;
+        }
+        if let Some(field) = self.onended.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onended="{field}""#)?;
+        }
+        if let Some(field) = self.onerror.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onerror="{field}""#)?;
+        }
+        if let Some(field) = self.onfocus.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onfocus="{field}""#)?;
+        }
+        if let Some(field) = self.oninput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninput="{field}""#)?;
+        }
+        if let Some(field) = self.oninvalid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninvalid="{field}""#)?;
+        }
+        if let Some(field) = self.onkeydown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeydown="{field}""#)?;
+        }
+        if let Some(field) = self.onkeypress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeypress="{field}""#)?;
+        }
+        if let Some(field) = self.onkeyup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeyup="{field}""#)?;
+        }
+        if let Some(field) = self.onload.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onload="{field}""#)?;
+        }
+        if let Some(field) = self.onloadeddata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadeddata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadedmetadata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadedmetadata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadstart="{field}""#)?;
+        }
+        if let Some(field) = self.onmousedown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousedown="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseenter="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseleave="{field}""#)?;
+        }
+        if let Some(field) = self.onmousemove.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousemove="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseout.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseout="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseover="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseup="{field}""#)?;
+        }
+        if let Some(field) = self.onpause.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpause="{field}""#)?;
+        }
+        if let Some(field) = self.onplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplay="{field}""#)?;
+        }
+        if let Some(field) = self.onplaying.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplaying="{field}""#)?;
+        }
+        if let Some(field) = self.onprogress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onprogress="{field}""#)?;
+        }
+        if let Some(field) = self.onratechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onratechange="{field}""#)?;
+        }
+        if let Some(field) = self.onreset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onreset="{field}""#)?;
+        }
+        if let Some(field) = self.onresize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onresize="{field}""#)?;
+        }
+        if let Some(field) = self.onscroll.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscroll="{field}""#)?;
+        }
+        if let Some(field) = self.onseeked.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeked="{field}""#)?;
+        }
+        if let Some(field) = self.onseeking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeking="{field}""#)?;
+        }
+        if let Some(field) = self.onselect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onselect="{field}""#)?;
+        }
+        if let Some(field) = self.onshow.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onshow="{field}""#)?;
+        }
+        if let Some(field) = self.onstalled.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onstalled="{field}""#)?;
+        }
+        if let Some(field) = self.onsubmit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsubmit="{field}""#)?;
+        }
+        if let Some(field) = self.onsuspend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsuspend="{field}""#)?;
+        }
+        if let Some(field) = self.ontimeupdate.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontimeupdate="{field}""#)?;
+        }
+        if let Some(field) = self.ontoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onvolumechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onvolumechange="{field}""#)?;
+        }
+        if let Some(field) = self.onwaiting.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwaiting="{field}""#)?;
+        }
+        if let Some(field) = self.onwheel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwheel="{field}""#)?;
+        }
+        if let Some(field) = self.cx.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" cx="{field}""#)?;
+        }
+        if let Some(field) = self.cy.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" cy="{field}""#)?;
+        }
+        if let Some(field) = self.r.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" r="{field}""#)?;
+        }
+        if let Some(field) = self.path_length.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" pathLength="{field}""#)?;
+        }
+        write!(writer, "{}", self.data_map)?;
+        write!(writer, ">")?;
+        Ok(())
+    }
+    #[allow(unused_variables)]
+    fn write_closing_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        write!(writer, "</circle>")?;
+        Ok(())
+    }
+    fn write_empty_tag<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        write!(writer, "<circle")?;
+        if let Some(field) = self.id.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" id="{field}""#)?;
+        }
+        if let Some(field) = self.tabindex.as_ref() {
+            write!(writer, r#" tabindex="{field}""#)?;
+        }
+        if self.autofocus {
+            write!(writer, r#" autofocus"#)?;
+        }
+        if let Some(field) = self.lang.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" lang="{field}""#)?;
+        }
+        if let Some(field) = self.class.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" class="{field}""#)?;
+        }
+        if let Some(field) = self.style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" style="{field}""#)?;
+        }
+        if let Some(field) = self.required_extensions.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" requiredExtensions="{field}""#)?;
+        }
+        if let Some(field) = self.system_language.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" systemLanguage="{field}""#)?;
+        }
+        if let Some(field) = self.alignment_baseline.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" alignment-baseline="{field}""#)?;
+        }
+        if let Some(field) = self.baseline_shift.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" baseline-shift="{field}""#)?;
+        }
+        if let Some(field) = self.clip.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" clip="{field}""#)?;
+        }
+        if let Some(field) = self.clip_path.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" clip-path="{field}""#)?;
+        }
+        if let Some(field) = self.clip_rule.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" clip-rule="{field}""#)?;
+        }
+        if let Some(field) = self.color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color="{field}""#)?;
+        }
+        if let Some(field) = self.color_interpolation.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color-interpolation="{field}""#)?;
+        }
+        if let Some(field) = self.color_interpolation_filters.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color-interpolation-filters="{field}""#)?;
+        }
+        if let Some(field) = self.color_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" color-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.cursor.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" cursor="{field}""#)?;
+        }
+        if let Some(field) = self.direction.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" direction="{field}""#)?;
+        }
+        if let Some(field) = self.display.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" display="{field}""#)?;
+        }
+        if let Some(field) = self.dominant_baseline.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" dominant-baseline="{field}""#)?;
+        }
+        if let Some(field) = self.fill.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" fill="{field}""#)?;
+        }
+        if let Some(field) = self.fill_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" fill-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.fill_rule.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" fill-rule="{field}""#)?;
+        }
+        if let Some(field) = self.filter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" filter="{field}""#)?;
+        }
+        if let Some(field) = self.flood_color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" flood-color="{field}""#)?;
+        }
+        if let Some(field) = self.flood_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" flood-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.font_family.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-family="{field}""#)?;
+        }
+        if let Some(field) = self.font_size.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-size="{field}""#)?;
+        }
+        if let Some(field) = self.font_size_adjust.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-size-adjust="{field}""#)?;
+        }
+        if let Some(field) = self.font_stretch.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-stretch="{field}""#)?;
+        }
+        if let Some(field) = self.font_style.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-style="{field}""#)?;
+        }
+        if let Some(field) = self.font_variant.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-variant="{field}""#)?;
+        }
+        if let Some(field) = self.font_weight.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" font-weight="{field}""#)?;
+        }
+        if let Some(field) = self.glyph_orientation_horizontal.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" glyph-orientation-horizontal="{field}""#)?;
+        }
+        if let Some(field) = self.glyph_orientation_vertical.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" glyph-orientation-vertical="{field}""#)?;
+        }
+        if let Some(field) = self.image_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" image-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.letter_spacing.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" letter-spacing="{field}""#)?;
+        }
+        if let Some(field) = self.lighting_color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" lighting-color="{field}""#)?;
+        }
+        if let Some(field) = self.marker_end.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" marker-end="{field}""#)?;
+        }
+        if let Some(field) = self.marker_mid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" marker-mid="{field}""#)?;
+        }
+        if let Some(field) = self.marker_start.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" marker-start="{field}""#)?;
+        }
+        if let Some(field) = self.mask.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mask="{field}""#)?;
+        }
+        if let Some(field) = self.mask_type.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" mask-type="{field}""#)?;
+        }
+        if let Some(field) = self.opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" opacity="{field}""#)?;
+        }
+        if let Some(field) = self.overflow.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" overflow="{field}""#)?;
+        }
+        if let Some(field) = self.paint_order.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" paint-order="{field}""#)?;
+        }
+        if let Some(field) = self.pointer_events.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" pointer-events="{field}""#)?;
+        }
+        if let Some(field) = self.shape_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" shape-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.stop_color.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stop-color="{field}""#)?;
+        }
+        if let Some(field) = self.stop_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stop-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.stroke.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_dasharray.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-dasharray="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_dashoffset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-dashoffset="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_linecap.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-linecap="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_linejoin.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-linejoin="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_miterlimit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-miterlimit="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_opacity.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-opacity="{field}""#)?;
+        }
+        if let Some(field) = self.stroke_width.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" stroke-width="{field}""#)?;
+        }
+        if let Some(field) = self.text_anchor.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-anchor="{field}""#)?;
+        }
+        if let Some(field) = self.text_decoration.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-decoration="{field}""#)?;
+        }
+        if let Some(field) = self.text_overflow.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-overflow="{field}""#)?;
+        }
+        if let Some(field) = self.text_rendering.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" text-rendering="{field}""#)?;
+        }
+        if let Some(field) = self.transform.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" transform="{field}""#)?;
+        }
+        if let Some(field) = self.transform_origin.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" transform-origin="{field}""#)?;
+        }
+        if let Some(field) = self.unicode_bidi.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" unicode-bidi="{field}""#)?;
+        }
+        if let Some(field) = self.vector_effect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" vector-effect="{field}""#)?;
+        }
+        if let Some(field) = self.visibility.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" visibility="{field}""#)?;
+        }
+        if let Some(field) = self.white_space.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" white-space="{field}""#)?;
+        }
+        if let Some(field) = self.word_spacing.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" word-spacing="{field}""#)?;
+        }
+        if let Some(field) = self.writing_mode.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" writing-mode="{field}""#)?;
+        }
+        if let Some(field) = self.oncancel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncancel="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplay="{field}""#)?;
+        }
+        if let Some(field) = self.oncanplaythrough.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncanplaythrough="{field}""#)?;
+        }
+        if let Some(field) = self.onchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onchange="{field}""#)?;
+        }
+        if let Some(field) = self.onclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclick="{field}""#)?;
+        }
+        if let Some(field) = self.onclose.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onclose="{field}""#)?;
+        }
+        if let Some(field) = self.oncuechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oncuechange="{field}""#)?;
+        }
+        if let Some(field) = self.ondblclick.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondblclick="{field}""#)?;
+        }
+        if let Some(field) = self.ondrag.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrag="{field}""#)?;
+        }
+        if let Some(field) = self.ondragend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragend="{field}""#)?;
+        }
+        if let Some(field) = self.ondragenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragenter="{field}""#)?;
+        }
+        if let Some(field) = self.ondragexit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragexit="{field}""#)?;
+        }
+        if let Some(field) = self.ondragleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragleave="{field}""#)?;
+        }
+        if let Some(field) = self.ondragover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragover="{field}""#)?;
+        }
+        if let Some(field) = self.ondragstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondragstart="{field}""#)?;
+        }
+        if let Some(field) = self.ondrop.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondrop="{field}""#)?;
+        }
+        if let Some(field) = self.ondurationchange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ondurationchange="{field}""#)?;
+        }
+        if let Some(field) = self.onemptied.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onemptied="{field}""#)?;
+        }
+        if let Some(field) = self.onended.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onended="{field}""#)?;
+        }
+        if let Some(field) = self.onerror.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onerror="{field}""#)?;
+        }
+        if let Some(field) = self.onfocus.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onfocus="{field}""#)?;
+        }
+        if let Some(field) = self.oninput.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninput="{field}""#)?;
+        }
+        if let Some(field) = self.oninvalid.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" oninvalid="{field}""#)?;
+        }
+        if let Some(field) = self.onkeydown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeydown="{field}""#)?;
+        }
+        if let Some(field) = self.onkeypress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeypress="{field}""#)?;
+        }
+        if let Some(field) = self.onkeyup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onkeyup="{field}""#)?;
+        }
+        if let Some(field) = self.onload.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onload="{field}""#)?;
+        }
+        if let Some(field) = self.onloadeddata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadeddata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadedmetadata.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadedmetadata="{field}""#)?;
+        }
+        if let Some(field) = self.onloadstart.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onloadstart="{field}""#)?;
+        }
+        if let Some(field) = self.onmousedown.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousedown="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseenter.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseenter="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseleave.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseleave="{field}""#)?;
+        }
+        if let Some(field) = self.onmousemove.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmousemove="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseout.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseout="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseover.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseover="{field}""#)?;
+        }
+        if let Some(field) = self.onmouseup.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onmouseup="{field}""#)?;
+        }
+        if let Some(field) = self.onpause.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onpause="{field}""#)?;
+        }
+        if let Some(field) = self.onplay.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplay="{field}""#)?;
+        }
+        if let Some(field) = self.onplaying.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onplaying="{field}""#)?;
+        }
+        if let Some(field) = self.onprogress.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onprogress="{field}""#)?;
+        }
+        if let Some(field) = self.onratechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onratechange="{field}""#)?;
+        }
+        if let Some(field) = self.onreset.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onreset="{field}""#)?;
+        }
+        if let Some(field) = self.onresize.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onresize="{field}""#)?;
+        }
+        if let Some(field) = self.onscroll.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onscroll="{field}""#)?;
+        }
+        if let Some(field) = self.onseeked.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeked="{field}""#)?;
+        }
+        if let Some(field) = self.onseeking.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onseeking="{field}""#)?;
+        }
+        if let Some(field) = self.onselect.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onselect="{field}""#)?;
+        }
+        if let Some(field) = self.onshow.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onshow="{field}""#)?;
+        }
+        if let Some(field) = self.onstalled.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onstalled="{field}""#)?;
+        }
+        if let Some(field) = self.onsubmit.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsubmit="{field}""#)?;
+        }
+        if let Some(field) = self.onsuspend.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onsuspend="{field}""#)?;
+        }
+        if let Some(field) = self.ontimeupdate.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontimeupdate="{field}""#)?;
+        }
+        if let Some(field) = self.ontoggle.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" ontoggle="{field}""#)?;
+        }
+        if let Some(field) = self.onvolumechange.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onvolumechange="{field}""#)?;
+        }
+        if let Some(field) = self.onwaiting.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwaiting="{field}""#)?;
+        }
+        if let Some(field) = self.onwheel.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" onwheel="{field}""#)?;
+        }
+        if let Some(field) = self.cx.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" cx="{field}""#)?;
+        }
+        if let Some(field) = self.cy.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" cy="{field}""#)?;
+        }
+        if let Some(field) = self.r.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" r="{field}""#)?;
+        }
+        if let Some(field) = self.path_length.as_ref() {
+            let field = crate::escape::AttributeValue(field);
+            write!(writer, r#" pathLength="{field}""#)?;
+        }
+        write!(writer, "{}", self.data_map)?;
+        write!(writer, "/>")?;
+        Ok(())
+    }
+}
+impl crate::ElementDescription for Circle {
+    fn attributes(&self) -> crate::AttributeMap {
+        let mut attrs = crate::AttributeMap::new();
+        if let Some(field) = &self.id {
+            attrs.insert(std::borrow::Cow::Borrowed("id"), field.clone());
+        }
+        if let Some(field) = &self.tabindex {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("tabindex"),
+                    std::borrow::Cow::Owned(field.to_string()),
+                );
+        }
+        if self.autofocus {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("autofocus"),
+                    std::borrow::Cow::Borrowed("true"),
+                );
+        }
+        if let Some(field) = &self.lang {
+            attrs.insert(std::borrow::Cow::Borrowed("lang"), field.clone());
+        }
+        if let Some(field) = &self.class {
+            attrs.insert(std::borrow::Cow::Borrowed("class"), field.clone());
+        }
+        if let Some(field) = &self.style {
+            attrs.insert(std::borrow::Cow::Borrowed("style"), field.clone());
+        }
+        if let Some(field) = &self.required_extensions {
+            attrs
+                .insert(std::borrow::Cow::Borrowed("requiredExtensions"), field.clone());
+        }
+        if let Some(field) = &self.system_language {
+            attrs.insert(std::borrow::Cow::Borrowed("systemLanguage"), field.clone());
+        }
+        if let Some(field) = &self.alignment_baseline {
+            attrs
+                .insert(std::borrow::Cow::Borrowed("alignment-baseline"), field.clone());
+        }
+        if let Some(field) = &self.baseline_shift {
+            attrs.insert(std::borrow::Cow::Borrowed("baseline-shift"), field.clone());
+        }
+        if let Some(field) = &self.clip {
+            attrs.insert(std::borrow::Cow::Borrowed("clip"), field.clone());
+        }
+        if let Some(field) = &self.clip_path {
+            attrs.insert(std::borrow::Cow::Borrowed("clip-path"), field.clone());
+        }
+        if let Some(field) = &self.clip_rule {
+            attrs.insert(std::borrow::Cow::Borrowed("clip-rule"), field.clone());
+        }
+        if let Some(field) = &self.color {
+            attrs.insert(std::borrow::Cow::Borrowed("color"), field.clone());
+        }
+        if let Some(field) = &self.color_interpolation {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("color-interpolation"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.color_interpolation_filters {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("color-interpolation-filters"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.color_rendering {
+            attrs.insert(std::borrow::Cow::Borrowed("color-rendering"), field.clone());
+        }
+        if let Some(field) = &self.cursor {
+            attrs.insert(std::borrow::Cow::Borrowed("cursor"), field.clone());
+        }
+        if let Some(field) = &self.direction {
+            attrs.insert(std::borrow::Cow::Borrowed("direction"), field.clone());
+        }
+        if let Some(field) = &self.display {
+            attrs.insert(std::borrow::Cow::Borrowed("display"), field.clone());
+        }
+        if let Some(field) = &self.dominant_baseline {
+            attrs.insert(std::borrow::Cow::Borrowed("dominant-baseline"), field.clone());
+        }
+        if let Some(field) = &self.fill {
+            attrs.insert(std::borrow::Cow::Borrowed("fill"), field.clone());
+        }
+        if let Some(field) = &self.fill_opacity {
+            attrs.insert(std::borrow::Cow::Borrowed("fill-opacity"), field.clone());
+        }
+        if let Some(field) = &self.fill_rule {
+            attrs.insert(std::borrow::Cow::Borrowed("fill-rule"), field.clone());
+        }
+        if let Some(field) = &self.filter {
+            attrs.insert(std::borrow::Cow::Borrowed("filter"), field.clone());
+        }
+        if let Some(field) = &self.flood_color {
+            attrs.insert(std::borrow::Cow::Borrowed("flood-color"), field.clone());
+        }
+        if let Some(field) = &self.flood_opacity {
+            attrs.insert(std::borrow::Cow::Borrowed("flood-opacity"), field.clone());
+        }
+        if let Some(field) = &self.font_family {
+            attrs.insert(std::borrow::Cow::Borrowed("font-family"), field.clone());
+        }
+        if let Some(field) = &self.font_size {
+            attrs.insert(std::borrow::Cow::Borrowed("font-size"), field.clone());
+        }
+        if let Some(field) = &self.font_size_adjust {
+            attrs.insert(std::borrow::Cow::Borrowed("font-size-adjust"), field.clone());
+        }
+        if let Some(field) = &self.font_stretch {
+            attrs.insert(std::borrow::Cow::Borrowed("font-stretch"), field.clone());
+        }
+        if let Some(field) = &self.font_style {
+            attrs.insert(std::borrow::Cow::Borrowed("font-style"), field.clone());
+        }
+        if let Some(field) = &self.font_variant {
+            attrs.insert(std::borrow::Cow::Borrowed("font-variant"), field.clone());
+        }
+        if let Some(field) = &self.font_weight {
+            attrs.insert(std::borrow::Cow::Borrowed("font-weight"), field.clone());
+        }
+        if let Some(field) = &self.glyph_orientation_horizontal {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("glyph-orientation-horizontal"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.glyph_orientation_vertical {
+            attrs
+                .insert(
+                    std::borrow::Cow::Borrowed("glyph-orientation-vertical"),
+                    field.clone(),
+                );
+        }
+        if let Some(field) = &self.image_rendering {
+            attrs.insert(std::borrow::Cow::Borrowed("image-rendering"), field.clone());
+        }
+        if let Some(field) = &self.letter_spacing {
+            attrs.insert(std::borrow::Cow::Borrowed("letter-spacing"), field.clone());
+        }
+        if let Some(field) = &self.lighting_color {
+            attrs.insert(std::borrow::Cow::Borrowed("lighting-color"), field.clone());
+        }
+        if let Some(field) = &self.marker_end {
+            attrs.insert(std::borrow::Cow::Borrowed("marker-end"), field.clone());
+        }
+        if let Some(field) = &self.marker_mid {
+            attrs.insert(std::borrow::Cow::Borrowed("marker-mid"), field.clone());
+        }
+        if let Some(field) = &self.marker_start {
+            attrs.insert(std::borrow::Cow::Borrowed("marker-start"), field.clone());
+        }
+        if let Some(field) = &self.mask {
+            attrs.insert(std::borrow::Cow::Borrowed("mask"), field.clone());
+        }
+        if let Some(field) = &self.mask_type {
+            attrs.insert(std::borrow::Cow::Borrowed("mask-type"), field.clone());
+        }
+        if let Some(field) = &self.opacity {
+            attrs.insert(std::borrow::Cow::Borrowed("opacity"), field.clone());
+        }
+        if let Some(field) = &self.overflow {
+            attrs.insert(std::borrow::Cow::Borrowed("overflow"), field.clone());
+        }
+        if let Some(field) = &self.paint_order {
+            attrs.insert(std::borrow::Cow::Borrowed("paint-order"), field.clone());
+        }
+        if let Some(field) = &self.pointer_events {
+            attrs.insert(std::borrow::Cow::Borrowed("pointer-events"), field.clone());
+        }
+        if let Some(field) = &self.shape_rendering {
+            attrs.insert(std::borrow::Cow::Borrowed("shape-rendering"), field.clone());
+        }
+        if let Some(field) = &self.stop_color {
+            attrs.insert(std::borrow::Cow::Borrowed("stop-color"), field.clone());
+        }
+        if let Some(field) = &self.stop_opacity {
+            attrs.insert(std::borrow::Cow::Borrowed("stop-opacity"), field.clone());
+        }
+        if let Some(field) = &self.stroke {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke"), field.clone());
+        }
+        if let Some(field) = &self.stroke_dasharray {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-dasharray"), field.clone());
+        }
+        if let Some(field) = &self.stroke_dashoffset {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-dashoffset"), field.clone());
+        }
+        if let Some(field) = &self.stroke_linecap {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-linecap"), field.clone());
+        }
+        if let Some(field) = &self.stroke_linejoin {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-linejoin"), field.clone());
+        }
+        if let Some(field) = &self.stroke_miterlimit {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-miterlimit"), field.clone());
+        }
+        if let Some(field) = &self.stroke_opacity {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-opacity"), field.clone());
+        }
+        if let Some(field) = &self.stroke_width {
+            attrs.insert(std::borrow::Cow::Borrowed("stroke-width"), field.clone());
+        }
+        if let Some(field) = &self.text_anchor {
+            attrs.insert(std::borrow::Cow::Borrowed("text-anchor"), field.clone());
+        }
+        if let Some(field) = &self.text_decoration {
+            attrs.insert(std::borrow::Cow::Borrowed("text-decoration"), field.clone());
+        }
+        if let Some(field) = &self.text_overflow {
+            attrs.insert(std::borrow::Cow::Borrowed("text-overflow"), field.clone());
+        }
+        if let Some(field) = &self.text_rendering {
+            attrs.insert(std::borrow::Cow::Borrowed("text-rendering"), field.clone());
+        }
+        if let Some(field) = &self.transform {
+            attrs.insert(std::borrow::Cow::Borrowed("transform"), field.clone());
+        }
+        if let Some(field) = &self.transform_origin {
+            attrs.insert(std::borrow::Cow::Borrowed("transform-origin"), field.clone());
+        }
+        if let Some(field) = &self.unicode_bidi {
+            attrs.insert(std::borrow::Cow::Borrowed("unicode-bidi"), field.clone());
+        }
+        if let Some(field) = &self.vector_effect {
+            attrs.insert(std::borrow::Cow::Borrowed("vector-effect"), field.clone());
+        }
+        if let Some(field) = &self.visibility {
+            attrs.insert(std::borrow::Cow::Borrowed("visibility"), field.clone());
+        }
+        if let Some(field) = &self.white_space {
+            attrs.insert(std::borrow::Cow::Borrowed("white-space"), field.clone());
+        }
+        if let Some(field) = &self.word_spacing {
+            attrs.insert(std::borrow::Cow::Borrowed("word-spacing"), field.clone());
+        }
+        if let Some(field) = &self.writing_mode {
+            attrs.insert(std::borrow::Cow::Borrowed("writing-mode"), field.clone());
+        }
+        if let Some(field) = &self.oncancel {
+            attrs.insert(std::borrow::Cow::Borrowed("oncancel"), field.clone());
+        }
+        if let Some(field) = &self.oncanplay {
+            attrs.insert(std::borrow::Cow::Borrowed("oncanplay"), field.clone());
+        }
+        if let Some(field) = &self.oncanplaythrough {
+            attrs.insert(std::borrow::Cow::Borrowed("oncanplaythrough"), field.clone());
+        }
+        if let Some(field) = &self.onchange {
+            attrs.insert(std::borrow::Cow::Borrowed("onchange"), field.clone());
+        }
+        if let Some(field) = &self.onclick {
+            attrs.insert(std::borrow::Cow::Borrowed("onclick"), field.clone());
+        }
+        if let Some(field) = &self.onclose {
+            attrs.insert(std::borrow::Cow::Borrowed("onclose"), field.clone());
+        }
+        if let Some(field) = &self.oncuechange {
+            attrs.insert(std::borrow::Cow::Borrowed("oncuechange"), field.clone());
+        }
+        if let Some(field) = &self.ondblclick {
+            attrs.insert(std::borrow::Cow::Borrowed("ondblclick"), field.clone());
+        }
+        if let Some(field) = &self.ondrag {
+            attrs.insert(std::borrow::Cow::Borrowed("ondrag"), field.clone());
+        }
+        if let Some(field) = &self.ondragend {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragend"), field.clone());
+        }
+        if let Some(field) = &self.ondragenter {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragenter"), field.clone());
+        }
+        if let Some(field) = &self.ondragexit {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragexit"), field.clone());
+        }
+        if let Some(field) = &self.ondragleave {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragleave"), field.clone());
+        }
+        if let Some(field) = &self.ondragover {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragover"), field.clone());
+        }
+        if let Some(field) = &self.ondragstart {
+            attrs.insert(std::borrow::Cow::Borrowed("ondragstart"), field.clone());
+        }
+        if let Some(field) = &self.ondrop {
+            attrs.insert(std::borrow::Cow::Borrowed("ondrop"), field.clone());
+        }
+        if let Some(field) = &self.ondurationchange {
+            attrs.insert(std::borrow::Cow::Borrowed("ondurationchange"), field.clone());
+        }
+        if let Some(field) = &self.onemptied {
+            attrs.insert(std::borrow::Cow::Borrowed("onemptied"), field.clone());
+        }
+        if let Some(field) = &self.onended {
+            attrs.insert(std::borrow::Cow::Borrowed("onended"), field.clone());
+        }
+        if let Some(field) = &self.onerror {
+            attrs.insert(std::borrow::Cow::Borrowed("onerror"), field.clone());
+        }
+        if let Some(field) = &self.onfocus {
+            attrs.insert(std::borrow::Cow::Borrowed("onfocus"), field.clone());
+        }
+        if let Some(field) = &self.oninput {
+            attrs.insert(std::borrow::Cow::Borrowed("oninput"), field.clone());
+        }
+        if let Some(field) = &self.oninvalid {
+            attrs.insert(std::borrow::Cow::Borrowed("oninvalid"), field.clone());
+        }
+        if let Some(field) = &self.onkeydown {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeydown"), field.clone());
+        }
+        if let Some(field) = &self.onkeypress {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeypress"), field.clone());
+        }
+        if let Some(field) = &self.onkeyup {
+            attrs.insert(std::borrow::Cow::Borrowed("onkeyup"), field.clone());
+        }
+        if let Some(field) = &self.onload {
+            attrs.insert(std::borrow::Cow::Borrowed("onload"), field.clone());
+        }
+        if let Some(field) = &self.onloadeddata {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadeddata"), field.clone());
+        }
+        if let Some(field) = &self.onloadedmetadata {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadedmetadata"), field.clone());
+        }
+        if let Some(field) = &self.onloadstart {
+            attrs.insert(std::borrow::Cow::Borrowed("onloadstart"), field.clone());
+        }
+        if let Some(field) = &self.onmousedown {
+            attrs.insert(std::borrow::Cow::Borrowed("onmousedown"), field.clone());
+        }
+        if let Some(field) = &self.onmouseenter {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseenter"), field.clone());
+        }
+        if let Some(field) = &self.onmouseleave {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseleave"), field.clone());
+        }
+        if let Some(field) = &self.onmousemove {
+            attrs.insert(std::borrow::Cow::Borrowed("onmousemove"), field.clone());
+        }
+        if let Some(field) = &self.onmouseout {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseout"), field.clone());
+        }
+        if let Some(field) = &self.onmouseover {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseover"), field.clone());
+        }
+        if let Some(field) = &self.onmouseup {
+            attrs.insert(std::borrow::Cow::Borrowed("onmouseup"), field.clone());
+        }
+        if let Some(field) = &self.onpause {
+            attrs.insert(std::borrow::Cow::Borrowed("onpause"), field.clone());
+        }
+        if let Some(field) = &self.onplay {
+            attrs.insert(std::borrow::Cow::Borrowed("onplay"), field.clone());
+        }
+        if let Some(field) = &self.onplaying {
+            attrs.insert(std::borrow::Cow::Borrowed("onplaying"), field.clone());
+        }
+        if let Some(field) = &self.onprogress {
+            attrs.insert(std::borrow::Cow::Borrowed("onprogress"), field.clone());
+        }
+        if let Some(field) = &self.onratechange {
+            attrs.insert(std::borrow::Cow::Borrowed("onratechange"), field.clone());
+        }
+        if let Some(field) = &self.onreset {
+            attrs.insert(std::borrow::Cow::Borrowed("onreset"), field.clone());
+        }
+        if let Some(field) = &self.onresize {
+            attrs.insert(std::borrow::Cow::Borrowed("onresize"), field.clone());
+        }
+        if let Some(field) = &self.onscroll {
+            attrs.insert(std::borrow::Cow::Borrowed("onscroll"), field.clone());
+        }
+        if let Some(field) = &self.onseeked {
+            attrs.insert(std::borrow::Cow::Borrowed("onseeked"), field.clone());
+        }
+        if let Some(field) = &self.onseeking {
+            attrs.insert(std::borrow::Cow::Borrowed("onseeking"), field.clone());
+        }
+        if let Some(field) = &self.onselect {
+            attrs.insert(std::borrow::Cow::Borrowed("onselect"), field.clone());
+        }
+        if let Some(field) = &self.onshow {
+            attrs.insert(std::borrow::Cow::Borrowed("onshow"), field.clone());
+        }
+        if let Some(field) = &self.onstalled {
+            attrs.insert(std::borrow::Cow::Borrowed("onstalled"), field.clone());
+        }
+        if let Some(field) = &self.onsubmit {
+            attrs.insert(std::borrow::Cow::Borrowed("onsubmit"), field.clone());
+        }
+        if let Some(field) = &self.onsuspend {
+            attrs.insert(std::borrow::Cow::Borrowed("onsuspend"), field.clone());
+        }
+        if let Some(field) = &self.ontimeupdate {
+            attrs.insert(std::borrow::Cow::Borrowed("ontimeupdate"), field.clone());
+        }
+        if let Some(field) = &self.ontoggle {
+            attrs.insert(std::borrow::Cow::Borrowed("ontoggle"), field.clone());
+        }
+        if let Some(field) = &self.onvolumechange {
+            attrs.insert(std::borrow::Cow::Borrowed("onvolumechange"), field.clone());
+        }
+        if let Some(field) = &self.onwaiting {
+            attrs.insert(std::borrow::Cow::Borrowed("onwaiting"), field.clone());
+        }
+        if let Some(field) = &self.onwheel {
+            attrs.insert(std::borrow::Cow::Borrowed("onwheel"), field.clone());
+        }
+        if let Some(field) = &self.cx {
+            attrs.insert(std::borrow::Cow::Borrowed("cx"), field.clone());
+        }
+        if let Some(field) = &self.cy {
+            attrs.insert(std::borrow::Cow::Borrowed("cy"), field.clone());
+        }
+        if let Some(field) = &self.r {
+            attrs.insert(std::borrow::Cow::Borrowed("r"), field.clone());
+        }
+        if let Some(field) = &self.path_length {
+            attrs.insert(std::borrow::Cow::Borrowed("pathLength"), field.clone());
+        }
+        attrs
+    }
+    fn data(&self) -> crate::AttributeMap {
+        self.data_map.map.clone()
+    }
+}
+impl std::fmt::Display for Circle {
+    fn fmt(&self, writer: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use crate::RenderElement;
+        self.write_empty_tag(writer)
+    }
+}
@@ -0,0 +1,43 @@
+use html_sys::escape::{AttributeValue, RawText, Text};
+use html_sys::text::Anchor;
+
+#[test]
+fn attribute_values() {
+    let mut anchor = Anchor::default();
+    anchor.href = Some(r#"/search?q="><script>&"#.into());
+    anchor
+        .data_map
+        .insert("title".into(), r#"a "quoted" title"#.into());
+    assert_eq!(
+        anchor.to_string(),
+        r#"<a href="/search?q=&quot;&gt;&lt;script&gt;&amp;" data-title="a &quot;quoted&quot; title"></a>"#
+    );
+}
+
+#[test]
+fn text() {
+    assert_eq!(
+        Text("1 < 2 && \"3\" > 2").to_string(),
+        r#"1 &lt; 2 &amp;&amp; "3" &gt; 2"#
+    );
+    assert_eq!(AttributeValue("it's").to_string(), "it's");
+}
+
+#[test]
+fn raw_text() {
+    let script = r#"if (a < b) { document.write("</SCRIPT><!-- hi"); }"#;
+    assert_eq!(
+        RawText::new("script", script).to_string(),
+        r#"if (a < b) { document.write("<\/SCRIPT><\!-- hi"); }"#
+    );
+
+    let style = "a::after { content: '</style>'; } /* <!-- */";
+    assert_eq!(
+        RawText::new("style", style).to_string(),
+        r"a::after { content: '<\/style>'; } /* <!-- */"
+    );
+    assert_eq!(
+        RawText::new("script", "</scripts> </p>").to_string(),
+        r"<\/scripts> </p>"
+    );
+}
@@ -0,0 +1,912 @@
+//! Audit element trees for accessibility problems.
+//!
+//! [`audit`] walks a tree and reports the problems it finds as [`Finding`]s.
+//! Each finding names the [`Rule`] which was broken, and holds the
+//! [path](Finding::path) to the element which broke it. The path has the
+//! indices of the children which lead to the element from the root, counting
+//! all children, including text and comments.
+//!
+//! The rules cover failures of [WCAG 2] which can be found by looking at the
+//! tree alone, so a tree without findings isn't necessarily accessible. Roles
+//! are taken from the `role` attribute, or else from the implicit roles
+//! [ARIA in HTML] gives elements.
+//!
+//! [WCAG 2]: https://www.w3.org/TR/WCAG2/
+//! [ARIA in HTML]: https://www.w3.org/TR/html-aria/
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #![recursion_limit = "512"]
+//! use html::a11y::{audit, Rule};
+//! use html::text_content::Division;
+//!
+//! let tree = Division::builder()
+//!     .image(|img| img.src("nori.png"))
+//!     .anchor(|a| a.href("/cats"))
+//!     .build();
+//!
+//! let findings = audit(&tree);
+//! assert_eq!(findings.len(), 2);
+//! assert_eq!(findings[0].rule, Rule::ImageAlt);
+//! assert_eq!(findings[0].path, [0]);
+//! assert_eq!(
+//!     findings[1].to_string(),
+//!     "link-name at [1]: `<a>` has no text"
+//! );
+//! ```
+//!
+//! # Names and roles
+//!
+//! [`accessible_name`] and [`accessible_description`] compute the name and
+//! description which assistive technology announces for an element,
+//! following the [Accessible Name and Description Computation]. They take
+//! `aria-labelledby`, `aria-label`, `<label>`s, `alt`, `<legend>`,
+//! `<caption>`, `<figcaption>`, `title` and the text of the element into
+//! account. [`implicit_role`] computes the role an element has without a
+//! `role` attribute, which can depend on its attributes and ancestors.
+//!
+//! Elements are found by their path in the tree, like the paths of findings.
+//! Styles aren't known, so only the `hidden` and `aria-hidden` attributes hide
+//! elements.
+//!
+//! [Accessible Name and Description Computation]: https://www.w3.org/TR/accname-1.2/
+//!
+//! ```rust
+//! # #![recursion_limit = "512"]
+//! use html::a11y::{accessible_name, implicit_role};
+//! use html::text_content::Division;
+//!
+//! let tree = Division::builder()
+//!     .label(|label| label.for_("name").text("Name"))
+//!     .input(|input| input.id("name"))
+//!     .button(|button| button.image(|img| img.src("close.png").alt("Close")))
+//!     .build();
+//!
+//! assert_eq!(accessible_name(&tree, &[1]).as_deref(), Some("Name"));
+//! assert_eq!(implicit_role(&tree, &[1]), Some("textbox"));
+//! assert_eq!(accessible_name(&tree, &[2]).as_deref(), Some("Close"));
+//! ```
+//!
+//! # Serialization
+//!
+//! With the `serde` feature enabled, findings serialize to objects with the
+//! id of the rule in a `"rule"` field, and the path and message in `"path"`
+//! and `"message"` fields:
+//!
+//! ```json
+//! { "rule": "image-alt", "path": [0], "message": "`<img>` has no `alt` attribute" }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::aria;
+use crate::attributes::InputType;
+use crate::content::{Heading1, Heading2, Heading3, Heading4, Heading5, Heading6};
+use crate::forms::{
+    self, Button, Fieldset, Input, Label, Legend, Meter, Progress, Select, TextArea,
+};
+use crate::generated::aria::{implicit_roles, NAME_FROM_CONTENTS};
+use crate::inline_text::Anchor;
+use crate::media::{Image, ImageMapArea};
+use crate::tables::{Caption, Table};
+use crate::text_content::{Figure, FigureCaption};
+use crate::visit::{NodeRef, Visit};
+use crate::AttributeMap;
+
+/// The roles of landmarks, which assistive technology lets users jump
+/// between.
+const LANDMARK_ROLES: &[&str] = &[
+    "banner",
+    "complementary",
+    "contentinfo",
+    "form",
+    "main",
+    "navigation",
+    "region",
+    "search",
+];
+
+/// A rule which trees are audited against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rule {
+    /// Images need an `alt` attribute, which is empty if they're decorative.
+    ImageAlt,
+    /// Form controls need a label, either from a `<label>` or from ARIA.
+    Label,
+    /// Headings shouldn't skip levels when going deeper, e.g. from `<h2>` to
+    /// `<h4>`.
+    HeadingOrder,
+    /// Landmarks which occur more than once need labels to tell them apart.
+    LandmarkUnique,
+    /// Links need text.
+    LinkName,
+    /// Buttons need text.
+    ButtonName,
+    /// `aria-labelledby` needs to refer to elements in the tree.
+    AriaLabelledBy,
+    /// ARIA attributes need to be supported by the role of their element.
+    AriaAllowedAttribute,
+    /// A positive `tabindex` moves an element ahead of the rest of the page
+    /// in the focus order.
+    Tabindex,
+}
+
+impl Rule {
+    /// The id of the rule, e.g. `image-alt`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::ImageAlt => "image-alt",
+            Self::Label => "label",
+            Self::HeadingOrder => "heading-order",
+            Self::LandmarkUnique => "landmark-unique",
+            Self::LinkName => "link-name",
+            Self::ButtonName => "button-name",
+            Self::AriaLabelledBy => "aria-labelledby",
+            Self::AriaAllowedAttribute => "aria-allowed-attr",
+            Self::Tabindex => "tabindex",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// A problem found in a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The rule which was broken.
+    pub rule: Rule,
+    /// The indices of the children which lead from the root to the element
+    /// which broke the rule.
+    pub path: Vec<usize>,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {:?}: {}", self.rule, self.path, self.message)
+    }
+}
+
+/// Audit a tree, and return the problems found in it in tree order.
+pub fn audit(tree: &dyn Visit) -> Vec<Finding> {
+    let mut auditor = Auditor {
+        index: Index::new(tree),
+        ancestors: vec![],
+        path: vec![],
+        heading_level: None,
+        landmarks: BTreeMap::new(),
+        findings: vec![],
+    };
+    auditor.element(tree);
+
+    let Auditor {
+        mut findings,
+        landmarks,
+        ..
+    } = auditor;
+    for (role, landmarks) in landmarks {
+        if landmarks.len() < 2 {
+            continue;
+        }
+        for (path, labelled) in &landmarks {
+            if !labelled {
+                findings.push(Finding {
+                    rule: Rule::LandmarkUnique,
+                    path: path.clone(),
+                    message: format!(
+                        "there are {} `{role}` landmarks, and this one has no label",
+                        landmarks.len()
+                    ),
+                });
+            }
+        }
+    }
+    // The sort is stable, so the findings for an element stay in the order
+    // they were found in.
+    findings.sort_by(|a, b| a.path.cmp(&b.path));
+    findings
+}
+
+/// The accessible name of the element at `path` in a tree, with its
+/// whitespace collapsed. The name is empty if the element doesn't have one.
+///
+/// Returns `None` if there's no element at `path`.
+pub fn accessible_name(tree: &dyn Visit, path: &[usize]) -> Option<String> {
+    let elements = elements_on_path(tree, path)?;
+    let (element, ancestors) = elements.split_last()?;
+    let name = Index::new(tree).name(*element, ancestors, Traversal::default());
+    Some(collapse_whitespace(&name))
+}
+
+/// The accessible description of the element at `path` in a tree, with its
+/// whitespace collapsed. The description is empty if the element doesn't
+/// have one.
+///
+/// The description comes from `aria-describedby`, `aria-description`, or
+/// else from `title` if it isn't the name of the element already. Returns
+/// `None` if there's no element at `path`.
+pub fn accessible_description(tree: &dyn Visit, path: &[usize]) -> Option<String> {
+    let elements = elements_on_path(tree, path)?;
+    let (element, ancestors) = elements.split_last()?;
+    let index = Index::new(tree);
+    let attributes = element.attributes();
+    let description = index
+        .referenced_name(*element, "aria-describedby")
+        .or_else(|| non_empty(&attributes, "aria-description"))
+        .or_else(|| {
+            let title = non_empty(&attributes, "title")?;
+            let name = index.name(*element, ancestors, Traversal::default());
+            (collapse_whitespace(&name) != collapse_whitespace(&title)).then_some(title)
+        })
+        .unwrap_or_default();
+    Some(collapse_whitespace(&description))
+}
+
+/// The role the element at `path` in a tree has when it has no `role`
+/// attribute, which can depend on its attributes and ancestors.
+///
+/// Returns `None` if the element has no implicit role, or if there's no
+/// element at `path`.
+pub fn implicit_role(tree: &dyn Visit, path: &[usize]) -> Option<&'static str> {
+    let elements = elements_on_path(tree, path)?;
+    let (element, ancestors) = elements.split_last()?;
+    implicit_role_of(*element, ancestors)
+}
+
+/// The elements of a tree which other elements refer to.
+struct Index<'a> {
+    /// The elements with an id. The first element with an id wins.
+    ids: HashMap<String, &'a dyn Visit>,
+    /// The `<label>`s with a `for` attribute and the id they point at, in
+    /// tree order.
+    labels: Vec<(&'a str, &'a dyn Visit)>,
+}
+
+/// How an element was reached while computing a name.
+#[derive(Debug, Clone, Copy, Default)]
+struct Traversal {
+    /// The element is referred to by `aria-labelledby`, `aria-describedby` or
+    /// a `<label>`, so it's used even if it's hidden.
+    referenced: bool,
+    /// The element is part of an `aria-labelledby` or `aria-describedby`
+    /// traversal, which doesn't follow `aria-labelledby` again.
+    labelled_by: bool,
+    /// The element is part of the name of another element.
+    recursion: bool,
+}
+
+impl<'a> Index<'a> {
+    fn new(tree: &'a dyn Visit) -> Self {
+        let mut index = Self {
+            ids: HashMap::new(),
+            labels: vec![],
+        };
+        index.collect(tree);
+        index
+    }
+
+    fn collect(&mut self, element: &'a dyn Visit) {
+        if let Some(id) = element.attributes().get("id") {
+            self.ids.entry(id.to_string()).or_insert(element);
+        }
+        if let Some(target) = element.downcast_ref::<Label>().and_then(Label::for_) {
+            self.labels.push((target, element));
+        }
+        for child in children(element) {
+            self.collect(child);
+        }
+    }
+
+    /// Compute the name of an element, without collapsing its whitespace.
+    fn name(
+        &self,
+        element: &'a dyn Visit,
+        ancestors: &[&'a dyn Visit],
+        traversal: Traversal,
+    ) -> String {
+        if is_hidden(element) && !traversal.referenced {
+            return String::new();
+        }
+        if !traversal.labelled_by {
+            if let Some(name) = self.referenced_name(element, "aria-labelledby") {
+                return name;
+            }
+        }
+        // Controls inside a label contribute their value to it.
+        if traversal.recursion {
+            if let Some(value) = control_value(element) {
+                return value;
+            }
+        }
+        let attributes = element.attributes();
+        if let Some(label) = non_empty(&attributes, "aria-label") {
+            return label;
+        }
+
+        let role = aria::role(element).or_else(|| implicit_role_of(element, ancestors));
+        if !matches!(role, Some("none" | "presentation")) {
+            if let Some(name) = self.native_name(element, ancestors, traversal) {
+                return name;
+            }
+        }
+        let from_contents = role.is_some_and(|role| NAME_FROM_CONTENTS.contains(&role));
+        if from_contents || traversal.recursion || traversal.referenced {
+            let name = self.contents(element, ancestors, traversal);
+            if !name.trim().is_empty() {
+                return name;
+            }
+        }
+
+        if let Some(title) = non_empty(&attributes, "title") {
+            return title;
+        }
+        if element.is::<Input>() || element.is::<TextArea>() {
+            if let Some(placeholder) = non_empty(&attributes, "placeholder") {
+                return placeholder;
+            }
+        }
+        String::new()
+    }
+
+    /// The names of the elements an `aria-labelledby` or `aria-describedby`
+    /// attribute refers to, joined by spaces.
+    ///
+    /// Returns `None` if the attribute doesn't refer to any elements, or
+    /// their names are empty.
+    fn referenced_name(&self, element: &dyn Visit, attribute: &str) -> Option<String> {
+        let ids = element.attributes().get(attribute)?.to_string();
+        let traversal = Traversal {
+            referenced: true,
+            labelled_by: true,
+            recursion: true,
+        };
+        let name = ids
+            .split_ascii_whitespace()
+            .filter_map(|id| self.ids.get(id))
+            .map(|referenced| self.name(*referenced, &[], traversal))
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!name.trim().is_empty()).then_some(name)
+    }
+
+    /// The name HTML gives an element, e.g. from its `alt` attribute or its
+    /// `<label>`s.
+    fn native_name(
+        &self,
+        element: &'a dyn Visit,
+        ancestors: &[&'a dyn Visit],
+        traversal: Traversal,
+    ) -> Option<String> {
+        let attributes = element.attributes();
+        if let Some(input) = element.downcast_ref::<Input>() {
+            // Buttons are named by their value, or else by their type.
+            let value = non_empty(&attributes, "value");
+            match input.type_() {
+                Some(InputType::Button) => return value,
+                Some(InputType::Submit) => return value.or(Some("Submit".to_owned())),
+                Some(InputType::Reset) => return value.or(Some("Reset".to_owned())),
+                Some(InputType::Image) => {
+                    return non_empty(&attributes, "alt")
+                        .or(value)
+                        .or_else(|| non_empty(&attributes, "title"))
+                        .or(Some("Submit".to_owned()))
+                }
+                _ => {}
+            }
+        }
+        // The labels of a control inside a label aren't part of the label's
+        // name.
+        if is_labelable(element) && !traversal.recursion {
+            return self.label_name(element, ancestors);
+        }
+        if element.is::<Image>() || element.is::<ImageMapArea>() {
+            return non_empty(&attributes, "alt");
+        }
+
+        let caption = if element.is::<Fieldset>() {
+            children(element).find(|child| child.is::<Legend>())
+        } else if element.is::<Table>() {
+            children(element).find(|child| child.is::<Caption>())
+        } else if element.is::<Figure>() {
+            children(element).find(|child| child.is::<FigureCaption>())
+        } else {
+            None
+        };
+        let traversal = Traversal {
+            referenced: true,
+            recursion: true,
+            ..traversal
+        };
+        let name = self.name(caption?, &[], traversal);
+        (!name.trim().is_empty()).then_some(name)
+    }
+
+    /// The names of the `<label>`s of a control, joined by spaces.
+    fn label_name(&self, element: &dyn Visit, ancestors: &[&'a dyn Visit]) -> Option<String> {
+        let id = element.attributes().get("id").map(|id| id.to_string());
+        let mut labels: Vec<&'a dyn Visit> = self
+            .labels
+            .iter()
+            .filter(|(target, _)| Some(*target) == id.as_deref())
+            .map(|(_, label)| *label)
+            .collect();
+        // A `<label>` without a `for` attribute labels the control inside it.
+        let ancestor = ancestors
+            .iter()
+            .rev()
+            .find(|ancestor| ancestor.is::<Label>());
+        if let Some(label) = ancestor.filter(|label| !label.attributes().contains_key("for")) {
+            labels.push(*label);
+        }
+
+        let traversal = Traversal {
+            referenced: true,
+            labelled_by: false,
+            recursion: true,
+        };
+        let name = labels
+            .into_iter()
+            .map(|label| self.name(label, &[], traversal))
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!name.trim().is_empty()).then_some(name)
+    }
+
+    /// The names of the children of an element, joined together.
+    fn contents(
+        &self,
+        element: &'a dyn Visit,
+        ancestors: &[&'a dyn Visit],
+        traversal: Traversal,
+    ) -> String {
+        let mut ancestors = ancestors.to_vec();
+        ancestors.push(element);
+        // The hidden children of a hidden element which is referred to are
+        // used too.
+        let traversal = Traversal {
+            referenced: traversal.referenced && is_hidden(element),
+            recursion: true,
+            ..traversal
+        };
+
+        let mut name = String::new();
+        let mut index = 0;
+        while let Some(child) = element.child(index) {
+            match child {
+                NodeRef::Text(text) => name.push_str(text),
+                NodeRef::Element(child) => name.push_str(&self.name(child, &ancestors, traversal)),
+                NodeRef::Comment(_) | NodeRef::RawHtml(_) => {}
+            }
+            index += 1;
+        }
+        name
+    }
+}
+
+struct Auditor<'a> {
+    index: Index<'a>,
+    ancestors: Vec<&'a dyn Visit>,
+    path: Vec<usize>,
+    heading_level: Option<u8>,
+    /// The paths of the landmarks with each role, and whether they're
+    /// labelled.
+    landmarks: BTreeMap<&'static str, Vec<(Vec<usize>, bool)>>,
+    findings: Vec<Finding>,
+}
+
+impl<'a> Auditor<'a> {
+    fn element(&mut self, element: &'a dyn Visit) {
+        self.check(element);
+
+        self.ancestors.push(element);
+        let mut index = 0;
+        while let Some(child) = element.child(index) {
+            if let NodeRef::Element(child) = child {
+                self.path.push(index);
+                self.element(child);
+                self.path.pop();
+            }
+            index += 1;
+        }
+        self.ancestors.pop();
+    }
+
+    fn report(&mut self, rule: Rule, message: String) {
+        self.findings.push(Finding {
+            rule,
+            path: self.path.clone(),
+            message,
+        });
+    }
+
+    /// Whether an element has a name which isn't just whitespace.
+    fn is_named(&self, element: &'a dyn Visit) -> bool {
+        let name = self
+            .index
+            .name(element, &self.ancestors, Traversal::default());
+        !name.trim().is_empty()
+    }
+
+    fn check(&mut self, element: &'a dyn Visit) {
+        let tag_name = element.tag_name();
+        let attributes = element.attributes();
+        let role = aria::role(element);
+
+        if let Some(image) = element.downcast_ref::<Image>() {
+            let presentational = matches!(role, Some("none" | "presentation"));
+            if image.alt().is_none() && !has_aria_label(element) && !presentational {
+                self.report(Rule::ImageAlt, "`<img>` has no `alt` attribute".to_owned());
+            }
+        }
+
+        if needs_label(element) && !self.is_named(element) {
+            self.report(Rule::Label, format!("`<{tag_name}>` has no label"));
+        }
+
+        if let Some(level) = heading_level(element) {
+            if let Some(previous) = self.heading_level {
+                if level > previous + 1 {
+                    self.report(
+                        Rule::HeadingOrder,
+                        format!("`<h{level}>` follows `<h{previous}>`, skipping a level"),
+                    );
+                }
+            }
+            self.heading_level = Some(level);
+        }
+
+        if let Some(landmark) = self.landmark_role(element, role) {
+            let labelled = has_label_attribute(element);
+            let entry = self.landmarks.entry(landmark).or_default();
+            entry.push((self.path.clone(), labelled));
+        }
+
+        let is_link = element
+            .downcast_ref::<Anchor>()
+            .is_some_and(|a| a.href().is_some());
+        if is_link && !self.is_named(element) {
+            self.report(Rule::LinkName, "`<a>` has no text".to_owned());
+        }
+        if element.is::<Button>() && !self.is_named(element) {
+            self.report(Rule::ButtonName, "`<button>` has no text".to_owned());
+        }
+
+        if let Some(ids) = attributes.get("aria-labelledby") {
+            for id in ids.split_ascii_whitespace() {
+                if !self.index.ids.contains_key(id) {
+                    self.report(
+                        Rule::AriaLabelledBy,
+                        format!("`aria-labelledby` refers to the missing id `{id}`"),
+                    );
+                }
+            }
+        }
+
+        for unsupported in aria::check(element) {
+            self.report(Rule::AriaAllowedAttribute, unsupported.to_string());
+        }
+
+        let tab_index = attributes
+            .get("tabindex")
+            .and_then(|value| value.trim().parse::<i64>().ok());
+        if let Some(tab_index) = tab_index.filter(|tab_index| *tab_index > 0) {
+            self.report(
+                Rule::Tabindex,
+                format!("`tabindex=\"{tab_index}\"` changes the focus order"),
+            );
+        }
+    }
+
+    /// The landmark role of an element, if it has one.
+    fn landmark_role(
+        &self,
+        element: &dyn Visit,
+        role: Option<&'static str>,
+    ) -> Option<&'static str> {
+        let role = role.or_else(|| implicit_role_of(element, &self.ancestors))?;
+        match role {
+            // Forms and regions are only landmarks if they're labelled.
+            "form" | "region" if !has_label_attribute(element) => None,
+            role => LANDMARK_ROLES.contains(&role).then_some(role),
+        }
+    }
+}
+
+/// The implicit role of an element, given its ancestors.
+fn implicit_role_of(element: &dyn Visit, ancestors: &[&dyn Visit]) -> Option<&'static str> {
+    let attributes = element.attributes();
+    // Some elements only apply to the whole page when they aren't part of a
+    // section of it.
+    let scoped = |sections: &[&str]| {
+        ancestors
+            .iter()
+            .any(|ancestor| sections.contains(&ancestor.tag_name()))
+    };
+    let role = match element.tag_name() {
+        "a" | "area" if attributes.contains_key("href") => "link",
+        "a" | "area" => "generic",
+        "img" if attributes.get("alt").is_some_and(|alt| alt.is_empty()) => "presentation",
+        "img" => "img",
+        "header" | "footer" if scoped(&["article", "aside", "main", "nav", "section"]) => "generic",
+        "header" => "banner",
+        "footer" => "contentinfo",
+        "aside" if scoped(&["article", "aside", "nav", "section"]) => {
+            match has_label_attribute(element) {
+                true => "complementary",
+                false => "generic",
+            }
+        }
+        "section" => match has_label_attribute(element) {
+            true => "region",
+            false => "generic",
+        },
+        "input" => return element.downcast_ref::<Input>().and_then(input_role),
+        "select" => {
+            let select = element.downcast_ref::<Select>()?;
+            match select.multiple() || select.size().is_some_and(|size| size > 1) {
+                true => "listbox",
+                false => "combobox",
+            }
+        }
+        "li" => match ancestors.last().map(|parent| parent.tag_name()) {
+            Some("menu" | "ol" | "ul") => "listitem",
+            _ => "generic",
+        },
+        "td" => {
+            let table = ancestors
+                .iter()
+                .rev()
+                .find(|ancestor| ancestor.is::<Table>());
+            match table.and_then(|table| aria::role(*table)) {
+                Some("grid" | "treegrid") => "gridcell",
+                _ => "cell",
+            }
+        }
+        "th" => match attributes
+            .get("scope")
+            .map(|scope| scope.to_ascii_lowercase())
+        {
+            Some(scope) if scope == "row" || scope == "rowgroup" => "rowheader",
+            _ => "columnheader",
+        },
+        tag_name => return implicit_roles(tag_name).first().copied(),
+    };
+    Some(role)
+}
+
+/// The implicit role of an `<input>`, which depends on its type.
+fn input_role(input: &Input) -> Option<&'static str> {
+    let list = input.list().is_some();
+    let role = match input.type_() {
+        Some(InputType::Button | InputType::Image | InputType::Reset | InputType::Submit) => {
+            "button"
+        }
+        Some(InputType::Checkbox) => "checkbox",
+        Some(InputType::Radio) => "radio",
+        Some(InputType::Range) => "slider",
+        Some(InputType::Number) => "spinbutton",
+        Some(InputType::Search) if !list => "searchbox",
+        None
+        | Some(
+            InputType::Text
+            | InputType::Email
+            | InputType::Search
+            | InputType::Tel
+            | InputType::Url,
+        ) => match list {
+            true => "combobox",
+            false => "textbox",
+        },
+        _ => return None,
+    };
+    Some(role)
+}
+
+/// The value of a form control, which is part of the name of a label it's
+/// in.
+///
+/// Returns `None` if the element isn't a form control.
+fn control_value(element: &dyn Visit) -> Option<String> {
+    let attributes = element.attributes();
+    let range_value = || {
+        non_empty(&attributes, "aria-valuetext")
+            .or_else(|| non_empty(&attributes, "aria-valuenow"))
+            .or_else(|| non_empty(&attributes, "value"))
+    };
+    match aria::role(element) {
+        Some("textbox") => return Some(text(element)),
+        Some("meter" | "progressbar" | "scrollbar" | "slider" | "spinbutton") => {
+            return Some(range_value().unwrap_or_default())
+        }
+        Some(_) => return None,
+        None => {}
+    }
+
+    if let Some(input) = element.downcast_ref::<Input>() {
+        return match input_role(input) {
+            Some("combobox" | "searchbox" | "textbox") => {
+                Some(non_empty(&attributes, "value").unwrap_or_default())
+            }
+            Some("slider" | "spinbutton") => Some(range_value().unwrap_or_default()),
+            _ => None,
+        };
+    }
+    if element.is::<Meter>() || element.is::<Progress>() {
+        return Some(range_value().unwrap_or_default());
+    }
+    if element.is::<TextArea>() {
+        return Some(text(element));
+    }
+    let select = element.downcast_ref::<Select>()?;
+    let mut options = vec![];
+    collect_options(element, &mut options);
+    let selected: Vec<_> = options.iter().filter(|option| option.selected()).collect();
+    let selected = match selected.is_empty() && !select.multiple() {
+        true => options.first().into_iter().collect(),
+        false => selected,
+    };
+    let value = selected
+        .into_iter()
+        .map(|option| match option.label() {
+            Some(label) => label.to_owned(),
+            None => text(*option),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(value)
+}
+
+/// Collect the `<option>`s in an element, including those in groups.
+fn collect_options<'a>(element: &'a dyn Visit, options: &mut Vec<&'a forms::Option>) {
+    for child in children(element) {
+        match child.downcast_ref::<forms::Option>() {
+            Some(option) => options.push(option),
+            None => collect_options(child, options),
+        }
+    }
+}
+
+/// The elements on the path to an element, from the root of the tree up to
+/// and including the element itself.
+///
+/// Returns `None` if the path doesn't lead to an element.
+fn elements_on_path<'a>(tree: &'a dyn Visit, path: &[usize]) -> Option<Vec<&'a dyn Visit>> {
+    let mut elements = vec![tree];
+    for &index in path {
+        let parent: &'a dyn Visit = *elements.last()?;
+        elements.push(parent.child(index)?.as_element()?);
+    }
+    Some(elements)
+}
+
+/// The element children of an element.
+fn children(element: &dyn Visit) -> impl Iterator<Item = &dyn Visit> {
+    (0..)
+        .map_while(move |index| element.child(index))
+        .filter_map(NodeRef::as_element)
+}
+
+/// The text in an element and its descendants.
+fn text(element: &dyn Visit) -> String {
+    let mut contents = String::new();
+    let mut index = 0;
+    while let Some(child) = element.child(index) {
+        match child {
+            NodeRef::Text(text) => contents.push_str(text),
+            NodeRef::Element(child) => contents.push_str(&text(child)),
+            NodeRef::Comment(_) | NodeRef::RawHtml(_) => {}
+        }
+        index += 1;
+    }
+    contents
+}
+
+/// The value of an attribute, unless it's missing or only whitespace.
+fn non_empty(attributes: &AttributeMap, name: &str) -> Option<String> {
+    attributes
+        .get(name)
+        .filter(|value| !value.trim().is_empty())
+        .map(|value| value.to_string())
+}
+
+/// Replace runs of whitespace with single spaces, and trim the ends.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether an element is hidden by the `hidden` or `aria-hidden` attributes.
+fn is_hidden(element: &dyn Visit) -> bool {
+    let attributes = element.attributes();
+    attributes.contains_key("hidden")
+        || attributes
+            .get("aria-hidden")
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+/// Whether an element is labelled with `aria-label` or `aria-labelledby`.
+fn has_aria_label(element: &dyn Visit) -> bool {
+    let attributes = element.attributes();
+    non_empty(&attributes, "aria-label").is_some()
+        || non_empty(&attributes, "aria-labelledby").is_some()
+}
+
+/// Whether an element is labelled with `aria-label`, `aria-labelledby` or
+/// `title`.
+fn has_label_attribute(element: &dyn Visit) -> bool {
+    has_aria_label(element) || non_empty(&element.attributes(), "title").is_some()
+}
+
+/// Whether an element can be labelled by a `<label>`.
+fn is_labelable(element: &dyn Visit) -> bool {
+    if let Some(input) = element.downcast_ref::<Input>() {
+        return input.type_() != Some(&InputType::Hidden);
+    }
+    element.is::<Button>()
+        || element.is::<Meter>()
+        || element.is::<forms::Output>()
+        || element.is::<Progress>()
+        || element.is::<Select>()
+        || element.is::<TextArea>()
+}
+
+/// Whether an element is a form control which needs a label.
+fn needs_label(element: &dyn Visit) -> bool {
+    if let Some(input) = element.downcast_ref::<Input>() {
+        // Buttons are labelled by their value, and hidden inputs can't be
+        // labelled at all.
+        return !matches!(
+            input.type_(),
+            Some(
+                InputType::Hidden
+                    | InputType::Button
+                    | InputType::Submit
+                    | InputType::Reset
+                    | InputType::Image
+            )
+        );
+    }
+    element.is::<Select>() || element.is::<TextArea>()
+}
+
+/// The level of a heading element.
+fn heading_level(element: &dyn Visit) -> Option<u8> {
+    let element = element.as_any();
+    if element.is::<Heading1>() {
+        Some(1)
+    } else if element.is::<Heading2>() {
+        Some(2)
+    } else if element.is::<Heading3>() {
+        Some(3)
+    } else if element.is::<Heading4>() {
+        Some(4)
+    } else if element.is::<Heading5>() {
+        Some(5)
+    } else if element.is::<Heading6>() {
+        Some(6)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Finding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("rule", self.rule.id())?;
+        map.serialize_entry("path", &self.path)?;
+        map.serialize_entry("message", &self.message)?;
+        map.end()
+    }
+}
@@ -0,0 +1,88 @@
+//! Working with elements whose type isn't known up front.
+
+use std::fmt;
+
+use crate::visit::Visit;
+use crate::AnyElement;
+
+impl AnyElement {
+    /// Get the tag name of the element.
+    pub fn tag_name(&self) -> &'static str {
+        self.as_visit().tag_name()
+    }
+
+    /// Returns `true` if the element is of type `T`.
+    pub fn is<T: Visit>(&self) -> bool {
+        self.as_visit().is::<T>()
+    }
+
+    /// Access the element as an element of type `T`.
+    pub fn as_ref<T: Visit>(&self) -> Option<&T> {
+        self.as_visit().downcast_ref()
+    }
+
+    /// Mutably access the element as an element of type `T`.
+    pub fn as_mut<T: Visit>(&mut self) -> Option<&mut T> {
+        self.as_visit_mut().downcast_mut()
+    }
+
+    /// Convert the element into an element of type `T`. If the element is of
+    /// another type it's returned as an error.
+    #[allow(clippy::result_large_err)]
+    pub fn into<T: Visit>(self) -> Result<T, Self> {
+        match self.is::<T>() {
+            true => Ok(*self
+                .into_any()
+                .downcast()
+                .expect("the type of the element was checked")),
+            false => Err(self),
+        }
+    }
+}
+
+/// An element which isn't permitted inside of another element.
+///
+/// This `struct` is returned when converting an [`AnyElement`] into the child
+/// enum of an element fails.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotPermitted {
+    element: Box<AnyElement>,
+    parent: &'static str,
+}
+
+impl NotPermitted {
+    pub(crate) fn new(element: AnyElement, parent: &'static str) -> Self {
+        Self {
+            element: Box::new(element),
+            parent,
+        }
+    }
+
+    /// Access the element which isn't permitted.
+    pub fn element(&self) -> &AnyElement {
+        &self.element
+    }
+
+    /// Get back the element which isn't permitted.
+    pub fn into_element(self) -> AnyElement {
+        *self.element
+    }
+
+    /// The tag name of the element it isn't permitted in.
+    pub fn parent(&self) -> &'static str {
+        self.parent
+    }
+}
+
+impl fmt::Display for NotPermitted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`<{}>` is not permitted inside `<{}>`",
+            self.element.tag_name(),
+            self.parent
+        )
+    }
+}
+
+impl std::error::Error for NotPermitted {}
@@ -0,0 +1,136 @@
+//! Check ARIA attributes against the roles of elements.
+//!
+//! Elements only have the ARIA attributes which [ARIA in HTML] permits on
+//! them. Which of those are supported depends on the role the element ends up
+//! with though, e.g. `aria-checked` needs a checkable role such as `checkbox`.
+//! Roles can change after an element is built, so [`check`] compares the ARIA
+//! attributes of an element with its current role: the one set by its `role`
+//! attribute, or else its implicit role.
+//!
+//! [ARIA in HTML]: https://www.w3.org/TR/html-aria/
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #![recursion_limit = "512"]
+//! use html::aria;
+//! use html::attributes::{Role, Tristate};
+//! use html::text_content::Division;
+//!
+//! let mut div = Division::builder()
+//!     .role(Role::Checkbox)
+//!     .aria_checked(Tristate::True)
+//!     .build();
+//! assert!(aria::check(&div).is_empty());
+//!
+//! div.set_role(Some(Role::Button));
+//! let unsupported = aria::check(&div);
+//! assert_eq!(
+//!     unsupported[0].to_string(),
+//!     "the `button` role of `<div>` doesn't support `aria-checked`"
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::generated::aria::{implicit_roles, GLOBAL_PROPERTIES, ROLES};
+use crate::HtmlElement;
+
+/// The role set by the `role` attribute of an element.
+///
+/// Returns `None` if the element has no `role` attribute, or if its role
+/// isn't a concrete ARIA role.
+pub fn role(element: &dyn HtmlElement) -> Option<&'static str> {
+    let attributes = element.attributes();
+    let value = attributes.get("role")?;
+    find_role(value).map(|(name, _)| name)
+}
+
+/// The properties supported by a role, or `None` if it isn't a concrete ARIA
+/// role.
+pub fn supported_properties(role: &str) -> Option<&'static [&'static str]> {
+    find_role(role).map(|(_, properties)| properties)
+}
+
+/// Find the ARIA attributes of an element which aren't supported by its role.
+///
+/// Elements without a `role` attribute can have one of several implicit
+/// roles, in which case an attribute only needs to be supported by one of
+/// them. Elements without any role only support the global properties.
+pub fn check(element: &dyn HtmlElement) -> Vec<UnsupportedAttribute> {
+    let tag_name = element.tag_name();
+    let roles = match role(element) {
+        Some(role) => vec![role],
+        None => implicit_roles(tag_name).to_vec(),
+    };
+    let supports = |attribute: &str| match roles.is_empty() {
+        true => GLOBAL_PROPERTIES.contains(&attribute),
+        false => roles.iter().any(|role| {
+            supported_properties(role).is_some_and(|properties| properties.contains(&attribute))
+        }),
+    };
+
+    element
+        .attributes()
+        .iter()
+        .filter(|(name, _)| name.starts_with("aria-") && !supports(name))
+        .map(|(name, _)| UnsupportedAttribute {
+            element: tag_name,
+            attribute: name.to_owned(),
+            role: match roles[..] {
+                [role] => Some(role),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+fn find_role(role: &str) -> Option<(&'static str, &'static [&'static str])> {
+    let role = role.to_ascii_lowercase();
+    ROLES
+        .binary_search_by(|(name, _)| (*name).cmp(&role))
+        .ok()
+        .map(|index| ROLES[index])
+}
+
+/// An ARIA attribute which isn't supported by the role of its element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedAttribute {
+    element: &'static str,
+    attribute: String,
+    role: Option<&'static str>,
+}
+
+impl UnsupportedAttribute {
+    /// The tag name of the element.
+    pub fn element(&self) -> &'static str {
+        self.element
+    }
+
+    /// The name of the attribute, e.g. `aria-checked`.
+    pub fn attribute(&self) -> &str {
+        &self.attribute
+    }
+
+    /// The role of the element, if it has exactly one.
+    pub fn role(&self) -> Option<&'static str> {
+        self.role
+    }
+}
+
+impl fmt::Display for UnsupportedAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.role {
+            Some(role) => write!(
+                f,
+                "the `{role}` role of `<{}>` doesn't support `{}`",
+                self.element, self.attribute
+            ),
+            None => write!(
+                f,
+                "`<{}>` has no role which supports `{}`",
+                self.element, self.attribute
+            ),
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Compute the changes between two trees as a list of patches.
+//!
+//! [`diff`] compares two trees of the same type, and returns the patches which
+//! turn the old tree into the new one. The patches can be applied to a tree
+//! with [`apply`], or be serialized and sent to a browser, which applies them
+//! to the DOM.
+//!
+//! Patches are applied in order. Every patch addresses an element by its
+//! [path](Patch::path), which holds the indices of the children which lead to
+//! the element from the root. Indices count all children, including text and
+//! comments, and refer to the tree as it is when the patch is applied.
+//!
+//! Children are matched by position, except for elements with a `data-key`
+//! attribute. Those are matched by key, so that reordering a list moves its
+//! items rather than rewriting all of them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #![recursion_limit = "512"]
+//! use html::diff::{apply, diff, Op};
+//! use html::text_content::UnorderedList;
+//!
+//! let old = UnorderedList::builder()
+//!     .list_item(|li| li.text("nori"))
+//!     .build();
+//! let new = UnorderedList::builder()
+//!     .list_item(|li| li.class("cat").text("nori"))
+//!     .list_item(|li| li.text("chashu"))
+//!     .build();
+//!
+//! let patches = diff(&old, &new);
+//! assert_eq!(patches.len(), 2);
+//! assert!(matches!(&patches[0].op, Op::SetAttribute { name, .. } if name == "class"));
+//!
+//! let mut tree = old.clone();
+//! apply(&mut tree, &patches).unwrap();
+//! assert_eq!(tree, new);
+//! ```
+//!
+//! # Serialization
+//!
+//! With the `serde` feature enabled, patches serialize to objects with the
+//! name of the operation in an `"op"` field, the path in a `"path"` field,
+//! and the fields of the operation. Nodes are serialized as HTML:
+//!
+//! ```json
+//! { "op": "insert", "path": [0, 2], "index": 1, "html": "<li>chashu</li>" }
+//! { "op": "remove", "path": [0], "index": 3 }
+//! { "op": "replace", "path": [], "index": 0, "html": "<p>hello</p>" }
+//! { "op": "set-attribute", "path": [1], "name": "class", "value": "cat" }
+//! { "op": "remove-attribute", "path": [1], "name": "hidden" }
+//! { "op": "set-text", "path": [1], "index": 0, "text": "chashu" }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::visit::{NodeMut, NodeRef, Visit};
+use crate::AttributeMap;
+
+/// A change to a tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Patch<'a> {
+    /// The indices of the children which lead from the root to the element
+    /// which is changed.
+    pub path: Vec<usize>,
+    /// The change to make to the element.
+    pub op: Op<'a>,
+}
+
+/// A change to an element.
+///
+/// Nodes which are inserted are borrowed from the tree they were found in.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op<'a> {
+    /// Insert a node as the child at `index`.
+    Insert {
+        /// The index of the new child.
+        index: usize,
+        /// The node to insert.
+        node: NodeRef<'a>,
+    },
+    /// Remove the child at `index`.
+    Remove {
+        /// The index of the child.
+        index: usize,
+    },
+    /// Replace the child at `index` with another node.
+    Replace {
+        /// The index of the child.
+        index: usize,
+        /// The node to replace the child with.
+        node: NodeRef<'a>,
+    },
+    /// Set the value of an attribute.
+    SetAttribute {
+        /// The name of the attribute.
+        name: String,
+        /// The new value of the attribute.
+        value: String,
+    },
+    /// Remove an attribute.
+    RemoveAttribute {
+        /// The name of the attribute.
+        name: String,
+    },
+    /// Change the text of the text node at `index`.
+    SetText {
+        /// The index of the text node.
+        index: usize,
+        /// The new text.
+        text: String,
+    },
+}
+
+/// Compute the patches which turn `old` into `new`.
+pub fn diff<'a, T: Visit>(old: &T, new: &'a T) -> Vec<Patch<'a>> {
+    let mut patches = vec![];
+    diff_element(old, new, &mut vec![], &mut patches);
+    patches
+}
+
+/// Apply patches to a tree.
+///
+/// Patches are applied in order, and applying stops at the first patch which
+/// doesn't fit the tree.
+pub fn apply(tree: &mut dyn Visit, patches: &[Patch<'_>]) -> Result<(), Error> {
+    for (index, patch) in patches.iter().enumerate() {
+        if !apply_patch(tree, patch) {
+            return Err(Error { patch: index });
+        }
+    }
+    Ok(())
+}
+
+/// A patch which couldn't be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    patch: usize,
+}
+
+impl Error {
+    /// The index of the patch which couldn't be applied.
+    pub fn patch(&self) -> usize {
+        self.patch
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "patch {} doesn't fit the tree", self.patch)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn apply_patch(tree: &mut dyn Visit, patch: &Patch<'_>) -> bool {
+    let mut element = tree;
+    for &index in &patch.path {
+        match element.child_mut(index) {
+            Some(NodeMut::Element(child)) => element = child,
+            _ => return false,
+        }
+    }
+    match &patch.op {
+        Op::Insert { index, node } => element.insert_child(*index, *node),
+        Op::Remove { index } => element.remove_child(*index),
+        Op::Replace { index, node } => {
+            element.remove_child(*index) && element.insert_child(*index, *node)
+        }
+        Op::SetAttribute { name, value } => element.set_attribute(name, Some(value)),
+        Op::RemoveAttribute { name } => element.set_attribute(name, None),
+        Op::SetText { index, text } => match element.child_mut(*index) {
+            Some(NodeMut::Text(old)) => {
+                *old = text.clone().into();
+                true
+            }
+            _ => false,
+        },
+    }
+}
+
+fn diff_element<'a>(
+    old: &dyn Visit,
+    new: &'a dyn Visit,
+    path: &mut Vec<usize>,
+    patches: &mut Vec<Patch<'a>>,
+) {
+    if old.eq_element(new) {
+        return;
+    }
+    let mut push = |op| {
+        patches.push(Patch {
+            path: path.clone(),
+            op,
+        })
+    };
+
+    let old_attributes = attributes(old);
+    let new_attributes = attributes(new);
+    for (name, value) in &new_attributes {
+        if old_attributes.get(name).map(|old| old.as_ref()) != Some(value) {
+            push(Op::SetAttribute {
+                name: name.to_owned(),
+                value: value.to_owned(),
+            });
+        }
+    }
+    for name in old_attributes.keys() {
+        if !new_attributes.contains_key(name) {
+            push(Op::RemoveAttribute {
+                name: name.to_owned(),
+            });
+        }
+    }
+
+    diff_children(old, new, path, patches);
+}
+
+/// Get all attributes of an element, including `data-*` attributes.
+fn attributes(element: &dyn Visit) -> AttributeMap {
+    let mut attributes = element.attributes();
+    let data = element.data().into_iter();
+    attributes.extend(data.map(|(key, value)| (format!("data-{key}"), value)));
+    attributes
+}
+
+/// Get the key which an element is matched by.
+fn key(node: NodeRef<'_>) -> Option<String> {
+    let data = node.as_element()?.data();
+    data.get("key").map(|key| key.to_string())
+}
+
+fn diff_children<'a>(
+    old: &dyn Visit,
+    new: &'a dyn Visit,
+    path: &mut Vec<usize>,
+    patches: &mut Vec<Patch<'a>>,
+) {
+    let old_children: Vec<_> = (0..).map_while(|index| old.child(index)).collect();
+    let new_children: Vec<_> = (0..).map_while(|index| new.child(index)).collect();
+    let old_keys: Vec<_> = old_children.iter().map(|child| key(*child)).collect();
+    let new_keys: Vec<_> = new_children.iter().map(|child| key(*child)).collect();
+
+    // For every new child, the index of the old child it's matched with.
+    // Keyed children are matched by key, and other children by position.
+    let mut matches = vec![None; new_children.len()];
+    let mut keyed = HashMap::new();
+    for (index, key) in old_keys.iter().enumerate() {
+        if let Some(key) = key {
+            keyed.entry(key.as_str()).or_insert(index);
+        }
+    }
+    for (index, key) in new_keys.iter().enumerate() {
+        if let Some(old) = key.as_deref().and_then(|key| keyed.remove(key)) {
+            matches[index] = Some(old);
+        }
+    }
+    let old_unkeyed = (0..old_children.len()).filter(|&index| old_keys[index].is_none());
+    let new_unkeyed = (0..new_children.len()).filter(|&index| new_keys[index].is_none());
+    for (old, new) in old_unkeyed.zip(new_unkeyed) {
+        matches[new] = Some(old);
+    }
+
+    // Matched children which keep their order stay where they are. All other
+    // old children are removed, and the new children are inserted in their
+    // place.
+    let kept = longest_increasing(&matches);
+    let mut is_kept = vec![false; old_children.len()];
+    for (index, old) in matches.iter_mut().enumerate() {
+        match kept[index] {
+            true => is_kept[old.unwrap()] = true,
+            false => *old = None,
+        }
+    }
+    for index in (0..old_children.len()).rev() {
+        if !is_kept[index] {
+            patches.push(Patch {
+                path: path.clone(),
+                op: Op::Remove { index },
+            });
+        }
+    }
+
+    for (index, node) in new_children.into_iter().enumerate() {
+        let op = match matches[index].map(|old| old_children[old]) {
+            None => Op::Insert { index, node },
+            Some(old) => match (old, node) {
+                (NodeRef::Element(old), NodeRef::Element(new))
+                    if old.as_any().type_id() == new.as_any().type_id() =>
+                {
+                    path.push(index);
+                    diff_element(old, new, path, patches);
+                    path.pop();
+                    continue;
+                }
+                (NodeRef::Text(old), NodeRef::Text(new)) if old != new => Op::SetText {
+                    index,
+                    text: new.to_owned(),
+                },
+                (old, new) if old == new => continue,
+                _ => Op::Replace { index, node },
+            },
+        };
+        patches.push(Patch {
+            path: path.clone(),
+            op,
+        });
+    }
+}
+
+/// Find the longest sequence of matched children whose old indices are in
+/// increasing order. Returns whether each new child is part of it.
+fn longest_increasing(matches: &[Option<usize>]) -> Vec<bool> {
+    let matched: Vec<(usize, usize)> = matches
+        .iter()
+        .enumerate()
+        .filter_map(|(new, old)| Some((new, (*old)?)))
+        .collect();
+
+    // `tails[len]` is the position in `matched` of the smallest old index
+    // which ends an increasing sequence of `len + 1` children.
+    let mut tails: Vec<usize> = vec![];
+    let mut previous = vec![None; matched.len()];
+    for (position, &(_, old)) in matched.iter().enumerate() {
+        let len = tails.partition_point(|&tail| matched[tail].1 < old);
+        previous[position] = len.checked_sub(1).map(|len| tails[len]);
+        match tails.get_mut(len) {
+            Some(tail) => *tail = position,
+            None => tails.push(position),
+        }
+    }
+
+    let mut kept = vec![false; matches.len()];
+    let mut position = tails.last().copied();
+    while let Some(current) = position {
+        kept[matched[current].0] = true;
+        position = previous[current];
+    }
+    kept
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Patch<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let (op, len) = match &self.op {
+            Op::Insert { .. } => ("insert", 4),
+            Op::Remove { .. } => ("remove", 3),
+            Op::Replace { .. } => ("replace", 4),
+            Op::SetAttribute { .. } => ("set-attribute", 4),
+            Op::RemoveAttribute { .. } => ("remove-attribute", 3),
+            Op::SetText { .. } => ("set-text", 4),
+        };
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("op", op)?;
+        map.serialize_entry("path", &self.path)?;
+        match &self.op {
+            Op::Insert { index, node } | Op::Replace { index, node } => {
+                map.serialize_entry("index", index)?;
+                map.serialize_entry("html", &Html(*node).to_string())?;
+            }
+            Op::Remove { index } => map.serialize_entry("index", index)?,
+            Op::SetAttribute { name, value } => {
+                map.serialize_entry("name", name)?;
+                map.serialize_entry("value", value)?;
+            }
+            Op::RemoveAttribute { name } => map.serialize_entry("name", name)?,
+            Op::SetText { index, text } => {
+                map.serialize_entry("index", index)?;
+                map.serialize_entry("text", text)?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// Writes a node as HTML.
+#[cfg(feature = "serde")]
+struct Html<'a>(NodeRef<'a>);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for Html<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            NodeRef::Element(element) => write!(f, "{element}"),
+            NodeRef::Text(text) => write!(f, "{}", html_sys::escape::Text(text)),
+            NodeRef::Comment(comment) => write!(f, "{comment}"),
+            NodeRef::RawHtml(html) => write!(f, "{html}"),
+        }
+    }
+}
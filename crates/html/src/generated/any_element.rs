@@ -0,0 +1,1950 @@
+/// Any element, held by value.
+///
+/// Elements can be converted into the child enums of the elements
+/// they're permitted in with `TryFrom`.
+#[derive(PartialEq, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyElement {
+    /// The `SuperScript` element
+    SuperScript(crate::generated::all::SuperScript),
+    /// The `Summary` element
+    Summary(crate::generated::all::Summary),
+    /// The `Anchor` element
+    Anchor(crate::generated::all::Anchor),
+    /// The `TableRow` element
+    TableRow(crate::generated::all::TableRow),
+    /// The `Aside` element
+    Aside(crate::generated::all::Aside),
+    /// The `Navigation` element
+    Navigation(crate::generated::all::Navigation),
+    /// The `TableBody` element
+    TableBody(crate::generated::all::TableBody),
+    /// The `Header` element
+    Header(crate::generated::all::Header),
+    /// The `Definition` element
+    Definition(crate::generated::all::Definition),
+    /// The `Embed` element
+    Embed(crate::generated::all::Embed),
+    /// The `Span` element
+    Span(crate::generated::all::Span),
+    /// The `Canvas` element
+    Canvas(crate::generated::all::Canvas),
+    /// The `Abbreviation` element
+    Abbreviation(crate::generated::all::Abbreviation),
+    /// The `Script` element
+    Script(crate::generated::all::Script),
+    /// The `UnorderedList` element
+    UnorderedList(crate::generated::all::UnorderedList),
+    /// The `Heading6` element
+    Heading6(crate::generated::all::Heading6),
+    /// The `BidirectionalIsolate` element
+    BidirectionalIsolate(crate::generated::all::BidirectionalIsolate),
+    /// The `SideComment` element
+    SideComment(crate::generated::all::SideComment),
+    /// The `TableColumnGroup` element
+    TableColumnGroup(crate::generated::all::TableColumnGroup),
+    /// The `MediaSource` element
+    MediaSource(crate::generated::all::MediaSource),
+    /// The `Template` element
+    Template(crate::generated::all::Template),
+    /// The `Search` element
+    Search(crate::generated::all::Search),
+    /// The `Footer` element
+    Footer(crate::generated::all::Footer),
+    /// The `Underline` element
+    Underline(crate::generated::all::Underline),
+    /// The `Code` element
+    Code(crate::generated::all::Code),
+    /// The `Head` element
+    Head(crate::generated::all::Head),
+    /// The `BlockQuote` element
+    BlockQuote(crate::generated::all::BlockQuote),
+    /// The `Section` element
+    Section(crate::generated::all::Section),
+    /// The `Input` element
+    Input(crate::generated::all::Input),
+    /// The `RubyAnnotation` element
+    RubyAnnotation(crate::generated::all::RubyAnnotation),
+    /// The `Main` element
+    Main(crate::generated::all::Main),
+    /// The `LineBreakOpportunity` element
+    LineBreakOpportunity(crate::generated::all::LineBreakOpportunity),
+    /// The `Meter` element
+    Meter(crate::generated::all::Meter),
+    /// The `Output` element
+    Output(crate::generated::all::Output),
+    /// The `Heading1` element
+    Heading1(crate::generated::all::Heading1),
+    /// The `Caption` element
+    Caption(crate::generated::all::Caption),
+    /// The `Fieldset` element
+    Fieldset(crate::generated::all::Fieldset),
+    /// The `Style` element
+    Style(crate::generated::all::Style),
+    /// The `BidirectionalTextOverride` element
+    BidirectionalTextOverride(crate::generated::all::BidirectionalTextOverride),
+    /// The `ImageMapArea` element
+    ImageMapArea(crate::generated::all::ImageMapArea),
+    /// The `FigureCaption` element
+    FigureCaption(crate::generated::all::FigureCaption),
+    /// The `SubScript` element
+    SubScript(crate::generated::all::SubScript),
+    /// The `SampleOutput` element
+    SampleOutput(crate::generated::all::SampleOutput),
+    /// The `StrikeThrough` element
+    StrikeThrough(crate::generated::all::StrikeThrough),
+    /// The `Slot` element
+    Slot(crate::generated::all::Slot),
+    /// The `RubyText` element
+    RubyText(crate::generated::all::RubyText),
+    /// The `OrderedList` element
+    OrderedList(crate::generated::all::OrderedList),
+    /// The `TableFoot` element
+    TableFoot(crate::generated::all::TableFoot),
+    /// The `DescriptionDetails` element
+    DescriptionDetails(crate::generated::all::DescriptionDetails),
+    /// The `ThematicBreak` element
+    ThematicBreak(crate::generated::all::ThematicBreak),
+    /// The `Italic` element
+    Italic(crate::generated::all::Italic),
+    /// The `Data` element
+    Data(crate::generated::all::Data),
+    /// The `Dialog` element
+    Dialog(crate::generated::all::Dialog),
+    /// The `TableColumn` element
+    TableColumn(crate::generated::all::TableColumn),
+    /// The `HeadingGroup` element
+    HeadingGroup(crate::generated::all::HeadingGroup),
+    /// The `Address` element
+    Address(crate::generated::all::Address),
+    /// The `Variable` element
+    Variable(crate::generated::all::Variable),
+    /// The `Object` element
+    Object(crate::generated::all::Object),
+    /// The `Division` element
+    Division(crate::generated::all::Division),
+    /// The `Heading3` element
+    Heading3(crate::generated::all::Heading3),
+    /// The `Time` element
+    Time(crate::generated::all::Time),
+    /// The `ListItem` element
+    ListItem(crate::generated::all::ListItem),
+    /// The `Emphasis` element
+    Emphasis(crate::generated::all::Emphasis),
+    /// The `Menu` element
+    Menu(crate::generated::all::Menu),
+    /// The `DeletedText` element
+    DeletedText(crate::generated::all::DeletedText),
+    /// The `Button` element
+    Button(crate::generated::all::Button),
+    /// The `Details` element
+    Details(crate::generated::all::Details),
+    /// The `Paragraph` element
+    Paragraph(crate::generated::all::Paragraph),
+    /// The `OptionGroup` element
+    OptionGroup(crate::generated::all::OptionGroup),
+    /// The `Quotation` element
+    Quotation(crate::generated::all::Quotation),
+    /// The `LineBreak` element
+    LineBreak(crate::generated::all::LineBreak),
+    /// The `Table` element
+    Table(crate::generated::all::Table),
+    /// The `Option` element
+    Option(crate::generated::all::Option),
+    /// The `NoScript` element
+    NoScript(crate::generated::all::NoScript),
+    /// The `Heading2` element
+    Heading2(crate::generated::all::Heading2),
+    /// The `Article` element
+    Article(crate::generated::all::Article),
+    /// The `Body` element
+    Body(crate::generated::all::Body),
+    /// The `TextArea` element
+    TextArea(crate::generated::all::TextArea),
+    /// The `Iframe` element
+    Iframe(crate::generated::all::Iframe),
+    /// The `Video` element
+    Video(crate::generated::all::Video),
+    /// The `Image` element
+    Image(crate::generated::all::Image),
+    /// The `Progress` element
+    Progress(crate::generated::all::Progress),
+    /// The `Title` element
+    Title(crate::generated::all::Title),
+    /// The `InsertedText` element
+    InsertedText(crate::generated::all::InsertedText),
+    /// The `Heading5` element
+    Heading5(crate::generated::all::Heading5),
+    /// The `Base` element
+    Base(crate::generated::all::Base),
+    /// The `Picture` element
+    Picture(crate::generated::all::Picture),
+    /// The `TextTrack` element
+    TextTrack(crate::generated::all::TextTrack),
+    /// The `KeyboardInput` element
+    KeyboardInput(crate::generated::all::KeyboardInput),
+    /// The `Audio` element
+    Audio(crate::generated::all::Audio),
+    /// The `Html` element
+    Html(crate::generated::all::Html),
+    /// The `DescriptionTerm` element
+    DescriptionTerm(crate::generated::all::DescriptionTerm),
+    /// The `DataList` element
+    DataList(crate::generated::all::DataList),
+    /// The `Link` element
+    Link(crate::generated::all::Link),
+    /// The `PreformattedText` element
+    PreformattedText(crate::generated::all::PreformattedText),
+    /// The `Label` element
+    Label(crate::generated::all::Label),
+    /// The `Figure` element
+    Figure(crate::generated::all::Figure),
+    /// The `Strong` element
+    Strong(crate::generated::all::Strong),
+    /// The `TableHeader` element
+    TableHeader(crate::generated::all::TableHeader),
+    /// The `Form` element
+    Form(crate::generated::all::Form),
+    /// The `TableHead` element
+    TableHead(crate::generated::all::TableHead),
+    /// The `RubyFallbackParenthesis` element
+    RubyFallbackParenthesis(crate::generated::all::RubyFallbackParenthesis),
+    /// The `ImageMap` element
+    ImageMap(crate::generated::all::ImageMap),
+    /// The `Cite` element
+    Cite(crate::generated::all::Cite),
+    /// The `MarkText` element
+    MarkText(crate::generated::all::MarkText),
+    /// The `DescriptionList` element
+    DescriptionList(crate::generated::all::DescriptionList),
+    /// The `TableCell` element
+    TableCell(crate::generated::all::TableCell),
+    /// The `Meta` element
+    Meta(crate::generated::all::Meta),
+    /// The `Bold` element
+    Bold(crate::generated::all::Bold),
+    /// The `Heading4` element
+    Heading4(crate::generated::all::Heading4),
+    /// The `Select` element
+    Select(crate::generated::all::Select),
+    /// The `Legend` element
+    Legend(crate::generated::all::Legend),
+    /// The `Annotation` element
+    Annotation(crate::generated::all::Annotation),
+    /// The `AnnotationXml` element
+    AnnotationXml(crate::generated::all::AnnotationXml),
+    /// The `Maction` element
+    Maction(crate::generated::all::Maction),
+    /// The `Math` element
+    Math(crate::generated::all::Math),
+    /// The `Merror` element
+    Merror(crate::generated::all::Merror),
+    /// The `Mfrac` element
+    Mfrac(crate::generated::all::Mfrac),
+    /// The `Mi` element
+    Mi(crate::generated::all::Mi),
+    /// The `Mmultiscripts` element
+    Mmultiscripts(crate::generated::all::Mmultiscripts),
+    /// The `Mn` element
+    Mn(crate::generated::all::Mn),
+    /// The `Mo` element
+    Mo(crate::generated::all::Mo),
+    /// The `Mover` element
+    Mover(crate::generated::all::Mover),
+    /// The `Mpadded` element
+    Mpadded(crate::generated::all::Mpadded),
+    /// The `Mphantom` element
+    Mphantom(crate::generated::all::Mphantom),
+    /// The `Mprescripts` element
+    Mprescripts(crate::generated::all::Mprescripts),
+    /// The `Mroot` element
+    Mroot(crate::generated::all::Mroot),
+    /// The `Mrow` element
+    Mrow(crate::generated::all::Mrow),
+    /// The `Ms` element
+    Ms(crate::generated::all::Ms),
+    /// The `Mspace` element
+    Mspace(crate::generated::all::Mspace),
+    /// The `Msqrt` element
+    Msqrt(crate::generated::all::Msqrt),
+    /// The `Mstyle` element
+    Mstyle(crate::generated::all::Mstyle),
+    /// The `Msub` element
+    Msub(crate::generated::all::Msub),
+    /// The `Msubsup` element
+    Msubsup(crate::generated::all::Msubsup),
+    /// The `Msup` element
+    Msup(crate::generated::all::Msup),
+    /// The `Mtable` element
+    Mtable(crate::generated::all::Mtable),
+    /// The `Mtd` element
+    Mtd(crate::generated::all::Mtd),
+    /// The `Mtext` element
+    Mtext(crate::generated::all::Mtext),
+    /// The `Mtr` element
+    Mtr(crate::generated::all::Mtr),
+    /// The `Munder` element
+    Munder(crate::generated::all::Munder),
+    /// The `Munderover` element
+    Munderover(crate::generated::all::Munderover),
+    /// The `Semantics` element
+    Semantics(crate::generated::all::Semantics),
+    /// The `SvgAnchor` element
+    SvgAnchor(crate::generated::all::SvgAnchor),
+    /// The `Circle` element
+    Circle(crate::generated::all::Circle),
+    /// The `ClipPath` element
+    ClipPath(crate::generated::all::ClipPath),
+    /// The `Defs` element
+    Defs(crate::generated::all::Defs),
+    /// The `Desc` element
+    Desc(crate::generated::all::Desc),
+    /// The `Ellipse` element
+    Ellipse(crate::generated::all::Ellipse),
+    /// The `ForeignObject` element
+    ForeignObject(crate::generated::all::ForeignObject),
+    /// The `G` element
+    G(crate::generated::all::G),
+    /// The `SvgImage` element
+    SvgImage(crate::generated::all::SvgImage),
+    /// The `Line` element
+    Line(crate::generated::all::Line),
+    /// The `LinearGradient` element
+    LinearGradient(crate::generated::all::LinearGradient),
+    /// The `Marker` element
+    Marker(crate::generated::all::Marker),
+    /// The `Mask` element
+    Mask(crate::generated::all::Mask),
+    /// The `Metadata` element
+    Metadata(crate::generated::all::Metadata),
+    /// The `Path` element
+    Path(crate::generated::all::Path),
+    /// The `Pattern` element
+    Pattern(crate::generated::all::Pattern),
+    /// The `Polygon` element
+    Polygon(crate::generated::all::Polygon),
+    /// The `Polyline` element
+    Polyline(crate::generated::all::Polyline),
+    /// The `RadialGradient` element
+    RadialGradient(crate::generated::all::RadialGradient),
+    /// The `Rect` element
+    Rect(crate::generated::all::Rect),
+    /// The `SvgScript` element
+    SvgScript(crate::generated::all::SvgScript),
+    /// The `Stop` element
+    Stop(crate::generated::all::Stop),
+    /// The `SvgStyle` element
+    SvgStyle(crate::generated::all::SvgStyle),
+    /// The `Svg` element
+    Svg(crate::generated::all::Svg),
+    /// The `Switch` element
+    Switch(crate::generated::all::Switch),
+    /// The `Symbol` element
+    Symbol(crate::generated::all::Symbol),
+    /// The `SvgText` element
+    SvgText(crate::generated::all::SvgText),
+    /// The `TextPath` element
+    TextPath(crate::generated::all::TextPath),
+    /// The `SvgTitle` element
+    SvgTitle(crate::generated::all::SvgTitle),
+    /// The `Tspan` element
+    Tspan(crate::generated::all::Tspan),
+    /// The `Use` element
+    Use(crate::generated::all::Use),
+    /// The `View` element
+    View(crate::generated::all::View),
+}
+impl AnyElement {
+    /// Access the element.
+    pub fn as_visit(&self) -> &dyn crate::visit::Visit {
+        match self {
+            Self::SuperScript(el) => el,
+            Self::Summary(el) => el,
+            Self::Anchor(el) => el,
+            Self::TableRow(el) => el,
+            Self::Aside(el) => el,
+            Self::Navigation(el) => el,
+            Self::TableBody(el) => el,
+            Self::Header(el) => el,
+            Self::Definition(el) => el,
+            Self::Embed(el) => el,
+            Self::Span(el) => el,
+            Self::Canvas(el) => el,
+            Self::Abbreviation(el) => el,
+            Self::Script(el) => el,
+            Self::UnorderedList(el) => el,
+            Self::Heading6(el) => el,
+            Self::BidirectionalIsolate(el) => el,
+            Self::SideComment(el) => el,
+            Self::TableColumnGroup(el) => el,
+            Self::MediaSource(el) => el,
+            Self::Template(el) => el,
+            Self::Search(el) => el,
+            Self::Footer(el) => el,
+            Self::Underline(el) => el,
+            Self::Code(el) => el,
+            Self::Head(el) => el,
+            Self::BlockQuote(el) => el,
+            Self::Section(el) => el,
+            Self::Input(el) => el,
+            Self::RubyAnnotation(el) => el,
+            Self::Main(el) => el,
+            Self::LineBreakOpportunity(el) => el,
+            Self::Meter(el) => el,
+            Self::Output(el) => el,
+            Self::Heading1(el) => el,
+            Self::Caption(el) => el,
+            Self::Fieldset(el) => el,
+            Self::Style(el) => el,
+            Self::BidirectionalTextOverride(el) => el,
+            Self::ImageMapArea(el) => el,
+            Self::FigureCaption(el) => el,
+            Self::SubScript(el) => el,
+            Self::SampleOutput(el) => el,
+            Self::StrikeThrough(el) => el,
+            Self::Slot(el) => el,
+            Self::RubyText(el) => el,
+            Self::OrderedList(el) => el,
+            Self::TableFoot(el) => el,
+            Self::DescriptionDetails(el) => el,
+            Self::ThematicBreak(el) => el,
+            Self::Italic(el) => el,
+            Self::Data(el) => el,
+            Self::Dialog(el) => el,
+            Self::TableColumn(el) => el,
+            Self::HeadingGroup(el) => el,
+            Self::Address(el) => el,
+            Self::Variable(el) => el,
+            Self::Object(el) => el,
+            Self::Division(el) => el,
+            Self::Heading3(el) => el,
+            Self::Time(el) => el,
+            Self::ListItem(el) => el,
+            Self::Emphasis(el) => el,
+            Self::Menu(el) => el,
+            Self::DeletedText(el) => el,
+            Self::Button(el) => el,
+            Self::Details(el) => el,
+            Self::Paragraph(el) => el,
+            Self::OptionGroup(el) => el,
+            Self::Quotation(el) => el,
+            Self::LineBreak(el) => el,
+            Self::Table(el) => el,
+            Self::Option(el) => el,
+            Self::NoScript(el) => el,
+            Self::Heading2(el) => el,
+            Self::Article(el) => el,
+            Self::Body(el) => el,
+            Self::TextArea(el) => el,
+            Self::Iframe(el) => el,
+            Self::Video(el) => el,
+            Self::Image(el) => el,
+            Self::Progress(el) => el,
+            Self::Title(el) => el,
+            Self::InsertedText(el) => el,
+            Self::Heading5(el) => el,
+            Self::Base(el) => el,
+            Self::Picture(el) => el,
+            Self::TextTrack(el) => el,
+            Self::KeyboardInput(el) => el,
+            Self::Audio(el) => el,
+            Self::Html(el) => el,
+            Self::DescriptionTerm(el) => el,
+            Self::DataList(el) => el,
+            Self::Link(el) => el,
+            Self::PreformattedText(el) => el,
+            Self::Label(el) => el,
+            Self::Figure(el) => el,
+            Self::Strong(el) => el,
+            Self::TableHeader(el) => el,
+            Self::Form(el) => el,
+            Self::TableHead(el) => el,
+            Self::RubyFallbackParenthesis(el) => el,
+            Self::ImageMap(el) => el,
+            Self::Cite(el) => el,
+            Self::MarkText(el) => el,
+            Self::DescriptionList(el) => el,
+            Self::TableCell(el) => el,
+            Self::Meta(el) => el,
+            Self::Bold(el) => el,
+            Self::Heading4(el) => el,
+            Self::Select(el) => el,
+            Self::Legend(el) => el,
+            Self::Annotation(el) => el,
+            Self::AnnotationXml(el) => el,
+            Self::Maction(el) => el,
+            Self::Math(el) => el,
+            Self::Merror(el) => el,
+            Self::Mfrac(el) => el,
+            Self::Mi(el) => el,
+            Self::Mmultiscripts(el) => el,
+            Self::Mn(el) => el,
+            Self::Mo(el) => el,
+            Self::Mover(el) => el,
+            Self::Mpadded(el) => el,
+            Self::Mphantom(el) => el,
+            Self::Mprescripts(el) => el,
+            Self::Mroot(el) => el,
+            Self::Mrow(el) => el,
+            Self::Ms(el) => el,
+            Self::Mspace(el) => el,
+            Self::Msqrt(el) => el,
+            Self::Mstyle(el) => el,
+            Self::Msub(el) => el,
+            Self::Msubsup(el) => el,
+            Self::Msup(el) => el,
+            Self::Mtable(el) => el,
+            Self::Mtd(el) => el,
+            Self::Mtext(el) => el,
+            Self::Mtr(el) => el,
+            Self::Munder(el) => el,
+            Self::Munderover(el) => el,
+            Self::Semantics(el) => el,
+            Self::SvgAnchor(el) => el,
+            Self::Circle(el) => el,
+            Self::ClipPath(el) => el,
+            Self::Defs(el) => el,
+            Self::Desc(el) => el,
+            Self::Ellipse(el) => el,
+            Self::ForeignObject(el) => el,
+            Self::G(el) => el,
+            Self::SvgImage(el) => el,
+            Self::Line(el) => el,
+            Self::LinearGradient(el) => el,
+            Self::Marker(el) => el,
+            Self::Mask(el) => el,
+            Self::Metadata(el) => el,
+            Self::Path(el) => el,
+            Self::Pattern(el) => el,
+            Self::Polygon(el) => el,
+            Self::Polyline(el) => el,
+            Self::RadialGradient(el) => el,
+            Self::Rect(el) => el,
+            Self::SvgScript(el) => el,
+            Self::Stop(el) => el,
+            Self::SvgStyle(el) => el,
+            Self::Svg(el) => el,
+            Self::Switch(el) => el,
+            Self::Symbol(el) => el,
+            Self::SvgText(el) => el,
+            Self::TextPath(el) => el,
+            Self::SvgTitle(el) => el,
+            Self::Tspan(el) => el,
+            Self::Use(el) => el,
+            Self::View(el) => el,
+        }
+    }
+    /// Mutably access the element.
+    pub fn as_visit_mut(&mut self) -> &mut dyn crate::visit::Visit {
+        match self {
+            Self::SuperScript(el) => el,
+            Self::Summary(el) => el,
+            Self::Anchor(el) => el,
+            Self::TableRow(el) => el,
+            Self::Aside(el) => el,
+            Self::Navigation(el) => el,
+            Self::TableBody(el) => el,
+            Self::Header(el) => el,
+            Self::Definition(el) => el,
+            Self::Embed(el) => el,
+            Self::Span(el) => el,
+            Self::Canvas(el) => el,
+            Self::Abbreviation(el) => el,
+            Self::Script(el) => el,
+            Self::UnorderedList(el) => el,
+            Self::Heading6(el) => el,
+            Self::BidirectionalIsolate(el) => el,
+            Self::SideComment(el) => el,
+            Self::TableColumnGroup(el) => el,
+            Self::MediaSource(el) => el,
+            Self::Template(el) => el,
+            Self::Search(el) => el,
+            Self::Footer(el) => el,
+            Self::Underline(el) => el,
+            Self::Code(el) => el,
+            Self::Head(el) => el,
+            Self::BlockQuote(el) => el,
+            Self::Section(el) => el,
+            Self::Input(el) => el,
+            Self::RubyAnnotation(el) => el,
+            Self::Main(el) => el,
+            Self::LineBreakOpportunity(el) => el,
+            Self::Meter(el) => el,
+            Self::Output(el) => el,
+            Self::Heading1(el) => el,
+            Self::Caption(el) => el,
+            Self::Fieldset(el) => el,
+            Self::Style(el) => el,
+            Self::BidirectionalTextOverride(el) => el,
+            Self::ImageMapArea(el) => el,
+            Self::FigureCaption(el) => el,
+            Self::SubScript(el) => el,
+            Self::SampleOutput(el) => el,
+            Self::StrikeThrough(el) => el,
+            Self::Slot(el) => el,
+            Self::RubyText(el) => el,
+            Self::OrderedList(el) => el,
+            Self::TableFoot(el) => el,
+            Self::DescriptionDetails(el) => el,
+            Self::ThematicBreak(el) => el,
+            Self::Italic(el) => el,
+            Self::Data(el) => el,
+            Self::Dialog(el) => el,
+            Self::TableColumn(el) => el,
+            Self::HeadingGroup(el) => el,
+            Self::Address(el) => el,
+            Self::Variable(el) => el,
+            Self::Object(el) => el,
+            Self::Division(el) => el,
+            Self::Heading3(el) => el,
+            Self::Time(el) => el,
+            Self::ListItem(el) => el,
+            Self::Emphasis(el) => el,
+            Self::Menu(el) => el,
+            Self::DeletedText(el) => el,
+            Self::Button(el) => el,
+            Self::Details(el) => el,
+            Self::Paragraph(el) => el,
+            Self::OptionGroup(el) => el,
+            Self::Quotation(el) => el,
+            Self::LineBreak(el) => el,
+            Self::Table(el) => el,
+            Self::Option(el) => el,
+            Self::NoScript(el) => el,
+            Self::Heading2(el) => el,
+            Self::Article(el) => el,
+            Self::Body(el) => el,
+            Self::TextArea(el) => el,
+            Self::Iframe(el) => el,
+            Self::Video(el) => el,
+            Self::Image(el) => el,
+            Self::Progress(el) => el,
+            Self::Title(el) => el,
+            Self::InsertedText(el) => el,
+            Self::Heading5(el) => el,
+            Self::Base(el) => el,
+            Self::Picture(el) => el,
+            Self::TextTrack(el) => el,
+            Self::KeyboardInput(el) => el,
+            Self::Audio(el) => el,
+            Self::Html(el) => el,
+            Self::DescriptionTerm(el) => el,
+            Self::DataList(el) => el,
+            Self::Link(el) => el,
+            Self::PreformattedText(el) => el,
+            Self::Label(el) => el,
+            Self::Figure(el) => el,
+            Self::Strong(el) => el,
+            Self::TableHeader(el) => el,
+            Self::Form(el) => el,
+            Self::TableHead(el) => el,
+            Self::RubyFallbackParenthesis(el) => el,
+            Self::ImageMap(el) => el,
+            Self::Cite(el) => el,
+            Self::MarkText(el) => el,
+            Self::DescriptionList(el) => el,
+            Self::TableCell(el) => el,
+            Self::Meta(el) => el,
+            Self::Bold(el) => el,
+            Self::Heading4(el) => el,
+            Self::Select(el) => el,
+            Self::Legend(el) => el,
+            Self::Annotation(el) => el,
+            Self::AnnotationXml(el) => el,
+            Self::Maction(el) => el,
+            Self::Math(el) => el,
+            Self::Merror(el) => el,
+            Self::Mfrac(el) => el,
+            Self::Mi(el) => el,
+            Self::Mmultiscripts(el) => el,
+            Self::Mn(el) => el,
+            Self::Mo(el) => el,
+            Self::Mover(el) => el,
+            Self::Mpadded(el) => el,
+            Self::Mphantom(el) => el,
+            Self::Mprescripts(el) => el,
+            Self::Mroot(el) => el,
+            Self::Mrow(el) => el,
+            Self::Ms(el) => el,
+            Self::Mspace(el) => el,
+            Self::Msqrt(el) => el,
+            Self::Mstyle(el) => el,
+            Self::Msub(el) => el,
+            Self::Msubsup(el) => el,
+            Self::Msup(el) => el,
+            Self::Mtable(el) => el,
+            Self::Mtd(el) => el,
+            Self::Mtext(el) => el,
+            Self::Mtr(el) => el,
+            Self::Munder(el) => el,
+            Self::Munderover(el) => el,
+            Self::Semantics(el) => el,
+            Self::SvgAnchor(el) => el,
+            Self::Circle(el) => el,
+            Self::ClipPath(el) => el,
+            Self::Defs(el) => el,
+            Self::Desc(el) => el,
+            Self::Ellipse(el) => el,
+            Self::ForeignObject(el) => el,
+            Self::G(el) => el,
+            Self::SvgImage(el) => el,
+            Self::Line(el) => el,
+            Self::LinearGradient(el) => el,
+            Self::Marker(el) => el,
+            Self::Mask(el) => el,
+            Self::Metadata(el) => el,
+            Self::Path(el) => el,
+            Self::Pattern(el) => el,
+            Self::Polygon(el) => el,
+            Self::Polyline(el) => el,
+            Self::RadialGradient(el) => el,
+            Self::Rect(el) => el,
+            Self::SvgScript(el) => el,
+            Self::Stop(el) => el,
+            Self::SvgStyle(el) => el,
+            Self::Svg(el) => el,
+            Self::Switch(el) => el,
+            Self::Symbol(el) => el,
+            Self::SvgText(el) => el,
+            Self::TextPath(el) => el,
+            Self::SvgTitle(el) => el,
+            Self::Tspan(el) => el,
+            Self::Use(el) => el,
+            Self::View(el) => el,
+        }
+    }
+    pub(crate) fn into_any(self) -> Box<dyn std::any::Any> {
+        match self {
+            Self::SuperScript(el) => Box::new(el),
+            Self::Summary(el) => Box::new(el),
+            Self::Anchor(el) => Box::new(el),
+            Self::TableRow(el) => Box::new(el),
+            Self::Aside(el) => Box::new(el),
+            Self::Navigation(el) => Box::new(el),
+            Self::TableBody(el) => Box::new(el),
+            Self::Header(el) => Box::new(el),
+            Self::Definition(el) => Box::new(el),
+            Self::Embed(el) => Box::new(el),
+            Self::Span(el) => Box::new(el),
+            Self::Canvas(el) => Box::new(el),
+            Self::Abbreviation(el) => Box::new(el),
+            Self::Script(el) => Box::new(el),
+            Self::UnorderedList(el) => Box::new(el),
+            Self::Heading6(el) => Box::new(el),
+            Self::BidirectionalIsolate(el) => Box::new(el),
+            Self::SideComment(el) => Box::new(el),
+            Self::TableColumnGroup(el) => Box::new(el),
+            Self::MediaSource(el) => Box::new(el),
+            Self::Template(el) => Box::new(el),
+            Self::Search(el) => Box::new(el),
+            Self::Footer(el) => Box::new(el),
+            Self::Underline(el) => Box::new(el),
+            Self::Code(el) => Box::new(el),
+            Self::Head(el) => Box::new(el),
+            Self::BlockQuote(el) => Box::new(el),
+            Self::Section(el) => Box::new(el),
+            Self::Input(el) => Box::new(el),
+            Self::RubyAnnotation(el) => Box::new(el),
+            Self::Main(el) => Box::new(el),
+            Self::LineBreakOpportunity(el) => Box::new(el),
+            Self::Meter(el) => Box::new(el),
+            Self::Output(el) => Box::new(el),
+            Self::Heading1(el) => Box::new(el),
+            Self::Caption(el) => Box::new(el),
+            Self::Fieldset(el) => Box::new(el),
+            Self::Style(el) => Box::new(el),
+            Self::BidirectionalTextOverride(el) => Box::new(el),
+            Self::ImageMapArea(el) => Box::new(el),
+            Self::FigureCaption(el) => Box::new(el),
+            Self::SubScript(el) => Box::new(el),
+            Self::SampleOutput(el) => Box::new(el),
+            Self::StrikeThrough(el) => Box::new(el),
+            Self::Slot(el) => Box::new(el),
+            Self::RubyText(el) => Box::new(el),
+            Self::OrderedList(el) => Box::new(el),
+            Self::TableFoot(el) => Box::new(el),
+            Self::DescriptionDetails(el) => Box::new(el),
+            Self::ThematicBreak(el) => Box::new(el),
+            Self::Italic(el) => Box::new(el),
+            Self::Data(el) => Box::new(el),
+            Self::Dialog(el) => Box::new(el),
+            Self::TableColumn(el) => Box::new(el),
+            Self::HeadingGroup(el) => Box::new(el),
+            Self::Address(el) => Box::new(el),
+            Self::Variable(el) => Box::new(el),
+            Self::Object(el) => Box::new(el),
+            Self::Division(el) => Box::new(el),
+            Self::Heading3(el) => Box::new(el),
+            Self::Time(el) => Box::new(el),
+            Self::ListItem(el) => Box::new(el),
+            Self::Emphasis(el) => Box::new(el),
+            Self::Menu(el) => Box::new(el),
+            Self::DeletedText(el) => Box::new(el),
+            Self::Button(el) => Box::new(el),
+            Self::Details(el) => Box::new(el),
+            Self::Paragraph(el) => Box::new(el),
+            Self::OptionGroup(el) => Box::new(el),
+            Self::Quotation(el) => Box::new(el),
+            Self::LineBreak(el) => Box::new(el),
+            Self::Table(el) => Box::new(el),
+            Self::Option(el) => Box::new(el),
+            Self::NoScript(el) => Box::new(el),
+            Self::Heading2(el) => Box::new(el),
+            Self::Article(el) => Box::new(el),
+            Self::Body(el) => Box::new(el),
+            Self::TextArea(el) => Box::new(el),
+            Self::Iframe(el) => Box::new(el),
+            Self::Video(el) => Box::new(el),
+            Self::Image(el) => Box::new(el),
+            Self::Progress(el) => Box::new(el),
+            Self::Title(el) => Box::new(el),
+            Self::InsertedText(el) => Box::new(el),
+            Self::Heading5(el) => Box::new(el),
+            Self::Base(el) => Box::new(el),
+            Self::Picture(el) => Box::new(el),
+            Self::TextTrack(el) => Box::new(el),
+            Self::KeyboardInput(el) => Box::new(el),
+            Self::Audio(el) => Box::new(el),
+            Self::Html(el) => Box::new(el),
+            Self::DescriptionTerm(el) => Box::new(el),
+            Self::DataList(el) => Box::new(el),
+            Self::Link(el) => Box::new(el),
+            Self::PreformattedText(el) => Box::new(el),
+            Self::Label(el) => Box::new(el),
+            Self::Figure(el) => Box::new(el),
+            Self::Strong(el) => Box::new(el),
+            Self::TableHeader(el) => Box::new(el),
+            Self::Form(el) => Box::new(el),
+            Self::TableHead(el) => Box::new(el),
+            Self::RubyFallbackParenthesis(el) => Box::new(el),
+            Self::ImageMap(el) => Box::new(el),
+            Self::Cite(el) => Box::new(el),
+            Self::MarkText(el) => Box::new(el),
+            Self::DescriptionList(el) => Box::new(el),
+            Self::TableCell(el) => Box::new(el),
+            Self::Meta(el) => Box::new(el),
+            Self::Bold(el) => Box::new(el),
+            Self::Heading4(el) => Box::new(el),
+            Self::Select(el) => Box::new(el),
+            Self::Legend(el) => Box::new(el),
+            Self::Annotation(el) => Box::new(el),
+            Self::AnnotationXml(el) => Box::new(el),
+            Self::Maction(el) => Box::new(el),
+            Self::Math(el) => Box::new(el),
+            Self::Merror(el) => Box::new(el),
+            Self::Mfrac(el) => Box::new(el),
+            Self::Mi(el) => Box::new(el),
+            Self::Mmultiscripts(el) => Box::new(el),
+            Self::Mn(el) => Box::new(el),
+            Self::Mo(el) => Box::new(el),
+            Self::Mover(el) => Box::new(el),
+            Self::Mpadded(el) => Box::new(el),
+            Self::Mphantom(el) => Box::new(el),
+            Self::Mprescripts(el) => Box::new(el),
+            Self::Mroot(el) => Box::new(el),
+            Self::Mrow(el) => Box::new(el),
+            Self::Ms(el) => Box::new(el),
+            Self::Mspace(el) => Box::new(el),
+            Self::Msqrt(el) => Box::new(el),
+            Self::Mstyle(el) => Box::new(el),
+            Self::Msub(el) => Box::new(el),
+            Self::Msubsup(el) => Box::new(el),
+            Self::Msup(el) => Box::new(el),
+            Self::Mtable(el) => Box::new(el),
+            Self::Mtd(el) => Box::new(el),
+            Self::Mtext(el) => Box::new(el),
+            Self::Mtr(el) => Box::new(el),
+            Self::Munder(el) => Box::new(el),
+            Self::Munderover(el) => Box::new(el),
+            Self::Semantics(el) => Box::new(el),
+            Self::SvgAnchor(el) => Box::new(el),
+            Self::Circle(el) => Box::new(el),
+            Self::ClipPath(el) => Box::new(el),
+            Self::Defs(el) => Box::new(el),
+            Self::Desc(el) => Box::new(el),
+            Self::Ellipse(el) => Box::new(el),
+            Self::ForeignObject(el) => Box::new(el),
+            Self::G(el) => Box::new(el),
+            Self::SvgImage(el) => Box::new(el),
+            Self::Line(el) => Box::new(el),
+            Self::LinearGradient(el) => Box::new(el),
+            Self::Marker(el) => Box::new(el),
+            Self::Mask(el) => Box::new(el),
+            Self::Metadata(el) => Box::new(el),
+            Self::Path(el) => Box::new(el),
+            Self::Pattern(el) => Box::new(el),
+            Self::Polygon(el) => Box::new(el),
+            Self::Polyline(el) => Box::new(el),
+            Self::RadialGradient(el) => Box::new(el),
+            Self::Rect(el) => Box::new(el),
+            Self::SvgScript(el) => Box::new(el),
+            Self::Stop(el) => Box::new(el),
+            Self::SvgStyle(el) => Box::new(el),
+            Self::Svg(el) => Box::new(el),
+            Self::Switch(el) => Box::new(el),
+            Self::Symbol(el) => Box::new(el),
+            Self::SvgText(el) => Box::new(el),
+            Self::TextPath(el) => Box::new(el),
+            Self::SvgTitle(el) => Box::new(el),
+            Self::Tspan(el) => Box::new(el),
+            Self::Use(el) => Box::new(el),
+            Self::View(el) => Box::new(el),
+        }
+    }
+}
+impl std::convert::From<crate::generated::all::SuperScript> for AnyElement {
+    fn from(value: crate::generated::all::SuperScript) -> Self {
+        Self::SuperScript(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Summary> for AnyElement {
+    fn from(value: crate::generated::all::Summary) -> Self {
+        Self::Summary(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Anchor> for AnyElement {
+    fn from(value: crate::generated::all::Anchor) -> Self {
+        Self::Anchor(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableRow> for AnyElement {
+    fn from(value: crate::generated::all::TableRow) -> Self {
+        Self::TableRow(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Aside> for AnyElement {
+    fn from(value: crate::generated::all::Aside) -> Self {
+        Self::Aside(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Navigation> for AnyElement {
+    fn from(value: crate::generated::all::Navigation) -> Self {
+        Self::Navigation(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableBody> for AnyElement {
+    fn from(value: crate::generated::all::TableBody) -> Self {
+        Self::TableBody(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Header> for AnyElement {
+    fn from(value: crate::generated::all::Header) -> Self {
+        Self::Header(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Definition> for AnyElement {
+    fn from(value: crate::generated::all::Definition) -> Self {
+        Self::Definition(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Embed> for AnyElement {
+    fn from(value: crate::generated::all::Embed) -> Self {
+        Self::Embed(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Span> for AnyElement {
+    fn from(value: crate::generated::all::Span) -> Self {
+        Self::Span(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Canvas> for AnyElement {
+    fn from(value: crate::generated::all::Canvas) -> Self {
+        Self::Canvas(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Abbreviation> for AnyElement {
+    fn from(value: crate::generated::all::Abbreviation) -> Self {
+        Self::Abbreviation(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Script> for AnyElement {
+    fn from(value: crate::generated::all::Script) -> Self {
+        Self::Script(value)
+    }
+}
+impl std::convert::From<crate::generated::all::UnorderedList> for AnyElement {
+    fn from(value: crate::generated::all::UnorderedList) -> Self {
+        Self::UnorderedList(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Heading6> for AnyElement {
+    fn from(value: crate::generated::all::Heading6) -> Self {
+        Self::Heading6(value)
+    }
+}
+impl std::convert::From<crate::generated::all::BidirectionalIsolate> for AnyElement {
+    fn from(value: crate::generated::all::BidirectionalIsolate) -> Self {
+        Self::BidirectionalIsolate(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SideComment> for AnyElement {
+    fn from(value: crate::generated::all::SideComment) -> Self {
+        Self::SideComment(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableColumnGroup> for AnyElement {
+    fn from(value: crate::generated::all::TableColumnGroup) -> Self {
+        Self::TableColumnGroup(value)
+    }
+}
+impl std::convert::From<crate::generated::all::MediaSource> for AnyElement {
+    fn from(value: crate::generated::all::MediaSource) -> Self {
+        Self::MediaSource(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Template> for AnyElement {
+    fn from(value: crate::generated::all::Template) -> Self {
+        Self::Template(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Search> for AnyElement {
+    fn from(value: crate::generated::all::Search) -> Self {
+        Self::Search(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Footer> for AnyElement {
+    fn from(value: crate::generated::all::Footer) -> Self {
+        Self::Footer(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Underline> for AnyElement {
+    fn from(value: crate::generated::all::Underline) -> Self {
+        Self::Underline(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Code> for AnyElement {
+    fn from(value: crate::generated::all::Code) -> Self {
+        Self::Code(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Head> for AnyElement {
+    fn from(value: crate::generated::all::Head) -> Self {
+        Self::Head(value)
+    }
+}
+impl std::convert::From<crate::generated::all::BlockQuote> for AnyElement {
+    fn from(value: crate::generated::all::BlockQuote) -> Self {
+        Self::BlockQuote(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Section> for AnyElement {
+    fn from(value: crate::generated::all::Section) -> Self {
+        Self::Section(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Input> for AnyElement {
+    fn from(value: crate::generated::all::Input) -> Self {
+        Self::Input(value)
+    }
+}
+impl std::convert::From<crate::generated::all::RubyAnnotation> for AnyElement {
+    fn from(value: crate::generated::all::RubyAnnotation) -> Self {
+        Self::RubyAnnotation(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Main> for AnyElement {
+    fn from(value: crate::generated::all::Main) -> Self {
+        Self::Main(value)
+    }
+}
+impl std::convert::From<crate::generated::all::LineBreakOpportunity> for AnyElement {
+    fn from(value: crate::generated::all::LineBreakOpportunity) -> Self {
+        Self::LineBreakOpportunity(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Meter> for AnyElement {
+    fn from(value: crate::generated::all::Meter) -> Self {
+        Self::Meter(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Output> for AnyElement {
+    fn from(value: crate::generated::all::Output) -> Self {
+        Self::Output(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Heading1> for AnyElement {
+    fn from(value: crate::generated::all::Heading1) -> Self {
+        Self::Heading1(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Caption> for AnyElement {
+    fn from(value: crate::generated::all::Caption) -> Self {
+        Self::Caption(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Fieldset> for AnyElement {
+    fn from(value: crate::generated::all::Fieldset) -> Self {
+        Self::Fieldset(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Style> for AnyElement {
+    fn from(value: crate::generated::all::Style) -> Self {
+        Self::Style(value)
+    }
+}
+impl std::convert::From<crate::generated::all::BidirectionalTextOverride>
+for AnyElement {
+    fn from(value: crate::generated::all::BidirectionalTextOverride) -> Self {
+        Self::BidirectionalTextOverride(value)
+    }
+}
+impl std::convert::From<crate::generated::all::ImageMapArea> for AnyElement {
+    fn from(value: crate::generated::all::ImageMapArea) -> Self {
+        Self::ImageMapArea(value)
+    }
+}
+impl std::convert::From<crate::generated::all::FigureCaption> for AnyElement {
+    fn from(value: crate::generated::all::FigureCaption) -> Self {
+        Self::FigureCaption(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SubScript> for AnyElement {
+    fn from(value: crate::generated::all::SubScript) -> Self {
+        Self::SubScript(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SampleOutput> for AnyElement {
+    fn from(value: crate::generated::all::SampleOutput) -> Self {
+        Self::SampleOutput(value)
+    }
+}
+impl std::convert::From<crate::generated::all::StrikeThrough> for AnyElement {
+    fn from(value: crate::generated::all::StrikeThrough) -> Self {
+        Self::StrikeThrough(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Slot> for AnyElement {
+    fn from(value: crate::generated::all::Slot) -> Self {
+        Self::Slot(value)
+    }
+}
+impl std::convert::From<crate::generated::all::RubyText> for AnyElement {
+    fn from(value: crate::generated::all::RubyText) -> Self {
+        Self::RubyText(value)
+    }
+}
+impl std::convert::From<crate::generated::all::OrderedList> for AnyElement {
+    fn from(value: crate::generated::all::OrderedList) -> Self {
+        Self::OrderedList(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableFoot> for AnyElement {
+    fn from(value: crate::generated::all::TableFoot) -> Self {
+        Self::TableFoot(value)
+    }
+}
+impl std::convert::From<crate::generated::all::DescriptionDetails> for AnyElement {
+    fn from(value: crate::generated::all::DescriptionDetails) -> Self {
+        Self::DescriptionDetails(value)
+    }
+}
+impl std::convert::From<crate::generated::all::ThematicBreak> for AnyElement {
+    fn from(value: crate::generated::all::ThematicBreak) -> Self {
+        Self::ThematicBreak(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Italic> for AnyElement {
+    fn from(value: crate::generated::all::Italic) -> Self {
+        Self::Italic(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Data> for AnyElement {
+    fn from(value: crate::generated::all::Data) -> Self {
+        Self::Data(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Dialog> for AnyElement {
+    fn from(value: crate::generated::all::Dialog) -> Self {
+        Self::Dialog(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableColumn> for AnyElement {
+    fn from(value: crate::generated::all::TableColumn) -> Self {
+        Self::TableColumn(value)
+    }
+}
+impl std::convert::From<crate::generated::all::HeadingGroup> for AnyElement {
+    fn from(value: crate::generated::all::HeadingGroup) -> Self {
+        Self::HeadingGroup(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Address> for AnyElement {
+    fn from(value: crate::generated::all::Address) -> Self {
+        Self::Address(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Variable> for AnyElement {
+    fn from(value: crate::generated::all::Variable) -> Self {
+        Self::Variable(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Object> for AnyElement {
+    fn from(value: crate::generated::all::Object) -> Self {
+        Self::Object(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Division> for AnyElement {
+    fn from(value: crate::generated::all::Division) -> Self {
+        Self::Division(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Heading3> for AnyElement {
+    fn from(value: crate::generated::all::Heading3) -> Self {
+        Self::Heading3(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Time> for AnyElement {
+    fn from(value: crate::generated::all::Time) -> Self {
+        Self::Time(value)
+    }
+}
+impl std::convert::From<crate::generated::all::ListItem> for AnyElement {
+    fn from(value: crate::generated::all::ListItem) -> Self {
+        Self::ListItem(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Emphasis> for AnyElement {
+    fn from(value: crate::generated::all::Emphasis) -> Self {
+        Self::Emphasis(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Menu> for AnyElement {
+    fn from(value: crate::generated::all::Menu) -> Self {
+        Self::Menu(value)
+    }
+}
+impl std::convert::From<crate::generated::all::DeletedText> for AnyElement {
+    fn from(value: crate::generated::all::DeletedText) -> Self {
+        Self::DeletedText(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Button> for AnyElement {
+    fn from(value: crate::generated::all::Button) -> Self {
+        Self::Button(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Details> for AnyElement {
+    fn from(value: crate::generated::all::Details) -> Self {
+        Self::Details(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Paragraph> for AnyElement {
+    fn from(value: crate::generated::all::Paragraph) -> Self {
+        Self::Paragraph(value)
+    }
+}
+impl std::convert::From<crate::generated::all::OptionGroup> for AnyElement {
+    fn from(value: crate::generated::all::OptionGroup) -> Self {
+        Self::OptionGroup(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Quotation> for AnyElement {
+    fn from(value: crate::generated::all::Quotation) -> Self {
+        Self::Quotation(value)
+    }
+}
+impl std::convert::From<crate::generated::all::LineBreak> for AnyElement {
+    fn from(value: crate::generated::all::LineBreak) -> Self {
+        Self::LineBreak(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Table> for AnyElement {
+    fn from(value: crate::generated::all::Table) -> Self {
+        Self::Table(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Option> for AnyElement {
+    fn from(value: crate::generated::all::Option) -> Self {
+        Self::Option(value)
+    }
+}
+impl std::convert::From<crate::generated::all::NoScript> for AnyElement {
+    fn from(value: crate::generated::all::NoScript) -> Self {
+        Self::NoScript(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Heading2> for AnyElement {
+    fn from(value: crate::generated::all::Heading2) -> Self {
+        Self::Heading2(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Article> for AnyElement {
+    fn from(value: crate::generated::all::Article) -> Self {
+        Self::Article(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Body> for AnyElement {
+    fn from(value: crate::generated::all::Body) -> Self {
+        Self::Body(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TextArea> for AnyElement {
+    fn from(value: crate::generated::all::TextArea) -> Self {
+        Self::TextArea(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Iframe> for AnyElement {
+    fn from(value: crate::generated::all::Iframe) -> Self {
+        Self::Iframe(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Video> for AnyElement {
+    fn from(value: crate::generated::all::Video) -> Self {
+        Self::Video(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Image> for AnyElement {
+    fn from(value: crate::generated::all::Image) -> Self {
+        Self::Image(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Progress> for AnyElement {
+    fn from(value: crate::generated::all::Progress) -> Self {
+        Self::Progress(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Title> for AnyElement {
+    fn from(value: crate::generated::all::Title) -> Self {
+        Self::Title(value)
+    }
+}
+impl std::convert::From<crate::generated::all::InsertedText> for AnyElement {
+    fn from(value: crate::generated::all::InsertedText) -> Self {
+        Self::InsertedText(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Heading5> for AnyElement {
+    fn from(value: crate::generated::all::Heading5) -> Self {
+        Self::Heading5(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Base> for AnyElement {
+    fn from(value: crate::generated::all::Base) -> Self {
+        Self::Base(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Picture> for AnyElement {
+    fn from(value: crate::generated::all::Picture) -> Self {
+        Self::Picture(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TextTrack> for AnyElement {
+    fn from(value: crate::generated::all::TextTrack) -> Self {
+        Self::TextTrack(value)
+    }
+}
+impl std::convert::From<crate::generated::all::KeyboardInput> for AnyElement {
+    fn from(value: crate::generated::all::KeyboardInput) -> Self {
+        Self::KeyboardInput(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Audio> for AnyElement {
+    fn from(value: crate::generated::all::Audio) -> Self {
+        Self::Audio(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Html> for AnyElement {
+    fn from(value: crate::generated::all::Html) -> Self {
+        Self::Html(value)
+    }
+}
+impl std::convert::From<crate::generated::all::DescriptionTerm> for AnyElement {
+    fn from(value: crate::generated::all::DescriptionTerm) -> Self {
+        Self::DescriptionTerm(value)
+    }
+}
+impl std::convert::From<crate::generated::all::DataList> for AnyElement {
+    fn from(value: crate::generated::all::DataList) -> Self {
+        Self::DataList(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Link> for AnyElement {
+    fn from(value: crate::generated::all::Link) -> Self {
+        Self::Link(value)
+    }
+}
+impl std::convert::From<crate::generated::all::PreformattedText> for AnyElement {
+    fn from(value: crate::generated::all::PreformattedText) -> Self {
+        Self::PreformattedText(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Label> for AnyElement {
+    fn from(value: crate::generated::all::Label) -> Self {
+        Self::Label(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Figure> for AnyElement {
+    fn from(value: crate::generated::all::Figure) -> Self {
+        Self::Figure(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Strong> for AnyElement {
+    fn from(value: crate::generated::all::Strong) -> Self {
+        Self::Strong(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableHeader> for AnyElement {
+    fn from(value: crate::generated::all::TableHeader) -> Self {
+        Self::TableHeader(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Form> for AnyElement {
+    fn from(value: crate::generated::all::Form) -> Self {
+        Self::Form(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableHead> for AnyElement {
+    fn from(value: crate::generated::all::TableHead) -> Self {
+        Self::TableHead(value)
+    }
+}
+impl std::convert::From<crate::generated::all::RubyFallbackParenthesis> for AnyElement {
+    fn from(value: crate::generated::all::RubyFallbackParenthesis) -> Self {
+        Self::RubyFallbackParenthesis(value)
+    }
+}
+impl std::convert::From<crate::generated::all::ImageMap> for AnyElement {
+    fn from(value: crate::generated::all::ImageMap) -> Self {
+        Self::ImageMap(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Cite> for AnyElement {
+    fn from(value: crate::generated::all::Cite) -> Self {
+        Self::Cite(value)
+    }
+}
+impl std::convert::From<crate::generated::all::MarkText> for AnyElement {
+    fn from(value: crate::generated::all::MarkText) -> Self {
+        Self::MarkText(value)
+    }
+}
+impl std::convert::From<crate::generated::all::DescriptionList> for AnyElement {
+    fn from(value: crate::generated::all::DescriptionList) -> Self {
+        Self::DescriptionList(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TableCell> for AnyElement {
+    fn from(value: crate::generated::all::TableCell) -> Self {
+        Self::TableCell(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Meta> for AnyElement {
+    fn from(value: crate::generated::all::Meta) -> Self {
+        Self::Meta(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Bold> for AnyElement {
+    fn from(value: crate::generated::all::Bold) -> Self {
+        Self::Bold(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Heading4> for AnyElement {
+    fn from(value: crate::generated::all::Heading4) -> Self {
+        Self::Heading4(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Select> for AnyElement {
+    fn from(value: crate::generated::all::Select) -> Self {
+        Self::Select(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Legend> for AnyElement {
+    fn from(value: crate::generated::all::Legend) -> Self {
+        Self::Legend(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Annotation> for AnyElement {
+    fn from(value: crate::generated::all::Annotation) -> Self {
+        Self::Annotation(value)
+    }
+}
+impl std::convert::From<crate::generated::all::AnnotationXml> for AnyElement {
+    fn from(value: crate::generated::all::AnnotationXml) -> Self {
+        Self::AnnotationXml(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Maction> for AnyElement {
+    fn from(value: crate::generated::all::Maction) -> Self {
+        Self::Maction(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Math> for AnyElement {
+    fn from(value: crate::generated::all::Math) -> Self {
+        Self::Math(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Merror> for AnyElement {
+    fn from(value: crate::generated::all::Merror) -> Self {
+        Self::Merror(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mfrac> for AnyElement {
+    fn from(value: crate::generated::all::Mfrac) -> Self {
+        Self::Mfrac(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mi> for AnyElement {
+    fn from(value: crate::generated::all::Mi) -> Self {
+        Self::Mi(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mmultiscripts> for AnyElement {
+    fn from(value: crate::generated::all::Mmultiscripts) -> Self {
+        Self::Mmultiscripts(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mn> for AnyElement {
+    fn from(value: crate::generated::all::Mn) -> Self {
+        Self::Mn(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mo> for AnyElement {
+    fn from(value: crate::generated::all::Mo) -> Self {
+        Self::Mo(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mover> for AnyElement {
+    fn from(value: crate::generated::all::Mover) -> Self {
+        Self::Mover(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mpadded> for AnyElement {
+    fn from(value: crate::generated::all::Mpadded) -> Self {
+        Self::Mpadded(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mphantom> for AnyElement {
+    fn from(value: crate::generated::all::Mphantom) -> Self {
+        Self::Mphantom(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mprescripts> for AnyElement {
+    fn from(value: crate::generated::all::Mprescripts) -> Self {
+        Self::Mprescripts(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mroot> for AnyElement {
+    fn from(value: crate::generated::all::Mroot) -> Self {
+        Self::Mroot(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mrow> for AnyElement {
+    fn from(value: crate::generated::all::Mrow) -> Self {
+        Self::Mrow(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Ms> for AnyElement {
+    fn from(value: crate::generated::all::Ms) -> Self {
+        Self::Ms(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mspace> for AnyElement {
+    fn from(value: crate::generated::all::Mspace) -> Self {
+        Self::Mspace(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Msqrt> for AnyElement {
+    fn from(value: crate::generated::all::Msqrt) -> Self {
+        Self::Msqrt(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mstyle> for AnyElement {
+    fn from(value: crate::generated::all::Mstyle) -> Self {
+        Self::Mstyle(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Msub> for AnyElement {
+    fn from(value: crate::generated::all::Msub) -> Self {
+        Self::Msub(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Msubsup> for AnyElement {
+    fn from(value: crate::generated::all::Msubsup) -> Self {
+        Self::Msubsup(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Msup> for AnyElement {
+    fn from(value: crate::generated::all::Msup) -> Self {
+        Self::Msup(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mtable> for AnyElement {
+    fn from(value: crate::generated::all::Mtable) -> Self {
+        Self::Mtable(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mtd> for AnyElement {
+    fn from(value: crate::generated::all::Mtd) -> Self {
+        Self::Mtd(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mtext> for AnyElement {
+    fn from(value: crate::generated::all::Mtext) -> Self {
+        Self::Mtext(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mtr> for AnyElement {
+    fn from(value: crate::generated::all::Mtr) -> Self {
+        Self::Mtr(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Munder> for AnyElement {
+    fn from(value: crate::generated::all::Munder) -> Self {
+        Self::Munder(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Munderover> for AnyElement {
+    fn from(value: crate::generated::all::Munderover) -> Self {
+        Self::Munderover(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Semantics> for AnyElement {
+    fn from(value: crate::generated::all::Semantics) -> Self {
+        Self::Semantics(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SvgAnchor> for AnyElement {
+    fn from(value: crate::generated::all::SvgAnchor) -> Self {
+        Self::SvgAnchor(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Circle> for AnyElement {
+    fn from(value: crate::generated::all::Circle) -> Self {
+        Self::Circle(value)
+    }
+}
+impl std::convert::From<crate::generated::all::ClipPath> for AnyElement {
+    fn from(value: crate::generated::all::ClipPath) -> Self {
+        Self::ClipPath(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Defs> for AnyElement {
+    fn from(value: crate::generated::all::Defs) -> Self {
+        Self::Defs(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Desc> for AnyElement {
+    fn from(value: crate::generated::all::Desc) -> Self {
+        Self::Desc(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Ellipse> for AnyElement {
+    fn from(value: crate::generated::all::Ellipse) -> Self {
+        Self::Ellipse(value)
+    }
+}
+impl std::convert::From<crate::generated::all::ForeignObject> for AnyElement {
+    fn from(value: crate::generated::all::ForeignObject) -> Self {
+        Self::ForeignObject(value)
+    }
+}
+impl std::convert::From<crate::generated::all::G> for AnyElement {
+    fn from(value: crate::generated::all::G) -> Self {
+        Self::G(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SvgImage> for AnyElement {
+    fn from(value: crate::generated::all::SvgImage) -> Self {
+        Self::SvgImage(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Line> for AnyElement {
+    fn from(value: crate::generated::all::Line) -> Self {
+        Self::Line(value)
+    }
+}
+impl std::convert::From<crate::generated::all::LinearGradient> for AnyElement {
+    fn from(value: crate::generated::all::LinearGradient) -> Self {
+        Self::LinearGradient(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Marker> for AnyElement {
+    fn from(value: crate::generated::all::Marker) -> Self {
+        Self::Marker(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Mask> for AnyElement {
+    fn from(value: crate::generated::all::Mask) -> Self {
+        Self::Mask(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Metadata> for AnyElement {
+    fn from(value: crate::generated::all::Metadata) -> Self {
+        Self::Metadata(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Path> for AnyElement {
+    fn from(value: crate::generated::all::Path) -> Self {
+        Self::Path(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Pattern> for AnyElement {
+    fn from(value: crate::generated::all::Pattern) -> Self {
+        Self::Pattern(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Polygon> for AnyElement {
+    fn from(value: crate::generated::all::Polygon) -> Self {
+        Self::Polygon(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Polyline> for AnyElement {
+    fn from(value: crate::generated::all::Polyline) -> Self {
+        Self::Polyline(value)
+    }
+}
+impl std::convert::From<crate::generated::all::RadialGradient> for AnyElement {
+    fn from(value: crate::generated::all::RadialGradient) -> Self {
+        Self::RadialGradient(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Rect> for AnyElement {
+    fn from(value: crate::generated::all::Rect) -> Self {
+        Self::Rect(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SvgScript> for AnyElement {
+    fn from(value: crate::generated::all::SvgScript) -> Self {
+        Self::SvgScript(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Stop> for AnyElement {
+    fn from(value: crate::generated::all::Stop) -> Self {
+        Self::Stop(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SvgStyle> for AnyElement {
+    fn from(value: crate::generated::all::SvgStyle) -> Self {
+        Self::SvgStyle(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Svg> for AnyElement {
+    fn from(value: crate::generated::all::Svg) -> Self {
+        Self::Svg(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Switch> for AnyElement {
+    fn from(value: crate::generated::all::Switch) -> Self {
+        Self::Switch(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Symbol> for AnyElement {
+    fn from(value: crate::generated::all::Symbol) -> Self {
+        Self::Symbol(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SvgText> for AnyElement {
+    fn from(value: crate::generated::all::SvgText) -> Self {
+        Self::SvgText(value)
+    }
+}
+impl std::convert::From<crate::generated::all::TextPath> for AnyElement {
+    fn from(value: crate::generated::all::TextPath) -> Self {
+        Self::TextPath(value)
+    }
+}
+impl std::convert::From<crate::generated::all::SvgTitle> for AnyElement {
+    fn from(value: crate::generated::all::SvgTitle) -> Self {
+        Self::SvgTitle(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Tspan> for AnyElement {
+    fn from(value: crate::generated::all::Tspan) -> Self {
+        Self::Tspan(value)
+    }
+}
+impl std::convert::From<crate::generated::all::Use> for AnyElement {
+    fn from(value: crate::generated::all::Use) -> Self {
+        Self::Use(value)
+    }
+}
+impl std::convert::From<crate::generated::all::View> for AnyElement {
+    fn from(value: crate::generated::all::View) -> Self {
+        Self::View(value)
+    }
+}
+impl std::fmt::Debug for AnyElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SuperScript(el) => std::fmt::Debug::fmt(el, f),
+            Self::Summary(el) => std::fmt::Debug::fmt(el, f),
+            Self::Anchor(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableRow(el) => std::fmt::Debug::fmt(el, f),
+            Self::Aside(el) => std::fmt::Debug::fmt(el, f),
+            Self::Navigation(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableBody(el) => std::fmt::Debug::fmt(el, f),
+            Self::Header(el) => std::fmt::Debug::fmt(el, f),
+            Self::Definition(el) => std::fmt::Debug::fmt(el, f),
+            Self::Embed(el) => std::fmt::Debug::fmt(el, f),
+            Self::Span(el) => std::fmt::Debug::fmt(el, f),
+            Self::Canvas(el) => std::fmt::Debug::fmt(el, f),
+            Self::Abbreviation(el) => std::fmt::Debug::fmt(el, f),
+            Self::Script(el) => std::fmt::Debug::fmt(el, f),
+            Self::UnorderedList(el) => std::fmt::Debug::fmt(el, f),
+            Self::Heading6(el) => std::fmt::Debug::fmt(el, f),
+            Self::BidirectionalIsolate(el) => std::fmt::Debug::fmt(el, f),
+            Self::SideComment(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableColumnGroup(el) => std::fmt::Debug::fmt(el, f),
+            Self::MediaSource(el) => std::fmt::Debug::fmt(el, f),
+            Self::Template(el) => std::fmt::Debug::fmt(el, f),
+            Self::Search(el) => std::fmt::Debug::fmt(el, f),
+            Self::Footer(el) => std::fmt::Debug::fmt(el, f),
+            Self::Underline(el) => std::fmt::Debug::fmt(el, f),
+            Self::Code(el) => std::fmt::Debug::fmt(el, f),
+            Self::Head(el) => std::fmt::Debug::fmt(el, f),
+            Self::BlockQuote(el) => std::fmt::Debug::fmt(el, f),
+            Self::Section(el) => std::fmt::Debug::fmt(el, f),
+            Self::Input(el) => std::fmt::Debug::fmt(el, f),
+            Self::RubyAnnotation(el) => std::fmt::Debug::fmt(el, f),
+            Self::Main(el) => std::fmt::Debug::fmt(el, f),
+            Self::LineBreakOpportunity(el) => std::fmt::Debug::fmt(el, f),
+            Self::Meter(el) => std::fmt::Debug::fmt(el, f),
+            Self::Output(el) => std::fmt::Debug::fmt(el, f),
+            Self::Heading1(el) => std::fmt::Debug::fmt(el, f),
+            Self::Caption(el) => std::fmt::Debug::fmt(el, f),
+            Self::Fieldset(el) => std::fmt::Debug::fmt(el, f),
+            Self::Style(el) => std::fmt::Debug::fmt(el, f),
+            Self::BidirectionalTextOverride(el) => std::fmt::Debug::fmt(el, f),
+            Self::ImageMapArea(el) => std::fmt::Debug::fmt(el, f),
+            Self::FigureCaption(el) => std::fmt::Debug::fmt(el, f),
+            Self::SubScript(el) => std::fmt::Debug::fmt(el, f),
+            Self::SampleOutput(el) => std::fmt::Debug::fmt(el, f),
+            Self::StrikeThrough(el) => std::fmt::Debug::fmt(el, f),
+            Self::Slot(el) => std::fmt::Debug::fmt(el, f),
+            Self::RubyText(el) => std::fmt::Debug::fmt(el, f),
+            Self::OrderedList(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableFoot(el) => std::fmt::Debug::fmt(el, f),
+            Self::DescriptionDetails(el) => std::fmt::Debug::fmt(el, f),
+            Self::ThematicBreak(el) => std::fmt::Debug::fmt(el, f),
+            Self::Italic(el) => std::fmt::Debug::fmt(el, f),
+            Self::Data(el) => std::fmt::Debug::fmt(el, f),
+            Self::Dialog(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableColumn(el) => std::fmt::Debug::fmt(el, f),
+            Self::HeadingGroup(el) => std::fmt::Debug::fmt(el, f),
+            Self::Address(el) => std::fmt::Debug::fmt(el, f),
+            Self::Variable(el) => std::fmt::Debug::fmt(el, f),
+            Self::Object(el) => std::fmt::Debug::fmt(el, f),
+            Self::Division(el) => std::fmt::Debug::fmt(el, f),
+            Self::Heading3(el) => std::fmt::Debug::fmt(el, f),
+            Self::Time(el) => std::fmt::Debug::fmt(el, f),
+            Self::ListItem(el) => std::fmt::Debug::fmt(el, f),
+            Self::Emphasis(el) => std::fmt::Debug::fmt(el, f),
+            Self::Menu(el) => std::fmt::Debug::fmt(el, f),
+            Self::DeletedText(el) => std::fmt::Debug::fmt(el, f),
+            Self::Button(el) => std::fmt::Debug::fmt(el, f),
+            Self::Details(el) => std::fmt::Debug::fmt(el, f),
+            Self::Paragraph(el) => std::fmt::Debug::fmt(el, f),
+            Self::OptionGroup(el) => std::fmt::Debug::fmt(el, f),
+            Self::Quotation(el) => std::fmt::Debug::fmt(el, f),
+            Self::LineBreak(el) => std::fmt::Debug::fmt(el, f),
+            Self::Table(el) => std::fmt::Debug::fmt(el, f),
+            Self::Option(el) => std::fmt::Debug::fmt(el, f),
+            Self::NoScript(el) => std::fmt::Debug::fmt(el, f),
+            Self::Heading2(el) => std::fmt::Debug::fmt(el, f),
+            Self::Article(el) => std::fmt::Debug::fmt(el, f),
+            Self::Body(el) => std::fmt::Debug::fmt(el, f),
+            Self::TextArea(el) => std::fmt::Debug::fmt(el, f),
+            Self::Iframe(el) => std::fmt::Debug::fmt(el, f),
+            Self::Video(el) => std::fmt::Debug::fmt(el, f),
+            Self::Image(el) => std::fmt::Debug::fmt(el, f),
+            Self::Progress(el) => std::fmt::Debug::fmt(el, f),
+            Self::Title(el) => std::fmt::Debug::fmt(el, f),
+            Self::InsertedText(el) => std::fmt::Debug::fmt(el, f),
+            Self::Heading5(el) => std::fmt::Debug::fmt(el, f),
+            Self::Base(el) => std::fmt::Debug::fmt(el, f),
+            Self::Picture(el) => std::fmt::Debug::fmt(el, f),
+            Self::TextTrack(el) => std::fmt::Debug::fmt(el, f),
+            Self::KeyboardInput(el) => std::fmt::Debug::fmt(el, f),
+            Self::Audio(el) => std::fmt::Debug::fmt(el, f),
+            Self::Html(el) => std::fmt::Debug::fmt(el, f),
+            Self::DescriptionTerm(el) => std::fmt::Debug::fmt(el, f),
+            Self::DataList(el) => std::fmt::Debug::fmt(el, f),
+            Self::Link(el) => std::fmt::Debug::fmt(el, f),
+            Self::PreformattedText(el) => std::fmt::Debug::fmt(el, f),
+            Self::Label(el) => std::fmt::Debug::fmt(el, f),
+            Self::Figure(el) => std::fmt::Debug::fmt(el, f),
+            Self::Strong(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableHeader(el) => std::fmt::Debug::fmt(el, f),
+            Self::Form(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableHead(el) => std::fmt::Debug::fmt(el, f),
+            Self::RubyFallbackParenthesis(el) => std::fmt::Debug::fmt(el, f),
+            Self::ImageMap(el) => std::fmt::Debug::fmt(el, f),
+            Self::Cite(el) => std::fmt::Debug::fmt(el, f),
+            Self::MarkText(el) => std::fmt::Debug::fmt(el, f),
+            Self::DescriptionList(el) => std::fmt::Debug::fmt(el, f),
+            Self::TableCell(el) => std::fmt::Debug::fmt(el, f),
+            Self::Meta(el) => std::fmt::Debug::fmt(el, f),
+            Self::Bold(el) => std::fmt::Debug::fmt(el, f),
+            Self::Heading4(el) => std::fmt::Debug::fmt(el, f),
+            Self::Select(el) => std::fmt::Debug::fmt(el, f),
+            Self::Legend(el) => std::fmt::Debug::fmt(el, f),
+            Self::Annotation(el) => std::fmt::Debug::fmt(el, f),
+            Self::AnnotationXml(el) => std::fmt::Debug::fmt(el, f),
+            Self::Maction(el) => std::fmt::Debug::fmt(el, f),
+            Self::Math(el) => std::fmt::Debug::fmt(el, f),
+            Self::Merror(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mfrac(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mi(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mmultiscripts(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mn(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mo(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mover(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mpadded(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mphantom(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mprescripts(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mroot(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mrow(el) => std::fmt::Debug::fmt(el, f),
+            Self::Ms(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mspace(el) => std::fmt::Debug::fmt(el, f),
+            Self::Msqrt(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mstyle(el) => std::fmt::Debug::fmt(el, f),
+            Self::Msub(el) => std::fmt::Debug::fmt(el, f),
+            Self::Msubsup(el) => std::fmt::Debug::fmt(el, f),
+            Self::Msup(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mtable(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mtd(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mtext(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mtr(el) => std::fmt::Debug::fmt(el, f),
+            Self::Munder(el) => std::fmt::Debug::fmt(el, f),
+            Self::Munderover(el) => std::fmt::Debug::fmt(el, f),
+            Self::Semantics(el) => std::fmt::Debug::fmt(el, f),
+            Self::SvgAnchor(el) => std::fmt::Debug::fmt(el, f),
+            Self::Circle(el) => std::fmt::Debug::fmt(el, f),
+            Self::ClipPath(el) => std::fmt::Debug::fmt(el, f),
+            Self::Defs(el) => std::fmt::Debug::fmt(el, f),
+            Self::Desc(el) => std::fmt::Debug::fmt(el, f),
+            Self::Ellipse(el) => std::fmt::Debug::fmt(el, f),
+            Self::ForeignObject(el) => std::fmt::Debug::fmt(el, f),
+            Self::G(el) => std::fmt::Debug::fmt(el, f),
+            Self::SvgImage(el) => std::fmt::Debug::fmt(el, f),
+            Self::Line(el) => std::fmt::Debug::fmt(el, f),
+            Self::LinearGradient(el) => std::fmt::Debug::fmt(el, f),
+            Self::Marker(el) => std::fmt::Debug::fmt(el, f),
+            Self::Mask(el) => std::fmt::Debug::fmt(el, f),
+            Self::Metadata(el) => std::fmt::Debug::fmt(el, f),
+            Self::Path(el) => std::fmt::Debug::fmt(el, f),
+            Self::Pattern(el) => std::fmt::Debug::fmt(el, f),
+            Self::Polygon(el) => std::fmt::Debug::fmt(el, f),
+            Self::Polyline(el) => std::fmt::Debug::fmt(el, f),
+            Self::RadialGradient(el) => std::fmt::Debug::fmt(el, f),
+            Self::Rect(el) => std::fmt::Debug::fmt(el, f),
+            Self::SvgScript(el) => std::fmt::Debug::fmt(el, f),
+            Self::Stop(el) => std::fmt::Debug::fmt(el, f),
+            Self::SvgStyle(el) => std::fmt::Debug::fmt(el, f),
+            Self::Svg(el) => std::fmt::Debug::fmt(el, f),
+            Self::Switch(el) => std::fmt::Debug::fmt(el, f),
+            Self::Symbol(el) => std::fmt::Debug::fmt(el, f),
+            Self::SvgText(el) => std::fmt::Debug::fmt(el, f),
+            Self::TextPath(el) => std::fmt::Debug::fmt(el, f),
+            Self::SvgTitle(el) => std::fmt::Debug::fmt(el, f),
+            Self::Tspan(el) => std::fmt::Debug::fmt(el, f),
+            Self::Use(el) => std::fmt::Debug::fmt(el, f),
+            Self::View(el) => std::fmt::Debug::fmt(el, f),
+        }
+    }
+}
+impl std::fmt::Display for AnyElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.as_visit(), f)
+    }
+}
@@ -0,0 +1,2838 @@
+//! The ARIA roles of elements, and the properties they support.
+/// The properties which are supported regardless of the role of an
+/// element.
+pub(crate) const GLOBAL_PROPERTIES: &[&str] = &[
+    "aria-atomic",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-live",
+    "aria-owns",
+    "aria-relevant",
+    "aria-roledescription",
+];
+/// The concrete roles and the properties they support, sorted by
+/// name.
+pub(crate) const ROLES: &[(&str, &[&str])] = &[
+    (
+        "alert",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "alertdialog",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-modal",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "application",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "article",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "associationlist",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "associationlistitemkey",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-level",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "associationlistitemvalue",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "banner",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "blockquote",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "button",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-pressed",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "caption",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "cell",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colindex",
+            "aria-colindextext",
+            "aria-colspan",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-rowindex",
+            "aria-rowindextext",
+            "aria-rowspan",
+        ],
+    ),
+    (
+        "checkbox",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "code",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "columnheader",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colindex",
+            "aria-colindextext",
+            "aria-colspan",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+            "aria-rowindex",
+            "aria-rowindextext",
+            "aria-rowspan",
+            "aria-selected",
+            "aria-sort",
+        ],
+    ),
+    (
+        "combobox",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-autocomplete",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "comment",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-level",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "complementary",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "contentinfo",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "definition",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "deletion",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "dialog",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-modal",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "directory",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "document",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "emphasis",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "feed",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "figure",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "form",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "generic",
+        &[
+            "aria-atomic",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+        ],
+    ),
+    (
+        "grid",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colcount",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiselectable",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-rowcount",
+        ],
+    ),
+    (
+        "gridcell",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colindex",
+            "aria-colindextext",
+            "aria-colspan",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+            "aria-rowindex",
+            "aria-rowindextext",
+            "aria-rowspan",
+            "aria-selected",
+        ],
+    ),
+    (
+        "group",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "heading",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-level",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    ("image", &[]),
+    (
+        "img",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "insertion",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "link",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "list",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "listbox",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiselectable",
+            "aria-orientation",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "listitem",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "log",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "main",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "mark",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "marquee",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "math",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "menu",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "menubar",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "menuitem",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "menuitemcheckbox",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "menuitemradio",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "meter",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-valuemax",
+            "aria-valuemin",
+            "aria-valuenow",
+            "aria-valuetext",
+        ],
+    ),
+    (
+        "navigation",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "none",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "note",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "option",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-selected",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "paragraph",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "presentation",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "progressbar",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-valuemax",
+            "aria-valuemin",
+            "aria-valuenow",
+            "aria-valuetext",
+        ],
+    ),
+    (
+        "radio",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "radiogroup",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "region",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "row",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colindex",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-level",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-rowindex",
+            "aria-rowindextext",
+            "aria-selected",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "rowgroup",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "rowheader",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colindex",
+            "aria-colindextext",
+            "aria-colspan",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+            "aria-rowindex",
+            "aria-rowindextext",
+            "aria-rowspan",
+            "aria-selected",
+            "aria-sort",
+        ],
+    ),
+    (
+        "scrollbar",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-valuemax",
+            "aria-valuemin",
+            "aria-valuenow",
+            "aria-valuetext",
+        ],
+    ),
+    (
+        "search",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "searchbox",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-autocomplete",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiline",
+            "aria-owns",
+            "aria-placeholder",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "separator",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-valuemax",
+            "aria-valuemin",
+            "aria-valuenow",
+            "aria-valuetext",
+        ],
+    ),
+    (
+        "slider",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-valuemax",
+            "aria-valuemin",
+            "aria-valuenow",
+            "aria-valuetext",
+        ],
+    ),
+    (
+        "spinbutton",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+            "aria-valuemax",
+            "aria-valuemin",
+            "aria-valuenow",
+            "aria-valuetext",
+        ],
+    ),
+    (
+        "status",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "strong",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "subscript",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "suggestion",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "superscript",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "switch",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "tab",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-selected",
+            "aria-setsize",
+        ],
+    ),
+    (
+        "table",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colcount",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-rowcount",
+        ],
+    ),
+    (
+        "tablist",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiselectable",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "tabpanel",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "term",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "textbox",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-autocomplete",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiline",
+            "aria-owns",
+            "aria-placeholder",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "time",
+        &[
+            "aria-atomic",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "timer",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "toolbar",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "tooltip",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-owns",
+            "aria-relevant",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "tree",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiselectable",
+            "aria-orientation",
+            "aria-owns",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+        ],
+    ),
+    (
+        "treegrid",
+        &[
+            "aria-activedescendant",
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-colcount",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-live",
+            "aria-multiselectable",
+            "aria-orientation",
+            "aria-owns",
+            "aria-readonly",
+            "aria-relevant",
+            "aria-required",
+            "aria-roledescription",
+            "aria-rowcount",
+        ],
+    ),
+    (
+        "treeitem",
+        &[
+            "aria-atomic",
+            "aria-braillelabel",
+            "aria-brailleroledescription",
+            "aria-busy",
+            "aria-checked",
+            "aria-controls",
+            "aria-current",
+            "aria-describedby",
+            "aria-description",
+            "aria-details",
+            "aria-disabled",
+            "aria-dropeffect",
+            "aria-errormessage",
+            "aria-expanded",
+            "aria-flowto",
+            "aria-grabbed",
+            "aria-haspopup",
+            "aria-hidden",
+            "aria-invalid",
+            "aria-keyshortcuts",
+            "aria-label",
+            "aria-labelledby",
+            "aria-level",
+            "aria-live",
+            "aria-owns",
+            "aria-posinset",
+            "aria-relevant",
+            "aria-roledescription",
+            "aria-selected",
+            "aria-setsize",
+        ],
+    ),
+];
+/// The roles which can get their name from their contents, sorted by
+/// name.
+pub(crate) const NAME_FROM_CONTENTS: &[&str] = &[
+    "button",
+    "cell",
+    "checkbox",
+    "columnheader",
+    "comment",
+    "gridcell",
+    "heading",
+    "link",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "row",
+    "rowheader",
+    "switch",
+    "tab",
+    "tooltip",
+    "treeitem",
+];
+/// The roles an HTML element can have when it has no `role` attribute.
+/// Which of them applies depends on the element's attributes and
+/// ancestors.
+pub(crate) fn implicit_roles(tag_name: &str) -> &'static [&'static str] {
+    match tag_name {
+        "sup" => &["superscript"],
+        "summary" => &["button"],
+        "a" => &["generic", "link"],
+        "tr" => &["row"],
+        "aside" => &["complementary"],
+        "nav" => &["navigation"],
+        "tbody" => &["rowgroup"],
+        "header" => &["banner", "generic"],
+        "dfn" => &["term"],
+        "span" => &["generic"],
+        "ul" => &["list"],
+        "h6" => &["heading"],
+        "bdi" => &["generic"],
+        "small" => &["generic"],
+        "search" => &["search"],
+        "footer" => &["contentinfo", "generic"],
+        "u" => &["generic"],
+        "code" => &["code"],
+        "blockquote" => &["blockquote"],
+        "section" => &["generic", "region"],
+        "input" => {
+            &[
+                "button",
+                "checkbox",
+                "combobox",
+                "radio",
+                "searchbox",
+                "slider",
+                "spinbutton",
+                "textbox",
+            ]
+        }
+        "main" => &["main"],
+        "meter" => &["meter"],
+        "output" => &["status"],
+        "h1" => &["heading"],
+        "caption" => &["caption"],
+        "fieldset" => &["group"],
+        "bdo" => &["generic"],
+        "area" => &["generic", "link"],
+        "sub" => &["subscript"],
+        "samp" => &["generic"],
+        "s" => &["deletion"],
+        "ol" => &["list"],
+        "tfoot" => &["rowgroup"],
+        "hr" => &["separator"],
+        "i" => &["generic"],
+        "data" => &["generic"],
+        "dialog" => &["dialog"],
+        "hgroup" => &["generic"],
+        "address" => &["group"],
+        "div" => &["generic"],
+        "h3" => &["heading"],
+        "time" => &["time"],
+        "li" => &["generic", "listitem"],
+        "em" => &["emphasis"],
+        "menu" => &["list"],
+        "del" => &["deletion"],
+        "button" => &["button"],
+        "details" => &["group"],
+        "p" => &["paragraph"],
+        "optgroup" => &["group"],
+        "q" => &["generic"],
+        "table" => &["table"],
+        "option" => &["option"],
+        "h2" => &["heading"],
+        "article" => &["article"],
+        "body" => &["generic"],
+        "textarea" => &["textbox"],
+        "img" => &["img", "none", "presentation"],
+        "progress" => &["progressbar"],
+        "ins" => &["insertion"],
+        "h5" => &["heading"],
+        "html" => &["document"],
+        "datalist" => &["listbox"],
+        "pre" => &["generic"],
+        "figure" => &["figure"],
+        "strong" => &["strong"],
+        "th" => &["cell", "columnheader", "gridcell", "rowheader"],
+        "form" => &["form"],
+        "thead" => &["rowgroup"],
+        "td" => &["cell", "gridcell"],
+        "b" => &["generic"],
+        "h4" => &["heading"],
+        "select" => &["combobox", "listbox"],
+        _ => &[],
+    }
+}
@@ -64,15 +64,12 @@ pub mod element {
             self.sys.autocomplete = value.map(|v| v.into());
         }
         /// Get the value of the `checked` attribute
-        pub fn checked(&self) -> std::option::Option<&str> {
-            self.sys.checked.as_deref()
+        pub fn checked(&self) -> bool {
+            self.sys.checked
         }
         /// Set the value of the `checked` attribute
-        pub fn set_checked(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.checked = value.map(|v| v.into());
+        pub fn set_checked(&mut self, value: bool) {
+            self.sys.checked = value;
         }
         /// Get the value of the `dirname` attribute
         pub fn dirname(&self) -> std::option::Option<&str> {
@@ -86,15 +83,12 @@ pub mod element {
             self.sys.dirname = value.map(|v| v.into());
         }
         /// Get the value of the `disabled` attribute
-        pub fn disabled(&self) -> std::option::Option<&str> {
-            self.sys.disabled.as_deref()
+        pub fn disabled(&self) -> bool {
+            self.sys.disabled
         }
         /// Set the value of the `disabled` attribute
-        pub fn set_disabled(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.disabled = value.map(|v| v.into());
+        pub fn set_disabled(&mut self, value: bool) {
+            self.sys.disabled = value;
         }
         /// Get the value of the `form` attribute
         pub fn form(&self) -> std::option::Option<&str> {
@@ -119,37 +113,38 @@ pub mod element {
             self.sys.formaction = value.map(|v| v.into());
         }
         /// Get the value of the `formenctype` attribute
-        pub fn formenctype(&self) -> std::option::Option<&str> {
-            self.sys.formenctype.as_deref()
+        pub fn formenctype(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::FormEncoding> {
+            self.sys.formenctype.as_ref()
         }
         /// Set the value of the `formenctype` attribute
         pub fn set_formenctype(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::FormEncoding>,
         ) {
-            self.sys.formenctype = value.map(|v| v.into());
+            self.sys.formenctype = value;
         }
         /// Get the value of the `formmethod` attribute
-        pub fn formmethod(&self) -> std::option::Option<&str> {
-            self.sys.formmethod.as_deref()
+        pub fn formmethod(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::FormMethod> {
+            self.sys.formmethod.as_ref()
         }
         /// Set the value of the `formmethod` attribute
         pub fn set_formmethod(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::FormMethod>,
         ) {
-            self.sys.formmethod = value.map(|v| v.into());
+            self.sys.formmethod = value;
         }
         /// Get the value of the `formnovalidate` attribute
-        pub fn formnovalidate(&self) -> std::option::Option<&str> {
-            self.sys.formnovalidate.as_deref()
+        pub fn formnovalidate(&self) -> bool {
+            self.sys.formnovalidate
         }
         /// Set the value of the `formnovalidate` attribute
-        pub fn set_formnovalidate(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.formnovalidate = value.map(|v| v.into());
+        pub fn set_formnovalidate(&mut self, value: bool) {
+            self.sys.formnovalidate = value;
         }
         /// Get the value of the `formtarget` attribute
         pub fn formtarget(&self) -> std::option::Option<&str> {
@@ -229,15 +224,12 @@ pub mod element {
             self.sys.minlength = value.map(|v| v.into());
         }
         /// Get the value of the `multiple` attribute
-        pub fn multiple(&self) -> std::option::Option<&str> {
-            self.sys.multiple.as_deref()
+        pub fn multiple(&self) -> bool {
+            self.sys.multiple
         }
         /// Set the value of the `multiple` attribute
-        pub fn set_multiple(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.multiple = value.map(|v| v.into());
+        pub fn set_multiple(&mut self, value: bool) {
+            self.sys.multiple = value;
         }
         /// Get the value of the `name` attribute
         pub fn name(&self) -> std::option::Option<&str> {
@@ -284,37 +276,33 @@ pub mod element {
             self.sys.popovertarget = value.map(|v| v.into());
         }
         /// Get the value of the `popovertargetaction` attribute
-        pub fn popovertargetaction(&self) -> std::option::Option<&str> {
-            self.sys.popovertargetaction.as_deref()
+        pub fn popovertargetaction(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::PopoverTargetAction> {
+            self.sys.popovertargetaction.as_ref()
         }
         /// Set the value of the `popovertargetaction` attribute
         pub fn set_popovertargetaction(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::PopoverTargetAction>,
         ) {
-            self.sys.popovertargetaction = value.map(|v| v.into());
+            self.sys.popovertargetaction = value;
         }
         /// Get the value of the `readonly` attribute
-        pub fn readonly(&self) -> std::option::Option<&str> {
-            self.sys.readonly.as_deref()
+        pub fn readonly(&self) -> bool {
+            self.sys.readonly
         }
         /// Set the value of the `readonly` attribute
-        pub fn set_readonly(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.readonly = value.map(|v| v.into());
+        pub fn set_readonly(&mut self, value: bool) {
+            self.sys.readonly = value;
         }
         /// Get the value of the `required` attribute
-        pub fn required(&self) -> std::option::Option<&str> {
-            self.sys.required.as_deref()
+        pub fn required(&self) -> bool {
+            self.sys.required
         }
         /// Set the value of the `required` attribute
-        pub fn set_required(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.required = value.map(|v| v.into());
+        pub fn set_required(&mut self, value: bool) {
+            self.sys.required = value;
         }
         /// Get the value of the `size` attribute
         pub fn size(&self) -> std::option::Option<&str> {
@@ -350,15 +338,15 @@ pub mod element {
             self.sys.step = value.map(|v| v.into());
         }
         /// Get the value of the `type` attribute
-        pub fn type_(&self) -> std::option::Option<&str> {
-            self.sys.type_.as_deref()
+        pub fn type_(&self) -> std::option::Option<&html_sys::attributes::InputType> {
+            self.sys.type_.as_ref()
         }
         /// Set the value of the `type` attribute
         pub fn set_type_(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::InputType>,
         ) {
-            self.sys.type_ = value.map(|v| v.into());
+            self.sys.type_ = value;
         }
         /// Get the value of the `value` attribute
         pub fn value(&self) -> std::option::Option<&str> {
@@ -383,15 +371,15 @@ pub mod element {
             self.sys.width = value.map(|v| v.into());
         }
         /// Get the value of the `role` attribute
-        pub fn role(&self) -> std::option::Option<&str> {
-            self.sys.role.as_deref()
+        pub fn role(&self) -> std::option::Option<&html_sys::attributes::InputRole> {
+            self.sys.role.as_ref()
         }
         /// Set the value of the `role` attribute
         pub fn set_role(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::InputRole>,
         ) {
-            self.sys.role = value.map(|v| v.into());
+            self.sys.role = value;
         }
         /// Get the value of the `aria-activedescendant` attribute
         pub fn aria_active_descendant_element(&self) -> std::option::Option<&str> {
@@ -413,15 +401,17 @@ pub mod element {
             self.sys.aria_atomic = value;
         }
         /// Get the value of the `aria-autocomplete` attribute
-        pub fn aria_auto_complete(&self) -> std::option::Option<&str> {
-            self.sys.aria_auto_complete.as_deref()
+        pub fn aria_auto_complete(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaAutoComplete> {
+            self.sys.aria_auto_complete.as_ref()
         }
         /// Set the value of the `aria-autocomplete` attribute
         pub fn set_aria_auto_complete(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaAutoComplete>,
         ) {
-            self.sys.aria_auto_complete = value.map(|v| v.into());
+            self.sys.aria_auto_complete = value;
         }
         /// Get the value of the `aria-braillelabel` attribute
         pub fn aria_braille_label(&self) -> std::option::Option<&str> {
@@ -454,15 +444,17 @@ pub mod element {
             self.sys.aria_busy = value;
         }
         /// Get the value of the `aria-checked` attribute
-        pub fn aria_checked(&self) -> std::option::Option<&str> {
-            self.sys.aria_checked.as_deref()
+        pub fn aria_checked(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Tristate> {
+            self.sys.aria_checked.as_ref()
         }
         /// Set the value of the `aria-checked` attribute
         pub fn set_aria_checked(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Tristate>,
         ) {
-            self.sys.aria_checked = value.map(|v| v.into());
+            self.sys.aria_checked = value;
         }
         /// Get the value of the `aria-colindex` attribute
         pub fn aria_col_index(&self) -> std::option::Option<i64> {
@@ -492,37 +484,39 @@ pub mod element {
             self.sys.aria_col_span = value;
         }
         /// Get the value of the `aria-controls` attribute
-        pub fn aria_controls_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_controls_elements.as_deref()
+        pub fn aria_controls_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_controls_elements
         }
         /// Set the value of the `aria-controls` attribute
         pub fn set_aria_controls_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_controls_elements = value.map(|v| v.into());
+            self.sys.aria_controls_elements = value;
         }
         /// Get the value of the `aria-current` attribute
-        pub fn aria_current(&self) -> std::option::Option<&str> {
-            self.sys.aria_current.as_deref()
+        pub fn aria_current(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaCurrent> {
+            self.sys.aria_current.as_ref()
         }
         /// Set the value of the `aria-current` attribute
         pub fn set_aria_current(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaCurrent>,
         ) {
-            self.sys.aria_current = value.map(|v| v.into());
+            self.sys.aria_current = value;
         }
         /// Get the value of the `aria-describedby` attribute
-        pub fn aria_described_by_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_described_by_elements.as_deref()
+        pub fn aria_described_by_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_described_by_elements
         }
         /// Set the value of the `aria-describedby` attribute
         pub fn set_aria_described_by_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_described_by_elements = value.map(|v| v.into());
+            self.sys.aria_described_by_elements = value;
         }
         /// Get the value of the `aria-description` attribute
         pub fn aria_description(&self) -> std::option::Option<&str> {
@@ -536,15 +530,15 @@ pub mod element {
             self.sys.aria_description = value.map(|v| v.into());
         }
         /// Get the value of the `aria-details` attribute
-        pub fn aria_details_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_details_elements.as_deref()
+        pub fn aria_details_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_details_elements
         }
         /// Set the value of the `aria-details` attribute
         pub fn set_aria_details_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_details_elements = value.map(|v| v.into());
+            self.sys.aria_details_elements = value;
         }
         /// Get the value of the `aria-disabled` attribute
         pub fn aria_disabled(&self) -> bool {
@@ -555,83 +549,87 @@ pub mod element {
             self.sys.aria_disabled = value;
         }
         /// Get the value of the `aria-dropeffect` attribute
-        pub fn aria_drop_effect(&self) -> std::option::Option<&str> {
-            self.sys.aria_drop_effect.as_deref()
+        pub fn aria_drop_effect(&self) -> &[html_sys::attributes::AriaDropEffect] {
+            &self.sys.aria_drop_effect
         }
         /// Set the value of the `aria-dropeffect` attribute
         pub fn set_aria_drop_effect(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<html_sys::attributes::AriaDropEffect>,
         ) {
-            self.sys.aria_drop_effect = value.map(|v| v.into());
+            self.sys.aria_drop_effect = value;
         }
         /// Get the value of the `aria-errormessage` attribute
-        pub fn aria_error_message_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_error_message_elements.as_deref()
+        pub fn aria_error_message_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_error_message_elements
         }
         /// Set the value of the `aria-errormessage` attribute
         pub fn set_aria_error_message_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_error_message_elements = value.map(|v| v.into());
+            self.sys.aria_error_message_elements = value;
         }
         /// Get the value of the `aria-expanded` attribute
-        pub fn aria_expanded(&self) -> bool {
+        pub fn aria_expanded(&self) -> std::option::Option<bool> {
             self.sys.aria_expanded
         }
         /// Set the value of the `aria-expanded` attribute
-        pub fn set_aria_expanded(&mut self, value: bool) {
+        pub fn set_aria_expanded(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_expanded = value;
         }
         /// Get the value of the `aria-flowto` attribute
-        pub fn aria_flow_to_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_flow_to_elements.as_deref()
+        pub fn aria_flow_to_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_flow_to_elements
         }
         /// Set the value of the `aria-flowto` attribute
         pub fn set_aria_flow_to_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_flow_to_elements = value.map(|v| v.into());
+            self.sys.aria_flow_to_elements = value;
         }
         /// Get the value of the `aria-grabbed` attribute
-        pub fn aria_grabbed(&self) -> bool {
+        pub fn aria_grabbed(&self) -> std::option::Option<bool> {
             self.sys.aria_grabbed
         }
         /// Set the value of the `aria-grabbed` attribute
-        pub fn set_aria_grabbed(&mut self, value: bool) {
+        pub fn set_aria_grabbed(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_grabbed = value;
         }
         /// Get the value of the `aria-haspopup` attribute
-        pub fn aria_has_popup(&self) -> std::option::Option<&str> {
-            self.sys.aria_has_popup.as_deref()
+        pub fn aria_has_popup(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaHasPopup> {
+            self.sys.aria_has_popup.as_ref()
         }
         /// Set the value of the `aria-haspopup` attribute
         pub fn set_aria_has_popup(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaHasPopup>,
         ) {
-            self.sys.aria_has_popup = value.map(|v| v.into());
+            self.sys.aria_has_popup = value;
         }
         /// Get the value of the `aria-hidden` attribute
-        pub fn aria_hidden(&self) -> bool {
+        pub fn aria_hidden(&self) -> std::option::Option<bool> {
             self.sys.aria_hidden
         }
         /// Set the value of the `aria-hidden` attribute
-        pub fn set_aria_hidden(&mut self, value: bool) {
+        pub fn set_aria_hidden(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_hidden = value;
         }
         /// Get the value of the `aria-invalid` attribute
-        pub fn aria_invalid(&self) -> std::option::Option<&str> {
-            self.sys.aria_invalid.as_deref()
+        pub fn aria_invalid(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaInvalid> {
+            self.sys.aria_invalid.as_ref()
         }
         /// Set the value of the `aria-invalid` attribute
         pub fn set_aria_invalid(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaInvalid>,
         ) {
-            self.sys.aria_invalid = value.map(|v| v.into());
+            self.sys.aria_invalid = value;
         }
         /// Get the value of the `aria-keyshortcuts` attribute
         pub fn aria_key_shortcuts(&self) -> std::option::Option<&str> {
@@ -656,15 +654,15 @@ pub mod element {
             self.sys.aria_label = value.map(|v| v.into());
         }
         /// Get the value of the `aria-labelledby` attribute
-        pub fn aria_labelled_by_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_labelled_by_elements.as_deref()
+        pub fn aria_labelled_by_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_labelled_by_elements
         }
         /// Set the value of the `aria-labelledby` attribute
         pub fn set_aria_labelled_by_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_labelled_by_elements = value.map(|v| v.into());
+            self.sys.aria_labelled_by_elements = value;
         }
         /// Get the value of the `aria-level` attribute
         pub fn aria_level(&self) -> std::option::Option<i64> {
@@ -675,15 +673,15 @@ pub mod element {
             self.sys.aria_level = value;
         }
         /// Get the value of the `aria-live` attribute
-        pub fn aria_live(&self) -> std::option::Option<&str> {
-            self.sys.aria_live.as_deref()
+        pub fn aria_live(&self) -> std::option::Option<&html_sys::attributes::AriaLive> {
+            self.sys.aria_live.as_ref()
         }
         /// Set the value of the `aria-live` attribute
         pub fn set_aria_live(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaLive>,
         ) {
-            self.sys.aria_live = value.map(|v| v.into());
+            self.sys.aria_live = value;
         }
         /// Get the value of the `aria-multiline` attribute
         pub fn aria_multi_line(&self) -> bool {
@@ -694,26 +692,28 @@ pub mod element {
             self.sys.aria_multi_line = value;
         }
         /// Get the value of the `aria-orientation` attribute
-        pub fn aria_orientation(&self) -> std::option::Option<&str> {
-            self.sys.aria_orientation.as_deref()
+        pub fn aria_orientation(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaOrientation> {
+            self.sys.aria_orientation.as_ref()
         }
         /// Set the value of the `aria-orientation` attribute
         pub fn set_aria_orientation(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaOrientation>,
         ) {
-            self.sys.aria_orientation = value.map(|v| v.into());
+            self.sys.aria_orientation = value;
         }
         /// Get the value of the `aria-owns` attribute
-        pub fn aria_owns_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_owns_elements.as_deref()
+        pub fn aria_owns_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_owns_elements
         }
         /// Set the value of the `aria-owns` attribute
         pub fn set_aria_owns_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_owns_elements = value.map(|v| v.into());
+            self.sys.aria_owns_elements = value;
         }
         /// Get the value of the `aria-placeholder` attribute
         pub fn aria_placeholder(&self) -> std::option::Option<&str> {
@@ -735,15 +735,17 @@ pub mod element {
             self.sys.aria_pos_in_set = value;
         }
         /// Get the value of the `aria-pressed` attribute
-        pub fn aria_pressed(&self) -> std::option::Option<&str> {
-            self.sys.aria_pressed.as_deref()
+        pub fn aria_pressed(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Tristate> {
+            self.sys.aria_pressed.as_ref()
         }
         /// Set the value of the `aria-pressed` attribute
         pub fn set_aria_pressed(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Tristate>,
         ) {
-            self.sys.aria_pressed = value.map(|v| v.into());
+            self.sys.aria_pressed = value;
         }
         /// Get the value of the `aria-readonly` attribute
         pub fn aria_read_only(&self) -> bool {
@@ -754,15 +756,15 @@ pub mod element {
             self.sys.aria_read_only = value;
         }
         /// Get the value of the `aria-relevant` attribute
-        pub fn aria_relevant(&self) -> std::option::Option<&str> {
-            self.sys.aria_relevant.as_deref()
+        pub fn aria_relevant(&self) -> &[html_sys::attributes::AriaRelevant] {
+            &self.sys.aria_relevant
         }
         /// Set the value of the `aria-relevant` attribute
         pub fn set_aria_relevant(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<html_sys::attributes::AriaRelevant>,
         ) {
-            self.sys.aria_relevant = value.map(|v| v.into());
+            self.sys.aria_relevant = value;
         }
         /// Get the value of the `aria-required` attribute
         pub fn aria_required(&self) -> bool {
@@ -811,11 +813,11 @@ pub mod element {
             self.sys.aria_row_span = value;
         }
         /// Get the value of the `aria-selected` attribute
-        pub fn aria_selected(&self) -> bool {
+        pub fn aria_selected(&self) -> std::option::Option<bool> {
             self.sys.aria_selected
         }
         /// Set the value of the `aria-selected` attribute
-        pub fn set_aria_selected(&mut self, value: bool) {
+        pub fn set_aria_selected(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_selected = value;
         }
         /// Get the value of the `aria-setsize` attribute
@@ -873,15 +875,17 @@ pub mod element {
             self.sys.access_key = value.map(|v| v.into());
         }
         /// Get the value of the `autocapitalize` attribute
-        pub fn auto_capitalize(&self) -> std::option::Option<&str> {
-            self.sys.auto_capitalize.as_deref()
+        pub fn auto_capitalize(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AutoCapitalize> {
+            self.sys.auto_capitalize.as_ref()
         }
         /// Set the value of the `autocapitalize` attribute
         pub fn set_auto_capitalize(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AutoCapitalize>,
         ) {
-            self.sys.auto_capitalize = value.map(|v| v.into());
+            self.sys.auto_capitalize = value;
         }
         /// Get the value of the `autofocus` attribute
         pub fn autofocus(&self) -> bool {
@@ -903,45 +907,56 @@ pub mod element {
             self.sys.class = value.map(|v| v.into());
         }
         /// Get the value of the `contenteditable` attribute
-        pub fn content_editable(&self) -> std::option::Option<&str> {
-            self.sys.content_editable.as_deref()
+        pub fn content_editable(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::ContentEditable> {
+            self.sys.content_editable.as_ref()
         }
         /// Set the value of the `contenteditable` attribute
         pub fn set_content_editable(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::ContentEditable>,
         ) {
-            self.sys.content_editable = value.map(|v| v.into());
+            self.sys.content_editable = value;
         }
         /// Get the value of the `dir` attribute
-        pub fn direction(&self) -> std::option::Option<&str> {
-            self.sys.direction.as_deref()
+        pub fn direction(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Direction> {
+            self.sys.direction.as_ref()
         }
         /// Set the value of the `dir` attribute
         pub fn set_direction(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Direction>,
         ) {
-            self.sys.direction = value.map(|v| v.into());
+            self.sys.direction = value;
         }
         /// Get the value of the `draggable` attribute
-        pub fn draggable(&self) -> bool {
-            self.sys.draggable
+        pub fn draggable(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Draggable> {
+            self.sys.draggable.as_ref()
         }
         /// Set the value of the `draggable` attribute
-        pub fn set_draggable(&mut self, value: bool) {
+        pub fn set_draggable(
+            &mut self,
+            value: std::option::Option<html_sys::attributes::Draggable>,
+        ) {
             self.sys.draggable = value;
         }
         /// Get the value of the `enterkeyhint` attribute
-        pub fn enter_key_hint(&self) -> std::option::Option<&str> {
-            self.sys.enter_key_hint.as_deref()
+        pub fn enter_key_hint(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::EnterKeyHint> {
+            self.sys.enter_key_hint.as_ref()
         }
         /// Set the value of the `enterkeyhint` attribute
         pub fn set_enter_key_hint(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::EnterKeyHint>,
         ) {
-            self.sys.enter_key_hint = value.map(|v| v.into());
+            self.sys.enter_key_hint = value;
         }
         /// Get the value of the `exportparts` attribute
         pub fn export_parts(&self) -> std::option::Option<&str> {
@@ -955,15 +970,15 @@ pub mod element {
             self.sys.export_parts = value.map(|v| v.into());
         }
         /// Get the value of the `hidden` attribute
-        pub fn hidden(&self) -> std::option::Option<&str> {
-            self.sys.hidden.as_deref()
+        pub fn hidden(&self) -> std::option::Option<&html_sys::attributes::Hidden> {
+            self.sys.hidden.as_ref()
         }
         /// Set the value of the `hidden` attribute
         pub fn set_hidden(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Hidden>,
         ) {
-            self.sys.hidden = value.map(|v| v.into());
+            self.sys.hidden = value;
         }
         /// Get the value of the `id` attribute
         pub fn id(&self) -> std::option::Option<&str> {
@@ -985,15 +1000,17 @@ pub mod element {
             self.sys.inert = value;
         }
         /// Get the value of the `inputmode` attribute
-        pub fn input_mode(&self) -> std::option::Option<&str> {
-            self.sys.input_mode.as_deref()
+        pub fn input_mode(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::InputMode> {
+            self.sys.input_mode.as_ref()
         }
         /// Set the value of the `inputmode` attribute
         pub fn set_input_mode(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::InputMode>,
         ) {
-            self.sys.input_mode = value.map(|v| v.into());
+            self.sys.input_mode = value;
         }
         /// Get the value of the `is` attribute
         pub fn is_(&self) -> std::option::Option<&str> {
@@ -1040,15 +1057,12 @@ pub mod element {
             self.sys.item_ref = value.map(|v| v.into());
         }
         /// Get the value of the `itemscope` attribute
-        pub fn item_scope(&self) -> std::option::Option<&str> {
-            self.sys.item_scope.as_deref()
+        pub fn item_scope(&self) -> bool {
+            self.sys.item_scope
         }
         /// Set the value of the `itemscope` attribute
-        pub fn set_item_scope(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.item_scope = value.map(|v| v.into());
+        pub fn set_item_scope(&mut self, value: bool) {
+            self.sys.item_scope = value;
         }
         /// Get the value of the `itemtype` attribute
         pub fn item_type(&self) -> std::option::Option<&str> {
@@ -1106,15 +1120,17 @@ pub mod element {
             self.sys.slot = value.map(|v| v.into());
         }
         /// Get the value of the `spellcheck` attribute
-        pub fn spellcheck(&self) -> std::option::Option<&str> {
-            self.sys.spellcheck.as_deref()
+        pub fn spellcheck(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Spellcheck> {
+            self.sys.spellcheck.as_ref()
         }
         /// Set the value of the `spellcheck` attribute
         pub fn set_spellcheck(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Spellcheck>,
         ) {
-            self.sys.spellcheck = value.map(|v| v.into());
+            self.sys.spellcheck = value;
         }
         /// Get the value of the `style` attribute
         pub fn style(&self) -> std::option::Option<&str> {
@@ -1147,897 +1163,3169 @@ pub mod element {
             self.sys.title = value.map(|v| v.into());
         }
         /// Get the value of the `translate` attribute
-        pub fn translate(&self) -> bool {
-            self.sys.translate
+        pub fn translate(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Translate> {
+            self.sys.translate.as_ref()
         }
         /// Set the value of the `translate` attribute
-        pub fn set_translate(&mut self, value: bool) {
+        pub fn set_translate(
+            &mut self,
+            value: std::option::Option<html_sys::attributes::Translate>,
+        ) {
             self.sys.translate = value;
         }
-    }
-    impl crate::Render for Input {
-        fn render(
-            &self,
-            f: &mut std::fmt::Formatter<'_>,
-            depth: usize,
-        ) -> std::fmt::Result {
-            write!(f, "{:level$}", "", level = depth * 4)?;
-            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-            Ok(())
+        /// Get the value of the `onabort` attribute
+        pub fn onabort(&self) -> std::option::Option<&str> {
+            self.sys.onabort.as_deref()
         }
-    }
-    impl std::fmt::Debug for Input {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            crate::Render::render(self, f, 0)?;
-            Ok(())
+        /// Set the value of the `onabort` attribute
+        pub fn set_onabort(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onabort = value.map(|v| v.into());
         }
-    }
-    impl std::fmt::Display for Input {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            Ok(())
+        /// Get the value of the `onauxclick` attribute
+        pub fn onauxclick(&self) -> std::option::Option<&str> {
+            self.sys.onauxclick.as_deref()
         }
-    }
-    impl crate::HtmlElement for Input {
-        fn tag_name(&self) -> &'static str {
-            "input"
+        /// Set the value of the `onauxclick` attribute
+        pub fn set_onauxclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onauxclick = value.map(|v| v.into());
         }
-        fn attributes(
-            &self,
-        ) -> std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        > {
-            use html_sys::ElementDescription;
-            self.sys.attributes()
+        /// Get the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(&self) -> std::option::Option<&str> {
+            self.sys.onbeforeinput.as_deref()
         }
-        fn data(
-            &self,
-        ) -> std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        > {
-            use html_sys::ElementDescription;
-            self.sys.data()
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn set_onbeforeinput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforeinput = value.map(|v| v.into());
         }
-        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
-            vec![]
+        /// Get the value of the `onbeforematch` attribute
+        pub fn onbeforematch(&self) -> std::option::Option<&str> {
+            self.sys.onbeforematch.as_deref()
         }
-    }
-    impl crate::FlowContent for Input {}
-    impl crate::PhrasingContent for Input {}
-    impl crate::InteractiveContent for Input {}
-    impl crate::PalpableContent for Input {}
-    impl std::convert::Into<html_sys::forms::Input> for Input {
-        fn into(self) -> html_sys::forms::Input {
-            self.sys
+        /// Set the value of the `onbeforematch` attribute
+        pub fn set_onbeforematch(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforematch = value.map(|v| v.into());
         }
-    }
-    impl From<html_sys::forms::Input> for Input {
-        fn from(sys: html_sys::forms::Input) -> Self {
-            Self { sys }
+        /// Get the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(&self) -> std::option::Option<&str> {
+            self.sys.onbeforetoggle.as_deref()
         }
-    }
-}
-pub mod child {}
-pub mod builder {
-    /// A builder struct for Input
-    pub struct InputBuilder {
-        element: super::element::Input,
-    }
-    impl InputBuilder {
-        pub(crate) fn new(element: super::element::Input) -> Self {
-            Self { element }
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn set_onbeforetoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforetoggle = value.map(|v| v.into());
         }
-        /// Finish building the element
-        pub fn build(&mut self) -> super::element::Input {
-            self.element.clone()
+        /// Get the value of the `onblur` attribute
+        pub fn onblur(&self) -> std::option::Option<&str> {
+            self.sys.onblur.as_deref()
         }
-        /// Insert a `data-*` property
-        pub fn data(
+        /// Set the value of the `onblur` attribute
+        pub fn set_onblur(
             &mut self,
-            data_key: impl Into<std::borrow::Cow<'static, str>>,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut InputBuilder {
-            self.element.data_map_mut().insert(data_key.into(), value.into());
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onblur = value.map(|v| v.into());
         }
-        /// Set the value of the `accept` attribute
-        pub fn accept(
+        /// Get the value of the `oncancel` attribute
+        pub fn oncancel(&self) -> std::option::Option<&str> {
+            self.sys.oncancel.as_deref()
+        }
+        /// Set the value of the `oncancel` attribute
+        pub fn set_oncancel(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_accept(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncancel = value.map(|v| v.into());
         }
-        /// Set the value of the `alt` attribute
-        pub fn alt(
+        /// Get the value of the `oncanplay` attribute
+        pub fn oncanplay(&self) -> std::option::Option<&str> {
+            self.sys.oncanplay.as_deref()
+        }
+        /// Set the value of the `oncanplay` attribute
+        pub fn set_oncanplay(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_alt(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplay = value.map(|v| v.into());
         }
-        /// Set the value of the `autocomplete` attribute
-        pub fn autocomplete(
+        /// Get the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(&self) -> std::option::Option<&str> {
+            self.sys.oncanplaythrough.as_deref()
+        }
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn set_oncanplaythrough(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_autocomplete(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplaythrough = value.map(|v| v.into());
         }
-        /// Set the value of the `checked` attribute
-        pub fn checked(
+        /// Get the value of the `onchange` attribute
+        pub fn onchange(&self) -> std::option::Option<&str> {
+            self.sys.onchange.as_deref()
+        }
+        /// Set the value of the `onchange` attribute
+        pub fn set_onchange(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_checked(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onchange = value.map(|v| v.into());
         }
-        /// Set the value of the `dirname` attribute
-        pub fn dirname(
+        /// Get the value of the `onclick` attribute
+        pub fn onclick(&self) -> std::option::Option<&str> {
+            self.sys.onclick.as_deref()
+        }
+        /// Set the value of the `onclick` attribute
+        pub fn set_onclick(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_dirname(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclick = value.map(|v| v.into());
         }
-        /// Set the value of the `disabled` attribute
-        pub fn disabled(
+        /// Get the value of the `onclose` attribute
+        pub fn onclose(&self) -> std::option::Option<&str> {
+            self.sys.onclose.as_deref()
+        }
+        /// Set the value of the `onclose` attribute
+        pub fn set_onclose(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_disabled(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclose = value.map(|v| v.into());
         }
-        /// Set the value of the `form` attribute
-        pub fn form(
+        /// Get the value of the `oncontextlost` attribute
+        pub fn oncontextlost(&self) -> std::option::Option<&str> {
+            self.sys.oncontextlost.as_deref()
+        }
+        /// Set the value of the `oncontextlost` attribute
+        pub fn set_oncontextlost(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_form(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextlost = value.map(|v| v.into());
         }
-        /// Set the value of the `formaction` attribute
-        pub fn formaction(
+        /// Get the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(&self) -> std::option::Option<&str> {
+            self.sys.oncontextmenu.as_deref()
+        }
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn set_oncontextmenu(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_formaction(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextmenu = value.map(|v| v.into());
         }
-        /// Set the value of the `formenctype` attribute
-        pub fn formenctype(
+        /// Get the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(&self) -> std::option::Option<&str> {
+            self.sys.oncontextrestored.as_deref()
+        }
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn set_oncontextrestored(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_formenctype(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextrestored = value.map(|v| v.into());
         }
-        /// Set the value of the `formmethod` attribute
-        pub fn formmethod(
+        /// Get the value of the `oncopy` attribute
+        pub fn oncopy(&self) -> std::option::Option<&str> {
+            self.sys.oncopy.as_deref()
+        }
+        /// Set the value of the `oncopy` attribute
+        pub fn set_oncopy(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_formmethod(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncopy = value.map(|v| v.into());
         }
-        /// Set the value of the `formnovalidate` attribute
-        pub fn formnovalidate(
+        /// Get the value of the `oncuechange` attribute
+        pub fn oncuechange(&self) -> std::option::Option<&str> {
+            self.sys.oncuechange.as_deref()
+        }
+        /// Set the value of the `oncuechange` attribute
+        pub fn set_oncuechange(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut Self {
-            self.element.set_formnovalidate(Some(value.into()));
-            self
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncuechange = value.map(|v| v.into());
         }
-        /// Set the value of the `formtarget` attribute
-        pub fn formtarget(
+        /// Get the value of the `oncut` attribute
+        pub fn oncut(&self) -> std::option::Option<&str> {
+            self.sys.oncut.as_deref()
+        }
+        /// Set the value of the `oncut` attribute
+        pub fn set_oncut(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncut = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondblclick` attribute
+        pub fn ondblclick(&self) -> std::option::Option<&str> {
+            self.sys.ondblclick.as_deref()
+        }
+        /// Set the value of the `ondblclick` attribute
+        pub fn set_ondblclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondblclick = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondrag` attribute
+        pub fn ondrag(&self) -> std::option::Option<&str> {
+            self.sys.ondrag.as_deref()
+        }
+        /// Set the value of the `ondrag` attribute
+        pub fn set_ondrag(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrag = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragend` attribute
+        pub fn ondragend(&self) -> std::option::Option<&str> {
+            self.sys.ondragend.as_deref()
+        }
+        /// Set the value of the `ondragend` attribute
+        pub fn set_ondragend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragenter` attribute
+        pub fn ondragenter(&self) -> std::option::Option<&str> {
+            self.sys.ondragenter.as_deref()
+        }
+        /// Set the value of the `ondragenter` attribute
+        pub fn set_ondragenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragleave` attribute
+        pub fn ondragleave(&self) -> std::option::Option<&str> {
+            self.sys.ondragleave.as_deref()
+        }
+        /// Set the value of the `ondragleave` attribute
+        pub fn set_ondragleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragover` attribute
+        pub fn ondragover(&self) -> std::option::Option<&str> {
+            self.sys.ondragover.as_deref()
+        }
+        /// Set the value of the `ondragover` attribute
+        pub fn set_ondragover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragover = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragstart` attribute
+        pub fn ondragstart(&self) -> std::option::Option<&str> {
+            self.sys.ondragstart.as_deref()
+        }
+        /// Set the value of the `ondragstart` attribute
+        pub fn set_ondragstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondrop` attribute
+        pub fn ondrop(&self) -> std::option::Option<&str> {
+            self.sys.ondrop.as_deref()
+        }
+        /// Set the value of the `ondrop` attribute
+        pub fn set_ondrop(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrop = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondurationchange` attribute
+        pub fn ondurationchange(&self) -> std::option::Option<&str> {
+            self.sys.ondurationchange.as_deref()
+        }
+        /// Set the value of the `ondurationchange` attribute
+        pub fn set_ondurationchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondurationchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onemptied` attribute
+        pub fn onemptied(&self) -> std::option::Option<&str> {
+            self.sys.onemptied.as_deref()
+        }
+        /// Set the value of the `onemptied` attribute
+        pub fn set_onemptied(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onemptied = value.map(|v| v.into());
+        }
+        /// Get the value of the `onended` attribute
+        pub fn onended(&self) -> std::option::Option<&str> {
+            self.sys.onended.as_deref()
+        }
+        /// Set the value of the `onended` attribute
+        pub fn set_onended(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onended = value.map(|v| v.into());
+        }
+        /// Get the value of the `onerror` attribute
+        pub fn onerror(&self) -> std::option::Option<&str> {
+            self.sys.onerror.as_deref()
+        }
+        /// Set the value of the `onerror` attribute
+        pub fn set_onerror(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onerror = value.map(|v| v.into());
+        }
+        /// Get the value of the `onfocus` attribute
+        pub fn onfocus(&self) -> std::option::Option<&str> {
+            self.sys.onfocus.as_deref()
+        }
+        /// Set the value of the `onfocus` attribute
+        pub fn set_onfocus(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onfocus = value.map(|v| v.into());
+        }
+        /// Get the value of the `onformdata` attribute
+        pub fn onformdata(&self) -> std::option::Option<&str> {
+            self.sys.onformdata.as_deref()
+        }
+        /// Set the value of the `onformdata` attribute
+        pub fn set_onformdata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onformdata = value.map(|v| v.into());
+        }
+        /// Get the value of the `oninput` attribute
+        pub fn oninput(&self) -> std::option::Option<&str> {
+            self.sys.oninput.as_deref()
+        }
+        /// Set the value of the `oninput` attribute
+        pub fn set_oninput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninput = value.map(|v| v.into());
+        }
+        /// Get the value of the `oninvalid` attribute
+        pub fn oninvalid(&self) -> std::option::Option<&str> {
+            self.sys.oninvalid.as_deref()
+        }
+        /// Set the value of the `oninvalid` attribute
+        pub fn set_oninvalid(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninvalid = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeydown` attribute
+        pub fn onkeydown(&self) -> std::option::Option<&str> {
+            self.sys.onkeydown.as_deref()
+        }
+        /// Set the value of the `onkeydown` attribute
+        pub fn set_onkeydown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeydown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeypress` attribute
+        pub fn onkeypress(&self) -> std::option::Option<&str> {
+            self.sys.onkeypress.as_deref()
+        }
+        /// Set the value of the `onkeypress` attribute
+        pub fn set_onkeypress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeypress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeyup` attribute
+        pub fn onkeyup(&self) -> std::option::Option<&str> {
+            self.sys.onkeyup.as_deref()
+        }
+        /// Set the value of the `onkeyup` attribute
+        pub fn set_onkeyup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeyup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onload` attribute
+        pub fn onload(&self) -> std::option::Option<&str> {
+            self.sys.onload.as_deref()
+        }
+        /// Set the value of the `onload` attribute
+        pub fn set_onload(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onload = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadeddata` attribute
+        pub fn onloadeddata(&self) -> std::option::Option<&str> {
+            self.sys.onloadeddata.as_deref()
+        }
+        /// Set the value of the `onloadeddata` attribute
+        pub fn set_onloadeddata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadeddata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(&self) -> std::option::Option<&str> {
+            self.sys.onloadedmetadata.as_deref()
+        }
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn set_onloadedmetadata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadedmetadata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadstart` attribute
+        pub fn onloadstart(&self) -> std::option::Option<&str> {
+            self.sys.onloadstart.as_deref()
+        }
+        /// Set the value of the `onloadstart` attribute
+        pub fn set_onloadstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousedown` attribute
+        pub fn onmousedown(&self) -> std::option::Option<&str> {
+            self.sys.onmousedown.as_deref()
+        }
+        /// Set the value of the `onmousedown` attribute
+        pub fn set_onmousedown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousedown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseenter` attribute
+        pub fn onmouseenter(&self) -> std::option::Option<&str> {
+            self.sys.onmouseenter.as_deref()
+        }
+        /// Set the value of the `onmouseenter` attribute
+        pub fn set_onmouseenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseleave` attribute
+        pub fn onmouseleave(&self) -> std::option::Option<&str> {
+            self.sys.onmouseleave.as_deref()
+        }
+        /// Set the value of the `onmouseleave` attribute
+        pub fn set_onmouseleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousemove` attribute
+        pub fn onmousemove(&self) -> std::option::Option<&str> {
+            self.sys.onmousemove.as_deref()
+        }
+        /// Set the value of the `onmousemove` attribute
+        pub fn set_onmousemove(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousemove = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseout` attribute
+        pub fn onmouseout(&self) -> std::option::Option<&str> {
+            self.sys.onmouseout.as_deref()
+        }
+        /// Set the value of the `onmouseout` attribute
+        pub fn set_onmouseout(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseout = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseover` attribute
+        pub fn onmouseover(&self) -> std::option::Option<&str> {
+            self.sys.onmouseover.as_deref()
+        }
+        /// Set the value of the `onmouseover` attribute
+        pub fn set_onmouseover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseover = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseup` attribute
+        pub fn onmouseup(&self) -> std::option::Option<&str> {
+            self.sys.onmouseup.as_deref()
+        }
+        /// Set the value of the `onmouseup` attribute
+        pub fn set_onmouseup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpaste` attribute
+        pub fn onpaste(&self) -> std::option::Option<&str> {
+            self.sys.onpaste.as_deref()
+        }
+        /// Set the value of the `onpaste` attribute
+        pub fn set_onpaste(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpaste = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpause` attribute
+        pub fn onpause(&self) -> std::option::Option<&str> {
+            self.sys.onpause.as_deref()
+        }
+        /// Set the value of the `onpause` attribute
+        pub fn set_onpause(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpause = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplay` attribute
+        pub fn onplay(&self) -> std::option::Option<&str> {
+            self.sys.onplay.as_deref()
+        }
+        /// Set the value of the `onplay` attribute
+        pub fn set_onplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplay = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplaying` attribute
+        pub fn onplaying(&self) -> std::option::Option<&str> {
+            self.sys.onplaying.as_deref()
+        }
+        /// Set the value of the `onplaying` attribute
+        pub fn set_onplaying(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplaying = value.map(|v| v.into());
+        }
+        /// Get the value of the `onprogress` attribute
+        pub fn onprogress(&self) -> std::option::Option<&str> {
+            self.sys.onprogress.as_deref()
+        }
+        /// Set the value of the `onprogress` attribute
+        pub fn set_onprogress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onprogress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onratechange` attribute
+        pub fn onratechange(&self) -> std::option::Option<&str> {
+            self.sys.onratechange.as_deref()
+        }
+        /// Set the value of the `onratechange` attribute
+        pub fn set_onratechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onratechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onreset` attribute
+        pub fn onreset(&self) -> std::option::Option<&str> {
+            self.sys.onreset.as_deref()
+        }
+        /// Set the value of the `onreset` attribute
+        pub fn set_onreset(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onreset = value.map(|v| v.into());
+        }
+        /// Get the value of the `onresize` attribute
+        pub fn onresize(&self) -> std::option::Option<&str> {
+            self.sys.onresize.as_deref()
+        }
+        /// Set the value of the `onresize` attribute
+        pub fn set_onresize(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onresize = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscroll` attribute
+        pub fn onscroll(&self) -> std::option::Option<&str> {
+            self.sys.onscroll.as_deref()
+        }
+        /// Set the value of the `onscroll` attribute
+        pub fn set_onscroll(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscroll = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscrollend` attribute
+        pub fn onscrollend(&self) -> std::option::Option<&str> {
+            self.sys.onscrollend.as_deref()
+        }
+        /// Set the value of the `onscrollend` attribute
+        pub fn set_onscrollend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscrollend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(&self) -> std::option::Option<&str> {
+            self.sys.onsecuritypolicyviolation.as_deref()
+        }
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn set_onsecuritypolicyviolation(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsecuritypolicyviolation = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeked` attribute
+        pub fn onseeked(&self) -> std::option::Option<&str> {
+            self.sys.onseeked.as_deref()
+        }
+        /// Set the value of the `onseeked` attribute
+        pub fn set_onseeked(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeked = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeking` attribute
+        pub fn onseeking(&self) -> std::option::Option<&str> {
+            self.sys.onseeking.as_deref()
+        }
+        /// Set the value of the `onseeking` attribute
+        pub fn set_onseeking(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeking = value.map(|v| v.into());
+        }
+        /// Get the value of the `onselect` attribute
+        pub fn onselect(&self) -> std::option::Option<&str> {
+            self.sys.onselect.as_deref()
+        }
+        /// Set the value of the `onselect` attribute
+        pub fn set_onselect(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onselect = value.map(|v| v.into());
+        }
+        /// Get the value of the `onslotchange` attribute
+        pub fn onslotchange(&self) -> std::option::Option<&str> {
+            self.sys.onslotchange.as_deref()
+        }
+        /// Set the value of the `onslotchange` attribute
+        pub fn set_onslotchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onslotchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onstalled` attribute
+        pub fn onstalled(&self) -> std::option::Option<&str> {
+            self.sys.onstalled.as_deref()
+        }
+        /// Set the value of the `onstalled` attribute
+        pub fn set_onstalled(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onstalled = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsubmit` attribute
+        pub fn onsubmit(&self) -> std::option::Option<&str> {
+            self.sys.onsubmit.as_deref()
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn set_onsubmit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsubmit = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsuspend` attribute
+        pub fn onsuspend(&self) -> std::option::Option<&str> {
+            self.sys.onsuspend.as_deref()
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn set_onsuspend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsuspend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(&self) -> std::option::Option<&str> {
+            self.sys.ontimeupdate.as_deref()
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn set_ontimeupdate(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontimeupdate = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontoggle` attribute
+        pub fn ontoggle(&self) -> std::option::Option<&str> {
+            self.sys.ontoggle.as_deref()
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn set_ontoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontoggle = value.map(|v| v.into());
+        }
+        /// Get the value of the `onvolumechange` attribute
+        pub fn onvolumechange(&self) -> std::option::Option<&str> {
+            self.sys.onvolumechange.as_deref()
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn set_onvolumechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onvolumechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwaiting` attribute
+        pub fn onwaiting(&self) -> std::option::Option<&str> {
+            self.sys.onwaiting.as_deref()
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn set_onwaiting(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwaiting = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationend.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn set_onwebkitanimationend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationiteration.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn set_onwebkitanimationiteration(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationiteration = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationstart.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn set_onwebkitanimationstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkittransitionend.as_deref()
+        }
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn set_onwebkittransitionend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkittransitionend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwheel` attribute
+        pub fn onwheel(&self) -> std::option::Option<&str> {
+            self.sys.onwheel.as_deref()
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn set_onwheel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwheel = value.map(|v| v.into());
+        }
+    }
+    impl std::fmt::Debug for Input {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for Input {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
+            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
+            Ok(())
+        }
+    }
+    impl crate::HtmlElement for Input {
+        fn tag_name(&self) -> &'static str {
+            "input"
+        }
+        fn attributes(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.attributes()
+        }
+        fn data(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.data()
+        }
+        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
+            vec![]
+        }
+    }
+    impl crate::FlowContent for Input {}
+    impl crate::PhrasingContent for Input {}
+    impl crate::InteractiveContent for Input {}
+    impl crate::PalpableContent for Input {}
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromHtml for Input {
+        const TAG_NAME: &'static str = "input";
+        fn from_html(
+            element: crate::parse::Element<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Self {
+            let mut this: Self = Default::default();
+            for (name, value) in element.attributes() {
+                match name {
+                    "accept" => this.set_accept(Some(value.to_owned())),
+                    "alt" => this.set_alt(Some(value.to_owned())),
+                    "autocomplete" => this.set_autocomplete(Some(value.to_owned())),
+                    "checked" => this.set_checked(true),
+                    "dirname" => this.set_dirname(Some(value.to_owned())),
+                    "disabled" => this.set_disabled(true),
+                    "form" => this.set_form(Some(value.to_owned())),
+                    "formaction" => this.set_formaction(Some(value.to_owned())),
+                    "formenctype" => {
+                        this.set_formenctype(cx.parse_attribute(name, value))
+                    }
+                    "formmethod" => this.set_formmethod(cx.parse_attribute(name, value)),
+                    "formnovalidate" => this.set_formnovalidate(true),
+                    "formtarget" => this.set_formtarget(Some(value.to_owned())),
+                    "height" => this.set_height(Some(value.to_owned())),
+                    "list" => this.set_list(Some(value.to_owned())),
+                    "max" => this.set_max(Some(value.to_owned())),
+                    "maxlength" => this.set_maxlength(Some(value.to_owned())),
+                    "min" => this.set_min(Some(value.to_owned())),
+                    "minlength" => this.set_minlength(Some(value.to_owned())),
+                    "multiple" => this.set_multiple(true),
+                    "name" => this.set_name(Some(value.to_owned())),
+                    "pattern" => this.set_pattern(Some(value.to_owned())),
+                    "placeholder" => this.set_placeholder(Some(value.to_owned())),
+                    "popovertarget" => this.set_popovertarget(Some(value.to_owned())),
+                    "popovertargetaction" => {
+                        this.set_popovertargetaction(cx.parse_attribute(name, value))
+                    }
+                    "readonly" => this.set_readonly(true),
+                    "required" => this.set_required(true),
+                    "size" => this.set_size(Some(value.to_owned())),
+                    "src" => this.set_src(Some(value.to_owned())),
+                    "step" => this.set_step(Some(value.to_owned())),
+                    "type" => this.set_type_(cx.parse_attribute(name, value)),
+                    "value" => this.set_value(Some(value.to_owned())),
+                    "width" => this.set_width(Some(value.to_owned())),
+                    "role" => this.set_role(cx.parse_attribute(name, value)),
+                    "aria-activedescendant" => {
+                        this.set_aria_active_descendant_element(Some(value.to_owned()))
+                    }
+                    "aria-atomic" => this.set_aria_atomic(value != "false"),
+                    "aria-autocomplete" => {
+                        this.set_aria_auto_complete(cx.parse_attribute(name, value))
+                    }
+                    "aria-braillelabel" => {
+                        this.set_aria_braille_label(Some(value.to_owned()))
+                    }
+                    "aria-brailleroledescription" => {
+                        this.set_aria_braille_role_description(Some(value.to_owned()))
+                    }
+                    "aria-busy" => this.set_aria_busy(value != "false"),
+                    "aria-checked" => {
+                        this.set_aria_checked(cx.parse_attribute(name, value))
+                    }
+                    "aria-colindex" => {
+                        this.set_aria_col_index(cx.parse_attribute(name, value))
+                    }
+                    "aria-colindextext" => {
+                        this.set_aria_col_index_text(Some(value.to_owned()))
+                    }
+                    "aria-colspan" => {
+                        this.set_aria_col_span(cx.parse_attribute(name, value))
+                    }
+                    "aria-controls" => {
+                        this.set_aria_controls_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-current" => {
+                        this.set_aria_current(cx.parse_attribute(name, value))
+                    }
+                    "aria-describedby" => {
+                        this.set_aria_described_by_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-description" => {
+                        this.set_aria_description(Some(value.to_owned()))
+                    }
+                    "aria-details" => {
+                        this.set_aria_details_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-disabled" => this.set_aria_disabled(value != "false"),
+                    "aria-dropeffect" => {
+                        this.set_aria_drop_effect(
+                            value
+                                .split_ascii_whitespace()
+                                .filter_map(|value| cx.parse_attribute(name, value))
+                                .collect(),
+                        )
+                    }
+                    "aria-errormessage" => {
+                        this.set_aria_error_message_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-expanded" => {
+                        this.set_aria_expanded(cx.parse_attribute(name, value))
+                    }
+                    "aria-flowto" => {
+                        this.set_aria_flow_to_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-grabbed" => {
+                        this.set_aria_grabbed(cx.parse_attribute(name, value))
+                    }
+                    "aria-haspopup" => {
+                        this.set_aria_has_popup(cx.parse_attribute(name, value))
+                    }
+                    "aria-hidden" => {
+                        this.set_aria_hidden(cx.parse_attribute(name, value))
+                    }
+                    "aria-invalid" => {
+                        this.set_aria_invalid(cx.parse_attribute(name, value))
+                    }
+                    "aria-keyshortcuts" => {
+                        this.set_aria_key_shortcuts(Some(value.to_owned()))
+                    }
+                    "aria-label" => this.set_aria_label(Some(value.to_owned())),
+                    "aria-labelledby" => {
+                        this.set_aria_labelled_by_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-level" => this.set_aria_level(cx.parse_attribute(name, value)),
+                    "aria-live" => this.set_aria_live(cx.parse_attribute(name, value)),
+                    "aria-multiline" => this.set_aria_multi_line(value != "false"),
+                    "aria-orientation" => {
+                        this.set_aria_orientation(cx.parse_attribute(name, value))
+                    }
+                    "aria-owns" => {
+                        this.set_aria_owns_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-placeholder" => {
+                        this.set_aria_placeholder(Some(value.to_owned()))
+                    }
+                    "aria-posinset" => {
+                        this.set_aria_pos_in_set(cx.parse_attribute(name, value))
+                    }
+                    "aria-pressed" => {
+                        this.set_aria_pressed(cx.parse_attribute(name, value))
+                    }
+                    "aria-readonly" => this.set_aria_read_only(value != "false"),
+                    "aria-relevant" => {
+                        this.set_aria_relevant(
+                            value
+                                .split_ascii_whitespace()
+                                .filter_map(|value| cx.parse_attribute(name, value))
+                                .collect(),
+                        )
+                    }
+                    "aria-required" => this.set_aria_required(value != "false"),
+                    "aria-roledescription" => {
+                        this.set_aria_role_description(Some(value.to_owned()))
+                    }
+                    "aria-rowindex" => {
+                        this.set_aria_row_index(cx.parse_attribute(name, value))
+                    }
+                    "aria-rowindextext" => {
+                        this.set_aria_row_index_text(Some(value.to_owned()))
+                    }
+                    "aria-rowspan" => {
+                        this.set_aria_row_span(cx.parse_attribute(name, value))
+                    }
+                    "aria-selected" => {
+                        this.set_aria_selected(cx.parse_attribute(name, value))
+                    }
+                    "aria-setsize" => {
+                        this.set_aria_set_size(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuemax" => {
+                        this.set_aria_value_max(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuemin" => {
+                        this.set_aria_value_min(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuenow" => {
+                        this.set_aria_value_now(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuetext" => this.set_aria_value_text(Some(value.to_owned())),
+                    "accesskey" => this.set_access_key(Some(value.to_owned())),
+                    "autocapitalize" => {
+                        this.set_auto_capitalize(cx.parse_attribute(name, value))
+                    }
+                    "autofocus" => this.set_autofocus(true),
+                    "class" => this.set_class(Some(value.to_owned())),
+                    "contenteditable" => {
+                        this.set_content_editable(cx.parse_attribute(name, value))
+                    }
+                    "dir" => this.set_direction(cx.parse_attribute(name, value)),
+                    "draggable" => this.set_draggable(cx.parse_attribute(name, value)),
+                    "enterkeyhint" => {
+                        this.set_enter_key_hint(cx.parse_attribute(name, value))
+                    }
+                    "exportparts" => this.set_export_parts(Some(value.to_owned())),
+                    "hidden" => this.set_hidden(cx.parse_attribute(name, value)),
+                    "id" => this.set_id(Some(value.to_owned())),
+                    "inert" => this.set_inert(true),
+                    "inputmode" => this.set_input_mode(cx.parse_attribute(name, value)),
+                    "is" => this.set_is_(Some(value.to_owned())),
+                    "itemid" => this.set_item_id(Some(value.to_owned())),
+                    "itemprop" => this.set_item_prop(Some(value.to_owned())),
+                    "itemref" => this.set_item_ref(Some(value.to_owned())),
+                    "itemscope" => this.set_item_scope(true),
+                    "itemtype" => this.set_item_type(Some(value.to_owned())),
+                    "lang" => this.set_lang(Some(value.to_owned())),
+                    "nonce" => this.set_nonce(Some(value.to_owned())),
+                    "part" => this.set_part(Some(value.to_owned())),
+                    "slot" => this.set_slot(Some(value.to_owned())),
+                    "spellcheck" => this.set_spellcheck(cx.parse_attribute(name, value)),
+                    "style" => this.set_style(Some(value.to_owned())),
+                    "tabindex" => this.set_tab_index(cx.parse_attribute(name, value)),
+                    "title" => this.set_title(Some(value.to_owned())),
+                    "translate" => this.set_translate(cx.parse_attribute(name, value)),
+                    "onabort" => this.set_onabort(Some(value.to_owned())),
+                    "onauxclick" => this.set_onauxclick(Some(value.to_owned())),
+                    "onbeforeinput" => this.set_onbeforeinput(Some(value.to_owned())),
+                    "onbeforematch" => this.set_onbeforematch(Some(value.to_owned())),
+                    "onbeforetoggle" => this.set_onbeforetoggle(Some(value.to_owned())),
+                    "onblur" => this.set_onblur(Some(value.to_owned())),
+                    "oncancel" => this.set_oncancel(Some(value.to_owned())),
+                    "oncanplay" => this.set_oncanplay(Some(value.to_owned())),
+                    "oncanplaythrough" => {
+                        this.set_oncanplaythrough(Some(value.to_owned()))
+                    }
+                    "onchange" => this.set_onchange(Some(value.to_owned())),
+                    "onclick" => this.set_onclick(Some(value.to_owned())),
+                    "onclose" => this.set_onclose(Some(value.to_owned())),
+                    "oncontextlost" => this.set_oncontextlost(Some(value.to_owned())),
+                    "oncontextmenu" => this.set_oncontextmenu(Some(value.to_owned())),
+                    "oncontextrestored" => {
+                        this.set_oncontextrestored(Some(value.to_owned()))
+                    }
+                    "oncopy" => this.set_oncopy(Some(value.to_owned())),
+                    "oncuechange" => this.set_oncuechange(Some(value.to_owned())),
+                    "oncut" => this.set_oncut(Some(value.to_owned())),
+                    "ondblclick" => this.set_ondblclick(Some(value.to_owned())),
+                    "ondrag" => this.set_ondrag(Some(value.to_owned())),
+                    "ondragend" => this.set_ondragend(Some(value.to_owned())),
+                    "ondragenter" => this.set_ondragenter(Some(value.to_owned())),
+                    "ondragleave" => this.set_ondragleave(Some(value.to_owned())),
+                    "ondragover" => this.set_ondragover(Some(value.to_owned())),
+                    "ondragstart" => this.set_ondragstart(Some(value.to_owned())),
+                    "ondrop" => this.set_ondrop(Some(value.to_owned())),
+                    "ondurationchange" => {
+                        this.set_ondurationchange(Some(value.to_owned()))
+                    }
+                    "onemptied" => this.set_onemptied(Some(value.to_owned())),
+                    "onended" => this.set_onended(Some(value.to_owned())),
+                    "onerror" => this.set_onerror(Some(value.to_owned())),
+                    "onfocus" => this.set_onfocus(Some(value.to_owned())),
+                    "onformdata" => this.set_onformdata(Some(value.to_owned())),
+                    "oninput" => this.set_oninput(Some(value.to_owned())),
+                    "oninvalid" => this.set_oninvalid(Some(value.to_owned())),
+                    "onkeydown" => this.set_onkeydown(Some(value.to_owned())),
+                    "onkeypress" => this.set_onkeypress(Some(value.to_owned())),
+                    "onkeyup" => this.set_onkeyup(Some(value.to_owned())),
+                    "onload" => this.set_onload(Some(value.to_owned())),
+                    "onloadeddata" => this.set_onloadeddata(Some(value.to_owned())),
+                    "onloadedmetadata" => {
+                        this.set_onloadedmetadata(Some(value.to_owned()))
+                    }
+                    "onloadstart" => this.set_onloadstart(Some(value.to_owned())),
+                    "onmousedown" => this.set_onmousedown(Some(value.to_owned())),
+                    "onmouseenter" => this.set_onmouseenter(Some(value.to_owned())),
+                    "onmouseleave" => this.set_onmouseleave(Some(value.to_owned())),
+                    "onmousemove" => this.set_onmousemove(Some(value.to_owned())),
+                    "onmouseout" => this.set_onmouseout(Some(value.to_owned())),
+                    "onmouseover" => this.set_onmouseover(Some(value.to_owned())),
+                    "onmouseup" => this.set_onmouseup(Some(value.to_owned())),
+                    "onpaste" => this.set_onpaste(Some(value.to_owned())),
+                    "onpause" => this.set_onpause(Some(value.to_owned())),
+                    "onplay" => this.set_onplay(Some(value.to_owned())),
+                    "onplaying" => this.set_onplaying(Some(value.to_owned())),
+                    "onprogress" => this.set_onprogress(Some(value.to_owned())),
+                    "onratechange" => this.set_onratechange(Some(value.to_owned())),
+                    "onreset" => this.set_onreset(Some(value.to_owned())),
+                    "onresize" => this.set_onresize(Some(value.to_owned())),
+                    "onscroll" => this.set_onscroll(Some(value.to_owned())),
+                    "onscrollend" => this.set_onscrollend(Some(value.to_owned())),
+                    "onsecuritypolicyviolation" => {
+                        this.set_onsecuritypolicyviolation(Some(value.to_owned()))
+                    }
+                    "onseeked" => this.set_onseeked(Some(value.to_owned())),
+                    "onseeking" => this.set_onseeking(Some(value.to_owned())),
+                    "onselect" => this.set_onselect(Some(value.to_owned())),
+                    "onslotchange" => this.set_onslotchange(Some(value.to_owned())),
+                    "onstalled" => this.set_onstalled(Some(value.to_owned())),
+                    "onsubmit" => this.set_onsubmit(Some(value.to_owned())),
+                    "onsuspend" => this.set_onsuspend(Some(value.to_owned())),
+                    "ontimeupdate" => this.set_ontimeupdate(Some(value.to_owned())),
+                    "ontoggle" => this.set_ontoggle(Some(value.to_owned())),
+                    "onvolumechange" => this.set_onvolumechange(Some(value.to_owned())),
+                    "onwaiting" => this.set_onwaiting(Some(value.to_owned())),
+                    "onwebkitanimationend" => {
+                        this.set_onwebkitanimationend(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationiteration" => {
+                        this.set_onwebkitanimationiteration(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationstart" => {
+                        this.set_onwebkitanimationstart(Some(value.to_owned()))
+                    }
+                    "onwebkittransitionend" => {
+                        this.set_onwebkittransitionend(Some(value.to_owned()))
+                    }
+                    "onwheel" => this.set_onwheel(Some(value.to_owned())),
+                    _ => cx.other_attribute(this.data_map_mut(), name, value),
+                }
+            }
+            cx.no_children(element);
+            this
+        }
+    }
+    impl crate::tree::Element for Input {
+        fn tag_name(&self) -> &'static str {
+            "input"
+        }
+        fn is_inline(&self) -> bool {
+            true
+        }
+        fn is_preformatted(&self) -> bool {
+            false
+        }
+        fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {
+            html_sys::RenderElement::write_opening_tag(&self.sys, writer)
+        }
+        fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {
+            html_sys::RenderElement::write_closing_tag(&self.sys, writer)
+        }
+        fn child(&self, _index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+            None
+        }
+    }
+    impl<'a> From<&'a Input> for crate::tree::Part<'a> {
+        fn from(element: &'a Input) -> Self {
+            crate::tree::Part::Element(element)
+        }
+    }
+    impl<'a> From<&'a Input> for crate::stream::Chunks<'a> {
+        fn from(element: &'a Input) -> Self {
+            crate::stream::Chunks::new(element)
+        }
+    }
+    impl<'a> From<&'a Input> for crate::render::Pretty<'a> {
+        fn from(element: &'a Input) -> Self {
+            crate::render::Pretty::new(element.into())
+        }
+    }
+    impl<'a> From<&'a Input> for crate::minify::Minified<'a> {
+        fn from(element: &'a Input) -> Self {
+            crate::minify::Minified::new(element.into())
+        }
+    }
+    impl crate::visit::Visit for Input {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn eq_element(&self, other: &dyn crate::visit::Visit) -> bool {
+            other.downcast_ref::<Self>() == Some(self)
+        }
+        fn child(
+            &self,
+            _index: usize,
+        ) -> std::option::Option<crate::visit::NodeRef<'_>> {
+            None
+        }
+        fn child_mut(
+            &mut self,
+            _index: usize,
+        ) -> std::option::Option<crate::visit::NodeMut<'_>> {
+            None
+        }
+        fn retain_children(
+            &mut self,
+            _f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool,
+        ) {}
+        fn insert_child(
+            &mut self,
+            _index: usize,
+            _node: crate::visit::NodeRef<'_>,
+        ) -> bool {
+            false
+        }
+        fn remove_child(&mut self, _index: usize) -> bool {
+            false
+        }
+        fn set_attribute(
+            &mut self,
+            name: &str,
+            value: std::option::Option<&str>,
+        ) -> bool {
+            match name {
+                "accept" => self.set_accept(value.map(str::to_owned)),
+                "alt" => self.set_alt(value.map(str::to_owned)),
+                "autocomplete" => self.set_autocomplete(value.map(str::to_owned)),
+                "checked" => self.set_checked(value.is_some()),
+                "dirname" => self.set_dirname(value.map(str::to_owned)),
+                "disabled" => self.set_disabled(value.is_some()),
+                "form" => self.set_form(value.map(str::to_owned)),
+                "formaction" => self.set_formaction(value.map(str::to_owned)),
+                "formenctype" => {
+                    self.set_formenctype(value.and_then(|value| value.parse().ok()))
+                }
+                "formmethod" => {
+                    self.set_formmethod(value.and_then(|value| value.parse().ok()))
+                }
+                "formnovalidate" => self.set_formnovalidate(value.is_some()),
+                "formtarget" => self.set_formtarget(value.map(str::to_owned)),
+                "height" => self.set_height(value.map(str::to_owned)),
+                "list" => self.set_list(value.map(str::to_owned)),
+                "max" => self.set_max(value.map(str::to_owned)),
+                "maxlength" => self.set_maxlength(value.map(str::to_owned)),
+                "min" => self.set_min(value.map(str::to_owned)),
+                "minlength" => self.set_minlength(value.map(str::to_owned)),
+                "multiple" => self.set_multiple(value.is_some()),
+                "name" => self.set_name(value.map(str::to_owned)),
+                "pattern" => self.set_pattern(value.map(str::to_owned)),
+                "placeholder" => self.set_placeholder(value.map(str::to_owned)),
+                "popovertarget" => self.set_popovertarget(value.map(str::to_owned)),
+                "popovertargetaction" => {
+                    self.set_popovertargetaction(
+                        value.and_then(|value| value.parse().ok()),
+                    )
+                }
+                "readonly" => self.set_readonly(value.is_some()),
+                "required" => self.set_required(value.is_some()),
+                "size" => self.set_size(value.map(str::to_owned)),
+                "src" => self.set_src(value.map(str::to_owned)),
+                "step" => self.set_step(value.map(str::to_owned)),
+                "type" => self.set_type_(value.and_then(|value| value.parse().ok())),
+                "value" => self.set_value(value.map(str::to_owned)),
+                "width" => self.set_width(value.map(str::to_owned)),
+                "role" => self.set_role(value.and_then(|value| value.parse().ok())),
+                "aria-activedescendant" => {
+                    self.set_aria_active_descendant_element(value.map(str::to_owned))
+                }
+                "aria-atomic" => {
+                    self.set_aria_atomic(value.is_some_and(|value| value != "false"))
+                }
+                "aria-autocomplete" => {
+                    self.set_aria_auto_complete(
+                        value.and_then(|value| value.parse().ok()),
+                    )
+                }
+                "aria-braillelabel" => {
+                    self.set_aria_braille_label(value.map(str::to_owned))
+                }
+                "aria-brailleroledescription" => {
+                    self.set_aria_braille_role_description(value.map(str::to_owned))
+                }
+                "aria-busy" => {
+                    self.set_aria_busy(value.is_some_and(|value| value != "false"))
+                }
+                "aria-checked" => {
+                    self.set_aria_checked(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-colindex" => {
+                    self.set_aria_col_index(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-colindextext" => {
+                    self.set_aria_col_index_text(value.map(str::to_owned))
+                }
+                "aria-colspan" => {
+                    self.set_aria_col_span(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-controls" => {
+                    self.set_aria_controls_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-current" => {
+                    self.set_aria_current(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-describedby" => {
+                    self.set_aria_described_by_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-description" => self.set_aria_description(value.map(str::to_owned)),
+                "aria-details" => {
+                    self.set_aria_details_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-disabled" => {
+                    self.set_aria_disabled(value.is_some_and(|value| value != "false"))
+                }
+                "aria-dropeffect" => {
+                    self.set_aria_drop_effect(
+                        value
+                            .and_then(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(str::parse)
+                                    .collect::<Result<_, _>>()
+                                    .ok()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-errormessage" => {
+                    self.set_aria_error_message_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-expanded" => {
+                    self.set_aria_expanded(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-flowto" => {
+                    self.set_aria_flow_to_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-grabbed" => {
+                    self.set_aria_grabbed(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-haspopup" => {
+                    self.set_aria_has_popup(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-hidden" => {
+                    self.set_aria_hidden(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-invalid" => {
+                    self.set_aria_invalid(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-keyshortcuts" => {
+                    self.set_aria_key_shortcuts(value.map(str::to_owned))
+                }
+                "aria-label" => self.set_aria_label(value.map(str::to_owned)),
+                "aria-labelledby" => {
+                    self.set_aria_labelled_by_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-level" => {
+                    self.set_aria_level(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-live" => {
+                    self.set_aria_live(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-multiline" => {
+                    self.set_aria_multi_line(value.is_some_and(|value| value != "false"))
+                }
+                "aria-orientation" => {
+                    self.set_aria_orientation(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-owns" => {
+                    self.set_aria_owns_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-placeholder" => self.set_aria_placeholder(value.map(str::to_owned)),
+                "aria-posinset" => {
+                    self.set_aria_pos_in_set(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-pressed" => {
+                    self.set_aria_pressed(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-readonly" => {
+                    self.set_aria_read_only(value.is_some_and(|value| value != "false"))
+                }
+                "aria-relevant" => {
+                    self.set_aria_relevant(
+                        value
+                            .and_then(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(str::parse)
+                                    .collect::<Result<_, _>>()
+                                    .ok()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-required" => {
+                    self.set_aria_required(value.is_some_and(|value| value != "false"))
+                }
+                "aria-roledescription" => {
+                    self.set_aria_role_description(value.map(str::to_owned))
+                }
+                "aria-rowindex" => {
+                    self.set_aria_row_index(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-rowindextext" => {
+                    self.set_aria_row_index_text(value.map(str::to_owned))
+                }
+                "aria-rowspan" => {
+                    self.set_aria_row_span(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-selected" => {
+                    self.set_aria_selected(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-setsize" => {
+                    self.set_aria_set_size(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuemax" => {
+                    self.set_aria_value_max(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuemin" => {
+                    self.set_aria_value_min(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuenow" => {
+                    self.set_aria_value_now(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuetext" => self.set_aria_value_text(value.map(str::to_owned)),
+                "accesskey" => self.set_access_key(value.map(str::to_owned)),
+                "autocapitalize" => {
+                    self.set_auto_capitalize(value.and_then(|value| value.parse().ok()))
+                }
+                "autofocus" => self.set_autofocus(value.is_some()),
+                "class" => self.set_class(value.map(str::to_owned)),
+                "contenteditable" => {
+                    self.set_content_editable(value.and_then(|value| value.parse().ok()))
+                }
+                "dir" => self.set_direction(value.and_then(|value| value.parse().ok())),
+                "draggable" => {
+                    self.set_draggable(value.and_then(|value| value.parse().ok()))
+                }
+                "enterkeyhint" => {
+                    self.set_enter_key_hint(value.and_then(|value| value.parse().ok()))
+                }
+                "exportparts" => self.set_export_parts(value.map(str::to_owned)),
+                "hidden" => self.set_hidden(value.and_then(|value| value.parse().ok())),
+                "id" => self.set_id(value.map(str::to_owned)),
+                "inert" => self.set_inert(value.is_some()),
+                "inputmode" => {
+                    self.set_input_mode(value.and_then(|value| value.parse().ok()))
+                }
+                "is" => self.set_is_(value.map(str::to_owned)),
+                "itemid" => self.set_item_id(value.map(str::to_owned)),
+                "itemprop" => self.set_item_prop(value.map(str::to_owned)),
+                "itemref" => self.set_item_ref(value.map(str::to_owned)),
+                "itemscope" => self.set_item_scope(value.is_some()),
+                "itemtype" => self.set_item_type(value.map(str::to_owned)),
+                "lang" => self.set_lang(value.map(str::to_owned)),
+                "nonce" => self.set_nonce(value.map(str::to_owned)),
+                "part" => self.set_part(value.map(str::to_owned)),
+                "slot" => self.set_slot(value.map(str::to_owned)),
+                "spellcheck" => {
+                    self.set_spellcheck(value.and_then(|value| value.parse().ok()))
+                }
+                "style" => self.set_style(value.map(str::to_owned)),
+                "tabindex" => {
+                    self.set_tab_index(value.and_then(|value| value.parse().ok()))
+                }
+                "title" => self.set_title(value.map(str::to_owned)),
+                "translate" => {
+                    self.set_translate(value.and_then(|value| value.parse().ok()))
+                }
+                "onabort" => self.set_onabort(value.map(str::to_owned)),
+                "onauxclick" => self.set_onauxclick(value.map(str::to_owned)),
+                "onbeforeinput" => self.set_onbeforeinput(value.map(str::to_owned)),
+                "onbeforematch" => self.set_onbeforematch(value.map(str::to_owned)),
+                "onbeforetoggle" => self.set_onbeforetoggle(value.map(str::to_owned)),
+                "onblur" => self.set_onblur(value.map(str::to_owned)),
+                "oncancel" => self.set_oncancel(value.map(str::to_owned)),
+                "oncanplay" => self.set_oncanplay(value.map(str::to_owned)),
+                "oncanplaythrough" => self.set_oncanplaythrough(value.map(str::to_owned)),
+                "onchange" => self.set_onchange(value.map(str::to_owned)),
+                "onclick" => self.set_onclick(value.map(str::to_owned)),
+                "onclose" => self.set_onclose(value.map(str::to_owned)),
+                "oncontextlost" => self.set_oncontextlost(value.map(str::to_owned)),
+                "oncontextmenu" => self.set_oncontextmenu(value.map(str::to_owned)),
+                "oncontextrestored" => {
+                    self.set_oncontextrestored(value.map(str::to_owned))
+                }
+                "oncopy" => self.set_oncopy(value.map(str::to_owned)),
+                "oncuechange" => self.set_oncuechange(value.map(str::to_owned)),
+                "oncut" => self.set_oncut(value.map(str::to_owned)),
+                "ondblclick" => self.set_ondblclick(value.map(str::to_owned)),
+                "ondrag" => self.set_ondrag(value.map(str::to_owned)),
+                "ondragend" => self.set_ondragend(value.map(str::to_owned)),
+                "ondragenter" => self.set_ondragenter(value.map(str::to_owned)),
+                "ondragleave" => self.set_ondragleave(value.map(str::to_owned)),
+                "ondragover" => self.set_ondragover(value.map(str::to_owned)),
+                "ondragstart" => self.set_ondragstart(value.map(str::to_owned)),
+                "ondrop" => self.set_ondrop(value.map(str::to_owned)),
+                "ondurationchange" => self.set_ondurationchange(value.map(str::to_owned)),
+                "onemptied" => self.set_onemptied(value.map(str::to_owned)),
+                "onended" => self.set_onended(value.map(str::to_owned)),
+                "onerror" => self.set_onerror(value.map(str::to_owned)),
+                "onfocus" => self.set_onfocus(value.map(str::to_owned)),
+                "onformdata" => self.set_onformdata(value.map(str::to_owned)),
+                "oninput" => self.set_oninput(value.map(str::to_owned)),
+                "oninvalid" => self.set_oninvalid(value.map(str::to_owned)),
+                "onkeydown" => self.set_onkeydown(value.map(str::to_owned)),
+                "onkeypress" => self.set_onkeypress(value.map(str::to_owned)),
+                "onkeyup" => self.set_onkeyup(value.map(str::to_owned)),
+                "onload" => self.set_onload(value.map(str::to_owned)),
+                "onloadeddata" => self.set_onloadeddata(value.map(str::to_owned)),
+                "onloadedmetadata" => self.set_onloadedmetadata(value.map(str::to_owned)),
+                "onloadstart" => self.set_onloadstart(value.map(str::to_owned)),
+                "onmousedown" => self.set_onmousedown(value.map(str::to_owned)),
+                "onmouseenter" => self.set_onmouseenter(value.map(str::to_owned)),
+                "onmouseleave" => self.set_onmouseleave(value.map(str::to_owned)),
+                "onmousemove" => self.set_onmousemove(value.map(str::to_owned)),
+                "onmouseout" => self.set_onmouseout(value.map(str::to_owned)),
+                "onmouseover" => self.set_onmouseover(value.map(str::to_owned)),
+                "onmouseup" => self.set_onmouseup(value.map(str::to_owned)),
+                "onpaste" => self.set_onpaste(value.map(str::to_owned)),
+                "onpause" => self.set_onpause(value.map(str::to_owned)),
+                "onplay" => self.set_onplay(value.map(str::to_owned)),
+                "onplaying" => self.set_onplaying(value.map(str::to_owned)),
+                "onprogress" => self.set_onprogress(value.map(str::to_owned)),
+                "onratechange" => self.set_onratechange(value.map(str::to_owned)),
+                "onreset" => self.set_onreset(value.map(str::to_owned)),
+                "onresize" => self.set_onresize(value.map(str::to_owned)),
+                "onscroll" => self.set_onscroll(value.map(str::to_owned)),
+                "onscrollend" => self.set_onscrollend(value.map(str::to_owned)),
+                "onsecuritypolicyviolation" => {
+                    self.set_onsecuritypolicyviolation(value.map(str::to_owned))
+                }
+                "onseeked" => self.set_onseeked(value.map(str::to_owned)),
+                "onseeking" => self.set_onseeking(value.map(str::to_owned)),
+                "onselect" => self.set_onselect(value.map(str::to_owned)),
+                "onslotchange" => self.set_onslotchange(value.map(str::to_owned)),
+                "onstalled" => self.set_onstalled(value.map(str::to_owned)),
+                "onsubmit" => self.set_onsubmit(value.map(str::to_owned)),
+                "onsuspend" => self.set_onsuspend(value.map(str::to_owned)),
+                "ontimeupdate" => self.set_ontimeupdate(value.map(str::to_owned)),
+                "ontoggle" => self.set_ontoggle(value.map(str::to_owned)),
+                "onvolumechange" => self.set_onvolumechange(value.map(str::to_owned)),
+                "onwaiting" => self.set_onwaiting(value.map(str::to_owned)),
+                "onwebkitanimationend" => {
+                    self.set_onwebkitanimationend(value.map(str::to_owned))
+                }
+                "onwebkitanimationiteration" => {
+                    self.set_onwebkitanimationiteration(value.map(str::to_owned))
+                }
+                "onwebkitanimationstart" => {
+                    self.set_onwebkitanimationstart(value.map(str::to_owned))
+                }
+                "onwebkittransitionend" => {
+                    self.set_onwebkittransitionend(value.map(str::to_owned))
+                }
+                "onwheel" => self.set_onwheel(value.map(str::to_owned)),
+                _ => {
+                    match (name.strip_prefix("data-"), value) {
+                        (Some(key), Some(value)) => {
+                            self.data_map_mut()
+                                .insert(key.to_owned().into(), value.to_owned().into());
+                        }
+                        (Some(key), None) => {
+                            self.data_map_mut().remove(key);
+                        }
+                        (None, _) => return false,
+                    }
+                }
+            }
+            true
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeElement for Input {
+        const TAG_NAME: &'static str = "input";
+        type Attributes = html_sys::forms::Input;
+        type Child = crate::serialize::Never;
+        fn attributes(&self) -> &Self::Attributes {
+            &self.sys
+        }
+        fn children(&self) -> &[Self::Child] {
+            &[]
+        }
+        fn from_parts(sys: Self::Attributes, _children: Vec<Self::Child>) -> Self {
+            Self { sys }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for Input {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            crate::serialize::serialize_element(self, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for Input {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_element(deserializer)
+        }
+    }
+    impl From<Input> for html_sys::forms::Input {
+        fn from(element: Input) -> Self {
+            element.sys
+        }
+    }
+    impl From<html_sys::forms::Input> for Input {
+        fn from(sys: html_sys::forms::Input) -> Self {
+            Self { sys }
+        }
+    }
+}
+pub mod child {}
+pub mod builder {
+    /// A builder struct for Input
+    pub struct InputBuilder {
+        element: super::element::Input,
+    }
+    impl InputBuilder {
+        pub(crate) fn new(element: super::element::Input) -> Self {
+            Self { element }
+        }
+        /// Finish building the element
+        pub fn build(&mut self) -> super::element::Input {
+            self.element.clone()
+        }
+        /// Insert a `data-*` property
+        pub fn data(
+            &mut self,
+            data_key: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut InputBuilder {
+            self.element.data_map_mut().insert(data_key.into(), value.into());
+            self
+        }
+        /// Set the value of the `accept` attribute
+        pub fn accept(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_accept(Some(value.into()));
+            self
+        }
+        /// Set the value of the `alt` attribute
+        pub fn alt(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_alt(Some(value.into()));
+            self
+        }
+        /// Set the value of the `autocomplete` attribute
+        pub fn autocomplete(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_autocomplete(Some(value.into()));
+            self
+        }
+        /// Set the value of the `checked` attribute
+        pub fn checked(&mut self, value: bool) -> &mut Self {
+            self.element.set_checked(value);
+            self
+        }
+        /// Set the value of the `dirname` attribute
+        pub fn dirname(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_dirname(Some(value.into()));
+            self
+        }
+        /// Set the value of the `disabled` attribute
+        pub fn disabled(&mut self, value: bool) -> &mut Self {
+            self.element.set_disabled(value);
+            self
+        }
+        /// Set the value of the `form` attribute
+        pub fn form(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_form(Some(value.into()));
+            self
+        }
+        /// Set the value of the `formaction` attribute
+        pub fn formaction(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_formaction(Some(value.into()));
+            self
+        }
+        /// Set the value of the `formenctype` attribute
+        pub fn formenctype(
+            &mut self,
+            value: html_sys::attributes::FormEncoding,
+        ) -> &mut Self {
+            self.element.set_formenctype(Some(value));
+            self
+        }
+        /// Set the value of the `formmethod` attribute
+        pub fn formmethod(
+            &mut self,
+            value: html_sys::attributes::FormMethod,
+        ) -> &mut Self {
+            self.element.set_formmethod(Some(value));
+            self
+        }
+        /// Set the value of the `formnovalidate` attribute
+        pub fn formnovalidate(&mut self, value: bool) -> &mut Self {
+            self.element.set_formnovalidate(value);
+            self
+        }
+        /// Set the value of the `formtarget` attribute
+        pub fn formtarget(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_formtarget(Some(value.into()));
+            self
+        }
+        /// Set the value of the `height` attribute
+        pub fn height(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_height(Some(value.into()));
+            self
+        }
+        /// Set the value of the `list` attribute
+        pub fn list(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_list(Some(value.into()));
+            self
+        }
+        /// Set the value of the `max` attribute
+        pub fn max(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_max(Some(value.into()));
+            self
+        }
+        /// Set the value of the `maxlength` attribute
+        pub fn maxlength(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_maxlength(Some(value.into()));
+            self
+        }
+        /// Set the value of the `min` attribute
+        pub fn min(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_min(Some(value.into()));
+            self
+        }
+        /// Set the value of the `minlength` attribute
+        pub fn minlength(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_minlength(Some(value.into()));
+            self
+        }
+        /// Set the value of the `multiple` attribute
+        pub fn multiple(&mut self, value: bool) -> &mut Self {
+            self.element.set_multiple(value);
+            self
+        }
+        /// Set the value of the `name` attribute
+        pub fn name(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_name(Some(value.into()));
+            self
+        }
+        /// Set the value of the `pattern` attribute
+        pub fn pattern(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_pattern(Some(value.into()));
+            self
+        }
+        /// Set the value of the `placeholder` attribute
+        pub fn placeholder(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_placeholder(Some(value.into()));
+            self
+        }
+        /// Set the value of the `popovertarget` attribute
+        pub fn popovertarget(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_popovertarget(Some(value.into()));
+            self
+        }
+        /// Set the value of the `popovertargetaction` attribute
+        pub fn popovertargetaction(
+            &mut self,
+            value: html_sys::attributes::PopoverTargetAction,
+        ) -> &mut Self {
+            self.element.set_popovertargetaction(Some(value));
+            self
+        }
+        /// Set the value of the `readonly` attribute
+        pub fn readonly(&mut self, value: bool) -> &mut Self {
+            self.element.set_readonly(value);
+            self
+        }
+        /// Set the value of the `required` attribute
+        pub fn required(&mut self, value: bool) -> &mut Self {
+            self.element.set_required(value);
+            self
+        }
+        /// Set the value of the `size` attribute
+        pub fn size(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_size(Some(value.into()));
+            self
+        }
+        /// Set the value of the `src` attribute
+        pub fn src(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_src(Some(value.into()));
+            self
+        }
+        /// Set the value of the `step` attribute
+        pub fn step(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_step(Some(value.into()));
+            self
+        }
+        /// Set the value of the `type` attribute
+        pub fn type_(&mut self, value: html_sys::attributes::InputType) -> &mut Self {
+            self.element.set_type_(Some(value));
+            self
+        }
+        /// Set the value of the `value` attribute
+        pub fn value(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_value(Some(value.into()));
+            self
+        }
+        /// Set the value of the `width` attribute
+        pub fn width(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_width(Some(value.into()));
+            self
+        }
+        /// Set the value of the `role` attribute
+        pub fn role(&mut self, value: html_sys::attributes::InputRole) -> &mut Self {
+            self.element.set_role(Some(value));
+            self
+        }
+        /// Set the value of the `aria-activedescendant` attribute
+        pub fn aria_active_descendant_element(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_active_descendant_element(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-atomic` attribute
+        pub fn aria_atomic(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_atomic(value);
+            self
+        }
+        /// Set the value of the `aria-autocomplete` attribute
+        pub fn aria_auto_complete(
+            &mut self,
+            value: html_sys::attributes::AriaAutoComplete,
+        ) -> &mut Self {
+            self.element.set_aria_auto_complete(Some(value));
+            self
+        }
+        /// Set the value of the `aria-braillelabel` attribute
+        pub fn aria_braille_label(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_braille_label(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-brailleroledescription` attribute
+        pub fn aria_braille_role_description(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_braille_role_description(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-busy` attribute
+        pub fn aria_busy(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_busy(value);
+            self
+        }
+        /// Set the value of the `aria-checked` attribute
+        pub fn aria_checked(
+            &mut self,
+            value: html_sys::attributes::Tristate,
+        ) -> &mut Self {
+            self.element.set_aria_checked(Some(value));
+            self
+        }
+        /// Set the value of the `aria-colindex` attribute
+        pub fn aria_col_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_col_index(Some(value));
+            self
+        }
+        /// Set the value of the `aria-colindextext` attribute
+        pub fn aria_col_index_text(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_col_index_text(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-colspan` attribute
+        pub fn aria_col_span(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_col_span(Some(value));
+            self
+        }
+        /// Set the value of the `aria-controls` attribute
+        pub fn aria_controls_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_controls_elements(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-current` attribute
+        pub fn aria_current(
+            &mut self,
+            value: html_sys::attributes::AriaCurrent,
+        ) -> &mut Self {
+            self.element.set_aria_current(Some(value));
+            self
+        }
+        /// Set the value of the `aria-describedby` attribute
+        pub fn aria_described_by_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_described_by_elements(
+                    value.into_iter().map(Into::into).collect(),
+                );
+            self
+        }
+        /// Set the value of the `aria-description` attribute
+        pub fn aria_description(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_description(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-details` attribute
+        pub fn aria_details_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_details_elements(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-disabled` attribute
+        pub fn aria_disabled(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_disabled(value);
+            self
+        }
+        /// Set the value of the `aria-dropeffect` attribute
+        pub fn aria_drop_effect(
+            &mut self,
+            value: impl IntoIterator<
+                Item = impl Into<html_sys::attributes::AriaDropEffect>,
+            >,
+        ) -> &mut Self {
+            self.element
+                .set_aria_drop_effect(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-errormessage` attribute
+        pub fn aria_error_message_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_error_message_elements(
+                    value.into_iter().map(Into::into).collect(),
+                );
+            self
+        }
+        /// Set the value of the `aria-expanded` attribute
+        pub fn aria_expanded(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_expanded(Some(value));
+            self
+        }
+        /// Set the value of the `aria-flowto` attribute
+        pub fn aria_flow_to_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_flow_to_elements(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-grabbed` attribute
+        pub fn aria_grabbed(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_grabbed(Some(value));
+            self
+        }
+        /// Set the value of the `aria-haspopup` attribute
+        pub fn aria_has_popup(
+            &mut self,
+            value: html_sys::attributes::AriaHasPopup,
+        ) -> &mut Self {
+            self.element.set_aria_has_popup(Some(value));
+            self
+        }
+        /// Set the value of the `aria-hidden` attribute
+        pub fn aria_hidden(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_hidden(Some(value));
+            self
+        }
+        /// Set the value of the `aria-invalid` attribute
+        pub fn aria_invalid(
+            &mut self,
+            value: html_sys::attributes::AriaInvalid,
+        ) -> &mut Self {
+            self.element.set_aria_invalid(Some(value));
+            self
+        }
+        /// Set the value of the `aria-keyshortcuts` attribute
+        pub fn aria_key_shortcuts(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_key_shortcuts(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-label` attribute
+        pub fn aria_label(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_label(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-labelledby` attribute
+        pub fn aria_labelled_by_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_labelled_by_elements(
+                    value.into_iter().map(Into::into).collect(),
+                );
+            self
+        }
+        /// Set the value of the `aria-level` attribute
+        pub fn aria_level(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_level(Some(value));
+            self
+        }
+        /// Set the value of the `aria-live` attribute
+        pub fn aria_live(&mut self, value: html_sys::attributes::AriaLive) -> &mut Self {
+            self.element.set_aria_live(Some(value));
+            self
+        }
+        /// Set the value of the `aria-multiline` attribute
+        pub fn aria_multi_line(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_multi_line(value);
+            self
+        }
+        /// Set the value of the `aria-orientation` attribute
+        pub fn aria_orientation(
+            &mut self,
+            value: html_sys::attributes::AriaOrientation,
         ) -> &mut Self {
-            self.element.set_formtarget(Some(value.into()));
+            self.element.set_aria_orientation(Some(value));
             self
         }
-        /// Set the value of the `height` attribute
-        pub fn height(
+        /// Set the value of the `aria-owns` attribute
+        pub fn aria_owns_elements(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
         ) -> &mut Self {
-            self.element.set_height(Some(value.into()));
+            self.element
+                .set_aria_owns_elements(value.into_iter().map(Into::into).collect());
             self
         }
-        /// Set the value of the `list` attribute
-        pub fn list(
+        /// Set the value of the `aria-placeholder` attribute
+        pub fn aria_placeholder(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_list(Some(value.into()));
+            self.element.set_aria_placeholder(Some(value.into()));
             self
         }
-        /// Set the value of the `max` attribute
-        pub fn max(
+        /// Set the value of the `aria-posinset` attribute
+        pub fn aria_pos_in_set(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_pos_in_set(Some(value));
+            self
+        }
+        /// Set the value of the `aria-pressed` attribute
+        pub fn aria_pressed(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
+            value: html_sys::attributes::Tristate,
         ) -> &mut Self {
-            self.element.set_max(Some(value.into()));
+            self.element.set_aria_pressed(Some(value));
             self
         }
-        /// Set the value of the `maxlength` attribute
-        pub fn maxlength(
+        /// Set the value of the `aria-readonly` attribute
+        pub fn aria_read_only(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_read_only(value);
+            self
+        }
+        /// Set the value of the `aria-relevant` attribute
+        pub fn aria_relevant(
             &mut self,
-            value: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl IntoIterator<
+                Item = impl Into<html_sys::attributes::AriaRelevant>,
+            >,
         ) -> &mut Self {
-            self.element.set_maxlength(Some(value.into()));
+            self.element.set_aria_relevant(value.into_iter().map(Into::into).collect());
             self
         }
-        /// Set the value of the `min` attribute
-        pub fn min(
+        /// Set the value of the `aria-required` attribute
+        pub fn aria_required(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_required(value);
+            self
+        }
+        /// Set the value of the `aria-roledescription` attribute
+        pub fn aria_role_description(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_min(Some(value.into()));
+            self.element.set_aria_role_description(Some(value.into()));
             self
         }
-        /// Set the value of the `minlength` attribute
-        pub fn minlength(
+        /// Set the value of the `aria-rowindex` attribute
+        pub fn aria_row_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_row_index(Some(value));
+            self
+        }
+        /// Set the value of the `aria-rowindextext` attribute
+        pub fn aria_row_index_text(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_minlength(Some(value.into()));
+            self.element.set_aria_row_index_text(Some(value.into()));
             self
         }
-        /// Set the value of the `multiple` attribute
-        pub fn multiple(
+        /// Set the value of the `aria-rowspan` attribute
+        pub fn aria_row_span(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_row_span(Some(value));
+            self
+        }
+        /// Set the value of the `aria-selected` attribute
+        pub fn aria_selected(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_selected(Some(value));
+            self
+        }
+        /// Set the value of the `aria-setsize` attribute
+        pub fn aria_set_size(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_set_size(Some(value));
+            self
+        }
+        /// Set the value of the `aria-valuemax` attribute
+        pub fn aria_value_max(&mut self, value: f64) -> &mut Self {
+            self.element.set_aria_value_max(Some(value));
+            self
+        }
+        /// Set the value of the `aria-valuemin` attribute
+        pub fn aria_value_min(&mut self, value: f64) -> &mut Self {
+            self.element.set_aria_value_min(Some(value));
+            self
+        }
+        /// Set the value of the `aria-valuenow` attribute
+        pub fn aria_value_now(&mut self, value: f64) -> &mut Self {
+            self.element.set_aria_value_now(Some(value));
+            self
+        }
+        /// Set the value of the `aria-valuetext` attribute
+        pub fn aria_value_text(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_multiple(Some(value.into()));
+            self.element.set_aria_value_text(Some(value.into()));
             self
         }
-        /// Set the value of the `name` attribute
-        pub fn name(
+        /// Set the value of the `accesskey` attribute
+        pub fn access_key(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_name(Some(value.into()));
+            self.element.set_access_key(Some(value.into()));
             self
         }
-        /// Set the value of the `pattern` attribute
-        pub fn pattern(
+        /// Set the value of the `autocapitalize` attribute
+        pub fn auto_capitalize(
+            &mut self,
+            value: html_sys::attributes::AutoCapitalize,
+        ) -> &mut Self {
+            self.element.set_auto_capitalize(Some(value));
+            self
+        }
+        /// Set the value of the `autofocus` attribute
+        pub fn autofocus(&mut self, value: bool) -> &mut Self {
+            self.element.set_autofocus(value);
+            self
+        }
+        /// Set the value of the `class` attribute
+        pub fn class(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_pattern(Some(value.into()));
+            self.element.set_class(Some(value.into()));
             self
         }
-        /// Set the value of the `placeholder` attribute
-        pub fn placeholder(
+        /// Set the value of the `contenteditable` attribute
+        pub fn content_editable(
+            &mut self,
+            value: html_sys::attributes::ContentEditable,
+        ) -> &mut Self {
+            self.element.set_content_editable(Some(value));
+            self
+        }
+        /// Set the value of the `dir` attribute
+        pub fn direction(
+            &mut self,
+            value: html_sys::attributes::Direction,
+        ) -> &mut Self {
+            self.element.set_direction(Some(value));
+            self
+        }
+        /// Set the value of the `draggable` attribute
+        pub fn draggable(
+            &mut self,
+            value: html_sys::attributes::Draggable,
+        ) -> &mut Self {
+            self.element.set_draggable(Some(value));
+            self
+        }
+        /// Set the value of the `enterkeyhint` attribute
+        pub fn enter_key_hint(
+            &mut self,
+            value: html_sys::attributes::EnterKeyHint,
+        ) -> &mut Self {
+            self.element.set_enter_key_hint(Some(value));
+            self
+        }
+        /// Set the value of the `exportparts` attribute
+        pub fn export_parts(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_placeholder(Some(value.into()));
+            self.element.set_export_parts(Some(value.into()));
             self
         }
-        /// Set the value of the `popovertarget` attribute
-        pub fn popovertarget(
+        /// Set the value of the `hidden` attribute
+        pub fn hidden(&mut self, value: html_sys::attributes::Hidden) -> &mut Self {
+            self.element.set_hidden(Some(value));
+            self
+        }
+        /// Set the value of the `id` attribute
+        pub fn id(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_popovertarget(Some(value.into()));
+            self.element.set_id(Some(value.into()));
             self
         }
-        /// Set the value of the `popovertargetaction` attribute
-        pub fn popovertargetaction(
+        /// Set the value of the `inert` attribute
+        pub fn inert(&mut self, value: bool) -> &mut Self {
+            self.element.set_inert(value);
+            self
+        }
+        /// Set the value of the `inputmode` attribute
+        pub fn input_mode(
+            &mut self,
+            value: html_sys::attributes::InputMode,
+        ) -> &mut Self {
+            self.element.set_input_mode(Some(value));
+            self
+        }
+        /// Set the value of the `is` attribute
+        pub fn is_(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_popovertargetaction(Some(value.into()));
+            self.element.set_is_(Some(value.into()));
             self
         }
-        /// Set the value of the `readonly` attribute
-        pub fn readonly(
+        /// Set the value of the `itemid` attribute
+        pub fn item_id(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_readonly(Some(value.into()));
+            self.element.set_item_id(Some(value.into()));
             self
         }
-        /// Set the value of the `required` attribute
-        pub fn required(
+        /// Set the value of the `itemprop` attribute
+        pub fn item_prop(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_required(Some(value.into()));
+            self.element.set_item_prop(Some(value.into()));
             self
         }
-        /// Set the value of the `size` attribute
-        pub fn size(
+        /// Set the value of the `itemref` attribute
+        pub fn item_ref(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_size(Some(value.into()));
+            self.element.set_item_ref(Some(value.into()));
             self
         }
-        /// Set the value of the `src` attribute
-        pub fn src(
+        /// Set the value of the `itemscope` attribute
+        pub fn item_scope(&mut self, value: bool) -> &mut Self {
+            self.element.set_item_scope(value);
+            self
+        }
+        /// Set the value of the `itemtype` attribute
+        pub fn item_type(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_src(Some(value.into()));
+            self.element.set_item_type(Some(value.into()));
             self
         }
-        /// Set the value of the `step` attribute
-        pub fn step(
+        /// Set the value of the `lang` attribute
+        pub fn lang(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_step(Some(value.into()));
+            self.element.set_lang(Some(value.into()));
             self
         }
-        /// Set the value of the `type` attribute
-        pub fn type_(
+        /// Set the value of the `nonce` attribute
+        pub fn nonce(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_type_(Some(value.into()));
+            self.element.set_nonce(Some(value.into()));
             self
         }
-        /// Set the value of the `value` attribute
-        pub fn value(
+        /// Set the value of the `part` attribute
+        pub fn part(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_value(Some(value.into()));
+            self.element.set_part(Some(value.into()));
             self
         }
-        /// Set the value of the `width` attribute
-        pub fn width(
+        /// Set the value of the `slot` attribute
+        pub fn slot(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_width(Some(value.into()));
+            self.element.set_slot(Some(value.into()));
             self
         }
-        /// Set the value of the `role` attribute
-        pub fn role(
+        /// Set the value of the `spellcheck` attribute
+        pub fn spellcheck(
+            &mut self,
+            value: html_sys::attributes::Spellcheck,
+        ) -> &mut Self {
+            self.element.set_spellcheck(Some(value));
+            self
+        }
+        /// Set the value of the `style` attribute
+        pub fn style(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_role(Some(value.into()));
+            self.element.set_style(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-activedescendant` attribute
-        pub fn aria_active_descendant_element(
+        /// Set the value of the `tabindex` attribute
+        pub fn tab_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_tab_index(Some(value));
+            self
+        }
+        /// Set the value of the `title` attribute
+        pub fn title(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_active_descendant_element(Some(value.into()));
+            self.element.set_title(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-atomic` attribute
-        pub fn aria_atomic(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_atomic(value);
+        /// Set the value of the `translate` attribute
+        pub fn translate(
+            &mut self,
+            value: html_sys::attributes::Translate,
+        ) -> &mut Self {
+            self.element.set_translate(Some(value));
             self
         }
-        /// Set the value of the `aria-autocomplete` attribute
-        pub fn aria_auto_complete(
+        /// Set the value of the `onabort` attribute
+        pub fn onabort(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onabort(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onauxclick` attribute
+        pub fn onauxclick(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_auto_complete(Some(value.into()));
+            self.element.set_onauxclick(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-braillelabel` attribute
-        pub fn aria_braille_label(
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_braille_label(Some(value.into()));
+            self.element.set_onbeforeinput(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-brailleroledescription` attribute
-        pub fn aria_braille_role_description(
+        /// Set the value of the `onbeforematch` attribute
+        pub fn onbeforematch(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_braille_role_description(Some(value.into()));
+            self.element.set_onbeforematch(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-busy` attribute
-        pub fn aria_busy(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_busy(value);
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforetoggle(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-checked` attribute
-        pub fn aria_checked(
+        /// Set the value of the `onblur` attribute
+        pub fn onblur(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_checked(Some(value.into()));
+            self.element.set_onblur(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colindex` attribute
-        pub fn aria_col_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_col_index(Some(value));
+        /// Set the value of the `oncancel` attribute
+        pub fn oncancel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncancel(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colindextext` attribute
-        pub fn aria_col_index_text(
+        /// Set the value of the `oncanplay` attribute
+        pub fn oncanplay(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_col_index_text(Some(value.into()));
+            self.element.set_oncanplay(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colspan` attribute
-        pub fn aria_col_span(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_col_span(Some(value));
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplaythrough(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-controls` attribute
-        pub fn aria_controls_elements(
+        /// Set the value of the `onchange` attribute
+        pub fn onchange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_controls_elements(Some(value.into()));
+            self.element.set_onchange(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-current` attribute
-        pub fn aria_current(
+        /// Set the value of the `onclick` attribute
+        pub fn onclick(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_current(Some(value.into()));
+            self.element.set_onclick(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-describedby` attribute
-        pub fn aria_described_by_elements(
+        /// Set the value of the `onclose` attribute
+        pub fn onclose(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_described_by_elements(Some(value.into()));
+            self.element.set_onclose(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-description` attribute
-        pub fn aria_description(
+        /// Set the value of the `oncontextlost` attribute
+        pub fn oncontextlost(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_description(Some(value.into()));
+            self.element.set_oncontextlost(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-details` attribute
-        pub fn aria_details_elements(
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_details_elements(Some(value.into()));
+            self.element.set_oncontextmenu(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-disabled` attribute
-        pub fn aria_disabled(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_disabled(value);
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextrestored(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-dropeffect` attribute
-        pub fn aria_drop_effect(
+        /// Set the value of the `oncopy` attribute
+        pub fn oncopy(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_drop_effect(Some(value.into()));
+            self.element.set_oncopy(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-errormessage` attribute
-        pub fn aria_error_message_elements(
+        /// Set the value of the `oncuechange` attribute
+        pub fn oncuechange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_error_message_elements(Some(value.into()));
+            self.element.set_oncuechange(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-expanded` attribute
-        pub fn aria_expanded(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_expanded(value);
+        /// Set the value of the `oncut` attribute
+        pub fn oncut(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncut(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-flowto` attribute
-        pub fn aria_flow_to_elements(
+        /// Set the value of the `ondblclick` attribute
+        pub fn ondblclick(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_flow_to_elements(Some(value.into()));
+            self.element.set_ondblclick(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-grabbed` attribute
-        pub fn aria_grabbed(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_grabbed(value);
+        /// Set the value of the `ondrag` attribute
+        pub fn ondrag(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrag(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-haspopup` attribute
-        pub fn aria_has_popup(
+        /// Set the value of the `ondragend` attribute
+        pub fn ondragend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_has_popup(Some(value.into()));
+            self.element.set_ondragend(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-hidden` attribute
-        pub fn aria_hidden(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_hidden(value);
+        /// Set the value of the `ondragenter` attribute
+        pub fn ondragenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragenter(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-invalid` attribute
-        pub fn aria_invalid(
+        /// Set the value of the `ondragleave` attribute
+        pub fn ondragleave(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_invalid(Some(value.into()));
+            self.element.set_ondragleave(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-keyshortcuts` attribute
-        pub fn aria_key_shortcuts(
+        /// Set the value of the `ondragover` attribute
+        pub fn ondragover(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_key_shortcuts(Some(value.into()));
+            self.element.set_ondragover(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-label` attribute
-        pub fn aria_label(
+        /// Set the value of the `ondragstart` attribute
+        pub fn ondragstart(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_label(Some(value.into()));
+            self.element.set_ondragstart(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-labelledby` attribute
-        pub fn aria_labelled_by_elements(
+        /// Set the value of the `ondrop` attribute
+        pub fn ondrop(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_labelled_by_elements(Some(value.into()));
+            self.element.set_ondrop(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-level` attribute
-        pub fn aria_level(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_level(Some(value));
+        /// Set the value of the `ondurationchange` attribute
+        pub fn ondurationchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondurationchange(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-live` attribute
-        pub fn aria_live(
+        /// Set the value of the `onemptied` attribute
+        pub fn onemptied(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_live(Some(value.into()));
+            self.element.set_onemptied(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-multiline` attribute
-        pub fn aria_multi_line(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_multi_line(value);
+        /// Set the value of the `onended` attribute
+        pub fn onended(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onended(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-orientation` attribute
-        pub fn aria_orientation(
+        /// Set the value of the `onerror` attribute
+        pub fn onerror(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_orientation(Some(value.into()));
+            self.element.set_onerror(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-owns` attribute
-        pub fn aria_owns_elements(
+        /// Set the value of the `onfocus` attribute
+        pub fn onfocus(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_owns_elements(Some(value.into()));
+            self.element.set_onfocus(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-placeholder` attribute
-        pub fn aria_placeholder(
+        /// Set the value of the `onformdata` attribute
+        pub fn onformdata(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_placeholder(Some(value.into()));
+            self.element.set_onformdata(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-posinset` attribute
-        pub fn aria_pos_in_set(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_pos_in_set(Some(value));
+        /// Set the value of the `oninput` attribute
+        pub fn oninput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninput(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-pressed` attribute
-        pub fn aria_pressed(
+        /// Set the value of the `oninvalid` attribute
+        pub fn oninvalid(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_pressed(Some(value.into()));
+            self.element.set_oninvalid(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-readonly` attribute
-        pub fn aria_read_only(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_read_only(value);
+        /// Set the value of the `onkeydown` attribute
+        pub fn onkeydown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeydown(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-relevant` attribute
-        pub fn aria_relevant(
+        /// Set the value of the `onkeypress` attribute
+        pub fn onkeypress(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_relevant(Some(value.into()));
+            self.element.set_onkeypress(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-required` attribute
-        pub fn aria_required(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_required(value);
+        /// Set the value of the `onkeyup` attribute
+        pub fn onkeyup(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeyup(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-roledescription` attribute
-        pub fn aria_role_description(
+        /// Set the value of the `onload` attribute
+        pub fn onload(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_role_description(Some(value.into()));
+            self.element.set_onload(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowindex` attribute
-        pub fn aria_row_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_row_index(Some(value));
+        /// Set the value of the `onloadeddata` attribute
+        pub fn onloadeddata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadeddata(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowindextext` attribute
-        pub fn aria_row_index_text(
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_row_index_text(Some(value.into()));
+            self.element.set_onloadedmetadata(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowspan` attribute
-        pub fn aria_row_span(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_row_span(Some(value));
+        /// Set the value of the `onloadstart` attribute
+        pub fn onloadstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadstart(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-selected` attribute
-        pub fn aria_selected(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_selected(value);
+        /// Set the value of the `onmousedown` attribute
+        pub fn onmousedown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousedown(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-setsize` attribute
-        pub fn aria_set_size(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_set_size(Some(value));
+        /// Set the value of the `onmouseenter` attribute
+        pub fn onmouseenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseenter(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuemax` attribute
-        pub fn aria_value_max(&mut self, value: f64) -> &mut Self {
-            self.element.set_aria_value_max(Some(value));
+        /// Set the value of the `onmouseleave` attribute
+        pub fn onmouseleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseleave(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuemin` attribute
-        pub fn aria_value_min(&mut self, value: f64) -> &mut Self {
-            self.element.set_aria_value_min(Some(value));
+        /// Set the value of the `onmousemove` attribute
+        pub fn onmousemove(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousemove(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuenow` attribute
-        pub fn aria_value_now(&mut self, value: f64) -> &mut Self {
-            self.element.set_aria_value_now(Some(value));
+        /// Set the value of the `onmouseout` attribute
+        pub fn onmouseout(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseout(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuetext` attribute
-        pub fn aria_value_text(
+        /// Set the value of the `onmouseover` attribute
+        pub fn onmouseover(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_value_text(Some(value.into()));
+            self.element.set_onmouseover(Some(value.into()));
             self
         }
-        /// Set the value of the `accesskey` attribute
-        pub fn access_key(
+        /// Set the value of the `onmouseup` attribute
+        pub fn onmouseup(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_access_key(Some(value.into()));
+            self.element.set_onmouseup(Some(value.into()));
             self
         }
-        /// Set the value of the `autocapitalize` attribute
-        pub fn auto_capitalize(
+        /// Set the value of the `onpaste` attribute
+        pub fn onpaste(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_auto_capitalize(Some(value.into()));
+            self.element.set_onpaste(Some(value.into()));
             self
         }
-        /// Set the value of the `autofocus` attribute
-        pub fn autofocus(&mut self, value: bool) -> &mut Self {
-            self.element.set_autofocus(value);
+        /// Set the value of the `onpause` attribute
+        pub fn onpause(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onpause(Some(value.into()));
             self
         }
-        /// Set the value of the `class` attribute
-        pub fn class(
+        /// Set the value of the `onplay` attribute
+        pub fn onplay(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_class(Some(value.into()));
+            self.element.set_onplay(Some(value.into()));
             self
         }
-        /// Set the value of the `contenteditable` attribute
-        pub fn content_editable(
+        /// Set the value of the `onplaying` attribute
+        pub fn onplaying(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_content_editable(Some(value.into()));
+            self.element.set_onplaying(Some(value.into()));
             self
         }
-        /// Set the value of the `dir` attribute
-        pub fn direction(
+        /// Set the value of the `onprogress` attribute
+        pub fn onprogress(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_direction(Some(value.into()));
+            self.element.set_onprogress(Some(value.into()));
             self
         }
-        /// Set the value of the `draggable` attribute
-        pub fn draggable(&mut self, value: bool) -> &mut Self {
-            self.element.set_draggable(value);
+        /// Set the value of the `onratechange` attribute
+        pub fn onratechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onratechange(Some(value.into()));
             self
         }
-        /// Set the value of the `enterkeyhint` attribute
-        pub fn enter_key_hint(
+        /// Set the value of the `onreset` attribute
+        pub fn onreset(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_enter_key_hint(Some(value.into()));
+            self.element.set_onreset(Some(value.into()));
             self
         }
-        /// Set the value of the `exportparts` attribute
-        pub fn export_parts(
+        /// Set the value of the `onresize` attribute
+        pub fn onresize(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_export_parts(Some(value.into()));
+            self.element.set_onresize(Some(value.into()));
             self
         }
-        /// Set the value of the `hidden` attribute
-        pub fn hidden(
+        /// Set the value of the `onscroll` attribute
+        pub fn onscroll(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_hidden(Some(value.into()));
+            self.element.set_onscroll(Some(value.into()));
             self
         }
-        /// Set the value of the `id` attribute
-        pub fn id(
+        /// Set the value of the `onscrollend` attribute
+        pub fn onscrollend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_id(Some(value.into()));
+            self.element.set_onscrollend(Some(value.into()));
             self
         }
-        /// Set the value of the `inert` attribute
-        pub fn inert(&mut self, value: bool) -> &mut Self {
-            self.element.set_inert(value);
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onsecuritypolicyviolation(Some(value.into()));
             self
         }
-        /// Set the value of the `inputmode` attribute
-        pub fn input_mode(
+        /// Set the value of the `onseeked` attribute
+        pub fn onseeked(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_input_mode(Some(value.into()));
+            self.element.set_onseeked(Some(value.into()));
             self
         }
-        /// Set the value of the `is` attribute
-        pub fn is_(
+        /// Set the value of the `onseeking` attribute
+        pub fn onseeking(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_is_(Some(value.into()));
+            self.element.set_onseeking(Some(value.into()));
             self
         }
-        /// Set the value of the `itemid` attribute
-        pub fn item_id(
+        /// Set the value of the `onselect` attribute
+        pub fn onselect(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_id(Some(value.into()));
+            self.element.set_onselect(Some(value.into()));
             self
         }
-        /// Set the value of the `itemprop` attribute
-        pub fn item_prop(
+        /// Set the value of the `onslotchange` attribute
+        pub fn onslotchange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_prop(Some(value.into()));
+            self.element.set_onslotchange(Some(value.into()));
             self
         }
-        /// Set the value of the `itemref` attribute
-        pub fn item_ref(
+        /// Set the value of the `onstalled` attribute
+        pub fn onstalled(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_ref(Some(value.into()));
+            self.element.set_onstalled(Some(value.into()));
             self
         }
-        /// Set the value of the `itemscope` attribute
-        pub fn item_scope(
+        /// Set the value of the `onsubmit` attribute
+        pub fn onsubmit(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_scope(Some(value.into()));
+            self.element.set_onsubmit(Some(value.into()));
             self
         }
-        /// Set the value of the `itemtype` attribute
-        pub fn item_type(
+        /// Set the value of the `onsuspend` attribute
+        pub fn onsuspend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_type(Some(value.into()));
+            self.element.set_onsuspend(Some(value.into()));
             self
         }
-        /// Set the value of the `lang` attribute
-        pub fn lang(
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_lang(Some(value.into()));
+            self.element.set_ontimeupdate(Some(value.into()));
             self
         }
-        /// Set the value of the `nonce` attribute
-        pub fn nonce(
+        /// Set the value of the `ontoggle` attribute
+        pub fn ontoggle(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_nonce(Some(value.into()));
+            self.element.set_ontoggle(Some(value.into()));
             self
         }
-        /// Set the value of the `part` attribute
-        pub fn part(
+        /// Set the value of the `onvolumechange` attribute
+        pub fn onvolumechange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_part(Some(value.into()));
+            self.element.set_onvolumechange(Some(value.into()));
             self
         }
-        /// Set the value of the `slot` attribute
-        pub fn slot(
+        /// Set the value of the `onwaiting` attribute
+        pub fn onwaiting(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_slot(Some(value.into()));
+            self.element.set_onwaiting(Some(value.into()));
             self
         }
-        /// Set the value of the `spellcheck` attribute
-        pub fn spellcheck(
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_spellcheck(Some(value.into()));
+            self.element.set_onwebkitanimationend(Some(value.into()));
             self
         }
-        /// Set the value of the `style` attribute
-        pub fn style(
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_style(Some(value.into()));
+            self.element.set_onwebkitanimationiteration(Some(value.into()));
             self
         }
-        /// Set the value of the `tabindex` attribute
-        pub fn tab_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_tab_index(Some(value));
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkitanimationstart(Some(value.into()));
             self
         }
-        /// Set the value of the `title` attribute
-        pub fn title(
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_title(Some(value.into()));
+            self.element.set_onwebkittransitionend(Some(value.into()));
             self
         }
-        /// Set the value of the `translate` attribute
-        pub fn translate(&mut self, value: bool) -> &mut Self {
-            self.element.set_translate(value);
+        /// Set the value of the `onwheel` attribute
+        pub fn onwheel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwheel(Some(value.into()));
             self
         }
     }
@@ -32,15 +32,15 @@ pub mod element {
     }
     impl KeyboardInput {
         /// Get the value of the `role` attribute
-        pub fn role(&self) -> std::option::Option<&str> {
-            self.sys.role.as_deref()
+        pub fn role(&self) -> std::option::Option<&html_sys::attributes::Role> {
+            self.sys.role.as_ref()
         }
         /// Set the value of the `role` attribute
         pub fn set_role(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Role>,
         ) {
-            self.sys.role = value.map(|v| v.into());
+            self.sys.role = value;
         }
         /// Get the value of the `aria-activedescendant` attribute
         pub fn aria_active_descendant_element(&self) -> std::option::Option<&str> {
@@ -62,15 +62,17 @@ pub mod element {
             self.sys.aria_atomic = value;
         }
         /// Get the value of the `aria-autocomplete` attribute
-        pub fn aria_auto_complete(&self) -> std::option::Option<&str> {
-            self.sys.aria_auto_complete.as_deref()
+        pub fn aria_auto_complete(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaAutoComplete> {
+            self.sys.aria_auto_complete.as_ref()
         }
         /// Set the value of the `aria-autocomplete` attribute
         pub fn set_aria_auto_complete(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaAutoComplete>,
         ) {
-            self.sys.aria_auto_complete = value.map(|v| v.into());
+            self.sys.aria_auto_complete = value;
         }
         /// Get the value of the `aria-brailleroledescription` attribute
         pub fn aria_braille_role_description(&self) -> std::option::Option<&str> {
@@ -92,15 +94,17 @@ pub mod element {
             self.sys.aria_busy = value;
         }
         /// Get the value of the `aria-checked` attribute
-        pub fn aria_checked(&self) -> std::option::Option<&str> {
-            self.sys.aria_checked.as_deref()
+        pub fn aria_checked(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Tristate> {
+            self.sys.aria_checked.as_ref()
         }
         /// Set the value of the `aria-checked` attribute
         pub fn set_aria_checked(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Tristate>,
         ) {
-            self.sys.aria_checked = value.map(|v| v.into());
+            self.sys.aria_checked = value;
         }
         /// Get the value of the `aria-colcount` attribute
         pub fn aria_col_count(&self) -> std::option::Option<i64> {
@@ -138,37 +142,39 @@ pub mod element {
             self.sys.aria_col_span = value;
         }
         /// Get the value of the `aria-controls` attribute
-        pub fn aria_controls_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_controls_elements.as_deref()
+        pub fn aria_controls_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_controls_elements
         }
         /// Set the value of the `aria-controls` attribute
         pub fn set_aria_controls_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_controls_elements = value.map(|v| v.into());
+            self.sys.aria_controls_elements = value;
         }
         /// Get the value of the `aria-current` attribute
-        pub fn aria_current(&self) -> std::option::Option<&str> {
-            self.sys.aria_current.as_deref()
+        pub fn aria_current(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaCurrent> {
+            self.sys.aria_current.as_ref()
         }
         /// Set the value of the `aria-current` attribute
         pub fn set_aria_current(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaCurrent>,
         ) {
-            self.sys.aria_current = value.map(|v| v.into());
+            self.sys.aria_current = value;
         }
         /// Get the value of the `aria-describedby` attribute
-        pub fn aria_described_by_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_described_by_elements.as_deref()
+        pub fn aria_described_by_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_described_by_elements
         }
         /// Set the value of the `aria-describedby` attribute
         pub fn set_aria_described_by_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_described_by_elements = value.map(|v| v.into());
+            self.sys.aria_described_by_elements = value;
         }
         /// Get the value of the `aria-description` attribute
         pub fn aria_description(&self) -> std::option::Option<&str> {
@@ -182,15 +188,15 @@ pub mod element {
             self.sys.aria_description = value.map(|v| v.into());
         }
         /// Get the value of the `aria-details` attribute
-        pub fn aria_details_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_details_elements.as_deref()
+        pub fn aria_details_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_details_elements
         }
         /// Set the value of the `aria-details` attribute
         pub fn set_aria_details_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_details_elements = value.map(|v| v.into());
+            self.sys.aria_details_elements = value;
         }
         /// Get the value of the `aria-disabled` attribute
         pub fn aria_disabled(&self) -> bool {
@@ -201,83 +207,87 @@ pub mod element {
             self.sys.aria_disabled = value;
         }
         /// Get the value of the `aria-dropeffect` attribute
-        pub fn aria_drop_effect(&self) -> std::option::Option<&str> {
-            self.sys.aria_drop_effect.as_deref()
+        pub fn aria_drop_effect(&self) -> &[html_sys::attributes::AriaDropEffect] {
+            &self.sys.aria_drop_effect
         }
         /// Set the value of the `aria-dropeffect` attribute
         pub fn set_aria_drop_effect(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<html_sys::attributes::AriaDropEffect>,
         ) {
-            self.sys.aria_drop_effect = value.map(|v| v.into());
+            self.sys.aria_drop_effect = value;
         }
         /// Get the value of the `aria-errormessage` attribute
-        pub fn aria_error_message_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_error_message_elements.as_deref()
+        pub fn aria_error_message_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_error_message_elements
         }
         /// Set the value of the `aria-errormessage` attribute
         pub fn set_aria_error_message_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_error_message_elements = value.map(|v| v.into());
+            self.sys.aria_error_message_elements = value;
         }
         /// Get the value of the `aria-expanded` attribute
-        pub fn aria_expanded(&self) -> bool {
+        pub fn aria_expanded(&self) -> std::option::Option<bool> {
             self.sys.aria_expanded
         }
         /// Set the value of the `aria-expanded` attribute
-        pub fn set_aria_expanded(&mut self, value: bool) {
+        pub fn set_aria_expanded(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_expanded = value;
         }
         /// Get the value of the `aria-flowto` attribute
-        pub fn aria_flow_to_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_flow_to_elements.as_deref()
+        pub fn aria_flow_to_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_flow_to_elements
         }
         /// Set the value of the `aria-flowto` attribute
         pub fn set_aria_flow_to_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_flow_to_elements = value.map(|v| v.into());
+            self.sys.aria_flow_to_elements = value;
         }
         /// Get the value of the `aria-grabbed` attribute
-        pub fn aria_grabbed(&self) -> bool {
+        pub fn aria_grabbed(&self) -> std::option::Option<bool> {
             self.sys.aria_grabbed
         }
         /// Set the value of the `aria-grabbed` attribute
-        pub fn set_aria_grabbed(&mut self, value: bool) {
+        pub fn set_aria_grabbed(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_grabbed = value;
         }
         /// Get the value of the `aria-haspopup` attribute
-        pub fn aria_has_popup(&self) -> std::option::Option<&str> {
-            self.sys.aria_has_popup.as_deref()
+        pub fn aria_has_popup(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaHasPopup> {
+            self.sys.aria_has_popup.as_ref()
         }
         /// Set the value of the `aria-haspopup` attribute
         pub fn set_aria_has_popup(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaHasPopup>,
         ) {
-            self.sys.aria_has_popup = value.map(|v| v.into());
+            self.sys.aria_has_popup = value;
         }
         /// Get the value of the `aria-hidden` attribute
-        pub fn aria_hidden(&self) -> bool {
+        pub fn aria_hidden(&self) -> std::option::Option<bool> {
             self.sys.aria_hidden
         }
         /// Set the value of the `aria-hidden` attribute
-        pub fn set_aria_hidden(&mut self, value: bool) {
+        pub fn set_aria_hidden(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_hidden = value;
         }
         /// Get the value of the `aria-invalid` attribute
-        pub fn aria_invalid(&self) -> std::option::Option<&str> {
-            self.sys.aria_invalid.as_deref()
+        pub fn aria_invalid(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaInvalid> {
+            self.sys.aria_invalid.as_ref()
         }
         /// Set the value of the `aria-invalid` attribute
         pub fn set_aria_invalid(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaInvalid>,
         ) {
-            self.sys.aria_invalid = value.map(|v| v.into());
+            self.sys.aria_invalid = value;
         }
         /// Get the value of the `aria-keyshortcuts` attribute
         pub fn aria_key_shortcuts(&self) -> std::option::Option<&str> {
@@ -299,15 +309,15 @@ pub mod element {
             self.sys.aria_level = value;
         }
         /// Get the value of the `aria-live` attribute
-        pub fn aria_live(&self) -> std::option::Option<&str> {
-            self.sys.aria_live.as_deref()
+        pub fn aria_live(&self) -> std::option::Option<&html_sys::attributes::AriaLive> {
+            self.sys.aria_live.as_ref()
         }
         /// Set the value of the `aria-live` attribute
         pub fn set_aria_live(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaLive>,
         ) {
-            self.sys.aria_live = value.map(|v| v.into());
+            self.sys.aria_live = value;
         }
         /// Get the value of the `aria-modal` attribute
         pub fn aria_modal(&self) -> bool {
@@ -334,26 +344,28 @@ pub mod element {
             self.sys.aria_multi_selectable = value;
         }
         /// Get the value of the `aria-orientation` attribute
-        pub fn aria_orientation(&self) -> std::option::Option<&str> {
-            self.sys.aria_orientation.as_deref()
+        pub fn aria_orientation(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AriaOrientation> {
+            self.sys.aria_orientation.as_ref()
         }
         /// Set the value of the `aria-orientation` attribute
         pub fn set_aria_orientation(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaOrientation>,
         ) {
-            self.sys.aria_orientation = value.map(|v| v.into());
+            self.sys.aria_orientation = value;
         }
         /// Get the value of the `aria-owns` attribute
-        pub fn aria_owns_elements(&self) -> std::option::Option<&str> {
-            self.sys.aria_owns_elements.as_deref()
+        pub fn aria_owns_elements(&self) -> &[std::borrow::Cow<'static, str>] {
+            &self.sys.aria_owns_elements
         }
         /// Set the value of the `aria-owns` attribute
         pub fn set_aria_owns_elements(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<std::borrow::Cow<'static, str>>,
         ) {
-            self.sys.aria_owns_elements = value.map(|v| v.into());
+            self.sys.aria_owns_elements = value;
         }
         /// Get the value of the `aria-placeholder` attribute
         pub fn aria_placeholder(&self) -> std::option::Option<&str> {
@@ -375,15 +387,17 @@ pub mod element {
             self.sys.aria_pos_in_set = value;
         }
         /// Get the value of the `aria-pressed` attribute
-        pub fn aria_pressed(&self) -> std::option::Option<&str> {
-            self.sys.aria_pressed.as_deref()
+        pub fn aria_pressed(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Tristate> {
+            self.sys.aria_pressed.as_ref()
         }
         /// Set the value of the `aria-pressed` attribute
         pub fn set_aria_pressed(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Tristate>,
         ) {
-            self.sys.aria_pressed = value.map(|v| v.into());
+            self.sys.aria_pressed = value;
         }
         /// Get the value of the `aria-readonly` attribute
         pub fn aria_read_only(&self) -> bool {
@@ -394,15 +408,15 @@ pub mod element {
             self.sys.aria_read_only = value;
         }
         /// Get the value of the `aria-relevant` attribute
-        pub fn aria_relevant(&self) -> std::option::Option<&str> {
-            self.sys.aria_relevant.as_deref()
+        pub fn aria_relevant(&self) -> &[html_sys::attributes::AriaRelevant] {
+            &self.sys.aria_relevant
         }
         /// Set the value of the `aria-relevant` attribute
         pub fn set_aria_relevant(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::vec::Vec<html_sys::attributes::AriaRelevant>,
         ) {
-            self.sys.aria_relevant = value.map(|v| v.into());
+            self.sys.aria_relevant = value;
         }
         /// Get the value of the `aria-required` attribute
         pub fn aria_required(&self) -> bool {
@@ -459,11 +473,11 @@ pub mod element {
             self.sys.aria_row_span = value;
         }
         /// Get the value of the `aria-selected` attribute
-        pub fn aria_selected(&self) -> bool {
+        pub fn aria_selected(&self) -> std::option::Option<bool> {
             self.sys.aria_selected
         }
         /// Set the value of the `aria-selected` attribute
-        pub fn set_aria_selected(&mut self, value: bool) {
+        pub fn set_aria_selected(&mut self, value: std::option::Option<bool>) {
             self.sys.aria_selected = value;
         }
         /// Get the value of the `aria-setsize` attribute
@@ -475,15 +489,15 @@ pub mod element {
             self.sys.aria_set_size = value;
         }
         /// Get the value of the `aria-sort` attribute
-        pub fn aria_sort(&self) -> std::option::Option<&str> {
-            self.sys.aria_sort.as_deref()
+        pub fn aria_sort(&self) -> std::option::Option<&html_sys::attributes::AriaSort> {
+            self.sys.aria_sort.as_ref()
         }
         /// Set the value of the `aria-sort` attribute
         pub fn set_aria_sort(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AriaSort>,
         ) {
-            self.sys.aria_sort = value.map(|v| v.into());
+            self.sys.aria_sort = value;
         }
         /// Get the value of the `aria-valuemax` attribute
         pub fn aria_value_max(&self) -> std::option::Option<f64> {
@@ -532,15 +546,17 @@ pub mod element {
             self.sys.access_key = value.map(|v| v.into());
         }
         /// Get the value of the `autocapitalize` attribute
-        pub fn auto_capitalize(&self) -> std::option::Option<&str> {
-            self.sys.auto_capitalize.as_deref()
+        pub fn auto_capitalize(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AutoCapitalize> {
+            self.sys.auto_capitalize.as_ref()
         }
         /// Set the value of the `autocapitalize` attribute
         pub fn set_auto_capitalize(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AutoCapitalize>,
         ) {
-            self.sys.auto_capitalize = value.map(|v| v.into());
+            self.sys.auto_capitalize = value;
         }
         /// Get the value of the `autofocus` attribute
         pub fn autofocus(&self) -> bool {
@@ -562,45 +578,56 @@ pub mod element {
             self.sys.class = value.map(|v| v.into());
         }
         /// Get the value of the `contenteditable` attribute
-        pub fn content_editable(&self) -> std::option::Option<&str> {
-            self.sys.content_editable.as_deref()
+        pub fn content_editable(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::ContentEditable> {
+            self.sys.content_editable.as_ref()
         }
         /// Set the value of the `contenteditable` attribute
         pub fn set_content_editable(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::ContentEditable>,
         ) {
-            self.sys.content_editable = value.map(|v| v.into());
+            self.sys.content_editable = value;
         }
         /// Get the value of the `dir` attribute
-        pub fn direction(&self) -> std::option::Option<&str> {
-            self.sys.direction.as_deref()
+        pub fn direction(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Direction> {
+            self.sys.direction.as_ref()
         }
         /// Set the value of the `dir` attribute
         pub fn set_direction(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Direction>,
         ) {
-            self.sys.direction = value.map(|v| v.into());
+            self.sys.direction = value;
         }
         /// Get the value of the `draggable` attribute
-        pub fn draggable(&self) -> bool {
-            self.sys.draggable
+        pub fn draggable(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Draggable> {
+            self.sys.draggable.as_ref()
         }
         /// Set the value of the `draggable` attribute
-        pub fn set_draggable(&mut self, value: bool) {
+        pub fn set_draggable(
+            &mut self,
+            value: std::option::Option<html_sys::attributes::Draggable>,
+        ) {
             self.sys.draggable = value;
         }
         /// Get the value of the `enterkeyhint` attribute
-        pub fn enter_key_hint(&self) -> std::option::Option<&str> {
-            self.sys.enter_key_hint.as_deref()
+        pub fn enter_key_hint(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::EnterKeyHint> {
+            self.sys.enter_key_hint.as_ref()
         }
         /// Set the value of the `enterkeyhint` attribute
         pub fn set_enter_key_hint(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::EnterKeyHint>,
         ) {
-            self.sys.enter_key_hint = value.map(|v| v.into());
+            self.sys.enter_key_hint = value;
         }
         /// Get the value of the `exportparts` attribute
         pub fn export_parts(&self) -> std::option::Option<&str> {
@@ -614,15 +641,15 @@ pub mod element {
             self.sys.export_parts = value.map(|v| v.into());
         }
         /// Get the value of the `hidden` attribute
-        pub fn hidden(&self) -> std::option::Option<&str> {
-            self.sys.hidden.as_deref()
+        pub fn hidden(&self) -> std::option::Option<&html_sys::attributes::Hidden> {
+            self.sys.hidden.as_ref()
         }
         /// Set the value of the `hidden` attribute
         pub fn set_hidden(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Hidden>,
         ) {
-            self.sys.hidden = value.map(|v| v.into());
+            self.sys.hidden = value;
         }
         /// Get the value of the `id` attribute
         pub fn id(&self) -> std::option::Option<&str> {
@@ -644,15 +671,17 @@ pub mod element {
             self.sys.inert = value;
         }
         /// Get the value of the `inputmode` attribute
-        pub fn input_mode(&self) -> std::option::Option<&str> {
-            self.sys.input_mode.as_deref()
+        pub fn input_mode(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::InputMode> {
+            self.sys.input_mode.as_ref()
         }
         /// Set the value of the `inputmode` attribute
         pub fn set_input_mode(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::InputMode>,
         ) {
-            self.sys.input_mode = value.map(|v| v.into());
+            self.sys.input_mode = value;
         }
         /// Get the value of the `is` attribute
         pub fn is_(&self) -> std::option::Option<&str> {
@@ -699,15 +728,12 @@ pub mod element {
             self.sys.item_ref = value.map(|v| v.into());
         }
         /// Get the value of the `itemscope` attribute
-        pub fn item_scope(&self) -> std::option::Option<&str> {
-            self.sys.item_scope.as_deref()
+        pub fn item_scope(&self) -> bool {
+            self.sys.item_scope
         }
         /// Set the value of the `itemscope` attribute
-        pub fn set_item_scope(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.item_scope = value.map(|v| v.into());
+        pub fn set_item_scope(&mut self, value: bool) {
+            self.sys.item_scope = value;
         }
         /// Get the value of the `itemtype` attribute
         pub fn item_type(&self) -> std::option::Option<&str> {
@@ -765,15 +791,17 @@ pub mod element {
             self.sys.slot = value.map(|v| v.into());
         }
         /// Get the value of the `spellcheck` attribute
-        pub fn spellcheck(&self) -> std::option::Option<&str> {
-            self.sys.spellcheck.as_deref()
+        pub fn spellcheck(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Spellcheck> {
+            self.sys.spellcheck.as_ref()
         }
         /// Set the value of the `spellcheck` attribute
         pub fn set_spellcheck(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Spellcheck>,
         ) {
-            self.sys.spellcheck = value.map(|v| v.into());
+            self.sys.spellcheck = value;
         }
         /// Get the value of the `style` attribute
         pub fn style(&self) -> std::option::Option<&str> {
@@ -806,735 +834,3768 @@ pub mod element {
             self.sys.title = value.map(|v| v.into());
         }
         /// Get the value of the `translate` attribute
-        pub fn translate(&self) -> bool {
-            self.sys.translate
+        pub fn translate(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Translate> {
+            self.sys.translate.as_ref()
         }
         /// Set the value of the `translate` attribute
-        pub fn set_translate(&mut self, value: bool) {
+        pub fn set_translate(
+            &mut self,
+            value: std::option::Option<html_sys::attributes::Translate>,
+        ) {
             self.sys.translate = value;
         }
-    }
-    impl KeyboardInput {
-        /// Access the element's children
-        pub fn children(&self) -> &[super::child::KeyboardInputChild] {
-            self.children.as_ref()
-        }
-        /// Mutably access the element's children
-        pub fn children_mut(&mut self) -> &mut Vec<super::child::KeyboardInputChild> {
-            &mut self.children
+        /// Get the value of the `onabort` attribute
+        pub fn onabort(&self) -> std::option::Option<&str> {
+            self.sys.onabort.as_deref()
         }
-    }
-    impl crate::Render for KeyboardInput {
-        fn render(
-            &self,
-            f: &mut std::fmt::Formatter<'_>,
-            depth: usize,
-        ) -> std::fmt::Result {
-            write!(f, "{:level$}", "", level = depth * 4)?;
-            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-            if !self.children.is_empty() {
-                write!(f, "\n")?;
-            }
-            for el in &self.children {
-                crate::Render::render(&el, f, depth)?;
-                write!(f, "\n")?;
-            }
-            write!(f, "{:level$}", "", level = depth * 4)?;
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            Ok(())
+        /// Set the value of the `onabort` attribute
+        pub fn set_onabort(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onabort = value.map(|v| v.into());
         }
-    }
-    impl std::fmt::Debug for KeyboardInput {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            crate::Render::render(self, f, 0)?;
-            Ok(())
+        /// Get the value of the `onauxclick` attribute
+        pub fn onauxclick(&self) -> std::option::Option<&str> {
+            self.sys.onauxclick.as_deref()
         }
-    }
-    impl std::fmt::Display for KeyboardInput {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-            for el in &self.children {
-                write!(f, "{el}")?;
-            }
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            Ok(())
+        /// Set the value of the `onauxclick` attribute
+        pub fn set_onauxclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onauxclick = value.map(|v| v.into());
         }
-    }
-    impl crate::HtmlElement for KeyboardInput {
-        fn tag_name(&self) -> &'static str {
-            "kbd"
+        /// Get the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(&self) -> std::option::Option<&str> {
+            self.sys.onbeforeinput.as_deref()
         }
-        fn attributes(
-            &self,
-        ) -> std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        > {
-            use html_sys::ElementDescription;
-            self.sys.attributes()
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn set_onbeforeinput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforeinput = value.map(|v| v.into());
         }
-        fn data(
-            &self,
-        ) -> std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        > {
-            use html_sys::ElementDescription;
-            self.sys.data()
+        /// Get the value of the `onbeforematch` attribute
+        pub fn onbeforematch(&self) -> std::option::Option<&str> {
+            self.sys.onbeforematch.as_deref()
         }
-        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
-            self.children.iter().map(From::from).collect()
+        /// Set the value of the `onbeforematch` attribute
+        pub fn set_onbeforematch(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforematch = value.map(|v| v.into());
         }
-    }
-    impl crate::FlowContent for KeyboardInput {}
-    impl crate::PhrasingContent for KeyboardInput {}
-    impl crate::PalpableContent for KeyboardInput {}
-    impl std::convert::Into<html_sys::text::KeyboardInput> for KeyboardInput {
-        fn into(self) -> html_sys::text::KeyboardInput {
-            self.sys
+        /// Get the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(&self) -> std::option::Option<&str> {
+            self.sys.onbeforetoggle.as_deref()
         }
-    }
-    impl From<html_sys::text::KeyboardInput> for KeyboardInput {
-        fn from(sys: html_sys::text::KeyboardInput) -> Self {
-            Self { sys, children: vec![] }
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn set_onbeforetoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforetoggle = value.map(|v| v.into());
         }
-    }
-}
-pub mod child {
-    /// The permitted child items for the `KeyboardInput` element
-    #[derive(PartialEq, Clone)]
-    pub enum KeyboardInputChild {
-        /// The Abbreviation element
-        Abbreviation(crate::generated::all::Abbreviation),
-        /// The Anchor element
-        Anchor(crate::generated::all::Anchor),
-        /// The Audio element
-        Audio(crate::generated::all::Audio),
-        /// The BidirectionalIsolate element
-        BidirectionalIsolate(crate::generated::all::BidirectionalIsolate),
-        /// The BidirectionalTextOverride element
-        BidirectionalTextOverride(crate::generated::all::BidirectionalTextOverride),
-        /// The Bold element
-        Bold(crate::generated::all::Bold),
-        /// The Button element
-        Button(crate::generated::all::Button),
-        /// The Canvas element
-        Canvas(crate::generated::all::Canvas),
-        /// The Cite element
-        Cite(crate::generated::all::Cite),
-        /// The Code element
-        Code(crate::generated::all::Code),
-        /// The Data element
-        Data(crate::generated::all::Data),
-        /// The DataList element
-        DataList(crate::generated::all::DataList),
-        /// The Definition element
-        Definition(crate::generated::all::Definition),
-        /// The DeletedText element
-        DeletedText(crate::generated::all::DeletedText),
-        /// The Embed element
-        Embed(crate::generated::all::Embed),
-        /// The Emphasis element
-        Emphasis(crate::generated::all::Emphasis),
-        /// The Iframe element
-        Iframe(crate::generated::all::Iframe),
-        /// The Image element
-        Image(crate::generated::all::Image),
-        /// The ImageMap element
-        ImageMap(crate::generated::all::ImageMap),
-        /// The ImageMapArea element
-        ImageMapArea(crate::generated::all::ImageMapArea),
-        /// The Input element
-        Input(crate::generated::all::Input),
-        /// The InsertedText element
-        InsertedText(crate::generated::all::InsertedText),
-        /// The Italic element
-        Italic(crate::generated::all::Italic),
-        /// The KeyboardInput element
-        KeyboardInput(crate::generated::all::KeyboardInput),
-        /// The Label element
-        Label(crate::generated::all::Label),
-        /// The LineBreak element
-        LineBreak(crate::generated::all::LineBreak),
-        /// The LineBreakOpportunity element
-        LineBreakOpportunity(crate::generated::all::LineBreakOpportunity),
-        /// The Link element
-        Link(crate::generated::all::Link),
-        /// The MarkText element
-        MarkText(crate::generated::all::MarkText),
-        /// The Meta element
-        Meta(crate::generated::all::Meta),
-        /// The Meter element
-        Meter(crate::generated::all::Meter),
-        /// The NoScript element
-        NoScript(crate::generated::all::NoScript),
-        /// The Object element
-        Object(crate::generated::all::Object),
-        /// The Output element
-        Output(crate::generated::all::Output),
-        /// The Picture element
-        Picture(crate::generated::all::Picture),
-        /// The Progress element
-        Progress(crate::generated::all::Progress),
-        /// The Quotation element
-        Quotation(crate::generated::all::Quotation),
-        /// The RubyAnnotation element
-        RubyAnnotation(crate::generated::all::RubyAnnotation),
-        /// The SampleOutput element
-        SampleOutput(crate::generated::all::SampleOutput),
-        /// The Script element
-        Script(crate::generated::all::Script),
-        /// The Select element
-        Select(crate::generated::all::Select),
-        /// The SideComment element
-        SideComment(crate::generated::all::SideComment),
-        /// The Slot element
-        Slot(crate::generated::all::Slot),
-        /// The Span element
-        Span(crate::generated::all::Span),
-        /// The StrikeThrough element
-        StrikeThrough(crate::generated::all::StrikeThrough),
-        /// The Strong element
-        Strong(crate::generated::all::Strong),
-        /// The SubScript element
-        SubScript(crate::generated::all::SubScript),
-        /// The SuperScript element
-        SuperScript(crate::generated::all::SuperScript),
-        /// The Template element
-        Template(crate::generated::all::Template),
-        /// The Text element
-        Text(std::borrow::Cow<'static, str>),
-        /// The TextArea element
-        TextArea(crate::generated::all::TextArea),
-        /// The Time element
-        Time(crate::generated::all::Time),
-        /// The Underline element
-        Underline(crate::generated::all::Underline),
-        /// The Variable element
-        Variable(crate::generated::all::Variable),
-        /// The Video element
-        Video(crate::generated::all::Video),
-    }
-    impl std::convert::From<crate::generated::all::Abbreviation> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Abbreviation) -> Self {
-            Self::Abbreviation(value)
+        /// Get the value of the `onblur` attribute
+        pub fn onblur(&self) -> std::option::Option<&str> {
+            self.sys.onblur.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Anchor> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Anchor) -> Self {
-            Self::Anchor(value)
+        /// Set the value of the `onblur` attribute
+        pub fn set_onblur(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onblur = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Audio> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Audio) -> Self {
-            Self::Audio(value)
+        /// Get the value of the `oncancel` attribute
+        pub fn oncancel(&self) -> std::option::Option<&str> {
+            self.sys.oncancel.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::BidirectionalIsolate>
-    for KeyboardInputChild {
-        fn from(value: crate::generated::all::BidirectionalIsolate) -> Self {
-            Self::BidirectionalIsolate(value)
+        /// Set the value of the `oncancel` attribute
+        pub fn set_oncancel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncancel = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::BidirectionalTextOverride>
-    for KeyboardInputChild {
-        fn from(value: crate::generated::all::BidirectionalTextOverride) -> Self {
-            Self::BidirectionalTextOverride(value)
+        /// Get the value of the `oncanplay` attribute
+        pub fn oncanplay(&self) -> std::option::Option<&str> {
+            self.sys.oncanplay.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Bold> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Bold) -> Self {
-            Self::Bold(value)
+        /// Set the value of the `oncanplay` attribute
+        pub fn set_oncanplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplay = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Button> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Button) -> Self {
-            Self::Button(value)
+        /// Get the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(&self) -> std::option::Option<&str> {
+            self.sys.oncanplaythrough.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Canvas> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Canvas) -> Self {
-            Self::Canvas(value)
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn set_oncanplaythrough(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplaythrough = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Cite> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Cite) -> Self {
-            Self::Cite(value)
+        /// Get the value of the `onchange` attribute
+        pub fn onchange(&self) -> std::option::Option<&str> {
+            self.sys.onchange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Code> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Code) -> Self {
-            Self::Code(value)
+        /// Set the value of the `onchange` attribute
+        pub fn set_onchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onchange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Data> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Data) -> Self {
-            Self::Data(value)
+        /// Get the value of the `onclick` attribute
+        pub fn onclick(&self) -> std::option::Option<&str> {
+            self.sys.onclick.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::DataList> for KeyboardInputChild {
-        fn from(value: crate::generated::all::DataList) -> Self {
-            Self::DataList(value)
+        /// Set the value of the `onclick` attribute
+        pub fn set_onclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclick = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Definition> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Definition) -> Self {
-            Self::Definition(value)
+        /// Get the value of the `onclose` attribute
+        pub fn onclose(&self) -> std::option::Option<&str> {
+            self.sys.onclose.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::DeletedText> for KeyboardInputChild {
-        fn from(value: crate::generated::all::DeletedText) -> Self {
-            Self::DeletedText(value)
+        /// Set the value of the `onclose` attribute
+        pub fn set_onclose(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclose = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Embed> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Embed) -> Self {
-            Self::Embed(value)
+        /// Get the value of the `oncontextlost` attribute
+        pub fn oncontextlost(&self) -> std::option::Option<&str> {
+            self.sys.oncontextlost.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Emphasis> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Emphasis) -> Self {
-            Self::Emphasis(value)
+        /// Set the value of the `oncontextlost` attribute
+        pub fn set_oncontextlost(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextlost = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Iframe> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Iframe) -> Self {
-            Self::Iframe(value)
+        /// Get the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(&self) -> std::option::Option<&str> {
+            self.sys.oncontextmenu.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Image> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Image) -> Self {
-            Self::Image(value)
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn set_oncontextmenu(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextmenu = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::ImageMap> for KeyboardInputChild {
-        fn from(value: crate::generated::all::ImageMap) -> Self {
-            Self::ImageMap(value)
+        /// Get the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(&self) -> std::option::Option<&str> {
+            self.sys.oncontextrestored.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::ImageMapArea> for KeyboardInputChild {
-        fn from(value: crate::generated::all::ImageMapArea) -> Self {
-            Self::ImageMapArea(value)
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn set_oncontextrestored(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextrestored = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Input> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Input) -> Self {
-            Self::Input(value)
+        /// Get the value of the `oncopy` attribute
+        pub fn oncopy(&self) -> std::option::Option<&str> {
+            self.sys.oncopy.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::InsertedText> for KeyboardInputChild {
-        fn from(value: crate::generated::all::InsertedText) -> Self {
-            Self::InsertedText(value)
+        /// Set the value of the `oncopy` attribute
+        pub fn set_oncopy(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncopy = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Italic> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Italic) -> Self {
-            Self::Italic(value)
+        /// Get the value of the `oncuechange` attribute
+        pub fn oncuechange(&self) -> std::option::Option<&str> {
+            self.sys.oncuechange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::KeyboardInput>
-    for KeyboardInputChild {
-        fn from(value: crate::generated::all::KeyboardInput) -> Self {
-            Self::KeyboardInput(value)
+        /// Set the value of the `oncuechange` attribute
+        pub fn set_oncuechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncuechange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Label> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Label) -> Self {
-            Self::Label(value)
+        /// Get the value of the `oncut` attribute
+        pub fn oncut(&self) -> std::option::Option<&str> {
+            self.sys.oncut.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::LineBreak> for KeyboardInputChild {
-        fn from(value: crate::generated::all::LineBreak) -> Self {
-            Self::LineBreak(value)
+        /// Set the value of the `oncut` attribute
+        pub fn set_oncut(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncut = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::LineBreakOpportunity>
-    for KeyboardInputChild {
-        fn from(value: crate::generated::all::LineBreakOpportunity) -> Self {
-            Self::LineBreakOpportunity(value)
+        /// Get the value of the `ondblclick` attribute
+        pub fn ondblclick(&self) -> std::option::Option<&str> {
+            self.sys.ondblclick.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Link> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Link) -> Self {
-            Self::Link(value)
+        /// Set the value of the `ondblclick` attribute
+        pub fn set_ondblclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondblclick = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::MarkText> for KeyboardInputChild {
-        fn from(value: crate::generated::all::MarkText) -> Self {
-            Self::MarkText(value)
+        /// Get the value of the `ondrag` attribute
+        pub fn ondrag(&self) -> std::option::Option<&str> {
+            self.sys.ondrag.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Meta> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Meta) -> Self {
-            Self::Meta(value)
+        /// Set the value of the `ondrag` attribute
+        pub fn set_ondrag(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrag = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Meter> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Meter) -> Self {
-            Self::Meter(value)
+        /// Get the value of the `ondragend` attribute
+        pub fn ondragend(&self) -> std::option::Option<&str> {
+            self.sys.ondragend.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::NoScript> for KeyboardInputChild {
-        fn from(value: crate::generated::all::NoScript) -> Self {
-            Self::NoScript(value)
+        /// Set the value of the `ondragend` attribute
+        pub fn set_ondragend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragend = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Object> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Object) -> Self {
-            Self::Object(value)
+        /// Get the value of the `ondragenter` attribute
+        pub fn ondragenter(&self) -> std::option::Option<&str> {
+            self.sys.ondragenter.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Output> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Output) -> Self {
-            Self::Output(value)
+        /// Set the value of the `ondragenter` attribute
+        pub fn set_ondragenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragenter = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Picture> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Picture) -> Self {
-            Self::Picture(value)
+        /// Get the value of the `ondragleave` attribute
+        pub fn ondragleave(&self) -> std::option::Option<&str> {
+            self.sys.ondragleave.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Progress> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Progress) -> Self {
-            Self::Progress(value)
+        /// Set the value of the `ondragleave` attribute
+        pub fn set_ondragleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragleave = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Quotation> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Quotation) -> Self {
-            Self::Quotation(value)
+        /// Get the value of the `ondragover` attribute
+        pub fn ondragover(&self) -> std::option::Option<&str> {
+            self.sys.ondragover.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::RubyAnnotation>
-    for KeyboardInputChild {
-        fn from(value: crate::generated::all::RubyAnnotation) -> Self {
-            Self::RubyAnnotation(value)
+        /// Set the value of the `ondragover` attribute
+        pub fn set_ondragover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragover = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::SampleOutput> for KeyboardInputChild {
-        fn from(value: crate::generated::all::SampleOutput) -> Self {
-            Self::SampleOutput(value)
+        /// Get the value of the `ondragstart` attribute
+        pub fn ondragstart(&self) -> std::option::Option<&str> {
+            self.sys.ondragstart.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Script> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Script) -> Self {
-            Self::Script(value)
+        /// Set the value of the `ondragstart` attribute
+        pub fn set_ondragstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragstart = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Select> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Select) -> Self {
-            Self::Select(value)
+        /// Get the value of the `ondrop` attribute
+        pub fn ondrop(&self) -> std::option::Option<&str> {
+            self.sys.ondrop.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::SideComment> for KeyboardInputChild {
-        fn from(value: crate::generated::all::SideComment) -> Self {
-            Self::SideComment(value)
+        /// Set the value of the `ondrop` attribute
+        pub fn set_ondrop(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrop = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Slot> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Slot) -> Self {
-            Self::Slot(value)
+        /// Get the value of the `ondurationchange` attribute
+        pub fn ondurationchange(&self) -> std::option::Option<&str> {
+            self.sys.ondurationchange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Span> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Span) -> Self {
-            Self::Span(value)
+        /// Set the value of the `ondurationchange` attribute
+        pub fn set_ondurationchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondurationchange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::StrikeThrough>
-    for KeyboardInputChild {
-        fn from(value: crate::generated::all::StrikeThrough) -> Self {
-            Self::StrikeThrough(value)
+        /// Get the value of the `onemptied` attribute
+        pub fn onemptied(&self) -> std::option::Option<&str> {
+            self.sys.onemptied.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Strong> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Strong) -> Self {
-            Self::Strong(value)
+        /// Set the value of the `onemptied` attribute
+        pub fn set_onemptied(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onemptied = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::SubScript> for KeyboardInputChild {
-        fn from(value: crate::generated::all::SubScript) -> Self {
-            Self::SubScript(value)
+        /// Get the value of the `onended` attribute
+        pub fn onended(&self) -> std::option::Option<&str> {
+            self.sys.onended.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::SuperScript> for KeyboardInputChild {
-        fn from(value: crate::generated::all::SuperScript) -> Self {
-            Self::SuperScript(value)
+        /// Set the value of the `onended` attribute
+        pub fn set_onended(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onended = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Template> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Template) -> Self {
-            Self::Template(value)
+        /// Get the value of the `onerror` attribute
+        pub fn onerror(&self) -> std::option::Option<&str> {
+            self.sys.onerror.as_deref()
         }
-    }
-    impl std::convert::From<std::borrow::Cow<'static, str>> for KeyboardInputChild {
-        fn from(value: std::borrow::Cow<'static, str>) -> Self {
-            Self::Text(value)
+        /// Set the value of the `onerror` attribute
+        pub fn set_onerror(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onerror = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<&'static str> for KeyboardInputChild {
-        fn from(value: &'static str) -> Self {
-            Self::Text(value.into())
+        /// Get the value of the `onfocus` attribute
+        pub fn onfocus(&self) -> std::option::Option<&str> {
+            self.sys.onfocus.as_deref()
         }
-    }
-    impl std::convert::From<String> for KeyboardInputChild {
-        fn from(value: String) -> Self {
-            Self::Text(value.into())
+        /// Set the value of the `onfocus` attribute
+        pub fn set_onfocus(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onfocus = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::TextArea> for KeyboardInputChild {
-        fn from(value: crate::generated::all::TextArea) -> Self {
-            Self::TextArea(value)
+        /// Get the value of the `onformdata` attribute
+        pub fn onformdata(&self) -> std::option::Option<&str> {
+            self.sys.onformdata.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Time> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Time) -> Self {
-            Self::Time(value)
+        /// Set the value of the `onformdata` attribute
+        pub fn set_onformdata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onformdata = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Underline> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Underline) -> Self {
-            Self::Underline(value)
+        /// Get the value of the `oninput` attribute
+        pub fn oninput(&self) -> std::option::Option<&str> {
+            self.sys.oninput.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Variable> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Variable) -> Self {
-            Self::Variable(value)
+        /// Set the value of the `oninput` attribute
+        pub fn set_oninput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninput = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Video> for KeyboardInputChild {
-        fn from(value: crate::generated::all::Video) -> Self {
-            Self::Video(value)
+        /// Get the value of the `oninvalid` attribute
+        pub fn oninvalid(&self) -> std::option::Option<&str> {
+            self.sys.oninvalid.as_deref()
         }
-    }
-    impl crate::Render for KeyboardInputChild {
-        fn render(
-            &self,
-            f: &mut std::fmt::Formatter<'_>,
-            depth: usize,
-        ) -> std::fmt::Result {
-            match self {
-                Self::Abbreviation(el) => crate::Render::render(el, f, depth + 1),
-                Self::Anchor(el) => crate::Render::render(el, f, depth + 1),
-                Self::Audio(el) => crate::Render::render(el, f, depth + 1),
-                Self::BidirectionalIsolate(el) => crate::Render::render(el, f, depth + 1),
-                Self::BidirectionalTextOverride(el) => {
-                    crate::Render::render(el, f, depth + 1)
-                }
-                Self::Bold(el) => crate::Render::render(el, f, depth + 1),
-                Self::Button(el) => crate::Render::render(el, f, depth + 1),
-                Self::Canvas(el) => crate::Render::render(el, f, depth + 1),
-                Self::Cite(el) => crate::Render::render(el, f, depth + 1),
-                Self::Code(el) => crate::Render::render(el, f, depth + 1),
-                Self::Data(el) => crate::Render::render(el, f, depth + 1),
-                Self::DataList(el) => crate::Render::render(el, f, depth + 1),
-                Self::Definition(el) => crate::Render::render(el, f, depth + 1),
-                Self::DeletedText(el) => crate::Render::render(el, f, depth + 1),
-                Self::Embed(el) => crate::Render::render(el, f, depth + 1),
-                Self::Emphasis(el) => crate::Render::render(el, f, depth + 1),
-                Self::Iframe(el) => crate::Render::render(el, f, depth + 1),
-                Self::Image(el) => crate::Render::render(el, f, depth + 1),
-                Self::ImageMap(el) => crate::Render::render(el, f, depth + 1),
-                Self::ImageMapArea(el) => crate::Render::render(el, f, depth + 1),
-                Self::Input(el) => crate::Render::render(el, f, depth + 1),
-                Self::InsertedText(el) => crate::Render::render(el, f, depth + 1),
-                Self::Italic(el) => crate::Render::render(el, f, depth + 1),
-                Self::KeyboardInput(el) => crate::Render::render(el, f, depth + 1),
-                Self::Label(el) => crate::Render::render(el, f, depth + 1),
-                Self::LineBreak(el) => crate::Render::render(el, f, depth + 1),
-                Self::LineBreakOpportunity(el) => crate::Render::render(el, f, depth + 1),
-                Self::Link(el) => crate::Render::render(el, f, depth + 1),
-                Self::MarkText(el) => crate::Render::render(el, f, depth + 1),
-                Self::Meta(el) => crate::Render::render(el, f, depth + 1),
-                Self::Meter(el) => crate::Render::render(el, f, depth + 1),
-                Self::NoScript(el) => crate::Render::render(el, f, depth + 1),
-                Self::Object(el) => crate::Render::render(el, f, depth + 1),
-                Self::Output(el) => crate::Render::render(el, f, depth + 1),
-                Self::Picture(el) => crate::Render::render(el, f, depth + 1),
-                Self::Progress(el) => crate::Render::render(el, f, depth + 1),
-                Self::Quotation(el) => crate::Render::render(el, f, depth + 1),
-                Self::RubyAnnotation(el) => crate::Render::render(el, f, depth + 1),
-                Self::SampleOutput(el) => crate::Render::render(el, f, depth + 1),
-                Self::Script(el) => crate::Render::render(el, f, depth + 1),
-                Self::Select(el) => crate::Render::render(el, f, depth + 1),
-                Self::SideComment(el) => crate::Render::render(el, f, depth + 1),
-                Self::Slot(el) => crate::Render::render(el, f, depth + 1),
-                Self::Span(el) => crate::Render::render(el, f, depth + 1),
-                Self::StrikeThrough(el) => crate::Render::render(el, f, depth + 1),
-                Self::Strong(el) => crate::Render::render(el, f, depth + 1),
-                Self::SubScript(el) => crate::Render::render(el, f, depth + 1),
-                Self::SuperScript(el) => crate::Render::render(el, f, depth + 1),
-                Self::Template(el) => crate::Render::render(el, f, depth + 1),
-                Self::Text(el) => crate::Render::render(el, f, depth + 1),
-                Self::TextArea(el) => crate::Render::render(el, f, depth + 1),
-                Self::Time(el) => crate::Render::render(el, f, depth + 1),
-                Self::Underline(el) => crate::Render::render(el, f, depth + 1),
-                Self::Variable(el) => crate::Render::render(el, f, depth + 1),
-                Self::Video(el) => crate::Render::render(el, f, depth + 1),
-            }
+        /// Set the value of the `oninvalid` attribute
+        pub fn set_oninvalid(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninvalid = value.map(|v| v.into());
         }
-    }
-    impl std::fmt::Debug for KeyboardInputChild {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            crate::Render::render(self, f, 0)?;
-            Ok(())
+        /// Get the value of the `onkeydown` attribute
+        pub fn onkeydown(&self) -> std::option::Option<&str> {
+            self.sys.onkeydown.as_deref()
         }
-    }
-    impl std::fmt::Display for KeyboardInputChild {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                Self::Abbreviation(el) => write!(f, "{el}"),
-                Self::Anchor(el) => write!(f, "{el}"),
-                Self::Audio(el) => write!(f, "{el}"),
-                Self::BidirectionalIsolate(el) => write!(f, "{el}"),
-                Self::BidirectionalTextOverride(el) => write!(f, "{el}"),
-                Self::Bold(el) => write!(f, "{el}"),
-                Self::Button(el) => write!(f, "{el}"),
-                Self::Canvas(el) => write!(f, "{el}"),
-                Self::Cite(el) => write!(f, "{el}"),
-                Self::Code(el) => write!(f, "{el}"),
-                Self::Data(el) => write!(f, "{el}"),
-                Self::DataList(el) => write!(f, "{el}"),
-                Self::Definition(el) => write!(f, "{el}"),
-                Self::DeletedText(el) => write!(f, "{el}"),
-                Self::Embed(el) => write!(f, "{el}"),
-                Self::Emphasis(el) => write!(f, "{el}"),
-                Self::Iframe(el) => write!(f, "{el}"),
-                Self::Image(el) => write!(f, "{el}"),
-                Self::ImageMap(el) => write!(f, "{el}"),
-                Self::ImageMapArea(el) => write!(f, "{el}"),
-                Self::Input(el) => write!(f, "{el}"),
-                Self::InsertedText(el) => write!(f, "{el}"),
-                Self::Italic(el) => write!(f, "{el}"),
-                Self::KeyboardInput(el) => write!(f, "{el}"),
-                Self::Label(el) => write!(f, "{el}"),
-                Self::LineBreak(el) => write!(f, "{el}"),
-                Self::LineBreakOpportunity(el) => write!(f, "{el}"),
-                Self::Link(el) => write!(f, "{el}"),
-                Self::MarkText(el) => write!(f, "{el}"),
-                Self::Meta(el) => write!(f, "{el}"),
-                Self::Meter(el) => write!(f, "{el}"),
-                Self::NoScript(el) => write!(f, "{el}"),
-                Self::Object(el) => write!(f, "{el}"),
-                Self::Output(el) => write!(f, "{el}"),
-                Self::Picture(el) => write!(f, "{el}"),
-                Self::Progress(el) => write!(f, "{el}"),
-                Self::Quotation(el) => write!(f, "{el}"),
-                Self::RubyAnnotation(el) => write!(f, "{el}"),
-                Self::SampleOutput(el) => write!(f, "{el}"),
-                Self::Script(el) => write!(f, "{el}"),
-                Self::Select(el) => write!(f, "{el}"),
-                Self::SideComment(el) => write!(f, "{el}"),
-                Self::Slot(el) => write!(f, "{el}"),
-                Self::Span(el) => write!(f, "{el}"),
-                Self::StrikeThrough(el) => write!(f, "{el}"),
-                Self::Strong(el) => write!(f, "{el}"),
-                Self::SubScript(el) => write!(f, "{el}"),
-                Self::SuperScript(el) => write!(f, "{el}"),
-                Self::Template(el) => write!(f, "{el}"),
-                Self::Text(el) => write!(f, "{el}"),
-                Self::TextArea(el) => write!(f, "{el}"),
-                Self::Time(el) => write!(f, "{el}"),
-                Self::Underline(el) => write!(f, "{el}"),
-                Self::Variable(el) => write!(f, "{el}"),
-                Self::Video(el) => write!(f, "{el}"),
-            }
+        /// Set the value of the `onkeydown` attribute
+        pub fn set_onkeydown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeydown = value.map(|v| v.into());
         }
-    }
-    impl<'a> From<&'a KeyboardInputChild> for crate::Node<'a> {
-        fn from(child: &'a KeyboardInputChild) -> Self {
-            match child {
-                KeyboardInputChild::Abbreviation(el) => crate::Node::from(el),
-                KeyboardInputChild::Anchor(el) => crate::Node::from(el),
-                KeyboardInputChild::Audio(el) => crate::Node::from(el),
-                KeyboardInputChild::BidirectionalIsolate(el) => crate::Node::from(el),
-                KeyboardInputChild::BidirectionalTextOverride(el) => {
-                    crate::Node::from(el)
-                }
-                KeyboardInputChild::Bold(el) => crate::Node::from(el),
-                KeyboardInputChild::Button(el) => crate::Node::from(el),
-                KeyboardInputChild::Canvas(el) => crate::Node::from(el),
-                KeyboardInputChild::Cite(el) => crate::Node::from(el),
-                KeyboardInputChild::Code(el) => crate::Node::from(el),
-                KeyboardInputChild::Data(el) => crate::Node::from(el),
-                KeyboardInputChild::DataList(el) => crate::Node::from(el),
-                KeyboardInputChild::Definition(el) => crate::Node::from(el),
-                KeyboardInputChild::DeletedText(el) => crate::Node::from(el),
-                KeyboardInputChild::Embed(el) => crate::Node::from(el),
-                KeyboardInputChild::Emphasis(el) => crate::Node::from(el),
-                KeyboardInputChild::Iframe(el) => crate::Node::from(el),
-                KeyboardInputChild::Image(el) => crate::Node::from(el),
-                KeyboardInputChild::ImageMap(el) => crate::Node::from(el),
-                KeyboardInputChild::ImageMapArea(el) => crate::Node::from(el),
-                KeyboardInputChild::Input(el) => crate::Node::from(el),
-                KeyboardInputChild::InsertedText(el) => crate::Node::from(el),
-                KeyboardInputChild::Italic(el) => crate::Node::from(el),
-                KeyboardInputChild::KeyboardInput(el) => crate::Node::from(el),
-                KeyboardInputChild::Label(el) => crate::Node::from(el),
-                KeyboardInputChild::LineBreak(el) => crate::Node::from(el),
-                KeyboardInputChild::LineBreakOpportunity(el) => crate::Node::from(el),
-                KeyboardInputChild::Link(el) => crate::Node::from(el),
-                KeyboardInputChild::MarkText(el) => crate::Node::from(el),
-                KeyboardInputChild::Meta(el) => crate::Node::from(el),
-                KeyboardInputChild::Meter(el) => crate::Node::from(el),
-                KeyboardInputChild::NoScript(el) => crate::Node::from(el),
-                KeyboardInputChild::Object(el) => crate::Node::from(el),
-                KeyboardInputChild::Output(el) => crate::Node::from(el),
-                KeyboardInputChild::Picture(el) => crate::Node::from(el),
-                KeyboardInputChild::Progress(el) => crate::Node::from(el),
-                KeyboardInputChild::Quotation(el) => crate::Node::from(el),
-                KeyboardInputChild::RubyAnnotation(el) => crate::Node::from(el),
-                KeyboardInputChild::SampleOutput(el) => crate::Node::from(el),
-                KeyboardInputChild::Script(el) => crate::Node::from(el),
-                KeyboardInputChild::Select(el) => crate::Node::from(el),
-                KeyboardInputChild::SideComment(el) => crate::Node::from(el),
-                KeyboardInputChild::Slot(el) => crate::Node::from(el),
-                KeyboardInputChild::Span(el) => crate::Node::from(el),
-                KeyboardInputChild::StrikeThrough(el) => crate::Node::from(el),
-                KeyboardInputChild::Strong(el) => crate::Node::from(el),
-                KeyboardInputChild::SubScript(el) => crate::Node::from(el),
-                KeyboardInputChild::SuperScript(el) => crate::Node::from(el),
-                KeyboardInputChild::Template(el) => crate::Node::from(el),
-                KeyboardInputChild::Text(el) => crate::Node::from(el),
-                KeyboardInputChild::TextArea(el) => crate::Node::from(el),
-                KeyboardInputChild::Time(el) => crate::Node::from(el),
-                KeyboardInputChild::Underline(el) => crate::Node::from(el),
-                KeyboardInputChild::Variable(el) => crate::Node::from(el),
-                KeyboardInputChild::Video(el) => crate::Node::from(el),
-            }
+        /// Get the value of the `onkeypress` attribute
+        pub fn onkeypress(&self) -> std::option::Option<&str> {
+            self.sys.onkeypress.as_deref()
+        }
+        /// Set the value of the `onkeypress` attribute
+        pub fn set_onkeypress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeypress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeyup` attribute
+        pub fn onkeyup(&self) -> std::option::Option<&str> {
+            self.sys.onkeyup.as_deref()
+        }
+        /// Set the value of the `onkeyup` attribute
+        pub fn set_onkeyup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeyup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onload` attribute
+        pub fn onload(&self) -> std::option::Option<&str> {
+            self.sys.onload.as_deref()
+        }
+        /// Set the value of the `onload` attribute
+        pub fn set_onload(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onload = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadeddata` attribute
+        pub fn onloadeddata(&self) -> std::option::Option<&str> {
+            self.sys.onloadeddata.as_deref()
+        }
+        /// Set the value of the `onloadeddata` attribute
+        pub fn set_onloadeddata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadeddata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(&self) -> std::option::Option<&str> {
+            self.sys.onloadedmetadata.as_deref()
+        }
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn set_onloadedmetadata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadedmetadata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadstart` attribute
+        pub fn onloadstart(&self) -> std::option::Option<&str> {
+            self.sys.onloadstart.as_deref()
+        }
+        /// Set the value of the `onloadstart` attribute
+        pub fn set_onloadstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousedown` attribute
+        pub fn onmousedown(&self) -> std::option::Option<&str> {
+            self.sys.onmousedown.as_deref()
+        }
+        /// Set the value of the `onmousedown` attribute
+        pub fn set_onmousedown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousedown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseenter` attribute
+        pub fn onmouseenter(&self) -> std::option::Option<&str> {
+            self.sys.onmouseenter.as_deref()
+        }
+        /// Set the value of the `onmouseenter` attribute
+        pub fn set_onmouseenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseleave` attribute
+        pub fn onmouseleave(&self) -> std::option::Option<&str> {
+            self.sys.onmouseleave.as_deref()
+        }
+        /// Set the value of the `onmouseleave` attribute
+        pub fn set_onmouseleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousemove` attribute
+        pub fn onmousemove(&self) -> std::option::Option<&str> {
+            self.sys.onmousemove.as_deref()
+        }
+        /// Set the value of the `onmousemove` attribute
+        pub fn set_onmousemove(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousemove = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseout` attribute
+        pub fn onmouseout(&self) -> std::option::Option<&str> {
+            self.sys.onmouseout.as_deref()
+        }
+        /// Set the value of the `onmouseout` attribute
+        pub fn set_onmouseout(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseout = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseover` attribute
+        pub fn onmouseover(&self) -> std::option::Option<&str> {
+            self.sys.onmouseover.as_deref()
+        }
+        /// Set the value of the `onmouseover` attribute
+        pub fn set_onmouseover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseover = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseup` attribute
+        pub fn onmouseup(&self) -> std::option::Option<&str> {
+            self.sys.onmouseup.as_deref()
+        }
+        /// Set the value of the `onmouseup` attribute
+        pub fn set_onmouseup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpaste` attribute
+        pub fn onpaste(&self) -> std::option::Option<&str> {
+            self.sys.onpaste.as_deref()
+        }
+        /// Set the value of the `onpaste` attribute
+        pub fn set_onpaste(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpaste = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpause` attribute
+        pub fn onpause(&self) -> std::option::Option<&str> {
+            self.sys.onpause.as_deref()
+        }
+        /// Set the value of the `onpause` attribute
+        pub fn set_onpause(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpause = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplay` attribute
+        pub fn onplay(&self) -> std::option::Option<&str> {
+            self.sys.onplay.as_deref()
+        }
+        /// Set the value of the `onplay` attribute
+        pub fn set_onplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplay = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplaying` attribute
+        pub fn onplaying(&self) -> std::option::Option<&str> {
+            self.sys.onplaying.as_deref()
+        }
+        /// Set the value of the `onplaying` attribute
+        pub fn set_onplaying(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplaying = value.map(|v| v.into());
+        }
+        /// Get the value of the `onprogress` attribute
+        pub fn onprogress(&self) -> std::option::Option<&str> {
+            self.sys.onprogress.as_deref()
+        }
+        /// Set the value of the `onprogress` attribute
+        pub fn set_onprogress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onprogress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onratechange` attribute
+        pub fn onratechange(&self) -> std::option::Option<&str> {
+            self.sys.onratechange.as_deref()
+        }
+        /// Set the value of the `onratechange` attribute
+        pub fn set_onratechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onratechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onreset` attribute
+        pub fn onreset(&self) -> std::option::Option<&str> {
+            self.sys.onreset.as_deref()
+        }
+        /// Set the value of the `onreset` attribute
+        pub fn set_onreset(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onreset = value.map(|v| v.into());
+        }
+        /// Get the value of the `onresize` attribute
+        pub fn onresize(&self) -> std::option::Option<&str> {
+            self.sys.onresize.as_deref()
+        }
+        /// Set the value of the `onresize` attribute
+        pub fn set_onresize(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onresize = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscroll` attribute
+        pub fn onscroll(&self) -> std::option::Option<&str> {
+            self.sys.onscroll.as_deref()
+        }
+        /// Set the value of the `onscroll` attribute
+        pub fn set_onscroll(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscroll = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscrollend` attribute
+        pub fn onscrollend(&self) -> std::option::Option<&str> {
+            self.sys.onscrollend.as_deref()
+        }
+        /// Set the value of the `onscrollend` attribute
+        pub fn set_onscrollend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscrollend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(&self) -> std::option::Option<&str> {
+            self.sys.onsecuritypolicyviolation.as_deref()
+        }
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn set_onsecuritypolicyviolation(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsecuritypolicyviolation = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeked` attribute
+        pub fn onseeked(&self) -> std::option::Option<&str> {
+            self.sys.onseeked.as_deref()
+        }
+        /// Set the value of the `onseeked` attribute
+        pub fn set_onseeked(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeked = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeking` attribute
+        pub fn onseeking(&self) -> std::option::Option<&str> {
+            self.sys.onseeking.as_deref()
+        }
+        /// Set the value of the `onseeking` attribute
+        pub fn set_onseeking(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeking = value.map(|v| v.into());
+        }
+        /// Get the value of the `onselect` attribute
+        pub fn onselect(&self) -> std::option::Option<&str> {
+            self.sys.onselect.as_deref()
+        }
+        /// Set the value of the `onselect` attribute
+        pub fn set_onselect(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onselect = value.map(|v| v.into());
+        }
+        /// Get the value of the `onslotchange` attribute
+        pub fn onslotchange(&self) -> std::option::Option<&str> {
+            self.sys.onslotchange.as_deref()
+        }
+        /// Set the value of the `onslotchange` attribute
+        pub fn set_onslotchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onslotchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onstalled` attribute
+        pub fn onstalled(&self) -> std::option::Option<&str> {
+            self.sys.onstalled.as_deref()
+        }
+        /// Set the value of the `onstalled` attribute
+        pub fn set_onstalled(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onstalled = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsubmit` attribute
+        pub fn onsubmit(&self) -> std::option::Option<&str> {
+            self.sys.onsubmit.as_deref()
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn set_onsubmit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsubmit = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsuspend` attribute
+        pub fn onsuspend(&self) -> std::option::Option<&str> {
+            self.sys.onsuspend.as_deref()
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn set_onsuspend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsuspend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(&self) -> std::option::Option<&str> {
+            self.sys.ontimeupdate.as_deref()
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn set_ontimeupdate(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontimeupdate = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontoggle` attribute
+        pub fn ontoggle(&self) -> std::option::Option<&str> {
+            self.sys.ontoggle.as_deref()
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn set_ontoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontoggle = value.map(|v| v.into());
+        }
+        /// Get the value of the `onvolumechange` attribute
+        pub fn onvolumechange(&self) -> std::option::Option<&str> {
+            self.sys.onvolumechange.as_deref()
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn set_onvolumechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onvolumechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwaiting` attribute
+        pub fn onwaiting(&self) -> std::option::Option<&str> {
+            self.sys.onwaiting.as_deref()
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn set_onwaiting(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwaiting = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationend.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn set_onwebkitanimationend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationiteration.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn set_onwebkitanimationiteration(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationiteration = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationstart.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn set_onwebkitanimationstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkittransitionend.as_deref()
+        }
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn set_onwebkittransitionend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkittransitionend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwheel` attribute
+        pub fn onwheel(&self) -> std::option::Option<&str> {
+            self.sys.onwheel.as_deref()
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn set_onwheel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwheel = value.map(|v| v.into());
+        }
+    }
+    impl KeyboardInput {
+        /// Access the element's children
+        pub fn children(&self) -> &[super::child::KeyboardInputChild] {
+            self.children.as_ref()
+        }
+        /// Mutably access the element's children
+        pub fn children_mut(&mut self) -> &mut Vec<super::child::KeyboardInputChild> {
+            &mut self.children
+        }
+    }
+    impl std::fmt::Debug for KeyboardInput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for KeyboardInput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
+            for el in &self.children {
+                write!(f, "{el}")?;
+            }
+            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
+            Ok(())
+        }
+    }
+    impl crate::HtmlElement for KeyboardInput {
+        fn tag_name(&self) -> &'static str {
+            "kbd"
+        }
+        fn attributes(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.attributes()
+        }
+        fn data(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.data()
+        }
+        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
+            self.children.iter().map(From::from).collect()
+        }
+    }
+    impl crate::FlowContent for KeyboardInput {}
+    impl crate::PhrasingContent for KeyboardInput {}
+    impl crate::PalpableContent for KeyboardInput {}
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromHtml for KeyboardInput {
+        const TAG_NAME: &'static str = "kbd";
+        fn from_html(
+            element: crate::parse::Element<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Self {
+            let mut this: Self = Default::default();
+            for (name, value) in element.attributes() {
+                match name {
+                    "role" => this.set_role(cx.parse_attribute(name, value)),
+                    "aria-activedescendant" => {
+                        this.set_aria_active_descendant_element(Some(value.to_owned()))
+                    }
+                    "aria-atomic" => this.set_aria_atomic(value != "false"),
+                    "aria-autocomplete" => {
+                        this.set_aria_auto_complete(cx.parse_attribute(name, value))
+                    }
+                    "aria-brailleroledescription" => {
+                        this.set_aria_braille_role_description(Some(value.to_owned()))
+                    }
+                    "aria-busy" => this.set_aria_busy(value != "false"),
+                    "aria-checked" => {
+                        this.set_aria_checked(cx.parse_attribute(name, value))
+                    }
+                    "aria-colcount" => {
+                        this.set_aria_col_count(cx.parse_attribute(name, value))
+                    }
+                    "aria-colindex" => {
+                        this.set_aria_col_index(cx.parse_attribute(name, value))
+                    }
+                    "aria-colindextext" => {
+                        this.set_aria_col_index_text(Some(value.to_owned()))
+                    }
+                    "aria-colspan" => {
+                        this.set_aria_col_span(cx.parse_attribute(name, value))
+                    }
+                    "aria-controls" => {
+                        this.set_aria_controls_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-current" => {
+                        this.set_aria_current(cx.parse_attribute(name, value))
+                    }
+                    "aria-describedby" => {
+                        this.set_aria_described_by_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-description" => {
+                        this.set_aria_description(Some(value.to_owned()))
+                    }
+                    "aria-details" => {
+                        this.set_aria_details_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-disabled" => this.set_aria_disabled(value != "false"),
+                    "aria-dropeffect" => {
+                        this.set_aria_drop_effect(
+                            value
+                                .split_ascii_whitespace()
+                                .filter_map(|value| cx.parse_attribute(name, value))
+                                .collect(),
+                        )
+                    }
+                    "aria-errormessage" => {
+                        this.set_aria_error_message_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-expanded" => {
+                        this.set_aria_expanded(cx.parse_attribute(name, value))
+                    }
+                    "aria-flowto" => {
+                        this.set_aria_flow_to_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-grabbed" => {
+                        this.set_aria_grabbed(cx.parse_attribute(name, value))
+                    }
+                    "aria-haspopup" => {
+                        this.set_aria_has_popup(cx.parse_attribute(name, value))
+                    }
+                    "aria-hidden" => {
+                        this.set_aria_hidden(cx.parse_attribute(name, value))
+                    }
+                    "aria-invalid" => {
+                        this.set_aria_invalid(cx.parse_attribute(name, value))
+                    }
+                    "aria-keyshortcuts" => {
+                        this.set_aria_key_shortcuts(Some(value.to_owned()))
+                    }
+                    "aria-level" => this.set_aria_level(cx.parse_attribute(name, value)),
+                    "aria-live" => this.set_aria_live(cx.parse_attribute(name, value)),
+                    "aria-modal" => this.set_aria_modal(value != "false"),
+                    "aria-multiline" => this.set_aria_multi_line(value != "false"),
+                    "aria-multiselectable" => {
+                        this.set_aria_multi_selectable(value != "false")
+                    }
+                    "aria-orientation" => {
+                        this.set_aria_orientation(cx.parse_attribute(name, value))
+                    }
+                    "aria-owns" => {
+                        this.set_aria_owns_elements(
+                            value
+                                .split_ascii_whitespace()
+                                .map(|value| value.to_owned().into())
+                                .collect(),
+                        )
+                    }
+                    "aria-placeholder" => {
+                        this.set_aria_placeholder(Some(value.to_owned()))
+                    }
+                    "aria-posinset" => {
+                        this.set_aria_pos_in_set(cx.parse_attribute(name, value))
+                    }
+                    "aria-pressed" => {
+                        this.set_aria_pressed(cx.parse_attribute(name, value))
+                    }
+                    "aria-readonly" => this.set_aria_read_only(value != "false"),
+                    "aria-relevant" => {
+                        this.set_aria_relevant(
+                            value
+                                .split_ascii_whitespace()
+                                .filter_map(|value| cx.parse_attribute(name, value))
+                                .collect(),
+                        )
+                    }
+                    "aria-required" => this.set_aria_required(value != "false"),
+                    "aria-roledescription" => {
+                        this.set_aria_role_description(Some(value.to_owned()))
+                    }
+                    "aria-rowcount" => {
+                        this.set_aria_row_count(cx.parse_attribute(name, value))
+                    }
+                    "aria-rowindex" => {
+                        this.set_aria_row_index(cx.parse_attribute(name, value))
+                    }
+                    "aria-rowindextext" => {
+                        this.set_aria_row_index_text(Some(value.to_owned()))
+                    }
+                    "aria-rowspan" => {
+                        this.set_aria_row_span(cx.parse_attribute(name, value))
+                    }
+                    "aria-selected" => {
+                        this.set_aria_selected(cx.parse_attribute(name, value))
+                    }
+                    "aria-setsize" => {
+                        this.set_aria_set_size(cx.parse_attribute(name, value))
+                    }
+                    "aria-sort" => this.set_aria_sort(cx.parse_attribute(name, value)),
+                    "aria-valuemax" => {
+                        this.set_aria_value_max(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuemin" => {
+                        this.set_aria_value_min(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuenow" => {
+                        this.set_aria_value_now(cx.parse_attribute(name, value))
+                    }
+                    "aria-valuetext" => this.set_aria_value_text(Some(value.to_owned())),
+                    "accesskey" => this.set_access_key(Some(value.to_owned())),
+                    "autocapitalize" => {
+                        this.set_auto_capitalize(cx.parse_attribute(name, value))
+                    }
+                    "autofocus" => this.set_autofocus(true),
+                    "class" => this.set_class(Some(value.to_owned())),
+                    "contenteditable" => {
+                        this.set_content_editable(cx.parse_attribute(name, value))
+                    }
+                    "dir" => this.set_direction(cx.parse_attribute(name, value)),
+                    "draggable" => this.set_draggable(cx.parse_attribute(name, value)),
+                    "enterkeyhint" => {
+                        this.set_enter_key_hint(cx.parse_attribute(name, value))
+                    }
+                    "exportparts" => this.set_export_parts(Some(value.to_owned())),
+                    "hidden" => this.set_hidden(cx.parse_attribute(name, value)),
+                    "id" => this.set_id(Some(value.to_owned())),
+                    "inert" => this.set_inert(true),
+                    "inputmode" => this.set_input_mode(cx.parse_attribute(name, value)),
+                    "is" => this.set_is_(Some(value.to_owned())),
+                    "itemid" => this.set_item_id(Some(value.to_owned())),
+                    "itemprop" => this.set_item_prop(Some(value.to_owned())),
+                    "itemref" => this.set_item_ref(Some(value.to_owned())),
+                    "itemscope" => this.set_item_scope(true),
+                    "itemtype" => this.set_item_type(Some(value.to_owned())),
+                    "lang" => this.set_lang(Some(value.to_owned())),
+                    "nonce" => this.set_nonce(Some(value.to_owned())),
+                    "part" => this.set_part(Some(value.to_owned())),
+                    "slot" => this.set_slot(Some(value.to_owned())),
+                    "spellcheck" => this.set_spellcheck(cx.parse_attribute(name, value)),
+                    "style" => this.set_style(Some(value.to_owned())),
+                    "tabindex" => this.set_tab_index(cx.parse_attribute(name, value)),
+                    "title" => this.set_title(Some(value.to_owned())),
+                    "translate" => this.set_translate(cx.parse_attribute(name, value)),
+                    "onabort" => this.set_onabort(Some(value.to_owned())),
+                    "onauxclick" => this.set_onauxclick(Some(value.to_owned())),
+                    "onbeforeinput" => this.set_onbeforeinput(Some(value.to_owned())),
+                    "onbeforematch" => this.set_onbeforematch(Some(value.to_owned())),
+                    "onbeforetoggle" => this.set_onbeforetoggle(Some(value.to_owned())),
+                    "onblur" => this.set_onblur(Some(value.to_owned())),
+                    "oncancel" => this.set_oncancel(Some(value.to_owned())),
+                    "oncanplay" => this.set_oncanplay(Some(value.to_owned())),
+                    "oncanplaythrough" => {
+                        this.set_oncanplaythrough(Some(value.to_owned()))
+                    }
+                    "onchange" => this.set_onchange(Some(value.to_owned())),
+                    "onclick" => this.set_onclick(Some(value.to_owned())),
+                    "onclose" => this.set_onclose(Some(value.to_owned())),
+                    "oncontextlost" => this.set_oncontextlost(Some(value.to_owned())),
+                    "oncontextmenu" => this.set_oncontextmenu(Some(value.to_owned())),
+                    "oncontextrestored" => {
+                        this.set_oncontextrestored(Some(value.to_owned()))
+                    }
+                    "oncopy" => this.set_oncopy(Some(value.to_owned())),
+                    "oncuechange" => this.set_oncuechange(Some(value.to_owned())),
+                    "oncut" => this.set_oncut(Some(value.to_owned())),
+                    "ondblclick" => this.set_ondblclick(Some(value.to_owned())),
+                    "ondrag" => this.set_ondrag(Some(value.to_owned())),
+                    "ondragend" => this.set_ondragend(Some(value.to_owned())),
+                    "ondragenter" => this.set_ondragenter(Some(value.to_owned())),
+                    "ondragleave" => this.set_ondragleave(Some(value.to_owned())),
+                    "ondragover" => this.set_ondragover(Some(value.to_owned())),
+                    "ondragstart" => this.set_ondragstart(Some(value.to_owned())),
+                    "ondrop" => this.set_ondrop(Some(value.to_owned())),
+                    "ondurationchange" => {
+                        this.set_ondurationchange(Some(value.to_owned()))
+                    }
+                    "onemptied" => this.set_onemptied(Some(value.to_owned())),
+                    "onended" => this.set_onended(Some(value.to_owned())),
+                    "onerror" => this.set_onerror(Some(value.to_owned())),
+                    "onfocus" => this.set_onfocus(Some(value.to_owned())),
+                    "onformdata" => this.set_onformdata(Some(value.to_owned())),
+                    "oninput" => this.set_oninput(Some(value.to_owned())),
+                    "oninvalid" => this.set_oninvalid(Some(value.to_owned())),
+                    "onkeydown" => this.set_onkeydown(Some(value.to_owned())),
+                    "onkeypress" => this.set_onkeypress(Some(value.to_owned())),
+                    "onkeyup" => this.set_onkeyup(Some(value.to_owned())),
+                    "onload" => this.set_onload(Some(value.to_owned())),
+                    "onloadeddata" => this.set_onloadeddata(Some(value.to_owned())),
+                    "onloadedmetadata" => {
+                        this.set_onloadedmetadata(Some(value.to_owned()))
+                    }
+                    "onloadstart" => this.set_onloadstart(Some(value.to_owned())),
+                    "onmousedown" => this.set_onmousedown(Some(value.to_owned())),
+                    "onmouseenter" => this.set_onmouseenter(Some(value.to_owned())),
+                    "onmouseleave" => this.set_onmouseleave(Some(value.to_owned())),
+                    "onmousemove" => this.set_onmousemove(Some(value.to_owned())),
+                    "onmouseout" => this.set_onmouseout(Some(value.to_owned())),
+                    "onmouseover" => this.set_onmouseover(Some(value.to_owned())),
+                    "onmouseup" => this.set_onmouseup(Some(value.to_owned())),
+                    "onpaste" => this.set_onpaste(Some(value.to_owned())),
+                    "onpause" => this.set_onpause(Some(value.to_owned())),
+                    "onplay" => this.set_onplay(Some(value.to_owned())),
+                    "onplaying" => this.set_onplaying(Some(value.to_owned())),
+                    "onprogress" => this.set_onprogress(Some(value.to_owned())),
+                    "onratechange" => this.set_onratechange(Some(value.to_owned())),
+                    "onreset" => this.set_onreset(Some(value.to_owned())),
+                    "onresize" => this.set_onresize(Some(value.to_owned())),
+                    "onscroll" => this.set_onscroll(Some(value.to_owned())),
+                    "onscrollend" => this.set_onscrollend(Some(value.to_owned())),
+                    "onsecuritypolicyviolation" => {
+                        this.set_onsecuritypolicyviolation(Some(value.to_owned()))
+                    }
+                    "onseeked" => this.set_onseeked(Some(value.to_owned())),
+                    "onseeking" => this.set_onseeking(Some(value.to_owned())),
+                    "onselect" => this.set_onselect(Some(value.to_owned())),
+                    "onslotchange" => this.set_onslotchange(Some(value.to_owned())),
+                    "onstalled" => this.set_onstalled(Some(value.to_owned())),
+                    "onsubmit" => this.set_onsubmit(Some(value.to_owned())),
+                    "onsuspend" => this.set_onsuspend(Some(value.to_owned())),
+                    "ontimeupdate" => this.set_ontimeupdate(Some(value.to_owned())),
+                    "ontoggle" => this.set_ontoggle(Some(value.to_owned())),
+                    "onvolumechange" => this.set_onvolumechange(Some(value.to_owned())),
+                    "onwaiting" => this.set_onwaiting(Some(value.to_owned())),
+                    "onwebkitanimationend" => {
+                        this.set_onwebkitanimationend(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationiteration" => {
+                        this.set_onwebkitanimationiteration(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationstart" => {
+                        this.set_onwebkitanimationstart(Some(value.to_owned()))
+                    }
+                    "onwebkittransitionend" => {
+                        this.set_onwebkittransitionend(Some(value.to_owned()))
+                    }
+                    "onwheel" => this.set_onwheel(Some(value.to_owned())),
+                    _ => cx.other_attribute(this.data_map_mut(), name, value),
+                }
+            }
+            this.children = cx.children(element);
+            this
+        }
+    }
+    impl crate::tree::Element for KeyboardInput {
+        fn tag_name(&self) -> &'static str {
+            "kbd"
+        }
+        fn is_inline(&self) -> bool {
+            true
+        }
+        fn is_preformatted(&self) -> bool {
+            false
+        }
+        fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {
+            html_sys::RenderElement::write_opening_tag(&self.sys, writer)
+        }
+        fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {
+            html_sys::RenderElement::write_closing_tag(&self.sys, writer)
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+            self.children.get(index).map(From::from)
+        }
+    }
+    impl<'a> From<&'a KeyboardInput> for crate::tree::Part<'a> {
+        fn from(element: &'a KeyboardInput) -> Self {
+            crate::tree::Part::Element(element)
+        }
+    }
+    impl<'a> From<&'a KeyboardInput> for crate::stream::Chunks<'a> {
+        fn from(element: &'a KeyboardInput) -> Self {
+            crate::stream::Chunks::new(element)
+        }
+    }
+    impl<'a> From<&'a KeyboardInput> for crate::render::Pretty<'a> {
+        fn from(element: &'a KeyboardInput) -> Self {
+            crate::render::Pretty::new(element.into())
+        }
+    }
+    impl<'a> From<&'a KeyboardInput> for crate::minify::Minified<'a> {
+        fn from(element: &'a KeyboardInput) -> Self {
+            crate::minify::Minified::new(element.into())
+        }
+    }
+    impl crate::visit::Visit for KeyboardInput {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn eq_element(&self, other: &dyn crate::visit::Visit) -> bool {
+            other.downcast_ref::<Self>() == Some(self)
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::visit::NodeRef<'_>> {
+            self.children.get(index).map(From::from)
+        }
+        fn child_mut(
+            &mut self,
+            index: usize,
+        ) -> std::option::Option<crate::visit::NodeMut<'_>> {
+            self.children.get_mut(index).map(From::from)
+        }
+        fn retain_children(
+            &mut self,
+            f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool,
+        ) {
+            self.children.retain(|child| f(child.into()));
+        }
+        fn insert_child(
+            &mut self,
+            index: usize,
+            node: crate::visit::NodeRef<'_>,
+        ) -> bool {
+            match super::child::KeyboardInputChild::from_node(node) {
+                Some(child) if index <= self.children.len() => {
+                    self.children.insert(index, child);
+                    true
+                }
+                _ => false,
+            }
+        }
+        fn remove_child(&mut self, index: usize) -> bool {
+            match index < self.children.len() {
+                true => {
+                    self.children.remove(index);
+                    true
+                }
+                false => false,
+            }
+        }
+        fn set_attribute(
+            &mut self,
+            name: &str,
+            value: std::option::Option<&str>,
+        ) -> bool {
+            match name {
+                "role" => self.set_role(value.and_then(|value| value.parse().ok())),
+                "aria-activedescendant" => {
+                    self.set_aria_active_descendant_element(value.map(str::to_owned))
+                }
+                "aria-atomic" => {
+                    self.set_aria_atomic(value.is_some_and(|value| value != "false"))
+                }
+                "aria-autocomplete" => {
+                    self.set_aria_auto_complete(
+                        value.and_then(|value| value.parse().ok()),
+                    )
+                }
+                "aria-brailleroledescription" => {
+                    self.set_aria_braille_role_description(value.map(str::to_owned))
+                }
+                "aria-busy" => {
+                    self.set_aria_busy(value.is_some_and(|value| value != "false"))
+                }
+                "aria-checked" => {
+                    self.set_aria_checked(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-colcount" => {
+                    self.set_aria_col_count(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-colindex" => {
+                    self.set_aria_col_index(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-colindextext" => {
+                    self.set_aria_col_index_text(value.map(str::to_owned))
+                }
+                "aria-colspan" => {
+                    self.set_aria_col_span(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-controls" => {
+                    self.set_aria_controls_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-current" => {
+                    self.set_aria_current(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-describedby" => {
+                    self.set_aria_described_by_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-description" => self.set_aria_description(value.map(str::to_owned)),
+                "aria-details" => {
+                    self.set_aria_details_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-disabled" => {
+                    self.set_aria_disabled(value.is_some_and(|value| value != "false"))
+                }
+                "aria-dropeffect" => {
+                    self.set_aria_drop_effect(
+                        value
+                            .and_then(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(str::parse)
+                                    .collect::<Result<_, _>>()
+                                    .ok()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-errormessage" => {
+                    self.set_aria_error_message_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-expanded" => {
+                    self.set_aria_expanded(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-flowto" => {
+                    self.set_aria_flow_to_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-grabbed" => {
+                    self.set_aria_grabbed(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-haspopup" => {
+                    self.set_aria_has_popup(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-hidden" => {
+                    self.set_aria_hidden(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-invalid" => {
+                    self.set_aria_invalid(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-keyshortcuts" => {
+                    self.set_aria_key_shortcuts(value.map(str::to_owned))
+                }
+                "aria-level" => {
+                    self.set_aria_level(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-live" => {
+                    self.set_aria_live(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-modal" => {
+                    self.set_aria_modal(value.is_some_and(|value| value != "false"))
+                }
+                "aria-multiline" => {
+                    self.set_aria_multi_line(value.is_some_and(|value| value != "false"))
+                }
+                "aria-multiselectable" => {
+                    self.set_aria_multi_selectable(
+                        value.is_some_and(|value| value != "false"),
+                    )
+                }
+                "aria-orientation" => {
+                    self.set_aria_orientation(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-owns" => {
+                    self.set_aria_owns_elements(
+                        value
+                            .map(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(|value| value.to_owned().into())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-placeholder" => self.set_aria_placeholder(value.map(str::to_owned)),
+                "aria-posinset" => {
+                    self.set_aria_pos_in_set(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-pressed" => {
+                    self.set_aria_pressed(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-readonly" => {
+                    self.set_aria_read_only(value.is_some_and(|value| value != "false"))
+                }
+                "aria-relevant" => {
+                    self.set_aria_relevant(
+                        value
+                            .and_then(|value| {
+                                value
+                                    .split_ascii_whitespace()
+                                    .map(str::parse)
+                                    .collect::<Result<_, _>>()
+                                    .ok()
+                            })
+                            .unwrap_or_default(),
+                    )
+                }
+                "aria-required" => {
+                    self.set_aria_required(value.is_some_and(|value| value != "false"))
+                }
+                "aria-roledescription" => {
+                    self.set_aria_role_description(value.map(str::to_owned))
+                }
+                "aria-rowcount" => {
+                    self.set_aria_row_count(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-rowindex" => {
+                    self.set_aria_row_index(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-rowindextext" => {
+                    self.set_aria_row_index_text(value.map(str::to_owned))
+                }
+                "aria-rowspan" => {
+                    self.set_aria_row_span(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-selected" => {
+                    self.set_aria_selected(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-setsize" => {
+                    self.set_aria_set_size(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-sort" => {
+                    self.set_aria_sort(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuemax" => {
+                    self.set_aria_value_max(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuemin" => {
+                    self.set_aria_value_min(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuenow" => {
+                    self.set_aria_value_now(value.and_then(|value| value.parse().ok()))
+                }
+                "aria-valuetext" => self.set_aria_value_text(value.map(str::to_owned)),
+                "accesskey" => self.set_access_key(value.map(str::to_owned)),
+                "autocapitalize" => {
+                    self.set_auto_capitalize(value.and_then(|value| value.parse().ok()))
+                }
+                "autofocus" => self.set_autofocus(value.is_some()),
+                "class" => self.set_class(value.map(str::to_owned)),
+                "contenteditable" => {
+                    self.set_content_editable(value.and_then(|value| value.parse().ok()))
+                }
+                "dir" => self.set_direction(value.and_then(|value| value.parse().ok())),
+                "draggable" => {
+                    self.set_draggable(value.and_then(|value| value.parse().ok()))
+                }
+                "enterkeyhint" => {
+                    self.set_enter_key_hint(value.and_then(|value| value.parse().ok()))
+                }
+                "exportparts" => self.set_export_parts(value.map(str::to_owned)),
+                "hidden" => self.set_hidden(value.and_then(|value| value.parse().ok())),
+                "id" => self.set_id(value.map(str::to_owned)),
+                "inert" => self.set_inert(value.is_some()),
+                "inputmode" => {
+                    self.set_input_mode(value.and_then(|value| value.parse().ok()))
+                }
+                "is" => self.set_is_(value.map(str::to_owned)),
+                "itemid" => self.set_item_id(value.map(str::to_owned)),
+                "itemprop" => self.set_item_prop(value.map(str::to_owned)),
+                "itemref" => self.set_item_ref(value.map(str::to_owned)),
+                "itemscope" => self.set_item_scope(value.is_some()),
+                "itemtype" => self.set_item_type(value.map(str::to_owned)),
+                "lang" => self.set_lang(value.map(str::to_owned)),
+                "nonce" => self.set_nonce(value.map(str::to_owned)),
+                "part" => self.set_part(value.map(str::to_owned)),
+                "slot" => self.set_slot(value.map(str::to_owned)),
+                "spellcheck" => {
+                    self.set_spellcheck(value.and_then(|value| value.parse().ok()))
+                }
+                "style" => self.set_style(value.map(str::to_owned)),
+                "tabindex" => {
+                    self.set_tab_index(value.and_then(|value| value.parse().ok()))
+                }
+                "title" => self.set_title(value.map(str::to_owned)),
+                "translate" => {
+                    self.set_translate(value.and_then(|value| value.parse().ok()))
+                }
+                "onabort" => self.set_onabort(value.map(str::to_owned)),
+                "onauxclick" => self.set_onauxclick(value.map(str::to_owned)),
+                "onbeforeinput" => self.set_onbeforeinput(value.map(str::to_owned)),
+                "onbeforematch" => self.set_onbeforematch(value.map(str::to_owned)),
+                "onbeforetoggle" => self.set_onbeforetoggle(value.map(str::to_owned)),
+                "onblur" => self.set_onblur(value.map(str::to_owned)),
+                "oncancel" => self.set_oncancel(value.map(str::to_owned)),
+                "oncanplay" => self.set_oncanplay(value.map(str::to_owned)),
+                "oncanplaythrough" => self.set_oncanplaythrough(value.map(str::to_owned)),
+                "onchange" => self.set_onchange(value.map(str::to_owned)),
+                "onclick" => self.set_onclick(value.map(str::to_owned)),
+                "onclose" => self.set_onclose(value.map(str::to_owned)),
+                "oncontextlost" => self.set_oncontextlost(value.map(str::to_owned)),
+                "oncontextmenu" => self.set_oncontextmenu(value.map(str::to_owned)),
+                "oncontextrestored" => {
+                    self.set_oncontextrestored(value.map(str::to_owned))
+                }
+                "oncopy" => self.set_oncopy(value.map(str::to_owned)),
+                "oncuechange" => self.set_oncuechange(value.map(str::to_owned)),
+                "oncut" => self.set_oncut(value.map(str::to_owned)),
+                "ondblclick" => self.set_ondblclick(value.map(str::to_owned)),
+                "ondrag" => self.set_ondrag(value.map(str::to_owned)),
+                "ondragend" => self.set_ondragend(value.map(str::to_owned)),
+                "ondragenter" => self.set_ondragenter(value.map(str::to_owned)),
+                "ondragleave" => self.set_ondragleave(value.map(str::to_owned)),
+                "ondragover" => self.set_ondragover(value.map(str::to_owned)),
+                "ondragstart" => self.set_ondragstart(value.map(str::to_owned)),
+                "ondrop" => self.set_ondrop(value.map(str::to_owned)),
+                "ondurationchange" => self.set_ondurationchange(value.map(str::to_owned)),
+                "onemptied" => self.set_onemptied(value.map(str::to_owned)),
+                "onended" => self.set_onended(value.map(str::to_owned)),
+                "onerror" => self.set_onerror(value.map(str::to_owned)),
+                "onfocus" => self.set_onfocus(value.map(str::to_owned)),
+                "onformdata" => self.set_onformdata(value.map(str::to_owned)),
+                "oninput" => self.set_oninput(value.map(str::to_owned)),
+                "oninvalid" => self.set_oninvalid(value.map(str::to_owned)),
+                "onkeydown" => self.set_onkeydown(value.map(str::to_owned)),
+                "onkeypress" => self.set_onkeypress(value.map(str::to_owned)),
+                "onkeyup" => self.set_onkeyup(value.map(str::to_owned)),
+                "onload" => self.set_onload(value.map(str::to_owned)),
+                "onloadeddata" => self.set_onloadeddata(value.map(str::to_owned)),
+                "onloadedmetadata" => self.set_onloadedmetadata(value.map(str::to_owned)),
+                "onloadstart" => self.set_onloadstart(value.map(str::to_owned)),
+                "onmousedown" => self.set_onmousedown(value.map(str::to_owned)),
+                "onmouseenter" => self.set_onmouseenter(value.map(str::to_owned)),
+                "onmouseleave" => self.set_onmouseleave(value.map(str::to_owned)),
+                "onmousemove" => self.set_onmousemove(value.map(str::to_owned)),
+                "onmouseout" => self.set_onmouseout(value.map(str::to_owned)),
+                "onmouseover" => self.set_onmouseover(value.map(str::to_owned)),
+                "onmouseup" => self.set_onmouseup(value.map(str::to_owned)),
+                "onpaste" => self.set_onpaste(value.map(str::to_owned)),
+                "onpause" => self.set_onpause(value.map(str::to_owned)),
+                "onplay" => self.set_onplay(value.map(str::to_owned)),
+                "onplaying" => self.set_onplaying(value.map(str::to_owned)),
+                "onprogress" => self.set_onprogress(value.map(str::to_owned)),
+                "onratechange" => self.set_onratechange(value.map(str::to_owned)),
+                "onreset" => self.set_onreset(value.map(str::to_owned)),
+                "onresize" => self.set_onresize(value.map(str::to_owned)),
+                "onscroll" => self.set_onscroll(value.map(str::to_owned)),
+                "onscrollend" => self.set_onscrollend(value.map(str::to_owned)),
+                "onsecuritypolicyviolation" => {
+                    self.set_onsecuritypolicyviolation(value.map(str::to_owned))
+                }
+                "onseeked" => self.set_onseeked(value.map(str::to_owned)),
+                "onseeking" => self.set_onseeking(value.map(str::to_owned)),
+                "onselect" => self.set_onselect(value.map(str::to_owned)),
+                "onslotchange" => self.set_onslotchange(value.map(str::to_owned)),
+                "onstalled" => self.set_onstalled(value.map(str::to_owned)),
+                "onsubmit" => self.set_onsubmit(value.map(str::to_owned)),
+                "onsuspend" => self.set_onsuspend(value.map(str::to_owned)),
+                "ontimeupdate" => self.set_ontimeupdate(value.map(str::to_owned)),
+                "ontoggle" => self.set_ontoggle(value.map(str::to_owned)),
+                "onvolumechange" => self.set_onvolumechange(value.map(str::to_owned)),
+                "onwaiting" => self.set_onwaiting(value.map(str::to_owned)),
+                "onwebkitanimationend" => {
+                    self.set_onwebkitanimationend(value.map(str::to_owned))
+                }
+                "onwebkitanimationiteration" => {
+                    self.set_onwebkitanimationiteration(value.map(str::to_owned))
+                }
+                "onwebkitanimationstart" => {
+                    self.set_onwebkitanimationstart(value.map(str::to_owned))
+                }
+                "onwebkittransitionend" => {
+                    self.set_onwebkittransitionend(value.map(str::to_owned))
+                }
+                "onwheel" => self.set_onwheel(value.map(str::to_owned)),
+                _ => {
+                    match (name.strip_prefix("data-"), value) {
+                        (Some(key), Some(value)) => {
+                            self.data_map_mut()
+                                .insert(key.to_owned().into(), value.to_owned().into());
+                        }
+                        (Some(key), None) => {
+                            self.data_map_mut().remove(key);
+                        }
+                        (None, _) => return false,
+                    }
+                }
+            }
+            true
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeElement for KeyboardInput {
+        const TAG_NAME: &'static str = "kbd";
+        type Attributes = html_sys::text::KeyboardInput;
+        type Child = super::child::KeyboardInputChild;
+        fn attributes(&self) -> &Self::Attributes {
+            &self.sys
+        }
+        fn children(&self) -> &[Self::Child] {
+            &self.children
+        }
+        fn from_parts(sys: Self::Attributes, children: Vec<Self::Child>) -> Self {
+            Self { sys, children }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for KeyboardInput {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            crate::serialize::serialize_element(self, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for KeyboardInput {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_element(deserializer)
+        }
+    }
+    impl From<KeyboardInput> for html_sys::text::KeyboardInput {
+        fn from(element: KeyboardInput) -> Self {
+            element.sys
+        }
+    }
+    impl From<html_sys::text::KeyboardInput> for KeyboardInput {
+        fn from(sys: html_sys::text::KeyboardInput) -> Self {
+            Self { sys, children: vec![] }
+        }
+    }
+}
+pub mod child {
+    /// The permitted child items for the `KeyboardInput` element
+    #[derive(PartialEq, Clone)]
+    #[allow(clippy::large_enum_variant)]
+    pub enum KeyboardInputChild {
+        /// The Abbreviation element
+        Abbreviation(crate::generated::all::Abbreviation),
+        /// The Anchor element
+        Anchor(crate::generated::all::Anchor),
+        /// The Audio element
+        Audio(crate::generated::all::Audio),
+        /// The BidirectionalIsolate element
+        BidirectionalIsolate(crate::generated::all::BidirectionalIsolate),
+        /// The BidirectionalTextOverride element
+        BidirectionalTextOverride(crate::generated::all::BidirectionalTextOverride),
+        /// The Bold element
+        Bold(crate::generated::all::Bold),
+        /// The Button element
+        Button(crate::generated::all::Button),
+        /// The Canvas element
+        Canvas(crate::generated::all::Canvas),
+        /// The Cite element
+        Cite(crate::generated::all::Cite),
+        /// The Code element
+        Code(crate::generated::all::Code),
+        /// The Data element
+        Data(crate::generated::all::Data),
+        /// The DataList element
+        DataList(crate::generated::all::DataList),
+        /// The Definition element
+        Definition(crate::generated::all::Definition),
+        /// The DeletedText element
+        DeletedText(crate::generated::all::DeletedText),
+        /// The Embed element
+        Embed(crate::generated::all::Embed),
+        /// The Emphasis element
+        Emphasis(crate::generated::all::Emphasis),
+        /// The Iframe element
+        Iframe(crate::generated::all::Iframe),
+        /// The Image element
+        Image(crate::generated::all::Image),
+        /// The ImageMap element
+        ImageMap(crate::generated::all::ImageMap),
+        /// The ImageMapArea element
+        ImageMapArea(crate::generated::all::ImageMapArea),
+        /// The Input element
+        Input(crate::generated::all::Input),
+        /// The InsertedText element
+        InsertedText(crate::generated::all::InsertedText),
+        /// The Italic element
+        Italic(crate::generated::all::Italic),
+        /// The KeyboardInput element
+        KeyboardInput(crate::generated::all::KeyboardInput),
+        /// The Label element
+        Label(crate::generated::all::Label),
+        /// The LineBreak element
+        LineBreak(crate::generated::all::LineBreak),
+        /// The LineBreakOpportunity element
+        LineBreakOpportunity(crate::generated::all::LineBreakOpportunity),
+        /// The Link element
+        Link(crate::generated::all::Link),
+        /// The MarkText element
+        MarkText(crate::generated::all::MarkText),
+        /// The Math element
+        Math(crate::generated::all::Math),
+        /// The Meta element
+        Meta(crate::generated::all::Meta),
+        /// The Meter element
+        Meter(crate::generated::all::Meter),
+        /// The NoScript element
+        NoScript(crate::generated::all::NoScript),
+        /// The Object element
+        Object(crate::generated::all::Object),
+        /// The Output element
+        Output(crate::generated::all::Output),
+        /// The Picture element
+        Picture(crate::generated::all::Picture),
+        /// The Progress element
+        Progress(crate::generated::all::Progress),
+        /// The Quotation element
+        Quotation(crate::generated::all::Quotation),
+        /// The RubyAnnotation element
+        RubyAnnotation(crate::generated::all::RubyAnnotation),
+        /// The SampleOutput element
+        SampleOutput(crate::generated::all::SampleOutput),
+        /// The Script element
+        Script(crate::generated::all::Script),
+        /// The Select element
+        Select(crate::generated::all::Select),
+        /// The SideComment element
+        SideComment(crate::generated::all::SideComment),
+        /// The Slot element
+        Slot(crate::generated::all::Slot),
+        /// The Span element
+        Span(crate::generated::all::Span),
+        /// The StrikeThrough element
+        StrikeThrough(crate::generated::all::StrikeThrough),
+        /// The Strong element
+        Strong(crate::generated::all::Strong),
+        /// The SubScript element
+        SubScript(crate::generated::all::SubScript),
+        /// The SuperScript element
+        SuperScript(crate::generated::all::SuperScript),
+        /// The Svg element
+        Svg(crate::generated::all::Svg),
+        /// The Template element
+        Template(crate::generated::all::Template),
+        /// The Text element
+        Text(std::borrow::Cow<'static, str>),
+        /// The TextArea element
+        TextArea(crate::generated::all::TextArea),
+        /// The Time element
+        Time(crate::generated::all::Time),
+        /// The Underline element
+        Underline(crate::generated::all::Underline),
+        /// The Variable element
+        Variable(crate::generated::all::Variable),
+        /// The Video element
+        Video(crate::generated::all::Video),
+        /// A comment
+        Comment(crate::Comment),
+        /// Trusted HTML, which is written without being escaped
+        RawHtml(crate::RawHtml),
+    }
+    impl std::convert::From<crate::generated::all::Abbreviation> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Abbreviation) -> Self {
+            Self::Abbreviation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Anchor> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Anchor) -> Self {
+            Self::Anchor(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Audio> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Audio) -> Self {
+            Self::Audio(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::BidirectionalIsolate>
+    for KeyboardInputChild {
+        fn from(value: crate::generated::all::BidirectionalIsolate) -> Self {
+            Self::BidirectionalIsolate(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::BidirectionalTextOverride>
+    for KeyboardInputChild {
+        fn from(value: crate::generated::all::BidirectionalTextOverride) -> Self {
+            Self::BidirectionalTextOverride(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Bold> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Bold) -> Self {
+            Self::Bold(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Button> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Button) -> Self {
+            Self::Button(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Canvas> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Canvas) -> Self {
+            Self::Canvas(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Cite> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Cite) -> Self {
+            Self::Cite(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Code> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Code) -> Self {
+            Self::Code(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Data> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Data) -> Self {
+            Self::Data(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DataList> for KeyboardInputChild {
+        fn from(value: crate::generated::all::DataList) -> Self {
+            Self::DataList(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Definition> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Definition) -> Self {
+            Self::Definition(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DeletedText> for KeyboardInputChild {
+        fn from(value: crate::generated::all::DeletedText) -> Self {
+            Self::DeletedText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Embed> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Embed) -> Self {
+            Self::Embed(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Emphasis> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Emphasis) -> Self {
+            Self::Emphasis(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Iframe> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Iframe) -> Self {
+            Self::Iframe(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Image> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Image) -> Self {
+            Self::Image(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::ImageMap> for KeyboardInputChild {
+        fn from(value: crate::generated::all::ImageMap) -> Self {
+            Self::ImageMap(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::ImageMapArea> for KeyboardInputChild {
+        fn from(value: crate::generated::all::ImageMapArea) -> Self {
+            Self::ImageMapArea(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Input> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Input) -> Self {
+            Self::Input(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::InsertedText> for KeyboardInputChild {
+        fn from(value: crate::generated::all::InsertedText) -> Self {
+            Self::InsertedText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Italic> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Italic) -> Self {
+            Self::Italic(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::KeyboardInput>
+    for KeyboardInputChild {
+        fn from(value: crate::generated::all::KeyboardInput) -> Self {
+            Self::KeyboardInput(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Label> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Label) -> Self {
+            Self::Label(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::LineBreak> for KeyboardInputChild {
+        fn from(value: crate::generated::all::LineBreak) -> Self {
+            Self::LineBreak(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::LineBreakOpportunity>
+    for KeyboardInputChild {
+        fn from(value: crate::generated::all::LineBreakOpportunity) -> Self {
+            Self::LineBreakOpportunity(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Link> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Link) -> Self {
+            Self::Link(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::MarkText> for KeyboardInputChild {
+        fn from(value: crate::generated::all::MarkText) -> Self {
+            Self::MarkText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Math> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Math) -> Self {
+            Self::Math(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Meta> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Meta) -> Self {
+            Self::Meta(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Meter> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Meter) -> Self {
+            Self::Meter(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::NoScript> for KeyboardInputChild {
+        fn from(value: crate::generated::all::NoScript) -> Self {
+            Self::NoScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Object> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Object) -> Self {
+            Self::Object(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Output> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Output) -> Self {
+            Self::Output(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Picture> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Picture) -> Self {
+            Self::Picture(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Progress> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Progress) -> Self {
+            Self::Progress(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Quotation> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Quotation) -> Self {
+            Self::Quotation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::RubyAnnotation>
+    for KeyboardInputChild {
+        fn from(value: crate::generated::all::RubyAnnotation) -> Self {
+            Self::RubyAnnotation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SampleOutput> for KeyboardInputChild {
+        fn from(value: crate::generated::all::SampleOutput) -> Self {
+            Self::SampleOutput(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Script> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Script) -> Self {
+            Self::Script(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Select> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Select) -> Self {
+            Self::Select(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SideComment> for KeyboardInputChild {
+        fn from(value: crate::generated::all::SideComment) -> Self {
+            Self::SideComment(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Slot> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Slot) -> Self {
+            Self::Slot(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Span> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Span) -> Self {
+            Self::Span(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::StrikeThrough>
+    for KeyboardInputChild {
+        fn from(value: crate::generated::all::StrikeThrough) -> Self {
+            Self::StrikeThrough(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Strong> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Strong) -> Self {
+            Self::Strong(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SubScript> for KeyboardInputChild {
+        fn from(value: crate::generated::all::SubScript) -> Self {
+            Self::SubScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SuperScript> for KeyboardInputChild {
+        fn from(value: crate::generated::all::SuperScript) -> Self {
+            Self::SuperScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Svg> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Svg) -> Self {
+            Self::Svg(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Template> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Template) -> Self {
+            Self::Template(value)
+        }
+    }
+    impl std::convert::From<std::borrow::Cow<'static, str>> for KeyboardInputChild {
+        fn from(value: std::borrow::Cow<'static, str>) -> Self {
+            Self::Text(value)
+        }
+    }
+    impl std::convert::From<&'static str> for KeyboardInputChild {
+        fn from(value: &'static str) -> Self {
+            Self::Text(value.into())
+        }
+    }
+    impl std::convert::From<String> for KeyboardInputChild {
+        fn from(value: String) -> Self {
+            Self::Text(value.into())
+        }
+    }
+    impl std::convert::From<crate::generated::all::TextArea> for KeyboardInputChild {
+        fn from(value: crate::generated::all::TextArea) -> Self {
+            Self::TextArea(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Time> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Time) -> Self {
+            Self::Time(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Underline> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Underline) -> Self {
+            Self::Underline(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Variable> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Variable) -> Self {
+            Self::Variable(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Video> for KeyboardInputChild {
+        fn from(value: crate::generated::all::Video) -> Self {
+            Self::Video(value)
+        }
+    }
+    impl std::convert::From<crate::Comment> for KeyboardInputChild {
+        fn from(value: crate::Comment) -> Self {
+            Self::Comment(value)
+        }
+    }
+    impl std::convert::From<crate::RawHtml> for KeyboardInputChild {
+        fn from(value: crate::RawHtml) -> Self {
+            Self::RawHtml(value)
+        }
+    }
+    impl std::fmt::Debug for KeyboardInputChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for KeyboardInputChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Abbreviation(el) => write!(f, "{el}"),
+                Self::Anchor(el) => write!(f, "{el}"),
+                Self::Audio(el) => write!(f, "{el}"),
+                Self::BidirectionalIsolate(el) => write!(f, "{el}"),
+                Self::BidirectionalTextOverride(el) => write!(f, "{el}"),
+                Self::Bold(el) => write!(f, "{el}"),
+                Self::Button(el) => write!(f, "{el}"),
+                Self::Canvas(el) => write!(f, "{el}"),
+                Self::Cite(el) => write!(f, "{el}"),
+                Self::Code(el) => write!(f, "{el}"),
+                Self::Data(el) => write!(f, "{el}"),
+                Self::DataList(el) => write!(f, "{el}"),
+                Self::Definition(el) => write!(f, "{el}"),
+                Self::DeletedText(el) => write!(f, "{el}"),
+                Self::Embed(el) => write!(f, "{el}"),
+                Self::Emphasis(el) => write!(f, "{el}"),
+                Self::Iframe(el) => write!(f, "{el}"),
+                Self::Image(el) => write!(f, "{el}"),
+                Self::ImageMap(el) => write!(f, "{el}"),
+                Self::ImageMapArea(el) => write!(f, "{el}"),
+                Self::Input(el) => write!(f, "{el}"),
+                Self::InsertedText(el) => write!(f, "{el}"),
+                Self::Italic(el) => write!(f, "{el}"),
+                Self::KeyboardInput(el) => write!(f, "{el}"),
+                Self::Label(el) => write!(f, "{el}"),
+                Self::LineBreak(el) => write!(f, "{el}"),
+                Self::LineBreakOpportunity(el) => write!(f, "{el}"),
+                Self::Link(el) => write!(f, "{el}"),
+                Self::MarkText(el) => write!(f, "{el}"),
+                Self::Math(el) => write!(f, "{el}"),
+                Self::Meta(el) => write!(f, "{el}"),
+                Self::Meter(el) => write!(f, "{el}"),
+                Self::NoScript(el) => write!(f, "{el}"),
+                Self::Object(el) => write!(f, "{el}"),
+                Self::Output(el) => write!(f, "{el}"),
+                Self::Picture(el) => write!(f, "{el}"),
+                Self::Progress(el) => write!(f, "{el}"),
+                Self::Quotation(el) => write!(f, "{el}"),
+                Self::RubyAnnotation(el) => write!(f, "{el}"),
+                Self::SampleOutput(el) => write!(f, "{el}"),
+                Self::Script(el) => write!(f, "{el}"),
+                Self::Select(el) => write!(f, "{el}"),
+                Self::SideComment(el) => write!(f, "{el}"),
+                Self::Slot(el) => write!(f, "{el}"),
+                Self::Span(el) => write!(f, "{el}"),
+                Self::StrikeThrough(el) => write!(f, "{el}"),
+                Self::Strong(el) => write!(f, "{el}"),
+                Self::SubScript(el) => write!(f, "{el}"),
+                Self::SuperScript(el) => write!(f, "{el}"),
+                Self::Svg(el) => write!(f, "{el}"),
+                Self::Template(el) => write!(f, "{el}"),
+                Self::Text(el) => write!(f, "{}", html_sys::escape::Text(el)),
+                Self::TextArea(el) => write!(f, "{el}"),
+                Self::Time(el) => write!(f, "{el}"),
+                Self::Underline(el) => write!(f, "{el}"),
+                Self::Variable(el) => write!(f, "{el}"),
+                Self::Video(el) => write!(f, "{el}"),
+                Self::Comment(el) => write!(f, "{el}"),
+                Self::RawHtml(el) => write!(f, "{el}"),
+            }
+        }
+    }
+    impl<'a> From<&'a KeyboardInputChild> for crate::Node<'a> {
+        fn from(child: &'a KeyboardInputChild) -> Self {
+            match child {
+                KeyboardInputChild::Abbreviation(el) => crate::Node::from(el),
+                KeyboardInputChild::Anchor(el) => crate::Node::from(el),
+                KeyboardInputChild::Audio(el) => crate::Node::from(el),
+                KeyboardInputChild::BidirectionalIsolate(el) => crate::Node::from(el),
+                KeyboardInputChild::BidirectionalTextOverride(el) => {
+                    crate::Node::from(el)
+                }
+                KeyboardInputChild::Bold(el) => crate::Node::from(el),
+                KeyboardInputChild::Button(el) => crate::Node::from(el),
+                KeyboardInputChild::Canvas(el) => crate::Node::from(el),
+                KeyboardInputChild::Cite(el) => crate::Node::from(el),
+                KeyboardInputChild::Code(el) => crate::Node::from(el),
+                KeyboardInputChild::Data(el) => crate::Node::from(el),
+                KeyboardInputChild::DataList(el) => crate::Node::from(el),
+                KeyboardInputChild::Definition(el) => crate::Node::from(el),
+                KeyboardInputChild::DeletedText(el) => crate::Node::from(el),
+                KeyboardInputChild::Embed(el) => crate::Node::from(el),
+                KeyboardInputChild::Emphasis(el) => crate::Node::from(el),
+                KeyboardInputChild::Iframe(el) => crate::Node::from(el),
+                KeyboardInputChild::Image(el) => crate::Node::from(el),
+                KeyboardInputChild::ImageMap(el) => crate::Node::from(el),
+                KeyboardInputChild::ImageMapArea(el) => crate::Node::from(el),
+                KeyboardInputChild::Input(el) => crate::Node::from(el),
+                KeyboardInputChild::InsertedText(el) => crate::Node::from(el),
+                KeyboardInputChild::Italic(el) => crate::Node::from(el),
+                KeyboardInputChild::KeyboardInput(el) => crate::Node::from(el),
+                KeyboardInputChild::Label(el) => crate::Node::from(el),
+                KeyboardInputChild::LineBreak(el) => crate::Node::from(el),
+                KeyboardInputChild::LineBreakOpportunity(el) => crate::Node::from(el),
+                KeyboardInputChild::Link(el) => crate::Node::from(el),
+                KeyboardInputChild::MarkText(el) => crate::Node::from(el),
+                KeyboardInputChild::Math(el) => crate::Node::from(el),
+                KeyboardInputChild::Meta(el) => crate::Node::from(el),
+                KeyboardInputChild::Meter(el) => crate::Node::from(el),
+                KeyboardInputChild::NoScript(el) => crate::Node::from(el),
+                KeyboardInputChild::Object(el) => crate::Node::from(el),
+                KeyboardInputChild::Output(el) => crate::Node::from(el),
+                KeyboardInputChild::Picture(el) => crate::Node::from(el),
+                KeyboardInputChild::Progress(el) => crate::Node::from(el),
+                KeyboardInputChild::Quotation(el) => crate::Node::from(el),
+                KeyboardInputChild::RubyAnnotation(el) => crate::Node::from(el),
+                KeyboardInputChild::SampleOutput(el) => crate::Node::from(el),
+                KeyboardInputChild::Script(el) => crate::Node::from(el),
+                KeyboardInputChild::Select(el) => crate::Node::from(el),
+                KeyboardInputChild::SideComment(el) => crate::Node::from(el),
+                KeyboardInputChild::Slot(el) => crate::Node::from(el),
+                KeyboardInputChild::Span(el) => crate::Node::from(el),
+                KeyboardInputChild::StrikeThrough(el) => crate::Node::from(el),
+                KeyboardInputChild::Strong(el) => crate::Node::from(el),
+                KeyboardInputChild::SubScript(el) => crate::Node::from(el),
+                KeyboardInputChild::SuperScript(el) => crate::Node::from(el),
+                KeyboardInputChild::Svg(el) => crate::Node::from(el),
+                KeyboardInputChild::Template(el) => crate::Node::from(el),
+                KeyboardInputChild::Text(el) => crate::Node::from(el),
+                KeyboardInputChild::TextArea(el) => crate::Node::from(el),
+                KeyboardInputChild::Time(el) => crate::Node::from(el),
+                KeyboardInputChild::Underline(el) => crate::Node::from(el),
+                KeyboardInputChild::Variable(el) => crate::Node::from(el),
+                KeyboardInputChild::Video(el) => crate::Node::from(el),
+                KeyboardInputChild::Comment(el) => crate::Node::from(el),
+                KeyboardInputChild::RawHtml(el) => crate::Node::from(el),
+            }
+        }
+    }
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromContent for KeyboardInputChild {
+        const PARENT_TAG_NAME: &'static str = "kbd";
+        fn from_content(
+            content: crate::parse::Content<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Option<Self> {
+            match content {
+                crate::parse::Content::Text(text) => {
+                    Some(Self::Text(text.to_owned().into()))
+                }
+                crate::parse::Content::Comment(comment) => {
+                    Some(Self::Comment(crate::Comment::new(comment.to_owned())))
+                }
+                crate::parse::Content::Element(el) => {
+                    match el.tag_name() {
+                        "abbr" => Some(Self::Abbreviation(cx.element(el))),
+                        "a" => Some(Self::Anchor(cx.element(el))),
+                        "audio" => Some(Self::Audio(cx.element(el))),
+                        "bdi" => Some(Self::BidirectionalIsolate(cx.element(el))),
+                        "bdo" => Some(Self::BidirectionalTextOverride(cx.element(el))),
+                        "b" => Some(Self::Bold(cx.element(el))),
+                        "button" => Some(Self::Button(cx.element(el))),
+                        "canvas" => Some(Self::Canvas(cx.element(el))),
+                        "cite" => Some(Self::Cite(cx.element(el))),
+                        "code" => Some(Self::Code(cx.element(el))),
+                        "data" => Some(Self::Data(cx.element(el))),
+                        "datalist" => Some(Self::DataList(cx.element(el))),
+                        "dfn" => Some(Self::Definition(cx.element(el))),
+                        "del" => Some(Self::DeletedText(cx.element(el))),
+                        "embed" => Some(Self::Embed(cx.element(el))),
+                        "em" => Some(Self::Emphasis(cx.element(el))),
+                        "iframe" => Some(Self::Iframe(cx.element(el))),
+                        "img" => Some(Self::Image(cx.element(el))),
+                        "map" => Some(Self::ImageMap(cx.element(el))),
+                        "area" => Some(Self::ImageMapArea(cx.element(el))),
+                        "input" => Some(Self::Input(cx.element(el))),
+                        "ins" => Some(Self::InsertedText(cx.element(el))),
+                        "i" => Some(Self::Italic(cx.element(el))),
+                        "kbd" => Some(Self::KeyboardInput(cx.element(el))),
+                        "label" => Some(Self::Label(cx.element(el))),
+                        "br" => Some(Self::LineBreak(cx.element(el))),
+                        "wbr" => Some(Self::LineBreakOpportunity(cx.element(el))),
+                        "link" => Some(Self::Link(cx.element(el))),
+                        "mark" => Some(Self::MarkText(cx.element(el))),
+                        "math" => Some(Self::Math(cx.element(el))),
+                        "meta" => Some(Self::Meta(cx.element(el))),
+                        "meter" => Some(Self::Meter(cx.element(el))),
+                        "noscript" => Some(Self::NoScript(cx.element(el))),
+                        "object" => Some(Self::Object(cx.element(el))),
+                        "output" => Some(Self::Output(cx.element(el))),
+                        "picture" => Some(Self::Picture(cx.element(el))),
+                        "progress" => Some(Self::Progress(cx.element(el))),
+                        "q" => Some(Self::Quotation(cx.element(el))),
+                        "ruby" => Some(Self::RubyAnnotation(cx.element(el))),
+                        "samp" => Some(Self::SampleOutput(cx.element(el))),
+                        "script" => Some(Self::Script(cx.element(el))),
+                        "select" => Some(Self::Select(cx.element(el))),
+                        "small" => Some(Self::SideComment(cx.element(el))),
+                        "slot" => Some(Self::Slot(cx.element(el))),
+                        "span" => Some(Self::Span(cx.element(el))),
+                        "s" => Some(Self::StrikeThrough(cx.element(el))),
+                        "strong" => Some(Self::Strong(cx.element(el))),
+                        "sub" => Some(Self::SubScript(cx.element(el))),
+                        "sup" => Some(Self::SuperScript(cx.element(el))),
+                        "svg" => Some(Self::Svg(cx.element(el))),
+                        "template" => Some(Self::Template(cx.element(el))),
+                        "textarea" => Some(Self::TextArea(cx.element(el))),
+                        "time" => Some(Self::Time(cx.element(el))),
+                        "u" => Some(Self::Underline(cx.element(el))),
+                        "var" => Some(Self::Variable(cx.element(el))),
+                        "video" => Some(Self::Video(cx.element(el))),
+                        _ => cx.not_permitted(content),
+                    }
+                }
+            }
+        }
+    }
+    impl<'a> From<&'a KeyboardInputChild> for crate::tree::Part<'a> {
+        fn from(child: &'a KeyboardInputChild) -> Self {
+            match child {
+                KeyboardInputChild::Abbreviation(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Anchor(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Audio(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::BidirectionalIsolate(el) => {
+                    crate::tree::Part::Element(el)
+                }
+                KeyboardInputChild::BidirectionalTextOverride(el) => {
+                    crate::tree::Part::Element(el)
+                }
+                KeyboardInputChild::Bold(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Button(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Canvas(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Cite(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Code(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Data(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::DataList(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Definition(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::DeletedText(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Embed(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Emphasis(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Iframe(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Image(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::ImageMap(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::ImageMapArea(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Input(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::InsertedText(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Italic(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::KeyboardInput(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Label(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::LineBreak(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::LineBreakOpportunity(el) => {
+                    crate::tree::Part::Element(el)
+                }
+                KeyboardInputChild::Link(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::MarkText(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Math(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Meta(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Meter(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::NoScript(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Object(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Output(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Picture(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Progress(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Quotation(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::RubyAnnotation(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::SampleOutput(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Script(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Select(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::SideComment(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Slot(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Span(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::StrikeThrough(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Strong(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::SubScript(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::SuperScript(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Svg(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Template(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Text(el) => {
+                    crate::tree::Part::Text(html_sys::escape::Text(el))
+                }
+                KeyboardInputChild::TextArea(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Time(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Underline(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Variable(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Video(el) => crate::tree::Part::Element(el),
+                KeyboardInputChild::Comment(el) => {
+                    crate::tree::Part::Comment(html_sys::escape::Comment(el.as_str()))
+                }
+                KeyboardInputChild::RawHtml(el) => {
+                    crate::tree::Part::RawHtml(el.as_str())
+                }
+            }
+        }
+    }
+    impl<'a> From<&'a KeyboardInputChild> for crate::render::Pretty<'a> {
+        fn from(child: &'a KeyboardInputChild) -> Self {
+            crate::render::Pretty::new(child.into())
+        }
+    }
+    impl<'a> From<&'a KeyboardInputChild> for crate::minify::Minified<'a> {
+        fn from(child: &'a KeyboardInputChild) -> Self {
+            crate::minify::Minified::new(child.into())
+        }
+    }
+    impl KeyboardInputChild {
+        /// Clone a node into a child, if it's permitted.
+        pub(crate) fn from_node(
+            node: crate::visit::NodeRef<'_>,
+        ) -> std::option::Option<Self> {
+            match node {
+                crate::visit::NodeRef::Element(el) => {
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Abbreviation>()
+                    {
+                        return Some(Self::Abbreviation(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Anchor>()
+                    {
+                        return Some(Self::Anchor(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Audio>() {
+                        return Some(Self::Audio(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::BidirectionalIsolate>()
+                    {
+                        return Some(Self::BidirectionalIsolate(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<
+                            crate::generated::all::BidirectionalTextOverride,
+                        >()
+                    {
+                        return Some(Self::BidirectionalTextOverride(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Bold>() {
+                        return Some(Self::Bold(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Button>()
+                    {
+                        return Some(Self::Button(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Canvas>()
+                    {
+                        return Some(Self::Canvas(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Cite>() {
+                        return Some(Self::Cite(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Code>() {
+                        return Some(Self::Code(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Data>() {
+                        return Some(Self::Data(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DataList>()
+                    {
+                        return Some(Self::DataList(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Definition>()
+                    {
+                        return Some(Self::Definition(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DeletedText>()
+                    {
+                        return Some(Self::DeletedText(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Embed>() {
+                        return Some(Self::Embed(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Emphasis>()
+                    {
+                        return Some(Self::Emphasis(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Iframe>()
+                    {
+                        return Some(Self::Iframe(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Image>() {
+                        return Some(Self::Image(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ImageMap>()
+                    {
+                        return Some(Self::ImageMap(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ImageMapArea>()
+                    {
+                        return Some(Self::ImageMapArea(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Input>() {
+                        return Some(Self::Input(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::InsertedText>()
+                    {
+                        return Some(Self::InsertedText(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Italic>()
+                    {
+                        return Some(Self::Italic(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::KeyboardInput>()
+                    {
+                        return Some(Self::KeyboardInput(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Label>() {
+                        return Some(Self::Label(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::LineBreak>()
+                    {
+                        return Some(Self::LineBreak(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::LineBreakOpportunity>()
+                    {
+                        return Some(Self::LineBreakOpportunity(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Link>() {
+                        return Some(Self::Link(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::MarkText>()
+                    {
+                        return Some(Self::MarkText(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Math>() {
+                        return Some(Self::Math(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Meta>() {
+                        return Some(Self::Meta(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Meter>() {
+                        return Some(Self::Meter(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::NoScript>()
+                    {
+                        return Some(Self::NoScript(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Object>()
+                    {
+                        return Some(Self::Object(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Output>()
+                    {
+                        return Some(Self::Output(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Picture>()
+                    {
+                        return Some(Self::Picture(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Progress>()
+                    {
+                        return Some(Self::Progress(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Quotation>()
+                    {
+                        return Some(Self::Quotation(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::RubyAnnotation>()
+                    {
+                        return Some(Self::RubyAnnotation(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SampleOutput>()
+                    {
+                        return Some(Self::SampleOutput(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Script>()
+                    {
+                        return Some(Self::Script(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Select>()
+                    {
+                        return Some(Self::Select(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SideComment>()
+                    {
+                        return Some(Self::SideComment(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Slot>() {
+                        return Some(Self::Slot(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Span>() {
+                        return Some(Self::Span(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::StrikeThrough>()
+                    {
+                        return Some(Self::StrikeThrough(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Strong>()
+                    {
+                        return Some(Self::Strong(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SubScript>()
+                    {
+                        return Some(Self::SubScript(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SuperScript>()
+                    {
+                        return Some(Self::SuperScript(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Svg>() {
+                        return Some(Self::Svg(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Template>()
+                    {
+                        return Some(Self::Template(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TextArea>()
+                    {
+                        return Some(Self::TextArea(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Time>() {
+                        return Some(Self::Time(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Underline>()
+                    {
+                        return Some(Self::Underline(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Variable>()
+                    {
+                        return Some(Self::Variable(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Video>() {
+                        return Some(Self::Video(el.clone()));
+                    }
+                    None
+                }
+                crate::visit::NodeRef::Text(text) => {
+                    Some(Self::Text(text.to_owned().into()))
+                }
+                crate::visit::NodeRef::Comment(comment) => {
+                    Some(Self::Comment(comment.clone()))
+                }
+                crate::visit::NodeRef::RawHtml(html) => Some(Self::RawHtml(html.clone())),
+            }
+        }
+    }
+    impl<'a> From<&'a KeyboardInputChild> for crate::visit::NodeRef<'a> {
+        fn from(child: &'a KeyboardInputChild) -> Self {
+            match child {
+                KeyboardInputChild::Abbreviation(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Anchor(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Audio(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::BidirectionalIsolate(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::BidirectionalTextOverride(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Bold(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Button(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Canvas(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Cite(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Code(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Data(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::DataList(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Definition(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::DeletedText(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Embed(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Emphasis(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Iframe(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Image(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::ImageMap(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::ImageMapArea(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Input(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::InsertedText(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Italic(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::KeyboardInput(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Label(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::LineBreak(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::LineBreakOpportunity(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Link(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::MarkText(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Math(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Meta(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Meter(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::NoScript(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Object(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Output(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Picture(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Progress(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Quotation(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::RubyAnnotation(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::SampleOutput(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Script(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Select(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::SideComment(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Slot(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Span(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::StrikeThrough(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                KeyboardInputChild::Strong(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::SubScript(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::SuperScript(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Svg(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Template(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Text(el) => crate::visit::NodeRef::Text(el),
+                KeyboardInputChild::TextArea(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Time(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Underline(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Variable(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Video(el) => crate::visit::NodeRef::Element(el),
+                KeyboardInputChild::Comment(el) => crate::visit::NodeRef::Comment(el),
+                KeyboardInputChild::RawHtml(el) => crate::visit::NodeRef::RawHtml(el),
+            }
+        }
+    }
+    impl<'a> From<&'a mut KeyboardInputChild> for crate::visit::NodeMut<'a> {
+        fn from(child: &'a mut KeyboardInputChild) -> Self {
+            match child {
+                KeyboardInputChild::Abbreviation(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Anchor(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Audio(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::BidirectionalIsolate(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::BidirectionalTextOverride(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Bold(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Button(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Canvas(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Cite(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Code(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Data(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::DataList(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Definition(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::DeletedText(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Embed(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Emphasis(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Iframe(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Image(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::ImageMap(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::ImageMapArea(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Input(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::InsertedText(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Italic(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::KeyboardInput(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Label(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::LineBreak(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::LineBreakOpportunity(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Link(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::MarkText(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Math(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Meta(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Meter(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::NoScript(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Object(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Output(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Picture(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Progress(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Quotation(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::RubyAnnotation(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::SampleOutput(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Script(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Select(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::SideComment(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Slot(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Span(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::StrikeThrough(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                KeyboardInputChild::Strong(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::SubScript(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::SuperScript(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Svg(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Template(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Text(el) => crate::visit::NodeMut::Text(el),
+                KeyboardInputChild::TextArea(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Time(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Underline(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Variable(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Video(el) => crate::visit::NodeMut::Element(el),
+                KeyboardInputChild::Comment(el) => crate::visit::NodeMut::Comment(el),
+                KeyboardInputChild::RawHtml(el) => crate::visit::NodeMut::RawHtml(el),
+            }
+        }
+    }
+    impl std::convert::TryFrom<crate::AnyElement> for KeyboardInputChild {
+        type Error = crate::NotPermitted;
+        fn try_from(element: crate::AnyElement) -> Result<Self, Self::Error> {
+            match element {
+                crate::AnyElement::Abbreviation(el) => Ok(Self::Abbreviation(el)),
+                crate::AnyElement::Anchor(el) => Ok(Self::Anchor(el)),
+                crate::AnyElement::Audio(el) => Ok(Self::Audio(el)),
+                crate::AnyElement::BidirectionalIsolate(el) => {
+                    Ok(Self::BidirectionalIsolate(el))
+                }
+                crate::AnyElement::BidirectionalTextOverride(el) => {
+                    Ok(Self::BidirectionalTextOverride(el))
+                }
+                crate::AnyElement::Bold(el) => Ok(Self::Bold(el)),
+                crate::AnyElement::Button(el) => Ok(Self::Button(el)),
+                crate::AnyElement::Canvas(el) => Ok(Self::Canvas(el)),
+                crate::AnyElement::Cite(el) => Ok(Self::Cite(el)),
+                crate::AnyElement::Code(el) => Ok(Self::Code(el)),
+                crate::AnyElement::Data(el) => Ok(Self::Data(el)),
+                crate::AnyElement::DataList(el) => Ok(Self::DataList(el)),
+                crate::AnyElement::Definition(el) => Ok(Self::Definition(el)),
+                crate::AnyElement::DeletedText(el) => Ok(Self::DeletedText(el)),
+                crate::AnyElement::Embed(el) => Ok(Self::Embed(el)),
+                crate::AnyElement::Emphasis(el) => Ok(Self::Emphasis(el)),
+                crate::AnyElement::Iframe(el) => Ok(Self::Iframe(el)),
+                crate::AnyElement::Image(el) => Ok(Self::Image(el)),
+                crate::AnyElement::ImageMap(el) => Ok(Self::ImageMap(el)),
+                crate::AnyElement::ImageMapArea(el) => Ok(Self::ImageMapArea(el)),
+                crate::AnyElement::Input(el) => Ok(Self::Input(el)),
+                crate::AnyElement::InsertedText(el) => Ok(Self::InsertedText(el)),
+                crate::AnyElement::Italic(el) => Ok(Self::Italic(el)),
+                crate::AnyElement::KeyboardInput(el) => Ok(Self::KeyboardInput(el)),
+                crate::AnyElement::Label(el) => Ok(Self::Label(el)),
+                crate::AnyElement::LineBreak(el) => Ok(Self::LineBreak(el)),
+                crate::AnyElement::LineBreakOpportunity(el) => {
+                    Ok(Self::LineBreakOpportunity(el))
+                }
+                crate::AnyElement::Link(el) => Ok(Self::Link(el)),
+                crate::AnyElement::MarkText(el) => Ok(Self::MarkText(el)),
+                crate::AnyElement::Math(el) => Ok(Self::Math(el)),
+                crate::AnyElement::Meta(el) => Ok(Self::Meta(el)),
+                crate::AnyElement::Meter(el) => Ok(Self::Meter(el)),
+                crate::AnyElement::NoScript(el) => Ok(Self::NoScript(el)),
+                crate::AnyElement::Object(el) => Ok(Self::Object(el)),
+                crate::AnyElement::Output(el) => Ok(Self::Output(el)),
+                crate::AnyElement::Picture(el) => Ok(Self::Picture(el)),
+                crate::AnyElement::Progress(el) => Ok(Self::Progress(el)),
+                crate::AnyElement::Quotation(el) => Ok(Self::Quotation(el)),
+                crate::AnyElement::RubyAnnotation(el) => Ok(Self::RubyAnnotation(el)),
+                crate::AnyElement::SampleOutput(el) => Ok(Self::SampleOutput(el)),
+                crate::AnyElement::Script(el) => Ok(Self::Script(el)),
+                crate::AnyElement::Select(el) => Ok(Self::Select(el)),
+                crate::AnyElement::SideComment(el) => Ok(Self::SideComment(el)),
+                crate::AnyElement::Slot(el) => Ok(Self::Slot(el)),
+                crate::AnyElement::Span(el) => Ok(Self::Span(el)),
+                crate::AnyElement::StrikeThrough(el) => Ok(Self::StrikeThrough(el)),
+                crate::AnyElement::Strong(el) => Ok(Self::Strong(el)),
+                crate::AnyElement::SubScript(el) => Ok(Self::SubScript(el)),
+                crate::AnyElement::SuperScript(el) => Ok(Self::SuperScript(el)),
+                crate::AnyElement::Svg(el) => Ok(Self::Svg(el)),
+                crate::AnyElement::Template(el) => Ok(Self::Template(el)),
+                crate::AnyElement::TextArea(el) => Ok(Self::TextArea(el)),
+                crate::AnyElement::Time(el) => Ok(Self::Time(el)),
+                crate::AnyElement::Underline(el) => Ok(Self::Underline(el)),
+                crate::AnyElement::Variable(el) => Ok(Self::Variable(el)),
+                crate::AnyElement::Video(el) => Ok(Self::Video(el)),
+                #[allow(unreachable_patterns)]
+                element => Err(crate::NotPermitted::new(element, "kbd")),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for KeyboardInputChild {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Abbreviation(el) => serde::Serialize::serialize(el, serializer),
+                Self::Anchor(el) => serde::Serialize::serialize(el, serializer),
+                Self::Audio(el) => serde::Serialize::serialize(el, serializer),
+                Self::BidirectionalIsolate(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::BidirectionalTextOverride(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::Bold(el) => serde::Serialize::serialize(el, serializer),
+                Self::Button(el) => serde::Serialize::serialize(el, serializer),
+                Self::Canvas(el) => serde::Serialize::serialize(el, serializer),
+                Self::Cite(el) => serde::Serialize::serialize(el, serializer),
+                Self::Code(el) => serde::Serialize::serialize(el, serializer),
+                Self::Data(el) => serde::Serialize::serialize(el, serializer),
+                Self::DataList(el) => serde::Serialize::serialize(el, serializer),
+                Self::Definition(el) => serde::Serialize::serialize(el, serializer),
+                Self::DeletedText(el) => serde::Serialize::serialize(el, serializer),
+                Self::Embed(el) => serde::Serialize::serialize(el, serializer),
+                Self::Emphasis(el) => serde::Serialize::serialize(el, serializer),
+                Self::Iframe(el) => serde::Serialize::serialize(el, serializer),
+                Self::Image(el) => serde::Serialize::serialize(el, serializer),
+                Self::ImageMap(el) => serde::Serialize::serialize(el, serializer),
+                Self::ImageMapArea(el) => serde::Serialize::serialize(el, serializer),
+                Self::Input(el) => serde::Serialize::serialize(el, serializer),
+                Self::InsertedText(el) => serde::Serialize::serialize(el, serializer),
+                Self::Italic(el) => serde::Serialize::serialize(el, serializer),
+                Self::KeyboardInput(el) => serde::Serialize::serialize(el, serializer),
+                Self::Label(el) => serde::Serialize::serialize(el, serializer),
+                Self::LineBreak(el) => serde::Serialize::serialize(el, serializer),
+                Self::LineBreakOpportunity(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::Link(el) => serde::Serialize::serialize(el, serializer),
+                Self::MarkText(el) => serde::Serialize::serialize(el, serializer),
+                Self::Math(el) => serde::Serialize::serialize(el, serializer),
+                Self::Meta(el) => serde::Serialize::serialize(el, serializer),
+                Self::Meter(el) => serde::Serialize::serialize(el, serializer),
+                Self::NoScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::Object(el) => serde::Serialize::serialize(el, serializer),
+                Self::Output(el) => serde::Serialize::serialize(el, serializer),
+                Self::Picture(el) => serde::Serialize::serialize(el, serializer),
+                Self::Progress(el) => serde::Serialize::serialize(el, serializer),
+                Self::Quotation(el) => serde::Serialize::serialize(el, serializer),
+                Self::RubyAnnotation(el) => serde::Serialize::serialize(el, serializer),
+                Self::SampleOutput(el) => serde::Serialize::serialize(el, serializer),
+                Self::Script(el) => serde::Serialize::serialize(el, serializer),
+                Self::Select(el) => serde::Serialize::serialize(el, serializer),
+                Self::SideComment(el) => serde::Serialize::serialize(el, serializer),
+                Self::Slot(el) => serde::Serialize::serialize(el, serializer),
+                Self::Span(el) => serde::Serialize::serialize(el, serializer),
+                Self::StrikeThrough(el) => serde::Serialize::serialize(el, serializer),
+                Self::Strong(el) => serde::Serialize::serialize(el, serializer),
+                Self::SubScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::SuperScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::Svg(el) => serde::Serialize::serialize(el, serializer),
+                Self::Template(el) => serde::Serialize::serialize(el, serializer),
+                Self::Text(el) => serde::Serialize::serialize(el, serializer),
+                Self::TextArea(el) => serde::Serialize::serialize(el, serializer),
+                Self::Time(el) => serde::Serialize::serialize(el, serializer),
+                Self::Underline(el) => serde::Serialize::serialize(el, serializer),
+                Self::Variable(el) => serde::Serialize::serialize(el, serializer),
+                Self::Video(el) => serde::Serialize::serialize(el, serializer),
+                Self::Comment(el) => serde::Serialize::serialize(el, serializer),
+                Self::RawHtml(el) => serde::Serialize::serialize(el, serializer),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for KeyboardInputChild {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_child(deserializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeChild for KeyboardInputChild {
+        const PARENT_TAG_NAME: &'static str = "kbd";
+        fn from_text(text: String) -> Option<Self> {
+            Some(Self::Text(text.into()))
+        }
+        fn from_comment(comment: crate::Comment) -> Option<Self> {
+            Some(Self::Comment(comment))
+        }
+        fn from_raw_html(html: crate::RawHtml) -> Self {
+            Self::RawHtml(html)
+        }
+        fn deserialize_element<'de, M: serde::de::MapAccess<'de>>(
+            tag: &str,
+            map: M,
+        ) -> Result<Self, M::Error> {
+            match tag {
+                "abbr" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::Abbreviation)
+                }
+                "a" => crate::serialize::deserialize_element_body(map).map(Self::Anchor),
+                "audio" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Audio)
+                }
+                "bdi" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::BidirectionalIsolate)
+                }
+                "bdo" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::BidirectionalTextOverride)
+                }
+                "b" => crate::serialize::deserialize_element_body(map).map(Self::Bold),
+                "button" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Button)
+                }
+                "canvas" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Canvas)
+                }
+                "cite" => crate::serialize::deserialize_element_body(map).map(Self::Cite),
+                "code" => crate::serialize::deserialize_element_body(map).map(Self::Code),
+                "data" => crate::serialize::deserialize_element_body(map).map(Self::Data),
+                "datalist" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::DataList)
+                }
+                "dfn" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Definition)
+                }
+                "del" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::DeletedText)
+                }
+                "embed" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Embed)
+                }
+                "em" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Emphasis)
+                }
+                "iframe" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Iframe)
+                }
+                "img" => crate::serialize::deserialize_element_body(map).map(Self::Image),
+                "map" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::ImageMap)
+                }
+                "area" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::ImageMapArea)
+                }
+                "input" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Input)
+                }
+                "ins" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::InsertedText)
+                }
+                "i" => crate::serialize::deserialize_element_body(map).map(Self::Italic),
+                "kbd" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::KeyboardInput)
+                }
+                "label" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Label)
+                }
+                "br" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::LineBreak)
+                }
+                "wbr" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::LineBreakOpportunity)
+                }
+                "link" => crate::serialize::deserialize_element_body(map).map(Self::Link),
+                "mark" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::MarkText)
+                }
+                "math" => crate::serialize::deserialize_element_body(map).map(Self::Math),
+                "meta" => crate::serialize::deserialize_element_body(map).map(Self::Meta),
+                "meter" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Meter)
+                }
+                "noscript" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::NoScript)
+                }
+                "object" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Object)
+                }
+                "output" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Output)
+                }
+                "picture" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Picture)
+                }
+                "progress" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Progress)
+                }
+                "q" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Quotation)
+                }
+                "ruby" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::RubyAnnotation)
+                }
+                "samp" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::SampleOutput)
+                }
+                "script" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Script)
+                }
+                "select" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Select)
+                }
+                "small" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::SideComment)
+                }
+                "slot" => crate::serialize::deserialize_element_body(map).map(Self::Slot),
+                "span" => crate::serialize::deserialize_element_body(map).map(Self::Span),
+                "s" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::StrikeThrough)
+                }
+                "strong" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Strong)
+                }
+                "sub" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::SubScript)
+                }
+                "sup" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::SuperScript)
+                }
+                "svg" => crate::serialize::deserialize_element_body(map).map(Self::Svg),
+                "template" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Template)
+                }
+                "textarea" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TextArea)
+                }
+                "time" => crate::serialize::deserialize_element_body(map).map(Self::Time),
+                "u" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Underline)
+                }
+                "var" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Variable)
+                }
+                "video" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Video)
+                }
+                tag => {
+                    Err(
+                        crate::serialize::child_not_permitted(Self::PARENT_TAG_NAME, tag),
+                    )
+                }
+            }
+        }
+    }
+}
+pub mod builder {
+    /// A builder struct for KeyboardInput
+    pub struct KeyboardInputBuilder {
+        element: super::element::KeyboardInput,
+    }
+    impl KeyboardInputBuilder {
+        pub(crate) fn new(element: super::element::KeyboardInput) -> Self {
+            Self { element }
+        }
+        /// Finish building the element
+        pub fn build(&mut self) -> super::element::KeyboardInput {
+            self.element.clone()
+        }
+        /// Insert a `data-*` property
+        pub fn data(
+            &mut self,
+            data_key: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut KeyboardInputBuilder {
+            self.element.data_map_mut().insert(data_key.into(), value.into());
+            self
+        }
+        /// Append a new `Abbreviation` element
+        pub fn abbreviation<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AbbreviationBuilder,
+            ) -> &'a mut crate::generated::all::builders::AbbreviationBuilder,
+        {
+            let ty: crate::generated::all::Abbreviation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AbbreviationBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Anchor` element
+        pub fn anchor<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AnchorBuilder,
+            ) -> &'a mut crate::generated::all::builders::AnchorBuilder,
+        {
+            let ty: crate::generated::all::Anchor = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AnchorBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Audio` element
+        pub fn audio<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AudioBuilder,
+            ) -> &'a mut crate::generated::all::builders::AudioBuilder,
+        {
+            let ty: crate::generated::all::Audio = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AudioBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `BidirectionalIsolate` element
+        pub fn bidirectional_isolate<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
+            ) -> &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
+        {
+            let ty: crate::generated::all::BidirectionalIsolate = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BidirectionalIsolateBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `BidirectionalTextOverride` element
+        pub fn bidirectional_text_override<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
+            ) -> &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
+        {
+            let ty: crate::generated::all::BidirectionalTextOverride = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BidirectionalTextOverrideBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Bold` element
+        pub fn bold<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BoldBuilder,
+            ) -> &'a mut crate::generated::all::builders::BoldBuilder,
+        {
+            let ty: crate::generated::all::Bold = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BoldBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Button` element
+        pub fn button<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ButtonBuilder,
+            ) -> &'a mut crate::generated::all::builders::ButtonBuilder,
+        {
+            let ty: crate::generated::all::Button = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ButtonBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Canvas` element
+        pub fn canvas<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CanvasBuilder,
+            ) -> &'a mut crate::generated::all::builders::CanvasBuilder,
+        {
+            let ty: crate::generated::all::Canvas = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CanvasBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Cite` element
+        pub fn cite<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CiteBuilder,
+            ) -> &'a mut crate::generated::all::builders::CiteBuilder,
+        {
+            let ty: crate::generated::all::Cite = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CiteBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Code` element
+        pub fn code<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CodeBuilder,
+            ) -> &'a mut crate::generated::all::builders::CodeBuilder,
+        {
+            let ty: crate::generated::all::Code = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CodeBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Data` element
+        pub fn data_el<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DataBuilder,
+            ) -> &'a mut crate::generated::all::builders::DataBuilder,
+        {
+            let ty: crate::generated::all::Data = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DataBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DataList` element
+        pub fn data_list<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DataListBuilder,
+            ) -> &'a mut crate::generated::all::builders::DataListBuilder,
+        {
+            let ty: crate::generated::all::DataList = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DataListBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Definition` element
+        pub fn definition<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DefinitionBuilder,
+            ) -> &'a mut crate::generated::all::builders::DefinitionBuilder,
+        {
+            let ty: crate::generated::all::Definition = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DefinitionBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DeletedText` element
+        pub fn deleted_text<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DeletedTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::DeletedTextBuilder,
+        {
+            let ty: crate::generated::all::DeletedText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DeletedTextBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Embed` element
+        pub fn embed<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::EmbedBuilder,
+            ) -> &'a mut crate::generated::all::builders::EmbedBuilder,
+        {
+            let ty: crate::generated::all::Embed = Default::default();
+            let mut ty_builder = crate::generated::all::builders::EmbedBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Emphasis` element
+        pub fn emphasis<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::EmphasisBuilder,
+            ) -> &'a mut crate::generated::all::builders::EmphasisBuilder,
+        {
+            let ty: crate::generated::all::Emphasis = Default::default();
+            let mut ty_builder = crate::generated::all::builders::EmphasisBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Iframe` element
+        pub fn iframe<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::IframeBuilder,
+            ) -> &'a mut crate::generated::all::builders::IframeBuilder,
+        {
+            let ty: crate::generated::all::Iframe = Default::default();
+            let mut ty_builder = crate::generated::all::builders::IframeBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Image` element
+        pub fn image<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ImageBuilder,
+            ) -> &'a mut crate::generated::all::builders::ImageBuilder,
+        {
+            let ty: crate::generated::all::Image = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ImageBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `ImageMap` element
+        pub fn image_map<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ImageMapBuilder,
+            ) -> &'a mut crate::generated::all::builders::ImageMapBuilder,
+        {
+            let ty: crate::generated::all::ImageMap = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ImageMapBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `ImageMapArea` element
+        pub fn image_map_area<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
+            ) -> &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
+        {
+            let ty: crate::generated::all::ImageMapArea = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ImageMapAreaBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Input` element
+        pub fn input<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::InputBuilder,
+            ) -> &'a mut crate::generated::all::builders::InputBuilder,
+        {
+            let ty: crate::generated::all::Input = Default::default();
+            let mut ty_builder = crate::generated::all::builders::InputBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `InsertedText` element
+        pub fn inserted_text<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::InsertedTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::InsertedTextBuilder,
+        {
+            let ty: crate::generated::all::InsertedText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::InsertedTextBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Italic` element
+        pub fn italic<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ItalicBuilder,
+            ) -> &'a mut crate::generated::all::builders::ItalicBuilder,
+        {
+            let ty: crate::generated::all::Italic = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ItalicBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `KeyboardInput` element
+        pub fn keyboard_input<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::KeyboardInputBuilder,
+            ) -> &'a mut crate::generated::all::builders::KeyboardInputBuilder,
+        {
+            let ty: crate::generated::all::KeyboardInput = Default::default();
+            let mut ty_builder = crate::generated::all::builders::KeyboardInputBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Label` element
+        pub fn label<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::LabelBuilder,
+            ) -> &'a mut crate::generated::all::builders::LabelBuilder,
+        {
+            let ty: crate::generated::all::Label = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LabelBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `LineBreak` element
+        pub fn line_break<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::LineBreakBuilder,
+            ) -> &'a mut crate::generated::all::builders::LineBreakBuilder,
+        {
+            let ty: crate::generated::all::LineBreak = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LineBreakBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `LineBreakOpportunity` element
+        pub fn line_break_opportunity<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
+            ) -> &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
+        {
+            let ty: crate::generated::all::LineBreakOpportunity = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LineBreakOpportunityBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Link` element
+        pub fn link<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::LinkBuilder,
+            ) -> &'a mut crate::generated::all::builders::LinkBuilder,
+        {
+            let ty: crate::generated::all::Link = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LinkBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `MarkText` element
+        pub fn mark_text<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MarkTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::MarkTextBuilder,
+        {
+            let ty: crate::generated::all::MarkText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MarkTextBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Math` element
+        pub fn math<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MathBuilder,
+            ) -> &'a mut crate::generated::all::builders::MathBuilder,
+        {
+            let ty: crate::generated::all::Math = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MathBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Meta` element
+        pub fn meta<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MetaBuilder,
+            ) -> &'a mut crate::generated::all::builders::MetaBuilder,
+        {
+            let ty: crate::generated::all::Meta = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MetaBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Meter` element
+        pub fn meter<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MeterBuilder,
+            ) -> &'a mut crate::generated::all::builders::MeterBuilder,
+        {
+            let ty: crate::generated::all::Meter = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MeterBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `NoScript` element
+        pub fn no_script<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::NoScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::NoScriptBuilder,
+        {
+            let ty: crate::generated::all::NoScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::NoScriptBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
         }
-    }
-}
-pub mod builder {
-    /// A builder struct for KeyboardInput
-    pub struct KeyboardInputBuilder {
-        element: super::element::KeyboardInput,
-    }
-    impl KeyboardInputBuilder {
-        pub(crate) fn new(element: super::element::KeyboardInput) -> Self {
-            Self { element }
+        /// Append a new `Object` element
+        pub fn object<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ObjectBuilder,
+            ) -> &'a mut crate::generated::all::builders::ObjectBuilder,
+        {
+            let ty: crate::generated::all::Object = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ObjectBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
         }
-        /// Finish building the element
-        pub fn build(&mut self) -> super::element::KeyboardInput {
-            self.element.clone()
+        /// Append a new `Output` element
+        pub fn output<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::OutputBuilder,
+            ) -> &'a mut crate::generated::all::builders::OutputBuilder,
+        {
+            let ty: crate::generated::all::Output = Default::default();
+            let mut ty_builder = crate::generated::all::builders::OutputBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
         }
-        /// Insert a `data-*` property
-        pub fn data(
-            &mut self,
-            data_key: impl Into<std::borrow::Cow<'static, str>>,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut KeyboardInputBuilder {
-            self.element.data_map_mut().insert(data_key.into(), value.into());
+        /// Append a new `Picture` element
+        pub fn picture<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::PictureBuilder,
+            ) -> &'a mut crate::generated::all::builders::PictureBuilder,
+        {
+            let ty: crate::generated::all::Picture = Default::default();
+            let mut ty_builder = crate::generated::all::builders::PictureBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Abbreviation` element
-        pub fn abbreviation<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Progress` element
+        pub fn progress<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AbbreviationBuilder,
-            ) -> &'a mut crate::generated::all::builders::AbbreviationBuilder,
+                &'a mut crate::generated::all::builders::ProgressBuilder,
+            ) -> &'a mut crate::generated::all::builders::ProgressBuilder,
         {
-            let ty: crate::generated::all::Abbreviation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AbbreviationBuilder::new(
+            let ty: crate::generated::all::Progress = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ProgressBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1542,43 +4603,47 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Anchor` element
-        pub fn anchor<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Quotation` element
+        pub fn quotation<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AnchorBuilder,
-            ) -> &'a mut crate::generated::all::builders::AnchorBuilder,
+                &'a mut crate::generated::all::builders::QuotationBuilder,
+            ) -> &'a mut crate::generated::all::builders::QuotationBuilder,
         {
-            let ty: crate::generated::all::Anchor = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AnchorBuilder::new(ty);
+            let ty: crate::generated::all::Quotation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::QuotationBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Audio` element
-        pub fn audio<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `RubyAnnotation` element
+        pub fn ruby_annotation<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AudioBuilder,
-            ) -> &'a mut crate::generated::all::builders::AudioBuilder,
+                &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
+            ) -> &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
         {
-            let ty: crate::generated::all::Audio = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AudioBuilder::new(ty);
+            let ty: crate::generated::all::RubyAnnotation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::RubyAnnotationBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `BidirectionalIsolate` element
-        pub fn bidirectional_isolate<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `SampleOutput` element
+        pub fn sample_output<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
-            ) -> &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
+                &'a mut crate::generated::all::builders::SampleOutputBuilder,
+            ) -> &'a mut crate::generated::all::builders::SampleOutputBuilder,
         {
-            let ty: crate::generated::all::BidirectionalIsolate = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BidirectionalIsolateBuilder::new(
+            let ty: crate::generated::all::SampleOutput = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SampleOutputBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1586,15 +4651,43 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `BidirectionalTextOverride` element
-        pub fn bidirectional_text_override<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Script` element
+        pub fn script<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
-            ) -> &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
+                &'a mut crate::generated::all::builders::ScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::ScriptBuilder,
         {
-            let ty: crate::generated::all::BidirectionalTextOverride = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BidirectionalTextOverrideBuilder::new(
+            let ty: crate::generated::all::Script = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ScriptBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Select` element
+        pub fn select<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SelectBuilder,
+            ) -> &'a mut crate::generated::all::builders::SelectBuilder,
+        {
+            let ty: crate::generated::all::Select = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SelectBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `SideComment` element
+        pub fn side_comment<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SideCommentBuilder,
+            ) -> &'a mut crate::generated::all::builders::SideCommentBuilder,
+        {
+            let ty: crate::generated::all::SideComment = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SideCommentBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1602,99 +4695,174 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Bold` element
-        pub fn bold<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Slot` element
+        pub fn slot<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BoldBuilder,
-            ) -> &'a mut crate::generated::all::builders::BoldBuilder,
+                &'a mut crate::generated::all::builders::SlotBuilder,
+            ) -> &'a mut crate::generated::all::builders::SlotBuilder,
         {
-            let ty: crate::generated::all::Bold = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BoldBuilder::new(ty);
+            let ty: crate::generated::all::Slot = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SlotBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Button` element
-        pub fn button<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Span` element
+        pub fn span<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ButtonBuilder,
-            ) -> &'a mut crate::generated::all::builders::ButtonBuilder,
+                &'a mut crate::generated::all::builders::SpanBuilder,
+            ) -> &'a mut crate::generated::all::builders::SpanBuilder,
         {
-            let ty: crate::generated::all::Button = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ButtonBuilder::new(ty);
+            let ty: crate::generated::all::Span = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SpanBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Canvas` element
-        pub fn canvas<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `StrikeThrough` element
+        pub fn strike_through<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CanvasBuilder,
-            ) -> &'a mut crate::generated::all::builders::CanvasBuilder,
+                &'a mut crate::generated::all::builders::StrikeThroughBuilder,
+            ) -> &'a mut crate::generated::all::builders::StrikeThroughBuilder,
         {
-            let ty: crate::generated::all::Canvas = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CanvasBuilder::new(ty);
+            let ty: crate::generated::all::StrikeThrough = Default::default();
+            let mut ty_builder = crate::generated::all::builders::StrikeThroughBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Cite` element
-        pub fn cite<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Strong` element
+        pub fn strong<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::StrongBuilder,
+            ) -> &'a mut crate::generated::all::builders::StrongBuilder,
+        {
+            let ty: crate::generated::all::Strong = Default::default();
+            let mut ty_builder = crate::generated::all::builders::StrongBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `SubScript` element
+        pub fn sub_script<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SubScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::SubScriptBuilder,
+        {
+            let ty: crate::generated::all::SubScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SubScriptBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `SuperScript` element
+        pub fn super_script<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SuperScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::SuperScriptBuilder,
+        {
+            let ty: crate::generated::all::SuperScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SuperScriptBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Svg` element
+        pub fn svg<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SvgBuilder,
+            ) -> &'a mut crate::generated::all::builders::SvgBuilder,
+        {
+            let ty: crate::generated::all::Svg = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SvgBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Template` element
+        pub fn template<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CiteBuilder,
-            ) -> &'a mut crate::generated::all::builders::CiteBuilder,
+                &'a mut crate::generated::all::builders::TemplateBuilder,
+            ) -> &'a mut crate::generated::all::builders::TemplateBuilder,
         {
-            let ty: crate::generated::all::Cite = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CiteBuilder::new(ty);
+            let ty: crate::generated::all::Template = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TemplateBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Code` element
-        pub fn code<F>(&mut self, f: F) -> &mut Self
+        /// Append a new text element.
+        pub fn text(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            let cow = s.into();
+            self.element.children_mut().push(cow.into());
+            self
+        }
+        /// Append a new `TextArea` element
+        pub fn text_area<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CodeBuilder,
-            ) -> &'a mut crate::generated::all::builders::CodeBuilder,
+                &'a mut crate::generated::all::builders::TextAreaBuilder,
+            ) -> &'a mut crate::generated::all::builders::TextAreaBuilder,
         {
-            let ty: crate::generated::all::Code = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CodeBuilder::new(ty);
+            let ty: crate::generated::all::TextArea = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TextAreaBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Data` element
-        pub fn data_el<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Time` element
+        pub fn time<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DataBuilder,
-            ) -> &'a mut crate::generated::all::builders::DataBuilder,
+                &'a mut crate::generated::all::builders::TimeBuilder,
+            ) -> &'a mut crate::generated::all::builders::TimeBuilder,
         {
-            let ty: crate::generated::all::Data = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DataBuilder::new(ty);
+            let ty: crate::generated::all::Time = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TimeBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DataList` element
-        pub fn data_list<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Underline` element
+        pub fn underline<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DataListBuilder,
-            ) -> &'a mut crate::generated::all::builders::DataListBuilder,
+                &'a mut crate::generated::all::builders::UnderlineBuilder,
+            ) -> &'a mut crate::generated::all::builders::UnderlineBuilder,
         {
-            let ty: crate::generated::all::DataList = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DataListBuilder::new(
+            let ty: crate::generated::all::Underline = Default::default();
+            let mut ty_builder = crate::generated::all::builders::UnderlineBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1702,15 +4870,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Definition` element
-        pub fn definition<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Variable` element
+        pub fn variable<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DefinitionBuilder,
-            ) -> &'a mut crate::generated::all::builders::DefinitionBuilder,
+                &'a mut crate::generated::all::builders::VariableBuilder,
+            ) -> &'a mut crate::generated::all::builders::VariableBuilder,
         {
-            let ty: crate::generated::all::Definition = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DefinitionBuilder::new(
+            let ty: crate::generated::all::Variable = Default::default();
+            let mut ty_builder = crate::generated::all::builders::VariableBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1718,1178 +4886,1188 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DeletedText` element
-        pub fn deleted_text<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Video` element
+        pub fn video<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DeletedTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::DeletedTextBuilder,
+                &'a mut crate::generated::all::builders::VideoBuilder,
+            ) -> &'a mut crate::generated::all::builders::VideoBuilder,
         {
-            let ty: crate::generated::all::DeletedText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DeletedTextBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Video = Default::default();
+            let mut ty_builder = crate::generated::all::builders::VideoBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Embed` element
-        pub fn embed<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::EmbedBuilder,
-            ) -> &'a mut crate::generated::all::builders::EmbedBuilder,
-        {
-            let ty: crate::generated::all::Embed = Default::default();
-            let mut ty_builder = crate::generated::all::builders::EmbedBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `role` attribute
+        pub fn role(&mut self, value: html_sys::attributes::Role) -> &mut Self {
+            self.element.set_role(Some(value));
+            self
+        }
+        /// Set the value of the `aria-activedescendant` attribute
+        pub fn aria_active_descendant_element(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_active_descendant_element(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-atomic` attribute
+        pub fn aria_atomic(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_atomic(value);
+            self
+        }
+        /// Set the value of the `aria-autocomplete` attribute
+        pub fn aria_auto_complete(
+            &mut self,
+            value: html_sys::attributes::AriaAutoComplete,
+        ) -> &mut Self {
+            self.element.set_aria_auto_complete(Some(value));
+            self
+        }
+        /// Set the value of the `aria-brailleroledescription` attribute
+        pub fn aria_braille_role_description(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_braille_role_description(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-busy` attribute
+        pub fn aria_busy(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_busy(value);
+            self
+        }
+        /// Set the value of the `aria-checked` attribute
+        pub fn aria_checked(
+            &mut self,
+            value: html_sys::attributes::Tristate,
+        ) -> &mut Self {
+            self.element.set_aria_checked(Some(value));
+            self
+        }
+        /// Set the value of the `aria-colcount` attribute
+        pub fn aria_col_count(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_col_count(Some(value));
+            self
+        }
+        /// Set the value of the `aria-colindex` attribute
+        pub fn aria_col_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_col_index(Some(value));
+            self
+        }
+        /// Set the value of the `aria-colindextext` attribute
+        pub fn aria_col_index_text(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_col_index_text(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-colspan` attribute
+        pub fn aria_col_span(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_col_span(Some(value));
+            self
+        }
+        /// Set the value of the `aria-controls` attribute
+        pub fn aria_controls_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_controls_elements(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-current` attribute
+        pub fn aria_current(
+            &mut self,
+            value: html_sys::attributes::AriaCurrent,
+        ) -> &mut Self {
+            self.element.set_aria_current(Some(value));
+            self
+        }
+        /// Set the value of the `aria-describedby` attribute
+        pub fn aria_described_by_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_described_by_elements(
+                    value.into_iter().map(Into::into).collect(),
+                );
+            self
+        }
+        /// Set the value of the `aria-description` attribute
+        pub fn aria_description(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_description(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-details` attribute
+        pub fn aria_details_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_details_elements(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-disabled` attribute
+        pub fn aria_disabled(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_disabled(value);
+            self
+        }
+        /// Set the value of the `aria-dropeffect` attribute
+        pub fn aria_drop_effect(
+            &mut self,
+            value: impl IntoIterator<
+                Item = impl Into<html_sys::attributes::AriaDropEffect>,
+            >,
+        ) -> &mut Self {
+            self.element
+                .set_aria_drop_effect(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-errormessage` attribute
+        pub fn aria_error_message_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_error_message_elements(
+                    value.into_iter().map(Into::into).collect(),
+                );
+            self
+        }
+        /// Set the value of the `aria-expanded` attribute
+        pub fn aria_expanded(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_expanded(Some(value));
+            self
+        }
+        /// Set the value of the `aria-flowto` attribute
+        pub fn aria_flow_to_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_flow_to_elements(value.into_iter().map(Into::into).collect());
+            self
+        }
+        /// Set the value of the `aria-grabbed` attribute
+        pub fn aria_grabbed(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_grabbed(Some(value));
+            self
+        }
+        /// Set the value of the `aria-haspopup` attribute
+        pub fn aria_has_popup(
+            &mut self,
+            value: html_sys::attributes::AriaHasPopup,
+        ) -> &mut Self {
+            self.element.set_aria_has_popup(Some(value));
+            self
+        }
+        /// Set the value of the `aria-hidden` attribute
+        pub fn aria_hidden(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_hidden(Some(value));
+            self
+        }
+        /// Set the value of the `aria-invalid` attribute
+        pub fn aria_invalid(
+            &mut self,
+            value: html_sys::attributes::AriaInvalid,
+        ) -> &mut Self {
+            self.element.set_aria_invalid(Some(value));
+            self
+        }
+        /// Set the value of the `aria-keyshortcuts` attribute
+        pub fn aria_key_shortcuts(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_key_shortcuts(Some(value.into()));
+            self
+        }
+        /// Set the value of the `aria-level` attribute
+        pub fn aria_level(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_level(Some(value));
+            self
+        }
+        /// Set the value of the `aria-live` attribute
+        pub fn aria_live(&mut self, value: html_sys::attributes::AriaLive) -> &mut Self {
+            self.element.set_aria_live(Some(value));
+            self
+        }
+        /// Set the value of the `aria-modal` attribute
+        pub fn aria_modal(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_modal(value);
+            self
+        }
+        /// Set the value of the `aria-multiline` attribute
+        pub fn aria_multi_line(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_multi_line(value);
+            self
+        }
+        /// Set the value of the `aria-multiselectable` attribute
+        pub fn aria_multi_selectable(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_multi_selectable(value);
+            self
+        }
+        /// Set the value of the `aria-orientation` attribute
+        pub fn aria_orientation(
+            &mut self,
+            value: html_sys::attributes::AriaOrientation,
+        ) -> &mut Self {
+            self.element.set_aria_orientation(Some(value));
             self
         }
-        /// Append a new `Emphasis` element
-        pub fn emphasis<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::EmphasisBuilder,
-            ) -> &'a mut crate::generated::all::builders::EmphasisBuilder,
-        {
-            let ty: crate::generated::all::Emphasis = Default::default();
-            let mut ty_builder = crate::generated::all::builders::EmphasisBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-owns` attribute
+        pub fn aria_owns_elements(
+            &mut self,
+            value: impl IntoIterator<Item = impl Into<std::borrow::Cow<'static, str>>>,
+        ) -> &mut Self {
+            self.element
+                .set_aria_owns_elements(value.into_iter().map(Into::into).collect());
             self
         }
-        /// Append a new `Iframe` element
-        pub fn iframe<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::IframeBuilder,
-            ) -> &'a mut crate::generated::all::builders::IframeBuilder,
-        {
-            let ty: crate::generated::all::Iframe = Default::default();
-            let mut ty_builder = crate::generated::all::builders::IframeBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-placeholder` attribute
+        pub fn aria_placeholder(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_placeholder(Some(value.into()));
             self
         }
-        /// Append a new `Image` element
-        pub fn image<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ImageBuilder,
-            ) -> &'a mut crate::generated::all::builders::ImageBuilder,
-        {
-            let ty: crate::generated::all::Image = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ImageBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-posinset` attribute
+        pub fn aria_pos_in_set(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_pos_in_set(Some(value));
             self
         }
-        /// Append a new `ImageMap` element
-        pub fn image_map<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ImageMapBuilder,
-            ) -> &'a mut crate::generated::all::builders::ImageMapBuilder,
-        {
-            let ty: crate::generated::all::ImageMap = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ImageMapBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-pressed` attribute
+        pub fn aria_pressed(
+            &mut self,
+            value: html_sys::attributes::Tristate,
+        ) -> &mut Self {
+            self.element.set_aria_pressed(Some(value));
             self
         }
-        /// Append a new `ImageMapArea` element
-        pub fn image_map_area<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
-            ) -> &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
-        {
-            let ty: crate::generated::all::ImageMapArea = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ImageMapAreaBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-readonly` attribute
+        pub fn aria_read_only(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_read_only(value);
             self
         }
-        /// Append a new `Input` element
-        pub fn input<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::InputBuilder,
-            ) -> &'a mut crate::generated::all::builders::InputBuilder,
-        {
-            let ty: crate::generated::all::Input = Default::default();
-            let mut ty_builder = crate::generated::all::builders::InputBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-relevant` attribute
+        pub fn aria_relevant(
+            &mut self,
+            value: impl IntoIterator<
+                Item = impl Into<html_sys::attributes::AriaRelevant>,
+            >,
+        ) -> &mut Self {
+            self.element.set_aria_relevant(value.into_iter().map(Into::into).collect());
             self
         }
-        /// Append a new `InsertedText` element
-        pub fn inserted_text<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::InsertedTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::InsertedTextBuilder,
-        {
-            let ty: crate::generated::all::InsertedText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::InsertedTextBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-required` attribute
+        pub fn aria_required(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_required(value);
             self
         }
-        /// Append a new `Italic` element
-        pub fn italic<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ItalicBuilder,
-            ) -> &'a mut crate::generated::all::builders::ItalicBuilder,
-        {
-            let ty: crate::generated::all::Italic = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ItalicBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-roledescription` attribute
+        pub fn aria_role_description(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_role_description(Some(value.into()));
             self
         }
-        /// Append a new `KeyboardInput` element
-        pub fn keyboard_input<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::KeyboardInputBuilder,
-            ) -> &'a mut crate::generated::all::builders::KeyboardInputBuilder,
-        {
-            let ty: crate::generated::all::KeyboardInput = Default::default();
-            let mut ty_builder = crate::generated::all::builders::KeyboardInputBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-rowcount` attribute
+        pub fn aria_row_count(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_row_count(Some(value));
             self
         }
-        /// Append a new `Label` element
-        pub fn label<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LabelBuilder,
-            ) -> &'a mut crate::generated::all::builders::LabelBuilder,
-        {
-            let ty: crate::generated::all::Label = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LabelBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-rowindex` attribute
+        pub fn aria_row_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_row_index(Some(value));
             self
         }
-        /// Append a new `LineBreak` element
-        pub fn line_break<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LineBreakBuilder,
-            ) -> &'a mut crate::generated::all::builders::LineBreakBuilder,
-        {
-            let ty: crate::generated::all::LineBreak = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LineBreakBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-rowindextext` attribute
+        pub fn aria_row_index_text(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_row_index_text(Some(value.into()));
             self
         }
-        /// Append a new `LineBreakOpportunity` element
-        pub fn line_break_opportunity<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
-            ) -> &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
-        {
-            let ty: crate::generated::all::LineBreakOpportunity = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LineBreakOpportunityBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-rowspan` attribute
+        pub fn aria_row_span(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_row_span(Some(value));
             self
         }
-        /// Append a new `Link` element
-        pub fn link<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LinkBuilder,
-            ) -> &'a mut crate::generated::all::builders::LinkBuilder,
-        {
-            let ty: crate::generated::all::Link = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LinkBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-selected` attribute
+        pub fn aria_selected(&mut self, value: bool) -> &mut Self {
+            self.element.set_aria_selected(Some(value));
             self
         }
-        /// Append a new `MarkText` element
-        pub fn mark_text<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MarkTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::MarkTextBuilder,
-        {
-            let ty: crate::generated::all::MarkText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MarkTextBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-setsize` attribute
+        pub fn aria_set_size(&mut self, value: i64) -> &mut Self {
+            self.element.set_aria_set_size(Some(value));
             self
         }
-        /// Append a new `Meta` element
-        pub fn meta<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MetaBuilder,
-            ) -> &'a mut crate::generated::all::builders::MetaBuilder,
-        {
-            let ty: crate::generated::all::Meta = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MetaBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-sort` attribute
+        pub fn aria_sort(&mut self, value: html_sys::attributes::AriaSort) -> &mut Self {
+            self.element.set_aria_sort(Some(value));
             self
         }
-        /// Append a new `Meter` element
-        pub fn meter<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MeterBuilder,
-            ) -> &'a mut crate::generated::all::builders::MeterBuilder,
-        {
-            let ty: crate::generated::all::Meter = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MeterBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-valuemax` attribute
+        pub fn aria_value_max(&mut self, value: f64) -> &mut Self {
+            self.element.set_aria_value_max(Some(value));
             self
         }
-        /// Append a new `NoScript` element
-        pub fn no_script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::NoScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::NoScriptBuilder,
-        {
-            let ty: crate::generated::all::NoScript = Default::default();
-            let mut ty_builder = crate::generated::all::builders::NoScriptBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-valuemin` attribute
+        pub fn aria_value_min(&mut self, value: f64) -> &mut Self {
+            self.element.set_aria_value_min(Some(value));
             self
         }
-        /// Append a new `Object` element
-        pub fn object<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ObjectBuilder,
-            ) -> &'a mut crate::generated::all::builders::ObjectBuilder,
-        {
-            let ty: crate::generated::all::Object = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ObjectBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-valuenow` attribute
+        pub fn aria_value_now(&mut self, value: f64) -> &mut Self {
+            self.element.set_aria_value_now(Some(value));
             self
         }
-        /// Append a new `Output` element
-        pub fn output<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::OutputBuilder,
-            ) -> &'a mut crate::generated::all::builders::OutputBuilder,
-        {
-            let ty: crate::generated::all::Output = Default::default();
-            let mut ty_builder = crate::generated::all::builders::OutputBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `aria-valuetext` attribute
+        pub fn aria_value_text(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_aria_value_text(Some(value.into()));
             self
         }
-        /// Append a new `Picture` element
-        pub fn picture<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::PictureBuilder,
-            ) -> &'a mut crate::generated::all::builders::PictureBuilder,
-        {
-            let ty: crate::generated::all::Picture = Default::default();
-            let mut ty_builder = crate::generated::all::builders::PictureBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `accesskey` attribute
+        pub fn access_key(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_access_key(Some(value.into()));
             self
         }
-        /// Append a new `Progress` element
-        pub fn progress<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ProgressBuilder,
-            ) -> &'a mut crate::generated::all::builders::ProgressBuilder,
-        {
-            let ty: crate::generated::all::Progress = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ProgressBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `autocapitalize` attribute
+        pub fn auto_capitalize(
+            &mut self,
+            value: html_sys::attributes::AutoCapitalize,
+        ) -> &mut Self {
+            self.element.set_auto_capitalize(Some(value));
             self
         }
-        /// Append a new `Quotation` element
-        pub fn quotation<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::QuotationBuilder,
-            ) -> &'a mut crate::generated::all::builders::QuotationBuilder,
-        {
-            let ty: crate::generated::all::Quotation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::QuotationBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `autofocus` attribute
+        pub fn autofocus(&mut self, value: bool) -> &mut Self {
+            self.element.set_autofocus(value);
             self
         }
-        /// Append a new `RubyAnnotation` element
-        pub fn ruby_annotation<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
-            ) -> &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
-        {
-            let ty: crate::generated::all::RubyAnnotation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::RubyAnnotationBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `class` attribute
+        pub fn class(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_class(Some(value.into()));
             self
         }
-        /// Append a new `SampleOutput` element
-        pub fn sample_output<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SampleOutputBuilder,
-            ) -> &'a mut crate::generated::all::builders::SampleOutputBuilder,
-        {
-            let ty: crate::generated::all::SampleOutput = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SampleOutputBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `contenteditable` attribute
+        pub fn content_editable(
+            &mut self,
+            value: html_sys::attributes::ContentEditable,
+        ) -> &mut Self {
+            self.element.set_content_editable(Some(value));
             self
         }
-        /// Append a new `Script` element
-        pub fn script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::ScriptBuilder,
-        {
-            let ty: crate::generated::all::Script = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ScriptBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `dir` attribute
+        pub fn direction(
+            &mut self,
+            value: html_sys::attributes::Direction,
+        ) -> &mut Self {
+            self.element.set_direction(Some(value));
             self
         }
-        /// Append a new `Select` element
-        pub fn select<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SelectBuilder,
-            ) -> &'a mut crate::generated::all::builders::SelectBuilder,
-        {
-            let ty: crate::generated::all::Select = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SelectBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `draggable` attribute
+        pub fn draggable(
+            &mut self,
+            value: html_sys::attributes::Draggable,
+        ) -> &mut Self {
+            self.element.set_draggable(Some(value));
             self
         }
-        /// Append a new `SideComment` element
-        pub fn side_comment<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SideCommentBuilder,
-            ) -> &'a mut crate::generated::all::builders::SideCommentBuilder,
-        {
-            let ty: crate::generated::all::SideComment = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SideCommentBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `enterkeyhint` attribute
+        pub fn enter_key_hint(
+            &mut self,
+            value: html_sys::attributes::EnterKeyHint,
+        ) -> &mut Self {
+            self.element.set_enter_key_hint(Some(value));
             self
         }
-        /// Append a new `Slot` element
-        pub fn slot<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SlotBuilder,
-            ) -> &'a mut crate::generated::all::builders::SlotBuilder,
-        {
-            let ty: crate::generated::all::Slot = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SlotBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `exportparts` attribute
+        pub fn export_parts(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_export_parts(Some(value.into()));
             self
         }
-        /// Append a new `Span` element
-        pub fn span<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SpanBuilder,
-            ) -> &'a mut crate::generated::all::builders::SpanBuilder,
-        {
-            let ty: crate::generated::all::Span = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SpanBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `hidden` attribute
+        pub fn hidden(&mut self, value: html_sys::attributes::Hidden) -> &mut Self {
+            self.element.set_hidden(Some(value));
+            self
+        }
+        /// Set the value of the `id` attribute
+        pub fn id(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_id(Some(value.into()));
             self
         }
-        /// Append a new `StrikeThrough` element
-        pub fn strike_through<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::StrikeThroughBuilder,
-            ) -> &'a mut crate::generated::all::builders::StrikeThroughBuilder,
-        {
-            let ty: crate::generated::all::StrikeThrough = Default::default();
-            let mut ty_builder = crate::generated::all::builders::StrikeThroughBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `inert` attribute
+        pub fn inert(&mut self, value: bool) -> &mut Self {
+            self.element.set_inert(value);
             self
         }
-        /// Append a new `Strong` element
-        pub fn strong<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::StrongBuilder,
-            ) -> &'a mut crate::generated::all::builders::StrongBuilder,
-        {
-            let ty: crate::generated::all::Strong = Default::default();
-            let mut ty_builder = crate::generated::all::builders::StrongBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `inputmode` attribute
+        pub fn input_mode(
+            &mut self,
+            value: html_sys::attributes::InputMode,
+        ) -> &mut Self {
+            self.element.set_input_mode(Some(value));
             self
         }
-        /// Append a new `SubScript` element
-        pub fn sub_script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SubScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::SubScriptBuilder,
-        {
-            let ty: crate::generated::all::SubScript = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SubScriptBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `is` attribute
+        pub fn is_(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_is_(Some(value.into()));
             self
         }
-        /// Append a new `SuperScript` element
-        pub fn super_script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SuperScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::SuperScriptBuilder,
-        {
-            let ty: crate::generated::all::SuperScript = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SuperScriptBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `itemid` attribute
+        pub fn item_id(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_id(Some(value.into()));
             self
         }
-        /// Append a new `Template` element
-        pub fn template<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TemplateBuilder,
-            ) -> &'a mut crate::generated::all::builders::TemplateBuilder,
-        {
-            let ty: crate::generated::all::Template = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TemplateBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `itemprop` attribute
+        pub fn item_prop(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_prop(Some(value.into()));
             self
         }
-        /// Append a new text element.
-        pub fn text(
+        /// Set the value of the `itemref` attribute
+        pub fn item_ref(
             &mut self,
-            s: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            let cow = s.into();
-            self.element.children_mut().push(cow.into());
+            self.element.set_item_ref(Some(value.into()));
             self
         }
-        /// Append a new `TextArea` element
-        pub fn text_area<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TextAreaBuilder,
-            ) -> &'a mut crate::generated::all::builders::TextAreaBuilder,
-        {
-            let ty: crate::generated::all::TextArea = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TextAreaBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `itemscope` attribute
+        pub fn item_scope(&mut self, value: bool) -> &mut Self {
+            self.element.set_item_scope(value);
             self
         }
-        /// Append a new `Time` element
-        pub fn time<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TimeBuilder,
-            ) -> &'a mut crate::generated::all::builders::TimeBuilder,
-        {
-            let ty: crate::generated::all::Time = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TimeBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `itemtype` attribute
+        pub fn item_type(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_type(Some(value.into()));
             self
         }
-        /// Append a new `Underline` element
-        pub fn underline<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::UnderlineBuilder,
-            ) -> &'a mut crate::generated::all::builders::UnderlineBuilder,
-        {
-            let ty: crate::generated::all::Underline = Default::default();
-            let mut ty_builder = crate::generated::all::builders::UnderlineBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `lang` attribute
+        pub fn lang(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_lang(Some(value.into()));
             self
         }
-        /// Append a new `Variable` element
-        pub fn variable<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::VariableBuilder,
-            ) -> &'a mut crate::generated::all::builders::VariableBuilder,
-        {
-            let ty: crate::generated::all::Variable = Default::default();
-            let mut ty_builder = crate::generated::all::builders::VariableBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `nonce` attribute
+        pub fn nonce(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_nonce(Some(value.into()));
             self
         }
-        /// Append a new `Video` element
-        pub fn video<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::VideoBuilder,
-            ) -> &'a mut crate::generated::all::builders::VideoBuilder,
-        {
-            let ty: crate::generated::all::Video = Default::default();
-            let mut ty_builder = crate::generated::all::builders::VideoBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `part` attribute
+        pub fn part(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_part(Some(value.into()));
             self
         }
-        /// Set the value of the `role` attribute
-        pub fn role(
+        /// Set the value of the `slot` attribute
+        pub fn slot_attr(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_role(Some(value.into()));
+            self.element.set_slot(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-activedescendant` attribute
-        pub fn aria_active_descendant_element(
+        /// Set the value of the `spellcheck` attribute
+        pub fn spellcheck(
+            &mut self,
+            value: html_sys::attributes::Spellcheck,
+        ) -> &mut Self {
+            self.element.set_spellcheck(Some(value));
+            self
+        }
+        /// Set the value of the `style` attribute
+        pub fn style(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_active_descendant_element(Some(value.into()));
+            self.element.set_style(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-atomic` attribute
-        pub fn aria_atomic(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_atomic(value);
+        /// Set the value of the `tabindex` attribute
+        pub fn tab_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_tab_index(Some(value));
             self
         }
-        /// Set the value of the `aria-autocomplete` attribute
-        pub fn aria_auto_complete(
+        /// Set the value of the `title` attribute
+        pub fn title(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_title(Some(value.into()));
+            self
+        }
+        /// Set the value of the `translate` attribute
+        pub fn translate(
+            &mut self,
+            value: html_sys::attributes::Translate,
+        ) -> &mut Self {
+            self.element.set_translate(Some(value));
+            self
+        }
+        /// Set the value of the `onabort` attribute
+        pub fn onabort(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_auto_complete(Some(value.into()));
+            self.element.set_onabort(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-brailleroledescription` attribute
-        pub fn aria_braille_role_description(
+        /// Set the value of the `onauxclick` attribute
+        pub fn onauxclick(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_braille_role_description(Some(value.into()));
+            self.element.set_onauxclick(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-busy` attribute
-        pub fn aria_busy(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_busy(value);
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforeinput(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-checked` attribute
-        pub fn aria_checked(
+        /// Set the value of the `onbeforematch` attribute
+        pub fn onbeforematch(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_checked(Some(value.into()));
+            self.element.set_onbeforematch(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colcount` attribute
-        pub fn aria_col_count(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_col_count(Some(value));
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforetoggle(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colindex` attribute
-        pub fn aria_col_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_col_index(Some(value));
+        /// Set the value of the `onblur` attribute
+        pub fn onblur(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onblur(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colindextext` attribute
-        pub fn aria_col_index_text(
+        /// Set the value of the `oncancel` attribute
+        pub fn oncancel(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_col_index_text(Some(value.into()));
+            self.element.set_oncancel(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-colspan` attribute
-        pub fn aria_col_span(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_col_span(Some(value));
+        /// Set the value of the `oncanplay` attribute
+        pub fn oncanplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplay(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-controls` attribute
-        pub fn aria_controls_elements(
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_controls_elements(Some(value.into()));
+            self.element.set_oncanplaythrough(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-current` attribute
-        pub fn aria_current(
+        /// Set the value of the `onchange` attribute
+        pub fn onchange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_current(Some(value.into()));
+            self.element.set_onchange(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-describedby` attribute
-        pub fn aria_described_by_elements(
+        /// Set the value of the `onclick` attribute
+        pub fn onclick(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_described_by_elements(Some(value.into()));
+            self.element.set_onclick(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-description` attribute
-        pub fn aria_description(
+        /// Set the value of the `onclose` attribute
+        pub fn onclose(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_description(Some(value.into()));
+            self.element.set_onclose(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-details` attribute
-        pub fn aria_details_elements(
+        /// Set the value of the `oncontextlost` attribute
+        pub fn oncontextlost(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_details_elements(Some(value.into()));
+            self.element.set_oncontextlost(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-disabled` attribute
-        pub fn aria_disabled(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_disabled(value);
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextmenu(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-dropeffect` attribute
-        pub fn aria_drop_effect(
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_drop_effect(Some(value.into()));
+            self.element.set_oncontextrestored(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-errormessage` attribute
-        pub fn aria_error_message_elements(
+        /// Set the value of the `oncopy` attribute
+        pub fn oncopy(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_error_message_elements(Some(value.into()));
+            self.element.set_oncopy(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-expanded` attribute
-        pub fn aria_expanded(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_expanded(value);
+        /// Set the value of the `oncuechange` attribute
+        pub fn oncuechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncuechange(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-flowto` attribute
-        pub fn aria_flow_to_elements(
+        /// Set the value of the `oncut` attribute
+        pub fn oncut(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_flow_to_elements(Some(value.into()));
+            self.element.set_oncut(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-grabbed` attribute
-        pub fn aria_grabbed(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_grabbed(value);
+        /// Set the value of the `ondblclick` attribute
+        pub fn ondblclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondblclick(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-haspopup` attribute
-        pub fn aria_has_popup(
+        /// Set the value of the `ondrag` attribute
+        pub fn ondrag(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_has_popup(Some(value.into()));
+            self.element.set_ondrag(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-hidden` attribute
-        pub fn aria_hidden(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_hidden(value);
+        /// Set the value of the `ondragend` attribute
+        pub fn ondragend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragend(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-invalid` attribute
-        pub fn aria_invalid(
+        /// Set the value of the `ondragenter` attribute
+        pub fn ondragenter(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_invalid(Some(value.into()));
+            self.element.set_ondragenter(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-keyshortcuts` attribute
-        pub fn aria_key_shortcuts(
+        /// Set the value of the `ondragleave` attribute
+        pub fn ondragleave(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_key_shortcuts(Some(value.into()));
+            self.element.set_ondragleave(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-level` attribute
-        pub fn aria_level(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_level(Some(value));
+        /// Set the value of the `ondragover` attribute
+        pub fn ondragover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragover(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-live` attribute
-        pub fn aria_live(
+        /// Set the value of the `ondragstart` attribute
+        pub fn ondragstart(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_live(Some(value.into()));
+            self.element.set_ondragstart(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-modal` attribute
-        pub fn aria_modal(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_modal(value);
+        /// Set the value of the `ondrop` attribute
+        pub fn ondrop(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrop(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-multiline` attribute
-        pub fn aria_multi_line(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_multi_line(value);
+        /// Set the value of the `ondurationchange` attribute
+        pub fn ondurationchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondurationchange(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-multiselectable` attribute
-        pub fn aria_multi_selectable(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_multi_selectable(value);
+        /// Set the value of the `onemptied` attribute
+        pub fn onemptied(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onemptied(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-orientation` attribute
-        pub fn aria_orientation(
+        /// Set the value of the `onended` attribute
+        pub fn onended(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_orientation(Some(value.into()));
+            self.element.set_onended(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-owns` attribute
-        pub fn aria_owns_elements(
+        /// Set the value of the `onerror` attribute
+        pub fn onerror(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_owns_elements(Some(value.into()));
+            self.element.set_onerror(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-placeholder` attribute
-        pub fn aria_placeholder(
+        /// Set the value of the `onfocus` attribute
+        pub fn onfocus(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_placeholder(Some(value.into()));
+            self.element.set_onfocus(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-posinset` attribute
-        pub fn aria_pos_in_set(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_pos_in_set(Some(value));
+        /// Set the value of the `onformdata` attribute
+        pub fn onformdata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onformdata(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-pressed` attribute
-        pub fn aria_pressed(
+        /// Set the value of the `oninput` attribute
+        pub fn oninput(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_pressed(Some(value.into()));
+            self.element.set_oninput(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-readonly` attribute
-        pub fn aria_read_only(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_read_only(value);
+        /// Set the value of the `oninvalid` attribute
+        pub fn oninvalid(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninvalid(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-relevant` attribute
-        pub fn aria_relevant(
+        /// Set the value of the `onkeydown` attribute
+        pub fn onkeydown(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_relevant(Some(value.into()));
+            self.element.set_onkeydown(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-required` attribute
-        pub fn aria_required(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_required(value);
+        /// Set the value of the `onkeypress` attribute
+        pub fn onkeypress(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeypress(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-roledescription` attribute
-        pub fn aria_role_description(
+        /// Set the value of the `onkeyup` attribute
+        pub fn onkeyup(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_role_description(Some(value.into()));
+            self.element.set_onkeyup(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowcount` attribute
-        pub fn aria_row_count(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_row_count(Some(value));
+        /// Set the value of the `onload` attribute
+        pub fn onload(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onload(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowindex` attribute
-        pub fn aria_row_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_row_index(Some(value));
+        /// Set the value of the `onloadeddata` attribute
+        pub fn onloadeddata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadeddata(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowindextext` attribute
-        pub fn aria_row_index_text(
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_row_index_text(Some(value.into()));
+            self.element.set_onloadedmetadata(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-rowspan` attribute
-        pub fn aria_row_span(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_row_span(Some(value));
+        /// Set the value of the `onloadstart` attribute
+        pub fn onloadstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadstart(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-selected` attribute
-        pub fn aria_selected(&mut self, value: bool) -> &mut Self {
-            self.element.set_aria_selected(value);
+        /// Set the value of the `onmousedown` attribute
+        pub fn onmousedown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousedown(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-setsize` attribute
-        pub fn aria_set_size(&mut self, value: i64) -> &mut Self {
-            self.element.set_aria_set_size(Some(value));
+        /// Set the value of the `onmouseenter` attribute
+        pub fn onmouseenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseenter(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-sort` attribute
-        pub fn aria_sort(
+        /// Set the value of the `onmouseleave` attribute
+        pub fn onmouseleave(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_sort(Some(value.into()));
+            self.element.set_onmouseleave(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuemax` attribute
-        pub fn aria_value_max(&mut self, value: f64) -> &mut Self {
-            self.element.set_aria_value_max(Some(value));
+        /// Set the value of the `onmousemove` attribute
+        pub fn onmousemove(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousemove(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuemin` attribute
-        pub fn aria_value_min(&mut self, value: f64) -> &mut Self {
-            self.element.set_aria_value_min(Some(value));
+        /// Set the value of the `onmouseout` attribute
+        pub fn onmouseout(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseout(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuenow` attribute
-        pub fn aria_value_now(&mut self, value: f64) -> &mut Self {
-            self.element.set_aria_value_now(Some(value));
+        /// Set the value of the `onmouseover` attribute
+        pub fn onmouseover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseover(Some(value.into()));
             self
         }
-        /// Set the value of the `aria-valuetext` attribute
-        pub fn aria_value_text(
+        /// Set the value of the `onmouseup` attribute
+        pub fn onmouseup(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_aria_value_text(Some(value.into()));
+            self.element.set_onmouseup(Some(value.into()));
             self
         }
-        /// Set the value of the `accesskey` attribute
-        pub fn access_key(
+        /// Set the value of the `onpaste` attribute
+        pub fn onpaste(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_access_key(Some(value.into()));
+            self.element.set_onpaste(Some(value.into()));
             self
         }
-        /// Set the value of the `autocapitalize` attribute
-        pub fn auto_capitalize(
+        /// Set the value of the `onpause` attribute
+        pub fn onpause(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_auto_capitalize(Some(value.into()));
+            self.element.set_onpause(Some(value.into()));
             self
         }
-        /// Set the value of the `autofocus` attribute
-        pub fn autofocus(&mut self, value: bool) -> &mut Self {
-            self.element.set_autofocus(value);
+        /// Set the value of the `onplay` attribute
+        pub fn onplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onplay(Some(value.into()));
             self
         }
-        /// Set the value of the `class` attribute
-        pub fn class(
+        /// Set the value of the `onplaying` attribute
+        pub fn onplaying(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_class(Some(value.into()));
+            self.element.set_onplaying(Some(value.into()));
             self
         }
-        /// Set the value of the `contenteditable` attribute
-        pub fn content_editable(
+        /// Set the value of the `onprogress` attribute
+        pub fn onprogress(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_content_editable(Some(value.into()));
+            self.element.set_onprogress(Some(value.into()));
             self
         }
-        /// Set the value of the `dir` attribute
-        pub fn direction(
+        /// Set the value of the `onratechange` attribute
+        pub fn onratechange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_direction(Some(value.into()));
+            self.element.set_onratechange(Some(value.into()));
             self
         }
-        /// Set the value of the `draggable` attribute
-        pub fn draggable(&mut self, value: bool) -> &mut Self {
-            self.element.set_draggable(value);
+        /// Set the value of the `onreset` attribute
+        pub fn onreset(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onreset(Some(value.into()));
             self
         }
-        /// Set the value of the `enterkeyhint` attribute
-        pub fn enter_key_hint(
+        /// Set the value of the `onresize` attribute
+        pub fn onresize(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_enter_key_hint(Some(value.into()));
+            self.element.set_onresize(Some(value.into()));
             self
         }
-        /// Set the value of the `exportparts` attribute
-        pub fn export_parts(
+        /// Set the value of the `onscroll` attribute
+        pub fn onscroll(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_export_parts(Some(value.into()));
+            self.element.set_onscroll(Some(value.into()));
             self
         }
-        /// Set the value of the `hidden` attribute
-        pub fn hidden(
+        /// Set the value of the `onscrollend` attribute
+        pub fn onscrollend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_hidden(Some(value.into()));
+            self.element.set_onscrollend(Some(value.into()));
             self
         }
-        /// Set the value of the `id` attribute
-        pub fn id(
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_id(Some(value.into()));
+            self.element.set_onsecuritypolicyviolation(Some(value.into()));
             self
         }
-        /// Set the value of the `inert` attribute
-        pub fn inert(&mut self, value: bool) -> &mut Self {
-            self.element.set_inert(value);
+        /// Set the value of the `onseeked` attribute
+        pub fn onseeked(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onseeked(Some(value.into()));
             self
         }
-        /// Set the value of the `inputmode` attribute
-        pub fn input_mode(
+        /// Set the value of the `onseeking` attribute
+        pub fn onseeking(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_input_mode(Some(value.into()));
+            self.element.set_onseeking(Some(value.into()));
             self
         }
-        /// Set the value of the `is` attribute
-        pub fn is_(
+        /// Set the value of the `onselect` attribute
+        pub fn onselect(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_is_(Some(value.into()));
+            self.element.set_onselect(Some(value.into()));
             self
         }
-        /// Set the value of the `itemid` attribute
-        pub fn item_id(
+        /// Set the value of the `onslotchange` attribute
+        pub fn onslotchange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_id(Some(value.into()));
+            self.element.set_onslotchange(Some(value.into()));
             self
         }
-        /// Set the value of the `itemprop` attribute
-        pub fn item_prop(
+        /// Set the value of the `onstalled` attribute
+        pub fn onstalled(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_prop(Some(value.into()));
+            self.element.set_onstalled(Some(value.into()));
             self
         }
-        /// Set the value of the `itemref` attribute
-        pub fn item_ref(
+        /// Set the value of the `onsubmit` attribute
+        pub fn onsubmit(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_ref(Some(value.into()));
+            self.element.set_onsubmit(Some(value.into()));
             self
         }
-        /// Set the value of the `itemscope` attribute
-        pub fn item_scope(
+        /// Set the value of the `onsuspend` attribute
+        pub fn onsuspend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_scope(Some(value.into()));
+            self.element.set_onsuspend(Some(value.into()));
             self
         }
-        /// Set the value of the `itemtype` attribute
-        pub fn item_type(
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_type(Some(value.into()));
+            self.element.set_ontimeupdate(Some(value.into()));
             self
         }
-        /// Set the value of the `lang` attribute
-        pub fn lang(
+        /// Set the value of the `ontoggle` attribute
+        pub fn ontoggle(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_lang(Some(value.into()));
+            self.element.set_ontoggle(Some(value.into()));
             self
         }
-        /// Set the value of the `nonce` attribute
-        pub fn nonce(
+        /// Set the value of the `onvolumechange` attribute
+        pub fn onvolumechange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_nonce(Some(value.into()));
+            self.element.set_onvolumechange(Some(value.into()));
             self
         }
-        /// Set the value of the `part` attribute
-        pub fn part(
+        /// Set the value of the `onwaiting` attribute
+        pub fn onwaiting(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_part(Some(value.into()));
+            self.element.set_onwaiting(Some(value.into()));
             self
         }
-        /// Set the value of the `slot` attribute
-        pub fn slot_attr(
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_slot(Some(value.into()));
+            self.element.set_onwebkitanimationend(Some(value.into()));
             self
         }
-        /// Set the value of the `spellcheck` attribute
-        pub fn spellcheck(
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_spellcheck(Some(value.into()));
+            self.element.set_onwebkitanimationiteration(Some(value.into()));
             self
         }
-        /// Set the value of the `style` attribute
-        pub fn style(
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_style(Some(value.into()));
+            self.element.set_onwebkitanimationstart(Some(value.into()));
             self
         }
-        /// Set the value of the `tabindex` attribute
-        pub fn tab_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_tab_index(Some(value));
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkittransitionend(Some(value.into()));
             self
         }
-        /// Set the value of the `title` attribute
-        pub fn title(
+        /// Set the value of the `onwheel` attribute
+        pub fn onwheel(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_title(Some(value.into()));
+            self.element.set_onwheel(Some(value.into()));
             self
         }
-        /// Set the value of the `translate` attribute
-        pub fn translate(&mut self, value: bool) -> &mut Self {
-            self.element.set_translate(value);
+        /// Append a comment.
+        pub fn comment(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::Comment::new(s).into());
+            self
+        }
+        /// Append trusted HTML, which is written without being escaped.
+        ///
+        /// It's up to the caller to make sure the HTML is well-formed and
+        /// doesn't contain any untrusted input.
+        pub fn raw_html(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::RawHtml::new(s).into());
             self
         }
         /// Push a new child element to the list of children.
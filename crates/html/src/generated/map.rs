@@ -54,15 +54,17 @@ pub mod element {
             self.sys.access_key = value.map(|v| v.into());
         }
         /// Get the value of the `autocapitalize` attribute
-        pub fn auto_capitalize(&self) -> std::option::Option<&str> {
-            self.sys.auto_capitalize.as_deref()
+        pub fn auto_capitalize(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::AutoCapitalize> {
+            self.sys.auto_capitalize.as_ref()
         }
         /// Set the value of the `autocapitalize` attribute
         pub fn set_auto_capitalize(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::AutoCapitalize>,
         ) {
-            self.sys.auto_capitalize = value.map(|v| v.into());
+            self.sys.auto_capitalize = value;
         }
         /// Get the value of the `autofocus` attribute
         pub fn autofocus(&self) -> bool {
@@ -84,45 +86,56 @@ pub mod element {
             self.sys.class = value.map(|v| v.into());
         }
         /// Get the value of the `contenteditable` attribute
-        pub fn content_editable(&self) -> std::option::Option<&str> {
-            self.sys.content_editable.as_deref()
+        pub fn content_editable(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::ContentEditable> {
+            self.sys.content_editable.as_ref()
         }
         /// Set the value of the `contenteditable` attribute
         pub fn set_content_editable(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::ContentEditable>,
         ) {
-            self.sys.content_editable = value.map(|v| v.into());
+            self.sys.content_editable = value;
         }
         /// Get the value of the `dir` attribute
-        pub fn direction(&self) -> std::option::Option<&str> {
-            self.sys.direction.as_deref()
+        pub fn direction(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Direction> {
+            self.sys.direction.as_ref()
         }
         /// Set the value of the `dir` attribute
         pub fn set_direction(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Direction>,
         ) {
-            self.sys.direction = value.map(|v| v.into());
+            self.sys.direction = value;
         }
         /// Get the value of the `draggable` attribute
-        pub fn draggable(&self) -> bool {
-            self.sys.draggable
+        pub fn draggable(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Draggable> {
+            self.sys.draggable.as_ref()
         }
         /// Set the value of the `draggable` attribute
-        pub fn set_draggable(&mut self, value: bool) {
+        pub fn set_draggable(
+            &mut self,
+            value: std::option::Option<html_sys::attributes::Draggable>,
+        ) {
             self.sys.draggable = value;
         }
         /// Get the value of the `enterkeyhint` attribute
-        pub fn enter_key_hint(&self) -> std::option::Option<&str> {
-            self.sys.enter_key_hint.as_deref()
+        pub fn enter_key_hint(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::EnterKeyHint> {
+            self.sys.enter_key_hint.as_ref()
         }
         /// Set the value of the `enterkeyhint` attribute
         pub fn set_enter_key_hint(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::EnterKeyHint>,
         ) {
-            self.sys.enter_key_hint = value.map(|v| v.into());
+            self.sys.enter_key_hint = value;
         }
         /// Get the value of the `exportparts` attribute
         pub fn export_parts(&self) -> std::option::Option<&str> {
@@ -136,15 +149,15 @@ pub mod element {
             self.sys.export_parts = value.map(|v| v.into());
         }
         /// Get the value of the `hidden` attribute
-        pub fn hidden(&self) -> std::option::Option<&str> {
-            self.sys.hidden.as_deref()
+        pub fn hidden(&self) -> std::option::Option<&html_sys::attributes::Hidden> {
+            self.sys.hidden.as_ref()
         }
         /// Set the value of the `hidden` attribute
         pub fn set_hidden(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Hidden>,
         ) {
-            self.sys.hidden = value.map(|v| v.into());
+            self.sys.hidden = value;
         }
         /// Get the value of the `id` attribute
         pub fn id(&self) -> std::option::Option<&str> {
@@ -166,15 +179,17 @@ pub mod element {
             self.sys.inert = value;
         }
         /// Get the value of the `inputmode` attribute
-        pub fn input_mode(&self) -> std::option::Option<&str> {
-            self.sys.input_mode.as_deref()
+        pub fn input_mode(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::InputMode> {
+            self.sys.input_mode.as_ref()
         }
         /// Set the value of the `inputmode` attribute
         pub fn set_input_mode(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::InputMode>,
         ) {
-            self.sys.input_mode = value.map(|v| v.into());
+            self.sys.input_mode = value;
         }
         /// Get the value of the `is` attribute
         pub fn is_(&self) -> std::option::Option<&str> {
@@ -221,15 +236,12 @@ pub mod element {
             self.sys.item_ref = value.map(|v| v.into());
         }
         /// Get the value of the `itemscope` attribute
-        pub fn item_scope(&self) -> std::option::Option<&str> {
-            self.sys.item_scope.as_deref()
+        pub fn item_scope(&self) -> bool {
+            self.sys.item_scope
         }
         /// Set the value of the `itemscope` attribute
-        pub fn set_item_scope(
-            &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
-        ) {
-            self.sys.item_scope = value.map(|v| v.into());
+        pub fn set_item_scope(&mut self, value: bool) {
+            self.sys.item_scope = value;
         }
         /// Get the value of the `itemtype` attribute
         pub fn item_type(&self) -> std::option::Option<&str> {
@@ -287,15 +299,17 @@ pub mod element {
             self.sys.slot = value.map(|v| v.into());
         }
         /// Get the value of the `spellcheck` attribute
-        pub fn spellcheck(&self) -> std::option::Option<&str> {
-            self.sys.spellcheck.as_deref()
+        pub fn spellcheck(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Spellcheck> {
+            self.sys.spellcheck.as_ref()
         }
         /// Set the value of the `spellcheck` attribute
         pub fn set_spellcheck(
             &mut self,
-            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+            value: std::option::Option<html_sys::attributes::Spellcheck>,
         ) {
-            self.sys.spellcheck = value.map(|v| v.into());
+            self.sys.spellcheck = value;
         }
         /// Get the value of the `style` attribute
         pub fn style(&self) -> std::option::Option<&str> {
@@ -328,1314 +342,4663 @@ pub mod element {
             self.sys.title = value.map(|v| v.into());
         }
         /// Get the value of the `translate` attribute
-        pub fn translate(&self) -> bool {
-            self.sys.translate
+        pub fn translate(
+            &self,
+        ) -> std::option::Option<&html_sys::attributes::Translate> {
+            self.sys.translate.as_ref()
         }
         /// Set the value of the `translate` attribute
-        pub fn set_translate(&mut self, value: bool) {
+        pub fn set_translate(
+            &mut self,
+            value: std::option::Option<html_sys::attributes::Translate>,
+        ) {
             self.sys.translate = value;
         }
-    }
-    impl ImageMap {
-        /// Access the element's children
-        pub fn children(&self) -> &[super::child::ImageMapChild] {
-            self.children.as_ref()
+        /// Get the value of the `onabort` attribute
+        pub fn onabort(&self) -> std::option::Option<&str> {
+            self.sys.onabort.as_deref()
         }
-        /// Mutably access the element's children
-        pub fn children_mut(&mut self) -> &mut Vec<super::child::ImageMapChild> {
-            &mut self.children
+        /// Set the value of the `onabort` attribute
+        pub fn set_onabort(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onabort = value.map(|v| v.into());
         }
-    }
-    impl crate::Render for ImageMap {
-        fn render(
-            &self,
-            f: &mut std::fmt::Formatter<'_>,
-            depth: usize,
-        ) -> std::fmt::Result {
-            write!(f, "{:level$}", "", level = depth * 4)?;
-            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-            if !self.children.is_empty() {
-                write!(f, "\n")?;
-            }
-            for el in &self.children {
-                crate::Render::render(&el, f, depth)?;
-                write!(f, "\n")?;
-            }
-            write!(f, "{:level$}", "", level = depth * 4)?;
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            Ok(())
+        /// Get the value of the `onauxclick` attribute
+        pub fn onauxclick(&self) -> std::option::Option<&str> {
+            self.sys.onauxclick.as_deref()
         }
-    }
-    impl std::fmt::Debug for ImageMap {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            crate::Render::render(self, f, 0)?;
-            Ok(())
+        /// Set the value of the `onauxclick` attribute
+        pub fn set_onauxclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onauxclick = value.map(|v| v.into());
         }
-    }
-    impl std::fmt::Display for ImageMap {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
-            for el in &self.children {
-                write!(f, "{el}")?;
-            }
-            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
-            Ok(())
+        /// Get the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(&self) -> std::option::Option<&str> {
+            self.sys.onbeforeinput.as_deref()
         }
-    }
-    impl crate::HtmlElement for ImageMap {
-        fn tag_name(&self) -> &'static str {
-            "map"
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn set_onbeforeinput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforeinput = value.map(|v| v.into());
         }
-        fn attributes(
-            &self,
-        ) -> std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        > {
-            use html_sys::ElementDescription;
-            self.sys.attributes()
+        /// Get the value of the `onbeforematch` attribute
+        pub fn onbeforematch(&self) -> std::option::Option<&str> {
+            self.sys.onbeforematch.as_deref()
         }
-        fn data(
-            &self,
-        ) -> std::collections::HashMap<
-            std::borrow::Cow<'static, str>,
-            std::borrow::Cow<'static, str>,
-        > {
-            use html_sys::ElementDescription;
-            self.sys.data()
+        /// Set the value of the `onbeforematch` attribute
+        pub fn set_onbeforematch(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforematch = value.map(|v| v.into());
         }
-        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
-            self.children.iter().map(From::from).collect()
+        /// Get the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(&self) -> std::option::Option<&str> {
+            self.sys.onbeforetoggle.as_deref()
         }
-    }
-    impl crate::FlowContent for ImageMap {}
-    impl crate::PhrasingContent for ImageMap {}
-    impl crate::PalpableContent for ImageMap {}
-    impl std::convert::Into<html_sys::embedded::ImageMap> for ImageMap {
-        fn into(self) -> html_sys::embedded::ImageMap {
-            self.sys
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn set_onbeforetoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforetoggle = value.map(|v| v.into());
         }
-    }
-    impl From<html_sys::embedded::ImageMap> for ImageMap {
-        fn from(sys: html_sys::embedded::ImageMap) -> Self {
-            Self { sys, children: vec![] }
+        /// Get the value of the `onblur` attribute
+        pub fn onblur(&self) -> std::option::Option<&str> {
+            self.sys.onblur.as_deref()
         }
-    }
-}
-pub mod child {
-    /// The permitted child items for the `ImageMap` element
-    #[derive(PartialEq, Clone)]
-    pub enum ImageMapChild {
-        /// The Abbreviation element
-        Abbreviation(crate::generated::all::Abbreviation),
-        /// The Address element
-        Address(crate::generated::all::Address),
-        /// The Anchor element
-        Anchor(crate::generated::all::Anchor),
-        /// The Article element
-        Article(crate::generated::all::Article),
-        /// The Aside element
-        Aside(crate::generated::all::Aside),
-        /// The Audio element
-        Audio(crate::generated::all::Audio),
-        /// The Base element
-        Base(crate::generated::all::Base),
-        /// The BidirectionalIsolate element
-        BidirectionalIsolate(crate::generated::all::BidirectionalIsolate),
-        /// The BidirectionalTextOverride element
-        BidirectionalTextOverride(crate::generated::all::BidirectionalTextOverride),
-        /// The BlockQuote element
-        BlockQuote(crate::generated::all::BlockQuote),
-        /// The Body element
-        Body(crate::generated::all::Body),
-        /// The Bold element
-        Bold(crate::generated::all::Bold),
-        /// The Button element
-        Button(crate::generated::all::Button),
-        /// The Canvas element
-        Canvas(crate::generated::all::Canvas),
-        /// The Caption element
-        Caption(crate::generated::all::Caption),
-        /// The Cite element
-        Cite(crate::generated::all::Cite),
-        /// The Code element
-        Code(crate::generated::all::Code),
-        /// The Data element
-        Data(crate::generated::all::Data),
-        /// The DataList element
-        DataList(crate::generated::all::DataList),
-        /// The Definition element
-        Definition(crate::generated::all::Definition),
-        /// The DeletedText element
-        DeletedText(crate::generated::all::DeletedText),
-        /// The DescriptionDetails element
-        DescriptionDetails(crate::generated::all::DescriptionDetails),
-        /// The DescriptionList element
-        DescriptionList(crate::generated::all::DescriptionList),
-        /// The DescriptionTerm element
-        DescriptionTerm(crate::generated::all::DescriptionTerm),
-        /// The Details element
-        Details(crate::generated::all::Details),
-        /// The Dialog element
-        Dialog(crate::generated::all::Dialog),
-        /// The Division element
-        Division(crate::generated::all::Division),
-        /// The Embed element
-        Embed(crate::generated::all::Embed),
-        /// The Emphasis element
-        Emphasis(crate::generated::all::Emphasis),
-        /// The Fieldset element
-        Fieldset(crate::generated::all::Fieldset),
-        /// The Figure element
-        Figure(crate::generated::all::Figure),
-        /// The FigureCaption element
-        FigureCaption(crate::generated::all::FigureCaption),
-        /// The Footer element
-        Footer(crate::generated::all::Footer),
-        /// The Form element
-        Form(crate::generated::all::Form),
-        /// The Head element
-        Head(crate::generated::all::Head),
-        /// The Header element
-        Header(crate::generated::all::Header),
-        /// The Heading1 element
-        Heading1(crate::generated::all::Heading1),
-        /// The Heading2 element
-        Heading2(crate::generated::all::Heading2),
-        /// The Heading3 element
-        Heading3(crate::generated::all::Heading3),
-        /// The Heading4 element
-        Heading4(crate::generated::all::Heading4),
-        /// The Heading5 element
-        Heading5(crate::generated::all::Heading5),
-        /// The Heading6 element
-        Heading6(crate::generated::all::Heading6),
-        /// The HeadingGroup element
-        HeadingGroup(crate::generated::all::HeadingGroup),
-        /// The Html element
-        Html(crate::generated::all::Html),
-        /// The Iframe element
-        Iframe(crate::generated::all::Iframe),
-        /// The Image element
-        Image(crate::generated::all::Image),
-        /// The ImageMap element
-        ImageMap(crate::generated::all::ImageMap),
-        /// The ImageMapArea element
-        ImageMapArea(crate::generated::all::ImageMapArea),
-        /// The Input element
-        Input(crate::generated::all::Input),
-        /// The InsertedText element
-        InsertedText(crate::generated::all::InsertedText),
-        /// The Italic element
-        Italic(crate::generated::all::Italic),
-        /// The KeyboardInput element
-        KeyboardInput(crate::generated::all::KeyboardInput),
-        /// The Label element
-        Label(crate::generated::all::Label),
-        /// The Legend element
-        Legend(crate::generated::all::Legend),
-        /// The LineBreak element
-        LineBreak(crate::generated::all::LineBreak),
-        /// The LineBreakOpportunity element
-        LineBreakOpportunity(crate::generated::all::LineBreakOpportunity),
-        /// The Link element
-        Link(crate::generated::all::Link),
-        /// The ListItem element
-        ListItem(crate::generated::all::ListItem),
-        /// The Main element
-        Main(crate::generated::all::Main),
-        /// The MarkText element
-        MarkText(crate::generated::all::MarkText),
-        /// The MediaSource element
-        MediaSource(crate::generated::all::MediaSource),
-        /// The Menu element
-        Menu(crate::generated::all::Menu),
-        /// The Meta element
-        Meta(crate::generated::all::Meta),
-        /// The Meter element
-        Meter(crate::generated::all::Meter),
-        /// The Navigation element
-        Navigation(crate::generated::all::Navigation),
-        /// The NoScript element
-        NoScript(crate::generated::all::NoScript),
-        /// The Object element
-        Object(crate::generated::all::Object),
-        /// The Option element
-        Option(crate::generated::all::Option),
-        /// The OptionGroup element
-        OptionGroup(crate::generated::all::OptionGroup),
-        /// The OrderedList element
-        OrderedList(crate::generated::all::OrderedList),
-        /// The Output element
-        Output(crate::generated::all::Output),
-        /// The Paragraph element
-        Paragraph(crate::generated::all::Paragraph),
-        /// The Picture element
-        Picture(crate::generated::all::Picture),
-        /// The PreformattedText element
-        PreformattedText(crate::generated::all::PreformattedText),
-        /// The Progress element
-        Progress(crate::generated::all::Progress),
-        /// The Quotation element
-        Quotation(crate::generated::all::Quotation),
-        /// The RubyAnnotation element
-        RubyAnnotation(crate::generated::all::RubyAnnotation),
-        /// The RubyFallbackParenthesis element
-        RubyFallbackParenthesis(crate::generated::all::RubyFallbackParenthesis),
-        /// The RubyText element
-        RubyText(crate::generated::all::RubyText),
-        /// The SampleOutput element
-        SampleOutput(crate::generated::all::SampleOutput),
-        /// The Script element
-        Script(crate::generated::all::Script),
-        /// The Search element
-        Search(crate::generated::all::Search),
-        /// The Section element
-        Section(crate::generated::all::Section),
-        /// The Select element
-        Select(crate::generated::all::Select),
-        /// The SideComment element
-        SideComment(crate::generated::all::SideComment),
-        /// The Slot element
-        Slot(crate::generated::all::Slot),
-        /// The Span element
-        Span(crate::generated::all::Span),
-        /// The StrikeThrough element
-        StrikeThrough(crate::generated::all::StrikeThrough),
-        /// The Strong element
-        Strong(crate::generated::all::Strong),
-        /// The Style element
-        Style(crate::generated::all::Style),
-        /// The SubScript element
-        SubScript(crate::generated::all::SubScript),
-        /// The Summary element
-        Summary(crate::generated::all::Summary),
-        /// The SuperScript element
-        SuperScript(crate::generated::all::SuperScript),
-        /// The Table element
-        Table(crate::generated::all::Table),
-        /// The TableBody element
-        TableBody(crate::generated::all::TableBody),
-        /// The TableCell element
-        TableCell(crate::generated::all::TableCell),
-        /// The TableColumn element
-        TableColumn(crate::generated::all::TableColumn),
-        /// The TableColumnGroup element
-        TableColumnGroup(crate::generated::all::TableColumnGroup),
-        /// The TableFoot element
-        TableFoot(crate::generated::all::TableFoot),
-        /// The TableHead element
-        TableHead(crate::generated::all::TableHead),
-        /// The TableHeader element
-        TableHeader(crate::generated::all::TableHeader),
-        /// The TableRow element
-        TableRow(crate::generated::all::TableRow),
-        /// The Template element
-        Template(crate::generated::all::Template),
-        /// The Text element
-        Text(std::borrow::Cow<'static, str>),
-        /// The TextArea element
-        TextArea(crate::generated::all::TextArea),
-        /// The TextTrack element
-        TextTrack(crate::generated::all::TextTrack),
-        /// The ThematicBreak element
-        ThematicBreak(crate::generated::all::ThematicBreak),
-        /// The Time element
-        Time(crate::generated::all::Time),
-        /// The Title element
-        Title(crate::generated::all::Title),
-        /// The Underline element
-        Underline(crate::generated::all::Underline),
-        /// The UnorderedList element
-        UnorderedList(crate::generated::all::UnorderedList),
-        /// The Variable element
-        Variable(crate::generated::all::Variable),
-        /// The Video element
-        Video(crate::generated::all::Video),
-    }
-    impl std::convert::From<crate::generated::all::Abbreviation> for ImageMapChild {
-        fn from(value: crate::generated::all::Abbreviation) -> Self {
-            Self::Abbreviation(value)
+        /// Set the value of the `onblur` attribute
+        pub fn set_onblur(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onblur = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Address> for ImageMapChild {
-        fn from(value: crate::generated::all::Address) -> Self {
-            Self::Address(value)
+        /// Get the value of the `oncancel` attribute
+        pub fn oncancel(&self) -> std::option::Option<&str> {
+            self.sys.oncancel.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Anchor> for ImageMapChild {
-        fn from(value: crate::generated::all::Anchor) -> Self {
-            Self::Anchor(value)
+        /// Set the value of the `oncancel` attribute
+        pub fn set_oncancel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncancel = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Article> for ImageMapChild {
-        fn from(value: crate::generated::all::Article) -> Self {
-            Self::Article(value)
+        /// Get the value of the `oncanplay` attribute
+        pub fn oncanplay(&self) -> std::option::Option<&str> {
+            self.sys.oncanplay.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Aside> for ImageMapChild {
-        fn from(value: crate::generated::all::Aside) -> Self {
-            Self::Aside(value)
+        /// Set the value of the `oncanplay` attribute
+        pub fn set_oncanplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplay = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Audio> for ImageMapChild {
-        fn from(value: crate::generated::all::Audio) -> Self {
-            Self::Audio(value)
+        /// Get the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(&self) -> std::option::Option<&str> {
+            self.sys.oncanplaythrough.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Base> for ImageMapChild {
-        fn from(value: crate::generated::all::Base) -> Self {
-            Self::Base(value)
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn set_oncanplaythrough(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplaythrough = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::BidirectionalIsolate>
-    for ImageMapChild {
-        fn from(value: crate::generated::all::BidirectionalIsolate) -> Self {
-            Self::BidirectionalIsolate(value)
+        /// Get the value of the `onchange` attribute
+        pub fn onchange(&self) -> std::option::Option<&str> {
+            self.sys.onchange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::BidirectionalTextOverride>
-    for ImageMapChild {
-        fn from(value: crate::generated::all::BidirectionalTextOverride) -> Self {
-            Self::BidirectionalTextOverride(value)
+        /// Set the value of the `onchange` attribute
+        pub fn set_onchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onchange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::BlockQuote> for ImageMapChild {
-        fn from(value: crate::generated::all::BlockQuote) -> Self {
-            Self::BlockQuote(value)
+        /// Get the value of the `onclick` attribute
+        pub fn onclick(&self) -> std::option::Option<&str> {
+            self.sys.onclick.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Body> for ImageMapChild {
-        fn from(value: crate::generated::all::Body) -> Self {
-            Self::Body(value)
+        /// Set the value of the `onclick` attribute
+        pub fn set_onclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclick = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Bold> for ImageMapChild {
-        fn from(value: crate::generated::all::Bold) -> Self {
-            Self::Bold(value)
+        /// Get the value of the `onclose` attribute
+        pub fn onclose(&self) -> std::option::Option<&str> {
+            self.sys.onclose.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Button> for ImageMapChild {
-        fn from(value: crate::generated::all::Button) -> Self {
-            Self::Button(value)
+        /// Set the value of the `onclose` attribute
+        pub fn set_onclose(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclose = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Canvas> for ImageMapChild {
-        fn from(value: crate::generated::all::Canvas) -> Self {
-            Self::Canvas(value)
+        /// Get the value of the `oncontextlost` attribute
+        pub fn oncontextlost(&self) -> std::option::Option<&str> {
+            self.sys.oncontextlost.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Caption> for ImageMapChild {
-        fn from(value: crate::generated::all::Caption) -> Self {
-            Self::Caption(value)
+        /// Set the value of the `oncontextlost` attribute
+        pub fn set_oncontextlost(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextlost = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Cite> for ImageMapChild {
-        fn from(value: crate::generated::all::Cite) -> Self {
-            Self::Cite(value)
+        /// Get the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(&self) -> std::option::Option<&str> {
+            self.sys.oncontextmenu.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Code> for ImageMapChild {
-        fn from(value: crate::generated::all::Code) -> Self {
-            Self::Code(value)
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn set_oncontextmenu(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextmenu = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Data> for ImageMapChild {
-        fn from(value: crate::generated::all::Data) -> Self {
-            Self::Data(value)
+        /// Get the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(&self) -> std::option::Option<&str> {
+            self.sys.oncontextrestored.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::DataList> for ImageMapChild {
-        fn from(value: crate::generated::all::DataList) -> Self {
-            Self::DataList(value)
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn set_oncontextrestored(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextrestored = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Definition> for ImageMapChild {
-        fn from(value: crate::generated::all::Definition) -> Self {
-            Self::Definition(value)
+        /// Get the value of the `oncopy` attribute
+        pub fn oncopy(&self) -> std::option::Option<&str> {
+            self.sys.oncopy.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::DeletedText> for ImageMapChild {
-        fn from(value: crate::generated::all::DeletedText) -> Self {
-            Self::DeletedText(value)
+        /// Set the value of the `oncopy` attribute
+        pub fn set_oncopy(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncopy = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::DescriptionDetails>
-    for ImageMapChild {
-        fn from(value: crate::generated::all::DescriptionDetails) -> Self {
-            Self::DescriptionDetails(value)
+        /// Get the value of the `oncuechange` attribute
+        pub fn oncuechange(&self) -> std::option::Option<&str> {
+            self.sys.oncuechange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::DescriptionList> for ImageMapChild {
-        fn from(value: crate::generated::all::DescriptionList) -> Self {
-            Self::DescriptionList(value)
+        /// Set the value of the `oncuechange` attribute
+        pub fn set_oncuechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncuechange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::DescriptionTerm> for ImageMapChild {
-        fn from(value: crate::generated::all::DescriptionTerm) -> Self {
-            Self::DescriptionTerm(value)
+        /// Get the value of the `oncut` attribute
+        pub fn oncut(&self) -> std::option::Option<&str> {
+            self.sys.oncut.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Details> for ImageMapChild {
-        fn from(value: crate::generated::all::Details) -> Self {
-            Self::Details(value)
+        /// Set the value of the `oncut` attribute
+        pub fn set_oncut(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncut = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Dialog> for ImageMapChild {
-        fn from(value: crate::generated::all::Dialog) -> Self {
-            Self::Dialog(value)
+        /// Get the value of the `ondblclick` attribute
+        pub fn ondblclick(&self) -> std::option::Option<&str> {
+            self.sys.ondblclick.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Division> for ImageMapChild {
-        fn from(value: crate::generated::all::Division) -> Self {
-            Self::Division(value)
+        /// Set the value of the `ondblclick` attribute
+        pub fn set_ondblclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondblclick = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Embed> for ImageMapChild {
-        fn from(value: crate::generated::all::Embed) -> Self {
-            Self::Embed(value)
+        /// Get the value of the `ondrag` attribute
+        pub fn ondrag(&self) -> std::option::Option<&str> {
+            self.sys.ondrag.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Emphasis> for ImageMapChild {
-        fn from(value: crate::generated::all::Emphasis) -> Self {
-            Self::Emphasis(value)
+        /// Set the value of the `ondrag` attribute
+        pub fn set_ondrag(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrag = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Fieldset> for ImageMapChild {
-        fn from(value: crate::generated::all::Fieldset) -> Self {
-            Self::Fieldset(value)
+        /// Get the value of the `ondragend` attribute
+        pub fn ondragend(&self) -> std::option::Option<&str> {
+            self.sys.ondragend.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Figure> for ImageMapChild {
-        fn from(value: crate::generated::all::Figure) -> Self {
-            Self::Figure(value)
+        /// Set the value of the `ondragend` attribute
+        pub fn set_ondragend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragend = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::FigureCaption> for ImageMapChild {
-        fn from(value: crate::generated::all::FigureCaption) -> Self {
-            Self::FigureCaption(value)
+        /// Get the value of the `ondragenter` attribute
+        pub fn ondragenter(&self) -> std::option::Option<&str> {
+            self.sys.ondragenter.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Footer> for ImageMapChild {
-        fn from(value: crate::generated::all::Footer) -> Self {
-            Self::Footer(value)
+        /// Set the value of the `ondragenter` attribute
+        pub fn set_ondragenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragenter = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Form> for ImageMapChild {
-        fn from(value: crate::generated::all::Form) -> Self {
-            Self::Form(value)
+        /// Get the value of the `ondragleave` attribute
+        pub fn ondragleave(&self) -> std::option::Option<&str> {
+            self.sys.ondragleave.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Head> for ImageMapChild {
-        fn from(value: crate::generated::all::Head) -> Self {
-            Self::Head(value)
+        /// Set the value of the `ondragleave` attribute
+        pub fn set_ondragleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragleave = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Header> for ImageMapChild {
-        fn from(value: crate::generated::all::Header) -> Self {
-            Self::Header(value)
+        /// Get the value of the `ondragover` attribute
+        pub fn ondragover(&self) -> std::option::Option<&str> {
+            self.sys.ondragover.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Heading1> for ImageMapChild {
-        fn from(value: crate::generated::all::Heading1) -> Self {
-            Self::Heading1(value)
+        /// Set the value of the `ondragover` attribute
+        pub fn set_ondragover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragover = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Heading2> for ImageMapChild {
-        fn from(value: crate::generated::all::Heading2) -> Self {
-            Self::Heading2(value)
+        /// Get the value of the `ondragstart` attribute
+        pub fn ondragstart(&self) -> std::option::Option<&str> {
+            self.sys.ondragstart.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Heading3> for ImageMapChild {
-        fn from(value: crate::generated::all::Heading3) -> Self {
-            Self::Heading3(value)
+        /// Set the value of the `ondragstart` attribute
+        pub fn set_ondragstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragstart = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Heading4> for ImageMapChild {
-        fn from(value: crate::generated::all::Heading4) -> Self {
-            Self::Heading4(value)
+        /// Get the value of the `ondrop` attribute
+        pub fn ondrop(&self) -> std::option::Option<&str> {
+            self.sys.ondrop.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Heading5> for ImageMapChild {
-        fn from(value: crate::generated::all::Heading5) -> Self {
-            Self::Heading5(value)
+        /// Set the value of the `ondrop` attribute
+        pub fn set_ondrop(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrop = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Heading6> for ImageMapChild {
-        fn from(value: crate::generated::all::Heading6) -> Self {
-            Self::Heading6(value)
+        /// Get the value of the `ondurationchange` attribute
+        pub fn ondurationchange(&self) -> std::option::Option<&str> {
+            self.sys.ondurationchange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::HeadingGroup> for ImageMapChild {
-        fn from(value: crate::generated::all::HeadingGroup) -> Self {
-            Self::HeadingGroup(value)
+        /// Set the value of the `ondurationchange` attribute
+        pub fn set_ondurationchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondurationchange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Html> for ImageMapChild {
-        fn from(value: crate::generated::all::Html) -> Self {
-            Self::Html(value)
+        /// Get the value of the `onemptied` attribute
+        pub fn onemptied(&self) -> std::option::Option<&str> {
+            self.sys.onemptied.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Iframe> for ImageMapChild {
-        fn from(value: crate::generated::all::Iframe) -> Self {
-            Self::Iframe(value)
+        /// Set the value of the `onemptied` attribute
+        pub fn set_onemptied(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onemptied = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Image> for ImageMapChild {
-        fn from(value: crate::generated::all::Image) -> Self {
-            Self::Image(value)
+        /// Get the value of the `onended` attribute
+        pub fn onended(&self) -> std::option::Option<&str> {
+            self.sys.onended.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::ImageMap> for ImageMapChild {
-        fn from(value: crate::generated::all::ImageMap) -> Self {
-            Self::ImageMap(value)
+        /// Set the value of the `onended` attribute
+        pub fn set_onended(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onended = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::ImageMapArea> for ImageMapChild {
-        fn from(value: crate::generated::all::ImageMapArea) -> Self {
-            Self::ImageMapArea(value)
+        /// Get the value of the `onerror` attribute
+        pub fn onerror(&self) -> std::option::Option<&str> {
+            self.sys.onerror.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Input> for ImageMapChild {
-        fn from(value: crate::generated::all::Input) -> Self {
-            Self::Input(value)
+        /// Set the value of the `onerror` attribute
+        pub fn set_onerror(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onerror = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::InsertedText> for ImageMapChild {
-        fn from(value: crate::generated::all::InsertedText) -> Self {
-            Self::InsertedText(value)
+        /// Get the value of the `onfocus` attribute
+        pub fn onfocus(&self) -> std::option::Option<&str> {
+            self.sys.onfocus.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Italic> for ImageMapChild {
-        fn from(value: crate::generated::all::Italic) -> Self {
-            Self::Italic(value)
+        /// Set the value of the `onfocus` attribute
+        pub fn set_onfocus(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onfocus = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::KeyboardInput> for ImageMapChild {
-        fn from(value: crate::generated::all::KeyboardInput) -> Self {
-            Self::KeyboardInput(value)
+        /// Get the value of the `onformdata` attribute
+        pub fn onformdata(&self) -> std::option::Option<&str> {
+            self.sys.onformdata.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Label> for ImageMapChild {
-        fn from(value: crate::generated::all::Label) -> Self {
-            Self::Label(value)
+        /// Set the value of the `onformdata` attribute
+        pub fn set_onformdata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onformdata = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Legend> for ImageMapChild {
-        fn from(value: crate::generated::all::Legend) -> Self {
-            Self::Legend(value)
+        /// Get the value of the `oninput` attribute
+        pub fn oninput(&self) -> std::option::Option<&str> {
+            self.sys.oninput.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::LineBreak> for ImageMapChild {
-        fn from(value: crate::generated::all::LineBreak) -> Self {
-            Self::LineBreak(value)
+        /// Set the value of the `oninput` attribute
+        pub fn set_oninput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninput = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::LineBreakOpportunity>
-    for ImageMapChild {
-        fn from(value: crate::generated::all::LineBreakOpportunity) -> Self {
-            Self::LineBreakOpportunity(value)
+        /// Get the value of the `oninvalid` attribute
+        pub fn oninvalid(&self) -> std::option::Option<&str> {
+            self.sys.oninvalid.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Link> for ImageMapChild {
-        fn from(value: crate::generated::all::Link) -> Self {
-            Self::Link(value)
+        /// Set the value of the `oninvalid` attribute
+        pub fn set_oninvalid(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninvalid = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::ListItem> for ImageMapChild {
-        fn from(value: crate::generated::all::ListItem) -> Self {
-            Self::ListItem(value)
+        /// Get the value of the `onkeydown` attribute
+        pub fn onkeydown(&self) -> std::option::Option<&str> {
+            self.sys.onkeydown.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Main> for ImageMapChild {
-        fn from(value: crate::generated::all::Main) -> Self {
-            Self::Main(value)
+        /// Set the value of the `onkeydown` attribute
+        pub fn set_onkeydown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeydown = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::MarkText> for ImageMapChild {
-        fn from(value: crate::generated::all::MarkText) -> Self {
-            Self::MarkText(value)
+        /// Get the value of the `onkeypress` attribute
+        pub fn onkeypress(&self) -> std::option::Option<&str> {
+            self.sys.onkeypress.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::MediaSource> for ImageMapChild {
-        fn from(value: crate::generated::all::MediaSource) -> Self {
-            Self::MediaSource(value)
+        /// Set the value of the `onkeypress` attribute
+        pub fn set_onkeypress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeypress = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Menu> for ImageMapChild {
-        fn from(value: crate::generated::all::Menu) -> Self {
-            Self::Menu(value)
+        /// Get the value of the `onkeyup` attribute
+        pub fn onkeyup(&self) -> std::option::Option<&str> {
+            self.sys.onkeyup.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Meta> for ImageMapChild {
-        fn from(value: crate::generated::all::Meta) -> Self {
-            Self::Meta(value)
+        /// Set the value of the `onkeyup` attribute
+        pub fn set_onkeyup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeyup = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Meter> for ImageMapChild {
-        fn from(value: crate::generated::all::Meter) -> Self {
-            Self::Meter(value)
+        /// Get the value of the `onload` attribute
+        pub fn onload(&self) -> std::option::Option<&str> {
+            self.sys.onload.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Navigation> for ImageMapChild {
-        fn from(value: crate::generated::all::Navigation) -> Self {
-            Self::Navigation(value)
+        /// Set the value of the `onload` attribute
+        pub fn set_onload(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onload = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::NoScript> for ImageMapChild {
-        fn from(value: crate::generated::all::NoScript) -> Self {
-            Self::NoScript(value)
+        /// Get the value of the `onloadeddata` attribute
+        pub fn onloadeddata(&self) -> std::option::Option<&str> {
+            self.sys.onloadeddata.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Object> for ImageMapChild {
-        fn from(value: crate::generated::all::Object) -> Self {
-            Self::Object(value)
+        /// Set the value of the `onloadeddata` attribute
+        pub fn set_onloadeddata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadeddata = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Option> for ImageMapChild {
-        fn from(value: crate::generated::all::Option) -> Self {
-            Self::Option(value)
+        /// Get the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(&self) -> std::option::Option<&str> {
+            self.sys.onloadedmetadata.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::OptionGroup> for ImageMapChild {
-        fn from(value: crate::generated::all::OptionGroup) -> Self {
-            Self::OptionGroup(value)
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn set_onloadedmetadata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadedmetadata = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::OrderedList> for ImageMapChild {
-        fn from(value: crate::generated::all::OrderedList) -> Self {
-            Self::OrderedList(value)
+        /// Get the value of the `onloadstart` attribute
+        pub fn onloadstart(&self) -> std::option::Option<&str> {
+            self.sys.onloadstart.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Output> for ImageMapChild {
-        fn from(value: crate::generated::all::Output) -> Self {
-            Self::Output(value)
+        /// Set the value of the `onloadstart` attribute
+        pub fn set_onloadstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadstart = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Paragraph> for ImageMapChild {
-        fn from(value: crate::generated::all::Paragraph) -> Self {
-            Self::Paragraph(value)
+        /// Get the value of the `onmousedown` attribute
+        pub fn onmousedown(&self) -> std::option::Option<&str> {
+            self.sys.onmousedown.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Picture> for ImageMapChild {
-        fn from(value: crate::generated::all::Picture) -> Self {
-            Self::Picture(value)
+        /// Set the value of the `onmousedown` attribute
+        pub fn set_onmousedown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousedown = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::PreformattedText> for ImageMapChild {
-        fn from(value: crate::generated::all::PreformattedText) -> Self {
-            Self::PreformattedText(value)
+        /// Get the value of the `onmouseenter` attribute
+        pub fn onmouseenter(&self) -> std::option::Option<&str> {
+            self.sys.onmouseenter.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Progress> for ImageMapChild {
-        fn from(value: crate::generated::all::Progress) -> Self {
-            Self::Progress(value)
+        /// Set the value of the `onmouseenter` attribute
+        pub fn set_onmouseenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseenter = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Quotation> for ImageMapChild {
-        fn from(value: crate::generated::all::Quotation) -> Self {
-            Self::Quotation(value)
+        /// Get the value of the `onmouseleave` attribute
+        pub fn onmouseleave(&self) -> std::option::Option<&str> {
+            self.sys.onmouseleave.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::RubyAnnotation> for ImageMapChild {
-        fn from(value: crate::generated::all::RubyAnnotation) -> Self {
-            Self::RubyAnnotation(value)
+        /// Set the value of the `onmouseleave` attribute
+        pub fn set_onmouseleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseleave = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::RubyFallbackParenthesis>
-    for ImageMapChild {
-        fn from(value: crate::generated::all::RubyFallbackParenthesis) -> Self {
-            Self::RubyFallbackParenthesis(value)
+        /// Get the value of the `onmousemove` attribute
+        pub fn onmousemove(&self) -> std::option::Option<&str> {
+            self.sys.onmousemove.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::RubyText> for ImageMapChild {
-        fn from(value: crate::generated::all::RubyText) -> Self {
-            Self::RubyText(value)
+        /// Set the value of the `onmousemove` attribute
+        pub fn set_onmousemove(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousemove = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::SampleOutput> for ImageMapChild {
-        fn from(value: crate::generated::all::SampleOutput) -> Self {
-            Self::SampleOutput(value)
+        /// Get the value of the `onmouseout` attribute
+        pub fn onmouseout(&self) -> std::option::Option<&str> {
+            self.sys.onmouseout.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Script> for ImageMapChild {
-        fn from(value: crate::generated::all::Script) -> Self {
-            Self::Script(value)
+        /// Set the value of the `onmouseout` attribute
+        pub fn set_onmouseout(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseout = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Search> for ImageMapChild {
-        fn from(value: crate::generated::all::Search) -> Self {
-            Self::Search(value)
+        /// Get the value of the `onmouseover` attribute
+        pub fn onmouseover(&self) -> std::option::Option<&str> {
+            self.sys.onmouseover.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Section> for ImageMapChild {
-        fn from(value: crate::generated::all::Section) -> Self {
-            Self::Section(value)
+        /// Set the value of the `onmouseover` attribute
+        pub fn set_onmouseover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseover = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Select> for ImageMapChild {
-        fn from(value: crate::generated::all::Select) -> Self {
-            Self::Select(value)
+        /// Get the value of the `onmouseup` attribute
+        pub fn onmouseup(&self) -> std::option::Option<&str> {
+            self.sys.onmouseup.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::SideComment> for ImageMapChild {
-        fn from(value: crate::generated::all::SideComment) -> Self {
-            Self::SideComment(value)
+        /// Set the value of the `onmouseup` attribute
+        pub fn set_onmouseup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseup = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Slot> for ImageMapChild {
-        fn from(value: crate::generated::all::Slot) -> Self {
-            Self::Slot(value)
+        /// Get the value of the `onpaste` attribute
+        pub fn onpaste(&self) -> std::option::Option<&str> {
+            self.sys.onpaste.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Span> for ImageMapChild {
-        fn from(value: crate::generated::all::Span) -> Self {
-            Self::Span(value)
+        /// Set the value of the `onpaste` attribute
+        pub fn set_onpaste(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpaste = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::StrikeThrough> for ImageMapChild {
-        fn from(value: crate::generated::all::StrikeThrough) -> Self {
-            Self::StrikeThrough(value)
+        /// Get the value of the `onpause` attribute
+        pub fn onpause(&self) -> std::option::Option<&str> {
+            self.sys.onpause.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Strong> for ImageMapChild {
-        fn from(value: crate::generated::all::Strong) -> Self {
-            Self::Strong(value)
+        /// Set the value of the `onpause` attribute
+        pub fn set_onpause(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpause = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Style> for ImageMapChild {
-        fn from(value: crate::generated::all::Style) -> Self {
-            Self::Style(value)
+        /// Get the value of the `onplay` attribute
+        pub fn onplay(&self) -> std::option::Option<&str> {
+            self.sys.onplay.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::SubScript> for ImageMapChild {
-        fn from(value: crate::generated::all::SubScript) -> Self {
-            Self::SubScript(value)
+        /// Set the value of the `onplay` attribute
+        pub fn set_onplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplay = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Summary> for ImageMapChild {
-        fn from(value: crate::generated::all::Summary) -> Self {
-            Self::Summary(value)
+        /// Get the value of the `onplaying` attribute
+        pub fn onplaying(&self) -> std::option::Option<&str> {
+            self.sys.onplaying.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::SuperScript> for ImageMapChild {
-        fn from(value: crate::generated::all::SuperScript) -> Self {
-            Self::SuperScript(value)
+        /// Set the value of the `onplaying` attribute
+        pub fn set_onplaying(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplaying = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Table> for ImageMapChild {
-        fn from(value: crate::generated::all::Table) -> Self {
-            Self::Table(value)
+        /// Get the value of the `onprogress` attribute
+        pub fn onprogress(&self) -> std::option::Option<&str> {
+            self.sys.onprogress.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::TableBody> for ImageMapChild {
-        fn from(value: crate::generated::all::TableBody) -> Self {
-            Self::TableBody(value)
+        /// Set the value of the `onprogress` attribute
+        pub fn set_onprogress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onprogress = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::TableCell> for ImageMapChild {
-        fn from(value: crate::generated::all::TableCell) -> Self {
-            Self::TableCell(value)
+        /// Get the value of the `onratechange` attribute
+        pub fn onratechange(&self) -> std::option::Option<&str> {
+            self.sys.onratechange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::TableColumn> for ImageMapChild {
-        fn from(value: crate::generated::all::TableColumn) -> Self {
-            Self::TableColumn(value)
+        /// Set the value of the `onratechange` attribute
+        pub fn set_onratechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onratechange = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::TableColumnGroup> for ImageMapChild {
-        fn from(value: crate::generated::all::TableColumnGroup) -> Self {
-            Self::TableColumnGroup(value)
+        /// Get the value of the `onreset` attribute
+        pub fn onreset(&self) -> std::option::Option<&str> {
+            self.sys.onreset.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::TableFoot> for ImageMapChild {
-        fn from(value: crate::generated::all::TableFoot) -> Self {
-            Self::TableFoot(value)
+        /// Set the value of the `onreset` attribute
+        pub fn set_onreset(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onreset = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::TableHead> for ImageMapChild {
-        fn from(value: crate::generated::all::TableHead) -> Self {
-            Self::TableHead(value)
+        /// Get the value of the `onresize` attribute
+        pub fn onresize(&self) -> std::option::Option<&str> {
+            self.sys.onresize.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::TableHeader> for ImageMapChild {
-        fn from(value: crate::generated::all::TableHeader) -> Self {
-            Self::TableHeader(value)
+        /// Set the value of the `onresize` attribute
+        pub fn set_onresize(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onresize = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::TableRow> for ImageMapChild {
-        fn from(value: crate::generated::all::TableRow) -> Self {
-            Self::TableRow(value)
+        /// Get the value of the `onscroll` attribute
+        pub fn onscroll(&self) -> std::option::Option<&str> {
+            self.sys.onscroll.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Template> for ImageMapChild {
-        fn from(value: crate::generated::all::Template) -> Self {
-            Self::Template(value)
+        /// Set the value of the `onscroll` attribute
+        pub fn set_onscroll(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscroll = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<std::borrow::Cow<'static, str>> for ImageMapChild {
-        fn from(value: std::borrow::Cow<'static, str>) -> Self {
-            Self::Text(value)
+        /// Get the value of the `onscrollend` attribute
+        pub fn onscrollend(&self) -> std::option::Option<&str> {
+            self.sys.onscrollend.as_deref()
         }
-    }
-    impl std::convert::From<&'static str> for ImageMapChild {
-        fn from(value: &'static str) -> Self {
-            Self::Text(value.into())
+        /// Set the value of the `onscrollend` attribute
+        pub fn set_onscrollend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscrollend = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<String> for ImageMapChild {
-        fn from(value: String) -> Self {
-            Self::Text(value.into())
+        /// Get the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(&self) -> std::option::Option<&str> {
+            self.sys.onsecuritypolicyviolation.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::TextArea> for ImageMapChild {
-        fn from(value: crate::generated::all::TextArea) -> Self {
-            Self::TextArea(value)
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn set_onsecuritypolicyviolation(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsecuritypolicyviolation = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::TextTrack> for ImageMapChild {
-        fn from(value: crate::generated::all::TextTrack) -> Self {
-            Self::TextTrack(value)
+        /// Get the value of the `onseeked` attribute
+        pub fn onseeked(&self) -> std::option::Option<&str> {
+            self.sys.onseeked.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::ThematicBreak> for ImageMapChild {
-        fn from(value: crate::generated::all::ThematicBreak) -> Self {
-            Self::ThematicBreak(value)
+        /// Set the value of the `onseeked` attribute
+        pub fn set_onseeked(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeked = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Time> for ImageMapChild {
-        fn from(value: crate::generated::all::Time) -> Self {
-            Self::Time(value)
+        /// Get the value of the `onseeking` attribute
+        pub fn onseeking(&self) -> std::option::Option<&str> {
+            self.sys.onseeking.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Title> for ImageMapChild {
-        fn from(value: crate::generated::all::Title) -> Self {
-            Self::Title(value)
+        /// Set the value of the `onseeking` attribute
+        pub fn set_onseeking(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeking = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Underline> for ImageMapChild {
-        fn from(value: crate::generated::all::Underline) -> Self {
-            Self::Underline(value)
+        /// Get the value of the `onselect` attribute
+        pub fn onselect(&self) -> std::option::Option<&str> {
+            self.sys.onselect.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::UnorderedList> for ImageMapChild {
-        fn from(value: crate::generated::all::UnorderedList) -> Self {
-            Self::UnorderedList(value)
+        /// Set the value of the `onselect` attribute
+        pub fn set_onselect(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onselect = value.map(|v| v.into());
         }
-    }
-    impl std::convert::From<crate::generated::all::Variable> for ImageMapChild {
-        fn from(value: crate::generated::all::Variable) -> Self {
-            Self::Variable(value)
+        /// Get the value of the `onslotchange` attribute
+        pub fn onslotchange(&self) -> std::option::Option<&str> {
+            self.sys.onslotchange.as_deref()
         }
-    }
-    impl std::convert::From<crate::generated::all::Video> for ImageMapChild {
-        fn from(value: crate::generated::all::Video) -> Self {
-            Self::Video(value)
+        /// Set the value of the `onslotchange` attribute
+        pub fn set_onslotchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onslotchange = value.map(|v| v.into());
         }
-    }
-    impl crate::Render for ImageMapChild {
-        fn render(
-            &self,
-            f: &mut std::fmt::Formatter<'_>,
-            depth: usize,
-        ) -> std::fmt::Result {
-            match self {
-                Self::Abbreviation(el) => crate::Render::render(el, f, depth + 1),
-                Self::Address(el) => crate::Render::render(el, f, depth + 1),
-                Self::Anchor(el) => crate::Render::render(el, f, depth + 1),
-                Self::Article(el) => crate::Render::render(el, f, depth + 1),
-                Self::Aside(el) => crate::Render::render(el, f, depth + 1),
-                Self::Audio(el) => crate::Render::render(el, f, depth + 1),
-                Self::Base(el) => crate::Render::render(el, f, depth + 1),
-                Self::BidirectionalIsolate(el) => crate::Render::render(el, f, depth + 1),
-                Self::BidirectionalTextOverride(el) => {
-                    crate::Render::render(el, f, depth + 1)
-                }
-                Self::BlockQuote(el) => crate::Render::render(el, f, depth + 1),
-                Self::Body(el) => crate::Render::render(el, f, depth + 1),
-                Self::Bold(el) => crate::Render::render(el, f, depth + 1),
-                Self::Button(el) => crate::Render::render(el, f, depth + 1),
-                Self::Canvas(el) => crate::Render::render(el, f, depth + 1),
-                Self::Caption(el) => crate::Render::render(el, f, depth + 1),
-                Self::Cite(el) => crate::Render::render(el, f, depth + 1),
-                Self::Code(el) => crate::Render::render(el, f, depth + 1),
-                Self::Data(el) => crate::Render::render(el, f, depth + 1),
-                Self::DataList(el) => crate::Render::render(el, f, depth + 1),
-                Self::Definition(el) => crate::Render::render(el, f, depth + 1),
-                Self::DeletedText(el) => crate::Render::render(el, f, depth + 1),
-                Self::DescriptionDetails(el) => crate::Render::render(el, f, depth + 1),
-                Self::DescriptionList(el) => crate::Render::render(el, f, depth + 1),
-                Self::DescriptionTerm(el) => crate::Render::render(el, f, depth + 1),
-                Self::Details(el) => crate::Render::render(el, f, depth + 1),
-                Self::Dialog(el) => crate::Render::render(el, f, depth + 1),
-                Self::Division(el) => crate::Render::render(el, f, depth + 1),
-                Self::Embed(el) => crate::Render::render(el, f, depth + 1),
-                Self::Emphasis(el) => crate::Render::render(el, f, depth + 1),
-                Self::Fieldset(el) => crate::Render::render(el, f, depth + 1),
-                Self::Figure(el) => crate::Render::render(el, f, depth + 1),
-                Self::FigureCaption(el) => crate::Render::render(el, f, depth + 1),
-                Self::Footer(el) => crate::Render::render(el, f, depth + 1),
-                Self::Form(el) => crate::Render::render(el, f, depth + 1),
-                Self::Head(el) => crate::Render::render(el, f, depth + 1),
-                Self::Header(el) => crate::Render::render(el, f, depth + 1),
-                Self::Heading1(el) => crate::Render::render(el, f, depth + 1),
-                Self::Heading2(el) => crate::Render::render(el, f, depth + 1),
-                Self::Heading3(el) => crate::Render::render(el, f, depth + 1),
-                Self::Heading4(el) => crate::Render::render(el, f, depth + 1),
-                Self::Heading5(el) => crate::Render::render(el, f, depth + 1),
-                Self::Heading6(el) => crate::Render::render(el, f, depth + 1),
-                Self::HeadingGroup(el) => crate::Render::render(el, f, depth + 1),
-                Self::Html(el) => crate::Render::render(el, f, depth + 1),
-                Self::Iframe(el) => crate::Render::render(el, f, depth + 1),
-                Self::Image(el) => crate::Render::render(el, f, depth + 1),
-                Self::ImageMap(el) => crate::Render::render(el, f, depth + 1),
-                Self::ImageMapArea(el) => crate::Render::render(el, f, depth + 1),
-                Self::Input(el) => crate::Render::render(el, f, depth + 1),
-                Self::InsertedText(el) => crate::Render::render(el, f, depth + 1),
-                Self::Italic(el) => crate::Render::render(el, f, depth + 1),
-                Self::KeyboardInput(el) => crate::Render::render(el, f, depth + 1),
-                Self::Label(el) => crate::Render::render(el, f, depth + 1),
-                Self::Legend(el) => crate::Render::render(el, f, depth + 1),
-                Self::LineBreak(el) => crate::Render::render(el, f, depth + 1),
-                Self::LineBreakOpportunity(el) => crate::Render::render(el, f, depth + 1),
-                Self::Link(el) => crate::Render::render(el, f, depth + 1),
-                Self::ListItem(el) => crate::Render::render(el, f, depth + 1),
-                Self::Main(el) => crate::Render::render(el, f, depth + 1),
-                Self::MarkText(el) => crate::Render::render(el, f, depth + 1),
-                Self::MediaSource(el) => crate::Render::render(el, f, depth + 1),
-                Self::Menu(el) => crate::Render::render(el, f, depth + 1),
-                Self::Meta(el) => crate::Render::render(el, f, depth + 1),
-                Self::Meter(el) => crate::Render::render(el, f, depth + 1),
-                Self::Navigation(el) => crate::Render::render(el, f, depth + 1),
-                Self::NoScript(el) => crate::Render::render(el, f, depth + 1),
-                Self::Object(el) => crate::Render::render(el, f, depth + 1),
-                Self::Option(el) => crate::Render::render(el, f, depth + 1),
-                Self::OptionGroup(el) => crate::Render::render(el, f, depth + 1),
-                Self::OrderedList(el) => crate::Render::render(el, f, depth + 1),
-                Self::Output(el) => crate::Render::render(el, f, depth + 1),
-                Self::Paragraph(el) => crate::Render::render(el, f, depth + 1),
-                Self::Picture(el) => crate::Render::render(el, f, depth + 1),
-                Self::PreformattedText(el) => crate::Render::render(el, f, depth + 1),
-                Self::Progress(el) => crate::Render::render(el, f, depth + 1),
-                Self::Quotation(el) => crate::Render::render(el, f, depth + 1),
-                Self::RubyAnnotation(el) => crate::Render::render(el, f, depth + 1),
-                Self::RubyFallbackParenthesis(el) => {
-                    crate::Render::render(el, f, depth + 1)
-                }
-                Self::RubyText(el) => crate::Render::render(el, f, depth + 1),
-                Self::SampleOutput(el) => crate::Render::render(el, f, depth + 1),
-                Self::Script(el) => crate::Render::render(el, f, depth + 1),
-                Self::Search(el) => crate::Render::render(el, f, depth + 1),
-                Self::Section(el) => crate::Render::render(el, f, depth + 1),
-                Self::Select(el) => crate::Render::render(el, f, depth + 1),
-                Self::SideComment(el) => crate::Render::render(el, f, depth + 1),
-                Self::Slot(el) => crate::Render::render(el, f, depth + 1),
-                Self::Span(el) => crate::Render::render(el, f, depth + 1),
-                Self::StrikeThrough(el) => crate::Render::render(el, f, depth + 1),
-                Self::Strong(el) => crate::Render::render(el, f, depth + 1),
-                Self::Style(el) => crate::Render::render(el, f, depth + 1),
-                Self::SubScript(el) => crate::Render::render(el, f, depth + 1),
-                Self::Summary(el) => crate::Render::render(el, f, depth + 1),
-                Self::SuperScript(el) => crate::Render::render(el, f, depth + 1),
-                Self::Table(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableBody(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableCell(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableColumn(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableColumnGroup(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableFoot(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableHead(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableHeader(el) => crate::Render::render(el, f, depth + 1),
-                Self::TableRow(el) => crate::Render::render(el, f, depth + 1),
-                Self::Template(el) => crate::Render::render(el, f, depth + 1),
-                Self::Text(el) => crate::Render::render(el, f, depth + 1),
-                Self::TextArea(el) => crate::Render::render(el, f, depth + 1),
-                Self::TextTrack(el) => crate::Render::render(el, f, depth + 1),
-                Self::ThematicBreak(el) => crate::Render::render(el, f, depth + 1),
-                Self::Time(el) => crate::Render::render(el, f, depth + 1),
-                Self::Title(el) => crate::Render::render(el, f, depth + 1),
-                Self::Underline(el) => crate::Render::render(el, f, depth + 1),
-                Self::UnorderedList(el) => crate::Render::render(el, f, depth + 1),
-                Self::Variable(el) => crate::Render::render(el, f, depth + 1),
-                Self::Video(el) => crate::Render::render(el, f, depth + 1),
-            }
+        /// Get the value of the `onstalled` attribute
+        pub fn onstalled(&self) -> std::option::Option<&str> {
+            self.sys.onstalled.as_deref()
         }
-    }
-    impl std::fmt::Debug for ImageMapChild {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            crate::Render::render(self, f, 0)?;
-            Ok(())
+        /// Set the value of the `onstalled` attribute
+        pub fn set_onstalled(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onstalled = value.map(|v| v.into());
         }
-    }
-    impl std::fmt::Display for ImageMapChild {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                Self::Abbreviation(el) => write!(f, "{el}"),
-                Self::Address(el) => write!(f, "{el}"),
-                Self::Anchor(el) => write!(f, "{el}"),
-                Self::Article(el) => write!(f, "{el}"),
-                Self::Aside(el) => write!(f, "{el}"),
-                Self::Audio(el) => write!(f, "{el}"),
-                Self::Base(el) => write!(f, "{el}"),
-                Self::BidirectionalIsolate(el) => write!(f, "{el}"),
-                Self::BidirectionalTextOverride(el) => write!(f, "{el}"),
-                Self::BlockQuote(el) => write!(f, "{el}"),
-                Self::Body(el) => write!(f, "{el}"),
-                Self::Bold(el) => write!(f, "{el}"),
-                Self::Button(el) => write!(f, "{el}"),
-                Self::Canvas(el) => write!(f, "{el}"),
-                Self::Caption(el) => write!(f, "{el}"),
-                Self::Cite(el) => write!(f, "{el}"),
-                Self::Code(el) => write!(f, "{el}"),
-                Self::Data(el) => write!(f, "{el}"),
-                Self::DataList(el) => write!(f, "{el}"),
-                Self::Definition(el) => write!(f, "{el}"),
-                Self::DeletedText(el) => write!(f, "{el}"),
-                Self::DescriptionDetails(el) => write!(f, "{el}"),
-                Self::DescriptionList(el) => write!(f, "{el}"),
-                Self::DescriptionTerm(el) => write!(f, "{el}"),
-                Self::Details(el) => write!(f, "{el}"),
-                Self::Dialog(el) => write!(f, "{el}"),
-                Self::Division(el) => write!(f, "{el}"),
-                Self::Embed(el) => write!(f, "{el}"),
-                Self::Emphasis(el) => write!(f, "{el}"),
-                Self::Fieldset(el) => write!(f, "{el}"),
-                Self::Figure(el) => write!(f, "{el}"),
-                Self::FigureCaption(el) => write!(f, "{el}"),
-                Self::Footer(el) => write!(f, "{el}"),
-                Self::Form(el) => write!(f, "{el}"),
-                Self::Head(el) => write!(f, "{el}"),
-                Self::Header(el) => write!(f, "{el}"),
-                Self::Heading1(el) => write!(f, "{el}"),
-                Self::Heading2(el) => write!(f, "{el}"),
-                Self::Heading3(el) => write!(f, "{el}"),
-                Self::Heading4(el) => write!(f, "{el}"),
-                Self::Heading5(el) => write!(f, "{el}"),
-                Self::Heading6(el) => write!(f, "{el}"),
-                Self::HeadingGroup(el) => write!(f, "{el}"),
-                Self::Html(el) => write!(f, "{el}"),
-                Self::Iframe(el) => write!(f, "{el}"),
-                Self::Image(el) => write!(f, "{el}"),
-                Self::ImageMap(el) => write!(f, "{el}"),
-                Self::ImageMapArea(el) => write!(f, "{el}"),
-                Self::Input(el) => write!(f, "{el}"),
-                Self::InsertedText(el) => write!(f, "{el}"),
-                Self::Italic(el) => write!(f, "{el}"),
-                Self::KeyboardInput(el) => write!(f, "{el}"),
-                Self::Label(el) => write!(f, "{el}"),
-                Self::Legend(el) => write!(f, "{el}"),
-                Self::LineBreak(el) => write!(f, "{el}"),
-                Self::LineBreakOpportunity(el) => write!(f, "{el}"),
-                Self::Link(el) => write!(f, "{el}"),
-                Self::ListItem(el) => write!(f, "{el}"),
-                Self::Main(el) => write!(f, "{el}"),
-                Self::MarkText(el) => write!(f, "{el}"),
-                Self::MediaSource(el) => write!(f, "{el}"),
-                Self::Menu(el) => write!(f, "{el}"),
-                Self::Meta(el) => write!(f, "{el}"),
-                Self::Meter(el) => write!(f, "{el}"),
-                Self::Navigation(el) => write!(f, "{el}"),
-                Self::NoScript(el) => write!(f, "{el}"),
-                Self::Object(el) => write!(f, "{el}"),
-                Self::Option(el) => write!(f, "{el}"),
-                Self::OptionGroup(el) => write!(f, "{el}"),
-                Self::OrderedList(el) => write!(f, "{el}"),
-                Self::Output(el) => write!(f, "{el}"),
-                Self::Paragraph(el) => write!(f, "{el}"),
-                Self::Picture(el) => write!(f, "{el}"),
-                Self::PreformattedText(el) => write!(f, "{el}"),
-                Self::Progress(el) => write!(f, "{el}"),
-                Self::Quotation(el) => write!(f, "{el}"),
-                Self::RubyAnnotation(el) => write!(f, "{el}"),
-                Self::RubyFallbackParenthesis(el) => write!(f, "{el}"),
-                Self::RubyText(el) => write!(f, "{el}"),
-                Self::SampleOutput(el) => write!(f, "{el}"),
-                Self::Script(el) => write!(f, "{el}"),
-                Self::Search(el) => write!(f, "{el}"),
-                Self::Section(el) => write!(f, "{el}"),
-                Self::Select(el) => write!(f, "{el}"),
-                Self::SideComment(el) => write!(f, "{el}"),
-                Self::Slot(el) => write!(f, "{el}"),
-                Self::Span(el) => write!(f, "{el}"),
-                Self::StrikeThrough(el) => write!(f, "{el}"),
-                Self::Strong(el) => write!(f, "{el}"),
-                Self::Style(el) => write!(f, "{el}"),
-                Self::SubScript(el) => write!(f, "{el}"),
-                Self::Summary(el) => write!(f, "{el}"),
-                Self::SuperScript(el) => write!(f, "{el}"),
-                Self::Table(el) => write!(f, "{el}"),
-                Self::TableBody(el) => write!(f, "{el}"),
-                Self::TableCell(el) => write!(f, "{el}"),
-                Self::TableColumn(el) => write!(f, "{el}"),
-                Self::TableColumnGroup(el) => write!(f, "{el}"),
-                Self::TableFoot(el) => write!(f, "{el}"),
-                Self::TableHead(el) => write!(f, "{el}"),
-                Self::TableHeader(el) => write!(f, "{el}"),
-                Self::TableRow(el) => write!(f, "{el}"),
-                Self::Template(el) => write!(f, "{el}"),
-                Self::Text(el) => write!(f, "{el}"),
-                Self::TextArea(el) => write!(f, "{el}"),
-                Self::TextTrack(el) => write!(f, "{el}"),
-                Self::ThematicBreak(el) => write!(f, "{el}"),
-                Self::Time(el) => write!(f, "{el}"),
-                Self::Title(el) => write!(f, "{el}"),
-                Self::Underline(el) => write!(f, "{el}"),
-                Self::UnorderedList(el) => write!(f, "{el}"),
-                Self::Variable(el) => write!(f, "{el}"),
-                Self::Video(el) => write!(f, "{el}"),
+        /// Get the value of the `onsubmit` attribute
+        pub fn onsubmit(&self) -> std::option::Option<&str> {
+            self.sys.onsubmit.as_deref()
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn set_onsubmit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsubmit = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsuspend` attribute
+        pub fn onsuspend(&self) -> std::option::Option<&str> {
+            self.sys.onsuspend.as_deref()
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn set_onsuspend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsuspend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(&self) -> std::option::Option<&str> {
+            self.sys.ontimeupdate.as_deref()
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn set_ontimeupdate(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontimeupdate = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontoggle` attribute
+        pub fn ontoggle(&self) -> std::option::Option<&str> {
+            self.sys.ontoggle.as_deref()
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn set_ontoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontoggle = value.map(|v| v.into());
+        }
+        /// Get the value of the `onvolumechange` attribute
+        pub fn onvolumechange(&self) -> std::option::Option<&str> {
+            self.sys.onvolumechange.as_deref()
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn set_onvolumechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onvolumechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwaiting` attribute
+        pub fn onwaiting(&self) -> std::option::Option<&str> {
+            self.sys.onwaiting.as_deref()
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn set_onwaiting(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwaiting = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationend.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn set_onwebkitanimationend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationiteration.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn set_onwebkitanimationiteration(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationiteration = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationstart.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn set_onwebkitanimationstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkittransitionend.as_deref()
+        }
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn set_onwebkittransitionend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkittransitionend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwheel` attribute
+        pub fn onwheel(&self) -> std::option::Option<&str> {
+            self.sys.onwheel.as_deref()
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn set_onwheel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwheel = value.map(|v| v.into());
+        }
+    }
+    impl ImageMap {
+        /// Access the element's children
+        pub fn children(&self) -> &[super::child::ImageMapChild] {
+            self.children.as_ref()
+        }
+        /// Mutably access the element's children
+        pub fn children_mut(&mut self) -> &mut Vec<super::child::ImageMapChild> {
+            &mut self.children
+        }
+    }
+    impl std::fmt::Debug for ImageMap {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for ImageMap {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
+            for el in &self.children {
+                write!(f, "{el}")?;
             }
+            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
+            Ok(())
         }
     }
-    impl<'a> From<&'a ImageMapChild> for crate::Node<'a> {
-        fn from(child: &'a ImageMapChild) -> Self {
-            match child {
-                ImageMapChild::Abbreviation(el) => crate::Node::from(el),
-                ImageMapChild::Address(el) => crate::Node::from(el),
-                ImageMapChild::Anchor(el) => crate::Node::from(el),
-                ImageMapChild::Article(el) => crate::Node::from(el),
-                ImageMapChild::Aside(el) => crate::Node::from(el),
-                ImageMapChild::Audio(el) => crate::Node::from(el),
-                ImageMapChild::Base(el) => crate::Node::from(el),
-                ImageMapChild::BidirectionalIsolate(el) => crate::Node::from(el),
-                ImageMapChild::BidirectionalTextOverride(el) => crate::Node::from(el),
-                ImageMapChild::BlockQuote(el) => crate::Node::from(el),
-                ImageMapChild::Body(el) => crate::Node::from(el),
-                ImageMapChild::Bold(el) => crate::Node::from(el),
-                ImageMapChild::Button(el) => crate::Node::from(el),
-                ImageMapChild::Canvas(el) => crate::Node::from(el),
-                ImageMapChild::Caption(el) => crate::Node::from(el),
-                ImageMapChild::Cite(el) => crate::Node::from(el),
-                ImageMapChild::Code(el) => crate::Node::from(el),
-                ImageMapChild::Data(el) => crate::Node::from(el),
-                ImageMapChild::DataList(el) => crate::Node::from(el),
-                ImageMapChild::Definition(el) => crate::Node::from(el),
-                ImageMapChild::DeletedText(el) => crate::Node::from(el),
-                ImageMapChild::DescriptionDetails(el) => crate::Node::from(el),
-                ImageMapChild::DescriptionList(el) => crate::Node::from(el),
-                ImageMapChild::DescriptionTerm(el) => crate::Node::from(el),
-                ImageMapChild::Details(el) => crate::Node::from(el),
-                ImageMapChild::Dialog(el) => crate::Node::from(el),
-                ImageMapChild::Division(el) => crate::Node::from(el),
-                ImageMapChild::Embed(el) => crate::Node::from(el),
-                ImageMapChild::Emphasis(el) => crate::Node::from(el),
-                ImageMapChild::Fieldset(el) => crate::Node::from(el),
-                ImageMapChild::Figure(el) => crate::Node::from(el),
-                ImageMapChild::FigureCaption(el) => crate::Node::from(el),
-                ImageMapChild::Footer(el) => crate::Node::from(el),
-                ImageMapChild::Form(el) => crate::Node::from(el),
-                ImageMapChild::Head(el) => crate::Node::from(el),
-                ImageMapChild::Header(el) => crate::Node::from(el),
-                ImageMapChild::Heading1(el) => crate::Node::from(el),
-                ImageMapChild::Heading2(el) => crate::Node::from(el),
-                ImageMapChild::Heading3(el) => crate::Node::from(el),
-                ImageMapChild::Heading4(el) => crate::Node::from(el),
-                ImageMapChild::Heading5(el) => crate::Node::from(el),
-                ImageMapChild::Heading6(el) => crate::Node::from(el),
-                ImageMapChild::HeadingGroup(el) => crate::Node::from(el),
-                ImageMapChild::Html(el) => crate::Node::from(el),
-                ImageMapChild::Iframe(el) => crate::Node::from(el),
-                ImageMapChild::Image(el) => crate::Node::from(el),
-                ImageMapChild::ImageMap(el) => crate::Node::from(el),
-                ImageMapChild::ImageMapArea(el) => crate::Node::from(el),
-                ImageMapChild::Input(el) => crate::Node::from(el),
-                ImageMapChild::InsertedText(el) => crate::Node::from(el),
-                ImageMapChild::Italic(el) => crate::Node::from(el),
-                ImageMapChild::KeyboardInput(el) => crate::Node::from(el),
-                ImageMapChild::Label(el) => crate::Node::from(el),
-                ImageMapChild::Legend(el) => crate::Node::from(el),
-                ImageMapChild::LineBreak(el) => crate::Node::from(el),
-                ImageMapChild::LineBreakOpportunity(el) => crate::Node::from(el),
-                ImageMapChild::Link(el) => crate::Node::from(el),
-                ImageMapChild::ListItem(el) => crate::Node::from(el),
-                ImageMapChild::Main(el) => crate::Node::from(el),
-                ImageMapChild::MarkText(el) => crate::Node::from(el),
-                ImageMapChild::MediaSource(el) => crate::Node::from(el),
-                ImageMapChild::Menu(el) => crate::Node::from(el),
-                ImageMapChild::Meta(el) => crate::Node::from(el),
-                ImageMapChild::Meter(el) => crate::Node::from(el),
-                ImageMapChild::Navigation(el) => crate::Node::from(el),
-                ImageMapChild::NoScript(el) => crate::Node::from(el),
-                ImageMapChild::Object(el) => crate::Node::from(el),
-                ImageMapChild::Option(el) => crate::Node::from(el),
-                ImageMapChild::OptionGroup(el) => crate::Node::from(el),
-                ImageMapChild::OrderedList(el) => crate::Node::from(el),
-                ImageMapChild::Output(el) => crate::Node::from(el),
-                ImageMapChild::Paragraph(el) => crate::Node::from(el),
-                ImageMapChild::Picture(el) => crate::Node::from(el),
-                ImageMapChild::PreformattedText(el) => crate::Node::from(el),
-                ImageMapChild::Progress(el) => crate::Node::from(el),
-                ImageMapChild::Quotation(el) => crate::Node::from(el),
-                ImageMapChild::RubyAnnotation(el) => crate::Node::from(el),
-                ImageMapChild::RubyFallbackParenthesis(el) => crate::Node::from(el),
-                ImageMapChild::RubyText(el) => crate::Node::from(el),
-                ImageMapChild::SampleOutput(el) => crate::Node::from(el),
-                ImageMapChild::Script(el) => crate::Node::from(el),
-                ImageMapChild::Search(el) => crate::Node::from(el),
-                ImageMapChild::Section(el) => crate::Node::from(el),
-                ImageMapChild::Select(el) => crate::Node::from(el),
-                ImageMapChild::SideComment(el) => crate::Node::from(el),
-                ImageMapChild::Slot(el) => crate::Node::from(el),
-                ImageMapChild::Span(el) => crate::Node::from(el),
-                ImageMapChild::StrikeThrough(el) => crate::Node::from(el),
-                ImageMapChild::Strong(el) => crate::Node::from(el),
-                ImageMapChild::Style(el) => crate::Node::from(el),
-                ImageMapChild::SubScript(el) => crate::Node::from(el),
-                ImageMapChild::Summary(el) => crate::Node::from(el),
-                ImageMapChild::SuperScript(el) => crate::Node::from(el),
-                ImageMapChild::Table(el) => crate::Node::from(el),
-                ImageMapChild::TableBody(el) => crate::Node::from(el),
-                ImageMapChild::TableCell(el) => crate::Node::from(el),
-                ImageMapChild::TableColumn(el) => crate::Node::from(el),
-                ImageMapChild::TableColumnGroup(el) => crate::Node::from(el),
-                ImageMapChild::TableFoot(el) => crate::Node::from(el),
-                ImageMapChild::TableHead(el) => crate::Node::from(el),
-                ImageMapChild::TableHeader(el) => crate::Node::from(el),
-                ImageMapChild::TableRow(el) => crate::Node::from(el),
-                ImageMapChild::Template(el) => crate::Node::from(el),
-                ImageMapChild::Text(el) => crate::Node::from(el),
-                ImageMapChild::TextArea(el) => crate::Node::from(el),
-                ImageMapChild::TextTrack(el) => crate::Node::from(el),
-                ImageMapChild::ThematicBreak(el) => crate::Node::from(el),
-                ImageMapChild::Time(el) => crate::Node::from(el),
-                ImageMapChild::Title(el) => crate::Node::from(el),
-                ImageMapChild::Underline(el) => crate::Node::from(el),
-                ImageMapChild::UnorderedList(el) => crate::Node::from(el),
-                ImageMapChild::Variable(el) => crate::Node::from(el),
-                ImageMapChild::Video(el) => crate::Node::from(el),
+    impl crate::HtmlElement for ImageMap {
+        fn tag_name(&self) -> &'static str {
+            "map"
+        }
+        fn attributes(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.attributes()
+        }
+        fn data(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.data()
+        }
+        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
+            self.children.iter().map(From::from).collect()
+        }
+    }
+    impl crate::FlowContent for ImageMap {}
+    impl crate::PhrasingContent for ImageMap {}
+    impl crate::PalpableContent for ImageMap {}
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromHtml for ImageMap {
+        const TAG_NAME: &'static str = "map";
+        fn from_html(
+            element: crate::parse::Element<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Self {
+            let mut this: Self = Default::default();
+            for (name, value) in element.attributes() {
+                match name {
+                    "name" => this.set_name(Some(value.to_owned())),
+                    "accesskey" => this.set_access_key(Some(value.to_owned())),
+                    "autocapitalize" => {
+                        this.set_auto_capitalize(cx.parse_attribute(name, value))
+                    }
+                    "autofocus" => this.set_autofocus(true),
+                    "class" => this.set_class(Some(value.to_owned())),
+                    "contenteditable" => {
+                        this.set_content_editable(cx.parse_attribute(name, value))
+                    }
+                    "dir" => this.set_direction(cx.parse_attribute(name, value)),
+                    "draggable" => this.set_draggable(cx.parse_attribute(name, value)),
+                    "enterkeyhint" => {
+                        this.set_enter_key_hint(cx.parse_attribute(name, value))
+                    }
+                    "exportparts" => this.set_export_parts(Some(value.to_owned())),
+                    "hidden" => this.set_hidden(cx.parse_attribute(name, value)),
+                    "id" => this.set_id(Some(value.to_owned())),
+                    "inert" => this.set_inert(true),
+                    "inputmode" => this.set_input_mode(cx.parse_attribute(name, value)),
+                    "is" => this.set_is_(Some(value.to_owned())),
+                    "itemid" => this.set_item_id(Some(value.to_owned())),
+                    "itemprop" => this.set_item_prop(Some(value.to_owned())),
+                    "itemref" => this.set_item_ref(Some(value.to_owned())),
+                    "itemscope" => this.set_item_scope(true),
+                    "itemtype" => this.set_item_type(Some(value.to_owned())),
+                    "lang" => this.set_lang(Some(value.to_owned())),
+                    "nonce" => this.set_nonce(Some(value.to_owned())),
+                    "part" => this.set_part(Some(value.to_owned())),
+                    "slot" => this.set_slot(Some(value.to_owned())),
+                    "spellcheck" => this.set_spellcheck(cx.parse_attribute(name, value)),
+                    "style" => this.set_style(Some(value.to_owned())),
+                    "tabindex" => this.set_tab_index(cx.parse_attribute(name, value)),
+                    "title" => this.set_title(Some(value.to_owned())),
+                    "translate" => this.set_translate(cx.parse_attribute(name, value)),
+                    "onabort" => this.set_onabort(Some(value.to_owned())),
+                    "onauxclick" => this.set_onauxclick(Some(value.to_owned())),
+                    "onbeforeinput" => this.set_onbeforeinput(Some(value.to_owned())),
+                    "onbeforematch" => this.set_onbeforematch(Some(value.to_owned())),
+                    "onbeforetoggle" => this.set_onbeforetoggle(Some(value.to_owned())),
+                    "onblur" => this.set_onblur(Some(value.to_owned())),
+                    "oncancel" => this.set_oncancel(Some(value.to_owned())),
+                    "oncanplay" => this.set_oncanplay(Some(value.to_owned())),
+                    "oncanplaythrough" => {
+                        this.set_oncanplaythrough(Some(value.to_owned()))
+                    }
+                    "onchange" => this.set_onchange(Some(value.to_owned())),
+                    "onclick" => this.set_onclick(Some(value.to_owned())),
+                    "onclose" => this.set_onclose(Some(value.to_owned())),
+                    "oncontextlost" => this.set_oncontextlost(Some(value.to_owned())),
+                    "oncontextmenu" => this.set_oncontextmenu(Some(value.to_owned())),
+                    "oncontextrestored" => {
+                        this.set_oncontextrestored(Some(value.to_owned()))
+                    }
+                    "oncopy" => this.set_oncopy(Some(value.to_owned())),
+                    "oncuechange" => this.set_oncuechange(Some(value.to_owned())),
+                    "oncut" => this.set_oncut(Some(value.to_owned())),
+                    "ondblclick" => this.set_ondblclick(Some(value.to_owned())),
+                    "ondrag" => this.set_ondrag(Some(value.to_owned())),
+                    "ondragend" => this.set_ondragend(Some(value.to_owned())),
+                    "ondragenter" => this.set_ondragenter(Some(value.to_owned())),
+                    "ondragleave" => this.set_ondragleave(Some(value.to_owned())),
+                    "ondragover" => this.set_ondragover(Some(value.to_owned())),
+                    "ondragstart" => this.set_ondragstart(Some(value.to_owned())),
+                    "ondrop" => this.set_ondrop(Some(value.to_owned())),
+                    "ondurationchange" => {
+                        this.set_ondurationchange(Some(value.to_owned()))
+                    }
+                    "onemptied" => this.set_onemptied(Some(value.to_owned())),
+                    "onended" => this.set_onended(Some(value.to_owned())),
+                    "onerror" => this.set_onerror(Some(value.to_owned())),
+                    "onfocus" => this.set_onfocus(Some(value.to_owned())),
+                    "onformdata" => this.set_onformdata(Some(value.to_owned())),
+                    "oninput" => this.set_oninput(Some(value.to_owned())),
+                    "oninvalid" => this.set_oninvalid(Some(value.to_owned())),
+                    "onkeydown" => this.set_onkeydown(Some(value.to_owned())),
+                    "onkeypress" => this.set_onkeypress(Some(value.to_owned())),
+                    "onkeyup" => this.set_onkeyup(Some(value.to_owned())),
+                    "onload" => this.set_onload(Some(value.to_owned())),
+                    "onloadeddata" => this.set_onloadeddata(Some(value.to_owned())),
+                    "onloadedmetadata" => {
+                        this.set_onloadedmetadata(Some(value.to_owned()))
+                    }
+                    "onloadstart" => this.set_onloadstart(Some(value.to_owned())),
+                    "onmousedown" => this.set_onmousedown(Some(value.to_owned())),
+                    "onmouseenter" => this.set_onmouseenter(Some(value.to_owned())),
+                    "onmouseleave" => this.set_onmouseleave(Some(value.to_owned())),
+                    "onmousemove" => this.set_onmousemove(Some(value.to_owned())),
+                    "onmouseout" => this.set_onmouseout(Some(value.to_owned())),
+                    "onmouseover" => this.set_onmouseover(Some(value.to_owned())),
+                    "onmouseup" => this.set_onmouseup(Some(value.to_owned())),
+                    "onpaste" => this.set_onpaste(Some(value.to_owned())),
+                    "onpause" => this.set_onpause(Some(value.to_owned())),
+                    "onplay" => this.set_onplay(Some(value.to_owned())),
+                    "onplaying" => this.set_onplaying(Some(value.to_owned())),
+                    "onprogress" => this.set_onprogress(Some(value.to_owned())),
+                    "onratechange" => this.set_onratechange(Some(value.to_owned())),
+                    "onreset" => this.set_onreset(Some(value.to_owned())),
+                    "onresize" => this.set_onresize(Some(value.to_owned())),
+                    "onscroll" => this.set_onscroll(Some(value.to_owned())),
+                    "onscrollend" => this.set_onscrollend(Some(value.to_owned())),
+                    "onsecuritypolicyviolation" => {
+                        this.set_onsecuritypolicyviolation(Some(value.to_owned()))
+                    }
+                    "onseeked" => this.set_onseeked(Some(value.to_owned())),
+                    "onseeking" => this.set_onseeking(Some(value.to_owned())),
+                    "onselect" => this.set_onselect(Some(value.to_owned())),
+                    "onslotchange" => this.set_onslotchange(Some(value.to_owned())),
+                    "onstalled" => this.set_onstalled(Some(value.to_owned())),
+                    "onsubmit" => this.set_onsubmit(Some(value.to_owned())),
+                    "onsuspend" => this.set_onsuspend(Some(value.to_owned())),
+                    "ontimeupdate" => this.set_ontimeupdate(Some(value.to_owned())),
+                    "ontoggle" => this.set_ontoggle(Some(value.to_owned())),
+                    "onvolumechange" => this.set_onvolumechange(Some(value.to_owned())),
+                    "onwaiting" => this.set_onwaiting(Some(value.to_owned())),
+                    "onwebkitanimationend" => {
+                        this.set_onwebkitanimationend(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationiteration" => {
+                        this.set_onwebkitanimationiteration(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationstart" => {
+                        this.set_onwebkitanimationstart(Some(value.to_owned()))
+                    }
+                    "onwebkittransitionend" => {
+                        this.set_onwebkittransitionend(Some(value.to_owned()))
+                    }
+                    "onwheel" => this.set_onwheel(Some(value.to_owned())),
+                    _ => cx.other_attribute(this.data_map_mut(), name, value),
+                }
+            }
+            this.children = cx.children(element);
+            this
+        }
+    }
+    impl crate::tree::Element for ImageMap {
+        fn tag_name(&self) -> &'static str {
+            "map"
+        }
+        fn is_inline(&self) -> bool {
+            true
+        }
+        fn is_preformatted(&self) -> bool {
+            false
+        }
+        fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {
+            html_sys::RenderElement::write_opening_tag(&self.sys, writer)
+        }
+        fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {
+            html_sys::RenderElement::write_closing_tag(&self.sys, writer)
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+            self.children.get(index).map(From::from)
+        }
+    }
+    impl<'a> From<&'a ImageMap> for crate::tree::Part<'a> {
+        fn from(element: &'a ImageMap) -> Self {
+            crate::tree::Part::Element(element)
+        }
+    }
+    impl<'a> From<&'a ImageMap> for crate::stream::Chunks<'a> {
+        fn from(element: &'a ImageMap) -> Self {
+            crate::stream::Chunks::new(element)
+        }
+    }
+    impl<'a> From<&'a ImageMap> for crate::render::Pretty<'a> {
+        fn from(element: &'a ImageMap) -> Self {
+            crate::render::Pretty::new(element.into())
+        }
+    }
+    impl<'a> From<&'a ImageMap> for crate::minify::Minified<'a> {
+        fn from(element: &'a ImageMap) -> Self {
+            crate::minify::Minified::new(element.into())
+        }
+    }
+    impl crate::visit::Visit for ImageMap {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn eq_element(&self, other: &dyn crate::visit::Visit) -> bool {
+            other.downcast_ref::<Self>() == Some(self)
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::visit::NodeRef<'_>> {
+            self.children.get(index).map(From::from)
+        }
+        fn child_mut(
+            &mut self,
+            index: usize,
+        ) -> std::option::Option<crate::visit::NodeMut<'_>> {
+            self.children.get_mut(index).map(From::from)
+        }
+        fn retain_children(
+            &mut self,
+            f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool,
+        ) {
+            self.children.retain(|child| f(child.into()));
+        }
+        fn insert_child(
+            &mut self,
+            index: usize,
+            node: crate::visit::NodeRef<'_>,
+        ) -> bool {
+            match super::child::ImageMapChild::from_node(node) {
+                Some(child) if index <= self.children.len() => {
+                    self.children.insert(index, child);
+                    true
+                }
+                _ => false,
+            }
+        }
+        fn remove_child(&mut self, index: usize) -> bool {
+            match index < self.children.len() {
+                true => {
+                    self.children.remove(index);
+                    true
+                }
+                false => false,
+            }
+        }
+        fn set_attribute(
+            &mut self,
+            name: &str,
+            value: std::option::Option<&str>,
+        ) -> bool {
+            match name {
+                "name" => self.set_name(value.map(str::to_owned)),
+                "accesskey" => self.set_access_key(value.map(str::to_owned)),
+                "autocapitalize" => {
+                    self.set_auto_capitalize(value.and_then(|value| value.parse().ok()))
+                }
+                "autofocus" => self.set_autofocus(value.is_some()),
+                "class" => self.set_class(value.map(str::to_owned)),
+                "contenteditable" => {
+                    self.set_content_editable(value.and_then(|value| value.parse().ok()))
+                }
+                "dir" => self.set_direction(value.and_then(|value| value.parse().ok())),
+                "draggable" => {
+                    self.set_draggable(value.and_then(|value| value.parse().ok()))
+                }
+                "enterkeyhint" => {
+                    self.set_enter_key_hint(value.and_then(|value| value.parse().ok()))
+                }
+                "exportparts" => self.set_export_parts(value.map(str::to_owned)),
+                "hidden" => self.set_hidden(value.and_then(|value| value.parse().ok())),
+                "id" => self.set_id(value.map(str::to_owned)),
+                "inert" => self.set_inert(value.is_some()),
+                "inputmode" => {
+                    self.set_input_mode(value.and_then(|value| value.parse().ok()))
+                }
+                "is" => self.set_is_(value.map(str::to_owned)),
+                "itemid" => self.set_item_id(value.map(str::to_owned)),
+                "itemprop" => self.set_item_prop(value.map(str::to_owned)),
+                "itemref" => self.set_item_ref(value.map(str::to_owned)),
+                "itemscope" => self.set_item_scope(value.is_some()),
+                "itemtype" => self.set_item_type(value.map(str::to_owned)),
+                "lang" => self.set_lang(value.map(str::to_owned)),
+                "nonce" => self.set_nonce(value.map(str::to_owned)),
+                "part" => self.set_part(value.map(str::to_owned)),
+                "slot" => self.set_slot(value.map(str::to_owned)),
+                "spellcheck" => {
+                    self.set_spellcheck(value.and_then(|value| value.parse().ok()))
+                }
+                "style" => self.set_style(value.map(str::to_owned)),
+                "tabindex" => {
+                    self.set_tab_index(value.and_then(|value| value.parse().ok()))
+                }
+                "title" => self.set_title(value.map(str::to_owned)),
+                "translate" => {
+                    self.set_translate(value.and_then(|value| value.parse().ok()))
+                }
+                "onabort" => self.set_onabort(value.map(str::to_owned)),
+                "onauxclick" => self.set_onauxclick(value.map(str::to_owned)),
+                "onbeforeinput" => self.set_onbeforeinput(value.map(str::to_owned)),
+                "onbeforematch" => self.set_onbeforematch(value.map(str::to_owned)),
+                "onbeforetoggle" => self.set_onbeforetoggle(value.map(str::to_owned)),
+                "onblur" => self.set_onblur(value.map(str::to_owned)),
+                "oncancel" => self.set_oncancel(value.map(str::to_owned)),
+                "oncanplay" => self.set_oncanplay(value.map(str::to_owned)),
+                "oncanplaythrough" => self.set_oncanplaythrough(value.map(str::to_owned)),
+                "onchange" => self.set_onchange(value.map(str::to_owned)),
+                "onclick" => self.set_onclick(value.map(str::to_owned)),
+                "onclose" => self.set_onclose(value.map(str::to_owned)),
+                "oncontextlost" => self.set_oncontextlost(value.map(str::to_owned)),
+                "oncontextmenu" => self.set_oncontextmenu(value.map(str::to_owned)),
+                "oncontextrestored" => {
+                    self.set_oncontextrestored(value.map(str::to_owned))
+                }
+                "oncopy" => self.set_oncopy(value.map(str::to_owned)),
+                "oncuechange" => self.set_oncuechange(value.map(str::to_owned)),
+                "oncut" => self.set_oncut(value.map(str::to_owned)),
+                "ondblclick" => self.set_ondblclick(value.map(str::to_owned)),
+                "ondrag" => self.set_ondrag(value.map(str::to_owned)),
+                "ondragend" => self.set_ondragend(value.map(str::to_owned)),
+                "ondragenter" => self.set_ondragenter(value.map(str::to_owned)),
+                "ondragleave" => self.set_ondragleave(value.map(str::to_owned)),
+                "ondragover" => self.set_ondragover(value.map(str::to_owned)),
+                "ondragstart" => self.set_ondragstart(value.map(str::to_owned)),
+                "ondrop" => self.set_ondrop(value.map(str::to_owned)),
+                "ondurationchange" => self.set_ondurationchange(value.map(str::to_owned)),
+                "onemptied" => self.set_onemptied(value.map(str::to_owned)),
+                "onended" => self.set_onended(value.map(str::to_owned)),
+                "onerror" => self.set_onerror(value.map(str::to_owned)),
+                "onfocus" => self.set_onfocus(value.map(str::to_owned)),
+                "onformdata" => self.set_onformdata(value.map(str::to_owned)),
+                "oninput" => self.set_oninput(value.map(str::to_owned)),
+                "oninvalid" => self.set_oninvalid(value.map(str::to_owned)),
+                "onkeydown" => self.set_onkeydown(value.map(str::to_owned)),
+                "onkeypress" => self.set_onkeypress(value.map(str::to_owned)),
+                "onkeyup" => self.set_onkeyup(value.map(str::to_owned)),
+                "onload" => self.set_onload(value.map(str::to_owned)),
+                "onloadeddata" => self.set_onloadeddata(value.map(str::to_owned)),
+                "onloadedmetadata" => self.set_onloadedmetadata(value.map(str::to_owned)),
+                "onloadstart" => self.set_onloadstart(value.map(str::to_owned)),
+                "onmousedown" => self.set_onmousedown(value.map(str::to_owned)),
+                "onmouseenter" => self.set_onmouseenter(value.map(str::to_owned)),
+                "onmouseleave" => self.set_onmouseleave(value.map(str::to_owned)),
+                "onmousemove" => self.set_onmousemove(value.map(str::to_owned)),
+                "onmouseout" => self.set_onmouseout(value.map(str::to_owned)),
+                "onmouseover" => self.set_onmouseover(value.map(str::to_owned)),
+                "onmouseup" => self.set_onmouseup(value.map(str::to_owned)),
+                "onpaste" => self.set_onpaste(value.map(str::to_owned)),
+                "onpause" => self.set_onpause(value.map(str::to_owned)),
+                "onplay" => self.set_onplay(value.map(str::to_owned)),
+                "onplaying" => self.set_onplaying(value.map(str::to_owned)),
+                "onprogress" => self.set_onprogress(value.map(str::to_owned)),
+                "onratechange" => self.set_onratechange(value.map(str::to_owned)),
+                "onreset" => self.set_onreset(value.map(str::to_owned)),
+                "onresize" => self.set_onresize(value.map(str::to_owned)),
+                "onscroll" => self.set_onscroll(value.map(str::to_owned)),
+                "onscrollend" => self.set_onscrollend(value.map(str::to_owned)),
+                "onsecuritypolicyviolation" => {
+                    self.set_onsecuritypolicyviolation(value.map(str::to_owned))
+                }
+                "onseeked" => self.set_onseeked(value.map(str::to_owned)),
+                "onseeking" => self.set_onseeking(value.map(str::to_owned)),
+                "onselect" => self.set_onselect(value.map(str::to_owned)),
+                "onslotchange" => self.set_onslotchange(value.map(str::to_owned)),
+                "onstalled" => self.set_onstalled(value.map(str::to_owned)),
+                "onsubmit" => self.set_onsubmit(value.map(str::to_owned)),
+                "onsuspend" => self.set_onsuspend(value.map(str::to_owned)),
+                "ontimeupdate" => self.set_ontimeupdate(value.map(str::to_owned)),
+                "ontoggle" => self.set_ontoggle(value.map(str::to_owned)),
+                "onvolumechange" => self.set_onvolumechange(value.map(str::to_owned)),
+                "onwaiting" => self.set_onwaiting(value.map(str::to_owned)),
+                "onwebkitanimationend" => {
+                    self.set_onwebkitanimationend(value.map(str::to_owned))
+                }
+                "onwebkitanimationiteration" => {
+                    self.set_onwebkitanimationiteration(value.map(str::to_owned))
+                }
+                "onwebkitanimationstart" => {
+                    self.set_onwebkitanimationstart(value.map(str::to_owned))
+                }
+                "onwebkittransitionend" => {
+                    self.set_onwebkittransitionend(value.map(str::to_owned))
+                }
+                "onwheel" => self.set_onwheel(value.map(str::to_owned)),
+                _ => {
+                    match (name.strip_prefix("data-"), value) {
+                        (Some(key), Some(value)) => {
+                            self.data_map_mut()
+                                .insert(key.to_owned().into(), value.to_owned().into());
+                        }
+                        (Some(key), None) => {
+                            self.data_map_mut().remove(key);
+                        }
+                        (None, _) => return false,
+                    }
+                }
             }
+            true
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeElement for ImageMap {
+        const TAG_NAME: &'static str = "map";
+        type Attributes = html_sys::embedded::ImageMap;
+        type Child = super::child::ImageMapChild;
+        fn attributes(&self) -> &Self::Attributes {
+            &self.sys
+        }
+        fn children(&self) -> &[Self::Child] {
+            &self.children
+        }
+        fn from_parts(sys: Self::Attributes, children: Vec<Self::Child>) -> Self {
+            Self { sys, children }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for ImageMap {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            crate::serialize::serialize_element(self, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for ImageMap {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_element(deserializer)
+        }
+    }
+    impl From<ImageMap> for html_sys::embedded::ImageMap {
+        fn from(element: ImageMap) -> Self {
+            element.sys
+        }
+    }
+    impl From<html_sys::embedded::ImageMap> for ImageMap {
+        fn from(sys: html_sys::embedded::ImageMap) -> Self {
+            Self { sys, children: vec![] }
+        }
+    }
+}
+pub mod child {
+    /// The permitted child items for the `ImageMap` element
+    #[derive(PartialEq, Clone)]
+    #[allow(clippy::large_enum_variant)]
+    pub enum ImageMapChild {
+        /// The Abbreviation element
+        Abbreviation(crate::generated::all::Abbreviation),
+        /// The Address element
+        Address(crate::generated::all::Address),
+        /// The Anchor element
+        Anchor(crate::generated::all::Anchor),
+        /// The Article element
+        Article(crate::generated::all::Article),
+        /// The Aside element
+        Aside(crate::generated::all::Aside),
+        /// The Audio element
+        Audio(crate::generated::all::Audio),
+        /// The Base element
+        Base(crate::generated::all::Base),
+        /// The BidirectionalIsolate element
+        BidirectionalIsolate(crate::generated::all::BidirectionalIsolate),
+        /// The BidirectionalTextOverride element
+        BidirectionalTextOverride(crate::generated::all::BidirectionalTextOverride),
+        /// The BlockQuote element
+        BlockQuote(crate::generated::all::BlockQuote),
+        /// The Body element
+        Body(crate::generated::all::Body),
+        /// The Bold element
+        Bold(crate::generated::all::Bold),
+        /// The Button element
+        Button(crate::generated::all::Button),
+        /// The Canvas element
+        Canvas(crate::generated::all::Canvas),
+        /// The Caption element
+        Caption(crate::generated::all::Caption),
+        /// The Cite element
+        Cite(crate::generated::all::Cite),
+        /// The Code element
+        Code(crate::generated::all::Code),
+        /// The Data element
+        Data(crate::generated::all::Data),
+        /// The DataList element
+        DataList(crate::generated::all::DataList),
+        /// The Definition element
+        Definition(crate::generated::all::Definition),
+        /// The DeletedText element
+        DeletedText(crate::generated::all::DeletedText),
+        /// The DescriptionDetails element
+        DescriptionDetails(crate::generated::all::DescriptionDetails),
+        /// The DescriptionList element
+        DescriptionList(crate::generated::all::DescriptionList),
+        /// The DescriptionTerm element
+        DescriptionTerm(crate::generated::all::DescriptionTerm),
+        /// The Details element
+        Details(crate::generated::all::Details),
+        /// The Dialog element
+        Dialog(crate::generated::all::Dialog),
+        /// The Division element
+        Division(crate::generated::all::Division),
+        /// The Embed element
+        Embed(crate::generated::all::Embed),
+        /// The Emphasis element
+        Emphasis(crate::generated::all::Emphasis),
+        /// The Fieldset element
+        Fieldset(crate::generated::all::Fieldset),
+        /// The Figure element
+        Figure(crate::generated::all::Figure),
+        /// The FigureCaption element
+        FigureCaption(crate::generated::all::FigureCaption),
+        /// The Footer element
+        Footer(crate::generated::all::Footer),
+        /// The Form element
+        Form(crate::generated::all::Form),
+        /// The Head element
+        Head(crate::generated::all::Head),
+        /// The Header element
+        Header(crate::generated::all::Header),
+        /// The Heading1 element
+        Heading1(crate::generated::all::Heading1),
+        /// The Heading2 element
+        Heading2(crate::generated::all::Heading2),
+        /// The Heading3 element
+        Heading3(crate::generated::all::Heading3),
+        /// The Heading4 element
+        Heading4(crate::generated::all::Heading4),
+        /// The Heading5 element
+        Heading5(crate::generated::all::Heading5),
+        /// The Heading6 element
+        Heading6(crate::generated::all::Heading6),
+        /// The HeadingGroup element
+        HeadingGroup(crate::generated::all::HeadingGroup),
+        /// The Html element
+        Html(crate::generated::all::Html),
+        /// The Iframe element
+        Iframe(crate::generated::all::Iframe),
+        /// The Image element
+        Image(crate::generated::all::Image),
+        /// The ImageMap element
+        ImageMap(crate::generated::all::ImageMap),
+        /// The ImageMapArea element
+        ImageMapArea(crate::generated::all::ImageMapArea),
+        /// The Input element
+        Input(crate::generated::all::Input),
+        /// The InsertedText element
+        InsertedText(crate::generated::all::InsertedText),
+        /// The Italic element
+        Italic(crate::generated::all::Italic),
+        /// The KeyboardInput element
+        KeyboardInput(crate::generated::all::KeyboardInput),
+        /// The Label element
+        Label(crate::generated::all::Label),
+        /// The Legend element
+        Legend(crate::generated::all::Legend),
+        /// The LineBreak element
+        LineBreak(crate::generated::all::LineBreak),
+        /// The LineBreakOpportunity element
+        LineBreakOpportunity(crate::generated::all::LineBreakOpportunity),
+        /// The Link element
+        Link(crate::generated::all::Link),
+        /// The ListItem element
+        ListItem(crate::generated::all::ListItem),
+        /// The Main element
+        Main(crate::generated::all::Main),
+        /// The MarkText element
+        MarkText(crate::generated::all::MarkText),
+        /// The Math element
+        Math(crate::generated::all::Math),
+        /// The MediaSource element
+        MediaSource(crate::generated::all::MediaSource),
+        /// The Menu element
+        Menu(crate::generated::all::Menu),
+        /// The Meta element
+        Meta(crate::generated::all::Meta),
+        /// The Meter element
+        Meter(crate::generated::all::Meter),
+        /// The Navigation element
+        Navigation(crate::generated::all::Navigation),
+        /// The NoScript element
+        NoScript(crate::generated::all::NoScript),
+        /// The Object element
+        Object(crate::generated::all::Object),
+        /// The Option element
+        Option(crate::generated::all::Option),
+        /// The OptionGroup element
+        OptionGroup(crate::generated::all::OptionGroup),
+        /// The OrderedList element
+        OrderedList(crate::generated::all::OrderedList),
+        /// The Output element
+        Output(crate::generated::all::Output),
+        /// The Paragraph element
+        Paragraph(crate::generated::all::Paragraph),
+        /// The Picture element
+        Picture(crate::generated::all::Picture),
+        /// The PreformattedText element
+        PreformattedText(crate::generated::all::PreformattedText),
+        /// The Progress element
+        Progress(crate::generated::all::Progress),
+        /// The Quotation element
+        Quotation(crate::generated::all::Quotation),
+        /// The RubyAnnotation element
+        RubyAnnotation(crate::generated::all::RubyAnnotation),
+        /// The RubyFallbackParenthesis element
+        RubyFallbackParenthesis(crate::generated::all::RubyFallbackParenthesis),
+        /// The RubyText element
+        RubyText(crate::generated::all::RubyText),
+        /// The SampleOutput element
+        SampleOutput(crate::generated::all::SampleOutput),
+        /// The Script element
+        Script(crate::generated::all::Script),
+        /// The Search element
+        Search(crate::generated::all::Search),
+        /// The Section element
+        Section(crate::generated::all::Section),
+        /// The Select element
+        Select(crate::generated::all::Select),
+        /// The SideComment element
+        SideComment(crate::generated::all::SideComment),
+        /// The Slot element
+        Slot(crate::generated::all::Slot),
+        /// The Span element
+        Span(crate::generated::all::Span),
+        /// The StrikeThrough element
+        StrikeThrough(crate::generated::all::StrikeThrough),
+        /// The Strong element
+        Strong(crate::generated::all::Strong),
+        /// The Style element
+        Style(crate::generated::all::Style),
+        /// The SubScript element
+        SubScript(crate::generated::all::SubScript),
+        /// The Summary element
+        Summary(crate::generated::all::Summary),
+        /// The SuperScript element
+        SuperScript(crate::generated::all::SuperScript),
+        /// The Svg element
+        Svg(crate::generated::all::Svg),
+        /// The Table element
+        Table(crate::generated::all::Table),
+        /// The TableBody element
+        TableBody(crate::generated::all::TableBody),
+        /// The TableCell element
+        TableCell(crate::generated::all::TableCell),
+        /// The TableColumn element
+        TableColumn(crate::generated::all::TableColumn),
+        /// The TableColumnGroup element
+        TableColumnGroup(crate::generated::all::TableColumnGroup),
+        /// The TableFoot element
+        TableFoot(crate::generated::all::TableFoot),
+        /// The TableHead element
+        TableHead(crate::generated::all::TableHead),
+        /// The TableHeader element
+        TableHeader(crate::generated::all::TableHeader),
+        /// The TableRow element
+        TableRow(crate::generated::all::TableRow),
+        /// The Template element
+        Template(crate::generated::all::Template),
+        /// The Text element
+        Text(std::borrow::Cow<'static, str>),
+        /// The TextArea element
+        TextArea(crate::generated::all::TextArea),
+        /// The TextTrack element
+        TextTrack(crate::generated::all::TextTrack),
+        /// The ThematicBreak element
+        ThematicBreak(crate::generated::all::ThematicBreak),
+        /// The Time element
+        Time(crate::generated::all::Time),
+        /// The Title element
+        Title(crate::generated::all::Title),
+        /// The Underline element
+        Underline(crate::generated::all::Underline),
+        /// The UnorderedList element
+        UnorderedList(crate::generated::all::UnorderedList),
+        /// The Variable element
+        Variable(crate::generated::all::Variable),
+        /// The Video element
+        Video(crate::generated::all::Video),
+        /// A comment
+        Comment(crate::Comment),
+        /// Trusted HTML, which is written without being escaped
+        RawHtml(crate::RawHtml),
+    }
+    impl std::convert::From<crate::generated::all::Abbreviation> for ImageMapChild {
+        fn from(value: crate::generated::all::Abbreviation) -> Self {
+            Self::Abbreviation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Address> for ImageMapChild {
+        fn from(value: crate::generated::all::Address) -> Self {
+            Self::Address(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Anchor> for ImageMapChild {
+        fn from(value: crate::generated::all::Anchor) -> Self {
+            Self::Anchor(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Article> for ImageMapChild {
+        fn from(value: crate::generated::all::Article) -> Self {
+            Self::Article(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Aside> for ImageMapChild {
+        fn from(value: crate::generated::all::Aside) -> Self {
+            Self::Aside(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Audio> for ImageMapChild {
+        fn from(value: crate::generated::all::Audio) -> Self {
+            Self::Audio(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Base> for ImageMapChild {
+        fn from(value: crate::generated::all::Base) -> Self {
+            Self::Base(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::BidirectionalIsolate>
+    for ImageMapChild {
+        fn from(value: crate::generated::all::BidirectionalIsolate) -> Self {
+            Self::BidirectionalIsolate(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::BidirectionalTextOverride>
+    for ImageMapChild {
+        fn from(value: crate::generated::all::BidirectionalTextOverride) -> Self {
+            Self::BidirectionalTextOverride(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::BlockQuote> for ImageMapChild {
+        fn from(value: crate::generated::all::BlockQuote) -> Self {
+            Self::BlockQuote(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Body> for ImageMapChild {
+        fn from(value: crate::generated::all::Body) -> Self {
+            Self::Body(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Bold> for ImageMapChild {
+        fn from(value: crate::generated::all::Bold) -> Self {
+            Self::Bold(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Button> for ImageMapChild {
+        fn from(value: crate::generated::all::Button) -> Self {
+            Self::Button(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Canvas> for ImageMapChild {
+        fn from(value: crate::generated::all::Canvas) -> Self {
+            Self::Canvas(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Caption> for ImageMapChild {
+        fn from(value: crate::generated::all::Caption) -> Self {
+            Self::Caption(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Cite> for ImageMapChild {
+        fn from(value: crate::generated::all::Cite) -> Self {
+            Self::Cite(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Code> for ImageMapChild {
+        fn from(value: crate::generated::all::Code) -> Self {
+            Self::Code(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Data> for ImageMapChild {
+        fn from(value: crate::generated::all::Data) -> Self {
+            Self::Data(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DataList> for ImageMapChild {
+        fn from(value: crate::generated::all::DataList) -> Self {
+            Self::DataList(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Definition> for ImageMapChild {
+        fn from(value: crate::generated::all::Definition) -> Self {
+            Self::Definition(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DeletedText> for ImageMapChild {
+        fn from(value: crate::generated::all::DeletedText) -> Self {
+            Self::DeletedText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DescriptionDetails>
+    for ImageMapChild {
+        fn from(value: crate::generated::all::DescriptionDetails) -> Self {
+            Self::DescriptionDetails(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DescriptionList> for ImageMapChild {
+        fn from(value: crate::generated::all::DescriptionList) -> Self {
+            Self::DescriptionList(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::DescriptionTerm> for ImageMapChild {
+        fn from(value: crate::generated::all::DescriptionTerm) -> Self {
+            Self::DescriptionTerm(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Details> for ImageMapChild {
+        fn from(value: crate::generated::all::Details) -> Self {
+            Self::Details(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Dialog> for ImageMapChild {
+        fn from(value: crate::generated::all::Dialog) -> Self {
+            Self::Dialog(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Division> for ImageMapChild {
+        fn from(value: crate::generated::all::Division) -> Self {
+            Self::Division(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Embed> for ImageMapChild {
+        fn from(value: crate::generated::all::Embed) -> Self {
+            Self::Embed(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Emphasis> for ImageMapChild {
+        fn from(value: crate::generated::all::Emphasis) -> Self {
+            Self::Emphasis(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Fieldset> for ImageMapChild {
+        fn from(value: crate::generated::all::Fieldset) -> Self {
+            Self::Fieldset(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Figure> for ImageMapChild {
+        fn from(value: crate::generated::all::Figure) -> Self {
+            Self::Figure(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::FigureCaption> for ImageMapChild {
+        fn from(value: crate::generated::all::FigureCaption) -> Self {
+            Self::FigureCaption(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Footer> for ImageMapChild {
+        fn from(value: crate::generated::all::Footer) -> Self {
+            Self::Footer(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Form> for ImageMapChild {
+        fn from(value: crate::generated::all::Form) -> Self {
+            Self::Form(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Head> for ImageMapChild {
+        fn from(value: crate::generated::all::Head) -> Self {
+            Self::Head(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Header> for ImageMapChild {
+        fn from(value: crate::generated::all::Header) -> Self {
+            Self::Header(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Heading1> for ImageMapChild {
+        fn from(value: crate::generated::all::Heading1) -> Self {
+            Self::Heading1(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Heading2> for ImageMapChild {
+        fn from(value: crate::generated::all::Heading2) -> Self {
+            Self::Heading2(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Heading3> for ImageMapChild {
+        fn from(value: crate::generated::all::Heading3) -> Self {
+            Self::Heading3(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Heading4> for ImageMapChild {
+        fn from(value: crate::generated::all::Heading4) -> Self {
+            Self::Heading4(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Heading5> for ImageMapChild {
+        fn from(value: crate::generated::all::Heading5) -> Self {
+            Self::Heading5(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Heading6> for ImageMapChild {
+        fn from(value: crate::generated::all::Heading6) -> Self {
+            Self::Heading6(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::HeadingGroup> for ImageMapChild {
+        fn from(value: crate::generated::all::HeadingGroup) -> Self {
+            Self::HeadingGroup(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Html> for ImageMapChild {
+        fn from(value: crate::generated::all::Html) -> Self {
+            Self::Html(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Iframe> for ImageMapChild {
+        fn from(value: crate::generated::all::Iframe) -> Self {
+            Self::Iframe(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Image> for ImageMapChild {
+        fn from(value: crate::generated::all::Image) -> Self {
+            Self::Image(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::ImageMap> for ImageMapChild {
+        fn from(value: crate::generated::all::ImageMap) -> Self {
+            Self::ImageMap(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::ImageMapArea> for ImageMapChild {
+        fn from(value: crate::generated::all::ImageMapArea) -> Self {
+            Self::ImageMapArea(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Input> for ImageMapChild {
+        fn from(value: crate::generated::all::Input) -> Self {
+            Self::Input(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::InsertedText> for ImageMapChild {
+        fn from(value: crate::generated::all::InsertedText) -> Self {
+            Self::InsertedText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Italic> for ImageMapChild {
+        fn from(value: crate::generated::all::Italic) -> Self {
+            Self::Italic(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::KeyboardInput> for ImageMapChild {
+        fn from(value: crate::generated::all::KeyboardInput) -> Self {
+            Self::KeyboardInput(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Label> for ImageMapChild {
+        fn from(value: crate::generated::all::Label) -> Self {
+            Self::Label(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Legend> for ImageMapChild {
+        fn from(value: crate::generated::all::Legend) -> Self {
+            Self::Legend(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::LineBreak> for ImageMapChild {
+        fn from(value: crate::generated::all::LineBreak) -> Self {
+            Self::LineBreak(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::LineBreakOpportunity>
+    for ImageMapChild {
+        fn from(value: crate::generated::all::LineBreakOpportunity) -> Self {
+            Self::LineBreakOpportunity(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Link> for ImageMapChild {
+        fn from(value: crate::generated::all::Link) -> Self {
+            Self::Link(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::ListItem> for ImageMapChild {
+        fn from(value: crate::generated::all::ListItem) -> Self {
+            Self::ListItem(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Main> for ImageMapChild {
+        fn from(value: crate::generated::all::Main) -> Self {
+            Self::Main(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::MarkText> for ImageMapChild {
+        fn from(value: crate::generated::all::MarkText) -> Self {
+            Self::MarkText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Math> for ImageMapChild {
+        fn from(value: crate::generated::all::Math) -> Self {
+            Self::Math(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::MediaSource> for ImageMapChild {
+        fn from(value: crate::generated::all::MediaSource) -> Self {
+            Self::MediaSource(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Menu> for ImageMapChild {
+        fn from(value: crate::generated::all::Menu) -> Self {
+            Self::Menu(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Meta> for ImageMapChild {
+        fn from(value: crate::generated::all::Meta) -> Self {
+            Self::Meta(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Meter> for ImageMapChild {
+        fn from(value: crate::generated::all::Meter) -> Self {
+            Self::Meter(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Navigation> for ImageMapChild {
+        fn from(value: crate::generated::all::Navigation) -> Self {
+            Self::Navigation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::NoScript> for ImageMapChild {
+        fn from(value: crate::generated::all::NoScript) -> Self {
+            Self::NoScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Object> for ImageMapChild {
+        fn from(value: crate::generated::all::Object) -> Self {
+            Self::Object(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Option> for ImageMapChild {
+        fn from(value: crate::generated::all::Option) -> Self {
+            Self::Option(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::OptionGroup> for ImageMapChild {
+        fn from(value: crate::generated::all::OptionGroup) -> Self {
+            Self::OptionGroup(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::OrderedList> for ImageMapChild {
+        fn from(value: crate::generated::all::OrderedList) -> Self {
+            Self::OrderedList(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Output> for ImageMapChild {
+        fn from(value: crate::generated::all::Output) -> Self {
+            Self::Output(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Paragraph> for ImageMapChild {
+        fn from(value: crate::generated::all::Paragraph) -> Self {
+            Self::Paragraph(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Picture> for ImageMapChild {
+        fn from(value: crate::generated::all::Picture) -> Self {
+            Self::Picture(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::PreformattedText> for ImageMapChild {
+        fn from(value: crate::generated::all::PreformattedText) -> Self {
+            Self::PreformattedText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Progress> for ImageMapChild {
+        fn from(value: crate::generated::all::Progress) -> Self {
+            Self::Progress(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Quotation> for ImageMapChild {
+        fn from(value: crate::generated::all::Quotation) -> Self {
+            Self::Quotation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::RubyAnnotation> for ImageMapChild {
+        fn from(value: crate::generated::all::RubyAnnotation) -> Self {
+            Self::RubyAnnotation(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::RubyFallbackParenthesis>
+    for ImageMapChild {
+        fn from(value: crate::generated::all::RubyFallbackParenthesis) -> Self {
+            Self::RubyFallbackParenthesis(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::RubyText> for ImageMapChild {
+        fn from(value: crate::generated::all::RubyText) -> Self {
+            Self::RubyText(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SampleOutput> for ImageMapChild {
+        fn from(value: crate::generated::all::SampleOutput) -> Self {
+            Self::SampleOutput(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Script> for ImageMapChild {
+        fn from(value: crate::generated::all::Script) -> Self {
+            Self::Script(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Search> for ImageMapChild {
+        fn from(value: crate::generated::all::Search) -> Self {
+            Self::Search(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Section> for ImageMapChild {
+        fn from(value: crate::generated::all::Section) -> Self {
+            Self::Section(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Select> for ImageMapChild {
+        fn from(value: crate::generated::all::Select) -> Self {
+            Self::Select(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SideComment> for ImageMapChild {
+        fn from(value: crate::generated::all::SideComment) -> Self {
+            Self::SideComment(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Slot> for ImageMapChild {
+        fn from(value: crate::generated::all::Slot) -> Self {
+            Self::Slot(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Span> for ImageMapChild {
+        fn from(value: crate::generated::all::Span) -> Self {
+            Self::Span(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::StrikeThrough> for ImageMapChild {
+        fn from(value: crate::generated::all::StrikeThrough) -> Self {
+            Self::StrikeThrough(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Strong> for ImageMapChild {
+        fn from(value: crate::generated::all::Strong) -> Self {
+            Self::Strong(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Style> for ImageMapChild {
+        fn from(value: crate::generated::all::Style) -> Self {
+            Self::Style(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SubScript> for ImageMapChild {
+        fn from(value: crate::generated::all::SubScript) -> Self {
+            Self::SubScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Summary> for ImageMapChild {
+        fn from(value: crate::generated::all::Summary) -> Self {
+            Self::Summary(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SuperScript> for ImageMapChild {
+        fn from(value: crate::generated::all::SuperScript) -> Self {
+            Self::SuperScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Svg> for ImageMapChild {
+        fn from(value: crate::generated::all::Svg) -> Self {
+            Self::Svg(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Table> for ImageMapChild {
+        fn from(value: crate::generated::all::Table) -> Self {
+            Self::Table(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableBody> for ImageMapChild {
+        fn from(value: crate::generated::all::TableBody) -> Self {
+            Self::TableBody(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableCell> for ImageMapChild {
+        fn from(value: crate::generated::all::TableCell) -> Self {
+            Self::TableCell(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableColumn> for ImageMapChild {
+        fn from(value: crate::generated::all::TableColumn) -> Self {
+            Self::TableColumn(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableColumnGroup> for ImageMapChild {
+        fn from(value: crate::generated::all::TableColumnGroup) -> Self {
+            Self::TableColumnGroup(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableFoot> for ImageMapChild {
+        fn from(value: crate::generated::all::TableFoot) -> Self {
+            Self::TableFoot(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableHead> for ImageMapChild {
+        fn from(value: crate::generated::all::TableHead) -> Self {
+            Self::TableHead(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableHeader> for ImageMapChild {
+        fn from(value: crate::generated::all::TableHeader) -> Self {
+            Self::TableHeader(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TableRow> for ImageMapChild {
+        fn from(value: crate::generated::all::TableRow) -> Self {
+            Self::TableRow(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Template> for ImageMapChild {
+        fn from(value: crate::generated::all::Template) -> Self {
+            Self::Template(value)
+        }
+    }
+    impl std::convert::From<std::borrow::Cow<'static, str>> for ImageMapChild {
+        fn from(value: std::borrow::Cow<'static, str>) -> Self {
+            Self::Text(value)
+        }
+    }
+    impl std::convert::From<&'static str> for ImageMapChild {
+        fn from(value: &'static str) -> Self {
+            Self::Text(value.into())
+        }
+    }
+    impl std::convert::From<String> for ImageMapChild {
+        fn from(value: String) -> Self {
+            Self::Text(value.into())
+        }
+    }
+    impl std::convert::From<crate::generated::all::TextArea> for ImageMapChild {
+        fn from(value: crate::generated::all::TextArea) -> Self {
+            Self::TextArea(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::TextTrack> for ImageMapChild {
+        fn from(value: crate::generated::all::TextTrack) -> Self {
+            Self::TextTrack(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::ThematicBreak> for ImageMapChild {
+        fn from(value: crate::generated::all::ThematicBreak) -> Self {
+            Self::ThematicBreak(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Time> for ImageMapChild {
+        fn from(value: crate::generated::all::Time) -> Self {
+            Self::Time(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Title> for ImageMapChild {
+        fn from(value: crate::generated::all::Title) -> Self {
+            Self::Title(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Underline> for ImageMapChild {
+        fn from(value: crate::generated::all::Underline) -> Self {
+            Self::Underline(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::UnorderedList> for ImageMapChild {
+        fn from(value: crate::generated::all::UnorderedList) -> Self {
+            Self::UnorderedList(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Variable> for ImageMapChild {
+        fn from(value: crate::generated::all::Variable) -> Self {
+            Self::Variable(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Video> for ImageMapChild {
+        fn from(value: crate::generated::all::Video) -> Self {
+            Self::Video(value)
+        }
+    }
+    impl std::convert::From<crate::Comment> for ImageMapChild {
+        fn from(value: crate::Comment) -> Self {
+            Self::Comment(value)
+        }
+    }
+    impl std::convert::From<crate::RawHtml> for ImageMapChild {
+        fn from(value: crate::RawHtml) -> Self {
+            Self::RawHtml(value)
+        }
+    }
+    impl std::fmt::Debug for ImageMapChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for ImageMapChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Abbreviation(el) => write!(f, "{el}"),
+                Self::Address(el) => write!(f, "{el}"),
+                Self::Anchor(el) => write!(f, "{el}"),
+                Self::Article(el) => write!(f, "{el}"),
+                Self::Aside(el) => write!(f, "{el}"),
+                Self::Audio(el) => write!(f, "{el}"),
+                Self::Base(el) => write!(f, "{el}"),
+                Self::BidirectionalIsolate(el) => write!(f, "{el}"),
+                Self::BidirectionalTextOverride(el) => write!(f, "{el}"),
+                Self::BlockQuote(el) => write!(f, "{el}"),
+                Self::Body(el) => write!(f, "{el}"),
+                Self::Bold(el) => write!(f, "{el}"),
+                Self::Button(el) => write!(f, "{el}"),
+                Self::Canvas(el) => write!(f, "{el}"),
+                Self::Caption(el) => write!(f, "{el}"),
+                Self::Cite(el) => write!(f, "{el}"),
+                Self::Code(el) => write!(f, "{el}"),
+                Self::Data(el) => write!(f, "{el}"),
+                Self::DataList(el) => write!(f, "{el}"),
+                Self::Definition(el) => write!(f, "{el}"),
+                Self::DeletedText(el) => write!(f, "{el}"),
+                Self::DescriptionDetails(el) => write!(f, "{el}"),
+                Self::DescriptionList(el) => write!(f, "{el}"),
+                Self::DescriptionTerm(el) => write!(f, "{el}"),
+                Self::Details(el) => write!(f, "{el}"),
+                Self::Dialog(el) => write!(f, "{el}"),
+                Self::Division(el) => write!(f, "{el}"),
+                Self::Embed(el) => write!(f, "{el}"),
+                Self::Emphasis(el) => write!(f, "{el}"),
+                Self::Fieldset(el) => write!(f, "{el}"),
+                Self::Figure(el) => write!(f, "{el}"),
+                Self::FigureCaption(el) => write!(f, "{el}"),
+                Self::Footer(el) => write!(f, "{el}"),
+                Self::Form(el) => write!(f, "{el}"),
+                Self::Head(el) => write!(f, "{el}"),
+                Self::Header(el) => write!(f, "{el}"),
+                Self::Heading1(el) => write!(f, "{el}"),
+                Self::Heading2(el) => write!(f, "{el}"),
+                Self::Heading3(el) => write!(f, "{el}"),
+                Self::Heading4(el) => write!(f, "{el}"),
+                Self::Heading5(el) => write!(f, "{el}"),
+                Self::Heading6(el) => write!(f, "{el}"),
+                Self::HeadingGroup(el) => write!(f, "{el}"),
+                Self::Html(el) => write!(f, "{el}"),
+                Self::Iframe(el) => write!(f, "{el}"),
+                Self::Image(el) => write!(f, "{el}"),
+                Self::ImageMap(el) => write!(f, "{el}"),
+                Self::ImageMapArea(el) => write!(f, "{el}"),
+                Self::Input(el) => write!(f, "{el}"),
+                Self::InsertedText(el) => write!(f, "{el}"),
+                Self::Italic(el) => write!(f, "{el}"),
+                Self::KeyboardInput(el) => write!(f, "{el}"),
+                Self::Label(el) => write!(f, "{el}"),
+                Self::Legend(el) => write!(f, "{el}"),
+                Self::LineBreak(el) => write!(f, "{el}"),
+                Self::LineBreakOpportunity(el) => write!(f, "{el}"),
+                Self::Link(el) => write!(f, "{el}"),
+                Self::ListItem(el) => write!(f, "{el}"),
+                Self::Main(el) => write!(f, "{el}"),
+                Self::MarkText(el) => write!(f, "{el}"),
+                Self::Math(el) => write!(f, "{el}"),
+                Self::MediaSource(el) => write!(f, "{el}"),
+                Self::Menu(el) => write!(f, "{el}"),
+                Self::Meta(el) => write!(f, "{el}"),
+                Self::Meter(el) => write!(f, "{el}"),
+                Self::Navigation(el) => write!(f, "{el}"),
+                Self::NoScript(el) => write!(f, "{el}"),
+                Self::Object(el) => write!(f, "{el}"),
+                Self::Option(el) => write!(f, "{el}"),
+                Self::OptionGroup(el) => write!(f, "{el}"),
+                Self::OrderedList(el) => write!(f, "{el}"),
+                Self::Output(el) => write!(f, "{el}"),
+                Self::Paragraph(el) => write!(f, "{el}"),
+                Self::Picture(el) => write!(f, "{el}"),
+                Self::PreformattedText(el) => write!(f, "{el}"),
+                Self::Progress(el) => write!(f, "{el}"),
+                Self::Quotation(el) => write!(f, "{el}"),
+                Self::RubyAnnotation(el) => write!(f, "{el}"),
+                Self::RubyFallbackParenthesis(el) => write!(f, "{el}"),
+                Self::RubyText(el) => write!(f, "{el}"),
+                Self::SampleOutput(el) => write!(f, "{el}"),
+                Self::Script(el) => write!(f, "{el}"),
+                Self::Search(el) => write!(f, "{el}"),
+                Self::Section(el) => write!(f, "{el}"),
+                Self::Select(el) => write!(f, "{el}"),
+                Self::SideComment(el) => write!(f, "{el}"),
+                Self::Slot(el) => write!(f, "{el}"),
+                Self::Span(el) => write!(f, "{el}"),
+                Self::StrikeThrough(el) => write!(f, "{el}"),
+                Self::Strong(el) => write!(f, "{el}"),
+                Self::Style(el) => write!(f, "{el}"),
+                Self::SubScript(el) => write!(f, "{el}"),
+                Self::Summary(el) => write!(f, "{el}"),
+                Self::SuperScript(el) => write!(f, "{el}"),
+                Self::Svg(el) => write!(f, "{el}"),
+                Self::Table(el) => write!(f, "{el}"),
+                Self::TableBody(el) => write!(f, "{el}"),
+                Self::TableCell(el) => write!(f, "{el}"),
+                Self::TableColumn(el) => write!(f, "{el}"),
+                Self::TableColumnGroup(el) => write!(f, "{el}"),
+                Self::TableFoot(el) => write!(f, "{el}"),
+                Self::TableHead(el) => write!(f, "{el}"),
+                Self::TableHeader(el) => write!(f, "{el}"),
+                Self::TableRow(el) => write!(f, "{el}"),
+                Self::Template(el) => write!(f, "{el}"),
+                Self::Text(el) => write!(f, "{}", html_sys::escape::Text(el)),
+                Self::TextArea(el) => write!(f, "{el}"),
+                Self::TextTrack(el) => write!(f, "{el}"),
+                Self::ThematicBreak(el) => write!(f, "{el}"),
+                Self::Time(el) => write!(f, "{el}"),
+                Self::Title(el) => write!(f, "{el}"),
+                Self::Underline(el) => write!(f, "{el}"),
+                Self::UnorderedList(el) => write!(f, "{el}"),
+                Self::Variable(el) => write!(f, "{el}"),
+                Self::Video(el) => write!(f, "{el}"),
+                Self::Comment(el) => write!(f, "{el}"),
+                Self::RawHtml(el) => write!(f, "{el}"),
+            }
+        }
+    }
+    impl<'a> From<&'a ImageMapChild> for crate::Node<'a> {
+        fn from(child: &'a ImageMapChild) -> Self {
+            match child {
+                ImageMapChild::Abbreviation(el) => crate::Node::from(el),
+                ImageMapChild::Address(el) => crate::Node::from(el),
+                ImageMapChild::Anchor(el) => crate::Node::from(el),
+                ImageMapChild::Article(el) => crate::Node::from(el),
+                ImageMapChild::Aside(el) => crate::Node::from(el),
+                ImageMapChild::Audio(el) => crate::Node::from(el),
+                ImageMapChild::Base(el) => crate::Node::from(el),
+                ImageMapChild::BidirectionalIsolate(el) => crate::Node::from(el),
+                ImageMapChild::BidirectionalTextOverride(el) => crate::Node::from(el),
+                ImageMapChild::BlockQuote(el) => crate::Node::from(el),
+                ImageMapChild::Body(el) => crate::Node::from(el),
+                ImageMapChild::Bold(el) => crate::Node::from(el),
+                ImageMapChild::Button(el) => crate::Node::from(el),
+                ImageMapChild::Canvas(el) => crate::Node::from(el),
+                ImageMapChild::Caption(el) => crate::Node::from(el),
+                ImageMapChild::Cite(el) => crate::Node::from(el),
+                ImageMapChild::Code(el) => crate::Node::from(el),
+                ImageMapChild::Data(el) => crate::Node::from(el),
+                ImageMapChild::DataList(el) => crate::Node::from(el),
+                ImageMapChild::Definition(el) => crate::Node::from(el),
+                ImageMapChild::DeletedText(el) => crate::Node::from(el),
+                ImageMapChild::DescriptionDetails(el) => crate::Node::from(el),
+                ImageMapChild::DescriptionList(el) => crate::Node::from(el),
+                ImageMapChild::DescriptionTerm(el) => crate::Node::from(el),
+                ImageMapChild::Details(el) => crate::Node::from(el),
+                ImageMapChild::Dialog(el) => crate::Node::from(el),
+                ImageMapChild::Division(el) => crate::Node::from(el),
+                ImageMapChild::Embed(el) => crate::Node::from(el),
+                ImageMapChild::Emphasis(el) => crate::Node::from(el),
+                ImageMapChild::Fieldset(el) => crate::Node::from(el),
+                ImageMapChild::Figure(el) => crate::Node::from(el),
+                ImageMapChild::FigureCaption(el) => crate::Node::from(el),
+                ImageMapChild::Footer(el) => crate::Node::from(el),
+                ImageMapChild::Form(el) => crate::Node::from(el),
+                ImageMapChild::Head(el) => crate::Node::from(el),
+                ImageMapChild::Header(el) => crate::Node::from(el),
+                ImageMapChild::Heading1(el) => crate::Node::from(el),
+                ImageMapChild::Heading2(el) => crate::Node::from(el),
+                ImageMapChild::Heading3(el) => crate::Node::from(el),
+                ImageMapChild::Heading4(el) => crate::Node::from(el),
+                ImageMapChild::Heading5(el) => crate::Node::from(el),
+                ImageMapChild::Heading6(el) => crate::Node::from(el),
+                ImageMapChild::HeadingGroup(el) => crate::Node::from(el),
+                ImageMapChild::Html(el) => crate::Node::from(el),
+                ImageMapChild::Iframe(el) => crate::Node::from(el),
+                ImageMapChild::Image(el) => crate::Node::from(el),
+                ImageMapChild::ImageMap(el) => crate::Node::from(el),
+                ImageMapChild::ImageMapArea(el) => crate::Node::from(el),
+                ImageMapChild::Input(el) => crate::Node::from(el),
+                ImageMapChild::InsertedText(el) => crate::Node::from(el),
+                ImageMapChild::Italic(el) => crate::Node::from(el),
+                ImageMapChild::KeyboardInput(el) => crate::Node::from(el),
+                ImageMapChild::Label(el) => crate::Node::from(el),
+                ImageMapChild::Legend(el) => crate::Node::from(el),
+                ImageMapChild::LineBreak(el) => crate::Node::from(el),
+                ImageMapChild::LineBreakOpportunity(el) => crate::Node::from(el),
+                ImageMapChild::Link(el) => crate::Node::from(el),
+                ImageMapChild::ListItem(el) => crate::Node::from(el),
+                ImageMapChild::Main(el) => crate::Node::from(el),
+                ImageMapChild::MarkText(el) => crate::Node::from(el),
+                ImageMapChild::Math(el) => crate::Node::from(el),
+                ImageMapChild::MediaSource(el) => crate::Node::from(el),
+                ImageMapChild::Menu(el) => crate::Node::from(el),
+                ImageMapChild::Meta(el) => crate::Node::from(el),
+                ImageMapChild::Meter(el) => crate::Node::from(el),
+                ImageMapChild::Navigation(el) => crate::Node::from(el),
+                ImageMapChild::NoScript(el) => crate::Node::from(el),
+                ImageMapChild::Object(el) => crate::Node::from(el),
+                ImageMapChild::Option(el) => crate::Node::from(el),
+                ImageMapChild::OptionGroup(el) => crate::Node::from(el),
+                ImageMapChild::OrderedList(el) => crate::Node::from(el),
+                ImageMapChild::Output(el) => crate::Node::from(el),
+                ImageMapChild::Paragraph(el) => crate::Node::from(el),
+                ImageMapChild::Picture(el) => crate::Node::from(el),
+                ImageMapChild::PreformattedText(el) => crate::Node::from(el),
+                ImageMapChild::Progress(el) => crate::Node::from(el),
+                ImageMapChild::Quotation(el) => crate::Node::from(el),
+                ImageMapChild::RubyAnnotation(el) => crate::Node::from(el),
+                ImageMapChild::RubyFallbackParenthesis(el) => crate::Node::from(el),
+                ImageMapChild::RubyText(el) => crate::Node::from(el),
+                ImageMapChild::SampleOutput(el) => crate::Node::from(el),
+                ImageMapChild::Script(el) => crate::Node::from(el),
+                ImageMapChild::Search(el) => crate::Node::from(el),
+                ImageMapChild::Section(el) => crate::Node::from(el),
+                ImageMapChild::Select(el) => crate::Node::from(el),
+                ImageMapChild::SideComment(el) => crate::Node::from(el),
+                ImageMapChild::Slot(el) => crate::Node::from(el),
+                ImageMapChild::Span(el) => crate::Node::from(el),
+                ImageMapChild::StrikeThrough(el) => crate::Node::from(el),
+                ImageMapChild::Strong(el) => crate::Node::from(el),
+                ImageMapChild::Style(el) => crate::Node::from(el),
+                ImageMapChild::SubScript(el) => crate::Node::from(el),
+                ImageMapChild::Summary(el) => crate::Node::from(el),
+                ImageMapChild::SuperScript(el) => crate::Node::from(el),
+                ImageMapChild::Svg(el) => crate::Node::from(el),
+                ImageMapChild::Table(el) => crate::Node::from(el),
+                ImageMapChild::TableBody(el) => crate::Node::from(el),
+                ImageMapChild::TableCell(el) => crate::Node::from(el),
+                ImageMapChild::TableColumn(el) => crate::Node::from(el),
+                ImageMapChild::TableColumnGroup(el) => crate::Node::from(el),
+                ImageMapChild::TableFoot(el) => crate::Node::from(el),
+                ImageMapChild::TableHead(el) => crate::Node::from(el),
+                ImageMapChild::TableHeader(el) => crate::Node::from(el),
+                ImageMapChild::TableRow(el) => crate::Node::from(el),
+                ImageMapChild::Template(el) => crate::Node::from(el),
+                ImageMapChild::Text(el) => crate::Node::from(el),
+                ImageMapChild::TextArea(el) => crate::Node::from(el),
+                ImageMapChild::TextTrack(el) => crate::Node::from(el),
+                ImageMapChild::ThematicBreak(el) => crate::Node::from(el),
+                ImageMapChild::Time(el) => crate::Node::from(el),
+                ImageMapChild::Title(el) => crate::Node::from(el),
+                ImageMapChild::Underline(el) => crate::Node::from(el),
+                ImageMapChild::UnorderedList(el) => crate::Node::from(el),
+                ImageMapChild::Variable(el) => crate::Node::from(el),
+                ImageMapChild::Video(el) => crate::Node::from(el),
+                ImageMapChild::Comment(el) => crate::Node::from(el),
+                ImageMapChild::RawHtml(el) => crate::Node::from(el),
+            }
+        }
+    }
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromContent for ImageMapChild {
+        const PARENT_TAG_NAME: &'static str = "map";
+        fn from_content(
+            content: crate::parse::Content<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Option<Self> {
+            match content {
+                crate::parse::Content::Text(text) => {
+                    Some(Self::Text(text.to_owned().into()))
+                }
+                crate::parse::Content::Comment(comment) => {
+                    Some(Self::Comment(crate::Comment::new(comment.to_owned())))
+                }
+                crate::parse::Content::Element(el) => {
+                    match el.tag_name() {
+                        "abbr" => Some(Self::Abbreviation(cx.element(el))),
+                        "address" => Some(Self::Address(cx.element(el))),
+                        "a" => Some(Self::Anchor(cx.element(el))),
+                        "article" => Some(Self::Article(cx.element(el))),
+                        "aside" => Some(Self::Aside(cx.element(el))),
+                        "audio" => Some(Self::Audio(cx.element(el))),
+                        "base" => Some(Self::Base(cx.element(el))),
+                        "bdi" => Some(Self::BidirectionalIsolate(cx.element(el))),
+                        "bdo" => Some(Self::BidirectionalTextOverride(cx.element(el))),
+                        "blockquote" => Some(Self::BlockQuote(cx.element(el))),
+                        "body" => Some(Self::Body(cx.element(el))),
+                        "b" => Some(Self::Bold(cx.element(el))),
+                        "button" => Some(Self::Button(cx.element(el))),
+                        "canvas" => Some(Self::Canvas(cx.element(el))),
+                        "caption" => Some(Self::Caption(cx.element(el))),
+                        "cite" => Some(Self::Cite(cx.element(el))),
+                        "code" => Some(Self::Code(cx.element(el))),
+                        "data" => Some(Self::Data(cx.element(el))),
+                        "datalist" => Some(Self::DataList(cx.element(el))),
+                        "dfn" => Some(Self::Definition(cx.element(el))),
+                        "del" => Some(Self::DeletedText(cx.element(el))),
+                        "dd" => Some(Self::DescriptionDetails(cx.element(el))),
+                        "dl" => Some(Self::DescriptionList(cx.element(el))),
+                        "dt" => Some(Self::DescriptionTerm(cx.element(el))),
+                        "details" => Some(Self::Details(cx.element(el))),
+                        "dialog" => Some(Self::Dialog(cx.element(el))),
+                        "div" => Some(Self::Division(cx.element(el))),
+                        "embed" => Some(Self::Embed(cx.element(el))),
+                        "em" => Some(Self::Emphasis(cx.element(el))),
+                        "fieldset" => Some(Self::Fieldset(cx.element(el))),
+                        "figure" => Some(Self::Figure(cx.element(el))),
+                        "figcaption" => Some(Self::FigureCaption(cx.element(el))),
+                        "footer" => Some(Self::Footer(cx.element(el))),
+                        "form" => Some(Self::Form(cx.element(el))),
+                        "head" => Some(Self::Head(cx.element(el))),
+                        "header" => Some(Self::Header(cx.element(el))),
+                        "h1" => Some(Self::Heading1(cx.element(el))),
+                        "h2" => Some(Self::Heading2(cx.element(el))),
+                        "h3" => Some(Self::Heading3(cx.element(el))),
+                        "h4" => Some(Self::Heading4(cx.element(el))),
+                        "h5" => Some(Self::Heading5(cx.element(el))),
+                        "h6" => Some(Self::Heading6(cx.element(el))),
+                        "hgroup" => Some(Self::HeadingGroup(cx.element(el))),
+                        "html" => Some(Self::Html(cx.element(el))),
+                        "iframe" => Some(Self::Iframe(cx.element(el))),
+                        "img" => Some(Self::Image(cx.element(el))),
+                        "map" => Some(Self::ImageMap(cx.element(el))),
+                        "area" => Some(Self::ImageMapArea(cx.element(el))),
+                        "input" => Some(Self::Input(cx.element(el))),
+                        "ins" => Some(Self::InsertedText(cx.element(el))),
+                        "i" => Some(Self::Italic(cx.element(el))),
+                        "kbd" => Some(Self::KeyboardInput(cx.element(el))),
+                        "label" => Some(Self::Label(cx.element(el))),
+                        "legend" => Some(Self::Legend(cx.element(el))),
+                        "br" => Some(Self::LineBreak(cx.element(el))),
+                        "wbr" => Some(Self::LineBreakOpportunity(cx.element(el))),
+                        "link" => Some(Self::Link(cx.element(el))),
+                        "li" => Some(Self::ListItem(cx.element(el))),
+                        "main" => Some(Self::Main(cx.element(el))),
+                        "mark" => Some(Self::MarkText(cx.element(el))),
+                        "math" => Some(Self::Math(cx.element(el))),
+                        "source" => Some(Self::MediaSource(cx.element(el))),
+                        "menu" => Some(Self::Menu(cx.element(el))),
+                        "meta" => Some(Self::Meta(cx.element(el))),
+                        "meter" => Some(Self::Meter(cx.element(el))),
+                        "nav" => Some(Self::Navigation(cx.element(el))),
+                        "noscript" => Some(Self::NoScript(cx.element(el))),
+                        "object" => Some(Self::Object(cx.element(el))),
+                        "option" => Some(Self::Option(cx.element(el))),
+                        "optgroup" => Some(Self::OptionGroup(cx.element(el))),
+                        "ol" => Some(Self::OrderedList(cx.element(el))),
+                        "output" => Some(Self::Output(cx.element(el))),
+                        "p" => Some(Self::Paragraph(cx.element(el))),
+                        "picture" => Some(Self::Picture(cx.element(el))),
+                        "pre" => Some(Self::PreformattedText(cx.element(el))),
+                        "progress" => Some(Self::Progress(cx.element(el))),
+                        "q" => Some(Self::Quotation(cx.element(el))),
+                        "ruby" => Some(Self::RubyAnnotation(cx.element(el))),
+                        "rp" => Some(Self::RubyFallbackParenthesis(cx.element(el))),
+                        "rt" => Some(Self::RubyText(cx.element(el))),
+                        "samp" => Some(Self::SampleOutput(cx.element(el))),
+                        "script" => Some(Self::Script(cx.element(el))),
+                        "search" => Some(Self::Search(cx.element(el))),
+                        "section" => Some(Self::Section(cx.element(el))),
+                        "select" => Some(Self::Select(cx.element(el))),
+                        "small" => Some(Self::SideComment(cx.element(el))),
+                        "slot" => Some(Self::Slot(cx.element(el))),
+                        "span" => Some(Self::Span(cx.element(el))),
+                        "s" => Some(Self::StrikeThrough(cx.element(el))),
+                        "strong" => Some(Self::Strong(cx.element(el))),
+                        "style" => Some(Self::Style(cx.element(el))),
+                        "sub" => Some(Self::SubScript(cx.element(el))),
+                        "summary" => Some(Self::Summary(cx.element(el))),
+                        "sup" => Some(Self::SuperScript(cx.element(el))),
+                        "svg" => Some(Self::Svg(cx.element(el))),
+                        "table" => Some(Self::Table(cx.element(el))),
+                        "tbody" => Some(Self::TableBody(cx.element(el))),
+                        "td" => Some(Self::TableCell(cx.element(el))),
+                        "col" => Some(Self::TableColumn(cx.element(el))),
+                        "colgroup" => Some(Self::TableColumnGroup(cx.element(el))),
+                        "tfoot" => Some(Self::TableFoot(cx.element(el))),
+                        "thead" => Some(Self::TableHead(cx.element(el))),
+                        "th" => Some(Self::TableHeader(cx.element(el))),
+                        "tr" => Some(Self::TableRow(cx.element(el))),
+                        "template" => Some(Self::Template(cx.element(el))),
+                        "textarea" => Some(Self::TextArea(cx.element(el))),
+                        "track" => Some(Self::TextTrack(cx.element(el))),
+                        "hr" => Some(Self::ThematicBreak(cx.element(el))),
+                        "time" => Some(Self::Time(cx.element(el))),
+                        "title" => Some(Self::Title(cx.element(el))),
+                        "u" => Some(Self::Underline(cx.element(el))),
+                        "ul" => Some(Self::UnorderedList(cx.element(el))),
+                        "var" => Some(Self::Variable(cx.element(el))),
+                        "video" => Some(Self::Video(cx.element(el))),
+                        _ => cx.not_permitted(content),
+                    }
+                }
+            }
+        }
+    }
+    impl<'a> From<&'a ImageMapChild> for crate::tree::Part<'a> {
+        fn from(child: &'a ImageMapChild) -> Self {
+            match child {
+                ImageMapChild::Abbreviation(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Address(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Anchor(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Article(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Aside(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Audio(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Base(el) => crate::tree::Part::Element(el),
+                ImageMapChild::BidirectionalIsolate(el) => crate::tree::Part::Element(el),
+                ImageMapChild::BidirectionalTextOverride(el) => {
+                    crate::tree::Part::Element(el)
+                }
+                ImageMapChild::BlockQuote(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Body(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Bold(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Button(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Canvas(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Caption(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Cite(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Code(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Data(el) => crate::tree::Part::Element(el),
+                ImageMapChild::DataList(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Definition(el) => crate::tree::Part::Element(el),
+                ImageMapChild::DeletedText(el) => crate::tree::Part::Element(el),
+                ImageMapChild::DescriptionDetails(el) => crate::tree::Part::Element(el),
+                ImageMapChild::DescriptionList(el) => crate::tree::Part::Element(el),
+                ImageMapChild::DescriptionTerm(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Details(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Dialog(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Division(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Embed(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Emphasis(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Fieldset(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Figure(el) => crate::tree::Part::Element(el),
+                ImageMapChild::FigureCaption(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Footer(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Form(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Head(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Header(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Heading1(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Heading2(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Heading3(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Heading4(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Heading5(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Heading6(el) => crate::tree::Part::Element(el),
+                ImageMapChild::HeadingGroup(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Html(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Iframe(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Image(el) => crate::tree::Part::Element(el),
+                ImageMapChild::ImageMap(el) => crate::tree::Part::Element(el),
+                ImageMapChild::ImageMapArea(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Input(el) => crate::tree::Part::Element(el),
+                ImageMapChild::InsertedText(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Italic(el) => crate::tree::Part::Element(el),
+                ImageMapChild::KeyboardInput(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Label(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Legend(el) => crate::tree::Part::Element(el),
+                ImageMapChild::LineBreak(el) => crate::tree::Part::Element(el),
+                ImageMapChild::LineBreakOpportunity(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Link(el) => crate::tree::Part::Element(el),
+                ImageMapChild::ListItem(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Main(el) => crate::tree::Part::Element(el),
+                ImageMapChild::MarkText(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Math(el) => crate::tree::Part::Element(el),
+                ImageMapChild::MediaSource(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Menu(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Meta(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Meter(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Navigation(el) => crate::tree::Part::Element(el),
+                ImageMapChild::NoScript(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Object(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Option(el) => crate::tree::Part::Element(el),
+                ImageMapChild::OptionGroup(el) => crate::tree::Part::Element(el),
+                ImageMapChild::OrderedList(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Output(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Paragraph(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Picture(el) => crate::tree::Part::Element(el),
+                ImageMapChild::PreformattedText(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Progress(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Quotation(el) => crate::tree::Part::Element(el),
+                ImageMapChild::RubyAnnotation(el) => crate::tree::Part::Element(el),
+                ImageMapChild::RubyFallbackParenthesis(el) => {
+                    crate::tree::Part::Element(el)
+                }
+                ImageMapChild::RubyText(el) => crate::tree::Part::Element(el),
+                ImageMapChild::SampleOutput(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Script(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Search(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Section(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Select(el) => crate::tree::Part::Element(el),
+                ImageMapChild::SideComment(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Slot(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Span(el) => crate::tree::Part::Element(el),
+                ImageMapChild::StrikeThrough(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Strong(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Style(el) => crate::tree::Part::Element(el),
+                ImageMapChild::SubScript(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Summary(el) => crate::tree::Part::Element(el),
+                ImageMapChild::SuperScript(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Svg(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Table(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableBody(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableCell(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableColumn(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableColumnGroup(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableFoot(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableHead(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableHeader(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TableRow(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Template(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Text(el) => {
+                    crate::tree::Part::Text(html_sys::escape::Text(el))
+                }
+                ImageMapChild::TextArea(el) => crate::tree::Part::Element(el),
+                ImageMapChild::TextTrack(el) => crate::tree::Part::Element(el),
+                ImageMapChild::ThematicBreak(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Time(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Title(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Underline(el) => crate::tree::Part::Element(el),
+                ImageMapChild::UnorderedList(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Variable(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Video(el) => crate::tree::Part::Element(el),
+                ImageMapChild::Comment(el) => {
+                    crate::tree::Part::Comment(html_sys::escape::Comment(el.as_str()))
+                }
+                ImageMapChild::RawHtml(el) => crate::tree::Part::RawHtml(el.as_str()),
+            }
+        }
+    }
+    impl<'a> From<&'a ImageMapChild> for crate::render::Pretty<'a> {
+        fn from(child: &'a ImageMapChild) -> Self {
+            crate::render::Pretty::new(child.into())
+        }
+    }
+    impl<'a> From<&'a ImageMapChild> for crate::minify::Minified<'a> {
+        fn from(child: &'a ImageMapChild) -> Self {
+            crate::minify::Minified::new(child.into())
+        }
+    }
+    impl ImageMapChild {
+        /// Clone a node into a child, if it's permitted.
+        pub(crate) fn from_node(
+            node: crate::visit::NodeRef<'_>,
+        ) -> std::option::Option<Self> {
+            match node {
+                crate::visit::NodeRef::Element(el) => {
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Abbreviation>()
+                    {
+                        return Some(Self::Abbreviation(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Address>()
+                    {
+                        return Some(Self::Address(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Anchor>()
+                    {
+                        return Some(Self::Anchor(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Article>()
+                    {
+                        return Some(Self::Article(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Aside>() {
+                        return Some(Self::Aside(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Audio>() {
+                        return Some(Self::Audio(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Base>() {
+                        return Some(Self::Base(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::BidirectionalIsolate>()
+                    {
+                        return Some(Self::BidirectionalIsolate(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<
+                            crate::generated::all::BidirectionalTextOverride,
+                        >()
+                    {
+                        return Some(Self::BidirectionalTextOverride(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::BlockQuote>()
+                    {
+                        return Some(Self::BlockQuote(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Body>() {
+                        return Some(Self::Body(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Bold>() {
+                        return Some(Self::Bold(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Button>()
+                    {
+                        return Some(Self::Button(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Canvas>()
+                    {
+                        return Some(Self::Canvas(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Caption>()
+                    {
+                        return Some(Self::Caption(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Cite>() {
+                        return Some(Self::Cite(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Code>() {
+                        return Some(Self::Code(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Data>() {
+                        return Some(Self::Data(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DataList>()
+                    {
+                        return Some(Self::DataList(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Definition>()
+                    {
+                        return Some(Self::Definition(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DeletedText>()
+                    {
+                        return Some(Self::DeletedText(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DescriptionDetails>()
+                    {
+                        return Some(Self::DescriptionDetails(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DescriptionList>()
+                    {
+                        return Some(Self::DescriptionList(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::DescriptionTerm>()
+                    {
+                        return Some(Self::DescriptionTerm(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Details>()
+                    {
+                        return Some(Self::Details(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Dialog>()
+                    {
+                        return Some(Self::Dialog(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Division>()
+                    {
+                        return Some(Self::Division(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Embed>() {
+                        return Some(Self::Embed(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Emphasis>()
+                    {
+                        return Some(Self::Emphasis(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Fieldset>()
+                    {
+                        return Some(Self::Fieldset(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Figure>()
+                    {
+                        return Some(Self::Figure(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::FigureCaption>()
+                    {
+                        return Some(Self::FigureCaption(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Footer>()
+                    {
+                        return Some(Self::Footer(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Form>() {
+                        return Some(Self::Form(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Head>() {
+                        return Some(Self::Head(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Header>()
+                    {
+                        return Some(Self::Header(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Heading1>()
+                    {
+                        return Some(Self::Heading1(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Heading2>()
+                    {
+                        return Some(Self::Heading2(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Heading3>()
+                    {
+                        return Some(Self::Heading3(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Heading4>()
+                    {
+                        return Some(Self::Heading4(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Heading5>()
+                    {
+                        return Some(Self::Heading5(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Heading6>()
+                    {
+                        return Some(Self::Heading6(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::HeadingGroup>()
+                    {
+                        return Some(Self::HeadingGroup(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Html>() {
+                        return Some(Self::Html(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Iframe>()
+                    {
+                        return Some(Self::Iframe(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Image>() {
+                        return Some(Self::Image(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ImageMap>()
+                    {
+                        return Some(Self::ImageMap(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ImageMapArea>()
+                    {
+                        return Some(Self::ImageMapArea(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Input>() {
+                        return Some(Self::Input(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::InsertedText>()
+                    {
+                        return Some(Self::InsertedText(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Italic>()
+                    {
+                        return Some(Self::Italic(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::KeyboardInput>()
+                    {
+                        return Some(Self::KeyboardInput(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Label>() {
+                        return Some(Self::Label(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Legend>()
+                    {
+                        return Some(Self::Legend(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::LineBreak>()
+                    {
+                        return Some(Self::LineBreak(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::LineBreakOpportunity>()
+                    {
+                        return Some(Self::LineBreakOpportunity(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Link>() {
+                        return Some(Self::Link(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ListItem>()
+                    {
+                        return Some(Self::ListItem(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Main>() {
+                        return Some(Self::Main(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::MarkText>()
+                    {
+                        return Some(Self::MarkText(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Math>() {
+                        return Some(Self::Math(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::MediaSource>()
+                    {
+                        return Some(Self::MediaSource(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Menu>() {
+                        return Some(Self::Menu(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Meta>() {
+                        return Some(Self::Meta(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Meter>() {
+                        return Some(Self::Meter(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Navigation>()
+                    {
+                        return Some(Self::Navigation(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::NoScript>()
+                    {
+                        return Some(Self::NoScript(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Object>()
+                    {
+                        return Some(Self::Object(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Option>()
+                    {
+                        return Some(Self::Option(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::OptionGroup>()
+                    {
+                        return Some(Self::OptionGroup(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::OrderedList>()
+                    {
+                        return Some(Self::OrderedList(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Output>()
+                    {
+                        return Some(Self::Output(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Paragraph>()
+                    {
+                        return Some(Self::Paragraph(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Picture>()
+                    {
+                        return Some(Self::Picture(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::PreformattedText>()
+                    {
+                        return Some(Self::PreformattedText(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Progress>()
+                    {
+                        return Some(Self::Progress(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Quotation>()
+                    {
+                        return Some(Self::Quotation(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::RubyAnnotation>()
+                    {
+                        return Some(Self::RubyAnnotation(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::RubyFallbackParenthesis>()
+                    {
+                        return Some(Self::RubyFallbackParenthesis(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::RubyText>()
+                    {
+                        return Some(Self::RubyText(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SampleOutput>()
+                    {
+                        return Some(Self::SampleOutput(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Script>()
+                    {
+                        return Some(Self::Script(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Search>()
+                    {
+                        return Some(Self::Search(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Section>()
+                    {
+                        return Some(Self::Section(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Select>()
+                    {
+                        return Some(Self::Select(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SideComment>()
+                    {
+                        return Some(Self::SideComment(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Slot>() {
+                        return Some(Self::Slot(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Span>() {
+                        return Some(Self::Span(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::StrikeThrough>()
+                    {
+                        return Some(Self::StrikeThrough(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Strong>()
+                    {
+                        return Some(Self::Strong(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Style>() {
+                        return Some(Self::Style(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SubScript>()
+                    {
+                        return Some(Self::SubScript(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Summary>()
+                    {
+                        return Some(Self::Summary(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SuperScript>()
+                    {
+                        return Some(Self::SuperScript(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Svg>() {
+                        return Some(Self::Svg(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Table>() {
+                        return Some(Self::Table(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableBody>()
+                    {
+                        return Some(Self::TableBody(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableCell>()
+                    {
+                        return Some(Self::TableCell(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableColumn>()
+                    {
+                        return Some(Self::TableColumn(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableColumnGroup>()
+                    {
+                        return Some(Self::TableColumnGroup(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableFoot>()
+                    {
+                        return Some(Self::TableFoot(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableHead>()
+                    {
+                        return Some(Self::TableHead(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableHeader>()
+                    {
+                        return Some(Self::TableHeader(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TableRow>()
+                    {
+                        return Some(Self::TableRow(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Template>()
+                    {
+                        return Some(Self::Template(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TextArea>()
+                    {
+                        return Some(Self::TextArea(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::TextTrack>()
+                    {
+                        return Some(Self::TextTrack(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ThematicBreak>()
+                    {
+                        return Some(Self::ThematicBreak(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Time>() {
+                        return Some(Self::Time(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Title>() {
+                        return Some(Self::Title(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Underline>()
+                    {
+                        return Some(Self::Underline(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::UnorderedList>()
+                    {
+                        return Some(Self::UnorderedList(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Variable>()
+                    {
+                        return Some(Self::Variable(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Video>() {
+                        return Some(Self::Video(el.clone()));
+                    }
+                    None
+                }
+                crate::visit::NodeRef::Text(text) => {
+                    Some(Self::Text(text.to_owned().into()))
+                }
+                crate::visit::NodeRef::Comment(comment) => {
+                    Some(Self::Comment(comment.clone()))
+                }
+                crate::visit::NodeRef::RawHtml(html) => Some(Self::RawHtml(html.clone())),
+            }
+        }
+    }
+    impl<'a> From<&'a ImageMapChild> for crate::visit::NodeRef<'a> {
+        fn from(child: &'a ImageMapChild) -> Self {
+            match child {
+                ImageMapChild::Abbreviation(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Address(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Anchor(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Article(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Aside(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Audio(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Base(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::BidirectionalIsolate(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                ImageMapChild::BidirectionalTextOverride(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                ImageMapChild::BlockQuote(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Body(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Bold(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Button(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Canvas(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Caption(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Cite(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Code(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Data(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::DataList(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Definition(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::DeletedText(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::DescriptionDetails(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                ImageMapChild::DescriptionList(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::DescriptionTerm(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Details(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Dialog(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Division(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Embed(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Emphasis(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Fieldset(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Figure(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::FigureCaption(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Footer(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Form(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Head(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Header(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Heading1(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Heading2(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Heading3(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Heading4(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Heading5(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Heading6(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::HeadingGroup(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Html(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Iframe(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Image(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::ImageMap(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::ImageMapArea(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Input(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::InsertedText(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Italic(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::KeyboardInput(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Label(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Legend(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::LineBreak(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::LineBreakOpportunity(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                ImageMapChild::Link(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::ListItem(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Main(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::MarkText(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Math(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::MediaSource(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Menu(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Meta(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Meter(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Navigation(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::NoScript(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Object(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Option(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::OptionGroup(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::OrderedList(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Output(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Paragraph(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Picture(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::PreformattedText(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Progress(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Quotation(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::RubyAnnotation(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::RubyFallbackParenthesis(el) => {
+                    crate::visit::NodeRef::Element(el)
+                }
+                ImageMapChild::RubyText(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::SampleOutput(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Script(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Search(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Section(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Select(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::SideComment(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Slot(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Span(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::StrikeThrough(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Strong(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Style(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::SubScript(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Summary(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::SuperScript(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Svg(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Table(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableBody(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableCell(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableColumn(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableColumnGroup(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableFoot(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableHead(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableHeader(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TableRow(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Template(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Text(el) => crate::visit::NodeRef::Text(el),
+                ImageMapChild::TextArea(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::TextTrack(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::ThematicBreak(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Time(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Title(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Underline(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::UnorderedList(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Variable(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Video(el) => crate::visit::NodeRef::Element(el),
+                ImageMapChild::Comment(el) => crate::visit::NodeRef::Comment(el),
+                ImageMapChild::RawHtml(el) => crate::visit::NodeRef::RawHtml(el),
+            }
+        }
+    }
+    impl<'a> From<&'a mut ImageMapChild> for crate::visit::NodeMut<'a> {
+        fn from(child: &'a mut ImageMapChild) -> Self {
+            match child {
+                ImageMapChild::Abbreviation(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Address(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Anchor(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Article(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Aside(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Audio(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Base(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::BidirectionalIsolate(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                ImageMapChild::BidirectionalTextOverride(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                ImageMapChild::BlockQuote(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Body(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Bold(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Button(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Canvas(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Caption(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Cite(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Code(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Data(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::DataList(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Definition(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::DeletedText(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::DescriptionDetails(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                ImageMapChild::DescriptionList(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::DescriptionTerm(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Details(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Dialog(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Division(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Embed(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Emphasis(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Fieldset(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Figure(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::FigureCaption(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Footer(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Form(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Head(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Header(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Heading1(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Heading2(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Heading3(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Heading4(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Heading5(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Heading6(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::HeadingGroup(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Html(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Iframe(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Image(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::ImageMap(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::ImageMapArea(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Input(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::InsertedText(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Italic(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::KeyboardInput(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Label(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Legend(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::LineBreak(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::LineBreakOpportunity(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                ImageMapChild::Link(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::ListItem(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Main(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::MarkText(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Math(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::MediaSource(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Menu(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Meta(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Meter(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Navigation(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::NoScript(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Object(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Option(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::OptionGroup(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::OrderedList(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Output(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Paragraph(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Picture(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::PreformattedText(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Progress(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Quotation(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::RubyAnnotation(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::RubyFallbackParenthesis(el) => {
+                    crate::visit::NodeMut::Element(el)
+                }
+                ImageMapChild::RubyText(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::SampleOutput(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Script(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Search(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Section(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Select(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::SideComment(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Slot(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Span(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::StrikeThrough(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Strong(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Style(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::SubScript(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Summary(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::SuperScript(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Svg(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Table(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableBody(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableCell(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableColumn(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableColumnGroup(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableFoot(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableHead(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableHeader(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TableRow(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Template(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Text(el) => crate::visit::NodeMut::Text(el),
+                ImageMapChild::TextArea(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::TextTrack(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::ThematicBreak(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Time(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Title(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Underline(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::UnorderedList(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Variable(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Video(el) => crate::visit::NodeMut::Element(el),
+                ImageMapChild::Comment(el) => crate::visit::NodeMut::Comment(el),
+                ImageMapChild::RawHtml(el) => crate::visit::NodeMut::RawHtml(el),
+            }
+        }
+    }
+    impl std::convert::TryFrom<crate::AnyElement> for ImageMapChild {
+        type Error = crate::NotPermitted;
+        fn try_from(element: crate::AnyElement) -> Result<Self, Self::Error> {
+            match element {
+                crate::AnyElement::Abbreviation(el) => Ok(Self::Abbreviation(el)),
+                crate::AnyElement::Address(el) => Ok(Self::Address(el)),
+                crate::AnyElement::Anchor(el) => Ok(Self::Anchor(el)),
+                crate::AnyElement::Article(el) => Ok(Self::Article(el)),
+                crate::AnyElement::Aside(el) => Ok(Self::Aside(el)),
+                crate::AnyElement::Audio(el) => Ok(Self::Audio(el)),
+                crate::AnyElement::Base(el) => Ok(Self::Base(el)),
+                crate::AnyElement::BidirectionalIsolate(el) => {
+                    Ok(Self::BidirectionalIsolate(el))
+                }
+                crate::AnyElement::BidirectionalTextOverride(el) => {
+                    Ok(Self::BidirectionalTextOverride(el))
+                }
+                crate::AnyElement::BlockQuote(el) => Ok(Self::BlockQuote(el)),
+                crate::AnyElement::Body(el) => Ok(Self::Body(el)),
+                crate::AnyElement::Bold(el) => Ok(Self::Bold(el)),
+                crate::AnyElement::Button(el) => Ok(Self::Button(el)),
+                crate::AnyElement::Canvas(el) => Ok(Self::Canvas(el)),
+                crate::AnyElement::Caption(el) => Ok(Self::Caption(el)),
+                crate::AnyElement::Cite(el) => Ok(Self::Cite(el)),
+                crate::AnyElement::Code(el) => Ok(Self::Code(el)),
+                crate::AnyElement::Data(el) => Ok(Self::Data(el)),
+                crate::AnyElement::DataList(el) => Ok(Self::DataList(el)),
+                crate::AnyElement::Definition(el) => Ok(Self::Definition(el)),
+                crate::AnyElement::DeletedText(el) => Ok(Self::DeletedText(el)),
+                crate::AnyElement::DescriptionDetails(el) => {
+                    Ok(Self::DescriptionDetails(el))
+                }
+                crate::AnyElement::DescriptionList(el) => Ok(Self::DescriptionList(el)),
+                crate::AnyElement::DescriptionTerm(el) => Ok(Self::DescriptionTerm(el)),
+                crate::AnyElement::Details(el) => Ok(Self::Details(el)),
+                crate::AnyElement::Dialog(el) => Ok(Self::Dialog(el)),
+                crate::AnyElement::Division(el) => Ok(Self::Division(el)),
+                crate::AnyElement::Embed(el) => Ok(Self::Embed(el)),
+                crate::AnyElement::Emphasis(el) => Ok(Self::Emphasis(el)),
+                crate::AnyElement::Fieldset(el) => Ok(Self::Fieldset(el)),
+                crate::AnyElement::Figure(el) => Ok(Self::Figure(el)),
+                crate::AnyElement::FigureCaption(el) => Ok(Self::FigureCaption(el)),
+                crate::AnyElement::Footer(el) => Ok(Self::Footer(el)),
+                crate::AnyElement::Form(el) => Ok(Self::Form(el)),
+                crate::AnyElement::Head(el) => Ok(Self::Head(el)),
+                crate::AnyElement::Header(el) => Ok(Self::Header(el)),
+                crate::AnyElement::Heading1(el) => Ok(Self::Heading1(el)),
+                crate::AnyElement::Heading2(el) => Ok(Self::Heading2(el)),
+                crate::AnyElement::Heading3(el) => Ok(Self::Heading3(el)),
+                crate::AnyElement::Heading4(el) => Ok(Self::Heading4(el)),
+                crate::AnyElement::Heading5(el) => Ok(Self::Heading5(el)),
+                crate::AnyElement::Heading6(el) => Ok(Self::Heading6(el)),
+                crate::AnyElement::HeadingGroup(el) => Ok(Self::HeadingGroup(el)),
+                crate::AnyElement::Html(el) => Ok(Self::Html(el)),
+                crate::AnyElement::Iframe(el) => Ok(Self::Iframe(el)),
+                crate::AnyElement::Image(el) => Ok(Self::Image(el)),
+                crate::AnyElement::ImageMap(el) => Ok(Self::ImageMap(el)),
+                crate::AnyElement::ImageMapArea(el) => Ok(Self::ImageMapArea(el)),
+                crate::AnyElement::Input(el) => Ok(Self::Input(el)),
+                crate::AnyElement::InsertedText(el) => Ok(Self::InsertedText(el)),
+                crate::AnyElement::Italic(el) => Ok(Self::Italic(el)),
+                crate::AnyElement::KeyboardInput(el) => Ok(Self::KeyboardInput(el)),
+                crate::AnyElement::Label(el) => Ok(Self::Label(el)),
+                crate::AnyElement::Legend(el) => Ok(Self::Legend(el)),
+                crate::AnyElement::LineBreak(el) => Ok(Self::LineBreak(el)),
+                crate::AnyElement::LineBreakOpportunity(el) => {
+                    Ok(Self::LineBreakOpportunity(el))
+                }
+                crate::AnyElement::Link(el) => Ok(Self::Link(el)),
+                crate::AnyElement::ListItem(el) => Ok(Self::ListItem(el)),
+                crate::AnyElement::Main(el) => Ok(Self::Main(el)),
+                crate::AnyElement::MarkText(el) => Ok(Self::MarkText(el)),
+                crate::AnyElement::Math(el) => Ok(Self::Math(el)),
+                crate::AnyElement::MediaSource(el) => Ok(Self::MediaSource(el)),
+                crate::AnyElement::Menu(el) => Ok(Self::Menu(el)),
+                crate::AnyElement::Meta(el) => Ok(Self::Meta(el)),
+                crate::AnyElement::Meter(el) => Ok(Self::Meter(el)),
+                crate::AnyElement::Navigation(el) => Ok(Self::Navigation(el)),
+                crate::AnyElement::NoScript(el) => Ok(Self::NoScript(el)),
+                crate::AnyElement::Object(el) => Ok(Self::Object(el)),
+                crate::AnyElement::Option(el) => Ok(Self::Option(el)),
+                crate::AnyElement::OptionGroup(el) => Ok(Self::OptionGroup(el)),
+                crate::AnyElement::OrderedList(el) => Ok(Self::OrderedList(el)),
+                crate::AnyElement::Output(el) => Ok(Self::Output(el)),
+                crate::AnyElement::Paragraph(el) => Ok(Self::Paragraph(el)),
+                crate::AnyElement::Picture(el) => Ok(Self::Picture(el)),
+                crate::AnyElement::PreformattedText(el) => Ok(Self::PreformattedText(el)),
+                crate::AnyElement::Progress(el) => Ok(Self::Progress(el)),
+                crate::AnyElement::Quotation(el) => Ok(Self::Quotation(el)),
+                crate::AnyElement::RubyAnnotation(el) => Ok(Self::RubyAnnotation(el)),
+                crate::AnyElement::RubyFallbackParenthesis(el) => {
+                    Ok(Self::RubyFallbackParenthesis(el))
+                }
+                crate::AnyElement::RubyText(el) => Ok(Self::RubyText(el)),
+                crate::AnyElement::SampleOutput(el) => Ok(Self::SampleOutput(el)),
+                crate::AnyElement::Script(el) => Ok(Self::Script(el)),
+                crate::AnyElement::Search(el) => Ok(Self::Search(el)),
+                crate::AnyElement::Section(el) => Ok(Self::Section(el)),
+                crate::AnyElement::Select(el) => Ok(Self::Select(el)),
+                crate::AnyElement::SideComment(el) => Ok(Self::SideComment(el)),
+                crate::AnyElement::Slot(el) => Ok(Self::Slot(el)),
+                crate::AnyElement::Span(el) => Ok(Self::Span(el)),
+                crate::AnyElement::StrikeThrough(el) => Ok(Self::StrikeThrough(el)),
+                crate::AnyElement::Strong(el) => Ok(Self::Strong(el)),
+                crate::AnyElement::Style(el) => Ok(Self::Style(el)),
+                crate::AnyElement::SubScript(el) => Ok(Self::SubScript(el)),
+                crate::AnyElement::Summary(el) => Ok(Self::Summary(el)),
+                crate::AnyElement::SuperScript(el) => Ok(Self::SuperScript(el)),
+                crate::AnyElement::Svg(el) => Ok(Self::Svg(el)),
+                crate::AnyElement::Table(el) => Ok(Self::Table(el)),
+                crate::AnyElement::TableBody(el) => Ok(Self::TableBody(el)),
+                crate::AnyElement::TableCell(el) => Ok(Self::TableCell(el)),
+                crate::AnyElement::TableColumn(el) => Ok(Self::TableColumn(el)),
+                crate::AnyElement::TableColumnGroup(el) => Ok(Self::TableColumnGroup(el)),
+                crate::AnyElement::TableFoot(el) => Ok(Self::TableFoot(el)),
+                crate::AnyElement::TableHead(el) => Ok(Self::TableHead(el)),
+                crate::AnyElement::TableHeader(el) => Ok(Self::TableHeader(el)),
+                crate::AnyElement::TableRow(el) => Ok(Self::TableRow(el)),
+                crate::AnyElement::Template(el) => Ok(Self::Template(el)),
+                crate::AnyElement::TextArea(el) => Ok(Self::TextArea(el)),
+                crate::AnyElement::TextTrack(el) => Ok(Self::TextTrack(el)),
+                crate::AnyElement::ThematicBreak(el) => Ok(Self::ThematicBreak(el)),
+                crate::AnyElement::Time(el) => Ok(Self::Time(el)),
+                crate::AnyElement::Title(el) => Ok(Self::Title(el)),
+                crate::AnyElement::Underline(el) => Ok(Self::Underline(el)),
+                crate::AnyElement::UnorderedList(el) => Ok(Self::UnorderedList(el)),
+                crate::AnyElement::Variable(el) => Ok(Self::Variable(el)),
+                crate::AnyElement::Video(el) => Ok(Self::Video(el)),
+                #[allow(unreachable_patterns)]
+                element => Err(crate::NotPermitted::new(element, "map")),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for ImageMapChild {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Abbreviation(el) => serde::Serialize::serialize(el, serializer),
+                Self::Address(el) => serde::Serialize::serialize(el, serializer),
+                Self::Anchor(el) => serde::Serialize::serialize(el, serializer),
+                Self::Article(el) => serde::Serialize::serialize(el, serializer),
+                Self::Aside(el) => serde::Serialize::serialize(el, serializer),
+                Self::Audio(el) => serde::Serialize::serialize(el, serializer),
+                Self::Base(el) => serde::Serialize::serialize(el, serializer),
+                Self::BidirectionalIsolate(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::BidirectionalTextOverride(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::BlockQuote(el) => serde::Serialize::serialize(el, serializer),
+                Self::Body(el) => serde::Serialize::serialize(el, serializer),
+                Self::Bold(el) => serde::Serialize::serialize(el, serializer),
+                Self::Button(el) => serde::Serialize::serialize(el, serializer),
+                Self::Canvas(el) => serde::Serialize::serialize(el, serializer),
+                Self::Caption(el) => serde::Serialize::serialize(el, serializer),
+                Self::Cite(el) => serde::Serialize::serialize(el, serializer),
+                Self::Code(el) => serde::Serialize::serialize(el, serializer),
+                Self::Data(el) => serde::Serialize::serialize(el, serializer),
+                Self::DataList(el) => serde::Serialize::serialize(el, serializer),
+                Self::Definition(el) => serde::Serialize::serialize(el, serializer),
+                Self::DeletedText(el) => serde::Serialize::serialize(el, serializer),
+                Self::DescriptionDetails(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::DescriptionList(el) => serde::Serialize::serialize(el, serializer),
+                Self::DescriptionTerm(el) => serde::Serialize::serialize(el, serializer),
+                Self::Details(el) => serde::Serialize::serialize(el, serializer),
+                Self::Dialog(el) => serde::Serialize::serialize(el, serializer),
+                Self::Division(el) => serde::Serialize::serialize(el, serializer),
+                Self::Embed(el) => serde::Serialize::serialize(el, serializer),
+                Self::Emphasis(el) => serde::Serialize::serialize(el, serializer),
+                Self::Fieldset(el) => serde::Serialize::serialize(el, serializer),
+                Self::Figure(el) => serde::Serialize::serialize(el, serializer),
+                Self::FigureCaption(el) => serde::Serialize::serialize(el, serializer),
+                Self::Footer(el) => serde::Serialize::serialize(el, serializer),
+                Self::Form(el) => serde::Serialize::serialize(el, serializer),
+                Self::Head(el) => serde::Serialize::serialize(el, serializer),
+                Self::Header(el) => serde::Serialize::serialize(el, serializer),
+                Self::Heading1(el) => serde::Serialize::serialize(el, serializer),
+                Self::Heading2(el) => serde::Serialize::serialize(el, serializer),
+                Self::Heading3(el) => serde::Serialize::serialize(el, serializer),
+                Self::Heading4(el) => serde::Serialize::serialize(el, serializer),
+                Self::Heading5(el) => serde::Serialize::serialize(el, serializer),
+                Self::Heading6(el) => serde::Serialize::serialize(el, serializer),
+                Self::HeadingGroup(el) => serde::Serialize::serialize(el, serializer),
+                Self::Html(el) => serde::Serialize::serialize(el, serializer),
+                Self::Iframe(el) => serde::Serialize::serialize(el, serializer),
+                Self::Image(el) => serde::Serialize::serialize(el, serializer),
+                Self::ImageMap(el) => serde::Serialize::serialize(el, serializer),
+                Self::ImageMapArea(el) => serde::Serialize::serialize(el, serializer),
+                Self::Input(el) => serde::Serialize::serialize(el, serializer),
+                Self::InsertedText(el) => serde::Serialize::serialize(el, serializer),
+                Self::Italic(el) => serde::Serialize::serialize(el, serializer),
+                Self::KeyboardInput(el) => serde::Serialize::serialize(el, serializer),
+                Self::Label(el) => serde::Serialize::serialize(el, serializer),
+                Self::Legend(el) => serde::Serialize::serialize(el, serializer),
+                Self::LineBreak(el) => serde::Serialize::serialize(el, serializer),
+                Self::LineBreakOpportunity(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::Link(el) => serde::Serialize::serialize(el, serializer),
+                Self::ListItem(el) => serde::Serialize::serialize(el, serializer),
+                Self::Main(el) => serde::Serialize::serialize(el, serializer),
+                Self::MarkText(el) => serde::Serialize::serialize(el, serializer),
+                Self::Math(el) => serde::Serialize::serialize(el, serializer),
+                Self::MediaSource(el) => serde::Serialize::serialize(el, serializer),
+                Self::Menu(el) => serde::Serialize::serialize(el, serializer),
+                Self::Meta(el) => serde::Serialize::serialize(el, serializer),
+                Self::Meter(el) => serde::Serialize::serialize(el, serializer),
+                Self::Navigation(el) => serde::Serialize::serialize(el, serializer),
+                Self::NoScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::Object(el) => serde::Serialize::serialize(el, serializer),
+                Self::Option(el) => serde::Serialize::serialize(el, serializer),
+                Self::OptionGroup(el) => serde::Serialize::serialize(el, serializer),
+                Self::OrderedList(el) => serde::Serialize::serialize(el, serializer),
+                Self::Output(el) => serde::Serialize::serialize(el, serializer),
+                Self::Paragraph(el) => serde::Serialize::serialize(el, serializer),
+                Self::Picture(el) => serde::Serialize::serialize(el, serializer),
+                Self::PreformattedText(el) => serde::Serialize::serialize(el, serializer),
+                Self::Progress(el) => serde::Serialize::serialize(el, serializer),
+                Self::Quotation(el) => serde::Serialize::serialize(el, serializer),
+                Self::RubyAnnotation(el) => serde::Serialize::serialize(el, serializer),
+                Self::RubyFallbackParenthesis(el) => {
+                    serde::Serialize::serialize(el, serializer)
+                }
+                Self::RubyText(el) => serde::Serialize::serialize(el, serializer),
+                Self::SampleOutput(el) => serde::Serialize::serialize(el, serializer),
+                Self::Script(el) => serde::Serialize::serialize(el, serializer),
+                Self::Search(el) => serde::Serialize::serialize(el, serializer),
+                Self::Section(el) => serde::Serialize::serialize(el, serializer),
+                Self::Select(el) => serde::Serialize::serialize(el, serializer),
+                Self::SideComment(el) => serde::Serialize::serialize(el, serializer),
+                Self::Slot(el) => serde::Serialize::serialize(el, serializer),
+                Self::Span(el) => serde::Serialize::serialize(el, serializer),
+                Self::StrikeThrough(el) => serde::Serialize::serialize(el, serializer),
+                Self::Strong(el) => serde::Serialize::serialize(el, serializer),
+                Self::Style(el) => serde::Serialize::serialize(el, serializer),
+                Self::SubScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::Summary(el) => serde::Serialize::serialize(el, serializer),
+                Self::SuperScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::Svg(el) => serde::Serialize::serialize(el, serializer),
+                Self::Table(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableBody(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableCell(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableColumn(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableColumnGroup(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableFoot(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableHead(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableHeader(el) => serde::Serialize::serialize(el, serializer),
+                Self::TableRow(el) => serde::Serialize::serialize(el, serializer),
+                Self::Template(el) => serde::Serialize::serialize(el, serializer),
+                Self::Text(el) => serde::Serialize::serialize(el, serializer),
+                Self::TextArea(el) => serde::Serialize::serialize(el, serializer),
+                Self::TextTrack(el) => serde::Serialize::serialize(el, serializer),
+                Self::ThematicBreak(el) => serde::Serialize::serialize(el, serializer),
+                Self::Time(el) => serde::Serialize::serialize(el, serializer),
+                Self::Title(el) => serde::Serialize::serialize(el, serializer),
+                Self::Underline(el) => serde::Serialize::serialize(el, serializer),
+                Self::UnorderedList(el) => serde::Serialize::serialize(el, serializer),
+                Self::Variable(el) => serde::Serialize::serialize(el, serializer),
+                Self::Video(el) => serde::Serialize::serialize(el, serializer),
+                Self::Comment(el) => serde::Serialize::serialize(el, serializer),
+                Self::RawHtml(el) => serde::Serialize::serialize(el, serializer),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for ImageMapChild {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_child(deserializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeChild for ImageMapChild {
+        const PARENT_TAG_NAME: &'static str = "map";
+        fn from_text(text: String) -> Option<Self> {
+            Some(Self::Text(text.into()))
+        }
+        fn from_comment(comment: crate::Comment) -> Option<Self> {
+            Some(Self::Comment(comment))
+        }
+        fn from_raw_html(html: crate::RawHtml) -> Self {
+            Self::RawHtml(html)
+        }
+        fn deserialize_element<'de, M: serde::de::MapAccess<'de>>(
+            tag: &str,
+            map: M,
+        ) -> Result<Self, M::Error> {
+            match tag {
+                "abbr" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::Abbreviation)
+                }
+                "address" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Address)
+                }
+                "a" => crate::serialize::deserialize_element_body(map).map(Self::Anchor),
+                "article" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Article)
+                }
+                "aside" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Aside)
+                }
+                "audio" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Audio)
+                }
+                "base" => crate::serialize::deserialize_element_body(map).map(Self::Base),
+                "bdi" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::BidirectionalIsolate)
+                }
+                "bdo" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::BidirectionalTextOverride)
+                }
+                "blockquote" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::BlockQuote)
+                }
+                "body" => crate::serialize::deserialize_element_body(map).map(Self::Body),
+                "b" => crate::serialize::deserialize_element_body(map).map(Self::Bold),
+                "button" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Button)
+                }
+                "canvas" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Canvas)
+                }
+                "caption" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Caption)
+                }
+                "cite" => crate::serialize::deserialize_element_body(map).map(Self::Cite),
+                "code" => crate::serialize::deserialize_element_body(map).map(Self::Code),
+                "data" => crate::serialize::deserialize_element_body(map).map(Self::Data),
+                "datalist" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::DataList)
+                }
+                "dfn" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Definition)
+                }
+                "del" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::DeletedText)
+                }
+                "dd" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::DescriptionDetails)
+                }
+                "dl" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::DescriptionList)
+                }
+                "dt" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::DescriptionTerm)
+                }
+                "details" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Details)
+                }
+                "dialog" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Dialog)
+                }
+                "div" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Division)
+                }
+                "embed" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Embed)
+                }
+                "em" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Emphasis)
+                }
+                "fieldset" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Fieldset)
+                }
+                "figure" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Figure)
+                }
+                "figcaption" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::FigureCaption)
+                }
+                "footer" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Footer)
+                }
+                "form" => crate::serialize::deserialize_element_body(map).map(Self::Form),
+                "head" => crate::serialize::deserialize_element_body(map).map(Self::Head),
+                "header" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Header)
+                }
+                "h1" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Heading1)
+                }
+                "h2" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Heading2)
+                }
+                "h3" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Heading3)
+                }
+                "h4" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Heading4)
+                }
+                "h5" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Heading5)
+                }
+                "h6" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Heading6)
+                }
+                "hgroup" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::HeadingGroup)
+                }
+                "html" => crate::serialize::deserialize_element_body(map).map(Self::Html),
+                "iframe" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Iframe)
+                }
+                "img" => crate::serialize::deserialize_element_body(map).map(Self::Image),
+                "map" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::ImageMap)
+                }
+                "area" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::ImageMapArea)
+                }
+                "input" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Input)
+                }
+                "ins" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::InsertedText)
+                }
+                "i" => crate::serialize::deserialize_element_body(map).map(Self::Italic),
+                "kbd" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::KeyboardInput)
+                }
+                "label" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Label)
+                }
+                "legend" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Legend)
+                }
+                "br" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::LineBreak)
+                }
+                "wbr" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::LineBreakOpportunity)
+                }
+                "link" => crate::serialize::deserialize_element_body(map).map(Self::Link),
+                "li" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::ListItem)
+                }
+                "main" => crate::serialize::deserialize_element_body(map).map(Self::Main),
+                "mark" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::MarkText)
+                }
+                "math" => crate::serialize::deserialize_element_body(map).map(Self::Math),
+                "source" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::MediaSource)
+                }
+                "menu" => crate::serialize::deserialize_element_body(map).map(Self::Menu),
+                "meta" => crate::serialize::deserialize_element_body(map).map(Self::Meta),
+                "meter" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Meter)
+                }
+                "nav" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Navigation)
+                }
+                "noscript" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::NoScript)
+                }
+                "object" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Object)
+                }
+                "option" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Option)
+                }
+                "optgroup" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::OptionGroup)
+                }
+                "ol" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::OrderedList)
+                }
+                "output" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Output)
+                }
+                "p" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Paragraph)
+                }
+                "picture" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Picture)
+                }
+                "pre" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::PreformattedText)
+                }
+                "progress" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Progress)
+                }
+                "q" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Quotation)
+                }
+                "ruby" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::RubyAnnotation)
+                }
+                "rp" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::RubyFallbackParenthesis)
+                }
+                "rt" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::RubyText)
+                }
+                "samp" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::SampleOutput)
+                }
+                "script" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Script)
+                }
+                "search" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Search)
+                }
+                "section" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Section)
+                }
+                "select" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Select)
+                }
+                "small" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::SideComment)
+                }
+                "slot" => crate::serialize::deserialize_element_body(map).map(Self::Slot),
+                "span" => crate::serialize::deserialize_element_body(map).map(Self::Span),
+                "s" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::StrikeThrough)
+                }
+                "strong" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Strong)
+                }
+                "style" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Style)
+                }
+                "sub" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::SubScript)
+                }
+                "summary" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Summary)
+                }
+                "sup" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::SuperScript)
+                }
+                "svg" => crate::serialize::deserialize_element_body(map).map(Self::Svg),
+                "table" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Table)
+                }
+                "tbody" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TableBody)
+                }
+                "td" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TableCell)
+                }
+                "col" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::TableColumn)
+                }
+                "colgroup" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::TableColumnGroup)
+                }
+                "tfoot" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TableFoot)
+                }
+                "thead" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TableHead)
+                }
+                "th" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::TableHeader)
+                }
+                "tr" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TableRow)
+                }
+                "template" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Template)
+                }
+                "textarea" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TextArea)
+                }
+                "track" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::TextTrack)
+                }
+                "hr" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::ThematicBreak)
+                }
+                "time" => crate::serialize::deserialize_element_body(map).map(Self::Time),
+                "title" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Title)
+                }
+                "u" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Underline)
+                }
+                "ul" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::UnorderedList)
+                }
+                "var" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Variable)
+                }
+                "video" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Video)
+                }
+                tag => {
+                    Err(
+                        crate::serialize::child_not_permitted(Self::PARENT_TAG_NAME, tag),
+                    )
+                }
+            }
+        }
+    }
+}
+pub mod builder {
+    /// A builder struct for ImageMap
+    pub struct ImageMapBuilder {
+        element: super::element::ImageMap,
+    }
+    impl ImageMapBuilder {
+        pub(crate) fn new(element: super::element::ImageMap) -> Self {
+            Self { element }
+        }
+        /// Finish building the element
+        pub fn build(&mut self) -> super::element::ImageMap {
+            self.element.clone()
+        }
+        /// Insert a `data-*` property
+        pub fn data(
+            &mut self,
+            data_key: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut ImageMapBuilder {
+            self.element.data_map_mut().insert(data_key.into(), value.into());
+            self
+        }
+        /// Append a new `Abbreviation` element
+        pub fn abbreviation<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AbbreviationBuilder,
+            ) -> &'a mut crate::generated::all::builders::AbbreviationBuilder,
+        {
+            let ty: crate::generated::all::Abbreviation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AbbreviationBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Address` element
+        pub fn address<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AddressBuilder,
+            ) -> &'a mut crate::generated::all::builders::AddressBuilder,
+        {
+            let ty: crate::generated::all::Address = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AddressBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Anchor` element
+        pub fn anchor<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AnchorBuilder,
+            ) -> &'a mut crate::generated::all::builders::AnchorBuilder,
+        {
+            let ty: crate::generated::all::Anchor = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AnchorBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Article` element
+        pub fn article<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ArticleBuilder,
+            ) -> &'a mut crate::generated::all::builders::ArticleBuilder,
+        {
+            let ty: crate::generated::all::Article = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ArticleBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Aside` element
+        pub fn aside<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AsideBuilder,
+            ) -> &'a mut crate::generated::all::builders::AsideBuilder,
+        {
+            let ty: crate::generated::all::Aside = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AsideBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Audio` element
+        pub fn audio<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::AudioBuilder,
+            ) -> &'a mut crate::generated::all::builders::AudioBuilder,
+        {
+            let ty: crate::generated::all::Audio = Default::default();
+            let mut ty_builder = crate::generated::all::builders::AudioBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Base` element
+        pub fn base<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BaseBuilder,
+            ) -> &'a mut crate::generated::all::builders::BaseBuilder,
+        {
+            let ty: crate::generated::all::Base = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BaseBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `BidirectionalIsolate` element
+        pub fn bidirectional_isolate<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
+            ) -> &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
+        {
+            let ty: crate::generated::all::BidirectionalIsolate = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BidirectionalIsolateBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `BidirectionalTextOverride` element
+        pub fn bidirectional_text_override<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
+            ) -> &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
+        {
+            let ty: crate::generated::all::BidirectionalTextOverride = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BidirectionalTextOverrideBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `BlockQuote` element
+        pub fn block_quote<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BlockQuoteBuilder,
+            ) -> &'a mut crate::generated::all::builders::BlockQuoteBuilder,
+        {
+            let ty: crate::generated::all::BlockQuote = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BlockQuoteBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Body` element
+        pub fn body<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BodyBuilder,
+            ) -> &'a mut crate::generated::all::builders::BodyBuilder,
+        {
+            let ty: crate::generated::all::Body = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BodyBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Bold` element
+        pub fn bold<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::BoldBuilder,
+            ) -> &'a mut crate::generated::all::builders::BoldBuilder,
+        {
+            let ty: crate::generated::all::Bold = Default::default();
+            let mut ty_builder = crate::generated::all::builders::BoldBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Button` element
+        pub fn button<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ButtonBuilder,
+            ) -> &'a mut crate::generated::all::builders::ButtonBuilder,
+        {
+            let ty: crate::generated::all::Button = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ButtonBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Canvas` element
+        pub fn canvas<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CanvasBuilder,
+            ) -> &'a mut crate::generated::all::builders::CanvasBuilder,
+        {
+            let ty: crate::generated::all::Canvas = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CanvasBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Caption` element
+        pub fn caption<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CaptionBuilder,
+            ) -> &'a mut crate::generated::all::builders::CaptionBuilder,
+        {
+            let ty: crate::generated::all::Caption = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CaptionBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Cite` element
+        pub fn cite<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CiteBuilder,
+            ) -> &'a mut crate::generated::all::builders::CiteBuilder,
+        {
+            let ty: crate::generated::all::Cite = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CiteBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Code` element
+        pub fn code<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::CodeBuilder,
+            ) -> &'a mut crate::generated::all::builders::CodeBuilder,
+        {
+            let ty: crate::generated::all::Code = Default::default();
+            let mut ty_builder = crate::generated::all::builders::CodeBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Data` element
+        pub fn data_el<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DataBuilder,
+            ) -> &'a mut crate::generated::all::builders::DataBuilder,
+        {
+            let ty: crate::generated::all::Data = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DataBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DataList` element
+        pub fn data_list<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DataListBuilder,
+            ) -> &'a mut crate::generated::all::builders::DataListBuilder,
+        {
+            let ty: crate::generated::all::DataList = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DataListBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Definition` element
+        pub fn definition<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DefinitionBuilder,
+            ) -> &'a mut crate::generated::all::builders::DefinitionBuilder,
+        {
+            let ty: crate::generated::all::Definition = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DefinitionBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DeletedText` element
+        pub fn deleted_text<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DeletedTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::DeletedTextBuilder,
+        {
+            let ty: crate::generated::all::DeletedText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DeletedTextBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DescriptionDetails` element
+        pub fn description_details<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DescriptionDetailsBuilder,
+            ) -> &'a mut crate::generated::all::builders::DescriptionDetailsBuilder,
+        {
+            let ty: crate::generated::all::DescriptionDetails = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DescriptionDetailsBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DescriptionList` element
+        pub fn description_list<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DescriptionListBuilder,
+            ) -> &'a mut crate::generated::all::builders::DescriptionListBuilder,
+        {
+            let ty: crate::generated::all::DescriptionList = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DescriptionListBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `DescriptionTerm` element
+        pub fn description_term<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DescriptionTermBuilder,
+            ) -> &'a mut crate::generated::all::builders::DescriptionTermBuilder,
+        {
+            let ty: crate::generated::all::DescriptionTerm = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DescriptionTermBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Details` element
+        pub fn details<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DetailsBuilder,
+            ) -> &'a mut crate::generated::all::builders::DetailsBuilder,
+        {
+            let ty: crate::generated::all::Details = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DetailsBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Dialog` element
+        pub fn dialog<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DialogBuilder,
+            ) -> &'a mut crate::generated::all::builders::DialogBuilder,
+        {
+            let ty: crate::generated::all::Dialog = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DialogBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Division` element
+        pub fn division<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DivisionBuilder,
+            ) -> &'a mut crate::generated::all::builders::DivisionBuilder,
+        {
+            let ty: crate::generated::all::Division = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DivisionBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Embed` element
+        pub fn embed<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::EmbedBuilder,
+            ) -> &'a mut crate::generated::all::builders::EmbedBuilder,
+        {
+            let ty: crate::generated::all::Embed = Default::default();
+            let mut ty_builder = crate::generated::all::builders::EmbedBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Emphasis` element
+        pub fn emphasis<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::EmphasisBuilder,
+            ) -> &'a mut crate::generated::all::builders::EmphasisBuilder,
+        {
+            let ty: crate::generated::all::Emphasis = Default::default();
+            let mut ty_builder = crate::generated::all::builders::EmphasisBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Fieldset` element
+        pub fn fieldset<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::FieldsetBuilder,
+            ) -> &'a mut crate::generated::all::builders::FieldsetBuilder,
+        {
+            let ty: crate::generated::all::Fieldset = Default::default();
+            let mut ty_builder = crate::generated::all::builders::FieldsetBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Figure` element
+        pub fn figure<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::FigureBuilder,
+            ) -> &'a mut crate::generated::all::builders::FigureBuilder,
+        {
+            let ty: crate::generated::all::Figure = Default::default();
+            let mut ty_builder = crate::generated::all::builders::FigureBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `FigureCaption` element
+        pub fn figure_caption<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::FigureCaptionBuilder,
+            ) -> &'a mut crate::generated::all::builders::FigureCaptionBuilder,
+        {
+            let ty: crate::generated::all::FigureCaption = Default::default();
+            let mut ty_builder = crate::generated::all::builders::FigureCaptionBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Footer` element
+        pub fn footer<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::FooterBuilder,
+            ) -> &'a mut crate::generated::all::builders::FooterBuilder,
+        {
+            let ty: crate::generated::all::Footer = Default::default();
+            let mut ty_builder = crate::generated::all::builders::FooterBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
         }
-    }
-}
-pub mod builder {
-    /// A builder struct for ImageMap
-    pub struct ImageMapBuilder {
-        element: super::element::ImageMap,
-    }
-    impl ImageMapBuilder {
-        pub(crate) fn new(element: super::element::ImageMap) -> Self {
-            Self { element }
+        /// Append a new `Form` element
+        pub fn form<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::FormBuilder,
+            ) -> &'a mut crate::generated::all::builders::FormBuilder,
+        {
+            let ty: crate::generated::all::Form = Default::default();
+            let mut ty_builder = crate::generated::all::builders::FormBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
         }
-        /// Finish building the element
-        pub fn build(&mut self) -> super::element::ImageMap {
-            self.element.clone()
+        /// Append a new `Head` element
+        pub fn head<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::HeadBuilder,
+            ) -> &'a mut crate::generated::all::builders::HeadBuilder,
+        {
+            let ty: crate::generated::all::Head = Default::default();
+            let mut ty_builder = crate::generated::all::builders::HeadBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
         }
-        /// Insert a `data-*` property
-        pub fn data(
-            &mut self,
-            data_key: impl Into<std::borrow::Cow<'static, str>>,
-            value: impl Into<std::borrow::Cow<'static, str>>,
-        ) -> &mut ImageMapBuilder {
-            self.element.data_map_mut().insert(data_key.into(), value.into());
+        /// Append a new `Header` element
+        pub fn header<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::HeaderBuilder,
+            ) -> &'a mut crate::generated::all::builders::HeaderBuilder,
+        {
+            let ty: crate::generated::all::Header = Default::default();
+            let mut ty_builder = crate::generated::all::builders::HeaderBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Abbreviation` element
-        pub fn abbreviation<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Heading1` element
+        pub fn heading_1<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AbbreviationBuilder,
-            ) -> &'a mut crate::generated::all::builders::AbbreviationBuilder,
+                &'a mut crate::generated::all::builders::Heading1Builder,
+            ) -> &'a mut crate::generated::all::builders::Heading1Builder,
         {
-            let ty: crate::generated::all::Abbreviation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AbbreviationBuilder::new(
+            let ty: crate::generated::all::Heading1 = Default::default();
+            let mut ty_builder = crate::generated::all::builders::Heading1Builder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1643,15 +5006,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Address` element
-        pub fn address<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Heading2` element
+        pub fn heading_2<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AddressBuilder,
-            ) -> &'a mut crate::generated::all::builders::AddressBuilder,
+                &'a mut crate::generated::all::builders::Heading2Builder,
+            ) -> &'a mut crate::generated::all::builders::Heading2Builder,
         {
-            let ty: crate::generated::all::Address = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AddressBuilder::new(
+            let ty: crate::generated::all::Heading2 = Default::default();
+            let mut ty_builder = crate::generated::all::builders::Heading2Builder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1659,29 +5022,31 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Anchor` element
-        pub fn anchor<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Heading3` element
+        pub fn heading_3<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AnchorBuilder,
-            ) -> &'a mut crate::generated::all::builders::AnchorBuilder,
+                &'a mut crate::generated::all::builders::Heading3Builder,
+            ) -> &'a mut crate::generated::all::builders::Heading3Builder,
         {
-            let ty: crate::generated::all::Anchor = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AnchorBuilder::new(ty);
+            let ty: crate::generated::all::Heading3 = Default::default();
+            let mut ty_builder = crate::generated::all::builders::Heading3Builder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Article` element
-        pub fn article<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Heading4` element
+        pub fn heading_4<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ArticleBuilder,
-            ) -> &'a mut crate::generated::all::builders::ArticleBuilder,
+                &'a mut crate::generated::all::builders::Heading4Builder,
+            ) -> &'a mut crate::generated::all::builders::Heading4Builder,
         {
-            let ty: crate::generated::all::Article = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ArticleBuilder::new(
+            let ty: crate::generated::all::Heading4 = Default::default();
+            let mut ty_builder = crate::generated::all::builders::Heading4Builder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1689,57 +5054,105 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Aside` element
-        pub fn aside<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Heading5` element
+        pub fn heading_5<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AsideBuilder,
-            ) -> &'a mut crate::generated::all::builders::AsideBuilder,
+                &'a mut crate::generated::all::builders::Heading5Builder,
+            ) -> &'a mut crate::generated::all::builders::Heading5Builder,
         {
-            let ty: crate::generated::all::Aside = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AsideBuilder::new(ty);
+            let ty: crate::generated::all::Heading5 = Default::default();
+            let mut ty_builder = crate::generated::all::builders::Heading5Builder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Audio` element
-        pub fn audio<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Heading6` element
+        pub fn heading_6<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::AudioBuilder,
-            ) -> &'a mut crate::generated::all::builders::AudioBuilder,
+                &'a mut crate::generated::all::builders::Heading6Builder,
+            ) -> &'a mut crate::generated::all::builders::Heading6Builder,
         {
-            let ty: crate::generated::all::Audio = Default::default();
-            let mut ty_builder = crate::generated::all::builders::AudioBuilder::new(ty);
+            let ty: crate::generated::all::Heading6 = Default::default();
+            let mut ty_builder = crate::generated::all::builders::Heading6Builder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Base` element
-        pub fn base<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `HeadingGroup` element
+        pub fn heading_group<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BaseBuilder,
-            ) -> &'a mut crate::generated::all::builders::BaseBuilder,
+                &'a mut crate::generated::all::builders::HeadingGroupBuilder,
+            ) -> &'a mut crate::generated::all::builders::HeadingGroupBuilder,
         {
-            let ty: crate::generated::all::Base = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BaseBuilder::new(ty);
+            let ty: crate::generated::all::HeadingGroup = Default::default();
+            let mut ty_builder = crate::generated::all::builders::HeadingGroupBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `BidirectionalIsolate` element
-        pub fn bidirectional_isolate<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Html` element
+        pub fn html<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
-            ) -> &'a mut crate::generated::all::builders::BidirectionalIsolateBuilder,
+                &'a mut crate::generated::all::builders::HtmlBuilder,
+            ) -> &'a mut crate::generated::all::builders::HtmlBuilder,
         {
-            let ty: crate::generated::all::BidirectionalIsolate = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BidirectionalIsolateBuilder::new(
+            let ty: crate::generated::all::Html = Default::default();
+            let mut ty_builder = crate::generated::all::builders::HtmlBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Iframe` element
+        pub fn iframe<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::IframeBuilder,
+            ) -> &'a mut crate::generated::all::builders::IframeBuilder,
+        {
+            let ty: crate::generated::all::Iframe = Default::default();
+            let mut ty_builder = crate::generated::all::builders::IframeBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Image` element
+        pub fn image<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ImageBuilder,
+            ) -> &'a mut crate::generated::all::builders::ImageBuilder,
+        {
+            let ty: crate::generated::all::Image = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ImageBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `ImageMap` element
+        pub fn image_map<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ImageMapBuilder,
+            ) -> &'a mut crate::generated::all::builders::ImageMapBuilder,
+        {
+            let ty: crate::generated::all::ImageMap = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ImageMapBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1747,15 +5160,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `BidirectionalTextOverride` element
-        pub fn bidirectional_text_override<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `ImageMapArea` element
+        pub fn image_map_area<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
-            ) -> &'a mut crate::generated::all::builders::BidirectionalTextOverrideBuilder,
+                &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
+            ) -> &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
         {
-            let ty: crate::generated::all::BidirectionalTextOverride = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BidirectionalTextOverrideBuilder::new(
+            let ty: crate::generated::all::ImageMapArea = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ImageMapAreaBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1763,15 +5176,29 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `BlockQuote` element
-        pub fn block_quote<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Input` element
+        pub fn input<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BlockQuoteBuilder,
-            ) -> &'a mut crate::generated::all::builders::BlockQuoteBuilder,
+                &'a mut crate::generated::all::builders::InputBuilder,
+            ) -> &'a mut crate::generated::all::builders::InputBuilder,
         {
-            let ty: crate::generated::all::BlockQuote = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BlockQuoteBuilder::new(
+            let ty: crate::generated::all::Input = Default::default();
+            let mut ty_builder = crate::generated::all::builders::InputBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `InsertedText` element
+        pub fn inserted_text<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::InsertedTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::InsertedTextBuilder,
+        {
+            let ty: crate::generated::all::InsertedText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::InsertedTextBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1779,71 +5206,89 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Body` element
-        pub fn body<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Italic` element
+        pub fn italic<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ItalicBuilder,
+            ) -> &'a mut crate::generated::all::builders::ItalicBuilder,
+        {
+            let ty: crate::generated::all::Italic = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ItalicBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `KeyboardInput` element
+        pub fn keyboard_input<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BodyBuilder,
-            ) -> &'a mut crate::generated::all::builders::BodyBuilder,
+                &'a mut crate::generated::all::builders::KeyboardInputBuilder,
+            ) -> &'a mut crate::generated::all::builders::KeyboardInputBuilder,
         {
-            let ty: crate::generated::all::Body = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BodyBuilder::new(ty);
+            let ty: crate::generated::all::KeyboardInput = Default::default();
+            let mut ty_builder = crate::generated::all::builders::KeyboardInputBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Bold` element
-        pub fn bold<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Label` element
+        pub fn label<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::BoldBuilder,
-            ) -> &'a mut crate::generated::all::builders::BoldBuilder,
+                &'a mut crate::generated::all::builders::LabelBuilder,
+            ) -> &'a mut crate::generated::all::builders::LabelBuilder,
         {
-            let ty: crate::generated::all::Bold = Default::default();
-            let mut ty_builder = crate::generated::all::builders::BoldBuilder::new(ty);
+            let ty: crate::generated::all::Label = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LabelBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Button` element
-        pub fn button<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Legend` element
+        pub fn legend<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ButtonBuilder,
-            ) -> &'a mut crate::generated::all::builders::ButtonBuilder,
+                &'a mut crate::generated::all::builders::LegendBuilder,
+            ) -> &'a mut crate::generated::all::builders::LegendBuilder,
         {
-            let ty: crate::generated::all::Button = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ButtonBuilder::new(ty);
+            let ty: crate::generated::all::Legend = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LegendBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Canvas` element
-        pub fn canvas<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `LineBreak` element
+        pub fn line_break<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CanvasBuilder,
-            ) -> &'a mut crate::generated::all::builders::CanvasBuilder,
+                &'a mut crate::generated::all::builders::LineBreakBuilder,
+            ) -> &'a mut crate::generated::all::builders::LineBreakBuilder,
         {
-            let ty: crate::generated::all::Canvas = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CanvasBuilder::new(ty);
+            let ty: crate::generated::all::LineBreak = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LineBreakBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Caption` element
-        pub fn caption<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `LineBreakOpportunity` element
+        pub fn line_break_opportunity<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CaptionBuilder,
-            ) -> &'a mut crate::generated::all::builders::CaptionBuilder,
+                &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
+            ) -> &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
         {
-            let ty: crate::generated::all::Caption = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CaptionBuilder::new(
+            let ty: crate::generated::all::LineBreakOpportunity = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LineBreakOpportunityBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1851,57 +5296,59 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Cite` element
-        pub fn cite<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Link` element
+        pub fn link<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CiteBuilder,
-            ) -> &'a mut crate::generated::all::builders::CiteBuilder,
+                &'a mut crate::generated::all::builders::LinkBuilder,
+            ) -> &'a mut crate::generated::all::builders::LinkBuilder,
         {
-            let ty: crate::generated::all::Cite = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CiteBuilder::new(ty);
+            let ty: crate::generated::all::Link = Default::default();
+            let mut ty_builder = crate::generated::all::builders::LinkBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Code` element
-        pub fn code<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `ListItem` element
+        pub fn list_item<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::CodeBuilder,
-            ) -> &'a mut crate::generated::all::builders::CodeBuilder,
+                &'a mut crate::generated::all::builders::ListItemBuilder,
+            ) -> &'a mut crate::generated::all::builders::ListItemBuilder,
         {
-            let ty: crate::generated::all::Code = Default::default();
-            let mut ty_builder = crate::generated::all::builders::CodeBuilder::new(ty);
+            let ty: crate::generated::all::ListItem = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ListItemBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Data` element
-        pub fn data_el<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Main` element
+        pub fn main<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DataBuilder,
-            ) -> &'a mut crate::generated::all::builders::DataBuilder,
+                &'a mut crate::generated::all::builders::MainBuilder,
+            ) -> &'a mut crate::generated::all::builders::MainBuilder,
         {
-            let ty: crate::generated::all::Data = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DataBuilder::new(ty);
+            let ty: crate::generated::all::Main = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MainBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DataList` element
-        pub fn data_list<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `MarkText` element
+        pub fn mark_text<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DataListBuilder,
-            ) -> &'a mut crate::generated::all::builders::DataListBuilder,
+                &'a mut crate::generated::all::builders::MarkTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::MarkTextBuilder,
         {
-            let ty: crate::generated::all::DataList = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DataListBuilder::new(
+            let ty: crate::generated::all::MarkText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MarkTextBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1909,31 +5356,29 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Definition` element
-        pub fn definition<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Math` element
+        pub fn math<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DefinitionBuilder,
-            ) -> &'a mut crate::generated::all::builders::DefinitionBuilder,
+                &'a mut crate::generated::all::builders::MathBuilder,
+            ) -> &'a mut crate::generated::all::builders::MathBuilder,
         {
-            let ty: crate::generated::all::Definition = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DefinitionBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Math = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MathBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DeletedText` element
-        pub fn deleted_text<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `MediaSource` element
+        pub fn media_source<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DeletedTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::DeletedTextBuilder,
+                &'a mut crate::generated::all::builders::MediaSourceBuilder,
+            ) -> &'a mut crate::generated::all::builders::MediaSourceBuilder,
         {
-            let ty: crate::generated::all::DeletedText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DeletedTextBuilder::new(
+            let ty: crate::generated::all::MediaSource = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MediaSourceBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -1941,63 +5386,57 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DescriptionDetails` element
-        pub fn description_details<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Menu` element
+        pub fn menu<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DescriptionDetailsBuilder,
-            ) -> &'a mut crate::generated::all::builders::DescriptionDetailsBuilder,
+                &'a mut crate::generated::all::builders::MenuBuilder,
+            ) -> &'a mut crate::generated::all::builders::MenuBuilder,
         {
-            let ty: crate::generated::all::DescriptionDetails = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DescriptionDetailsBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Menu = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MenuBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DescriptionList` element
-        pub fn description_list<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Meta` element
+        pub fn meta<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DescriptionListBuilder,
-            ) -> &'a mut crate::generated::all::builders::DescriptionListBuilder,
+                &'a mut crate::generated::all::builders::MetaBuilder,
+            ) -> &'a mut crate::generated::all::builders::MetaBuilder,
         {
-            let ty: crate::generated::all::DescriptionList = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DescriptionListBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Meta = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MetaBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `DescriptionTerm` element
-        pub fn description_term<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Meter` element
+        pub fn meter<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DescriptionTermBuilder,
-            ) -> &'a mut crate::generated::all::builders::DescriptionTermBuilder,
+                &'a mut crate::generated::all::builders::MeterBuilder,
+            ) -> &'a mut crate::generated::all::builders::MeterBuilder,
         {
-            let ty: crate::generated::all::DescriptionTerm = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DescriptionTermBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Meter = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MeterBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Details` element
-        pub fn details<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Navigation` element
+        pub fn navigation<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DetailsBuilder,
-            ) -> &'a mut crate::generated::all::builders::DetailsBuilder,
+                &'a mut crate::generated::all::builders::NavigationBuilder,
+            ) -> &'a mut crate::generated::all::builders::NavigationBuilder,
         {
-            let ty: crate::generated::all::Details = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DetailsBuilder::new(
+            let ty: crate::generated::all::Navigation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::NavigationBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2005,59 +5444,59 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Dialog` element
-        pub fn dialog<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `NoScript` element
+        pub fn no_script<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DialogBuilder,
-            ) -> &'a mut crate::generated::all::builders::DialogBuilder,
+                &'a mut crate::generated::all::builders::NoScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::NoScriptBuilder,
         {
-            let ty: crate::generated::all::Dialog = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DialogBuilder::new(ty);
+            let ty: crate::generated::all::NoScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::NoScriptBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Division` element
-        pub fn division<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Object` element
+        pub fn object<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::DivisionBuilder,
-            ) -> &'a mut crate::generated::all::builders::DivisionBuilder,
+                &'a mut crate::generated::all::builders::ObjectBuilder,
+            ) -> &'a mut crate::generated::all::builders::ObjectBuilder,
         {
-            let ty: crate::generated::all::Division = Default::default();
-            let mut ty_builder = crate::generated::all::builders::DivisionBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Object = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ObjectBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Embed` element
-        pub fn embed<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Option` element
+        pub fn option<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::EmbedBuilder,
-            ) -> &'a mut crate::generated::all::builders::EmbedBuilder,
+                &'a mut crate::generated::all::builders::OptionBuilder,
+            ) -> &'a mut crate::generated::all::builders::OptionBuilder,
         {
-            let ty: crate::generated::all::Embed = Default::default();
-            let mut ty_builder = crate::generated::all::builders::EmbedBuilder::new(ty);
+            let ty: crate::generated::all::Option = Default::default();
+            let mut ty_builder = crate::generated::all::builders::OptionBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Emphasis` element
-        pub fn emphasis<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `OptionGroup` element
+        pub fn option_group<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::EmphasisBuilder,
-            ) -> &'a mut crate::generated::all::builders::EmphasisBuilder,
+                &'a mut crate::generated::all::builders::OptionGroupBuilder,
+            ) -> &'a mut crate::generated::all::builders::OptionGroupBuilder,
         {
-            let ty: crate::generated::all::Emphasis = Default::default();
-            let mut ty_builder = crate::generated::all::builders::EmphasisBuilder::new(
+            let ty: crate::generated::all::OptionGroup = Default::default();
+            let mut ty_builder = crate::generated::all::builders::OptionGroupBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2065,15 +5504,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Fieldset` element
-        pub fn fieldset<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `OrderedList` element
+        pub fn ordered_list<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::FieldsetBuilder,
-            ) -> &'a mut crate::generated::all::builders::FieldsetBuilder,
+                &'a mut crate::generated::all::builders::OrderedListBuilder,
+            ) -> &'a mut crate::generated::all::builders::OrderedListBuilder,
         {
-            let ty: crate::generated::all::Fieldset = Default::default();
-            let mut ty_builder = crate::generated::all::builders::FieldsetBuilder::new(
+            let ty: crate::generated::all::OrderedList = Default::default();
+            let mut ty_builder = crate::generated::all::builders::OrderedListBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2081,29 +5520,29 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Figure` element
-        pub fn figure<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Output` element
+        pub fn output<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::FigureBuilder,
-            ) -> &'a mut crate::generated::all::builders::FigureBuilder,
+                &'a mut crate::generated::all::builders::OutputBuilder,
+            ) -> &'a mut crate::generated::all::builders::OutputBuilder,
         {
-            let ty: crate::generated::all::Figure = Default::default();
-            let mut ty_builder = crate::generated::all::builders::FigureBuilder::new(ty);
+            let ty: crate::generated::all::Output = Default::default();
+            let mut ty_builder = crate::generated::all::builders::OutputBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `FigureCaption` element
-        pub fn figure_caption<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Paragraph` element
+        pub fn paragraph<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::FigureCaptionBuilder,
-            ) -> &'a mut crate::generated::all::builders::FigureCaptionBuilder,
+                &'a mut crate::generated::all::builders::ParagraphBuilder,
+            ) -> &'a mut crate::generated::all::builders::ParagraphBuilder,
         {
-            let ty: crate::generated::all::FigureCaption = Default::default();
-            let mut ty_builder = crate::generated::all::builders::FigureCaptionBuilder::new(
+            let ty: crate::generated::all::Paragraph = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ParagraphBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2111,71 +5550,79 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Footer` element
-        pub fn footer<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Picture` element
+        pub fn picture<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::FooterBuilder,
-            ) -> &'a mut crate::generated::all::builders::FooterBuilder,
+                &'a mut crate::generated::all::builders::PictureBuilder,
+            ) -> &'a mut crate::generated::all::builders::PictureBuilder,
         {
-            let ty: crate::generated::all::Footer = Default::default();
-            let mut ty_builder = crate::generated::all::builders::FooterBuilder::new(ty);
+            let ty: crate::generated::all::Picture = Default::default();
+            let mut ty_builder = crate::generated::all::builders::PictureBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Form` element
-        pub fn form<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `PreformattedText` element
+        pub fn preformatted_text<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::FormBuilder,
-            ) -> &'a mut crate::generated::all::builders::FormBuilder,
+                &'a mut crate::generated::all::builders::PreformattedTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::PreformattedTextBuilder,
         {
-            let ty: crate::generated::all::Form = Default::default();
-            let mut ty_builder = crate::generated::all::builders::FormBuilder::new(ty);
+            let ty: crate::generated::all::PreformattedText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::PreformattedTextBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Head` element
-        pub fn head<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Progress` element
+        pub fn progress<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::HeadBuilder,
-            ) -> &'a mut crate::generated::all::builders::HeadBuilder,
+                &'a mut crate::generated::all::builders::ProgressBuilder,
+            ) -> &'a mut crate::generated::all::builders::ProgressBuilder,
         {
-            let ty: crate::generated::all::Head = Default::default();
-            let mut ty_builder = crate::generated::all::builders::HeadBuilder::new(ty);
+            let ty: crate::generated::all::Progress = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ProgressBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Header` element
-        pub fn header<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Quotation` element
+        pub fn quotation<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::HeaderBuilder,
-            ) -> &'a mut crate::generated::all::builders::HeaderBuilder,
+                &'a mut crate::generated::all::builders::QuotationBuilder,
+            ) -> &'a mut crate::generated::all::builders::QuotationBuilder,
         {
-            let ty: crate::generated::all::Header = Default::default();
-            let mut ty_builder = crate::generated::all::builders::HeaderBuilder::new(ty);
+            let ty: crate::generated::all::Quotation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::QuotationBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Heading1` element
-        pub fn heading_1<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `RubyAnnotation` element
+        pub fn ruby_annotation<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::Heading1Builder,
-            ) -> &'a mut crate::generated::all::builders::Heading1Builder,
+                &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
+            ) -> &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
         {
-            let ty: crate::generated::all::Heading1 = Default::default();
-            let mut ty_builder = crate::generated::all::builders::Heading1Builder::new(
+            let ty: crate::generated::all::RubyAnnotation = Default::default();
+            let mut ty_builder = crate::generated::all::builders::RubyAnnotationBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2183,15 +5630,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Heading2` element
-        pub fn heading_2<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `RubyFallbackParenthesis` element
+        pub fn ruby_fallback_parenthesis<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::Heading2Builder,
-            ) -> &'a mut crate::generated::all::builders::Heading2Builder,
+                &'a mut crate::generated::all::builders::RubyFallbackParenthesisBuilder,
+            ) -> &'a mut crate::generated::all::builders::RubyFallbackParenthesisBuilder,
         {
-            let ty: crate::generated::all::Heading2 = Default::default();
-            let mut ty_builder = crate::generated::all::builders::Heading2Builder::new(
+            let ty: crate::generated::all::RubyFallbackParenthesis = Default::default();
+            let mut ty_builder = crate::generated::all::builders::RubyFallbackParenthesisBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2199,15 +5646,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Heading3` element
-        pub fn heading_3<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `RubyText` element
+        pub fn ruby_text<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::Heading3Builder,
-            ) -> &'a mut crate::generated::all::builders::Heading3Builder,
+                &'a mut crate::generated::all::builders::RubyTextBuilder,
+            ) -> &'a mut crate::generated::all::builders::RubyTextBuilder,
         {
-            let ty: crate::generated::all::Heading3 = Default::default();
-            let mut ty_builder = crate::generated::all::builders::Heading3Builder::new(
+            let ty: crate::generated::all::RubyText = Default::default();
+            let mut ty_builder = crate::generated::all::builders::RubyTextBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2215,15 +5662,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Heading4` element
-        pub fn heading_4<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `SampleOutput` element
+        pub fn sample_output<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::Heading4Builder,
-            ) -> &'a mut crate::generated::all::builders::Heading4Builder,
+                &'a mut crate::generated::all::builders::SampleOutputBuilder,
+            ) -> &'a mut crate::generated::all::builders::SampleOutputBuilder,
         {
-            let ty: crate::generated::all::Heading4 = Default::default();
-            let mut ty_builder = crate::generated::all::builders::Heading4Builder::new(
+            let ty: crate::generated::all::SampleOutput = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SampleOutputBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2231,47 +5678,43 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Heading5` element
-        pub fn heading_5<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Script` element
+        pub fn script<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::Heading5Builder,
-            ) -> &'a mut crate::generated::all::builders::Heading5Builder,
+                &'a mut crate::generated::all::builders::ScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::ScriptBuilder,
         {
-            let ty: crate::generated::all::Heading5 = Default::default();
-            let mut ty_builder = crate::generated::all::builders::Heading5Builder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Script = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ScriptBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Heading6` element
-        pub fn heading_6<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Search` element
+        pub fn search<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::Heading6Builder,
-            ) -> &'a mut crate::generated::all::builders::Heading6Builder,
+                &'a mut crate::generated::all::builders::SearchBuilder,
+            ) -> &'a mut crate::generated::all::builders::SearchBuilder,
         {
-            let ty: crate::generated::all::Heading6 = Default::default();
-            let mut ty_builder = crate::generated::all::builders::Heading6Builder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Search = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SearchBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `HeadingGroup` element
-        pub fn heading_group<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Section` element
+        pub fn section<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::HeadingGroupBuilder,
-            ) -> &'a mut crate::generated::all::builders::HeadingGroupBuilder,
+                &'a mut crate::generated::all::builders::SectionBuilder,
+            ) -> &'a mut crate::generated::all::builders::SectionBuilder,
         {
-            let ty: crate::generated::all::HeadingGroup = Default::default();
-            let mut ty_builder = crate::generated::all::builders::HeadingGroupBuilder::new(
+            let ty: crate::generated::all::Section = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SectionBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2279,73 +5722,73 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Html` element
-        pub fn html<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Select` element
+        pub fn select<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::HtmlBuilder,
-            ) -> &'a mut crate::generated::all::builders::HtmlBuilder,
+                &'a mut crate::generated::all::builders::SelectBuilder,
+            ) -> &'a mut crate::generated::all::builders::SelectBuilder,
         {
-            let ty: crate::generated::all::Html = Default::default();
-            let mut ty_builder = crate::generated::all::builders::HtmlBuilder::new(ty);
+            let ty: crate::generated::all::Select = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SelectBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Iframe` element
-        pub fn iframe<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `SideComment` element
+        pub fn side_comment<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::IframeBuilder,
-            ) -> &'a mut crate::generated::all::builders::IframeBuilder,
+                &'a mut crate::generated::all::builders::SideCommentBuilder,
+            ) -> &'a mut crate::generated::all::builders::SideCommentBuilder,
         {
-            let ty: crate::generated::all::Iframe = Default::default();
-            let mut ty_builder = crate::generated::all::builders::IframeBuilder::new(ty);
+            let ty: crate::generated::all::SideComment = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SideCommentBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Image` element
-        pub fn image<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Slot` element
+        pub fn slot<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ImageBuilder,
-            ) -> &'a mut crate::generated::all::builders::ImageBuilder,
+                &'a mut crate::generated::all::builders::SlotBuilder,
+            ) -> &'a mut crate::generated::all::builders::SlotBuilder,
         {
-            let ty: crate::generated::all::Image = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ImageBuilder::new(ty);
+            let ty: crate::generated::all::Slot = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SlotBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `ImageMap` element
-        pub fn image_map<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Span` element
+        pub fn span<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ImageMapBuilder,
-            ) -> &'a mut crate::generated::all::builders::ImageMapBuilder,
+                &'a mut crate::generated::all::builders::SpanBuilder,
+            ) -> &'a mut crate::generated::all::builders::SpanBuilder,
         {
-            let ty: crate::generated::all::ImageMap = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ImageMapBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Span = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SpanBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `ImageMapArea` element
-        pub fn image_map_area<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `StrikeThrough` element
+        pub fn strike_through<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
-            ) -> &'a mut crate::generated::all::builders::ImageMapAreaBuilder,
+                &'a mut crate::generated::all::builders::StrikeThroughBuilder,
+            ) -> &'a mut crate::generated::all::builders::StrikeThroughBuilder,
         {
-            let ty: crate::generated::all::ImageMapArea = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ImageMapAreaBuilder::new(
+            let ty: crate::generated::all::StrikeThrough = Default::default();
+            let mut ty_builder = crate::generated::all::builders::StrikeThroughBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2353,29 +5796,43 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Input` element
-        pub fn input<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Strong` element
+        pub fn strong<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::InputBuilder,
-            ) -> &'a mut crate::generated::all::builders::InputBuilder,
+                &'a mut crate::generated::all::builders::StrongBuilder,
+            ) -> &'a mut crate::generated::all::builders::StrongBuilder,
         {
-            let ty: crate::generated::all::Input = Default::default();
-            let mut ty_builder = crate::generated::all::builders::InputBuilder::new(ty);
+            let ty: crate::generated::all::Strong = Default::default();
+            let mut ty_builder = crate::generated::all::builders::StrongBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Style` element
+        pub fn style<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::StyleBuilder,
+            ) -> &'a mut crate::generated::all::builders::StyleBuilder,
+        {
+            let ty: crate::generated::all::Style = Default::default();
+            let mut ty_builder = crate::generated::all::builders::StyleBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `InsertedText` element
-        pub fn inserted_text<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `SubScript` element
+        pub fn sub_script<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::InsertedTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::InsertedTextBuilder,
+                &'a mut crate::generated::all::builders::SubScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::SubScriptBuilder,
         {
-            let ty: crate::generated::all::InsertedText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::InsertedTextBuilder::new(
+            let ty: crate::generated::all::SubScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SubScriptBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2383,29 +5840,31 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Italic` element
-        pub fn italic<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Summary` element
+        pub fn summary<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ItalicBuilder,
-            ) -> &'a mut crate::generated::all::builders::ItalicBuilder,
+                &'a mut crate::generated::all::builders::SummaryBuilder,
+            ) -> &'a mut crate::generated::all::builders::SummaryBuilder,
         {
-            let ty: crate::generated::all::Italic = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ItalicBuilder::new(ty);
+            let ty: crate::generated::all::Summary = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SummaryBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `KeyboardInput` element
-        pub fn keyboard_input<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `SuperScript` element
+        pub fn super_script<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::KeyboardInputBuilder,
-            ) -> &'a mut crate::generated::all::builders::KeyboardInputBuilder,
+                &'a mut crate::generated::all::builders::SuperScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::SuperScriptBuilder,
         {
-            let ty: crate::generated::all::KeyboardInput = Default::default();
-            let mut ty_builder = crate::generated::all::builders::KeyboardInputBuilder::new(
+            let ty: crate::generated::all::SuperScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SuperScriptBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2413,43 +5872,43 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Label` element
-        pub fn label<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Svg` element
+        pub fn svg<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LabelBuilder,
-            ) -> &'a mut crate::generated::all::builders::LabelBuilder,
+                &'a mut crate::generated::all::builders::SvgBuilder,
+            ) -> &'a mut crate::generated::all::builders::SvgBuilder,
         {
-            let ty: crate::generated::all::Label = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LabelBuilder::new(ty);
+            let ty: crate::generated::all::Svg = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SvgBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Legend` element
-        pub fn legend<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Table` element
+        pub fn table<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LegendBuilder,
-            ) -> &'a mut crate::generated::all::builders::LegendBuilder,
+                &'a mut crate::generated::all::builders::TableBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableBuilder,
         {
-            let ty: crate::generated::all::Legend = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LegendBuilder::new(ty);
+            let ty: crate::generated::all::Table = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `LineBreak` element
-        pub fn line_break<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableBody` element
+        pub fn table_body<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LineBreakBuilder,
-            ) -> &'a mut crate::generated::all::builders::LineBreakBuilder,
+                &'a mut crate::generated::all::builders::TableBodyBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableBodyBuilder,
         {
-            let ty: crate::generated::all::LineBreak = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LineBreakBuilder::new(
+            let ty: crate::generated::all::TableBody = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableBodyBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2457,15 +5916,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `LineBreakOpportunity` element
-        pub fn line_break_opportunity<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableCell` element
+        pub fn table_cell<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
-            ) -> &'a mut crate::generated::all::builders::LineBreakOpportunityBuilder,
+                &'a mut crate::generated::all::builders::TableCellBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableCellBuilder,
         {
-            let ty: crate::generated::all::LineBreakOpportunity = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LineBreakOpportunityBuilder::new(
+            let ty: crate::generated::all::TableCell = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableCellBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2473,29 +5932,31 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Link` element
-        pub fn link<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableColumn` element
+        pub fn table_column<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::LinkBuilder,
-            ) -> &'a mut crate::generated::all::builders::LinkBuilder,
+                &'a mut crate::generated::all::builders::TableColumnBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableColumnBuilder,
         {
-            let ty: crate::generated::all::Link = Default::default();
-            let mut ty_builder = crate::generated::all::builders::LinkBuilder::new(ty);
+            let ty: crate::generated::all::TableColumn = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableColumnBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `ListItem` element
-        pub fn list_item<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableColumnGroup` element
+        pub fn table_column_group<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ListItemBuilder,
-            ) -> &'a mut crate::generated::all::builders::ListItemBuilder,
+                &'a mut crate::generated::all::builders::TableColumnGroupBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableColumnGroupBuilder,
         {
-            let ty: crate::generated::all::ListItem = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ListItemBuilder::new(
+            let ty: crate::generated::all::TableColumnGroup = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableColumnGroupBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2503,29 +5964,31 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Main` element
-        pub fn main<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableFoot` element
+        pub fn table_foot<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MainBuilder,
-            ) -> &'a mut crate::generated::all::builders::MainBuilder,
+                &'a mut crate::generated::all::builders::TableFootBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableFootBuilder,
         {
-            let ty: crate::generated::all::Main = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MainBuilder::new(ty);
+            let ty: crate::generated::all::TableFoot = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableFootBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `MarkText` element
-        pub fn mark_text<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableHead` element
+        pub fn table_head<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MarkTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::MarkTextBuilder,
+                &'a mut crate::generated::all::builders::TableHeadBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableHeadBuilder,
         {
-            let ty: crate::generated::all::MarkText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MarkTextBuilder::new(
+            let ty: crate::generated::all::TableHead = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableHeadBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2533,15 +5996,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `MediaSource` element
-        pub fn media_source<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableHeader` element
+        pub fn table_header<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MediaSourceBuilder,
-            ) -> &'a mut crate::generated::all::builders::MediaSourceBuilder,
+                &'a mut crate::generated::all::builders::TableHeaderBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableHeaderBuilder,
         {
-            let ty: crate::generated::all::MediaSource = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MediaSourceBuilder::new(
+            let ty: crate::generated::all::TableHeader = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableHeaderBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2549,57 +6012,56 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Menu` element
-        pub fn menu<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TableRow` element
+        pub fn table_row<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MenuBuilder,
-            ) -> &'a mut crate::generated::all::builders::MenuBuilder,
+                &'a mut crate::generated::all::builders::TableRowBuilder,
+            ) -> &'a mut crate::generated::all::builders::TableRowBuilder,
         {
-            let ty: crate::generated::all::Menu = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MenuBuilder::new(ty);
+            let ty: crate::generated::all::TableRow = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TableRowBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Meta` element
-        pub fn meta<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Template` element
+        pub fn template<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MetaBuilder,
-            ) -> &'a mut crate::generated::all::builders::MetaBuilder,
+                &'a mut crate::generated::all::builders::TemplateBuilder,
+            ) -> &'a mut crate::generated::all::builders::TemplateBuilder,
         {
-            let ty: crate::generated::all::Meta = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MetaBuilder::new(ty);
+            let ty: crate::generated::all::Template = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TemplateBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Meter` element
-        pub fn meter<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::MeterBuilder,
-            ) -> &'a mut crate::generated::all::builders::MeterBuilder,
-        {
-            let ty: crate::generated::all::Meter = Default::default();
-            let mut ty_builder = crate::generated::all::builders::MeterBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Append a new text element.
+        pub fn text(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            let cow = s.into();
+            self.element.children_mut().push(cow.into());
             self
         }
-        /// Append a new `Navigation` element
-        pub fn navigation<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TextArea` element
+        pub fn text_area<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::NavigationBuilder,
-            ) -> &'a mut crate::generated::all::builders::NavigationBuilder,
+                &'a mut crate::generated::all::builders::TextAreaBuilder,
+            ) -> &'a mut crate::generated::all::builders::TextAreaBuilder,
         {
-            let ty: crate::generated::all::Navigation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::NavigationBuilder::new(
+            let ty: crate::generated::all::TextArea = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TextAreaBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2607,15 +6069,15 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `NoScript` element
-        pub fn no_script<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `TextTrack` element
+        pub fn text_track<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::NoScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::NoScriptBuilder,
+                &'a mut crate::generated::all::builders::TextTrackBuilder,
+            ) -> &'a mut crate::generated::all::builders::TextTrackBuilder,
         {
-            let ty: crate::generated::all::NoScript = Default::default();
-            let mut ty_builder = crate::generated::all::builders::NoScriptBuilder::new(
+            let ty: crate::generated::all::TextTrack = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TextTrackBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2623,59 +6085,59 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Object` element
-        pub fn object<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `ThematicBreak` element
+        pub fn thematic_break<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ObjectBuilder,
-            ) -> &'a mut crate::generated::all::builders::ObjectBuilder,
+                &'a mut crate::generated::all::builders::ThematicBreakBuilder,
+            ) -> &'a mut crate::generated::all::builders::ThematicBreakBuilder,
         {
-            let ty: crate::generated::all::Object = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ObjectBuilder::new(ty);
+            let ty: crate::generated::all::ThematicBreak = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ThematicBreakBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Option` element
-        pub fn option<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Time` element
+        pub fn time<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::OptionBuilder,
-            ) -> &'a mut crate::generated::all::builders::OptionBuilder,
+                &'a mut crate::generated::all::builders::TimeBuilder,
+            ) -> &'a mut crate::generated::all::builders::TimeBuilder,
         {
-            let ty: crate::generated::all::Option = Default::default();
-            let mut ty_builder = crate::generated::all::builders::OptionBuilder::new(ty);
+            let ty: crate::generated::all::Time = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TimeBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `OptionGroup` element
-        pub fn option_group<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Title` element
+        pub fn title<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::OptionGroupBuilder,
-            ) -> &'a mut crate::generated::all::builders::OptionGroupBuilder,
+                &'a mut crate::generated::all::builders::TitleBuilder,
+            ) -> &'a mut crate::generated::all::builders::TitleBuilder,
         {
-            let ty: crate::generated::all::OptionGroup = Default::default();
-            let mut ty_builder = crate::generated::all::builders::OptionGroupBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Title = Default::default();
+            let mut ty_builder = crate::generated::all::builders::TitleBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `OrderedList` element
-        pub fn ordered_list<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Underline` element
+        pub fn underline<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::OrderedListBuilder,
-            ) -> &'a mut crate::generated::all::builders::OrderedListBuilder,
+                &'a mut crate::generated::all::builders::UnderlineBuilder,
+            ) -> &'a mut crate::generated::all::builders::UnderlineBuilder,
         {
-            let ty: crate::generated::all::OrderedList = Default::default();
-            let mut ty_builder = crate::generated::all::builders::OrderedListBuilder::new(
+            let ty: crate::generated::all::Underline = Default::default();
+            let mut ty_builder = crate::generated::all::builders::UnderlineBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2683,29 +6145,31 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Output` element
-        pub fn output<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `UnorderedList` element
+        pub fn unordered_list<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::OutputBuilder,
-            ) -> &'a mut crate::generated::all::builders::OutputBuilder,
+                &'a mut crate::generated::all::builders::UnorderedListBuilder,
+            ) -> &'a mut crate::generated::all::builders::UnorderedListBuilder,
         {
-            let ty: crate::generated::all::Output = Default::default();
-            let mut ty_builder = crate::generated::all::builders::OutputBuilder::new(ty);
+            let ty: crate::generated::all::UnorderedList = Default::default();
+            let mut ty_builder = crate::generated::all::builders::UnorderedListBuilder::new(
+                ty,
+            );
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Paragraph` element
-        pub fn paragraph<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Variable` element
+        pub fn variable<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ParagraphBuilder,
-            ) -> &'a mut crate::generated::all::builders::ParagraphBuilder,
+                &'a mut crate::generated::all::builders::VariableBuilder,
+            ) -> &'a mut crate::generated::all::builders::VariableBuilder,
         {
-            let ty: crate::generated::all::Paragraph = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ParagraphBuilder::new(
+            let ty: crate::generated::all::Variable = Default::default();
+            let mut ty_builder = crate::generated::all::builders::VariableBuilder::new(
                 ty,
             );
             (f)(&mut ty_builder);
@@ -2713,848 +6177,854 @@ pub mod builder {
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `Picture` element
-        pub fn picture<F>(&mut self, f: F) -> &mut Self
+        /// Append a new `Video` element
+        pub fn video<F>(&mut self, f: F) -> &mut Self
         where
             F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::PictureBuilder,
-            ) -> &'a mut crate::generated::all::builders::PictureBuilder,
+                &'a mut crate::generated::all::builders::VideoBuilder,
+            ) -> &'a mut crate::generated::all::builders::VideoBuilder,
         {
-            let ty: crate::generated::all::Picture = Default::default();
-            let mut ty_builder = crate::generated::all::builders::PictureBuilder::new(
-                ty,
-            );
+            let ty: crate::generated::all::Video = Default::default();
+            let mut ty_builder = crate::generated::all::builders::VideoBuilder::new(ty);
             (f)(&mut ty_builder);
             let ty = ty_builder.build();
             self.element.children_mut().push(ty.into());
             self
         }
-        /// Append a new `PreformattedText` element
-        pub fn preformatted_text<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::PreformattedTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::PreformattedTextBuilder,
-        {
-            let ty: crate::generated::all::PreformattedText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::PreformattedTextBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `name` attribute
+        pub fn name(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_name(Some(value.into()));
+            self
+        }
+        /// Set the value of the `accesskey` attribute
+        pub fn access_key(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_access_key(Some(value.into()));
+            self
+        }
+        /// Set the value of the `autocapitalize` attribute
+        pub fn auto_capitalize(
+            &mut self,
+            value: html_sys::attributes::AutoCapitalize,
+        ) -> &mut Self {
+            self.element.set_auto_capitalize(Some(value));
+            self
+        }
+        /// Set the value of the `autofocus` attribute
+        pub fn autofocus(&mut self, value: bool) -> &mut Self {
+            self.element.set_autofocus(value);
+            self
+        }
+        /// Set the value of the `class` attribute
+        pub fn class(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_class(Some(value.into()));
+            self
+        }
+        /// Set the value of the `contenteditable` attribute
+        pub fn content_editable(
+            &mut self,
+            value: html_sys::attributes::ContentEditable,
+        ) -> &mut Self {
+            self.element.set_content_editable(Some(value));
+            self
+        }
+        /// Set the value of the `dir` attribute
+        pub fn direction(
+            &mut self,
+            value: html_sys::attributes::Direction,
+        ) -> &mut Self {
+            self.element.set_direction(Some(value));
+            self
+        }
+        /// Set the value of the `draggable` attribute
+        pub fn draggable(
+            &mut self,
+            value: html_sys::attributes::Draggable,
+        ) -> &mut Self {
+            self.element.set_draggable(Some(value));
+            self
+        }
+        /// Set the value of the `enterkeyhint` attribute
+        pub fn enter_key_hint(
+            &mut self,
+            value: html_sys::attributes::EnterKeyHint,
+        ) -> &mut Self {
+            self.element.set_enter_key_hint(Some(value));
+            self
+        }
+        /// Set the value of the `exportparts` attribute
+        pub fn export_parts(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_export_parts(Some(value.into()));
+            self
+        }
+        /// Set the value of the `hidden` attribute
+        pub fn hidden(&mut self, value: html_sys::attributes::Hidden) -> &mut Self {
+            self.element.set_hidden(Some(value));
+            self
+        }
+        /// Set the value of the `id` attribute
+        pub fn id(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_id(Some(value.into()));
+            self
+        }
+        /// Set the value of the `inert` attribute
+        pub fn inert(&mut self, value: bool) -> &mut Self {
+            self.element.set_inert(value);
+            self
+        }
+        /// Set the value of the `inputmode` attribute
+        pub fn input_mode(
+            &mut self,
+            value: html_sys::attributes::InputMode,
+        ) -> &mut Self {
+            self.element.set_input_mode(Some(value));
+            self
+        }
+        /// Set the value of the `is` attribute
+        pub fn is_(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_is_(Some(value.into()));
+            self
+        }
+        /// Set the value of the `itemid` attribute
+        pub fn item_id(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_id(Some(value.into()));
+            self
+        }
+        /// Set the value of the `itemprop` attribute
+        pub fn item_prop(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_prop(Some(value.into()));
+            self
+        }
+        /// Set the value of the `itemref` attribute
+        pub fn item_ref(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_ref(Some(value.into()));
+            self
+        }
+        /// Set the value of the `itemscope` attribute
+        pub fn item_scope(&mut self, value: bool) -> &mut Self {
+            self.element.set_item_scope(value);
+            self
+        }
+        /// Set the value of the `itemtype` attribute
+        pub fn item_type(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_item_type(Some(value.into()));
+            self
+        }
+        /// Set the value of the `lang` attribute
+        pub fn lang(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_lang(Some(value.into()));
+            self
+        }
+        /// Set the value of the `nonce` attribute
+        pub fn nonce(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_nonce(Some(value.into()));
+            self
+        }
+        /// Set the value of the `part` attribute
+        pub fn part(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_part(Some(value.into()));
+            self
+        }
+        /// Set the value of the `slot` attribute
+        pub fn slot_attr(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_slot(Some(value.into()));
+            self
+        }
+        /// Set the value of the `spellcheck` attribute
+        pub fn spellcheck(
+            &mut self,
+            value: html_sys::attributes::Spellcheck,
+        ) -> &mut Self {
+            self.element.set_spellcheck(Some(value));
+            self
+        }
+        /// Set the value of the `style` attribute
+        pub fn style_attr(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_style(Some(value.into()));
+            self
+        }
+        /// Set the value of the `tabindex` attribute
+        pub fn tab_index(&mut self, value: i64) -> &mut Self {
+            self.element.set_tab_index(Some(value));
+            self
+        }
+        /// Set the value of the `title` attribute
+        pub fn title_attr(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_title(Some(value.into()));
+            self
+        }
+        /// Set the value of the `translate` attribute
+        pub fn translate(
+            &mut self,
+            value: html_sys::attributes::Translate,
+        ) -> &mut Self {
+            self.element.set_translate(Some(value));
+            self
+        }
+        /// Set the value of the `onabort` attribute
+        pub fn onabort(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onabort(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onauxclick` attribute
+        pub fn onauxclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onauxclick(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforeinput(Some(value.into()));
             self
         }
-        /// Append a new `Progress` element
-        pub fn progress<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ProgressBuilder,
-            ) -> &'a mut crate::generated::all::builders::ProgressBuilder,
-        {
-            let ty: crate::generated::all::Progress = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ProgressBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onbeforematch` attribute
+        pub fn onbeforematch(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforematch(Some(value.into()));
             self
         }
-        /// Append a new `Quotation` element
-        pub fn quotation<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::QuotationBuilder,
-            ) -> &'a mut crate::generated::all::builders::QuotationBuilder,
-        {
-            let ty: crate::generated::all::Quotation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::QuotationBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforetoggle(Some(value.into()));
             self
         }
-        /// Append a new `RubyAnnotation` element
-        pub fn ruby_annotation<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
-            ) -> &'a mut crate::generated::all::builders::RubyAnnotationBuilder,
-        {
-            let ty: crate::generated::all::RubyAnnotation = Default::default();
-            let mut ty_builder = crate::generated::all::builders::RubyAnnotationBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onblur` attribute
+        pub fn onblur(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onblur(Some(value.into()));
             self
         }
-        /// Append a new `RubyFallbackParenthesis` element
-        pub fn ruby_fallback_parenthesis<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::RubyFallbackParenthesisBuilder,
-            ) -> &'a mut crate::generated::all::builders::RubyFallbackParenthesisBuilder,
-        {
-            let ty: crate::generated::all::RubyFallbackParenthesis = Default::default();
-            let mut ty_builder = crate::generated::all::builders::RubyFallbackParenthesisBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncancel` attribute
+        pub fn oncancel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncancel(Some(value.into()));
             self
         }
-        /// Append a new `RubyText` element
-        pub fn ruby_text<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::RubyTextBuilder,
-            ) -> &'a mut crate::generated::all::builders::RubyTextBuilder,
-        {
-            let ty: crate::generated::all::RubyText = Default::default();
-            let mut ty_builder = crate::generated::all::builders::RubyTextBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncanplay` attribute
+        pub fn oncanplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplay(Some(value.into()));
             self
         }
-        /// Append a new `SampleOutput` element
-        pub fn sample_output<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SampleOutputBuilder,
-            ) -> &'a mut crate::generated::all::builders::SampleOutputBuilder,
-        {
-            let ty: crate::generated::all::SampleOutput = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SampleOutputBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplaythrough(Some(value.into()));
             self
         }
-        /// Append a new `Script` element
-        pub fn script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::ScriptBuilder,
-        {
-            let ty: crate::generated::all::Script = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ScriptBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onchange` attribute
+        pub fn onchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onchange(Some(value.into()));
             self
         }
-        /// Append a new `Search` element
-        pub fn search<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SearchBuilder,
-            ) -> &'a mut crate::generated::all::builders::SearchBuilder,
-        {
-            let ty: crate::generated::all::Search = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SearchBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onclick` attribute
+        pub fn onclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onclick(Some(value.into()));
             self
         }
-        /// Append a new `Section` element
-        pub fn section<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SectionBuilder,
-            ) -> &'a mut crate::generated::all::builders::SectionBuilder,
-        {
-            let ty: crate::generated::all::Section = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SectionBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onclose` attribute
+        pub fn onclose(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onclose(Some(value.into()));
             self
         }
-        /// Append a new `Select` element
-        pub fn select<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SelectBuilder,
-            ) -> &'a mut crate::generated::all::builders::SelectBuilder,
-        {
-            let ty: crate::generated::all::Select = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SelectBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncontextlost` attribute
+        pub fn oncontextlost(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextlost(Some(value.into()));
             self
         }
-        /// Append a new `SideComment` element
-        pub fn side_comment<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SideCommentBuilder,
-            ) -> &'a mut crate::generated::all::builders::SideCommentBuilder,
-        {
-            let ty: crate::generated::all::SideComment = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SideCommentBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextmenu(Some(value.into()));
             self
         }
-        /// Append a new `Slot` element
-        pub fn slot<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SlotBuilder,
-            ) -> &'a mut crate::generated::all::builders::SlotBuilder,
-        {
-            let ty: crate::generated::all::Slot = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SlotBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextrestored(Some(value.into()));
             self
         }
-        /// Append a new `Span` element
-        pub fn span<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SpanBuilder,
-            ) -> &'a mut crate::generated::all::builders::SpanBuilder,
-        {
-            let ty: crate::generated::all::Span = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SpanBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncopy` attribute
+        pub fn oncopy(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncopy(Some(value.into()));
             self
         }
-        /// Append a new `StrikeThrough` element
-        pub fn strike_through<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::StrikeThroughBuilder,
-            ) -> &'a mut crate::generated::all::builders::StrikeThroughBuilder,
-        {
-            let ty: crate::generated::all::StrikeThrough = Default::default();
-            let mut ty_builder = crate::generated::all::builders::StrikeThroughBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oncuechange` attribute
+        pub fn oncuechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncuechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncut` attribute
+        pub fn oncut(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncut(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondblclick` attribute
+        pub fn ondblclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondblclick(Some(value.into()));
             self
         }
-        /// Append a new `Strong` element
-        pub fn strong<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::StrongBuilder,
-            ) -> &'a mut crate::generated::all::builders::StrongBuilder,
-        {
-            let ty: crate::generated::all::Strong = Default::default();
-            let mut ty_builder = crate::generated::all::builders::StrongBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondrag` attribute
+        pub fn ondrag(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrag(Some(value.into()));
             self
         }
-        /// Append a new `Style` element
-        pub fn style<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::StyleBuilder,
-            ) -> &'a mut crate::generated::all::builders::StyleBuilder,
-        {
-            let ty: crate::generated::all::Style = Default::default();
-            let mut ty_builder = crate::generated::all::builders::StyleBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondragend` attribute
+        pub fn ondragend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragend(Some(value.into()));
             self
         }
-        /// Append a new `SubScript` element
-        pub fn sub_script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SubScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::SubScriptBuilder,
-        {
-            let ty: crate::generated::all::SubScript = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SubScriptBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondragenter` attribute
+        pub fn ondragenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragenter(Some(value.into()));
             self
         }
-        /// Append a new `Summary` element
-        pub fn summary<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SummaryBuilder,
-            ) -> &'a mut crate::generated::all::builders::SummaryBuilder,
-        {
-            let ty: crate::generated::all::Summary = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SummaryBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondragleave` attribute
+        pub fn ondragleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragleave(Some(value.into()));
             self
         }
-        /// Append a new `SuperScript` element
-        pub fn super_script<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::SuperScriptBuilder,
-            ) -> &'a mut crate::generated::all::builders::SuperScriptBuilder,
-        {
-            let ty: crate::generated::all::SuperScript = Default::default();
-            let mut ty_builder = crate::generated::all::builders::SuperScriptBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondragover` attribute
+        pub fn ondragover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragover(Some(value.into()));
             self
         }
-        /// Append a new `Table` element
-        pub fn table<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableBuilder,
-        {
-            let ty: crate::generated::all::Table = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondragstart` attribute
+        pub fn ondragstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragstart(Some(value.into()));
             self
         }
-        /// Append a new `TableBody` element
-        pub fn table_body<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableBodyBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableBodyBuilder,
-        {
-            let ty: crate::generated::all::TableBody = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableBodyBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondrop` attribute
+        pub fn ondrop(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrop(Some(value.into()));
             self
         }
-        /// Append a new `TableCell` element
-        pub fn table_cell<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableCellBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableCellBuilder,
-        {
-            let ty: crate::generated::all::TableCell = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableCellBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `ondurationchange` attribute
+        pub fn ondurationchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondurationchange(Some(value.into()));
             self
         }
-        /// Append a new `TableColumn` element
-        pub fn table_column<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableColumnBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableColumnBuilder,
-        {
-            let ty: crate::generated::all::TableColumn = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableColumnBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onemptied` attribute
+        pub fn onemptied(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onemptied(Some(value.into()));
             self
         }
-        /// Append a new `TableColumnGroup` element
-        pub fn table_column_group<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableColumnGroupBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableColumnGroupBuilder,
-        {
-            let ty: crate::generated::all::TableColumnGroup = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableColumnGroupBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onended` attribute
+        pub fn onended(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onended(Some(value.into()));
             self
         }
-        /// Append a new `TableFoot` element
-        pub fn table_foot<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableFootBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableFootBuilder,
-        {
-            let ty: crate::generated::all::TableFoot = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableFootBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onerror` attribute
+        pub fn onerror(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onerror(Some(value.into()));
             self
         }
-        /// Append a new `TableHead` element
-        pub fn table_head<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableHeadBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableHeadBuilder,
-        {
-            let ty: crate::generated::all::TableHead = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableHeadBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onfocus` attribute
+        pub fn onfocus(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onfocus(Some(value.into()));
             self
         }
-        /// Append a new `TableHeader` element
-        pub fn table_header<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableHeaderBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableHeaderBuilder,
-        {
-            let ty: crate::generated::all::TableHeader = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableHeaderBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onformdata` attribute
+        pub fn onformdata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onformdata(Some(value.into()));
             self
         }
-        /// Append a new `TableRow` element
-        pub fn table_row<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TableRowBuilder,
-            ) -> &'a mut crate::generated::all::builders::TableRowBuilder,
-        {
-            let ty: crate::generated::all::TableRow = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TableRowBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oninput` attribute
+        pub fn oninput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninput(Some(value.into()));
             self
         }
-        /// Append a new `Template` element
-        pub fn template<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TemplateBuilder,
-            ) -> &'a mut crate::generated::all::builders::TemplateBuilder,
-        {
-            let ty: crate::generated::all::Template = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TemplateBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `oninvalid` attribute
+        pub fn oninvalid(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninvalid(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeydown` attribute
+        pub fn onkeydown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeydown(Some(value.into()));
             self
         }
-        /// Append a new text element.
-        pub fn text(
+        /// Set the value of the `onkeypress` attribute
+        pub fn onkeypress(
             &mut self,
-            s: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            let cow = s.into();
-            self.element.children_mut().push(cow.into());
+            self.element.set_onkeypress(Some(value.into()));
             self
         }
-        /// Append a new `TextArea` element
-        pub fn text_area<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TextAreaBuilder,
-            ) -> &'a mut crate::generated::all::builders::TextAreaBuilder,
-        {
-            let ty: crate::generated::all::TextArea = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TextAreaBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onkeyup` attribute
+        pub fn onkeyup(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeyup(Some(value.into()));
             self
         }
-        /// Append a new `TextTrack` element
-        pub fn text_track<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TextTrackBuilder,
-            ) -> &'a mut crate::generated::all::builders::TextTrackBuilder,
-        {
-            let ty: crate::generated::all::TextTrack = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TextTrackBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onload` attribute
+        pub fn onload(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onload(Some(value.into()));
             self
         }
-        /// Append a new `ThematicBreak` element
-        pub fn thematic_break<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::ThematicBreakBuilder,
-            ) -> &'a mut crate::generated::all::builders::ThematicBreakBuilder,
-        {
-            let ty: crate::generated::all::ThematicBreak = Default::default();
-            let mut ty_builder = crate::generated::all::builders::ThematicBreakBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onloadeddata` attribute
+        pub fn onloadeddata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadeddata(Some(value.into()));
             self
         }
-        /// Append a new `Time` element
-        pub fn time<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TimeBuilder,
-            ) -> &'a mut crate::generated::all::builders::TimeBuilder,
-        {
-            let ty: crate::generated::all::Time = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TimeBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadedmetadata(Some(value.into()));
             self
         }
-        /// Append a new `Title` element
-        pub fn title<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::TitleBuilder,
-            ) -> &'a mut crate::generated::all::builders::TitleBuilder,
-        {
-            let ty: crate::generated::all::Title = Default::default();
-            let mut ty_builder = crate::generated::all::builders::TitleBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onloadstart` attribute
+        pub fn onloadstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadstart(Some(value.into()));
             self
         }
-        /// Append a new `Underline` element
-        pub fn underline<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::UnderlineBuilder,
-            ) -> &'a mut crate::generated::all::builders::UnderlineBuilder,
-        {
-            let ty: crate::generated::all::Underline = Default::default();
-            let mut ty_builder = crate::generated::all::builders::UnderlineBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onmousedown` attribute
+        pub fn onmousedown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousedown(Some(value.into()));
             self
         }
-        /// Append a new `UnorderedList` element
-        pub fn unordered_list<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::UnorderedListBuilder,
-            ) -> &'a mut crate::generated::all::builders::UnorderedListBuilder,
-        {
-            let ty: crate::generated::all::UnorderedList = Default::default();
-            let mut ty_builder = crate::generated::all::builders::UnorderedListBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onmouseenter` attribute
+        pub fn onmouseenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseenter(Some(value.into()));
             self
         }
-        /// Append a new `Variable` element
-        pub fn variable<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::VariableBuilder,
-            ) -> &'a mut crate::generated::all::builders::VariableBuilder,
-        {
-            let ty: crate::generated::all::Variable = Default::default();
-            let mut ty_builder = crate::generated::all::builders::VariableBuilder::new(
-                ty,
-            );
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onmouseleave` attribute
+        pub fn onmouseleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseleave(Some(value.into()));
             self
         }
-        /// Append a new `Video` element
-        pub fn video<F>(&mut self, f: F) -> &mut Self
-        where
-            F: for<'a> FnOnce(
-                &'a mut crate::generated::all::builders::VideoBuilder,
-            ) -> &'a mut crate::generated::all::builders::VideoBuilder,
-        {
-            let ty: crate::generated::all::Video = Default::default();
-            let mut ty_builder = crate::generated::all::builders::VideoBuilder::new(ty);
-            (f)(&mut ty_builder);
-            let ty = ty_builder.build();
-            self.element.children_mut().push(ty.into());
+        /// Set the value of the `onmousemove` attribute
+        pub fn onmousemove(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousemove(Some(value.into()));
             self
         }
-        /// Set the value of the `name` attribute
-        pub fn name(
+        /// Set the value of the `onmouseout` attribute
+        pub fn onmouseout(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_name(Some(value.into()));
+            self.element.set_onmouseout(Some(value.into()));
             self
         }
-        /// Set the value of the `accesskey` attribute
-        pub fn access_key(
+        /// Set the value of the `onmouseover` attribute
+        pub fn onmouseover(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_access_key(Some(value.into()));
+            self.element.set_onmouseover(Some(value.into()));
             self
         }
-        /// Set the value of the `autocapitalize` attribute
-        pub fn auto_capitalize(
+        /// Set the value of the `onmouseup` attribute
+        pub fn onmouseup(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_auto_capitalize(Some(value.into()));
+            self.element.set_onmouseup(Some(value.into()));
             self
         }
-        /// Set the value of the `autofocus` attribute
-        pub fn autofocus(&mut self, value: bool) -> &mut Self {
-            self.element.set_autofocus(value);
+        /// Set the value of the `onpaste` attribute
+        pub fn onpaste(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onpaste(Some(value.into()));
             self
         }
-        /// Set the value of the `class` attribute
-        pub fn class(
+        /// Set the value of the `onpause` attribute
+        pub fn onpause(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_class(Some(value.into()));
+            self.element.set_onpause(Some(value.into()));
             self
         }
-        /// Set the value of the `contenteditable` attribute
-        pub fn content_editable(
+        /// Set the value of the `onplay` attribute
+        pub fn onplay(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_content_editable(Some(value.into()));
+            self.element.set_onplay(Some(value.into()));
             self
         }
-        /// Set the value of the `dir` attribute
-        pub fn direction(
+        /// Set the value of the `onplaying` attribute
+        pub fn onplaying(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_direction(Some(value.into()));
+            self.element.set_onplaying(Some(value.into()));
             self
         }
-        /// Set the value of the `draggable` attribute
-        pub fn draggable(&mut self, value: bool) -> &mut Self {
-            self.element.set_draggable(value);
+        /// Set the value of the `onprogress` attribute
+        pub fn onprogress(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onprogress(Some(value.into()));
             self
         }
-        /// Set the value of the `enterkeyhint` attribute
-        pub fn enter_key_hint(
+        /// Set the value of the `onratechange` attribute
+        pub fn onratechange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_enter_key_hint(Some(value.into()));
+            self.element.set_onratechange(Some(value.into()));
             self
         }
-        /// Set the value of the `exportparts` attribute
-        pub fn export_parts(
+        /// Set the value of the `onreset` attribute
+        pub fn onreset(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_export_parts(Some(value.into()));
+            self.element.set_onreset(Some(value.into()));
             self
         }
-        /// Set the value of the `hidden` attribute
-        pub fn hidden(
+        /// Set the value of the `onresize` attribute
+        pub fn onresize(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_hidden(Some(value.into()));
+            self.element.set_onresize(Some(value.into()));
             self
         }
-        /// Set the value of the `id` attribute
-        pub fn id(
+        /// Set the value of the `onscroll` attribute
+        pub fn onscroll(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_id(Some(value.into()));
+            self.element.set_onscroll(Some(value.into()));
             self
         }
-        /// Set the value of the `inert` attribute
-        pub fn inert(&mut self, value: bool) -> &mut Self {
-            self.element.set_inert(value);
+        /// Set the value of the `onscrollend` attribute
+        pub fn onscrollend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onscrollend(Some(value.into()));
             self
         }
-        /// Set the value of the `inputmode` attribute
-        pub fn input_mode(
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_input_mode(Some(value.into()));
+            self.element.set_onsecuritypolicyviolation(Some(value.into()));
             self
         }
-        /// Set the value of the `is` attribute
-        pub fn is_(
+        /// Set the value of the `onseeked` attribute
+        pub fn onseeked(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_is_(Some(value.into()));
+            self.element.set_onseeked(Some(value.into()));
             self
         }
-        /// Set the value of the `itemid` attribute
-        pub fn item_id(
+        /// Set the value of the `onseeking` attribute
+        pub fn onseeking(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_id(Some(value.into()));
+            self.element.set_onseeking(Some(value.into()));
             self
         }
-        /// Set the value of the `itemprop` attribute
-        pub fn item_prop(
+        /// Set the value of the `onselect` attribute
+        pub fn onselect(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_prop(Some(value.into()));
+            self.element.set_onselect(Some(value.into()));
             self
         }
-        /// Set the value of the `itemref` attribute
-        pub fn item_ref(
+        /// Set the value of the `onslotchange` attribute
+        pub fn onslotchange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_ref(Some(value.into()));
+            self.element.set_onslotchange(Some(value.into()));
             self
         }
-        /// Set the value of the `itemscope` attribute
-        pub fn item_scope(
+        /// Set the value of the `onstalled` attribute
+        pub fn onstalled(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_scope(Some(value.into()));
+            self.element.set_onstalled(Some(value.into()));
             self
         }
-        /// Set the value of the `itemtype` attribute
-        pub fn item_type(
+        /// Set the value of the `onsubmit` attribute
+        pub fn onsubmit(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_item_type(Some(value.into()));
+            self.element.set_onsubmit(Some(value.into()));
             self
         }
-        /// Set the value of the `lang` attribute
-        pub fn lang(
+        /// Set the value of the `onsuspend` attribute
+        pub fn onsuspend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_lang(Some(value.into()));
+            self.element.set_onsuspend(Some(value.into()));
             self
         }
-        /// Set the value of the `nonce` attribute
-        pub fn nonce(
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_nonce(Some(value.into()));
+            self.element.set_ontimeupdate(Some(value.into()));
             self
         }
-        /// Set the value of the `part` attribute
-        pub fn part(
+        /// Set the value of the `ontoggle` attribute
+        pub fn ontoggle(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_part(Some(value.into()));
+            self.element.set_ontoggle(Some(value.into()));
             self
         }
-        /// Set the value of the `slot` attribute
-        pub fn slot_attr(
+        /// Set the value of the `onvolumechange` attribute
+        pub fn onvolumechange(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_slot(Some(value.into()));
+            self.element.set_onvolumechange(Some(value.into()));
             self
         }
-        /// Set the value of the `spellcheck` attribute
-        pub fn spellcheck(
+        /// Set the value of the `onwaiting` attribute
+        pub fn onwaiting(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_spellcheck(Some(value.into()));
+            self.element.set_onwaiting(Some(value.into()));
             self
         }
-        /// Set the value of the `style` attribute
-        pub fn style_attr(
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_style(Some(value.into()));
+            self.element.set_onwebkitanimationend(Some(value.into()));
             self
         }
-        /// Set the value of the `tabindex` attribute
-        pub fn tab_index(&mut self, value: i64) -> &mut Self {
-            self.element.set_tab_index(Some(value));
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkitanimationiteration(Some(value.into()));
             self
         }
-        /// Set the value of the `title` attribute
-        pub fn title_attr(
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(
             &mut self,
             value: impl Into<std::borrow::Cow<'static, str>>,
         ) -> &mut Self {
-            self.element.set_title(Some(value.into()));
+            self.element.set_onwebkitanimationstart(Some(value.into()));
             self
         }
-        /// Set the value of the `translate` attribute
-        pub fn translate(&mut self, value: bool) -> &mut Self {
-            self.element.set_translate(value);
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkittransitionend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn onwheel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwheel(Some(value.into()));
+            self
+        }
+        /// Append a comment.
+        pub fn comment(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::Comment::new(s).into());
+            self
+        }
+        /// Append trusted HTML, which is written without being escaped.
+        ///
+        /// It's up to the caller to make sure the HTML is well-formed and
+        /// doesn't contain any untrusted input.
+        pub fn raw_html(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::RawHtml::new(s).into());
             self
         }
         /// Push a new child element to the list of children.
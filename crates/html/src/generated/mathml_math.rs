@@ -0,0 +1,3216 @@
+pub mod element {
+    /// The MathML `<math>` element
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/math)
+    #[doc(alias = "math")]
+    #[non_exhaustive]
+    #[derive(PartialEq, Clone, Default)]
+    pub struct Math {
+        sys: html_sys::mathml::Math,
+        children: Vec<super::child::MathChild>,
+    }
+    impl Math {
+        /// Create a new builder
+        pub fn builder() -> super::builder::MathBuilder {
+            super::builder::MathBuilder::new(Default::default())
+        }
+    }
+    impl<'a> From<&'a Math> for crate::Node<'a> {
+        fn from(element: &'a Math) -> crate::Node<'a> {
+            crate::Node::Element(element)
+        }
+    }
+    impl Math {
+        /// Access the element's `data-*` properties
+        pub fn data_map(&self) -> &html_sys::DataMap {
+            &self.sys.data_map
+        }
+        /// Mutably access the element's `data-*` properties
+        pub fn data_map_mut(&mut self) -> &mut html_sys::DataMap {
+            &mut self.sys.data_map
+        }
+    }
+    impl Math {
+        /// Get the value of the `autofocus` attribute
+        pub fn autofocus(&self) -> bool {
+            self.sys.autofocus
+        }
+        /// Set the value of the `autofocus` attribute
+        pub fn set_autofocus(&mut self, value: bool) {
+            self.sys.autofocus = value;
+        }
+        /// Get the value of the `class` attribute
+        pub fn class(&self) -> std::option::Option<&str> {
+            self.sys.class.as_deref()
+        }
+        /// Set the value of the `class` attribute
+        pub fn set_class(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.class = value.map(|v| v.into());
+        }
+        /// Get the value of the `dir` attribute
+        pub fn dir(&self) -> std::option::Option<&str> {
+            self.sys.dir.as_deref()
+        }
+        /// Set the value of the `dir` attribute
+        pub fn set_dir(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.dir = value.map(|v| v.into());
+        }
+        /// Get the value of the `displaystyle` attribute
+        pub fn displaystyle(&self) -> std::option::Option<&str> {
+            self.sys.displaystyle.as_deref()
+        }
+        /// Set the value of the `displaystyle` attribute
+        pub fn set_displaystyle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.displaystyle = value.map(|v| v.into());
+        }
+        /// Get the value of the `id` attribute
+        pub fn id(&self) -> std::option::Option<&str> {
+            self.sys.id.as_deref()
+        }
+        /// Set the value of the `id` attribute
+        pub fn set_id(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.id = value.map(|v| v.into());
+        }
+        /// Get the value of the `mathbackground` attribute
+        pub fn mathbackground(&self) -> std::option::Option<&str> {
+            self.sys.mathbackground.as_deref()
+        }
+        /// Set the value of the `mathbackground` attribute
+        pub fn set_mathbackground(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.mathbackground = value.map(|v| v.into());
+        }
+        /// Get the value of the `mathcolor` attribute
+        pub fn mathcolor(&self) -> std::option::Option<&str> {
+            self.sys.mathcolor.as_deref()
+        }
+        /// Set the value of the `mathcolor` attribute
+        pub fn set_mathcolor(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.mathcolor = value.map(|v| v.into());
+        }
+        /// Get the value of the `mathsize` attribute
+        pub fn mathsize(&self) -> std::option::Option<&str> {
+            self.sys.mathsize.as_deref()
+        }
+        /// Set the value of the `mathsize` attribute
+        pub fn set_mathsize(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.mathsize = value.map(|v| v.into());
+        }
+        /// Get the value of the `nonce` attribute
+        pub fn nonce(&self) -> std::option::Option<&str> {
+            self.sys.nonce.as_deref()
+        }
+        /// Set the value of the `nonce` attribute
+        pub fn set_nonce(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.nonce = value.map(|v| v.into());
+        }
+        /// Get the value of the `scriptlevel` attribute
+        pub fn scriptlevel(&self) -> std::option::Option<&str> {
+            self.sys.scriptlevel.as_deref()
+        }
+        /// Set the value of the `scriptlevel` attribute
+        pub fn set_scriptlevel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.scriptlevel = value.map(|v| v.into());
+        }
+        /// Get the value of the `style` attribute
+        pub fn style(&self) -> std::option::Option<&str> {
+            self.sys.style.as_deref()
+        }
+        /// Set the value of the `style` attribute
+        pub fn set_style(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.style = value.map(|v| v.into());
+        }
+        /// Get the value of the `tabindex` attribute
+        pub fn tabindex(&self) -> std::option::Option<i64> {
+            self.sys.tabindex
+        }
+        /// Set the value of the `tabindex` attribute
+        pub fn set_tabindex(&mut self, value: std::option::Option<i64>) {
+            self.sys.tabindex = value;
+        }
+        /// Get the value of the `display` attribute
+        pub fn display(&self) -> std::option::Option<&str> {
+            self.sys.display.as_deref()
+        }
+        /// Set the value of the `display` attribute
+        pub fn set_display(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.display = value.map(|v| v.into());
+        }
+        /// Get the value of the `onabort` attribute
+        pub fn onabort(&self) -> std::option::Option<&str> {
+            self.sys.onabort.as_deref()
+        }
+        /// Set the value of the `onabort` attribute
+        pub fn set_onabort(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onabort = value.map(|v| v.into());
+        }
+        /// Get the value of the `onauxclick` attribute
+        pub fn onauxclick(&self) -> std::option::Option<&str> {
+            self.sys.onauxclick.as_deref()
+        }
+        /// Set the value of the `onauxclick` attribute
+        pub fn set_onauxclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onauxclick = value.map(|v| v.into());
+        }
+        /// Get the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(&self) -> std::option::Option<&str> {
+            self.sys.onbeforeinput.as_deref()
+        }
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn set_onbeforeinput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforeinput = value.map(|v| v.into());
+        }
+        /// Get the value of the `onbeforematch` attribute
+        pub fn onbeforematch(&self) -> std::option::Option<&str> {
+            self.sys.onbeforematch.as_deref()
+        }
+        /// Set the value of the `onbeforematch` attribute
+        pub fn set_onbeforematch(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforematch = value.map(|v| v.into());
+        }
+        /// Get the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(&self) -> std::option::Option<&str> {
+            self.sys.onbeforetoggle.as_deref()
+        }
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn set_onbeforetoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onbeforetoggle = value.map(|v| v.into());
+        }
+        /// Get the value of the `onblur` attribute
+        pub fn onblur(&self) -> std::option::Option<&str> {
+            self.sys.onblur.as_deref()
+        }
+        /// Set the value of the `onblur` attribute
+        pub fn set_onblur(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onblur = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncancel` attribute
+        pub fn oncancel(&self) -> std::option::Option<&str> {
+            self.sys.oncancel.as_deref()
+        }
+        /// Set the value of the `oncancel` attribute
+        pub fn set_oncancel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncancel = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncanplay` attribute
+        pub fn oncanplay(&self) -> std::option::Option<&str> {
+            self.sys.oncanplay.as_deref()
+        }
+        /// Set the value of the `oncanplay` attribute
+        pub fn set_oncanplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplay = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(&self) -> std::option::Option<&str> {
+            self.sys.oncanplaythrough.as_deref()
+        }
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn set_oncanplaythrough(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplaythrough = value.map(|v| v.into());
+        }
+        /// Get the value of the `onchange` attribute
+        pub fn onchange(&self) -> std::option::Option<&str> {
+            self.sys.onchange.as_deref()
+        }
+        /// Set the value of the `onchange` attribute
+        pub fn set_onchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onclick` attribute
+        pub fn onclick(&self) -> std::option::Option<&str> {
+            self.sys.onclick.as_deref()
+        }
+        /// Set the value of the `onclick` attribute
+        pub fn set_onclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclick = value.map(|v| v.into());
+        }
+        /// Get the value of the `onclose` attribute
+        pub fn onclose(&self) -> std::option::Option<&str> {
+            self.sys.onclose.as_deref()
+        }
+        /// Set the value of the `onclose` attribute
+        pub fn set_onclose(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclose = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncontextlost` attribute
+        pub fn oncontextlost(&self) -> std::option::Option<&str> {
+            self.sys.oncontextlost.as_deref()
+        }
+        /// Set the value of the `oncontextlost` attribute
+        pub fn set_oncontextlost(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextlost = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(&self) -> std::option::Option<&str> {
+            self.sys.oncontextmenu.as_deref()
+        }
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn set_oncontextmenu(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextmenu = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(&self) -> std::option::Option<&str> {
+            self.sys.oncontextrestored.as_deref()
+        }
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn set_oncontextrestored(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncontextrestored = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncopy` attribute
+        pub fn oncopy(&self) -> std::option::Option<&str> {
+            self.sys.oncopy.as_deref()
+        }
+        /// Set the value of the `oncopy` attribute
+        pub fn set_oncopy(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncopy = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncuechange` attribute
+        pub fn oncuechange(&self) -> std::option::Option<&str> {
+            self.sys.oncuechange.as_deref()
+        }
+        /// Set the value of the `oncuechange` attribute
+        pub fn set_oncuechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncuechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncut` attribute
+        pub fn oncut(&self) -> std::option::Option<&str> {
+            self.sys.oncut.as_deref()
+        }
+        /// Set the value of the `oncut` attribute
+        pub fn set_oncut(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncut = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondblclick` attribute
+        pub fn ondblclick(&self) -> std::option::Option<&str> {
+            self.sys.ondblclick.as_deref()
+        }
+        /// Set the value of the `ondblclick` attribute
+        pub fn set_ondblclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondblclick = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondrag` attribute
+        pub fn ondrag(&self) -> std::option::Option<&str> {
+            self.sys.ondrag.as_deref()
+        }
+        /// Set the value of the `ondrag` attribute
+        pub fn set_ondrag(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrag = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragend` attribute
+        pub fn ondragend(&self) -> std::option::Option<&str> {
+            self.sys.ondragend.as_deref()
+        }
+        /// Set the value of the `ondragend` attribute
+        pub fn set_ondragend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragenter` attribute
+        pub fn ondragenter(&self) -> std::option::Option<&str> {
+            self.sys.ondragenter.as_deref()
+        }
+        /// Set the value of the `ondragenter` attribute
+        pub fn set_ondragenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragleave` attribute
+        pub fn ondragleave(&self) -> std::option::Option<&str> {
+            self.sys.ondragleave.as_deref()
+        }
+        /// Set the value of the `ondragleave` attribute
+        pub fn set_ondragleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragover` attribute
+        pub fn ondragover(&self) -> std::option::Option<&str> {
+            self.sys.ondragover.as_deref()
+        }
+        /// Set the value of the `ondragover` attribute
+        pub fn set_ondragover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragover = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragstart` attribute
+        pub fn ondragstart(&self) -> std::option::Option<&str> {
+            self.sys.ondragstart.as_deref()
+        }
+        /// Set the value of the `ondragstart` attribute
+        pub fn set_ondragstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondrop` attribute
+        pub fn ondrop(&self) -> std::option::Option<&str> {
+            self.sys.ondrop.as_deref()
+        }
+        /// Set the value of the `ondrop` attribute
+        pub fn set_ondrop(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrop = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondurationchange` attribute
+        pub fn ondurationchange(&self) -> std::option::Option<&str> {
+            self.sys.ondurationchange.as_deref()
+        }
+        /// Set the value of the `ondurationchange` attribute
+        pub fn set_ondurationchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondurationchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onemptied` attribute
+        pub fn onemptied(&self) -> std::option::Option<&str> {
+            self.sys.onemptied.as_deref()
+        }
+        /// Set the value of the `onemptied` attribute
+        pub fn set_onemptied(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onemptied = value.map(|v| v.into());
+        }
+        /// Get the value of the `onended` attribute
+        pub fn onended(&self) -> std::option::Option<&str> {
+            self.sys.onended.as_deref()
+        }
+        /// Set the value of the `onended` attribute
+        pub fn set_onended(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onended = value.map(|v| v.into());
+        }
+        /// Get the value of the `onerror` attribute
+        pub fn onerror(&self) -> std::option::Option<&str> {
+            self.sys.onerror.as_deref()
+        }
+        /// Set the value of the `onerror` attribute
+        pub fn set_onerror(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onerror = value.map(|v| v.into());
+        }
+        /// Get the value of the `onfocus` attribute
+        pub fn onfocus(&self) -> std::option::Option<&str> {
+            self.sys.onfocus.as_deref()
+        }
+        /// Set the value of the `onfocus` attribute
+        pub fn set_onfocus(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onfocus = value.map(|v| v.into());
+        }
+        /// Get the value of the `onformdata` attribute
+        pub fn onformdata(&self) -> std::option::Option<&str> {
+            self.sys.onformdata.as_deref()
+        }
+        /// Set the value of the `onformdata` attribute
+        pub fn set_onformdata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onformdata = value.map(|v| v.into());
+        }
+        /// Get the value of the `oninput` attribute
+        pub fn oninput(&self) -> std::option::Option<&str> {
+            self.sys.oninput.as_deref()
+        }
+        /// Set the value of the `oninput` attribute
+        pub fn set_oninput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninput = value.map(|v| v.into());
+        }
+        /// Get the value of the `oninvalid` attribute
+        pub fn oninvalid(&self) -> std::option::Option<&str> {
+            self.sys.oninvalid.as_deref()
+        }
+        /// Set the value of the `oninvalid` attribute
+        pub fn set_oninvalid(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninvalid = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeydown` attribute
+        pub fn onkeydown(&self) -> std::option::Option<&str> {
+            self.sys.onkeydown.as_deref()
+        }
+        /// Set the value of the `onkeydown` attribute
+        pub fn set_onkeydown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeydown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeypress` attribute
+        pub fn onkeypress(&self) -> std::option::Option<&str> {
+            self.sys.onkeypress.as_deref()
+        }
+        /// Set the value of the `onkeypress` attribute
+        pub fn set_onkeypress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeypress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeyup` attribute
+        pub fn onkeyup(&self) -> std::option::Option<&str> {
+            self.sys.onkeyup.as_deref()
+        }
+        /// Set the value of the `onkeyup` attribute
+        pub fn set_onkeyup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeyup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onload` attribute
+        pub fn onload(&self) -> std::option::Option<&str> {
+            self.sys.onload.as_deref()
+        }
+        /// Set the value of the `onload` attribute
+        pub fn set_onload(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onload = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadeddata` attribute
+        pub fn onloadeddata(&self) -> std::option::Option<&str> {
+            self.sys.onloadeddata.as_deref()
+        }
+        /// Set the value of the `onloadeddata` attribute
+        pub fn set_onloadeddata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadeddata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(&self) -> std::option::Option<&str> {
+            self.sys.onloadedmetadata.as_deref()
+        }
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn set_onloadedmetadata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadedmetadata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadstart` attribute
+        pub fn onloadstart(&self) -> std::option::Option<&str> {
+            self.sys.onloadstart.as_deref()
+        }
+        /// Set the value of the `onloadstart` attribute
+        pub fn set_onloadstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousedown` attribute
+        pub fn onmousedown(&self) -> std::option::Option<&str> {
+            self.sys.onmousedown.as_deref()
+        }
+        /// Set the value of the `onmousedown` attribute
+        pub fn set_onmousedown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousedown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseenter` attribute
+        pub fn onmouseenter(&self) -> std::option::Option<&str> {
+            self.sys.onmouseenter.as_deref()
+        }
+        /// Set the value of the `onmouseenter` attribute
+        pub fn set_onmouseenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseleave` attribute
+        pub fn onmouseleave(&self) -> std::option::Option<&str> {
+            self.sys.onmouseleave.as_deref()
+        }
+        /// Set the value of the `onmouseleave` attribute
+        pub fn set_onmouseleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousemove` attribute
+        pub fn onmousemove(&self) -> std::option::Option<&str> {
+            self.sys.onmousemove.as_deref()
+        }
+        /// Set the value of the `onmousemove` attribute
+        pub fn set_onmousemove(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousemove = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseout` attribute
+        pub fn onmouseout(&self) -> std::option::Option<&str> {
+            self.sys.onmouseout.as_deref()
+        }
+        /// Set the value of the `onmouseout` attribute
+        pub fn set_onmouseout(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseout = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseover` attribute
+        pub fn onmouseover(&self) -> std::option::Option<&str> {
+            self.sys.onmouseover.as_deref()
+        }
+        /// Set the value of the `onmouseover` attribute
+        pub fn set_onmouseover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseover = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseup` attribute
+        pub fn onmouseup(&self) -> std::option::Option<&str> {
+            self.sys.onmouseup.as_deref()
+        }
+        /// Set the value of the `onmouseup` attribute
+        pub fn set_onmouseup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpaste` attribute
+        pub fn onpaste(&self) -> std::option::Option<&str> {
+            self.sys.onpaste.as_deref()
+        }
+        /// Set the value of the `onpaste` attribute
+        pub fn set_onpaste(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpaste = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpause` attribute
+        pub fn onpause(&self) -> std::option::Option<&str> {
+            self.sys.onpause.as_deref()
+        }
+        /// Set the value of the `onpause` attribute
+        pub fn set_onpause(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpause = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplay` attribute
+        pub fn onplay(&self) -> std::option::Option<&str> {
+            self.sys.onplay.as_deref()
+        }
+        /// Set the value of the `onplay` attribute
+        pub fn set_onplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplay = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplaying` attribute
+        pub fn onplaying(&self) -> std::option::Option<&str> {
+            self.sys.onplaying.as_deref()
+        }
+        /// Set the value of the `onplaying` attribute
+        pub fn set_onplaying(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplaying = value.map(|v| v.into());
+        }
+        /// Get the value of the `onprogress` attribute
+        pub fn onprogress(&self) -> std::option::Option<&str> {
+            self.sys.onprogress.as_deref()
+        }
+        /// Set the value of the `onprogress` attribute
+        pub fn set_onprogress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onprogress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onratechange` attribute
+        pub fn onratechange(&self) -> std::option::Option<&str> {
+            self.sys.onratechange.as_deref()
+        }
+        /// Set the value of the `onratechange` attribute
+        pub fn set_onratechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onratechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onreset` attribute
+        pub fn onreset(&self) -> std::option::Option<&str> {
+            self.sys.onreset.as_deref()
+        }
+        /// Set the value of the `onreset` attribute
+        pub fn set_onreset(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onreset = value.map(|v| v.into());
+        }
+        /// Get the value of the `onresize` attribute
+        pub fn onresize(&self) -> std::option::Option<&str> {
+            self.sys.onresize.as_deref()
+        }
+        /// Set the value of the `onresize` attribute
+        pub fn set_onresize(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onresize = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscroll` attribute
+        pub fn onscroll(&self) -> std::option::Option<&str> {
+            self.sys.onscroll.as_deref()
+        }
+        /// Set the value of the `onscroll` attribute
+        pub fn set_onscroll(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscroll = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscrollend` attribute
+        pub fn onscrollend(&self) -> std::option::Option<&str> {
+            self.sys.onscrollend.as_deref()
+        }
+        /// Set the value of the `onscrollend` attribute
+        pub fn set_onscrollend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscrollend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(&self) -> std::option::Option<&str> {
+            self.sys.onsecuritypolicyviolation.as_deref()
+        }
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn set_onsecuritypolicyviolation(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsecuritypolicyviolation = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeked` attribute
+        pub fn onseeked(&self) -> std::option::Option<&str> {
+            self.sys.onseeked.as_deref()
+        }
+        /// Set the value of the `onseeked` attribute
+        pub fn set_onseeked(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeked = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeking` attribute
+        pub fn onseeking(&self) -> std::option::Option<&str> {
+            self.sys.onseeking.as_deref()
+        }
+        /// Set the value of the `onseeking` attribute
+        pub fn set_onseeking(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeking = value.map(|v| v.into());
+        }
+        /// Get the value of the `onselect` attribute
+        pub fn onselect(&self) -> std::option::Option<&str> {
+            self.sys.onselect.as_deref()
+        }
+        /// Set the value of the `onselect` attribute
+        pub fn set_onselect(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onselect = value.map(|v| v.into());
+        }
+        /// Get the value of the `onslotchange` attribute
+        pub fn onslotchange(&self) -> std::option::Option<&str> {
+            self.sys.onslotchange.as_deref()
+        }
+        /// Set the value of the `onslotchange` attribute
+        pub fn set_onslotchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onslotchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onstalled` attribute
+        pub fn onstalled(&self) -> std::option::Option<&str> {
+            self.sys.onstalled.as_deref()
+        }
+        /// Set the value of the `onstalled` attribute
+        pub fn set_onstalled(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onstalled = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsubmit` attribute
+        pub fn onsubmit(&self) -> std::option::Option<&str> {
+            self.sys.onsubmit.as_deref()
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn set_onsubmit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsubmit = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsuspend` attribute
+        pub fn onsuspend(&self) -> std::option::Option<&str> {
+            self.sys.onsuspend.as_deref()
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn set_onsuspend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsuspend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(&self) -> std::option::Option<&str> {
+            self.sys.ontimeupdate.as_deref()
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn set_ontimeupdate(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontimeupdate = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontoggle` attribute
+        pub fn ontoggle(&self) -> std::option::Option<&str> {
+            self.sys.ontoggle.as_deref()
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn set_ontoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontoggle = value.map(|v| v.into());
+        }
+        /// Get the value of the `onvolumechange` attribute
+        pub fn onvolumechange(&self) -> std::option::Option<&str> {
+            self.sys.onvolumechange.as_deref()
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn set_onvolumechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onvolumechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwaiting` attribute
+        pub fn onwaiting(&self) -> std::option::Option<&str> {
+            self.sys.onwaiting.as_deref()
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn set_onwaiting(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwaiting = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationend.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn set_onwebkitanimationend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationiteration.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn set_onwebkitanimationiteration(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationiteration = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(&self) -> std::option::Option<&str> {
+            self.sys.onwebkitanimationstart.as_deref()
+        }
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn set_onwebkitanimationstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkitanimationstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(&self) -> std::option::Option<&str> {
+            self.sys.onwebkittransitionend.as_deref()
+        }
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn set_onwebkittransitionend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwebkittransitionend = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwheel` attribute
+        pub fn onwheel(&self) -> std::option::Option<&str> {
+            self.sys.onwheel.as_deref()
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn set_onwheel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwheel = value.map(|v| v.into());
+        }
+    }
+    impl Math {
+        /// Access the element's children
+        pub fn children(&self) -> &[super::child::MathChild] {
+            self.children.as_ref()
+        }
+        /// Mutably access the element's children
+        pub fn children_mut(&mut self) -> &mut Vec<super::child::MathChild> {
+            &mut self.children
+        }
+    }
+    impl std::fmt::Debug for Math {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for Math {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.children.is_empty() {
+                return html_sys::RenderElement::write_empty_tag(&self.sys, f);
+            }
+            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
+            for el in &self.children {
+                write!(f, "{el}")?;
+            }
+            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
+            Ok(())
+        }
+    }
+    impl crate::HtmlElement for Math {
+        fn tag_name(&self) -> &'static str {
+            "math"
+        }
+        fn attributes(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.attributes()
+        }
+        fn data(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.data()
+        }
+        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
+            self.children.iter().map(From::from).collect()
+        }
+    }
+    impl crate::FlowContent for Math {}
+    impl crate::PhrasingContent for Math {}
+    impl crate::EmbeddedContent for Math {}
+    impl crate::PalpableContent for Math {}
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromHtml for Math {
+        const TAG_NAME: &'static str = "math";
+        fn from_html(
+            element: crate::parse::Element<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Self {
+            let mut this: Self = Default::default();
+            for (name, value) in element.attributes() {
+                match name {
+                    "autofocus" => this.set_autofocus(true),
+                    "class" => this.set_class(Some(value.to_owned())),
+                    "dir" => this.set_dir(Some(value.to_owned())),
+                    "displaystyle" => this.set_displaystyle(Some(value.to_owned())),
+                    "id" => this.set_id(Some(value.to_owned())),
+                    "mathbackground" => this.set_mathbackground(Some(value.to_owned())),
+                    "mathcolor" => this.set_mathcolor(Some(value.to_owned())),
+                    "mathsize" => this.set_mathsize(Some(value.to_owned())),
+                    "nonce" => this.set_nonce(Some(value.to_owned())),
+                    "scriptlevel" => this.set_scriptlevel(Some(value.to_owned())),
+                    "style" => this.set_style(Some(value.to_owned())),
+                    "tabindex" => this.set_tabindex(cx.parse_attribute(name, value)),
+                    "display" => this.set_display(Some(value.to_owned())),
+                    "onabort" => this.set_onabort(Some(value.to_owned())),
+                    "onauxclick" => this.set_onauxclick(Some(value.to_owned())),
+                    "onbeforeinput" => this.set_onbeforeinput(Some(value.to_owned())),
+                    "onbeforematch" => this.set_onbeforematch(Some(value.to_owned())),
+                    "onbeforetoggle" => this.set_onbeforetoggle(Some(value.to_owned())),
+                    "onblur" => this.set_onblur(Some(value.to_owned())),
+                    "oncancel" => this.set_oncancel(Some(value.to_owned())),
+                    "oncanplay" => this.set_oncanplay(Some(value.to_owned())),
+                    "oncanplaythrough" => {
+                        this.set_oncanplaythrough(Some(value.to_owned()))
+                    }
+                    "onchange" => this.set_onchange(Some(value.to_owned())),
+                    "onclick" => this.set_onclick(Some(value.to_owned())),
+                    "onclose" => this.set_onclose(Some(value.to_owned())),
+                    "oncontextlost" => this.set_oncontextlost(Some(value.to_owned())),
+                    "oncontextmenu" => this.set_oncontextmenu(Some(value.to_owned())),
+                    "oncontextrestored" => {
+                        this.set_oncontextrestored(Some(value.to_owned()))
+                    }
+                    "oncopy" => this.set_oncopy(Some(value.to_owned())),
+                    "oncuechange" => this.set_oncuechange(Some(value.to_owned())),
+                    "oncut" => this.set_oncut(Some(value.to_owned())),
+                    "ondblclick" => this.set_ondblclick(Some(value.to_owned())),
+                    "ondrag" => this.set_ondrag(Some(value.to_owned())),
+                    "ondragend" => this.set_ondragend(Some(value.to_owned())),
+                    "ondragenter" => this.set_ondragenter(Some(value.to_owned())),
+                    "ondragleave" => this.set_ondragleave(Some(value.to_owned())),
+                    "ondragover" => this.set_ondragover(Some(value.to_owned())),
+                    "ondragstart" => this.set_ondragstart(Some(value.to_owned())),
+                    "ondrop" => this.set_ondrop(Some(value.to_owned())),
+                    "ondurationchange" => {
+                        this.set_ondurationchange(Some(value.to_owned()))
+                    }
+                    "onemptied" => this.set_onemptied(Some(value.to_owned())),
+                    "onended" => this.set_onended(Some(value.to_owned())),
+                    "onerror" => this.set_onerror(Some(value.to_owned())),
+                    "onfocus" => this.set_onfocus(Some(value.to_owned())),
+                    "onformdata" => this.set_onformdata(Some(value.to_owned())),
+                    "oninput" => this.set_oninput(Some(value.to_owned())),
+                    "oninvalid" => this.set_oninvalid(Some(value.to_owned())),
+                    "onkeydown" => this.set_onkeydown(Some(value.to_owned())),
+                    "onkeypress" => this.set_onkeypress(Some(value.to_owned())),
+                    "onkeyup" => this.set_onkeyup(Some(value.to_owned())),
+                    "onload" => this.set_onload(Some(value.to_owned())),
+                    "onloadeddata" => this.set_onloadeddata(Some(value.to_owned())),
+                    "onloadedmetadata" => {
+                        this.set_onloadedmetadata(Some(value.to_owned()))
+                    }
+                    "onloadstart" => this.set_onloadstart(Some(value.to_owned())),
+                    "onmousedown" => this.set_onmousedown(Some(value.to_owned())),
+                    "onmouseenter" => this.set_onmouseenter(Some(value.to_owned())),
+                    "onmouseleave" => this.set_onmouseleave(Some(value.to_owned())),
+                    "onmousemove" => this.set_onmousemove(Some(value.to_owned())),
+                    "onmouseout" => this.set_onmouseout(Some(value.to_owned())),
+                    "onmouseover" => this.set_onmouseover(Some(value.to_owned())),
+                    "onmouseup" => this.set_onmouseup(Some(value.to_owned())),
+                    "onpaste" => this.set_onpaste(Some(value.to_owned())),
+                    "onpause" => this.set_onpause(Some(value.to_owned())),
+                    "onplay" => this.set_onplay(Some(value.to_owned())),
+                    "onplaying" => this.set_onplaying(Some(value.to_owned())),
+                    "onprogress" => this.set_onprogress(Some(value.to_owned())),
+                    "onratechange" => this.set_onratechange(Some(value.to_owned())),
+                    "onreset" => this.set_onreset(Some(value.to_owned())),
+                    "onresize" => this.set_onresize(Some(value.to_owned())),
+                    "onscroll" => this.set_onscroll(Some(value.to_owned())),
+                    "onscrollend" => this.set_onscrollend(Some(value.to_owned())),
+                    "onsecuritypolicyviolation" => {
+                        this.set_onsecuritypolicyviolation(Some(value.to_owned()))
+                    }
+                    "onseeked" => this.set_onseeked(Some(value.to_owned())),
+                    "onseeking" => this.set_onseeking(Some(value.to_owned())),
+                    "onselect" => this.set_onselect(Some(value.to_owned())),
+                    "onslotchange" => this.set_onslotchange(Some(value.to_owned())),
+                    "onstalled" => this.set_onstalled(Some(value.to_owned())),
+                    "onsubmit" => this.set_onsubmit(Some(value.to_owned())),
+                    "onsuspend" => this.set_onsuspend(Some(value.to_owned())),
+                    "ontimeupdate" => this.set_ontimeupdate(Some(value.to_owned())),
+                    "ontoggle" => this.set_ontoggle(Some(value.to_owned())),
+                    "onvolumechange" => this.set_onvolumechange(Some(value.to_owned())),
+                    "onwaiting" => this.set_onwaiting(Some(value.to_owned())),
+                    "onwebkitanimationend" => {
+                        this.set_onwebkitanimationend(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationiteration" => {
+                        this.set_onwebkitanimationiteration(Some(value.to_owned()))
+                    }
+                    "onwebkitanimationstart" => {
+                        this.set_onwebkitanimationstart(Some(value.to_owned()))
+                    }
+                    "onwebkittransitionend" => {
+                        this.set_onwebkittransitionend(Some(value.to_owned()))
+                    }
+                    "onwheel" => this.set_onwheel(Some(value.to_owned())),
+                    _ => cx.other_attribute(this.data_map_mut(), name, value),
+                }
+            }
+            this.children = cx.children(element);
+            this
+        }
+    }
+    impl crate::tree::Element for Math {
+        fn tag_name(&self) -> &'static str {
+            "math"
+        }
+        fn is_inline(&self) -> bool {
+            true
+        }
+        fn is_preformatted(&self) -> bool {
+            false
+        }
+        fn is_self_closing(&self) -> bool {
+            self.children.is_empty()
+        }
+        fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {
+            match self.is_self_closing() {
+                true => html_sys::RenderElement::write_empty_tag(&self.sys, writer),
+                false => html_sys::RenderElement::write_opening_tag(&self.sys, writer),
+            }
+        }
+        fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {
+            match self.is_self_closing() {
+                true => Ok(()),
+                false => html_sys::RenderElement::write_closing_tag(&self.sys, writer),
+            }
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+            self.children.get(index).map(From::from)
+        }
+    }
+    impl<'a> From<&'a Math> for crate::tree::Part<'a> {
+        fn from(element: &'a Math) -> Self {
+            crate::tree::Part::Element(element)
+        }
+    }
+    impl<'a> From<&'a Math> for crate::stream::Chunks<'a> {
+        fn from(element: &'a Math) -> Self {
+            crate::stream::Chunks::new(element)
+        }
+    }
+    impl<'a> From<&'a Math> for crate::render::Pretty<'a> {
+        fn from(element: &'a Math) -> Self {
+            crate::render::Pretty::new(element.into())
+        }
+    }
+    impl<'a> From<&'a Math> for crate::minify::Minified<'a> {
+        fn from(element: &'a Math) -> Self {
+            crate::minify::Minified::new(element.into())
+        }
+    }
+    impl crate::visit::Visit for Math {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn eq_element(&self, other: &dyn crate::visit::Visit) -> bool {
+            other.downcast_ref::<Self>() == Some(self)
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::visit::NodeRef<'_>> {
+            self.children.get(index).map(From::from)
+        }
+        fn child_mut(
+            &mut self,
+            index: usize,
+        ) -> std::option::Option<crate::visit::NodeMut<'_>> {
+            self.children.get_mut(index).map(From::from)
+        }
+        fn retain_children(
+            &mut self,
+            f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool,
+        ) {
+            self.children.retain(|child| f(child.into()));
+        }
+        fn insert_child(
+            &mut self,
+            index: usize,
+            node: crate::visit::NodeRef<'_>,
+        ) -> bool {
+            match super::child::MathChild::from_node(node) {
+                Some(child) if index <= self.children.len() => {
+                    self.children.insert(index, child);
+                    true
+                }
+                _ => false,
+            }
+        }
+        fn remove_child(&mut self, index: usize) -> bool {
+            match index < self.children.len() {
+                true => {
+                    self.children.remove(index);
+                    true
+                }
+                false => false,
+            }
+        }
+        fn set_attribute(
+            &mut self,
+            name: &str,
+            value: std::option::Option<&str>,
+        ) -> bool {
+            match name {
+                "autofocus" => self.set_autofocus(value.is_some()),
+                "class" => self.set_class(value.map(str::to_owned)),
+                "dir" => self.set_dir(value.map(str::to_owned)),
+                "displaystyle" => self.set_displaystyle(value.map(str::to_owned)),
+                "id" => self.set_id(value.map(str::to_owned)),
+                "mathbackground" => self.set_mathbackground(value.map(str::to_owned)),
+                "mathcolor" => self.set_mathcolor(value.map(str::to_owned)),
+                "mathsize" => self.set_mathsize(value.map(str::to_owned)),
+                "nonce" => self.set_nonce(value.map(str::to_owned)),
+                "scriptlevel" => self.set_scriptlevel(value.map(str::to_owned)),
+                "style" => self.set_style(value.map(str::to_owned)),
+                "tabindex" => {
+                    self.set_tabindex(value.and_then(|value| value.parse().ok()))
+                }
+                "display" => self.set_display(value.map(str::to_owned)),
+                "onabort" => self.set_onabort(value.map(str::to_owned)),
+                "onauxclick" => self.set_onauxclick(value.map(str::to_owned)),
+                "onbeforeinput" => self.set_onbeforeinput(value.map(str::to_owned)),
+                "onbeforematch" => self.set_onbeforematch(value.map(str::to_owned)),
+                "onbeforetoggle" => self.set_onbeforetoggle(value.map(str::to_owned)),
+                "onblur" => self.set_onblur(value.map(str::to_owned)),
+                "oncancel" => self.set_oncancel(value.map(str::to_owned)),
+                "oncanplay" => self.set_oncanplay(value.map(str::to_owned)),
+                "oncanplaythrough" => self.set_oncanplaythrough(value.map(str::to_owned)),
+                "onchange" => self.set_onchange(value.map(str::to_owned)),
+                "onclick" => self.set_onclick(value.map(str::to_owned)),
+                "onclose" => self.set_onclose(value.map(str::to_owned)),
+                "oncontextlost" => self.set_oncontextlost(value.map(str::to_owned)),
+                "oncontextmenu" => self.set_oncontextmenu(value.map(str::to_owned)),
+                "oncontextrestored" => {
+                    self.set_oncontextrestored(value.map(str::to_owned))
+                }
+                "oncopy" => self.set_oncopy(value.map(str::to_owned)),
+                "oncuechange" => self.set_oncuechange(value.map(str::to_owned)),
+                "oncut" => self.set_oncut(value.map(str::to_owned)),
+                "ondblclick" => self.set_ondblclick(value.map(str::to_owned)),
+                "ondrag" => self.set_ondrag(value.map(str::to_owned)),
+                "ondragend" => self.set_ondragend(value.map(str::to_owned)),
+                "ondragenter" => self.set_ondragenter(value.map(str::to_owned)),
+                "ondragleave" => self.set_ondragleave(value.map(str::to_owned)),
+                "ondragover" => self.set_ondragover(value.map(str::to_owned)),
+                "ondragstart" => self.set_ondragstart(value.map(str::to_owned)),
+                "ondrop" => self.set_ondrop(value.map(str::to_owned)),
+                "ondurationchange" => self.set_ondurationchange(value.map(str::to_owned)),
+                "onemptied" => self.set_onemptied(value.map(str::to_owned)),
+                "onended" => self.set_onended(value.map(str::to_owned)),
+                "onerror" => self.set_onerror(value.map(str::to_owned)),
+                "onfocus" => self.set_onfocus(value.map(str::to_owned)),
+                "onformdata" => self.set_onformdata(value.map(str::to_owned)),
+                "oninput" => self.set_oninput(value.map(str::to_owned)),
+                "oninvalid" => self.set_oninvalid(value.map(str::to_owned)),
+                "onkeydown" => self.set_onkeydown(value.map(str::to_owned)),
+                "onkeypress" => self.set_onkeypress(value.map(str::to_owned)),
+                "onkeyup" => self.set_onkeyup(value.map(str::to_owned)),
+                "onload" => self.set_onload(value.map(str::to_owned)),
+                "onloadeddata" => self.set_onloadeddata(value.map(str::to_owned)),
+                "onloadedmetadata" => self.set_onloadedmetadata(value.map(str::to_owned)),
+                "onloadstart" => self.set_onloadstart(value.map(str::to_owned)),
+                "onmousedown" => self.set_onmousedown(value.map(str::to_owned)),
+                "onmouseenter" => self.set_onmouseenter(value.map(str::to_owned)),
+                "onmouseleave" => self.set_onmouseleave(value.map(str::to_owned)),
+                "onmousemove" => self.set_onmousemove(value.map(str::to_owned)),
+                "onmouseout" => self.set_onmouseout(value.map(str::to_owned)),
+                "onmouseover" => self.set_onmouseover(value.map(str::to_owned)),
+                "onmouseup" => self.set_onmouseup(value.map(str::to_owned)),
+                "onpaste" => self.set_onpaste(value.map(str::to_owned)),
+                "onpause" => self.set_onpause(value.map(str::to_owned)),
+                "onplay" => self.set_onplay(value.map(str::to_owned)),
+                "onplaying" => self.set_onplaying(value.map(str::to_owned)),
+                "onprogress" => self.set_onprogress(value.map(str::to_owned)),
+                "onratechange" => self.set_onratechange(value.map(str::to_owned)),
+                "onreset" => self.set_onreset(value.map(str::to_owned)),
+                "onresize" => self.set_onresize(value.map(str::to_owned)),
+                "onscroll" => self.set_onscroll(value.map(str::to_owned)),
+                "onscrollend" => self.set_onscrollend(value.map(str::to_owned)),
+                "onsecuritypolicyviolation" => {
+                    self.set_onsecuritypolicyviolation(value.map(str::to_owned))
+                }
+                "onseeked" => self.set_onseeked(value.map(str::to_owned)),
+                "onseeking" => self.set_onseeking(value.map(str::to_owned)),
+                "onselect" => self.set_onselect(value.map(str::to_owned)),
+                "onslotchange" => self.set_onslotchange(value.map(str::to_owned)),
+                "onstalled" => self.set_onstalled(value.map(str::to_owned)),
+                "onsubmit" => self.set_onsubmit(value.map(str::to_owned)),
+                "onsuspend" => self.set_onsuspend(value.map(str::to_owned)),
+                "ontimeupdate" => self.set_ontimeupdate(value.map(str::to_owned)),
+                "ontoggle" => self.set_ontoggle(value.map(str::to_owned)),
+                "onvolumechange" => self.set_onvolumechange(value.map(str::to_owned)),
+                "onwaiting" => self.set_onwaiting(value.map(str::to_owned)),
+                "onwebkitanimationend" => {
+                    self.set_onwebkitanimationend(value.map(str::to_owned))
+                }
+                "onwebkitanimationiteration" => {
+                    self.set_onwebkitanimationiteration(value.map(str::to_owned))
+                }
+                "onwebkitanimationstart" => {
+                    self.set_onwebkitanimationstart(value.map(str::to_owned))
+                }
+                "onwebkittransitionend" => {
+                    self.set_onwebkittransitionend(value.map(str::to_owned))
+                }
+                "onwheel" => self.set_onwheel(value.map(str::to_owned)),
+                _ => {
+                    match (name.strip_prefix("data-"), value) {
+                        (Some(key), Some(value)) => {
+                            self.data_map_mut()
+                                .insert(key.to_owned().into(), value.to_owned().into());
+                        }
+                        (Some(key), None) => {
+                            self.data_map_mut().remove(key);
+                        }
+                        (None, _) => return false,
+                    }
+                }
+            }
+            true
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeElement for Math {
+        const TAG_NAME: &'static str = "math";
+        type Attributes = html_sys::mathml::Math;
+        type Child = super::child::MathChild;
+        fn attributes(&self) -> &Self::Attributes {
+            &self.sys
+        }
+        fn children(&self) -> &[Self::Child] {
+            &self.children
+        }
+        fn from_parts(sys: Self::Attributes, children: Vec<Self::Child>) -> Self {
+            Self { sys, children }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for Math {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            crate::serialize::serialize_element(self, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for Math {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_element(deserializer)
+        }
+    }
+    impl From<Math> for html_sys::mathml::Math {
+        fn from(element: Math) -> Self {
+            element.sys
+        }
+    }
+    impl From<html_sys::mathml::Math> for Math {
+        fn from(sys: html_sys::mathml::Math) -> Self {
+            Self { sys, children: vec![] }
+        }
+    }
+}
+pub mod child {
+    /// The permitted child items for the `Math` element
+    #[derive(PartialEq, Clone)]
+    #[allow(clippy::large_enum_variant)]
+    pub enum MathChild {
+        /// The Maction element
+        Maction(crate::generated::all::Maction),
+        /// The Merror element
+        Merror(crate::generated::all::Merror),
+        /// The Mfrac element
+        Mfrac(crate::generated::all::Mfrac),
+        /// The Mi element
+        Mi(crate::generated::all::Mi),
+        /// The Mmultiscripts element
+        Mmultiscripts(crate::generated::all::Mmultiscripts),
+        /// The Mn element
+        Mn(crate::generated::all::Mn),
+        /// The Mo element
+        Mo(crate::generated::all::Mo),
+        /// The Mover element
+        Mover(crate::generated::all::Mover),
+        /// The Mpadded element
+        Mpadded(crate::generated::all::Mpadded),
+        /// The Mphantom element
+        Mphantom(crate::generated::all::Mphantom),
+        /// The Mroot element
+        Mroot(crate::generated::all::Mroot),
+        /// The Mrow element
+        Mrow(crate::generated::all::Mrow),
+        /// The Ms element
+        Ms(crate::generated::all::Ms),
+        /// The Mspace element
+        Mspace(crate::generated::all::Mspace),
+        /// The Msqrt element
+        Msqrt(crate::generated::all::Msqrt),
+        /// The Mstyle element
+        Mstyle(crate::generated::all::Mstyle),
+        /// The Msub element
+        Msub(crate::generated::all::Msub),
+        /// The Msubsup element
+        Msubsup(crate::generated::all::Msubsup),
+        /// The Msup element
+        Msup(crate::generated::all::Msup),
+        /// The Mtable element
+        Mtable(crate::generated::all::Mtable),
+        /// The Mtext element
+        Mtext(crate::generated::all::Mtext),
+        /// The Munder element
+        Munder(crate::generated::all::Munder),
+        /// The Munderover element
+        Munderover(crate::generated::all::Munderover),
+        /// The Semantics element
+        Semantics(crate::generated::all::Semantics),
+        /// A comment
+        Comment(crate::Comment),
+        /// Trusted HTML, which is written without being escaped
+        RawHtml(crate::RawHtml),
+    }
+    impl std::convert::From<crate::generated::all::Maction> for MathChild {
+        fn from(value: crate::generated::all::Maction) -> Self {
+            Self::Maction(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Merror> for MathChild {
+        fn from(value: crate::generated::all::Merror) -> Self {
+            Self::Merror(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mfrac> for MathChild {
+        fn from(value: crate::generated::all::Mfrac) -> Self {
+            Self::Mfrac(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mi> for MathChild {
+        fn from(value: crate::generated::all::Mi) -> Self {
+            Self::Mi(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mmultiscripts> for MathChild {
+        fn from(value: crate::generated::all::Mmultiscripts) -> Self {
+            Self::Mmultiscripts(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mn> for MathChild {
+        fn from(value: crate::generated::all::Mn) -> Self {
+            Self::Mn(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mo> for MathChild {
+        fn from(value: crate::generated::all::Mo) -> Self {
+            Self::Mo(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mover> for MathChild {
+        fn from(value: crate::generated::all::Mover) -> Self {
+            Self::Mover(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mpadded> for MathChild {
+        fn from(value: crate::generated::all::Mpadded) -> Self {
+            Self::Mpadded(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mphantom> for MathChild {
+        fn from(value: crate::generated::all::Mphantom) -> Self {
+            Self::Mphantom(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mroot> for MathChild {
+        fn from(value: crate::generated::all::Mroot) -> Self {
+            Self::Mroot(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mrow> for MathChild {
+        fn from(value: crate::generated::all::Mrow) -> Self {
+            Self::Mrow(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Ms> for MathChild {
+        fn from(value: crate::generated::all::Ms) -> Self {
+            Self::Ms(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mspace> for MathChild {
+        fn from(value: crate::generated::all::Mspace) -> Self {
+            Self::Mspace(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Msqrt> for MathChild {
+        fn from(value: crate::generated::all::Msqrt) -> Self {
+            Self::Msqrt(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mstyle> for MathChild {
+        fn from(value: crate::generated::all::Mstyle) -> Self {
+            Self::Mstyle(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Msub> for MathChild {
+        fn from(value: crate::generated::all::Msub) -> Self {
+            Self::Msub(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Msubsup> for MathChild {
+        fn from(value: crate::generated::all::Msubsup) -> Self {
+            Self::Msubsup(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Msup> for MathChild {
+        fn from(value: crate::generated::all::Msup) -> Self {
+            Self::Msup(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mtable> for MathChild {
+        fn from(value: crate::generated::all::Mtable) -> Self {
+            Self::Mtable(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mtext> for MathChild {
+        fn from(value: crate::generated::all::Mtext) -> Self {
+            Self::Mtext(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Munder> for MathChild {
+        fn from(value: crate::generated::all::Munder) -> Self {
+            Self::Munder(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Munderover> for MathChild {
+        fn from(value: crate::generated::all::Munderover) -> Self {
+            Self::Munderover(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Semantics> for MathChild {
+        fn from(value: crate::generated::all::Semantics) -> Self {
+            Self::Semantics(value)
+        }
+    }
+    impl std::convert::From<crate::Comment> for MathChild {
+        fn from(value: crate::Comment) -> Self {
+            Self::Comment(value)
+        }
+    }
+    impl std::convert::From<crate::RawHtml> for MathChild {
+        fn from(value: crate::RawHtml) -> Self {
+            Self::RawHtml(value)
+        }
+    }
+    impl std::fmt::Debug for MathChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for MathChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Maction(el) => write!(f, "{el}"),
+                Self::Merror(el) => write!(f, "{el}"),
+                Self::Mfrac(el) => write!(f, "{el}"),
+                Self::Mi(el) => write!(f, "{el}"),
+                Self::Mmultiscripts(el) => write!(f, "{el}"),
+                Self::Mn(el) => write!(f, "{el}"),
+                Self::Mo(el) => write!(f, "{el}"),
+                Self::Mover(el) => write!(f, "{el}"),
+                Self::Mpadded(el) => write!(f, "{el}"),
+                Self::Mphantom(el) => write!(f, "{el}"),
+                Self::Mroot(el) => write!(f, "{el}"),
+                Self::Mrow(el) => write!(f, "{el}"),
+                Self::Ms(el) => write!(f, "{el}"),
+                Self::Mspace(el) => write!(f, "{el}"),
+                Self::Msqrt(el) => write!(f, "{el}"),
+                Self::Mstyle(el) => write!(f, "{el}"),
+                Self::Msub(el) => write!(f, "{el}"),
+                Self::Msubsup(el) => write!(f, "{el}"),
+                Self::Msup(el) => write!(f, "{el}"),
+                Self::Mtable(el) => write!(f, "{el}"),
+                Self::Mtext(el) => write!(f, "{el}"),
+                Self::Munder(el) => write!(f, "{el}"),
+                Self::Munderover(el) => write!(f, "{el}"),
+                Self::Semantics(el) => write!(f, "{el}"),
+                Self::Comment(el) => write!(f, "{el}"),
+                Self::RawHtml(el) => write!(f, "{el}"),
+            }
+        }
+    }
+    impl<'a> From<&'a MathChild> for crate::Node<'a> {
+        fn from(child: &'a MathChild) -> Self {
+            match child {
+                MathChild::Maction(el) => crate::Node::from(el),
+                MathChild::Merror(el) => crate::Node::from(el),
+                MathChild::Mfrac(el) => crate::Node::from(el),
+                MathChild::Mi(el) => crate::Node::from(el),
+                MathChild::Mmultiscripts(el) => crate::Node::from(el),
+                MathChild::Mn(el) => crate::Node::from(el),
+                MathChild::Mo(el) => crate::Node::from(el),
+                MathChild::Mover(el) => crate::Node::from(el),
+                MathChild::Mpadded(el) => crate::Node::from(el),
+                MathChild::Mphantom(el) => crate::Node::from(el),
+                MathChild::Mroot(el) => crate::Node::from(el),
+                MathChild::Mrow(el) => crate::Node::from(el),
+                MathChild::Ms(el) => crate::Node::from(el),
+                MathChild::Mspace(el) => crate::Node::from(el),
+                MathChild::Msqrt(el) => crate::Node::from(el),
+                MathChild::Mstyle(el) => crate::Node::from(el),
+                MathChild::Msub(el) => crate::Node::from(el),
+                MathChild::Msubsup(el) => crate::Node::from(el),
+                MathChild::Msup(el) => crate::Node::from(el),
+                MathChild::Mtable(el) => crate::Node::from(el),
+                MathChild::Mtext(el) => crate::Node::from(el),
+                MathChild::Munder(el) => crate::Node::from(el),
+                MathChild::Munderover(el) => crate::Node::from(el),
+                MathChild::Semantics(el) => crate::Node::from(el),
+                MathChild::Comment(el) => crate::Node::from(el),
+                MathChild::RawHtml(el) => crate::Node::from(el),
+            }
+        }
+    }
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromContent for MathChild {
+        const PARENT_TAG_NAME: &'static str = "math";
+        fn from_content(
+            content: crate::parse::Content<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Option<Self> {
+            match content {
+                crate::parse::Content::Text(_) => cx.not_permitted(content),
+                crate::parse::Content::Comment(comment) => {
+                    Some(Self::Comment(crate::Comment::new(comment.to_owned())))
+                }
+                crate::parse::Content::Element(el) => {
+                    match el.tag_name() {
+                        "maction" => Some(Self::Maction(cx.element(el))),
+                        "merror" => Some(Self::Merror(cx.element(el))),
+                        "mfrac" => Some(Self::Mfrac(cx.element(el))),
+                        "mi" => Some(Self::Mi(cx.element(el))),
+                        "mmultiscripts" => Some(Self::Mmultiscripts(cx.element(el))),
+                        "mn" => Some(Self::Mn(cx.element(el))),
+                        "mo" => Some(Self::Mo(cx.element(el))),
+                        "mover" => Some(Self::Mover(cx.element(el))),
+                        "mpadded" => Some(Self::Mpadded(cx.element(el))),
+                        "mphantom" => Some(Self::Mphantom(cx.element(el))),
+                        "mroot" => Some(Self::Mroot(cx.element(el))),
+                        "mrow" => Some(Self::Mrow(cx.element(el))),
+                        "ms" => Some(Self::Ms(cx.element(el))),
+                        "mspace" => Some(Self::Mspace(cx.element(el))),
+                        "msqrt" => Some(Self::Msqrt(cx.element(el))),
+                        "mstyle" => Some(Self::Mstyle(cx.element(el))),
+                        "msub" => Some(Self::Msub(cx.element(el))),
+                        "msubsup" => Some(Self::Msubsup(cx.element(el))),
+                        "msup" => Some(Self::Msup(cx.element(el))),
+                        "mtable" => Some(Self::Mtable(cx.element(el))),
+                        "mtext" => Some(Self::Mtext(cx.element(el))),
+                        "munder" => Some(Self::Munder(cx.element(el))),
+                        "munderover" => Some(Self::Munderover(cx.element(el))),
+                        "semantics" => Some(Self::Semantics(cx.element(el))),
+                        _ => cx.not_permitted(content),
+                    }
+                }
+            }
+        }
+    }
+    impl<'a> From<&'a MathChild> for crate::tree::Part<'a> {
+        fn from(child: &'a MathChild) -> Self {
+            match child {
+                MathChild::Maction(el) => crate::tree::Part::Element(el),
+                MathChild::Merror(el) => crate::tree::Part::Element(el),
+                MathChild::Mfrac(el) => crate::tree::Part::Element(el),
+                MathChild::Mi(el) => crate::tree::Part::Element(el),
+                MathChild::Mmultiscripts(el) => crate::tree::Part::Element(el),
+                MathChild::Mn(el) => crate::tree::Part::Element(el),
+                MathChild::Mo(el) => crate::tree::Part::Element(el),
+                MathChild::Mover(el) => crate::tree::Part::Element(el),
+                MathChild::Mpadded(el) => crate::tree::Part::Element(el),
+                MathChild::Mphantom(el) => crate::tree::Part::Element(el),
+                MathChild::Mroot(el) => crate::tree::Part::Element(el),
+                MathChild::Mrow(el) => crate::tree::Part::Element(el),
+                MathChild::Ms(el) => crate::tree::Part::Element(el),
+                MathChild::Mspace(el) => crate::tree::Part::Element(el),
+                MathChild::Msqrt(el) => crate::tree::Part::Element(el),
+                MathChild::Mstyle(el) => crate::tree::Part::Element(el),
+                MathChild::Msub(el) => crate::tree::Part::Element(el),
+                MathChild::Msubsup(el) => crate::tree::Part::Element(el),
+                MathChild::Msup(el) => crate::tree::Part::Element(el),
+                MathChild::Mtable(el) => crate::tree::Part::Element(el),
+                MathChild::Mtext(el) => crate::tree::Part::Element(el),
+                MathChild::Munder(el) => crate::tree::Part::Element(el),
+                MathChild::Munderover(el) => crate::tree::Part::Element(el),
+                MathChild::Semantics(el) => crate::tree::Part::Element(el),
+                MathChild::Comment(el) => {
+                    crate::tree::Part::Comment(html_sys::escape::Comment(el.as_str()))
+                }
+                MathChild::RawHtml(el) => crate::tree::Part::RawHtml(el.as_str()),
+            }
+        }
+    }
+    impl<'a> From<&'a MathChild> for crate::render::Pretty<'a> {
+        fn from(child: &'a MathChild) -> Self {
+            crate::render::Pretty::new(child.into())
+        }
+    }
+    impl<'a> From<&'a MathChild> for crate::minify::Minified<'a> {
+        fn from(child: &'a MathChild) -> Self {
+            crate::minify::Minified::new(child.into())
+        }
+    }
+    impl MathChild {
+        /// Clone a node into a child, if it's permitted.
+        pub(crate) fn from_node(
+            node: crate::visit::NodeRef<'_>,
+        ) -> std::option::Option<Self> {
+            match node {
+                crate::visit::NodeRef::Element(el) => {
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Maction>()
+                    {
+                        return Some(Self::Maction(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Merror>()
+                    {
+                        return Some(Self::Merror(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mfrac>() {
+                        return Some(Self::Mfrac(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mi>() {
+                        return Some(Self::Mi(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Mmultiscripts>()
+                    {
+                        return Some(Self::Mmultiscripts(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mn>() {
+                        return Some(Self::Mn(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mo>() {
+                        return Some(Self::Mo(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mover>() {
+                        return Some(Self::Mover(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mpadded>()
+                    {
+                        return Some(Self::Mpadded(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Mphantom>()
+                    {
+                        return Some(Self::Mphantom(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mroot>() {
+                        return Some(Self::Mroot(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mrow>() {
+                        return Some(Self::Mrow(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Ms>() {
+                        return Some(Self::Ms(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mspace>()
+                    {
+                        return Some(Self::Mspace(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Msqrt>() {
+                        return Some(Self::Msqrt(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mstyle>()
+                    {
+                        return Some(Self::Mstyle(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Msub>() {
+                        return Some(Self::Msub(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Msubsup>()
+                    {
+                        return Some(Self::Msubsup(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Msup>() {
+                        return Some(Self::Msup(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mtable>()
+                    {
+                        return Some(Self::Mtable(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mtext>() {
+                        return Some(Self::Mtext(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Munder>()
+                    {
+                        return Some(Self::Munder(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Munderover>()
+                    {
+                        return Some(Self::Munderover(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Semantics>()
+                    {
+                        return Some(Self::Semantics(el.clone()));
+                    }
+                    None
+                }
+                crate::visit::NodeRef::Text(_) => None,
+                crate::visit::NodeRef::Comment(comment) => {
+                    Some(Self::Comment(comment.clone()))
+                }
+                crate::visit::NodeRef::RawHtml(html) => Some(Self::RawHtml(html.clone())),
+            }
+        }
+    }
+    impl<'a> From<&'a MathChild> for crate::visit::NodeRef<'a> {
+        fn from(child: &'a MathChild) -> Self {
+            match child {
+                MathChild::Maction(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Merror(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mfrac(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mi(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mmultiscripts(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mn(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mo(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mover(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mpadded(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mphantom(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mroot(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mrow(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Ms(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mspace(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Msqrt(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mstyle(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Msub(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Msubsup(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Msup(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mtable(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Mtext(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Munder(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Munderover(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Semantics(el) => crate::visit::NodeRef::Element(el),
+                MathChild::Comment(el) => crate::visit::NodeRef::Comment(el),
+                MathChild::RawHtml(el) => crate::visit::NodeRef::RawHtml(el),
+            }
+        }
+    }
+    impl<'a> From<&'a mut MathChild> for crate::visit::NodeMut<'a> {
+        fn from(child: &'a mut MathChild) -> Self {
+            match child {
+                MathChild::Maction(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Merror(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mfrac(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mi(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mmultiscripts(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mn(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mo(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mover(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mpadded(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mphantom(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mroot(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mrow(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Ms(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mspace(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Msqrt(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mstyle(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Msub(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Msubsup(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Msup(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mtable(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Mtext(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Munder(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Munderover(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Semantics(el) => crate::visit::NodeMut::Element(el),
+                MathChild::Comment(el) => crate::visit::NodeMut::Comment(el),
+                MathChild::RawHtml(el) => crate::visit::NodeMut::RawHtml(el),
+            }
+        }
+    }
+    impl std::convert::TryFrom<crate::AnyElement> for MathChild {
+        type Error = crate::NotPermitted;
+        fn try_from(element: crate::AnyElement) -> Result<Self, Self::Error> {
+            match element {
+                crate::AnyElement::Maction(el) => Ok(Self::Maction(el)),
+                crate::AnyElement::Merror(el) => Ok(Self::Merror(el)),
+                crate::AnyElement::Mfrac(el) => Ok(Self::Mfrac(el)),
+                crate::AnyElement::Mi(el) => Ok(Self::Mi(el)),
+                crate::AnyElement::Mmultiscripts(el) => Ok(Self::Mmultiscripts(el)),
+                crate::AnyElement::Mn(el) => Ok(Self::Mn(el)),
+                crate::AnyElement::Mo(el) => Ok(Self::Mo(el)),
+                crate::AnyElement::Mover(el) => Ok(Self::Mover(el)),
+                crate::AnyElement::Mpadded(el) => Ok(Self::Mpadded(el)),
+                crate::AnyElement::Mphantom(el) => Ok(Self::Mphantom(el)),
+                crate::AnyElement::Mroot(el) => Ok(Self::Mroot(el)),
+                crate::AnyElement::Mrow(el) => Ok(Self::Mrow(el)),
+                crate::AnyElement::Ms(el) => Ok(Self::Ms(el)),
+                crate::AnyElement::Mspace(el) => Ok(Self::Mspace(el)),
+                crate::AnyElement::Msqrt(el) => Ok(Self::Msqrt(el)),
+                crate::AnyElement::Mstyle(el) => Ok(Self::Mstyle(el)),
+                crate::AnyElement::Msub(el) => Ok(Self::Msub(el)),
+                crate::AnyElement::Msubsup(el) => Ok(Self::Msubsup(el)),
+                crate::AnyElement::Msup(el) => Ok(Self::Msup(el)),
+                crate::AnyElement::Mtable(el) => Ok(Self::Mtable(el)),
+                crate::AnyElement::Mtext(el) => Ok(Self::Mtext(el)),
+                crate::AnyElement::Munder(el) => Ok(Self::Munder(el)),
+                crate::AnyElement::Munderover(el) => Ok(Self::Munderover(el)),
+                crate::AnyElement::Semantics(el) => Ok(Self::Semantics(el)),
+                #[allow(unreachable_patterns)]
+                element => Err(crate::NotPermitted::new(element, "math")),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for MathChild {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Maction(el) => serde::Serialize::serialize(el, serializer),
+                Self::Merror(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mfrac(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mi(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mmultiscripts(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mn(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mo(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mover(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mpadded(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mphantom(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mroot(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mrow(el) => serde::Serialize::serialize(el, serializer),
+                Self::Ms(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mspace(el) => serde::Serialize::serialize(el, serializer),
+                Self::Msqrt(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mstyle(el) => serde::Serialize::serialize(el, serializer),
+                Self::Msub(el) => serde::Serialize::serialize(el, serializer),
+                Self::Msubsup(el) => serde::Serialize::serialize(el, serializer),
+                Self::Msup(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mtable(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mtext(el) => serde::Serialize::serialize(el, serializer),
+                Self::Munder(el) => serde::Serialize::serialize(el, serializer),
+                Self::Munderover(el) => serde::Serialize::serialize(el, serializer),
+                Self::Semantics(el) => serde::Serialize::serialize(el, serializer),
+                Self::Comment(el) => serde::Serialize::serialize(el, serializer),
+                Self::RawHtml(el) => serde::Serialize::serialize(el, serializer),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for MathChild {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_child(deserializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeChild for MathChild {
+        const PARENT_TAG_NAME: &'static str = "math";
+        fn from_text(_text: String) -> Option<Self> {
+            None
+        }
+        fn from_comment(comment: crate::Comment) -> Option<Self> {
+            Some(Self::Comment(comment))
+        }
+        fn from_raw_html(html: crate::RawHtml) -> Self {
+            Self::RawHtml(html)
+        }
+        fn deserialize_element<'de, M: serde::de::MapAccess<'de>>(
+            tag: &str,
+            map: M,
+        ) -> Result<Self, M::Error> {
+            match tag {
+                "maction" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Maction)
+                }
+                "merror" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Merror)
+                }
+                "mfrac" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mfrac)
+                }
+                "mi" => crate::serialize::deserialize_element_body(map).map(Self::Mi),
+                "mmultiscripts" => {
+                    crate::serialize::deserialize_element_body(map)
+                        .map(Self::Mmultiscripts)
+                }
+                "mn" => crate::serialize::deserialize_element_body(map).map(Self::Mn),
+                "mo" => crate::serialize::deserialize_element_body(map).map(Self::Mo),
+                "mover" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mover)
+                }
+                "mpadded" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mpadded)
+                }
+                "mphantom" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mphantom)
+                }
+                "mroot" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mroot)
+                }
+                "mrow" => crate::serialize::deserialize_element_body(map).map(Self::Mrow),
+                "ms" => crate::serialize::deserialize_element_body(map).map(Self::Ms),
+                "mspace" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mspace)
+                }
+                "msqrt" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Msqrt)
+                }
+                "mstyle" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mstyle)
+                }
+                "msub" => crate::serialize::deserialize_element_body(map).map(Self::Msub),
+                "msubsup" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Msubsup)
+                }
+                "msup" => crate::serialize::deserialize_element_body(map).map(Self::Msup),
+                "mtable" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mtable)
+                }
+                "mtext" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Mtext)
+                }
+                "munder" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Munder)
+                }
+                "munderover" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Munderover)
+                }
+                "semantics" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Semantics)
+                }
+                tag => {
+                    Err(
+                        crate::serialize::child_not_permitted(Self::PARENT_TAG_NAME, tag),
+                    )
+                }
+            }
+        }
+    }
+}
+pub mod builder {
+    /// A builder struct for Math
+    pub struct MathBuilder {
+        element: super::element::Math,
+    }
+    impl MathBuilder {
+        pub(crate) fn new(element: super::element::Math) -> Self {
+            Self { element }
+        }
+        /// Finish building the element
+        pub fn build(&mut self) -> super::element::Math {
+            self.element.clone()
+        }
+        /// Insert a `data-*` property
+        pub fn data(
+            &mut self,
+            data_key: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut MathBuilder {
+            self.element.data_map_mut().insert(data_key.into(), value.into());
+            self
+        }
+        /// Append a new `Maction` element
+        pub fn maction<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MactionBuilder,
+            ) -> &'a mut crate::generated::all::builders::MactionBuilder,
+        {
+            let ty: crate::generated::all::Maction = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MactionBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Merror` element
+        pub fn merror<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MerrorBuilder,
+            ) -> &'a mut crate::generated::all::builders::MerrorBuilder,
+        {
+            let ty: crate::generated::all::Merror = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MerrorBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mfrac` element
+        pub fn mfrac<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MfracBuilder,
+            ) -> &'a mut crate::generated::all::builders::MfracBuilder,
+        {
+            let ty: crate::generated::all::Mfrac = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MfracBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mi` element
+        pub fn mi<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MiBuilder,
+            ) -> &'a mut crate::generated::all::builders::MiBuilder,
+        {
+            let ty: crate::generated::all::Mi = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MiBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mmultiscripts` element
+        pub fn mmultiscripts<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MmultiscriptsBuilder,
+            ) -> &'a mut crate::generated::all::builders::MmultiscriptsBuilder,
+        {
+            let ty: crate::generated::all::Mmultiscripts = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MmultiscriptsBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mn` element
+        pub fn mn<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MnBuilder,
+            ) -> &'a mut crate::generated::all::builders::MnBuilder,
+        {
+            let ty: crate::generated::all::Mn = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MnBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mo` element
+        pub fn mo<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MoBuilder,
+            ) -> &'a mut crate::generated::all::builders::MoBuilder,
+        {
+            let ty: crate::generated::all::Mo = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MoBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mover` element
+        pub fn mover<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MoverBuilder,
+            ) -> &'a mut crate::generated::all::builders::MoverBuilder,
+        {
+            let ty: crate::generated::all::Mover = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MoverBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mpadded` element
+        pub fn mpadded<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MpaddedBuilder,
+            ) -> &'a mut crate::generated::all::builders::MpaddedBuilder,
+        {
+            let ty: crate::generated::all::Mpadded = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MpaddedBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mphantom` element
+        pub fn mphantom<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MphantomBuilder,
+            ) -> &'a mut crate::generated::all::builders::MphantomBuilder,
+        {
+            let ty: crate::generated::all::Mphantom = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MphantomBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mroot` element
+        pub fn mroot<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MrootBuilder,
+            ) -> &'a mut crate::generated::all::builders::MrootBuilder,
+        {
+            let ty: crate::generated::all::Mroot = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MrootBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mrow` element
+        pub fn mrow<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MrowBuilder,
+            ) -> &'a mut crate::generated::all::builders::MrowBuilder,
+        {
+            let ty: crate::generated::all::Mrow = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MrowBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Ms` element
+        pub fn ms<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MsBuilder,
+            ) -> &'a mut crate::generated::all::builders::MsBuilder,
+        {
+            let ty: crate::generated::all::Ms = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MsBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mspace` element
+        pub fn mspace<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MspaceBuilder,
+            ) -> &'a mut crate::generated::all::builders::MspaceBuilder,
+        {
+            let ty: crate::generated::all::Mspace = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MspaceBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Msqrt` element
+        pub fn msqrt<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MsqrtBuilder,
+            ) -> &'a mut crate::generated::all::builders::MsqrtBuilder,
+        {
+            let ty: crate::generated::all::Msqrt = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MsqrtBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mstyle` element
+        pub fn mstyle<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MstyleBuilder,
+            ) -> &'a mut crate::generated::all::builders::MstyleBuilder,
+        {
+            let ty: crate::generated::all::Mstyle = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MstyleBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Msub` element
+        pub fn msub<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MsubBuilder,
+            ) -> &'a mut crate::generated::all::builders::MsubBuilder,
+        {
+            let ty: crate::generated::all::Msub = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MsubBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Msubsup` element
+        pub fn msubsup<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MsubsupBuilder,
+            ) -> &'a mut crate::generated::all::builders::MsubsupBuilder,
+        {
+            let ty: crate::generated::all::Msubsup = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MsubsupBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Msup` element
+        pub fn msup<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MsupBuilder,
+            ) -> &'a mut crate::generated::all::builders::MsupBuilder,
+        {
+            let ty: crate::generated::all::Msup = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MsupBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mtable` element
+        pub fn mtable<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MtableBuilder,
+            ) -> &'a mut crate::generated::all::builders::MtableBuilder,
+        {
+            let ty: crate::generated::all::Mtable = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MtableBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mtext` element
+        pub fn mtext<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MtextBuilder,
+            ) -> &'a mut crate::generated::all::builders::MtextBuilder,
+        {
+            let ty: crate::generated::all::Mtext = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MtextBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Munder` element
+        pub fn munder<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MunderBuilder,
+            ) -> &'a mut crate::generated::all::builders::MunderBuilder,
+        {
+            let ty: crate::generated::all::Munder = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MunderBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Munderover` element
+        pub fn munderover<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MunderoverBuilder,
+            ) -> &'a mut crate::generated::all::builders::MunderoverBuilder,
+        {
+            let ty: crate::generated::all::Munderover = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MunderoverBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Semantics` element
+        pub fn semantics<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SemanticsBuilder,
+            ) -> &'a mut crate::generated::all::builders::SemanticsBuilder,
+        {
+            let ty: crate::generated::all::Semantics = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SemanticsBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Set the value of the `autofocus` attribute
+        pub fn autofocus(&mut self, value: bool) -> &mut Self {
+            self.element.set_autofocus(value);
+            self
+        }
+        /// Set the value of the `class` attribute
+        pub fn class(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_class(Some(value.into()));
+            self
+        }
+        /// Set the value of the `dir` attribute
+        pub fn dir(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_dir(Some(value.into()));
+            self
+        }
+        /// Set the value of the `displaystyle` attribute
+        pub fn displaystyle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_displaystyle(Some(value.into()));
+            self
+        }
+        /// Set the value of the `id` attribute
+        pub fn id(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_id(Some(value.into()));
+            self
+        }
+        /// Set the value of the `mathbackground` attribute
+        pub fn mathbackground(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_mathbackground(Some(value.into()));
+            self
+        }
+        /// Set the value of the `mathcolor` attribute
+        pub fn mathcolor(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_mathcolor(Some(value.into()));
+            self
+        }
+        /// Set the value of the `mathsize` attribute
+        pub fn mathsize(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_mathsize(Some(value.into()));
+            self
+        }
+        /// Set the value of the `nonce` attribute
+        pub fn nonce(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_nonce(Some(value.into()));
+            self
+        }
+        /// Set the value of the `scriptlevel` attribute
+        pub fn scriptlevel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_scriptlevel(Some(value.into()));
+            self
+        }
+        /// Set the value of the `style` attribute
+        pub fn style(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_style(Some(value.into()));
+            self
+        }
+        /// Set the value of the `tabindex` attribute
+        pub fn tabindex(&mut self, value: i64) -> &mut Self {
+            self.element.set_tabindex(Some(value));
+            self
+        }
+        /// Set the value of the `display` attribute
+        pub fn display(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_display(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onabort` attribute
+        pub fn onabort(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onabort(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onauxclick` attribute
+        pub fn onauxclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onauxclick(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onbeforeinput` attribute
+        pub fn onbeforeinput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforeinput(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onbeforematch` attribute
+        pub fn onbeforematch(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforematch(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onbeforetoggle` attribute
+        pub fn onbeforetoggle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onbeforetoggle(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onblur` attribute
+        pub fn onblur(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onblur(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncancel` attribute
+        pub fn oncancel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncancel(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncanplay` attribute
+        pub fn oncanplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplay(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplaythrough(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onchange` attribute
+        pub fn onchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onchange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onclick` attribute
+        pub fn onclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onclick(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onclose` attribute
+        pub fn onclose(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onclose(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncontextlost` attribute
+        pub fn oncontextlost(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextlost(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncontextmenu` attribute
+        pub fn oncontextmenu(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextmenu(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncontextrestored` attribute
+        pub fn oncontextrestored(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncontextrestored(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncopy` attribute
+        pub fn oncopy(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncopy(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncuechange` attribute
+        pub fn oncuechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncuechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncut` attribute
+        pub fn oncut(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncut(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondblclick` attribute
+        pub fn ondblclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondblclick(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondrag` attribute
+        pub fn ondrag(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrag(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragend` attribute
+        pub fn ondragend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragenter` attribute
+        pub fn ondragenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragenter(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragleave` attribute
+        pub fn ondragleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragleave(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragover` attribute
+        pub fn ondragover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragover(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragstart` attribute
+        pub fn ondragstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragstart(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondrop` attribute
+        pub fn ondrop(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrop(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondurationchange` attribute
+        pub fn ondurationchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondurationchange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onemptied` attribute
+        pub fn onemptied(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onemptied(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onended` attribute
+        pub fn onended(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onended(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onerror` attribute
+        pub fn onerror(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onerror(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onfocus` attribute
+        pub fn onfocus(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onfocus(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onformdata` attribute
+        pub fn onformdata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onformdata(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oninput` attribute
+        pub fn oninput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninput(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oninvalid` attribute
+        pub fn oninvalid(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninvalid(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeydown` attribute
+        pub fn onkeydown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeydown(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeypress` attribute
+        pub fn onkeypress(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeypress(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeyup` attribute
+        pub fn onkeyup(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeyup(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onload` attribute
+        pub fn onload(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onload(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onloadeddata` attribute
+        pub fn onloadeddata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadeddata(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadedmetadata(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onloadstart` attribute
+        pub fn onloadstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadstart(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmousedown` attribute
+        pub fn onmousedown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousedown(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseenter` attribute
+        pub fn onmouseenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseenter(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseleave` attribute
+        pub fn onmouseleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseleave(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmousemove` attribute
+        pub fn onmousemove(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousemove(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseout` attribute
+        pub fn onmouseout(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseout(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseover` attribute
+        pub fn onmouseover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseover(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseup` attribute
+        pub fn onmouseup(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseup(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onpaste` attribute
+        pub fn onpaste(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onpaste(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onpause` attribute
+        pub fn onpause(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onpause(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onplay` attribute
+        pub fn onplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onplay(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onplaying` attribute
+        pub fn onplaying(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onplaying(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onprogress` attribute
+        pub fn onprogress(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onprogress(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onratechange` attribute
+        pub fn onratechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onratechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onreset` attribute
+        pub fn onreset(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onreset(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onresize` attribute
+        pub fn onresize(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onresize(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onscroll` attribute
+        pub fn onscroll(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onscroll(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onscrollend` attribute
+        pub fn onscrollend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onscrollend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onsecuritypolicyviolation` attribute
+        pub fn onsecuritypolicyviolation(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onsecuritypolicyviolation(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onseeked` attribute
+        pub fn onseeked(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onseeked(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onseeking` attribute
+        pub fn onseeking(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onseeking(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onselect` attribute
+        pub fn onselect(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onselect(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onslotchange` attribute
+        pub fn onslotchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onslotchange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onstalled` attribute
+        pub fn onstalled(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onstalled(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn onsubmit(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onsubmit(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn onsuspend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onsuspend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ontimeupdate(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn ontoggle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ontoggle(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn onvolumechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onvolumechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn onwaiting(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwaiting(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwebkitanimationend` attribute
+        pub fn onwebkitanimationend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkitanimationend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwebkitanimationiteration` attribute
+        pub fn onwebkitanimationiteration(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkitanimationiteration(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwebkitanimationstart` attribute
+        pub fn onwebkitanimationstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkitanimationstart(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwebkittransitionend` attribute
+        pub fn onwebkittransitionend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwebkittransitionend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn onwheel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwheel(Some(value.into()));
+            self
+        }
+        /// Append a comment.
+        pub fn comment(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::Comment::new(s).into());
+            self
+        }
+        /// Append trusted HTML, which is written without being escaped.
+        ///
+        /// It's up to the caller to make sure the HTML is well-formed and
+        /// doesn't contain any untrusted input.
+        pub fn raw_html(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::RawHtml::new(s).into());
+            self
+        }
+        /// Push a new child element to the list of children.
+        pub fn push<T>(&mut self, child_el: T) -> &mut Self
+        where
+            T: Into<crate::generated::all::children::MathChild>,
+        {
+            let child_el = child_el.into();
+            self.element.children_mut().push(child_el);
+            self
+        }
+        /// Extend the list of children with an iterator of child elements.
+        pub fn extend<I, T>(&mut self, iter: I) -> &mut Self
+        where
+            I: IntoIterator<Item = T>,
+            T: Into<crate::generated::all::children::MathChild>,
+        {
+            let iter = iter.into_iter().map(|child_el| child_el.into());
+            self.element.children_mut().extend(iter);
+            self
+        }
+    }
+}
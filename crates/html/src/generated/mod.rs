@@ -1,4 +1,6 @@
 //! HTML elements support
+pub(crate) mod any_element;
+pub(crate) mod aria;
 pub(crate) mod sup;
 pub(crate) mod summary;
 pub(crate) mod a;
@@ -111,6 +113,68 @@ pub(crate) mod b;
 pub(crate) mod h4;
 pub(crate) mod select;
 pub(crate) mod legend;
+pub(crate) mod mathml_annotation;
+pub(crate) mod mathml_annotation_xml;
+pub(crate) mod mathml_maction;
+pub(crate) mod mathml_math;
+pub(crate) mod mathml_merror;
+pub(crate) mod mathml_mfrac;
+pub(crate) mod mathml_mi;
+pub(crate) mod mathml_mmultiscripts;
+pub(crate) mod mathml_mn;
+pub(crate) mod mathml_mo;
+pub(crate) mod mathml_mover;
+pub(crate) mod mathml_mpadded;
+pub(crate) mod mathml_mphantom;
+pub(crate) mod mathml_mprescripts;
+pub(crate) mod mathml_mroot;
+pub(crate) mod mathml_mrow;
+pub(crate) mod mathml_ms;
+pub(crate) mod mathml_mspace;
+pub(crate) mod mathml_msqrt;
+pub(crate) mod mathml_mstyle;
+pub(crate) mod mathml_msub;
+pub(crate) mod mathml_msubsup;
+pub(crate) mod mathml_msup;
+pub(crate) mod mathml_mtable;
+pub(crate) mod mathml_mtd;
+pub(crate) mod mathml_mtext;
+pub(crate) mod mathml_mtr;
+pub(crate) mod mathml_munder;
+pub(crate) mod mathml_munderover;
+pub(crate) mod mathml_semantics;
+pub(crate) mod svg_a;
+pub(crate) mod svg_circle;
+pub(crate) mod svg_clip_path;
+pub(crate) mod svg_defs;
+pub(crate) mod svg_desc;
+pub(crate) mod svg_ellipse;
+pub(crate) mod svg_foreign_object;
+pub(crate) mod svg_g;
+pub(crate) mod svg_image;
+pub(crate) mod svg_line;
+pub(crate) mod svg_linear_gradient;
+pub(crate) mod svg_marker;
+pub(crate) mod svg_mask;
+pub(crate) mod svg_metadata;
+pub(crate) mod svg_path;
+pub(crate) mod svg_pattern;
+pub(crate) mod svg_polygon;
+pub(crate) mod svg_polyline;
+pub(crate) mod svg_radial_gradient;
+pub(crate) mod svg_rect;
+pub(crate) mod svg_script;
+pub(crate) mod svg_stop;
+pub(crate) mod svg_style;
+pub(crate) mod svg_svg;
+pub(crate) mod svg_switch;
+pub(crate) mod svg_symbol;
+pub(crate) mod svg_text;
+pub(crate) mod svg_text_path;
+pub(crate) mod svg_title;
+pub(crate) mod svg_tspan;
+pub(crate) mod svg_use;
+pub(crate) mod svg_view;
 /// All auto-generated items in this crate
 #[allow(unused)]
 pub(crate) mod all {
@@ -226,6 +290,68 @@ pub(crate) mod all {
     pub(crate) use crate::generated::h4::element::*;
     pub(crate) use crate::generated::select::element::*;
     pub(crate) use crate::generated::legend::element::*;
+    pub(crate) use crate::generated::mathml_annotation::element::*;
+    pub(crate) use crate::generated::mathml_annotation_xml::element::*;
+    pub(crate) use crate::generated::mathml_maction::element::*;
+    pub(crate) use crate::generated::mathml_math::element::*;
+    pub(crate) use crate::generated::mathml_merror::element::*;
+    pub(crate) use crate::generated::mathml_mfrac::element::*;
+    pub(crate) use crate::generated::mathml_mi::element::*;
+    pub(crate) use crate::generated::mathml_mmultiscripts::element::*;
+    pub(crate) use crate::generated::mathml_mn::element::*;
+    pub(crate) use crate::generated::mathml_mo::element::*;
+    pub(crate) use crate::generated::mathml_mover::element::*;
+    pub(crate) use crate::generated::mathml_mpadded::element::*;
+    pub(crate) use crate::generated::mathml_mphantom::element::*;
+    pub(crate) use crate::generated::mathml_mprescripts::element::*;
+    pub(crate) use crate::generated::mathml_mroot::element::*;
+    pub(crate) use crate::generated::mathml_mrow::element::*;
+    pub(crate) use crate::generated::mathml_ms::element::*;
+    pub(crate) use crate::generated::mathml_mspace::element::*;
+    pub(crate) use crate::generated::mathml_msqrt::element::*;
+    pub(crate) use crate::generated::mathml_mstyle::element::*;
+    pub(crate) use crate::generated::mathml_msub::element::*;
+    pub(crate) use crate::generated::mathml_msubsup::element::*;
+    pub(crate) use crate::generated::mathml_msup::element::*;
+    pub(crate) use crate::generated::mathml_mtable::element::*;
+    pub(crate) use crate::generated::mathml_mtd::element::*;
+    pub(crate) use crate::generated::mathml_mtext::element::*;
+    pub(crate) use crate::generated::mathml_mtr::element::*;
+    pub(crate) use crate::generated::mathml_munder::element::*;
+    pub(crate) use crate::generated::mathml_munderover::element::*;
+    pub(crate) use crate::generated::mathml_semantics::element::*;
+    pub(crate) use crate::generated::svg_a::element::*;
+    pub(crate) use crate::generated::svg_circle::element::*;
+    pub(crate) use crate::generated::svg_clip_path::element::*;
+    pub(crate) use crate::generated::svg_defs::element::*;
+    pub(crate) use crate::generated::svg_desc::element::*;
+    pub(crate) use crate::generated::svg_ellipse::element::*;
+    pub(crate) use crate::generated::svg_foreign_object::element::*;
+    pub(crate) use crate::generated::svg_g::element::*;
+    pub(crate) use crate::generated::svg_image::element::*;
+    pub(crate) use crate::generated::svg_line::element::*;
+    pub(crate) use crate::generated::svg_linear_gradient::element::*;
+    pub(crate) use crate::generated::svg_marker::element::*;
+    pub(crate) use crate::generated::svg_mask::element::*;
+    pub(crate) use crate::generated::svg_metadata::element::*;
+    pub(crate) use crate::generated::svg_path::element::*;
+    pub(crate) use crate::generated::svg_pattern::element::*;
+    pub(crate) use crate::generated::svg_polygon::element::*;
+    pub(crate) use crate::generated::svg_polyline::element::*;
+    pub(crate) use crate::generated::svg_radial_gradient::element::*;
+    pub(crate) use crate::generated::svg_rect::element::*;
+    pub(crate) use crate::generated::svg_script::element::*;
+    pub(crate) use crate::generated::svg_stop::element::*;
+    pub(crate) use crate::generated::svg_style::element::*;
+    pub(crate) use crate::generated::svg_svg::element::*;
+    pub(crate) use crate::generated::svg_switch::element::*;
+    pub(crate) use crate::generated::svg_symbol::element::*;
+    pub(crate) use crate::generated::svg_text::element::*;
+    pub(crate) use crate::generated::svg_text_path::element::*;
+    pub(crate) use crate::generated::svg_title::element::*;
+    pub(crate) use crate::generated::svg_tspan::element::*;
+    pub(crate) use crate::generated::svg_use::element::*;
+    pub(crate) use crate::generated::svg_view::element::*;
     /// All auto-generated builders
     pub mod builders {
         pub(crate) use crate::generated::sup::builder::*;
@@ -340,6 +466,68 @@ pub(crate) mod all {
         pub(crate) use crate::generated::h4::builder::*;
         pub(crate) use crate::generated::select::builder::*;
         pub(crate) use crate::generated::legend::builder::*;
+        pub(crate) use crate::generated::mathml_annotation::builder::*;
+        pub(crate) use crate::generated::mathml_annotation_xml::builder::*;
+        pub(crate) use crate::generated::mathml_maction::builder::*;
+        pub(crate) use crate::generated::mathml_math::builder::*;
+        pub(crate) use crate::generated::mathml_merror::builder::*;
+        pub(crate) use crate::generated::mathml_mfrac::builder::*;
+        pub(crate) use crate::generated::mathml_mi::builder::*;
+        pub(crate) use crate::generated::mathml_mmultiscripts::builder::*;
+        pub(crate) use crate::generated::mathml_mn::builder::*;
+        pub(crate) use crate::generated::mathml_mo::builder::*;
+        pub(crate) use crate::generated::mathml_mover::builder::*;
+        pub(crate) use crate::generated::mathml_mpadded::builder::*;
+        pub(crate) use crate::generated::mathml_mphantom::builder::*;
+        pub(crate) use crate::generated::mathml_mprescripts::builder::*;
+        pub(crate) use crate::generated::mathml_mroot::builder::*;
+        pub(crate) use crate::generated::mathml_mrow::builder::*;
+        pub(crate) use crate::generated::mathml_ms::builder::*;
+        pub(crate) use crate::generated::mathml_mspace::builder::*;
+        pub(crate) use crate::generated::mathml_msqrt::builder::*;
+        pub(crate) use crate::generated::mathml_mstyle::builder::*;
+        pub(crate) use crate::generated::mathml_msub::builder::*;
+        pub(crate) use crate::generated::mathml_msubsup::builder::*;
+        pub(crate) use crate::generated::mathml_msup::builder::*;
+        pub(crate) use crate::generated::mathml_mtable::builder::*;
+        pub(crate) use crate::generated::mathml_mtd::builder::*;
+        pub(crate) use crate::generated::mathml_mtext::builder::*;
+        pub(crate) use crate::generated::mathml_mtr::builder::*;
+        pub(crate) use crate::generated::mathml_munder::builder::*;
+        pub(crate) use crate::generated::mathml_munderover::builder::*;
+        pub(crate) use crate::generated::mathml_semantics::builder::*;
+        pub(crate) use crate::generated::svg_a::builder::*;
+        pub(crate) use crate::generated::svg_circle::builder::*;
+        pub(crate) use crate::generated::svg_clip_path::builder::*;
+        pub(crate) use crate::generated::svg_defs::builder::*;
+        pub(crate) use crate::generated::svg_desc::builder::*;
+        pub(crate) use crate::generated::svg_ellipse::builder::*;
+        pub(crate) use crate::generated::svg_foreign_object::builder::*;
+        pub(crate) use crate::generated::svg_g::builder::*;
+        pub(crate) use crate::generated::svg_image::builder::*;
+        pub(crate) use crate::generated::svg_line::builder::*;
+        pub(crate) use crate::generated::svg_linear_gradient::builder::*;
+        pub(crate) use crate::generated::svg_marker::builder::*;
+        pub(crate) use crate::generated::svg_mask::builder::*;
+        pub(crate) use crate::generated::svg_metadata::builder::*;
+        pub(crate) use crate::generated::svg_path::builder::*;
+        pub(crate) use crate::generated::svg_pattern::builder::*;
+        pub(crate) use crate::generated::svg_polygon::builder::*;
+        pub(crate) use crate::generated::svg_polyline::builder::*;
+        pub(crate) use crate::generated::svg_radial_gradient::builder::*;
+        pub(crate) use crate::generated::svg_rect::builder::*;
+        pub(crate) use crate::generated::svg_script::builder::*;
+        pub(crate) use crate::generated::svg_stop::builder::*;
+        pub(crate) use crate::generated::svg_style::builder::*;
+        pub(crate) use crate::generated::svg_svg::builder::*;
+        pub(crate) use crate::generated::svg_switch::builder::*;
+        pub(crate) use crate::generated::svg_symbol::builder::*;
+        pub(crate) use crate::generated::svg_text::builder::*;
+        pub(crate) use crate::generated::svg_text_path::builder::*;
+        pub(crate) use crate::generated::svg_title::builder::*;
+        pub(crate) use crate::generated::svg_tspan::builder::*;
+        pub(crate) use crate::generated::svg_use::builder::*;
+        pub(crate) use crate::generated::svg_view::builder::*;
     }
     /// All auto-generated children
     pub mod children {
@@ -455,6 +643,68 @@ pub(crate) mod all {
         pub(crate) use crate::generated::h4::child::*;
         pub(crate) use crate::generated::select::child::*;
         pub(crate) use crate::generated::legend::child::*;
+        pub(crate) use crate::generated::mathml_annotation::child::*;
+        pub(crate) use crate::generated::mathml_annotation_xml::child::*;
+        pub(crate) use crate::generated::mathml_maction::child::*;
+        pub(crate) use crate::generated::mathml_math::child::*;
+        pub(crate) use crate::generated::mathml_merror::child::*;
+        pub(crate) use crate::generated::mathml_mfrac::child::*;
+        pub(crate) use crate::generated::mathml_mi::child::*;
+        pub(crate) use crate::generated::mathml_mmultiscripts::child::*;
+        pub(crate) use crate::generated::mathml_mn::child::*;
+        pub(crate) use crate::generated::mathml_mo::child::*;
+        pub(crate) use crate::generated::mathml_mover::child::*;
+        pub(crate) use crate::generated::mathml_mpadded::child::*;
+        pub(crate) use crate::generated::mathml_mphantom::child::*;
+        pub(crate) use crate::generated::mathml_mprescripts::child::*;
+        pub(crate) use crate::generated::mathml_mroot::child::*;
+        pub(crate) use crate::generated::mathml_mrow::child::*;
+        pub(crate) use crate::generated::mathml_ms::child::*;
+        pub(crate) use crate::generated::mathml_mspace::child::*;
+        pub(crate) use crate::generated::mathml_msqrt::child::*;
+        pub(crate) use crate::generated::mathml_mstyle::child::*;
+        pub(crate) use crate::generated::mathml_msub::child::*;
+        pub(crate) use crate::generated::mathml_msubsup::child::*;
+        pub(crate) use crate::generated::mathml_msup::child::*;
+        pub(crate) use crate::generated::mathml_mtable::child::*;
+        pub(crate) use crate::generated::mathml_mtd::child::*;
+        pub(crate) use crate::generated::mathml_mtext::child::*;
+        pub(crate) use crate::generated::mathml_mtr::child::*;
+        pub(crate) use crate::generated::mathml_munder::child::*;
+        pub(crate) use crate::generated::mathml_munderover::child::*;
+        pub(crate) use crate::generated::mathml_semantics::child::*;
+        pub(crate) use crate::generated::svg_a::child::*;
+        pub(crate) use crate::generated::svg_circle::child::*;
+        pub(crate) use crate::generated::svg_clip_path::child::*;
+        pub(crate) use crate::generated::svg_defs::child::*;
+        pub(crate) use crate::generated::svg_desc::child::*;
+        pub(crate) use crate::generated::svg_ellipse::child::*;
+        pub(crate) use crate::generated::svg_foreign_object::child::*;
+        pub(crate) use crate::generated::svg_g::child::*;
+        pub(crate) use crate::generated::svg_image::child::*;
+        pub(crate) use crate::generated::svg_line::child::*;
+        pub(crate) use crate::generated::svg_linear_gradient::child::*;
+        pub(crate) use crate::generated::svg_marker::child::*;
+        pub(crate) use crate::generated::svg_mask::child::*;
+        pub(crate) use crate::generated::svg_metadata::child::*;
+        pub(crate) use crate::generated::svg_path::child::*;
+        pub(crate) use crate::generated::svg_pattern::child::*;
+        pub(crate) use crate::generated::svg_polygon::child::*;
+        pub(crate) use crate::generated::svg_polyline::child::*;
+        pub(crate) use crate::generated::svg_radial_gradient::child::*;
+        pub(crate) use crate::generated::svg_rect::child::*;
+        pub(crate) use crate::generated::svg_script::child::*;
+        pub(crate) use crate::generated::svg_stop::child::*;
+        pub(crate) use crate::generated::svg_style::child::*;
+        pub(crate) use crate::generated::svg_svg::child::*;
+        pub(crate) use crate::generated::svg_switch::child::*;
+        pub(crate) use crate::generated::svg_symbol::child::*;
+        pub(crate) use crate::generated::svg_text::child::*;
+        pub(crate) use crate::generated::svg_text_path::child::*;
+        pub(crate) use crate::generated::svg_title::child::*;
+        pub(crate) use crate::generated::svg_tspan::child::*;
+        pub(crate) use crate::generated::svg_use::child::*;
+        pub(crate) use crate::generated::svg_view::child::*;
     }
 }
 /// Modules according to the MDN mappings.
@@ -540,11 +790,8 @@ pub(crate) mod mdn {
         }
         /// Child elements
         pub mod children {
-            pub use crate::generated::embed::child::*;
-            pub use crate::generated::iframe::child::*;
             pub use crate::generated::object::child::*;
             pub use crate::generated::picture::child::*;
-            pub use crate::generated::source::child::*;
         }
         /// Element builders
         pub mod builders {
@@ -579,7 +826,6 @@ pub(crate) mod mdn {
             pub use crate::generated::datalist::child::*;
             pub use crate::generated::fieldset::child::*;
             pub use crate::generated::form::child::*;
-            pub use crate::generated::input::child::*;
             pub use crate::generated::label::child::*;
             pub use crate::generated::legend::child::*;
             pub use crate::generated::meter::child::*;
@@ -589,7 +835,6 @@ pub(crate) mod mdn {
             pub use crate::generated::progress::child::*;
             pub use crate::generated::search::child::*;
             pub use crate::generated::select::child::*;
-            pub use crate::generated::textarea::child::*;
         }
         /// Element builders
         pub mod builders {
@@ -649,7 +894,6 @@ pub(crate) mod mdn {
             pub use crate::generated::b::child::*;
             pub use crate::generated::bdi::child::*;
             pub use crate::generated::bdo::child::*;
-            pub use crate::generated::br::child::*;
             pub use crate::generated::cite::child::*;
             pub use crate::generated::code::child::*;
             pub use crate::generated::data::child::*;
@@ -659,9 +903,7 @@ pub(crate) mod mdn {
             pub use crate::generated::kbd::child::*;
             pub use crate::generated::mark::child::*;
             pub use crate::generated::q::child::*;
-            pub use crate::generated::rp::child::*;
             pub use crate::generated::rt::child::*;
-            pub use crate::generated::ruby::child::*;
             pub use crate::generated::s::child::*;
             pub use crate::generated::samp::child::*;
             pub use crate::generated::small::child::*;
@@ -672,7 +914,6 @@ pub(crate) mod mdn {
             pub use crate::generated::time::child::*;
             pub use crate::generated::u::child::*;
             pub use crate::generated::var::child::*;
-            pub use crate::generated::wbr::child::*;
         }
         /// Element builders
         pub mod builders {
@@ -737,11 +978,8 @@ pub(crate) mod mdn {
         }
         /// Child elements
         pub mod children {
-            pub use crate::generated::area::child::*;
             pub use crate::generated::audio::child::*;
-            pub use crate::generated::img::child::*;
             pub use crate::generated::map::child::*;
-            pub use crate::generated::track::child::*;
             pub use crate::generated::video::child::*;
         }
         /// Element builders
@@ -765,10 +1003,7 @@ pub(crate) mod mdn {
         }
         /// Child elements
         pub mod children {
-            pub use crate::generated::base::child::*;
             pub use crate::generated::head::child::*;
-            pub use crate::generated::link::child::*;
-            pub use crate::generated::meta::child::*;
             pub use crate::generated::style::child::*;
             pub use crate::generated::title::child::*;
         }
@@ -833,7 +1068,6 @@ pub(crate) mod mdn {
         /// Child elements
         pub mod children {
             pub use crate::generated::caption::child::*;
-            pub use crate::generated::col::child::*;
             pub use crate::generated::colgroup::child::*;
             pub use crate::generated::table::child::*;
             pub use crate::generated::tbody::child::*;
@@ -883,7 +1117,6 @@ pub(crate) mod mdn {
             pub use crate::generated::dt::child::*;
             pub use crate::generated::figcaption::child::*;
             pub use crate::generated::figure::child::*;
-            pub use crate::generated::hr::child::*;
             pub use crate::generated::li::child::*;
             pub use crate::generated::menu::child::*;
             pub use crate::generated::ol::child::*;
@@ -917,7 +1150,6 @@ pub(crate) mod mdn {
         /// Child elements
         pub mod children {
             pub use crate::generated::slot::child::*;
-            pub use crate::generated::template::child::*;
         }
         /// Element builders
         pub mod builders {
@@ -925,4 +1157,208 @@ pub(crate) mod mdn {
             pub use crate::generated::template::builder::*;
         }
     }
+    pub mod mathml {
+        pub mod elements {
+            pub use crate::generated::mathml_annotation::element::*;
+            pub use crate::generated::mathml_annotation_xml::element::*;
+            pub use crate::generated::mathml_maction::element::*;
+            pub use crate::generated::mathml_math::element::*;
+            pub use crate::generated::mathml_merror::element::*;
+            pub use crate::generated::mathml_mfrac::element::*;
+            pub use crate::generated::mathml_mi::element::*;
+            pub use crate::generated::mathml_mmultiscripts::element::*;
+            pub use crate::generated::mathml_mn::element::*;
+            pub use crate::generated::mathml_mo::element::*;
+            pub use crate::generated::mathml_mover::element::*;
+            pub use crate::generated::mathml_mpadded::element::*;
+            pub use crate::generated::mathml_mphantom::element::*;
+            pub use crate::generated::mathml_mprescripts::element::*;
+            pub use crate::generated::mathml_mroot::element::*;
+            pub use crate::generated::mathml_mrow::element::*;
+            pub use crate::generated::mathml_ms::element::*;
+            pub use crate::generated::mathml_mspace::element::*;
+            pub use crate::generated::mathml_msqrt::element::*;
+            pub use crate::generated::mathml_mstyle::element::*;
+            pub use crate::generated::mathml_msub::element::*;
+            pub use crate::generated::mathml_msubsup::element::*;
+            pub use crate::generated::mathml_msup::element::*;
+            pub use crate::generated::mathml_mtable::element::*;
+            pub use crate::generated::mathml_mtd::element::*;
+            pub use crate::generated::mathml_mtext::element::*;
+            pub use crate::generated::mathml_mtr::element::*;
+            pub use crate::generated::mathml_munder::element::*;
+            pub use crate::generated::mathml_munderover::element::*;
+            pub use crate::generated::mathml_semantics::element::*;
+        }
+        /// Child elements
+        pub mod children {
+            pub use crate::generated::mathml_annotation::child::*;
+            pub use crate::generated::mathml_annotation_xml::child::*;
+            pub use crate::generated::mathml_maction::child::*;
+            pub use crate::generated::mathml_math::child::*;
+            pub use crate::generated::mathml_merror::child::*;
+            pub use crate::generated::mathml_mfrac::child::*;
+            pub use crate::generated::mathml_mi::child::*;
+            pub use crate::generated::mathml_mmultiscripts::child::*;
+            pub use crate::generated::mathml_mn::child::*;
+            pub use crate::generated::mathml_mo::child::*;
+            pub use crate::generated::mathml_mover::child::*;
+            pub use crate::generated::mathml_mpadded::child::*;
+            pub use crate::generated::mathml_mphantom::child::*;
+            pub use crate::generated::mathml_mroot::child::*;
+            pub use crate::generated::mathml_mrow::child::*;
+            pub use crate::generated::mathml_ms::child::*;
+            pub use crate::generated::mathml_msqrt::child::*;
+            pub use crate::generated::mathml_mstyle::child::*;
+            pub use crate::generated::mathml_msub::child::*;
+            pub use crate::generated::mathml_msubsup::child::*;
+            pub use crate::generated::mathml_msup::child::*;
+            pub use crate::generated::mathml_mtable::child::*;
+            pub use crate::generated::mathml_mtd::child::*;
+            pub use crate::generated::mathml_mtext::child::*;
+            pub use crate::generated::mathml_mtr::child::*;
+            pub use crate::generated::mathml_munder::child::*;
+            pub use crate::generated::mathml_munderover::child::*;
+            pub use crate::generated::mathml_semantics::child::*;
+        }
+        /// Element builders
+        pub mod builders {
+            pub use crate::generated::mathml_annotation::builder::*;
+            pub use crate::generated::mathml_annotation_xml::builder::*;
+            pub use crate::generated::mathml_maction::builder::*;
+            pub use crate::generated::mathml_math::builder::*;
+            pub use crate::generated::mathml_merror::builder::*;
+            pub use crate::generated::mathml_mfrac::builder::*;
+            pub use crate::generated::mathml_mi::builder::*;
+            pub use crate::generated::mathml_mmultiscripts::builder::*;
+            pub use crate::generated::mathml_mn::builder::*;
+            pub use crate::generated::mathml_mo::builder::*;
+            pub use crate::generated::mathml_mover::builder::*;
+            pub use crate::generated::mathml_mpadded::builder::*;
+            pub use crate::generated::mathml_mphantom::builder::*;
+            pub use crate::generated::mathml_mprescripts::builder::*;
+            pub use crate::generated::mathml_mroot::builder::*;
+            pub use crate::generated::mathml_mrow::builder::*;
+            pub use crate::generated::mathml_ms::builder::*;
+            pub use crate::generated::mathml_mspace::builder::*;
+            pub use crate::generated::mathml_msqrt::builder::*;
+            pub use crate::generated::mathml_mstyle::builder::*;
+            pub use crate::generated::mathml_msub::builder::*;
+            pub use crate::generated::mathml_msubsup::builder::*;
+            pub use crate::generated::mathml_msup::builder::*;
+            pub use crate::generated::mathml_mtable::builder::*;
+            pub use crate::generated::mathml_mtd::builder::*;
+            pub use crate::generated::mathml_mtext::builder::*;
+            pub use crate::generated::mathml_mtr::builder::*;
+            pub use crate::generated::mathml_munder::builder::*;
+            pub use crate::generated::mathml_munderover::builder::*;
+            pub use crate::generated::mathml_semantics::builder::*;
+        }
+    }
+    pub mod svg {
+        pub mod elements {
+            pub use crate::generated::svg_a::element::*;
+            pub use crate::generated::svg_circle::element::*;
+            pub use crate::generated::svg_clip_path::element::*;
+            pub use crate::generated::svg_defs::element::*;
+            pub use crate::generated::svg_desc::element::*;
+            pub use crate::generated::svg_ellipse::element::*;
+            pub use crate::generated::svg_foreign_object::element::*;
+            pub use crate::generated::svg_g::element::*;
+            pub use crate::generated::svg_image::element::*;
+            pub use crate::generated::svg_line::element::*;
+            pub use crate::generated::svg_linear_gradient::element::*;
+            pub use crate::generated::svg_marker::element::*;
+            pub use crate::generated::svg_mask::element::*;
+            pub use crate::generated::svg_metadata::element::*;
+            pub use crate::generated::svg_path::element::*;
+            pub use crate::generated::svg_pattern::element::*;
+            pub use crate::generated::svg_polygon::element::*;
+            pub use crate::generated::svg_polyline::element::*;
+            pub use crate::generated::svg_radial_gradient::element::*;
+            pub use crate::generated::svg_rect::element::*;
+            pub use crate::generated::svg_script::element::*;
+            pub use crate::generated::svg_stop::element::*;
+            pub use crate::generated::svg_style::element::*;
+            pub use crate::generated::svg_svg::element::*;
+            pub use crate::generated::svg_switch::element::*;
+            pub use crate::generated::svg_symbol::element::*;
+            pub use crate::generated::svg_text::element::*;
+            pub use crate::generated::svg_text_path::element::*;
+            pub use crate::generated::svg_title::element::*;
+            pub use crate::generated::svg_tspan::element::*;
+            pub use crate::generated::svg_use::element::*;
+            pub use crate::generated::svg_view::element::*;
+        }
+        /// Child elements
+        pub mod children {
+            pub use crate::generated::svg_a::child::*;
+            pub use crate::generated::svg_circle::child::*;
+            pub use crate::generated::svg_clip_path::child::*;
+            pub use crate::generated::svg_defs::child::*;
+            pub use crate::generated::svg_desc::child::*;
+            pub use crate::generated::svg_ellipse::child::*;
+            pub use crate::generated::svg_foreign_object::child::*;
+            pub use crate::generated::svg_g::child::*;
+            pub use crate::generated::svg_image::child::*;
+            pub use crate::generated::svg_line::child::*;
+            pub use crate::generated::svg_linear_gradient::child::*;
+            pub use crate::generated::svg_marker::child::*;
+            pub use crate::generated::svg_mask::child::*;
+            pub use crate::generated::svg_metadata::child::*;
+            pub use crate::generated::svg_path::child::*;
+            pub use crate::generated::svg_pattern::child::*;
+            pub use crate::generated::svg_polygon::child::*;
+            pub use crate::generated::svg_polyline::child::*;
+            pub use crate::generated::svg_radial_gradient::child::*;
+            pub use crate::generated::svg_rect::child::*;
+            pub use crate::generated::svg_script::child::*;
+            pub use crate::generated::svg_stop::child::*;
+            pub use crate::generated::svg_style::child::*;
+            pub use crate::generated::svg_svg::child::*;
+            pub use crate::generated::svg_switch::child::*;
+            pub use crate::generated::svg_symbol::child::*;
+            pub use crate::generated::svg_text::child::*;
+            pub use crate::generated::svg_text_path::child::*;
+            pub use crate::generated::svg_title::child::*;
+            pub use crate::generated::svg_tspan::child::*;
+            pub use crate::generated::svg_use::child::*;
+            pub use crate::generated::svg_view::child::*;
+        }
+        /// Element builders
+        pub mod builders {
+            pub use crate::generated::svg_a::builder::*;
+            pub use crate::generated::svg_circle::builder::*;
+            pub use crate::generated::svg_clip_path::builder::*;
+            pub use crate::generated::svg_defs::builder::*;
+            pub use crate::generated::svg_desc::builder::*;
+            pub use crate::generated::svg_ellipse::builder::*;
+            pub use crate::generated::svg_foreign_object::builder::*;
+            pub use crate::generated::svg_g::builder::*;
+            pub use crate::generated::svg_image::builder::*;
+            pub use crate::generated::svg_line::builder::*;
+            pub use crate::generated::svg_linear_gradient::builder::*;
+            pub use crate::generated::svg_marker::builder::*;
+            pub use crate::generated::svg_mask::builder::*;
+            pub use crate::generated::svg_metadata::builder::*;
+            pub use crate::generated::svg_path::builder::*;
+            pub use crate::generated::svg_pattern::builder::*;
+            pub use crate::generated::svg_polygon::builder::*;
+            pub use crate::generated::svg_polyline::builder::*;
+            pub use crate::generated::svg_radial_gradient::builder::*;
+            pub use crate::generated::svg_rect::builder::*;
+            pub use crate::generated::svg_script::builder::*;
+            pub use crate::generated::svg_stop::builder::*;
+            pub use crate::generated::svg_style::builder::*;
+            pub use crate::generated::svg_svg::builder::*;
+            pub use crate::generated::svg_switch::builder::*;
+            pub use crate::generated::svg_symbol::builder::*;
+            pub use crate::generated::svg_text::builder::*;
+            pub use crate::generated::svg_text_path::builder::*;
+            pub use crate::generated::svg_title::builder::*;
+            pub use crate::generated::svg_tspan::builder::*;
+            pub use crate::generated::svg_use::builder::*;
+            pub use crate::generated::svg_view::builder::*;
+        }
+    }
 }
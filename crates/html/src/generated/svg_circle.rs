@@ -0,0 +1,3594 @@
+pub mod element {
+    /// The SVG `<circle>` element
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/circle)
+    #[doc(alias = "circle")]
+    #[non_exhaustive]
+    #[derive(PartialEq, Clone, Default)]
+    pub struct Circle {
+        sys: html_sys::svg::Circle,
+        children: Vec<super::child::CircleChild>,
+    }
+    impl Circle {
+        /// Create a new builder
+        pub fn builder() -> super::builder::CircleBuilder {
+            super::builder::CircleBuilder::new(Default::default())
+        }
+    }
+    impl<'a> From<&'a Circle> for crate::Node<'a> {
+        fn from(element: &'a Circle) -> crate::Node<'a> {
+            crate::Node::Element(element)
+        }
+    }
+    impl Circle {
+        /// Access the element's `data-*` properties
+        pub fn data_map(&self) -> &html_sys::DataMap {
+            &self.sys.data_map
+        }
+        /// Mutably access the element's `data-*` properties
+        pub fn data_map_mut(&mut self) -> &mut html_sys::DataMap {
+            &mut self.sys.data_map
+        }
+    }
+    impl Circle {
+        /// Get the value of the `id` attribute
+        pub fn id(&self) -> std::option::Option<&str> {
+            self.sys.id.as_deref()
+        }
+        /// Set the value of the `id` attribute
+        pub fn set_id(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.id = value.map(|v| v.into());
+        }
+        /// Get the value of the `tabindex` attribute
+        pub fn tabindex(&self) -> std::option::Option<i64> {
+            self.sys.tabindex
+        }
+        /// Set the value of the `tabindex` attribute
+        pub fn set_tabindex(&mut self, value: std::option::Option<i64>) {
+            self.sys.tabindex = value;
+        }
+        /// Get the value of the `autofocus` attribute
+        pub fn autofocus(&self) -> bool {
+            self.sys.autofocus
+        }
+        /// Set the value of the `autofocus` attribute
+        pub fn set_autofocus(&mut self, value: bool) {
+            self.sys.autofocus = value;
+        }
+        /// Get the value of the `lang` attribute
+        pub fn lang(&self) -> std::option::Option<&str> {
+            self.sys.lang.as_deref()
+        }
+        /// Set the value of the `lang` attribute
+        pub fn set_lang(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.lang = value.map(|v| v.into());
+        }
+        /// Get the value of the `class` attribute
+        pub fn class(&self) -> std::option::Option<&str> {
+            self.sys.class.as_deref()
+        }
+        /// Set the value of the `class` attribute
+        pub fn set_class(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.class = value.map(|v| v.into());
+        }
+        /// Get the value of the `style` attribute
+        pub fn style(&self) -> std::option::Option<&str> {
+            self.sys.style.as_deref()
+        }
+        /// Set the value of the `style` attribute
+        pub fn set_style(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.style = value.map(|v| v.into());
+        }
+        /// Get the value of the `requiredExtensions` attribute
+        pub fn required_extensions(&self) -> std::option::Option<&str> {
+            self.sys.required_extensions.as_deref()
+        }
+        /// Set the value of the `requiredExtensions` attribute
+        pub fn set_required_extensions(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.required_extensions = value.map(|v| v.into());
+        }
+        /// Get the value of the `systemLanguage` attribute
+        pub fn system_language(&self) -> std::option::Option<&str> {
+            self.sys.system_language.as_deref()
+        }
+        /// Set the value of the `systemLanguage` attribute
+        pub fn set_system_language(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.system_language = value.map(|v| v.into());
+        }
+        /// Get the value of the `alignment-baseline` attribute
+        pub fn alignment_baseline(&self) -> std::option::Option<&str> {
+            self.sys.alignment_baseline.as_deref()
+        }
+        /// Set the value of the `alignment-baseline` attribute
+        pub fn set_alignment_baseline(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.alignment_baseline = value.map(|v| v.into());
+        }
+        /// Get the value of the `baseline-shift` attribute
+        pub fn baseline_shift(&self) -> std::option::Option<&str> {
+            self.sys.baseline_shift.as_deref()
+        }
+        /// Set the value of the `baseline-shift` attribute
+        pub fn set_baseline_shift(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.baseline_shift = value.map(|v| v.into());
+        }
+        /// Get the value of the `clip` attribute
+        pub fn clip(&self) -> std::option::Option<&str> {
+            self.sys.clip.as_deref()
+        }
+        /// Set the value of the `clip` attribute
+        pub fn set_clip(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.clip = value.map(|v| v.into());
+        }
+        /// Get the value of the `clip-path` attribute
+        pub fn clip_path(&self) -> std::option::Option<&str> {
+            self.sys.clip_path.as_deref()
+        }
+        /// Set the value of the `clip-path` attribute
+        pub fn set_clip_path(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.clip_path = value.map(|v| v.into());
+        }
+        /// Get the value of the `clip-rule` attribute
+        pub fn clip_rule(&self) -> std::option::Option<&str> {
+            self.sys.clip_rule.as_deref()
+        }
+        /// Set the value of the `clip-rule` attribute
+        pub fn set_clip_rule(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.clip_rule = value.map(|v| v.into());
+        }
+        /// Get the value of the `color` attribute
+        pub fn color(&self) -> std::option::Option<&str> {
+            self.sys.color.as_deref()
+        }
+        /// Set the value of the `color` attribute
+        pub fn set_color(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.color = value.map(|v| v.into());
+        }
+        /// Get the value of the `color-interpolation` attribute
+        pub fn color_interpolation(&self) -> std::option::Option<&str> {
+            self.sys.color_interpolation.as_deref()
+        }
+        /// Set the value of the `color-interpolation` attribute
+        pub fn set_color_interpolation(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.color_interpolation = value.map(|v| v.into());
+        }
+        /// Get the value of the `color-interpolation-filters` attribute
+        pub fn color_interpolation_filters(&self) -> std::option::Option<&str> {
+            self.sys.color_interpolation_filters.as_deref()
+        }
+        /// Set the value of the `color-interpolation-filters` attribute
+        pub fn set_color_interpolation_filters(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.color_interpolation_filters = value.map(|v| v.into());
+        }
+        /// Get the value of the `color-rendering` attribute
+        pub fn color_rendering(&self) -> std::option::Option<&str> {
+            self.sys.color_rendering.as_deref()
+        }
+        /// Set the value of the `color-rendering` attribute
+        pub fn set_color_rendering(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.color_rendering = value.map(|v| v.into());
+        }
+        /// Get the value of the `cursor` attribute
+        pub fn cursor(&self) -> std::option::Option<&str> {
+            self.sys.cursor.as_deref()
+        }
+        /// Set the value of the `cursor` attribute
+        pub fn set_cursor(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.cursor = value.map(|v| v.into());
+        }
+        /// Get the value of the `direction` attribute
+        pub fn direction(&self) -> std::option::Option<&str> {
+            self.sys.direction.as_deref()
+        }
+        /// Set the value of the `direction` attribute
+        pub fn set_direction(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.direction = value.map(|v| v.into());
+        }
+        /// Get the value of the `display` attribute
+        pub fn display(&self) -> std::option::Option<&str> {
+            self.sys.display.as_deref()
+        }
+        /// Set the value of the `display` attribute
+        pub fn set_display(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.display = value.map(|v| v.into());
+        }
+        /// Get the value of the `dominant-baseline` attribute
+        pub fn dominant_baseline(&self) -> std::option::Option<&str> {
+            self.sys.dominant_baseline.as_deref()
+        }
+        /// Set the value of the `dominant-baseline` attribute
+        pub fn set_dominant_baseline(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.dominant_baseline = value.map(|v| v.into());
+        }
+        /// Get the value of the `fill` attribute
+        pub fn fill(&self) -> std::option::Option<&str> {
+            self.sys.fill.as_deref()
+        }
+        /// Set the value of the `fill` attribute
+        pub fn set_fill(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.fill = value.map(|v| v.into());
+        }
+        /// Get the value of the `fill-opacity` attribute
+        pub fn fill_opacity(&self) -> std::option::Option<&str> {
+            self.sys.fill_opacity.as_deref()
+        }
+        /// Set the value of the `fill-opacity` attribute
+        pub fn set_fill_opacity(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.fill_opacity = value.map(|v| v.into());
+        }
+        /// Get the value of the `fill-rule` attribute
+        pub fn fill_rule(&self) -> std::option::Option<&str> {
+            self.sys.fill_rule.as_deref()
+        }
+        /// Set the value of the `fill-rule` attribute
+        pub fn set_fill_rule(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.fill_rule = value.map(|v| v.into());
+        }
+        /// Get the value of the `filter` attribute
+        pub fn filter(&self) -> std::option::Option<&str> {
+            self.sys.filter.as_deref()
+        }
+        /// Set the value of the `filter` attribute
+        pub fn set_filter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.filter = value.map(|v| v.into());
+        }
+        /// Get the value of the `flood-color` attribute
+        pub fn flood_color(&self) -> std::option::Option<&str> {
+            self.sys.flood_color.as_deref()
+        }
+        /// Set the value of the `flood-color` attribute
+        pub fn set_flood_color(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.flood_color = value.map(|v| v.into());
+        }
+        /// Get the value of the `flood-opacity` attribute
+        pub fn flood_opacity(&self) -> std::option::Option<&str> {
+            self.sys.flood_opacity.as_deref()
+        }
+        /// Set the value of the `flood-opacity` attribute
+        pub fn set_flood_opacity(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.flood_opacity = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-family` attribute
+        pub fn font_family(&self) -> std::option::Option<&str> {
+            self.sys.font_family.as_deref()
+        }
+        /// Set the value of the `font-family` attribute
+        pub fn set_font_family(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_family = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-size` attribute
+        pub fn font_size(&self) -> std::option::Option<&str> {
+            self.sys.font_size.as_deref()
+        }
+        /// Set the value of the `font-size` attribute
+        pub fn set_font_size(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_size = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-size-adjust` attribute
+        pub fn font_size_adjust(&self) -> std::option::Option<&str> {
+            self.sys.font_size_adjust.as_deref()
+        }
+        /// Set the value of the `font-size-adjust` attribute
+        pub fn set_font_size_adjust(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_size_adjust = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-stretch` attribute
+        pub fn font_stretch(&self) -> std::option::Option<&str> {
+            self.sys.font_stretch.as_deref()
+        }
+        /// Set the value of the `font-stretch` attribute
+        pub fn set_font_stretch(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_stretch = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-style` attribute
+        pub fn font_style(&self) -> std::option::Option<&str> {
+            self.sys.font_style.as_deref()
+        }
+        /// Set the value of the `font-style` attribute
+        pub fn set_font_style(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_style = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-variant` attribute
+        pub fn font_variant(&self) -> std::option::Option<&str> {
+            self.sys.font_variant.as_deref()
+        }
+        /// Set the value of the `font-variant` attribute
+        pub fn set_font_variant(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_variant = value.map(|v| v.into());
+        }
+        /// Get the value of the `font-weight` attribute
+        pub fn font_weight(&self) -> std::option::Option<&str> {
+            self.sys.font_weight.as_deref()
+        }
+        /// Set the value of the `font-weight` attribute
+        pub fn set_font_weight(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.font_weight = value.map(|v| v.into());
+        }
+        /// Get the value of the `glyph-orientation-horizontal` attribute
+        pub fn glyph_orientation_horizontal(&self) -> std::option::Option<&str> {
+            self.sys.glyph_orientation_horizontal.as_deref()
+        }
+        /// Set the value of the `glyph-orientation-horizontal` attribute
+        pub fn set_glyph_orientation_horizontal(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.glyph_orientation_horizontal = value.map(|v| v.into());
+        }
+        /// Get the value of the `glyph-orientation-vertical` attribute
+        pub fn glyph_orientation_vertical(&self) -> std::option::Option<&str> {
+            self.sys.glyph_orientation_vertical.as_deref()
+        }
+        /// Set the value of the `glyph-orientation-vertical` attribute
+        pub fn set_glyph_orientation_vertical(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.glyph_orientation_vertical = value.map(|v| v.into());
+        }
+        /// Get the value of the `image-rendering` attribute
+        pub fn image_rendering(&self) -> std::option::Option<&str> {
+            self.sys.image_rendering.as_deref()
+        }
+        /// Set the value of the `image-rendering` attribute
+        pub fn set_image_rendering(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.image_rendering = value.map(|v| v.into());
+        }
+        /// Get the value of the `letter-spacing` attribute
+        pub fn letter_spacing(&self) -> std::option::Option<&str> {
+            self.sys.letter_spacing.as_deref()
+        }
+        /// Set the value of the `letter-spacing` attribute
+        pub fn set_letter_spacing(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.letter_spacing = value.map(|v| v.into());
+        }
+        /// Get the value of the `lighting-color` attribute
+        pub fn lighting_color(&self) -> std::option::Option<&str> {
+            self.sys.lighting_color.as_deref()
+        }
+        /// Set the value of the `lighting-color` attribute
+        pub fn set_lighting_color(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.lighting_color = value.map(|v| v.into());
+        }
+        /// Get the value of the `marker-end` attribute
+        pub fn marker_end(&self) -> std::option::Option<&str> {
+            self.sys.marker_end.as_deref()
+        }
+        /// Set the value of the `marker-end` attribute
+        pub fn set_marker_end(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.marker_end = value.map(|v| v.into());
+        }
+        /// Get the value of the `marker-mid` attribute
+        pub fn marker_mid(&self) -> std::option::Option<&str> {
+            self.sys.marker_mid.as_deref()
+        }
+        /// Set the value of the `marker-mid` attribute
+        pub fn set_marker_mid(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.marker_mid = value.map(|v| v.into());
+        }
+        /// Get the value of the `marker-start` attribute
+        pub fn marker_start(&self) -> std::option::Option<&str> {
+            self.sys.marker_start.as_deref()
+        }
+        /// Set the value of the `marker-start` attribute
+        pub fn set_marker_start(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.marker_start = value.map(|v| v.into());
+        }
+        /// Get the value of the `mask` attribute
+        pub fn mask(&self) -> std::option::Option<&str> {
+            self.sys.mask.as_deref()
+        }
+        /// Set the value of the `mask` attribute
+        pub fn set_mask(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.mask = value.map(|v| v.into());
+        }
+        /// Get the value of the `mask-type` attribute
+        pub fn mask_type(&self) -> std::option::Option<&str> {
+            self.sys.mask_type.as_deref()
+        }
+        /// Set the value of the `mask-type` attribute
+        pub fn set_mask_type(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.mask_type = value.map(|v| v.into());
+        }
+        /// Get the value of the `opacity` attribute
+        pub fn opacity(&self) -> std::option::Option<&str> {
+            self.sys.opacity.as_deref()
+        }
+        /// Set the value of the `opacity` attribute
+        pub fn set_opacity(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.opacity = value.map(|v| v.into());
+        }
+        /// Get the value of the `overflow` attribute
+        pub fn overflow(&self) -> std::option::Option<&str> {
+            self.sys.overflow.as_deref()
+        }
+        /// Set the value of the `overflow` attribute
+        pub fn set_overflow(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.overflow = value.map(|v| v.into());
+        }
+        /// Get the value of the `paint-order` attribute
+        pub fn paint_order(&self) -> std::option::Option<&str> {
+            self.sys.paint_order.as_deref()
+        }
+        /// Set the value of the `paint-order` attribute
+        pub fn set_paint_order(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.paint_order = value.map(|v| v.into());
+        }
+        /// Get the value of the `pointer-events` attribute
+        pub fn pointer_events(&self) -> std::option::Option<&str> {
+            self.sys.pointer_events.as_deref()
+        }
+        /// Set the value of the `pointer-events` attribute
+        pub fn set_pointer_events(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.pointer_events = value.map(|v| v.into());
+        }
+        /// Get the value of the `shape-rendering` attribute
+        pub fn shape_rendering(&self) -> std::option::Option<&str> {
+            self.sys.shape_rendering.as_deref()
+        }
+        /// Set the value of the `shape-rendering` attribute
+        pub fn set_shape_rendering(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.shape_rendering = value.map(|v| v.into());
+        }
+        /// Get the value of the `stop-color` attribute
+        pub fn stop_color(&self) -> std::option::Option<&str> {
+            self.sys.stop_color.as_deref()
+        }
+        /// Set the value of the `stop-color` attribute
+        pub fn set_stop_color(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stop_color = value.map(|v| v.into());
+        }
+        /// Get the value of the `stop-opacity` attribute
+        pub fn stop_opacity(&self) -> std::option::Option<&str> {
+            self.sys.stop_opacity.as_deref()
+        }
+        /// Set the value of the `stop-opacity` attribute
+        pub fn set_stop_opacity(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stop_opacity = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke` attribute
+        pub fn stroke(&self) -> std::option::Option<&str> {
+            self.sys.stroke.as_deref()
+        }
+        /// Set the value of the `stroke` attribute
+        pub fn set_stroke(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-dasharray` attribute
+        pub fn stroke_dasharray(&self) -> std::option::Option<&str> {
+            self.sys.stroke_dasharray.as_deref()
+        }
+        /// Set the value of the `stroke-dasharray` attribute
+        pub fn set_stroke_dasharray(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_dasharray = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-dashoffset` attribute
+        pub fn stroke_dashoffset(&self) -> std::option::Option<&str> {
+            self.sys.stroke_dashoffset.as_deref()
+        }
+        /// Set the value of the `stroke-dashoffset` attribute
+        pub fn set_stroke_dashoffset(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_dashoffset = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-linecap` attribute
+        pub fn stroke_linecap(&self) -> std::option::Option<&str> {
+            self.sys.stroke_linecap.as_deref()
+        }
+        /// Set the value of the `stroke-linecap` attribute
+        pub fn set_stroke_linecap(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_linecap = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-linejoin` attribute
+        pub fn stroke_linejoin(&self) -> std::option::Option<&str> {
+            self.sys.stroke_linejoin.as_deref()
+        }
+        /// Set the value of the `stroke-linejoin` attribute
+        pub fn set_stroke_linejoin(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_linejoin = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-miterlimit` attribute
+        pub fn stroke_miterlimit(&self) -> std::option::Option<&str> {
+            self.sys.stroke_miterlimit.as_deref()
+        }
+        /// Set the value of the `stroke-miterlimit` attribute
+        pub fn set_stroke_miterlimit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_miterlimit = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-opacity` attribute
+        pub fn stroke_opacity(&self) -> std::option::Option<&str> {
+            self.sys.stroke_opacity.as_deref()
+        }
+        /// Set the value of the `stroke-opacity` attribute
+        pub fn set_stroke_opacity(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_opacity = value.map(|v| v.into());
+        }
+        /// Get the value of the `stroke-width` attribute
+        pub fn stroke_width(&self) -> std::option::Option<&str> {
+            self.sys.stroke_width.as_deref()
+        }
+        /// Set the value of the `stroke-width` attribute
+        pub fn set_stroke_width(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.stroke_width = value.map(|v| v.into());
+        }
+        /// Get the value of the `text-anchor` attribute
+        pub fn text_anchor(&self) -> std::option::Option<&str> {
+            self.sys.text_anchor.as_deref()
+        }
+        /// Set the value of the `text-anchor` attribute
+        pub fn set_text_anchor(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.text_anchor = value.map(|v| v.into());
+        }
+        /// Get the value of the `text-decoration` attribute
+        pub fn text_decoration(&self) -> std::option::Option<&str> {
+            self.sys.text_decoration.as_deref()
+        }
+        /// Set the value of the `text-decoration` attribute
+        pub fn set_text_decoration(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.text_decoration = value.map(|v| v.into());
+        }
+        /// Get the value of the `text-overflow` attribute
+        pub fn text_overflow(&self) -> std::option::Option<&str> {
+            self.sys.text_overflow.as_deref()
+        }
+        /// Set the value of the `text-overflow` attribute
+        pub fn set_text_overflow(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.text_overflow = value.map(|v| v.into());
+        }
+        /// Get the value of the `text-rendering` attribute
+        pub fn text_rendering(&self) -> std::option::Option<&str> {
+            self.sys.text_rendering.as_deref()
+        }
+        /// Set the value of the `text-rendering` attribute
+        pub fn set_text_rendering(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.text_rendering = value.map(|v| v.into());
+        }
+        /// Get the value of the `transform` attribute
+        pub fn transform(&self) -> std::option::Option<&str> {
+            self.sys.transform.as_deref()
+        }
+        /// Set the value of the `transform` attribute
+        pub fn set_transform(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.transform = value.map(|v| v.into());
+        }
+        /// Get the value of the `transform-origin` attribute
+        pub fn transform_origin(&self) -> std::option::Option<&str> {
+            self.sys.transform_origin.as_deref()
+        }
+        /// Set the value of the `transform-origin` attribute
+        pub fn set_transform_origin(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.transform_origin = value.map(|v| v.into());
+        }
+        /// Get the value of the `unicode-bidi` attribute
+        pub fn unicode_bidi(&self) -> std::option::Option<&str> {
+            self.sys.unicode_bidi.as_deref()
+        }
+        /// Set the value of the `unicode-bidi` attribute
+        pub fn set_unicode_bidi(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.unicode_bidi = value.map(|v| v.into());
+        }
+        /// Get the value of the `vector-effect` attribute
+        pub fn vector_effect(&self) -> std::option::Option<&str> {
+            self.sys.vector_effect.as_deref()
+        }
+        /// Set the value of the `vector-effect` attribute
+        pub fn set_vector_effect(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.vector_effect = value.map(|v| v.into());
+        }
+        /// Get the value of the `visibility` attribute
+        pub fn visibility(&self) -> std::option::Option<&str> {
+            self.sys.visibility.as_deref()
+        }
+        /// Set the value of the `visibility` attribute
+        pub fn set_visibility(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.visibility = value.map(|v| v.into());
+        }
+        /// Get the value of the `white-space` attribute
+        pub fn white_space(&self) -> std::option::Option<&str> {
+            self.sys.white_space.as_deref()
+        }
+        /// Set the value of the `white-space` attribute
+        pub fn set_white_space(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.white_space = value.map(|v| v.into());
+        }
+        /// Get the value of the `word-spacing` attribute
+        pub fn word_spacing(&self) -> std::option::Option<&str> {
+            self.sys.word_spacing.as_deref()
+        }
+        /// Set the value of the `word-spacing` attribute
+        pub fn set_word_spacing(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.word_spacing = value.map(|v| v.into());
+        }
+        /// Get the value of the `writing-mode` attribute
+        pub fn writing_mode(&self) -> std::option::Option<&str> {
+            self.sys.writing_mode.as_deref()
+        }
+        /// Set the value of the `writing-mode` attribute
+        pub fn set_writing_mode(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.writing_mode = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncancel` attribute
+        pub fn oncancel(&self) -> std::option::Option<&str> {
+            self.sys.oncancel.as_deref()
+        }
+        /// Set the value of the `oncancel` attribute
+        pub fn set_oncancel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncancel = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncanplay` attribute
+        pub fn oncanplay(&self) -> std::option::Option<&str> {
+            self.sys.oncanplay.as_deref()
+        }
+        /// Set the value of the `oncanplay` attribute
+        pub fn set_oncanplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplay = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(&self) -> std::option::Option<&str> {
+            self.sys.oncanplaythrough.as_deref()
+        }
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn set_oncanplaythrough(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncanplaythrough = value.map(|v| v.into());
+        }
+        /// Get the value of the `onchange` attribute
+        pub fn onchange(&self) -> std::option::Option<&str> {
+            self.sys.onchange.as_deref()
+        }
+        /// Set the value of the `onchange` attribute
+        pub fn set_onchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onclick` attribute
+        pub fn onclick(&self) -> std::option::Option<&str> {
+            self.sys.onclick.as_deref()
+        }
+        /// Set the value of the `onclick` attribute
+        pub fn set_onclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclick = value.map(|v| v.into());
+        }
+        /// Get the value of the `onclose` attribute
+        pub fn onclose(&self) -> std::option::Option<&str> {
+            self.sys.onclose.as_deref()
+        }
+        /// Set the value of the `onclose` attribute
+        pub fn set_onclose(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onclose = value.map(|v| v.into());
+        }
+        /// Get the value of the `oncuechange` attribute
+        pub fn oncuechange(&self) -> std::option::Option<&str> {
+            self.sys.oncuechange.as_deref()
+        }
+        /// Set the value of the `oncuechange` attribute
+        pub fn set_oncuechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oncuechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondblclick` attribute
+        pub fn ondblclick(&self) -> std::option::Option<&str> {
+            self.sys.ondblclick.as_deref()
+        }
+        /// Set the value of the `ondblclick` attribute
+        pub fn set_ondblclick(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondblclick = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondrag` attribute
+        pub fn ondrag(&self) -> std::option::Option<&str> {
+            self.sys.ondrag.as_deref()
+        }
+        /// Set the value of the `ondrag` attribute
+        pub fn set_ondrag(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrag = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragend` attribute
+        pub fn ondragend(&self) -> std::option::Option<&str> {
+            self.sys.ondragend.as_deref()
+        }
+        /// Set the value of the `ondragend` attribute
+        pub fn set_ondragend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragenter` attribute
+        pub fn ondragenter(&self) -> std::option::Option<&str> {
+            self.sys.ondragenter.as_deref()
+        }
+        /// Set the value of the `ondragenter` attribute
+        pub fn set_ondragenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragexit` attribute
+        pub fn ondragexit(&self) -> std::option::Option<&str> {
+            self.sys.ondragexit.as_deref()
+        }
+        /// Set the value of the `ondragexit` attribute
+        pub fn set_ondragexit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragexit = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragleave` attribute
+        pub fn ondragleave(&self) -> std::option::Option<&str> {
+            self.sys.ondragleave.as_deref()
+        }
+        /// Set the value of the `ondragleave` attribute
+        pub fn set_ondragleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragover` attribute
+        pub fn ondragover(&self) -> std::option::Option<&str> {
+            self.sys.ondragover.as_deref()
+        }
+        /// Set the value of the `ondragover` attribute
+        pub fn set_ondragover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragover = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondragstart` attribute
+        pub fn ondragstart(&self) -> std::option::Option<&str> {
+            self.sys.ondragstart.as_deref()
+        }
+        /// Set the value of the `ondragstart` attribute
+        pub fn set_ondragstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondragstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondrop` attribute
+        pub fn ondrop(&self) -> std::option::Option<&str> {
+            self.sys.ondrop.as_deref()
+        }
+        /// Set the value of the `ondrop` attribute
+        pub fn set_ondrop(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondrop = value.map(|v| v.into());
+        }
+        /// Get the value of the `ondurationchange` attribute
+        pub fn ondurationchange(&self) -> std::option::Option<&str> {
+            self.sys.ondurationchange.as_deref()
+        }
+        /// Set the value of the `ondurationchange` attribute
+        pub fn set_ondurationchange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ondurationchange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onemptied` attribute
+        pub fn onemptied(&self) -> std::option::Option<&str> {
+            self.sys.onemptied.as_deref()
+        }
+        /// Set the value of the `onemptied` attribute
+        pub fn set_onemptied(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onemptied = value.map(|v| v.into());
+        }
+        /// Get the value of the `onended` attribute
+        pub fn onended(&self) -> std::option::Option<&str> {
+            self.sys.onended.as_deref()
+        }
+        /// Set the value of the `onended` attribute
+        pub fn set_onended(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onended = value.map(|v| v.into());
+        }
+        /// Get the value of the `onerror` attribute
+        pub fn onerror(&self) -> std::option::Option<&str> {
+            self.sys.onerror.as_deref()
+        }
+        /// Set the value of the `onerror` attribute
+        pub fn set_onerror(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onerror = value.map(|v| v.into());
+        }
+        /// Get the value of the `onfocus` attribute
+        pub fn onfocus(&self) -> std::option::Option<&str> {
+            self.sys.onfocus.as_deref()
+        }
+        /// Set the value of the `onfocus` attribute
+        pub fn set_onfocus(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onfocus = value.map(|v| v.into());
+        }
+        /// Get the value of the `oninput` attribute
+        pub fn oninput(&self) -> std::option::Option<&str> {
+            self.sys.oninput.as_deref()
+        }
+        /// Set the value of the `oninput` attribute
+        pub fn set_oninput(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninput = value.map(|v| v.into());
+        }
+        /// Get the value of the `oninvalid` attribute
+        pub fn oninvalid(&self) -> std::option::Option<&str> {
+            self.sys.oninvalid.as_deref()
+        }
+        /// Set the value of the `oninvalid` attribute
+        pub fn set_oninvalid(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.oninvalid = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeydown` attribute
+        pub fn onkeydown(&self) -> std::option::Option<&str> {
+            self.sys.onkeydown.as_deref()
+        }
+        /// Set the value of the `onkeydown` attribute
+        pub fn set_onkeydown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeydown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeypress` attribute
+        pub fn onkeypress(&self) -> std::option::Option<&str> {
+            self.sys.onkeypress.as_deref()
+        }
+        /// Set the value of the `onkeypress` attribute
+        pub fn set_onkeypress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeypress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onkeyup` attribute
+        pub fn onkeyup(&self) -> std::option::Option<&str> {
+            self.sys.onkeyup.as_deref()
+        }
+        /// Set the value of the `onkeyup` attribute
+        pub fn set_onkeyup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onkeyup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onload` attribute
+        pub fn onload(&self) -> std::option::Option<&str> {
+            self.sys.onload.as_deref()
+        }
+        /// Set the value of the `onload` attribute
+        pub fn set_onload(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onload = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadeddata` attribute
+        pub fn onloadeddata(&self) -> std::option::Option<&str> {
+            self.sys.onloadeddata.as_deref()
+        }
+        /// Set the value of the `onloadeddata` attribute
+        pub fn set_onloadeddata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadeddata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(&self) -> std::option::Option<&str> {
+            self.sys.onloadedmetadata.as_deref()
+        }
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn set_onloadedmetadata(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadedmetadata = value.map(|v| v.into());
+        }
+        /// Get the value of the `onloadstart` attribute
+        pub fn onloadstart(&self) -> std::option::Option<&str> {
+            self.sys.onloadstart.as_deref()
+        }
+        /// Set the value of the `onloadstart` attribute
+        pub fn set_onloadstart(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onloadstart = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousedown` attribute
+        pub fn onmousedown(&self) -> std::option::Option<&str> {
+            self.sys.onmousedown.as_deref()
+        }
+        /// Set the value of the `onmousedown` attribute
+        pub fn set_onmousedown(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousedown = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseenter` attribute
+        pub fn onmouseenter(&self) -> std::option::Option<&str> {
+            self.sys.onmouseenter.as_deref()
+        }
+        /// Set the value of the `onmouseenter` attribute
+        pub fn set_onmouseenter(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseenter = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseleave` attribute
+        pub fn onmouseleave(&self) -> std::option::Option<&str> {
+            self.sys.onmouseleave.as_deref()
+        }
+        /// Set the value of the `onmouseleave` attribute
+        pub fn set_onmouseleave(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseleave = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmousemove` attribute
+        pub fn onmousemove(&self) -> std::option::Option<&str> {
+            self.sys.onmousemove.as_deref()
+        }
+        /// Set the value of the `onmousemove` attribute
+        pub fn set_onmousemove(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmousemove = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseout` attribute
+        pub fn onmouseout(&self) -> std::option::Option<&str> {
+            self.sys.onmouseout.as_deref()
+        }
+        /// Set the value of the `onmouseout` attribute
+        pub fn set_onmouseout(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseout = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseover` attribute
+        pub fn onmouseover(&self) -> std::option::Option<&str> {
+            self.sys.onmouseover.as_deref()
+        }
+        /// Set the value of the `onmouseover` attribute
+        pub fn set_onmouseover(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseover = value.map(|v| v.into());
+        }
+        /// Get the value of the `onmouseup` attribute
+        pub fn onmouseup(&self) -> std::option::Option<&str> {
+            self.sys.onmouseup.as_deref()
+        }
+        /// Set the value of the `onmouseup` attribute
+        pub fn set_onmouseup(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onmouseup = value.map(|v| v.into());
+        }
+        /// Get the value of the `onpause` attribute
+        pub fn onpause(&self) -> std::option::Option<&str> {
+            self.sys.onpause.as_deref()
+        }
+        /// Set the value of the `onpause` attribute
+        pub fn set_onpause(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onpause = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplay` attribute
+        pub fn onplay(&self) -> std::option::Option<&str> {
+            self.sys.onplay.as_deref()
+        }
+        /// Set the value of the `onplay` attribute
+        pub fn set_onplay(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplay = value.map(|v| v.into());
+        }
+        /// Get the value of the `onplaying` attribute
+        pub fn onplaying(&self) -> std::option::Option<&str> {
+            self.sys.onplaying.as_deref()
+        }
+        /// Set the value of the `onplaying` attribute
+        pub fn set_onplaying(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onplaying = value.map(|v| v.into());
+        }
+        /// Get the value of the `onprogress` attribute
+        pub fn onprogress(&self) -> std::option::Option<&str> {
+            self.sys.onprogress.as_deref()
+        }
+        /// Set the value of the `onprogress` attribute
+        pub fn set_onprogress(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onprogress = value.map(|v| v.into());
+        }
+        /// Get the value of the `onratechange` attribute
+        pub fn onratechange(&self) -> std::option::Option<&str> {
+            self.sys.onratechange.as_deref()
+        }
+        /// Set the value of the `onratechange` attribute
+        pub fn set_onratechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onratechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onreset` attribute
+        pub fn onreset(&self) -> std::option::Option<&str> {
+            self.sys.onreset.as_deref()
+        }
+        /// Set the value of the `onreset` attribute
+        pub fn set_onreset(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onreset = value.map(|v| v.into());
+        }
+        /// Get the value of the `onresize` attribute
+        pub fn onresize(&self) -> std::option::Option<&str> {
+            self.sys.onresize.as_deref()
+        }
+        /// Set the value of the `onresize` attribute
+        pub fn set_onresize(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onresize = value.map(|v| v.into());
+        }
+        /// Get the value of the `onscroll` attribute
+        pub fn onscroll(&self) -> std::option::Option<&str> {
+            self.sys.onscroll.as_deref()
+        }
+        /// Set the value of the `onscroll` attribute
+        pub fn set_onscroll(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onscroll = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeked` attribute
+        pub fn onseeked(&self) -> std::option::Option<&str> {
+            self.sys.onseeked.as_deref()
+        }
+        /// Set the value of the `onseeked` attribute
+        pub fn set_onseeked(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeked = value.map(|v| v.into());
+        }
+        /// Get the value of the `onseeking` attribute
+        pub fn onseeking(&self) -> std::option::Option<&str> {
+            self.sys.onseeking.as_deref()
+        }
+        /// Set the value of the `onseeking` attribute
+        pub fn set_onseeking(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onseeking = value.map(|v| v.into());
+        }
+        /// Get the value of the `onselect` attribute
+        pub fn onselect(&self) -> std::option::Option<&str> {
+            self.sys.onselect.as_deref()
+        }
+        /// Set the value of the `onselect` attribute
+        pub fn set_onselect(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onselect = value.map(|v| v.into());
+        }
+        /// Get the value of the `onshow` attribute
+        pub fn onshow(&self) -> std::option::Option<&str> {
+            self.sys.onshow.as_deref()
+        }
+        /// Set the value of the `onshow` attribute
+        pub fn set_onshow(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onshow = value.map(|v| v.into());
+        }
+        /// Get the value of the `onstalled` attribute
+        pub fn onstalled(&self) -> std::option::Option<&str> {
+            self.sys.onstalled.as_deref()
+        }
+        /// Set the value of the `onstalled` attribute
+        pub fn set_onstalled(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onstalled = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsubmit` attribute
+        pub fn onsubmit(&self) -> std::option::Option<&str> {
+            self.sys.onsubmit.as_deref()
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn set_onsubmit(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsubmit = value.map(|v| v.into());
+        }
+        /// Get the value of the `onsuspend` attribute
+        pub fn onsuspend(&self) -> std::option::Option<&str> {
+            self.sys.onsuspend.as_deref()
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn set_onsuspend(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onsuspend = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(&self) -> std::option::Option<&str> {
+            self.sys.ontimeupdate.as_deref()
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn set_ontimeupdate(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontimeupdate = value.map(|v| v.into());
+        }
+        /// Get the value of the `ontoggle` attribute
+        pub fn ontoggle(&self) -> std::option::Option<&str> {
+            self.sys.ontoggle.as_deref()
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn set_ontoggle(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.ontoggle = value.map(|v| v.into());
+        }
+        /// Get the value of the `onvolumechange` attribute
+        pub fn onvolumechange(&self) -> std::option::Option<&str> {
+            self.sys.onvolumechange.as_deref()
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn set_onvolumechange(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onvolumechange = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwaiting` attribute
+        pub fn onwaiting(&self) -> std::option::Option<&str> {
+            self.sys.onwaiting.as_deref()
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn set_onwaiting(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwaiting = value.map(|v| v.into());
+        }
+        /// Get the value of the `onwheel` attribute
+        pub fn onwheel(&self) -> std::option::Option<&str> {
+            self.sys.onwheel.as_deref()
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn set_onwheel(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.onwheel = value.map(|v| v.into());
+        }
+        /// Get the value of the `cx` attribute
+        pub fn cx(&self) -> std::option::Option<&str> {
+            self.sys.cx.as_deref()
+        }
+        /// Set the value of the `cx` attribute
+        pub fn set_cx(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.cx = value.map(|v| v.into());
+        }
+        /// Get the value of the `cy` attribute
+        pub fn cy(&self) -> std::option::Option<&str> {
+            self.sys.cy.as_deref()
+        }
+        /// Set the value of the `cy` attribute
+        pub fn set_cy(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.cy = value.map(|v| v.into());
+        }
+        /// Get the value of the `r` attribute
+        pub fn r(&self) -> std::option::Option<&str> {
+            self.sys.r.as_deref()
+        }
+        /// Set the value of the `r` attribute
+        pub fn set_r(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.r = value.map(|v| v.into());
+        }
+        /// Get the value of the `pathLength` attribute
+        pub fn path_length(&self) -> std::option::Option<&str> {
+            self.sys.path_length.as_deref()
+        }
+        /// Set the value of the `pathLength` attribute
+        pub fn set_path_length(
+            &mut self,
+            value: std::option::Option<impl Into<std::borrow::Cow<'static, str>>>,
+        ) {
+            self.sys.path_length = value.map(|v| v.into());
+        }
+    }
+    impl Circle {
+        /// Access the element's children
+        pub fn children(&self) -> &[super::child::CircleChild] {
+            self.children.as_ref()
+        }
+        /// Mutably access the element's children
+        pub fn children_mut(&mut self) -> &mut Vec<super::child::CircleChild> {
+            &mut self.children
+        }
+    }
+    impl std::fmt::Debug for Circle {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for Circle {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.children.is_empty() {
+                return html_sys::RenderElement::write_empty_tag(&self.sys, f);
+            }
+            html_sys::RenderElement::write_opening_tag(&self.sys, f)?;
+            for el in &self.children {
+                write!(f, "{el}")?;
+            }
+            html_sys::RenderElement::write_closing_tag(&self.sys, f)?;
+            Ok(())
+        }
+    }
+    impl crate::HtmlElement for Circle {
+        fn tag_name(&self) -> &'static str {
+            "circle"
+        }
+        fn attributes(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.attributes()
+        }
+        fn data(&self) -> html_sys::AttributeMap {
+            use html_sys::ElementDescription;
+            self.sys.data()
+        }
+        fn children<'a>(&'a self) -> Vec<crate::Node<'a>> {
+            self.children.iter().map(From::from).collect()
+        }
+    }
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromHtml for Circle {
+        const TAG_NAME: &'static str = "circle";
+        fn from_html(
+            element: crate::parse::Element<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Self {
+            let mut this: Self = Default::default();
+            for (name, value) in element.attributes() {
+                match name {
+                    "id" => this.set_id(Some(value.to_owned())),
+                    "tabindex" => this.set_tabindex(cx.parse_attribute(name, value)),
+                    "autofocus" => this.set_autofocus(true),
+                    "lang" => this.set_lang(Some(value.to_owned())),
+                    "class" => this.set_class(Some(value.to_owned())),
+                    "style" => this.set_style(Some(value.to_owned())),
+                    "requiredExtensions" => {
+                        this.set_required_extensions(Some(value.to_owned()))
+                    }
+                    "systemLanguage" => this.set_system_language(Some(value.to_owned())),
+                    "alignment-baseline" => {
+                        this.set_alignment_baseline(Some(value.to_owned()))
+                    }
+                    "baseline-shift" => this.set_baseline_shift(Some(value.to_owned())),
+                    "clip" => this.set_clip(Some(value.to_owned())),
+                    "clip-path" => this.set_clip_path(Some(value.to_owned())),
+                    "clip-rule" => this.set_clip_rule(Some(value.to_owned())),
+                    "color" => this.set_color(Some(value.to_owned())),
+                    "color-interpolation" => {
+                        this.set_color_interpolation(Some(value.to_owned()))
+                    }
+                    "color-interpolation-filters" => {
+                        this.set_color_interpolation_filters(Some(value.to_owned()))
+                    }
+                    "color-rendering" => this.set_color_rendering(Some(value.to_owned())),
+                    "cursor" => this.set_cursor(Some(value.to_owned())),
+                    "direction" => this.set_direction(Some(value.to_owned())),
+                    "display" => this.set_display(Some(value.to_owned())),
+                    "dominant-baseline" => {
+                        this.set_dominant_baseline(Some(value.to_owned()))
+                    }
+                    "fill" => this.set_fill(Some(value.to_owned())),
+                    "fill-opacity" => this.set_fill_opacity(Some(value.to_owned())),
+                    "fill-rule" => this.set_fill_rule(Some(value.to_owned())),
+                    "filter" => this.set_filter(Some(value.to_owned())),
+                    "flood-color" => this.set_flood_color(Some(value.to_owned())),
+                    "flood-opacity" => this.set_flood_opacity(Some(value.to_owned())),
+                    "font-family" => this.set_font_family(Some(value.to_owned())),
+                    "font-size" => this.set_font_size(Some(value.to_owned())),
+                    "font-size-adjust" => {
+                        this.set_font_size_adjust(Some(value.to_owned()))
+                    }
+                    "font-stretch" => this.set_font_stretch(Some(value.to_owned())),
+                    "font-style" => this.set_font_style(Some(value.to_owned())),
+                    "font-variant" => this.set_font_variant(Some(value.to_owned())),
+                    "font-weight" => this.set_font_weight(Some(value.to_owned())),
+                    "glyph-orientation-horizontal" => {
+                        this.set_glyph_orientation_horizontal(Some(value.to_owned()))
+                    }
+                    "glyph-orientation-vertical" => {
+                        this.set_glyph_orientation_vertical(Some(value.to_owned()))
+                    }
+                    "image-rendering" => this.set_image_rendering(Some(value.to_owned())),
+                    "letter-spacing" => this.set_letter_spacing(Some(value.to_owned())),
+                    "lighting-color" => this.set_lighting_color(Some(value.to_owned())),
+                    "marker-end" => this.set_marker_end(Some(value.to_owned())),
+                    "marker-mid" => this.set_marker_mid(Some(value.to_owned())),
+                    "marker-start" => this.set_marker_start(Some(value.to_owned())),
+                    "mask" => this.set_mask(Some(value.to_owned())),
+                    "mask-type" => this.set_mask_type(Some(value.to_owned())),
+                    "opacity" => this.set_opacity(Some(value.to_owned())),
+                    "overflow" => this.set_overflow(Some(value.to_owned())),
+                    "paint-order" => this.set_paint_order(Some(value.to_owned())),
+                    "pointer-events" => this.set_pointer_events(Some(value.to_owned())),
+                    "shape-rendering" => this.set_shape_rendering(Some(value.to_owned())),
+                    "stop-color" => this.set_stop_color(Some(value.to_owned())),
+                    "stop-opacity" => this.set_stop_opacity(Some(value.to_owned())),
+                    "stroke" => this.set_stroke(Some(value.to_owned())),
+                    "stroke-dasharray" => {
+                        this.set_stroke_dasharray(Some(value.to_owned()))
+                    }
+                    "stroke-dashoffset" => {
+                        this.set_stroke_dashoffset(Some(value.to_owned()))
+                    }
+                    "stroke-linecap" => this.set_stroke_linecap(Some(value.to_owned())),
+                    "stroke-linejoin" => this.set_stroke_linejoin(Some(value.to_owned())),
+                    "stroke-miterlimit" => {
+                        this.set_stroke_miterlimit(Some(value.to_owned()))
+                    }
+                    "stroke-opacity" => this.set_stroke_opacity(Some(value.to_owned())),
+                    "stroke-width" => this.set_stroke_width(Some(value.to_owned())),
+                    "text-anchor" => this.set_text_anchor(Some(value.to_owned())),
+                    "text-decoration" => this.set_text_decoration(Some(value.to_owned())),
+                    "text-overflow" => this.set_text_overflow(Some(value.to_owned())),
+                    "text-rendering" => this.set_text_rendering(Some(value.to_owned())),
+                    "transform" => this.set_transform(Some(value.to_owned())),
+                    "transform-origin" => {
+                        this.set_transform_origin(Some(value.to_owned()))
+                    }
+                    "unicode-bidi" => this.set_unicode_bidi(Some(value.to_owned())),
+                    "vector-effect" => this.set_vector_effect(Some(value.to_owned())),
+                    "visibility" => this.set_visibility(Some(value.to_owned())),
+                    "white-space" => this.set_white_space(Some(value.to_owned())),
+                    "word-spacing" => this.set_word_spacing(Some(value.to_owned())),
+                    "writing-mode" => this.set_writing_mode(Some(value.to_owned())),
+                    "oncancel" => this.set_oncancel(Some(value.to_owned())),
+                    "oncanplay" => this.set_oncanplay(Some(value.to_owned())),
+                    "oncanplaythrough" => {
+                        this.set_oncanplaythrough(Some(value.to_owned()))
+                    }
+                    "onchange" => this.set_onchange(Some(value.to_owned())),
+                    "onclick" => this.set_onclick(Some(value.to_owned())),
+                    "onclose" => this.set_onclose(Some(value.to_owned())),
+                    "oncuechange" => this.set_oncuechange(Some(value.to_owned())),
+                    "ondblclick" => this.set_ondblclick(Some(value.to_owned())),
+                    "ondrag" => this.set_ondrag(Some(value.to_owned())),
+                    "ondragend" => this.set_ondragend(Some(value.to_owned())),
+                    "ondragenter" => this.set_ondragenter(Some(value.to_owned())),
+                    "ondragexit" => this.set_ondragexit(Some(value.to_owned())),
+                    "ondragleave" => this.set_ondragleave(Some(value.to_owned())),
+                    "ondragover" => this.set_ondragover(Some(value.to_owned())),
+                    "ondragstart" => this.set_ondragstart(Some(value.to_owned())),
+                    "ondrop" => this.set_ondrop(Some(value.to_owned())),
+                    "ondurationchange" => {
+                        this.set_ondurationchange(Some(value.to_owned()))
+                    }
+                    "onemptied" => this.set_onemptied(Some(value.to_owned())),
+                    "onended" => this.set_onended(Some(value.to_owned())),
+                    "onerror" => this.set_onerror(Some(value.to_owned())),
+                    "onfocus" => this.set_onfocus(Some(value.to_owned())),
+                    "oninput" => this.set_oninput(Some(value.to_owned())),
+                    "oninvalid" => this.set_oninvalid(Some(value.to_owned())),
+                    "onkeydown" => this.set_onkeydown(Some(value.to_owned())),
+                    "onkeypress" => this.set_onkeypress(Some(value.to_owned())),
+                    "onkeyup" => this.set_onkeyup(Some(value.to_owned())),
+                    "onload" => this.set_onload(Some(value.to_owned())),
+                    "onloadeddata" => this.set_onloadeddata(Some(value.to_owned())),
+                    "onloadedmetadata" => {
+                        this.set_onloadedmetadata(Some(value.to_owned()))
+                    }
+                    "onloadstart" => this.set_onloadstart(Some(value.to_owned())),
+                    "onmousedown" => this.set_onmousedown(Some(value.to_owned())),
+                    "onmouseenter" => this.set_onmouseenter(Some(value.to_owned())),
+                    "onmouseleave" => this.set_onmouseleave(Some(value.to_owned())),
+                    "onmousemove" => this.set_onmousemove(Some(value.to_owned())),
+                    "onmouseout" => this.set_onmouseout(Some(value.to_owned())),
+                    "onmouseover" => this.set_onmouseover(Some(value.to_owned())),
+                    "onmouseup" => this.set_onmouseup(Some(value.to_owned())),
+                    "onpause" => this.set_onpause(Some(value.to_owned())),
+                    "onplay" => this.set_onplay(Some(value.to_owned())),
+                    "onplaying" => this.set_onplaying(Some(value.to_owned())),
+                    "onprogress" => this.set_onprogress(Some(value.to_owned())),
+                    "onratechange" => this.set_onratechange(Some(value.to_owned())),
+                    "onreset" => this.set_onreset(Some(value.to_owned())),
+                    "onresize" => this.set_onresize(Some(value.to_owned())),
+                    "onscroll" => this.set_onscroll(Some(value.to_owned())),
+                    "onseeked" => this.set_onseeked(Some(value.to_owned())),
+                    "onseeking" => this.set_onseeking(Some(value.to_owned())),
+                    "onselect" => this.set_onselect(Some(value.to_owned())),
+                    "onshow" => this.set_onshow(Some(value.to_owned())),
+                    "onstalled" => this.set_onstalled(Some(value.to_owned())),
+                    "onsubmit" => this.set_onsubmit(Some(value.to_owned())),
+                    "onsuspend" => this.set_onsuspend(Some(value.to_owned())),
+                    "ontimeupdate" => this.set_ontimeupdate(Some(value.to_owned())),
+                    "ontoggle" => this.set_ontoggle(Some(value.to_owned())),
+                    "onvolumechange" => this.set_onvolumechange(Some(value.to_owned())),
+                    "onwaiting" => this.set_onwaiting(Some(value.to_owned())),
+                    "onwheel" => this.set_onwheel(Some(value.to_owned())),
+                    "cx" => this.set_cx(Some(value.to_owned())),
+                    "cy" => this.set_cy(Some(value.to_owned())),
+                    "r" => this.set_r(Some(value.to_owned())),
+                    "pathLength" => this.set_path_length(Some(value.to_owned())),
+                    _ => cx.other_attribute(this.data_map_mut(), name, value),
+                }
+            }
+            this.children = cx.children(element);
+            this
+        }
+    }
+    impl crate::tree::Element for Circle {
+        fn tag_name(&self) -> &'static str {
+            "circle"
+        }
+        fn is_inline(&self) -> bool {
+            false
+        }
+        fn is_preformatted(&self) -> bool {
+            false
+        }
+        fn is_self_closing(&self) -> bool {
+            self.children.is_empty()
+        }
+        fn write_opening_tag(&self, writer: &mut String) -> std::fmt::Result {
+            match self.is_self_closing() {
+                true => html_sys::RenderElement::write_empty_tag(&self.sys, writer),
+                false => html_sys::RenderElement::write_opening_tag(&self.sys, writer),
+            }
+        }
+        fn write_closing_tag(&self, writer: &mut String) -> std::fmt::Result {
+            match self.is_self_closing() {
+                true => Ok(()),
+                false => html_sys::RenderElement::write_closing_tag(&self.sys, writer),
+            }
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::tree::Part<'_>> {
+            self.children.get(index).map(From::from)
+        }
+    }
+    impl<'a> From<&'a Circle> for crate::tree::Part<'a> {
+        fn from(element: &'a Circle) -> Self {
+            crate::tree::Part::Element(element)
+        }
+    }
+    impl<'a> From<&'a Circle> for crate::stream::Chunks<'a> {
+        fn from(element: &'a Circle) -> Self {
+            crate::stream::Chunks::new(element)
+        }
+    }
+    impl<'a> From<&'a Circle> for crate::render::Pretty<'a> {
+        fn from(element: &'a Circle) -> Self {
+            crate::render::Pretty::new(element.into())
+        }
+    }
+    impl<'a> From<&'a Circle> for crate::minify::Minified<'a> {
+        fn from(element: &'a Circle) -> Self {
+            crate::minify::Minified::new(element.into())
+        }
+    }
+    impl crate::visit::Visit for Circle {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn eq_element(&self, other: &dyn crate::visit::Visit) -> bool {
+            other.downcast_ref::<Self>() == Some(self)
+        }
+        fn child(&self, index: usize) -> std::option::Option<crate::visit::NodeRef<'_>> {
+            self.children.get(index).map(From::from)
+        }
+        fn child_mut(
+            &mut self,
+            index: usize,
+        ) -> std::option::Option<crate::visit::NodeMut<'_>> {
+            self.children.get_mut(index).map(From::from)
+        }
+        fn retain_children(
+            &mut self,
+            f: &mut dyn FnMut(crate::visit::NodeRef<'_>) -> bool,
+        ) {
+            self.children.retain(|child| f(child.into()));
+        }
+        fn insert_child(
+            &mut self,
+            index: usize,
+            node: crate::visit::NodeRef<'_>,
+        ) -> bool {
+            match super::child::CircleChild::from_node(node) {
+                Some(child) if index <= self.children.len() => {
+                    self.children.insert(index, child);
+                    true
+                }
+                _ => false,
+            }
+        }
+        fn remove_child(&mut self, index: usize) -> bool {
+            match index < self.children.len() {
+                true => {
+                    self.children.remove(index);
+                    true
+                }
+                false => false,
+            }
+        }
+        fn set_attribute(
+            &mut self,
+            name: &str,
+            value: std::option::Option<&str>,
+        ) -> bool {
+            match name {
+                "id" => self.set_id(value.map(str::to_owned)),
+                "tabindex" => {
+                    self.set_tabindex(value.and_then(|value| value.parse().ok()))
+                }
+                "autofocus" => self.set_autofocus(value.is_some()),
+                "lang" => self.set_lang(value.map(str::to_owned)),
+                "class" => self.set_class(value.map(str::to_owned)),
+                "style" => self.set_style(value.map(str::to_owned)),
+                "requiredExtensions" => {
+                    self.set_required_extensions(value.map(str::to_owned))
+                }
+                "systemLanguage" => self.set_system_language(value.map(str::to_owned)),
+                "alignment-baseline" => {
+                    self.set_alignment_baseline(value.map(str::to_owned))
+                }
+                "baseline-shift" => self.set_baseline_shift(value.map(str::to_owned)),
+                "clip" => self.set_clip(value.map(str::to_owned)),
+                "clip-path" => self.set_clip_path(value.map(str::to_owned)),
+                "clip-rule" => self.set_clip_rule(value.map(str::to_owned)),
+                "color" => self.set_color(value.map(str::to_owned)),
+                "color-interpolation" => {
+                    self.set_color_interpolation(value.map(str::to_owned))
+                }
+                "color-interpolation-filters" => {
+                    self.set_color_interpolation_filters(value.map(str::to_owned))
+                }
+                "color-rendering" => self.set_color_rendering(value.map(str::to_owned)),
+                "cursor" => self.set_cursor(value.map(str::to_owned)),
+                "direction" => self.set_direction(value.map(str::to_owned)),
+                "display" => self.set_display(value.map(str::to_owned)),
+                "dominant-baseline" => {
+                    self.set_dominant_baseline(value.map(str::to_owned))
+                }
+                "fill" => self.set_fill(value.map(str::to_owned)),
+                "fill-opacity" => self.set_fill_opacity(value.map(str::to_owned)),
+                "fill-rule" => self.set_fill_rule(value.map(str::to_owned)),
+                "filter" => self.set_filter(value.map(str::to_owned)),
+                "flood-color" => self.set_flood_color(value.map(str::to_owned)),
+                "flood-opacity" => self.set_flood_opacity(value.map(str::to_owned)),
+                "font-family" => self.set_font_family(value.map(str::to_owned)),
+                "font-size" => self.set_font_size(value.map(str::to_owned)),
+                "font-size-adjust" => self.set_font_size_adjust(value.map(str::to_owned)),
+                "font-stretch" => self.set_font_stretch(value.map(str::to_owned)),
+                "font-style" => self.set_font_style(value.map(str::to_owned)),
+                "font-variant" => self.set_font_variant(value.map(str::to_owned)),
+                "font-weight" => self.set_font_weight(value.map(str::to_owned)),
+                "glyph-orientation-horizontal" => {
+                    self.set_glyph_orientation_horizontal(value.map(str::to_owned))
+                }
+                "glyph-orientation-vertical" => {
+                    self.set_glyph_orientation_vertical(value.map(str::to_owned))
+                }
+                "image-rendering" => self.set_image_rendering(value.map(str::to_owned)),
+                "letter-spacing" => self.set_letter_spacing(value.map(str::to_owned)),
+                "lighting-color" => self.set_lighting_color(value.map(str::to_owned)),
+                "marker-end" => self.set_marker_end(value.map(str::to_owned)),
+                "marker-mid" => self.set_marker_mid(value.map(str::to_owned)),
+                "marker-start" => self.set_marker_start(value.map(str::to_owned)),
+                "mask" => self.set_mask(value.map(str::to_owned)),
+                "mask-type" => self.set_mask_type(value.map(str::to_owned)),
+                "opacity" => self.set_opacity(value.map(str::to_owned)),
+                "overflow" => self.set_overflow(value.map(str::to_owned)),
+                "paint-order" => self.set_paint_order(value.map(str::to_owned)),
+                "pointer-events" => self.set_pointer_events(value.map(str::to_owned)),
+                "shape-rendering" => self.set_shape_rendering(value.map(str::to_owned)),
+                "stop-color" => self.set_stop_color(value.map(str::to_owned)),
+                "stop-opacity" => self.set_stop_opacity(value.map(str::to_owned)),
+                "stroke" => self.set_stroke(value.map(str::to_owned)),
+                "stroke-dasharray" => self.set_stroke_dasharray(value.map(str::to_owned)),
+                "stroke-dashoffset" => {
+                    self.set_stroke_dashoffset(value.map(str::to_owned))
+                }
+                "stroke-linecap" => self.set_stroke_linecap(value.map(str::to_owned)),
+                "stroke-linejoin" => self.set_stroke_linejoin(value.map(str::to_owned)),
+                "stroke-miterlimit" => {
+                    self.set_stroke_miterlimit(value.map(str::to_owned))
+                }
+                "stroke-opacity" => self.set_stroke_opacity(value.map(str::to_owned)),
+                "stroke-width" => self.set_stroke_width(value.map(str::to_owned)),
+                "text-anchor" => self.set_text_anchor(value.map(str::to_owned)),
+                "text-decoration" => self.set_text_decoration(value.map(str::to_owned)),
+                "text-overflow" => self.set_text_overflow(value.map(str::to_owned)),
+                "text-rendering" => self.set_text_rendering(value.map(str::to_owned)),
+                "transform" => self.set_transform(value.map(str::to_owned)),
+                "transform-origin" => self.set_transform_origin(value.map(str::to_owned)),
+                "unicode-bidi" => self.set_unicode_bidi(value.map(str::to_owned)),
+                "vector-effect" => self.set_vector_effect(value.map(str::to_owned)),
+                "visibility" => self.set_visibility(value.map(str::to_owned)),
+                "white-space" => self.set_white_space(value.map(str::to_owned)),
+                "word-spacing" => self.set_word_spacing(value.map(str::to_owned)),
+                "writing-mode" => self.set_writing_mode(value.map(str::to_owned)),
+                "oncancel" => self.set_oncancel(value.map(str::to_owned)),
+                "oncanplay" => self.set_oncanplay(value.map(str::to_owned)),
+                "oncanplaythrough" => self.set_oncanplaythrough(value.map(str::to_owned)),
+                "onchange" => self.set_onchange(value.map(str::to_owned)),
+                "onclick" => self.set_onclick(value.map(str::to_owned)),
+                "onclose" => self.set_onclose(value.map(str::to_owned)),
+                "oncuechange" => self.set_oncuechange(value.map(str::to_owned)),
+                "ondblclick" => self.set_ondblclick(value.map(str::to_owned)),
+                "ondrag" => self.set_ondrag(value.map(str::to_owned)),
+                "ondragend" => self.set_ondragend(value.map(str::to_owned)),
+                "ondragenter" => self.set_ondragenter(value.map(str::to_owned)),
+                "ondragexit" => self.set_ondragexit(value.map(str::to_owned)),
+                "ondragleave" => self.set_ondragleave(value.map(str::to_owned)),
+                "ondragover" => self.set_ondragover(value.map(str::to_owned)),
+                "ondragstart" => self.set_ondragstart(value.map(str::to_owned)),
+                "ondrop" => self.set_ondrop(value.map(str::to_owned)),
+                "ondurationchange" => self.set_ondurationchange(value.map(str::to_owned)),
+                "onemptied" => self.set_onemptied(value.map(str::to_owned)),
+                "onended" => self.set_onended(value.map(str::to_owned)),
+                "onerror" => self.set_onerror(value.map(str::to_owned)),
+                "onfocus" => self.set_onfocus(value.map(str::to_owned)),
+                "oninput" => self.set_oninput(value.map(str::to_owned)),
+                "oninvalid" => self.set_oninvalid(value.map(str::to_owned)),
+                "onkeydown" => self.set_onkeydown(value.map(str::to_owned)),
+                "onkeypress" => self.set_onkeypress(value.map(str::to_owned)),
+                "onkeyup" => self.set_onkeyup(value.map(str::to_owned)),
+                "onload" => self.set_onload(value.map(str::to_owned)),
+                "onloadeddata" => self.set_onloadeddata(value.map(str::to_owned)),
+                "onloadedmetadata" => self.set_onloadedmetadata(value.map(str::to_owned)),
+                "onloadstart" => self.set_onloadstart(value.map(str::to_owned)),
+                "onmousedown" => self.set_onmousedown(value.map(str::to_owned)),
+                "onmouseenter" => self.set_onmouseenter(value.map(str::to_owned)),
+                "onmouseleave" => self.set_onmouseleave(value.map(str::to_owned)),
+                "onmousemove" => self.set_onmousemove(value.map(str::to_owned)),
+                "onmouseout" => self.set_onmouseout(value.map(str::to_owned)),
+                "onmouseover" => self.set_onmouseover(value.map(str::to_owned)),
+                "onmouseup" => self.set_onmouseup(value.map(str::to_owned)),
+                "onpause" => self.set_onpause(value.map(str::to_owned)),
+                "onplay" => self.set_onplay(value.map(str::to_owned)),
+                "onplaying" => self.set_onplaying(value.map(str::to_owned)),
+                "onprogress" => self.set_onprogress(value.map(str::to_owned)),
+                "onratechange" => self.set_onratechange(value.map(str::to_owned)),
+                "onreset" => self.set_onreset(value.map(str::to_owned)),
+                "onresize" => self.set_onresize(value.map(str::to_owned)),
+                "onscroll" => self.set_onscroll(value.map(str::to_owned)),
+                "onseeked" => self.set_onseeked(value.map(str::to_owned)),
+                "onseeking" => self.set_onseeking(value.map(str::to_owned)),
+                "onselect" => self.set_onselect(value.map(str::to_owned)),
+                "onshow" => self.set_onshow(value.map(str::to_owned)),
+                "onstalled" => self.set_onstalled(value.map(str::to_owned)),
+                "onsubmit" => self.set_onsubmit(value.map(str::to_owned)),
+                "onsuspend" => self.set_onsuspend(value.map(str::to_owned)),
+                "ontimeupdate" => self.set_ontimeupdate(value.map(str::to_owned)),
+                "ontoggle" => self.set_ontoggle(value.map(str::to_owned)),
+                "onvolumechange" => self.set_onvolumechange(value.map(str::to_owned)),
+                "onwaiting" => self.set_onwaiting(value.map(str::to_owned)),
+                "onwheel" => self.set_onwheel(value.map(str::to_owned)),
+                "cx" => self.set_cx(value.map(str::to_owned)),
+                "cy" => self.set_cy(value.map(str::to_owned)),
+                "r" => self.set_r(value.map(str::to_owned)),
+                "pathLength" => self.set_path_length(value.map(str::to_owned)),
+                _ => {
+                    match (name.strip_prefix("data-"), value) {
+                        (Some(key), Some(value)) => {
+                            self.data_map_mut()
+                                .insert(key.to_owned().into(), value.to_owned().into());
+                        }
+                        (Some(key), None) => {
+                            self.data_map_mut().remove(key);
+                        }
+                        (None, _) => return false,
+                    }
+                }
+            }
+            true
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeElement for Circle {
+        const TAG_NAME: &'static str = "circle";
+        type Attributes = html_sys::svg::Circle;
+        type Child = super::child::CircleChild;
+        fn attributes(&self) -> &Self::Attributes {
+            &self.sys
+        }
+        fn children(&self) -> &[Self::Child] {
+            &self.children
+        }
+        fn from_parts(sys: Self::Attributes, children: Vec<Self::Child>) -> Self {
+            Self { sys, children }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for Circle {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            crate::serialize::serialize_element(self, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for Circle {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_element(deserializer)
+        }
+    }
+    impl From<Circle> for html_sys::svg::Circle {
+        fn from(element: Circle) -> Self {
+            element.sys
+        }
+    }
+    impl From<html_sys::svg::Circle> for Circle {
+        fn from(sys: html_sys::svg::Circle) -> Self {
+            Self { sys, children: vec![] }
+        }
+    }
+}
+pub mod child {
+    /// The permitted child items for the `Circle` element
+    #[derive(PartialEq, Clone)]
+    #[allow(clippy::large_enum_variant)]
+    pub enum CircleChild {
+        /// The ClipPath element
+        ClipPath(crate::generated::all::ClipPath),
+        /// The Desc element
+        Desc(crate::generated::all::Desc),
+        /// The Marker element
+        Marker(crate::generated::all::Marker),
+        /// The Mask element
+        Mask(crate::generated::all::Mask),
+        /// The Metadata element
+        Metadata(crate::generated::all::Metadata),
+        /// The SvgScript element
+        SvgScript(crate::generated::all::SvgScript),
+        /// The SvgStyle element
+        SvgStyle(crate::generated::all::SvgStyle),
+        /// The SvgTitle element
+        SvgTitle(crate::generated::all::SvgTitle),
+        /// A comment
+        Comment(crate::Comment),
+        /// Trusted HTML, which is written without being escaped
+        RawHtml(crate::RawHtml),
+    }
+    impl std::convert::From<crate::generated::all::ClipPath> for CircleChild {
+        fn from(value: crate::generated::all::ClipPath) -> Self {
+            Self::ClipPath(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Desc> for CircleChild {
+        fn from(value: crate::generated::all::Desc) -> Self {
+            Self::Desc(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Marker> for CircleChild {
+        fn from(value: crate::generated::all::Marker) -> Self {
+            Self::Marker(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Mask> for CircleChild {
+        fn from(value: crate::generated::all::Mask) -> Self {
+            Self::Mask(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::Metadata> for CircleChild {
+        fn from(value: crate::generated::all::Metadata) -> Self {
+            Self::Metadata(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SvgScript> for CircleChild {
+        fn from(value: crate::generated::all::SvgScript) -> Self {
+            Self::SvgScript(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SvgStyle> for CircleChild {
+        fn from(value: crate::generated::all::SvgStyle) -> Self {
+            Self::SvgStyle(value)
+        }
+    }
+    impl std::convert::From<crate::generated::all::SvgTitle> for CircleChild {
+        fn from(value: crate::generated::all::SvgTitle) -> Self {
+            Self::SvgTitle(value)
+        }
+    }
+    impl std::convert::From<crate::Comment> for CircleChild {
+        fn from(value: crate::Comment) -> Self {
+            Self::Comment(value)
+        }
+    }
+    impl std::convert::From<crate::RawHtml> for CircleChild {
+        fn from(value: crate::RawHtml) -> Self {
+            Self::RawHtml(value)
+        }
+    }
+    impl std::fmt::Debug for CircleChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", crate ::render::Pretty::from(self))
+        }
+    }
+    impl std::fmt::Display for CircleChild {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::ClipPath(el) => write!(f, "{el}"),
+                Self::Desc(el) => write!(f, "{el}"),
+                Self::Marker(el) => write!(f, "{el}"),
+                Self::Mask(el) => write!(f, "{el}"),
+                Self::Metadata(el) => write!(f, "{el}"),
+                Self::SvgScript(el) => write!(f, "{el}"),
+                Self::SvgStyle(el) => write!(f, "{el}"),
+                Self::SvgTitle(el) => write!(f, "{el}"),
+                Self::Comment(el) => write!(f, "{el}"),
+                Self::RawHtml(el) => write!(f, "{el}"),
+            }
+        }
+    }
+    impl<'a> From<&'a CircleChild> for crate::Node<'a> {
+        fn from(child: &'a CircleChild) -> Self {
+            match child {
+                CircleChild::ClipPath(el) => crate::Node::from(el),
+                CircleChild::Desc(el) => crate::Node::from(el),
+                CircleChild::Marker(el) => crate::Node::from(el),
+                CircleChild::Mask(el) => crate::Node::from(el),
+                CircleChild::Metadata(el) => crate::Node::from(el),
+                CircleChild::SvgScript(el) => crate::Node::from(el),
+                CircleChild::SvgStyle(el) => crate::Node::from(el),
+                CircleChild::SvgTitle(el) => crate::Node::from(el),
+                CircleChild::Comment(el) => crate::Node::from(el),
+                CircleChild::RawHtml(el) => crate::Node::from(el),
+            }
+        }
+    }
+    #[cfg(feature = "parse")]
+    impl crate::parse::FromContent for CircleChild {
+        const PARENT_TAG_NAME: &'static str = "circle";
+        fn from_content(
+            content: crate::parse::Content<'_>,
+            cx: &mut crate::parse::Context,
+        ) -> Option<Self> {
+            match content {
+                crate::parse::Content::Text(_) => cx.not_permitted(content),
+                crate::parse::Content::Comment(comment) => {
+                    Some(Self::Comment(crate::Comment::new(comment.to_owned())))
+                }
+                crate::parse::Content::Element(el) => {
+                    match el.tag_name() {
+                        "clipPath" => Some(Self::ClipPath(cx.element(el))),
+                        "desc" => Some(Self::Desc(cx.element(el))),
+                        "marker" => Some(Self::Marker(cx.element(el))),
+                        "mask" => Some(Self::Mask(cx.element(el))),
+                        "metadata" => Some(Self::Metadata(cx.element(el))),
+                        "script" => Some(Self::SvgScript(cx.element(el))),
+                        "style" => Some(Self::SvgStyle(cx.element(el))),
+                        "title" => Some(Self::SvgTitle(cx.element(el))),
+                        _ => cx.not_permitted(content),
+                    }
+                }
+            }
+        }
+    }
+    impl<'a> From<&'a CircleChild> for crate::tree::Part<'a> {
+        fn from(child: &'a CircleChild) -> Self {
+            match child {
+                CircleChild::ClipPath(el) => crate::tree::Part::Element(el),
+                CircleChild::Desc(el) => crate::tree::Part::Element(el),
+                CircleChild::Marker(el) => crate::tree::Part::Element(el),
+                CircleChild::Mask(el) => crate::tree::Part::Element(el),
+                CircleChild::Metadata(el) => crate::tree::Part::Element(el),
+                CircleChild::SvgScript(el) => crate::tree::Part::Element(el),
+                CircleChild::SvgStyle(el) => crate::tree::Part::Element(el),
+                CircleChild::SvgTitle(el) => crate::tree::Part::Element(el),
+                CircleChild::Comment(el) => {
+                    crate::tree::Part::Comment(html_sys::escape::Comment(el.as_str()))
+                }
+                CircleChild::RawHtml(el) => crate::tree::Part::RawHtml(el.as_str()),
+            }
+        }
+    }
+    impl<'a> From<&'a CircleChild> for crate::render::Pretty<'a> {
+        fn from(child: &'a CircleChild) -> Self {
+            crate::render::Pretty::new(child.into())
+        }
+    }
+    impl<'a> From<&'a CircleChild> for crate::minify::Minified<'a> {
+        fn from(child: &'a CircleChild) -> Self {
+            crate::minify::Minified::new(child.into())
+        }
+    }
+    impl CircleChild {
+        /// Clone a node into a child, if it's permitted.
+        pub(crate) fn from_node(
+            node: crate::visit::NodeRef<'_>,
+        ) -> std::option::Option<Self> {
+            match node {
+                crate::visit::NodeRef::Element(el) => {
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::ClipPath>()
+                    {
+                        return Some(Self::ClipPath(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Desc>() {
+                        return Some(Self::Desc(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Marker>()
+                    {
+                        return Some(Self::Marker(el.clone()));
+                    }
+                    if let Some(el) = el.downcast_ref::<crate::generated::all::Mask>() {
+                        return Some(Self::Mask(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::Metadata>()
+                    {
+                        return Some(Self::Metadata(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SvgScript>()
+                    {
+                        return Some(Self::SvgScript(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SvgStyle>()
+                    {
+                        return Some(Self::SvgStyle(el.clone()));
+                    }
+                    if let Some(el) = el
+                        .downcast_ref::<crate::generated::all::SvgTitle>()
+                    {
+                        return Some(Self::SvgTitle(el.clone()));
+                    }
+                    None
+                }
+                crate::visit::NodeRef::Text(_) => None,
+                crate::visit::NodeRef::Comment(comment) => {
+                    Some(Self::Comment(comment.clone()))
+                }
+                crate::visit::NodeRef::RawHtml(html) => Some(Self::RawHtml(html.clone())),
+            }
+        }
+    }
+    impl<'a> From<&'a CircleChild> for crate::visit::NodeRef<'a> {
+        fn from(child: &'a CircleChild) -> Self {
+            match child {
+                CircleChild::ClipPath(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::Desc(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::Marker(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::Mask(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::Metadata(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::SvgScript(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::SvgStyle(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::SvgTitle(el) => crate::visit::NodeRef::Element(el),
+                CircleChild::Comment(el) => crate::visit::NodeRef::Comment(el),
+                CircleChild::RawHtml(el) => crate::visit::NodeRef::RawHtml(el),
+            }
+        }
+    }
+    impl<'a> From<&'a mut CircleChild> for crate::visit::NodeMut<'a> {
+        fn from(child: &'a mut CircleChild) -> Self {
+            match child {
+                CircleChild::ClipPath(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::Desc(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::Marker(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::Mask(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::Metadata(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::SvgScript(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::SvgStyle(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::SvgTitle(el) => crate::visit::NodeMut::Element(el),
+                CircleChild::Comment(el) => crate::visit::NodeMut::Comment(el),
+                CircleChild::RawHtml(el) => crate::visit::NodeMut::RawHtml(el),
+            }
+        }
+    }
+    impl std::convert::TryFrom<crate::AnyElement> for CircleChild {
+        type Error = crate::NotPermitted;
+        fn try_from(element: crate::AnyElement) -> Result<Self, Self::Error> {
+            match element {
+                crate::AnyElement::ClipPath(el) => Ok(Self::ClipPath(el)),
+                crate::AnyElement::Desc(el) => Ok(Self::Desc(el)),
+                crate::AnyElement::Marker(el) => Ok(Self::Marker(el)),
+                crate::AnyElement::Mask(el) => Ok(Self::Mask(el)),
+                crate::AnyElement::Metadata(el) => Ok(Self::Metadata(el)),
+                crate::AnyElement::SvgScript(el) => Ok(Self::SvgScript(el)),
+                crate::AnyElement::SvgStyle(el) => Ok(Self::SvgStyle(el)),
+                crate::AnyElement::SvgTitle(el) => Ok(Self::SvgTitle(el)),
+                #[allow(unreachable_patterns)]
+                element => Err(crate::NotPermitted::new(element, "circle")),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for CircleChild {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::ClipPath(el) => serde::Serialize::serialize(el, serializer),
+                Self::Desc(el) => serde::Serialize::serialize(el, serializer),
+                Self::Marker(el) => serde::Serialize::serialize(el, serializer),
+                Self::Mask(el) => serde::Serialize::serialize(el, serializer),
+                Self::Metadata(el) => serde::Serialize::serialize(el, serializer),
+                Self::SvgScript(el) => serde::Serialize::serialize(el, serializer),
+                Self::SvgStyle(el) => serde::Serialize::serialize(el, serializer),
+                Self::SvgTitle(el) => serde::Serialize::serialize(el, serializer),
+                Self::Comment(el) => serde::Serialize::serialize(el, serializer),
+                Self::RawHtml(el) => serde::Serialize::serialize(el, serializer),
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for CircleChild {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            crate::serialize::deserialize_child(deserializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl crate::serialize::SerdeChild for CircleChild {
+        const PARENT_TAG_NAME: &'static str = "circle";
+        fn from_text(_text: String) -> Option<Self> {
+            None
+        }
+        fn from_comment(comment: crate::Comment) -> Option<Self> {
+            Some(Self::Comment(comment))
+        }
+        fn from_raw_html(html: crate::RawHtml) -> Self {
+            Self::RawHtml(html)
+        }
+        fn deserialize_element<'de, M: serde::de::MapAccess<'de>>(
+            tag: &str,
+            map: M,
+        ) -> Result<Self, M::Error> {
+            match tag {
+                "clipPath" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::ClipPath)
+                }
+                "desc" => crate::serialize::deserialize_element_body(map).map(Self::Desc),
+                "marker" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Marker)
+                }
+                "mask" => crate::serialize::deserialize_element_body(map).map(Self::Mask),
+                "metadata" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::Metadata)
+                }
+                "script" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::SvgScript)
+                }
+                "style" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::SvgStyle)
+                }
+                "title" => {
+                    crate::serialize::deserialize_element_body(map).map(Self::SvgTitle)
+                }
+                tag => {
+                    Err(
+                        crate::serialize::child_not_permitted(Self::PARENT_TAG_NAME, tag),
+                    )
+                }
+            }
+        }
+    }
+}
+pub mod builder {
+    /// A builder struct for Circle
+    pub struct CircleBuilder {
+        element: super::element::Circle,
+    }
+    impl CircleBuilder {
+        pub(crate) fn new(element: super::element::Circle) -> Self {
+            Self { element }
+        }
+        /// Finish building the element
+        pub fn build(&mut self) -> super::element::Circle {
+            self.element.clone()
+        }
+        /// Insert a `data-*` property
+        pub fn data(
+            &mut self,
+            data_key: impl Into<std::borrow::Cow<'static, str>>,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut CircleBuilder {
+            self.element.data_map_mut().insert(data_key.into(), value.into());
+            self
+        }
+        /// Append a new `ClipPath` element
+        pub fn clip_path<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::ClipPathBuilder,
+            ) -> &'a mut crate::generated::all::builders::ClipPathBuilder,
+        {
+            let ty: crate::generated::all::ClipPath = Default::default();
+            let mut ty_builder = crate::generated::all::builders::ClipPathBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Desc` element
+        pub fn desc<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::DescBuilder,
+            ) -> &'a mut crate::generated::all::builders::DescBuilder,
+        {
+            let ty: crate::generated::all::Desc = Default::default();
+            let mut ty_builder = crate::generated::all::builders::DescBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Marker` element
+        pub fn marker<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MarkerBuilder,
+            ) -> &'a mut crate::generated::all::builders::MarkerBuilder,
+        {
+            let ty: crate::generated::all::Marker = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MarkerBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Mask` element
+        pub fn mask<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MaskBuilder,
+            ) -> &'a mut crate::generated::all::builders::MaskBuilder,
+        {
+            let ty: crate::generated::all::Mask = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MaskBuilder::new(ty);
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `Metadata` element
+        pub fn metadata<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::MetadataBuilder,
+            ) -> &'a mut crate::generated::all::builders::MetadataBuilder,
+        {
+            let ty: crate::generated::all::Metadata = Default::default();
+            let mut ty_builder = crate::generated::all::builders::MetadataBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `SvgScript` element
+        pub fn svg_script<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SvgScriptBuilder,
+            ) -> &'a mut crate::generated::all::builders::SvgScriptBuilder,
+        {
+            let ty: crate::generated::all::SvgScript = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SvgScriptBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `SvgStyle` element
+        pub fn svg_style<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SvgStyleBuilder,
+            ) -> &'a mut crate::generated::all::builders::SvgStyleBuilder,
+        {
+            let ty: crate::generated::all::SvgStyle = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SvgStyleBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Append a new `SvgTitle` element
+        pub fn svg_title<F>(&mut self, f: F) -> &mut Self
+        where
+            F: for<'a> FnOnce(
+                &'a mut crate::generated::all::builders::SvgTitleBuilder,
+            ) -> &'a mut crate::generated::all::builders::SvgTitleBuilder,
+        {
+            let ty: crate::generated::all::SvgTitle = Default::default();
+            let mut ty_builder = crate::generated::all::builders::SvgTitleBuilder::new(
+                ty,
+            );
+            (f)(&mut ty_builder);
+            let ty = ty_builder.build();
+            self.element.children_mut().push(ty.into());
+            self
+        }
+        /// Set the value of the `id` attribute
+        pub fn id(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_id(Some(value.into()));
+            self
+        }
+        /// Set the value of the `tabindex` attribute
+        pub fn tabindex(&mut self, value: i64) -> &mut Self {
+            self.element.set_tabindex(Some(value));
+            self
+        }
+        /// Set the value of the `autofocus` attribute
+        pub fn autofocus(&mut self, value: bool) -> &mut Self {
+            self.element.set_autofocus(value);
+            self
+        }
+        /// Set the value of the `lang` attribute
+        pub fn lang(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_lang(Some(value.into()));
+            self
+        }
+        /// Set the value of the `class` attribute
+        pub fn class(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_class(Some(value.into()));
+            self
+        }
+        /// Set the value of the `style` attribute
+        pub fn style(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_style(Some(value.into()));
+            self
+        }
+        /// Set the value of the `requiredExtensions` attribute
+        pub fn required_extensions(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_required_extensions(Some(value.into()));
+            self
+        }
+        /// Set the value of the `systemLanguage` attribute
+        pub fn system_language(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_system_language(Some(value.into()));
+            self
+        }
+        /// Set the value of the `alignment-baseline` attribute
+        pub fn alignment_baseline(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_alignment_baseline(Some(value.into()));
+            self
+        }
+        /// Set the value of the `baseline-shift` attribute
+        pub fn baseline_shift(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_baseline_shift(Some(value.into()));
+            self
+        }
+        /// Set the value of the `clip` attribute
+        pub fn clip(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_clip(Some(value.into()));
+            self
+        }
+        /// Set the value of the `clip-path` attribute
+        pub fn clip_path_attr(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_clip_path(Some(value.into()));
+            self
+        }
+        /// Set the value of the `clip-rule` attribute
+        pub fn clip_rule(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_clip_rule(Some(value.into()));
+            self
+        }
+        /// Set the value of the `color` attribute
+        pub fn color(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_color(Some(value.into()));
+            self
+        }
+        /// Set the value of the `color-interpolation` attribute
+        pub fn color_interpolation(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_color_interpolation(Some(value.into()));
+            self
+        }
+        /// Set the value of the `color-interpolation-filters` attribute
+        pub fn color_interpolation_filters(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_color_interpolation_filters(Some(value.into()));
+            self
+        }
+        /// Set the value of the `color-rendering` attribute
+        pub fn color_rendering(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_color_rendering(Some(value.into()));
+            self
+        }
+        /// Set the value of the `cursor` attribute
+        pub fn cursor(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_cursor(Some(value.into()));
+            self
+        }
+        /// Set the value of the `direction` attribute
+        pub fn direction(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_direction(Some(value.into()));
+            self
+        }
+        /// Set the value of the `display` attribute
+        pub fn display(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_display(Some(value.into()));
+            self
+        }
+        /// Set the value of the `dominant-baseline` attribute
+        pub fn dominant_baseline(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_dominant_baseline(Some(value.into()));
+            self
+        }
+        /// Set the value of the `fill` attribute
+        pub fn fill(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_fill(Some(value.into()));
+            self
+        }
+        /// Set the value of the `fill-opacity` attribute
+        pub fn fill_opacity(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_fill_opacity(Some(value.into()));
+            self
+        }
+        /// Set the value of the `fill-rule` attribute
+        pub fn fill_rule(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_fill_rule(Some(value.into()));
+            self
+        }
+        /// Set the value of the `filter` attribute
+        pub fn filter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_filter(Some(value.into()));
+            self
+        }
+        /// Set the value of the `flood-color` attribute
+        pub fn flood_color(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_flood_color(Some(value.into()));
+            self
+        }
+        /// Set the value of the `flood-opacity` attribute
+        pub fn flood_opacity(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_flood_opacity(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-family` attribute
+        pub fn font_family(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_family(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-size` attribute
+        pub fn font_size(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_size(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-size-adjust` attribute
+        pub fn font_size_adjust(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_size_adjust(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-stretch` attribute
+        pub fn font_stretch(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_stretch(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-style` attribute
+        pub fn font_style(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_style(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-variant` attribute
+        pub fn font_variant(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_variant(Some(value.into()));
+            self
+        }
+        /// Set the value of the `font-weight` attribute
+        pub fn font_weight(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_font_weight(Some(value.into()));
+            self
+        }
+        /// Set the value of the `glyph-orientation-horizontal` attribute
+        pub fn glyph_orientation_horizontal(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_glyph_orientation_horizontal(Some(value.into()));
+            self
+        }
+        /// Set the value of the `glyph-orientation-vertical` attribute
+        pub fn glyph_orientation_vertical(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_glyph_orientation_vertical(Some(value.into()));
+            self
+        }
+        /// Set the value of the `image-rendering` attribute
+        pub fn image_rendering(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_image_rendering(Some(value.into()));
+            self
+        }
+        /// Set the value of the `letter-spacing` attribute
+        pub fn letter_spacing(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_letter_spacing(Some(value.into()));
+            self
+        }
+        /// Set the value of the `lighting-color` attribute
+        pub fn lighting_color(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_lighting_color(Some(value.into()));
+            self
+        }
+        /// Set the value of the `marker-end` attribute
+        pub fn marker_end(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_marker_end(Some(value.into()));
+            self
+        }
+        /// Set the value of the `marker-mid` attribute
+        pub fn marker_mid(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_marker_mid(Some(value.into()));
+            self
+        }
+        /// Set the value of the `marker-start` attribute
+        pub fn marker_start(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_marker_start(Some(value.into()));
+            self
+        }
+        /// Set the value of the `mask` attribute
+        pub fn mask_attr(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_mask(Some(value.into()));
+            self
+        }
+        /// Set the value of the `mask-type` attribute
+        pub fn mask_type(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_mask_type(Some(value.into()));
+            self
+        }
+        /// Set the value of the `opacity` attribute
+        pub fn opacity(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_opacity(Some(value.into()));
+            self
+        }
+        /// Set the value of the `overflow` attribute
+        pub fn overflow(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_overflow(Some(value.into()));
+            self
+        }
+        /// Set the value of the `paint-order` attribute
+        pub fn paint_order(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_paint_order(Some(value.into()));
+            self
+        }
+        /// Set the value of the `pointer-events` attribute
+        pub fn pointer_events(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_pointer_events(Some(value.into()));
+            self
+        }
+        /// Set the value of the `shape-rendering` attribute
+        pub fn shape_rendering(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_shape_rendering(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stop-color` attribute
+        pub fn stop_color(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stop_color(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stop-opacity` attribute
+        pub fn stop_opacity(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stop_opacity(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke` attribute
+        pub fn stroke(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-dasharray` attribute
+        pub fn stroke_dasharray(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_dasharray(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-dashoffset` attribute
+        pub fn stroke_dashoffset(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_dashoffset(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-linecap` attribute
+        pub fn stroke_linecap(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_linecap(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-linejoin` attribute
+        pub fn stroke_linejoin(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_linejoin(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-miterlimit` attribute
+        pub fn stroke_miterlimit(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_miterlimit(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-opacity` attribute
+        pub fn stroke_opacity(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_opacity(Some(value.into()));
+            self
+        }
+        /// Set the value of the `stroke-width` attribute
+        pub fn stroke_width(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_stroke_width(Some(value.into()));
+            self
+        }
+        /// Set the value of the `text-anchor` attribute
+        pub fn text_anchor(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_text_anchor(Some(value.into()));
+            self
+        }
+        /// Set the value of the `text-decoration` attribute
+        pub fn text_decoration(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_text_decoration(Some(value.into()));
+            self
+        }
+        /// Set the value of the `text-overflow` attribute
+        pub fn text_overflow(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_text_overflow(Some(value.into()));
+            self
+        }
+        /// Set the value of the `text-rendering` attribute
+        pub fn text_rendering(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_text_rendering(Some(value.into()));
+            self
+        }
+        /// Set the value of the `transform` attribute
+        pub fn transform(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_transform(Some(value.into()));
+            self
+        }
+        /// Set the value of the `transform-origin` attribute
+        pub fn transform_origin(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_transform_origin(Some(value.into()));
+            self
+        }
+        /// Set the value of the `unicode-bidi` attribute
+        pub fn unicode_bidi(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_unicode_bidi(Some(value.into()));
+            self
+        }
+        /// Set the value of the `vector-effect` attribute
+        pub fn vector_effect(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_vector_effect(Some(value.into()));
+            self
+        }
+        /// Set the value of the `visibility` attribute
+        pub fn visibility(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_visibility(Some(value.into()));
+            self
+        }
+        /// Set the value of the `white-space` attribute
+        pub fn white_space(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_white_space(Some(value.into()));
+            self
+        }
+        /// Set the value of the `word-spacing` attribute
+        pub fn word_spacing(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_word_spacing(Some(value.into()));
+            self
+        }
+        /// Set the value of the `writing-mode` attribute
+        pub fn writing_mode(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_writing_mode(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncancel` attribute
+        pub fn oncancel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncancel(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncanplay` attribute
+        pub fn oncanplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplay(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncanplaythrough` attribute
+        pub fn oncanplaythrough(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncanplaythrough(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onchange` attribute
+        pub fn onchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onchange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onclick` attribute
+        pub fn onclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onclick(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onclose` attribute
+        pub fn onclose(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onclose(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oncuechange` attribute
+        pub fn oncuechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oncuechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondblclick` attribute
+        pub fn ondblclick(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondblclick(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondrag` attribute
+        pub fn ondrag(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrag(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragend` attribute
+        pub fn ondragend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragenter` attribute
+        pub fn ondragenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragenter(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragexit` attribute
+        pub fn ondragexit(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragexit(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragleave` attribute
+        pub fn ondragleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragleave(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragover` attribute
+        pub fn ondragover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragover(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondragstart` attribute
+        pub fn ondragstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondragstart(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondrop` attribute
+        pub fn ondrop(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondrop(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ondurationchange` attribute
+        pub fn ondurationchange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ondurationchange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onemptied` attribute
+        pub fn onemptied(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onemptied(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onended` attribute
+        pub fn onended(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onended(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onerror` attribute
+        pub fn onerror(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onerror(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onfocus` attribute
+        pub fn onfocus(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onfocus(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oninput` attribute
+        pub fn oninput(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninput(Some(value.into()));
+            self
+        }
+        /// Set the value of the `oninvalid` attribute
+        pub fn oninvalid(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_oninvalid(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeydown` attribute
+        pub fn onkeydown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeydown(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeypress` attribute
+        pub fn onkeypress(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeypress(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onkeyup` attribute
+        pub fn onkeyup(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onkeyup(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onload` attribute
+        pub fn onload(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onload(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onloadeddata` attribute
+        pub fn onloadeddata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadeddata(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onloadedmetadata` attribute
+        pub fn onloadedmetadata(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadedmetadata(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onloadstart` attribute
+        pub fn onloadstart(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onloadstart(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmousedown` attribute
+        pub fn onmousedown(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousedown(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseenter` attribute
+        pub fn onmouseenter(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseenter(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseleave` attribute
+        pub fn onmouseleave(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseleave(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmousemove` attribute
+        pub fn onmousemove(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmousemove(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseout` attribute
+        pub fn onmouseout(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseout(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseover` attribute
+        pub fn onmouseover(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseover(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onmouseup` attribute
+        pub fn onmouseup(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onmouseup(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onpause` attribute
+        pub fn onpause(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onpause(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onplay` attribute
+        pub fn onplay(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onplay(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onplaying` attribute
+        pub fn onplaying(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onplaying(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onprogress` attribute
+        pub fn onprogress(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onprogress(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onratechange` attribute
+        pub fn onratechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onratechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onreset` attribute
+        pub fn onreset(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onreset(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onresize` attribute
+        pub fn onresize(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onresize(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onscroll` attribute
+        pub fn onscroll(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onscroll(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onseeked` attribute
+        pub fn onseeked(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onseeked(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onseeking` attribute
+        pub fn onseeking(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onseeking(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onselect` attribute
+        pub fn onselect(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onselect(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onshow` attribute
+        pub fn onshow(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onshow(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onstalled` attribute
+        pub fn onstalled(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onstalled(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onsubmit` attribute
+        pub fn onsubmit(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onsubmit(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onsuspend` attribute
+        pub fn onsuspend(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onsuspend(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ontimeupdate` attribute
+        pub fn ontimeupdate(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ontimeupdate(Some(value.into()));
+            self
+        }
+        /// Set the value of the `ontoggle` attribute
+        pub fn ontoggle(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_ontoggle(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onvolumechange` attribute
+        pub fn onvolumechange(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onvolumechange(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwaiting` attribute
+        pub fn onwaiting(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwaiting(Some(value.into()));
+            self
+        }
+        /// Set the value of the `onwheel` attribute
+        pub fn onwheel(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_onwheel(Some(value.into()));
+            self
+        }
+        /// Set the value of the `cx` attribute
+        pub fn cx(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_cx(Some(value.into()));
+            self
+        }
+        /// Set the value of the `cy` attribute
+        pub fn cy(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_cy(Some(value.into()));
+            self
+        }
+        /// Set the value of the `r` attribute
+        pub fn r(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_r(Some(value.into()));
+            self
+        }
+        /// Set the value of the `pathLength` attribute
+        pub fn path_length(
+            &mut self,
+            value: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.set_path_length(Some(value.into()));
+            self
+        }
+        /// Append a comment.
+        pub fn comment(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::Comment::new(s).into());
+            self
+        }
+        /// Append trusted HTML, which is written without being escaped.
+        ///
+        /// It's up to the caller to make sure the HTML is well-formed and
+        /// doesn't contain any untrusted input.
+        pub fn raw_html(
+            &mut self,
+            s: impl Into<std::borrow::Cow<'static, str>>,
+        ) -> &mut Self {
+            self.element.children_mut().push(crate::RawHtml::new(s).into());
+            self
+        }
+        /// Push a new child element to the list of children.
+        pub fn push<T>(&mut self, child_el: T) -> &mut Self
+        where
+            T: Into<crate::generated::all::children::CircleChild>,
+        {
+            let child_el = child_el.into();
+            self.element.children_mut().push(child_el);
+            self
+        }
+        /// Extend the list of children with an iterator of child elements.
+        pub fn extend<I, T>(&mut self, iter: I) -> &mut Self
+        where
+            I: IntoIterator<Item = T>,
+            T: Into<crate::generated::all::children::CircleChild>,
+        {
+            let iter = iter.into_iter().map(|child_el| child_el.into());
+            self.element.children_mut().extend(iter);
+            self
+        }
+    }
+}